@@ -0,0 +1,28 @@
+//! Benchmarks for the scalar multiplication on Baby Jubjub.
+
+use ark_ec::{AdditiveGroup, PrimeGroup};
+use ark_ff::PrimeField;
+use ark_std::{UniformRand, test_rng};
+use criterion::{Criterion, criterion_group, criterion_main};
+use taceo_ark_babyjubjub::{EdwardsConfig, EdwardsProjective, Fr};
+
+/// Compares the fixed-base multiplication of the generator with the constant-time ladder and the variable-time wNAF multiplication of arbitrary points.
+fn generator_bench(c: &mut Criterion) {
+    let scalar = Fr::rand(&mut test_rng());
+    let base = EdwardsProjective::generator().double();
+
+    let mut group = c.benchmark_group("Scalar Multiplication");
+    group.bench_function("Generator (fixed-base table)", |b| {
+        b.iter(|| EdwardsConfig::mul_generator(std::hint::black_box(&scalar)));
+    });
+    group.bench_function("Arbitrary point (constant-time ladder)", |b| {
+        b.iter(|| EdwardsConfig::mul_ct(&base, &std::hint::black_box(scalar).into_bigint().0));
+    });
+    group.bench_function("Arbitrary point (variable-time wNAF)", |b| {
+        b.iter(|| EdwardsConfig::mul_vartime(&base, &std::hint::black_box(scalar).into_bigint().0));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, generator_bench);
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+# Generates src/curves/generator_table.rs, the precomputed table for the fixed-base multiplication with the generator.
+#
+# Entry [i][j] of the table is (j + 1) * 16^i * G for i in 0..=64 and j in 0..8, such that a scalar in signed radix-16
+# representation with digits in [-8, 8] can be multiplied with G using one table lookup and one addition per digit.
+#
+# Usage: python3 scripts/generator_table.py > src/curves/generator_table.rs && cargo fmt
+
+q = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+a = 168700
+d = 168696
+G = (
+    5299619240641551281634865583518297030282874472190772894086521144482721001553,
+    16950150798460657717958625567821834550301663161624707787222815936182638968203,
+)
+
+WINDOWS = 65
+ENTRIES = 8
+
+
+def add(p1, p2):
+    (x1, y1), (x2, y2) = p1, p2
+    t = d * x1 * x2 * y1 * y2 % q
+    x3 = (x1 * y2 + y1 * x2) * pow(1 + t, -1, q) % q
+    y3 = (y1 * y2 - a * x1 * x2) * pow(1 - t, -1, q) % q
+    return (x3, y3)
+
+
+def main():
+    assert (a * G[0] ** 2 + G[1] ** 2 - 1 - d * G[0] ** 2 * G[1] ** 2) % q == 0
+
+    print("//! The precomputed table for [`super::fixed_base`], generated by `scripts/generator_table.py`. Do not edit.")
+    print()
+    print("use ark_ff::MontFp;")
+    print()
+    print("use super::EdwardsAffine;")
+    print()
+    print("/// Entry `[i][j]` is `(j + 1) * 16^i * G`.")
+    print(f"pub(super) static GENERATOR_TABLE: [[EdwardsAffine; {ENTRIES}]; {WINDOWS}] = [")
+    base = G
+    for _ in range(WINDOWS):
+        print("    [")
+        point = base
+        for j in range(ENTRIES):
+            print("        EdwardsAffine::new_unchecked(")
+            print(f'            MontFp!("{point[0]}"),')
+            print(f'            MontFp!("{point[1]}"),')
+            print("        ),")
+            if j + 1 < ENTRIES:
+                point = add(point, base)
+        print("    ],")
+        # 16 * base
+        for _ in range(4):
+            base = add(base, base)
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
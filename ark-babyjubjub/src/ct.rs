@@ -0,0 +1,223 @@
+//! Constant-time selection, equality and negation with the traits of the [`subtle`] crate.
+//!
+//! The orphan rule forbids implementing the traits of `subtle` for the arkworks types, so they are implemented for the wrapper [`Ct`] instead.
+
+use core::ops::Neg;
+
+use ark_ff::{AdditiveGroup, BigInt, BigInteger, Fp256, MontBackend, MontConfig};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{EdwardsAffine, EdwardsProjective};
+
+/// A wrapper around [`Fq`](crate::Fq), [`Fr`](crate::Fr), [`EdwardsAffine`] and [`EdwardsProjective`], which implements [`ConditionallySelectable`], [`ConstantTimeEq`] and
+/// [`ConditionallyNegatable`](subtle::ConditionallyNegatable) in constant time.
+///
+/// ```
+/// use taceo_ark_babyjubjub::{Ct, EdwardsProjective};
+/// use ark_ec::PrimeGroup;
+/// use subtle::{Choice, ConditionallyNegatable, ConstantTimeEq};
+///
+/// let mut point = Ct(EdwardsProjective::generator());
+/// point.conditional_negate(Choice::from(1));
+/// assert!(bool::from(point.ct_eq(&Ct(-EdwardsProjective::generator()))));
+/// ```
+///
+/// Equality of points compares the represented points, i.e., projective points are equal if they only differ in their `z` coordinate.
+/// The field arithmetic of arkworks is used for this comparison and for the points themselves, which is not guaranteed to be constant time.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Ct<T>(pub T);
+
+impl<T> From<T> for Ct<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<P: MontConfig<4>> ConditionallySelectable for Ct<Fp256<MontBackend<P, 4>>> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(Fp256::new_unchecked(BigInt(core::array::from_fn(|i| {
+            u64::conditional_select(&a.0.0.0[i], &b.0.0.0[i], choice)
+        }))))
+    }
+}
+
+impl<P: MontConfig<4>> ConstantTimeEq for Ct<Fp256<MontBackend<P, 4>>> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The Montgomery representation of a field element is unique, so comparing the limbs suffices.
+        self.0.0.0[..].ct_eq(&other.0.0.0[..])
+    }
+}
+
+impl<P: MontConfig<4>> Neg for Ct<Fp256<MontBackend<P, 4>>> {
+    type Output = Self;
+
+    /// Negates without the branch of `Neg` of arkworks on zero.
+    fn neg(self) -> Self {
+        let mut neg = P::MODULUS;
+        neg.sub_with_borrow(&self.0.0);
+        // `MODULUS - 0` is not reduced, so zero has to be selected for zero.
+        let is_zero = self.ct_eq(&Self(Fp256::ZERO));
+        Self::conditional_select(&Self(Fp256::new_unchecked(neg)), &self, is_zero)
+    }
+}
+
+impl<P: MontConfig<4>> Neg for &Ct<Fp256<MontBackend<P, 4>>> {
+    type Output = Ct<Fp256<MontBackend<P, 4>>>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl ConditionallySelectable for Ct<EdwardsAffine> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(EdwardsAffine::new_unchecked(
+            Ct::conditional_select(&Ct(a.0.x), &Ct(b.0.x), choice).0,
+            Ct::conditional_select(&Ct(a.0.y), &Ct(b.0.y), choice).0,
+        ))
+    }
+}
+
+impl ConstantTimeEq for Ct<EdwardsAffine> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Ct(self.0.x).ct_eq(&Ct(other.0.x)) & Ct(self.0.y).ct_eq(&Ct(other.0.y))
+    }
+}
+
+impl Neg for Ct<EdwardsAffine> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(EdwardsAffine::new_unchecked((-Ct(self.0.x)).0, self.0.y))
+    }
+}
+
+impl Neg for &Ct<EdwardsAffine> {
+    type Output = Ct<EdwardsAffine>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl ConditionallySelectable for Ct<EdwardsProjective> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let select = |a, b| Ct::conditional_select(&Ct(a), &Ct(b), choice).0;
+        Self(EdwardsProjective::new_unchecked(
+            select(a.0.x, b.0.x),
+            select(a.0.y, b.0.y),
+            select(a.0.t, b.0.t),
+            select(a.0.z, b.0.z),
+        ))
+    }
+
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for (a, b) in [
+            (&mut a.0.x, &mut b.0.x),
+            (&mut a.0.y, &mut b.0.y),
+            (&mut a.0.t, &mut b.0.t),
+            (&mut a.0.z, &mut b.0.z),
+        ] {
+            for (a, b) in a.0.0.iter_mut().zip(&mut b.0.0) {
+                u64::conditional_swap(a, b, choice);
+            }
+        }
+    }
+}
+
+impl ConstantTimeEq for Ct<EdwardsProjective> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // (x1 / z1, y1 / z1) = (x2 / z2, y2 / z2)
+        let (a, b) = (&self.0, &other.0);
+        Ct(a.x * b.z).ct_eq(&Ct(b.x * a.z)) & Ct(a.y * b.z).ct_eq(&Ct(b.y * a.z))
+    }
+}
+
+impl Neg for Ct<EdwardsProjective> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let Self(point) = self;
+        Self(EdwardsProjective::new_unchecked(
+            (-Ct(point.x)).0,
+            point.y,
+            (-Ct(point.t)).0,
+            point.z,
+        ))
+    }
+}
+
+impl Neg for &Ct<EdwardsProjective> {
+    type Output = Ct<EdwardsProjective>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::CurveGroup;
+    use ark_ff::{UniformRand, Zero};
+    use ark_std::test_rng;
+    use subtle::ConditionallyNegatable;
+
+    use super::*;
+    use crate::{Fq, Fr};
+
+    fn check_field<F: Copy + PartialEq + UniformRand + Neg<Output = F> + core::fmt::Debug>()
+    where
+        Ct<F>: ConditionallySelectable + ConstantTimeEq + Neg<Output = Ct<F>>,
+        for<'a> &'a Ct<F>: Neg<Output = Ct<F>>,
+    {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let (a, b) = (F::rand(&mut rng), F::rand(&mut rng));
+            assert_eq!(Ct::conditional_select(&Ct(a), &Ct(b), Choice::from(0)).0, a);
+            assert_eq!(Ct::conditional_select(&Ct(a), &Ct(b), Choice::from(1)).0, b);
+            assert!(bool::from(Ct(a).ct_eq(&Ct(a))));
+            assert!(!bool::from(Ct(a).ct_eq(&Ct(b))));
+            assert_eq!((-Ct(a)).0, -a);
+        }
+    }
+
+    #[test]
+    fn field_select_eq_neg() {
+        check_field::<Fq>();
+        check_field::<Fr>();
+        let mut zero = Ct(Fq::zero());
+        zero.conditional_negate(Choice::from(1));
+        assert_eq!(zero.0, Fq::zero());
+        assert_eq!((-Ct(Fr::zero())).0, Fr::zero());
+    }
+
+    #[test]
+    fn point_select_eq_neg() {
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let a = EdwardsProjective::rand(&mut rng);
+            let b = EdwardsProjective::rand(&mut rng);
+            // the same point with a different z coordinate
+            let a_normalized = EdwardsProjective::from(a.into_affine());
+            assert!(bool::from(Ct(a).ct_eq(&Ct(a_normalized))));
+            assert!(!bool::from(Ct(a).ct_eq(&Ct(b))));
+            assert_eq!(Ct::conditional_select(&Ct(a), &Ct(b), Choice::from(0)).0, a);
+            assert_eq!(Ct::conditional_select(&Ct(a), &Ct(b), Choice::from(1)).0, b);
+            let (mut x, mut y) = (Ct(a), Ct(b));
+            Ct::conditional_swap(&mut x, &mut y, Choice::from(1));
+            assert_eq!((x.0, y.0), (b, a));
+            assert_eq!((-Ct(a)).0, -a);
+
+            let (a, b) = (a.into_affine(), b.into_affine());
+            assert!(bool::from(Ct(a).ct_eq(&Ct(a))));
+            assert!(!bool::from(Ct(a).ct_eq(&Ct(b))));
+            assert_eq!(Ct::conditional_select(&Ct(a), &Ct(b), Choice::from(1)).0, b);
+            let mut neg = Ct(a);
+            neg.conditional_negate(Choice::from(1));
+            assert_eq!(neg.0, -a);
+        }
+        let zero = EdwardsProjective::zero();
+        assert_eq!((-Ct(zero)).0, zero);
+    }
+}
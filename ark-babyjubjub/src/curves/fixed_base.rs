@@ -0,0 +1,58 @@
+//! Constant-time multiplication of the generator with the precomputed [`GENERATOR_TABLE`].
+//!
+//! The scalar is recoded into 65 signed radix-16 digits `e_i` in `[-8, 8]`, such that `scalar = sum e_i * 16^i`.
+//! Then `scalar * G = sum e_i * 16^i * G`, where each term is read from the table with a constant-time lookup and added with the complete mixed addition formula.
+//! Compared to the ladder, this replaces 256 doublings and 256 additions with 65 mixed additions and 520 conditional selections.
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+use super::{EdwardsAffine, EdwardsProjective, generator_table::GENERATOR_TABLE};
+use crate::Ct;
+
+/// The number of radix-16 digits of a scalar with at most 4 limbs, including the final carry.
+const DIGITS: usize = 65;
+
+/// Computes `scalar * G`, where `scalar` are at most 4 little-endian limbs.
+///
+/// The running time and memory access pattern only depend on the number of limbs.
+pub(super) fn mul_generator(scalar: &[u64]) -> EdwardsProjective {
+    debug_assert!(scalar.len() <= 4, "scalar must have at most 4 limbs");
+    let digits = signed_digits(scalar);
+    let mut acc = EdwardsProjective::default();
+    for (digit, table) in digits.into_iter().zip(&GENERATOR_TABLE) {
+        acc += lookup(table, digit);
+    }
+    acc
+}
+
+/// Recodes the scalar into signed radix-16 digits, the first 64 in `[-8, 8)` and the last one in `[0, 1]`.
+fn signed_digits(scalar: &[u64]) -> [i8; DIGITS] {
+    let mut digits = [0i8; DIGITS];
+    for (i, digit) in digits[..DIGITS - 1].iter_mut().enumerate() {
+        let limb = scalar.get(i / 16).copied().unwrap_or(0);
+        *digit = ((limb >> (4 * (i % 16))) & 0xF) as i8;
+    }
+    let mut carry = 0;
+    for digit in &mut digits[..DIGITS - 1] {
+        *digit += carry;
+        carry = (*digit + 8) >> 4;
+        *digit -= carry << 4;
+    }
+    digits[DIGITS - 1] = carry;
+    digits
+}
+
+/// Returns `digit * table[0]`, reading all entries of the table independently of `digit`.
+fn lookup(table: &[EdwardsAffine; 8], digit: i8) -> EdwardsAffine {
+    let is_negative = digit as u8 >> 7;
+    // all 1s if the digit is negative, all 0s otherwise
+    let sign_mask = u64::from(is_negative).wrapping_neg();
+    let abs = (i64::from(digit) as u64 ^ sign_mask).wrapping_sub(sign_mask);
+
+    let mut point = Ct(EdwardsAffine::zero());
+    for (j, entry) in (1u64..).zip(table) {
+        point.conditional_assign(&Ct(*entry), abs.ct_eq(&j));
+    }
+    point.conditional_negate(Choice::from(is_negative));
+    point.0
+}
@@ -0,0 +1,3397 @@
+//! The precomputed table for [`super::fixed_base`], generated by `scripts/generator_table.py`. Do not edit.
+
+use ark_ff::MontFp;
+
+use super::EdwardsAffine;
+
+/// Entry `[i][j]` is `(j + 1) * 16^i * G`.
+pub(super) static GENERATOR_TABLE: [[EdwardsAffine; 8]; 65] = [
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553"),
+            MontFp!(
+                "16950150798460657717958625567821834550301663161624707787222815936182638968203"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10031262171927540148667355526369034398030886437092045105752248699557385197826"
+            ),
+            MontFp!("633281375905621697187330766174974863687049529291089048651929454608812697683"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2763488322167937039616325905516046217694264098671987087929565332380420898366"),
+            MontFp!(
+                "15305195750036305661220525648961313310481046260814497672243197092298550508693"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12252886604826192316928789929706397349846234911198931249025449955069330867144"
+            ),
+            MontFp!("1286140751908834028607023759717162073146610688084909004843365841635476459484"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11480966271046430430613841218147196773252373073876138147006741179837832100836"
+            ),
+            MontFp!(
+                "15148236048131954717802795400425086368006776860859772698778589175317365693546"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10483991165196995731760716870725509190315033255344071753161464961897900552628"
+            ),
+            MontFp!(
+                "16822899191463256771813724222715007505997804748105685077895991386716774358231"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20092560661213339045022877747484245238324772779820628739268223482659246842641"
+            ),
+            MontFp!(
+                "12112450042127193446189577552007703839818242727902437791835414514847797088033"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7582035475627193640797276505418002166691739036475590846121162698650004832581"),
+            MontFp!("7801528930831391612913542953849263092120765287178679640990215688947513841260"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20535751008137662458650892643857854177364093782887716696778361156345824450120"
+            ),
+            MontFp!(
+                "21459189231378695508316163458360356529222201254620325044724979975334648070151"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17637772869292411350162712206160621391799277598172371975548617963057997942415"
+            ),
+            MontFp!(
+                "19641326725043875799903403987343978153690949184340502090612493837393445612301"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6746289764529063117757275978151137209280572017166985325039920625187571527186"),
+            MontFp!("1131369846817699207467818622880768934338418324444440514270575019143804497556"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11147493790480072323687435957938716856159204975974571370488032222228450666507"
+            ),
+            MontFp!("7297783963315293144303066632651031949682903221819939895220732712782447138294"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18401778727396740491739480074385170109138464140553815062172486681759762582296"
+            ),
+            MontFp!(
+                "11869599578200887562675025170808957800782160060821871120473637591935845770694"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16928049722608597595603303603867784587481607124437215289320242908492463571317"
+            ),
+            MontFp!("7146365008264901078149709207065336778482980225871062186676418911186804319112"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6821883073042454586565393262677949517220864822948319679600708844585487034312"),
+            MontFp!(
+                "11934214317241381929160018274014956731070058296444847086121539996582012950956"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15682916869554082088927242380307540680242868250587650971620353578859275889350"
+            ),
+            MontFp!("2256150559862924509301488304706696335357793961614189900426090067095184088266"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6731772326573707943281170126807511100588982199552205014357183957581855002669"),
+            MontFp!(
+                "11973951889356123770128127962555067849151313231298804870147860675316725975748"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5302137634790117518217103058625746073394606742896349422255473727763681741566"),
+            MontFp!("5715782234278246684037434299542088499278554735952525271260387151476520542125"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("76047922482242218235696203901805248268689391477462811220576076208149921568"),
+            MontFp!(
+                "21554554442752885892208136156284930989058809034935507609725645557128474556451"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8002483549832294162773183878688895687540015241151729522994976801202180095377"),
+            MontFp!("9230261269014627056320236658183624618743880096729897065398761428492566647833"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15161844161245338657789886039214649249985515966422837170547203588403114498564"
+            ),
+            MontFp!(
+                "19432535043539692273239563949825692778270451157303563147323654296907453587222"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20378513221332058737766896875452957944060069581290035935967527169541293411263"
+            ),
+            MontFp!(
+                "15295231761471805302762748742011244906975195590696804486761504414293755223165"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10832767335855041286305922482392472131771487687241087214678233469489330644263"
+            ),
+            MontFp!(
+                "11912255227305228071364425725447266843850332681104684928985296885461426438222"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2286651464569112544294953541116746863136500001098781865856980039751923274424"),
+            MontFp!("7363230526563484206447356558931199626455619717068375767259058182110698893911"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14553701413074991219096747080711615242065815659865424017845211034410965840239"
+            ),
+            MontFp!(
+                "13591243454297365848719372676992908085762757043204242277513940025707896351954"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19678128255920944991287858448093868740484672659646108311980516690580026068110"
+            ),
+            MontFp!(
+                "21272836954776476886917169960736847641993451090581680169106828110848349153636"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21575518226803092563346513305075609374617091302857797863474564149053790283997"
+            ),
+            MontFp!(
+                "17628215722788343202614604676940371438011194683460505350569313109653855765949"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15227170406973047599586217726306581161903787950549337238461996926532754888158"
+            ),
+            MontFp!("5997587780164905627222321320982748688838012655235182822671989907135512787656"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("846502671128419220198516197713648744856318476193308722707448548536212346805"),
+            MontFp!("325201988980013180820016788364202083270096276542542811270571970238025068431"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12144255094566385858429581420389066843362652981575373027230066484295428535249"
+            ),
+            MontFp!("1501809389641836097943215650695039029868083808888681920074046698134798415220"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1546808599628180502655044899353194479398360143355491507054246488187416853432"),
+            MontFp!(
+                "15988105623820178906263780479620060645401479777440362929687404380942953279726"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2159432878320710799047301437777456558024763119294390094099170870011697868850"),
+            MontFp!(
+                "20556379768951106225497465810739987525270200668974586114514951160655326137737"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6087860318543522148066035595487069153742436291511180267123197344643978926406"),
+            MontFp!(
+                "21279850443278030856937990895443501144215625627019470193972148984983205961795"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21043986026226881658335140302855256039340865894916618016578909046624155458218"
+            ),
+            MontFp!(
+                "16526380266692672615932128594058421414504994904611156529557362443278894695449"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9696564120772228288346128040937287048208129022597493901673691320525155772702"),
+            MontFp!(
+                "15461439514078762491483834289882909166918454085843261862398780983349769603745"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7018880040597171679619301901571935469792746257472108994634149534132957272339"),
+            MontFp!(
+                "10660625195243399351376766962182950884450440804655026801012608488026815258857"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17520529679598131031201683211616840956929689916421612488253438928036737855860"
+            ),
+            MontFp!("8135917577740759720967293360049990845510111753373700439022035655463089848531"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16748096613248007938750483984314638210247070638157854438258723502178087517867"
+            ),
+            MontFp!("8102920914008548778595094960036717112917751564526761958142948751811568695673"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3228632268451253840756043641653728398188005039588380271428109983231444606"),
+            MontFp!(
+                "11498069272837906073481878958609667137580309472480394327273266265124172580670"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7292237757592026547608113176730624015785473709073583335899082566975310335664"),
+            MontFp!(
+                "20487509553425843033426515597260184923273561091772733297617672650167555370601"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19778492900002352124648515686864543223655015191596662007370083254218304690828"
+            ),
+            MontFp!("5973218203242513272835745690928765422621098470728843393050004275315589452024"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10729918080520118123464130235941844407435370400759784931720880958234364741202"
+            ),
+            MontFp!(
+                "10792289606471496202404806533469044337556676306189999502147940325575779035449"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12415307261694345999339004912358573969041401622758143495457538173217393304375"
+            ),
+            MontFp!(
+                "15287831324150767320421556916990862319737053962163362236288074532081934766398"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18076958270990943237204235062947145477890430288742142965644160361311734126105"
+            ),
+            MontFp!("8493158497032493655678474755546082841022794488122625890689784582496215159606"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15672462332097376136643671199574499914764057167301363709524412141280823251254"
+            ),
+            MontFp!(
+                "19272660916731449949091584844393829614627153486928277768017964399877482157316"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13676160151119952056444298493789222129210179245496065732924046877813465975175"
+            ),
+            MontFp!("6008206676225975883930814516512257839068355882489014510581044159940778133640"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19875578265231360073997926468854306550403299081285331140638030075896564686447"
+            ),
+            MontFp!("6038288190116634710063871730392734124973529968845291709454098204378097294315"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15787423637776991190270802505250918900539496100549450323918627170607794952612"
+            ),
+            MontFp!("6087886223782492363610045662986807800941040348610967943184024284770446769861"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14039928464820124421050888682250598606935565423652438816847805570702203084223"
+            ),
+            MontFp!("5366670138758769914717825502348980772457625673164203689850802916249728019851"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5367702110741344770985151059531430158888921701835628397334584302629397019568"),
+            MontFp!(
+                "16847903117415152552110450697220860606066640144387478958516173230368899389438"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5125477434959534117326041264189060205369413595507041963276021285544252459917"),
+            MontFp!(
+                "11088383159624404703017105768695387193421944754098075251011101747355258114069"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15514484009085462523768962318060026818784898526521711265343545690065515165309"
+            ),
+            MontFp!("4994261979773101433898571297480736160409057976036039836124169449357769618791"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16528083356641376033182978732884763907468632979574330514567561965441945362770"
+            ),
+            MontFp!(
+                "18511384754297721075158267220031030483373541751197672409039998008397850423657"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11421155496574708267028236213016960288734404587674589999403839632288082436614"
+            ),
+            MontFp!(
+                "10235937350700025463343426967849050317515389886974198389839551483431642528151"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15228072120963864990238445730842886949490251182841102837580913077969237465810"
+            ),
+            MontFp!(
+                "19306618817020695672058809374346764078651690946533825876555497240847260113606"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5978965049830640440789354952942063826079057614630617454027359737855185314618"),
+            MontFp!("9206401761097751114235485525210546951459936063104562248311920687092997715826"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("8952703977308106773381678650553001603529097062087589727874413492001962390686"),
+            MontFp!(
+                "18143455169742129651893987214073287724185746869272813449431722929632623189329"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6306130290505896753091891989978579242271761641227566561619046952356078481227"),
+            MontFp!(
+                "16572699589422214560864406300943243666295889076901168917542155100898583354254"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12183208290824453929140270236212853885194857818476966434102264466635399043299"
+            ),
+            MontFp!(
+                "11038979314417374309776917380395124598888805657067930215972209466529205700848"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12321328698847114099317943570564090682082102733985714438734692046545107815711"
+            ),
+            MontFp!(
+                "13546343462164398375610952051929842474944293246179717710044361280145845869959"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17186619144150926395877811965279192810244990279330708106778720371157484870975"
+            ),
+            MontFp!("2288032742194079614796636897595715605100122694200451726666921588094505101204"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4933831372048675708443113871881274576557182563861509468483475290891098418554"),
+            MontFp!(
+                "11755901681316752453425014919967318819656296766938860933617992268862226730279"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10087085622902725399001167193048265219464910580337589680622285824462911536290"
+            ),
+            MontFp!(
+                "14805789139369153616610162320610229226021300727067806171263357219791990830476"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21372871726206208816813640205630131938345673671424128789183282815096876690209"
+            ),
+            MontFp!(
+                "21491782824646388571423914728088871457068984734024971785358900251994802117594"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13924641157502813998759594372437865478825281838225516826720234568502723756325"
+            ),
+            MontFp!("5909344824063165275624417723557917583356454568781175462442470854960225156713"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9082895948244164437177740500998344122940721136076790948386255475278429860274"),
+            MontFp!(
+                "10795372130698610547498215613147500539086793995885575249891830253826260960062"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19534299496367548411400850168375918120631631765902564806593594930602635280195"
+            ),
+            MontFp!(
+                "18554550995455076602191625615423138460743100275937538782611414734197621491634"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21241877958269118349252055794288158267610665751705889513426830643707416732645"
+            ),
+            MontFp!(
+                "21021731325497391466040199910361597817345134231460120235109164205941141980485"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14076599636678249779941323661026783500037111185731563580454218582731484742001"
+            ),
+            MontFp!(
+                "19598510592553198739486271430296001007866615380103304132718962913672187651986"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17657031845553121621476650810673208059041199855597517337901996921295082110075"
+            ),
+            MontFp!("6593908797914917037566037580808941209585065499529804918909891815693988276121"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2409003739064811416878908639976329118778855685916631068805586572478439460044"),
+            MontFp!("3695204150302775207045875511435099813273237514077610381156727627551431672392"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17703211952018463495478103243995313872283905723193157491862059465185150356618"
+            ),
+            MontFp!(
+                "12440158894065727250161310112310671628120044389286965510380176422904246259713"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12922924855865174317381123733250527858852803435567169755546187555369719237384"
+            ),
+            MontFp!("2821944769690741670493738861774183114658792674677397036446844822979287887659"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18717920469648820640837013782794498811353995889026787605055908747714342618723"
+            ),
+            MontFp!(
+                "13153931782879480368414579229159228321046214897536665963976194025412022261741"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2738733552729566645703831568508155803181274457213462026568637214173452297735"),
+            MontFp!("6004070001690030087183126820890789472994673042123336583230336447653237052345"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14528951733968492300398491662818898839554913984523948162382447125866362530296"
+            ),
+            MontFp!("1738840812475072794000822431332215917621418929704838017048961606738342672438"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9482079204226196269893769261270555408503341844719932683810990654047593166169"),
+            MontFp!(
+                "18345270162184064810371707281369793947884772580951576808579218734507544833775"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21797754111653064857706182709051183016324638488591648240647181116613351063267"
+            ),
+            MontFp!(
+                "14398632676836202643794196369707159438963872799669094473531243596134857547296"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13487733660409410098069768957300360725350836215518231845804737994039247383812"
+            ),
+            MontFp!(
+                "14669480494842404602617803811489756454103154901327957526828948215321865548275"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1708853014448844538186975599297749740193307916482363170037096276507092102269"),
+            MontFp!("9530181497955858702903690505295999848334745497916449752314308467197450787721"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("1922364862937225794969445407984242679306843931604824366960335767162411229421"),
+            MontFp!("409835779568587692372384972864995236820235824553758025048710389691129427522"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14087347025655337214397516518329401665683242420776888305953519031495443874950"
+            ),
+            MontFp!("7076305323990798768328492548061267595465729634601171839117131384640080848261"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("689384931217644905061977179863348959228637216419954769089926764788735789459"),
+            MontFp!(
+                "17895654631916851694012708361036884766231768054265842843658665224006811102105"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10822133453613922090137607341023416144218084185102711320326281596400714670080"
+            ),
+            MontFp!(
+                "19627281887395329458268532326868623967998777237531685838663356892159375980176"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19886475717744818049145969806974756955910639974508451131140704863983458293987"
+            ),
+            MontFp!("6921907600400693603599748421081737622231124727294842754735525218374379975051"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8683537248517003573994547304280908579809419669823392715214178105032639046713"),
+            MontFp!("2058777699618688926995290947969010309165306920330686232584646700621792491154"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10499134096718760153135237168454184944304094228352708885451043040278441900743"
+            ),
+            MontFp!(
+                "16672469434367938668025483981863460193178761925038920850964442727409416361059"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11270963838532999811935840744838671853320049455745427367701687554315138537187"
+            ),
+            MontFp!(
+                "19785628338688052587640851408202162764260996046206703619533586561004219718646"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("3473090910751943848656785830477967319713879188422943521007200014391124099674"),
+            MontFp!(
+                "19748851962936141001762450238066305840051403297809288235324786098010696196335"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3796243100649458168593938002004629653638015182608255510767971621758039476003"),
+            MontFp!("721483557009857689195670829632428104749290349705340260702465542429346022065"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18984569499923235743111640568745373695874620581563562456482101731557847197920"
+            ),
+            MontFp!(
+                "19788036431897392086584280550387848643925441983963555142757671073794398752914"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2940696173170780569015730405625986400812775718642239317321675850383387151066"),
+            MontFp!("4276669164966298015460630779817850008391783859380551899856133012983276320581"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8772626228857220280383011997719501158613672374502714575923292526160035427488"),
+            MontFp!(
+                "21131320609969664245690700692636605716129395937520320543978918736883138588312"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9761492101027176662455579043083072249512473420663358069907884275280988407677"),
+            MontFp!("8030238347243268958062045064521729917056099508019604991016410170147759868072"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4879629698308611026314296309309033904943885256165441318623823722523517380233"),
+            MontFp!("2196816965117208198568329614644797504710670586623654365939288427043879526075"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18079416035230656149234811836675734376721622358549707577590401938674282129515"
+            ),
+            MontFp!("5621698601987675307528948441029834296456071929167250346404203380762199874730"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("5361975302943842507890733473483048702455265142042473246996571995031725969534"),
+            MontFp!("3072034374648054167867677601745685009024481963789231479548604425310850807476"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19828349637491409533078528744837286005233439866911416416776376030430690920846"
+            ),
+            MontFp!("2223847491325449053677045711077391131201022363360509134624802612589594713174"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13659772186984313984211629342199359839029584495212931077261134143262098035332"
+            ),
+            MontFp!(
+                "14722302180986129360534785566347371276195771469632452699451466264670151887391"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21650284802870369919329306047127051552669484619629852626879947268001414229489"
+            ),
+            MontFp!(
+                "12374419870942973950770696188631682233229274234441526714583687433783837986776"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15050545925593675676098862540780027098280288757658949663380862219554863181864"
+            ),
+            MontFp!(
+                "19958034854645657083104866024341761788056445379774745679972295825205740778425"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11384545759718700134711342472421007634007101139704974904858061156703298629360"
+            ),
+            MontFp!(
+                "18447831450420958927684398618142841768380730755940357812740496784993327174162"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16298050695612692283472121944201960242202868026421351402632192790601462816254"
+            ),
+            MontFp!("8812128247608763144221676197538727454646639948363439133340510416397241815979"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15548204179875058574785846547136497336391975222457372985529118535335009405775"
+            ),
+            MontFp!("5286213742464630792964598111882671724754589597045118964463037988498821432935"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("3937166883539410805984358929911445965511059648279993507610886748617586866922"),
+            MontFp!(
+                "10774401781619132225795525259892382834015493095206333719115394143099940705078"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21432929979471144880824373055406992287795092964587397042064064313060939335886"
+            ),
+            MontFp!(
+                "17239560032311799702434686174934452460522713331259656498490626097439581257567"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8928703014936132924855812239224306063583007783519054747578832375155331927653"),
+            MontFp!(
+                "19537548826493128183673021587577694095580019795430256127206744497896713693453"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18898877488177594589233600795647071250832384451244918032683109403564097065822"
+            ),
+            MontFp!("9859690846220527600742557038971078577433843648851444471497893512709317387914"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19647330069337959727209490568249548279701642433663621196155526503667657415440"
+            ),
+            MontFp!(
+                "18700927888650109632290343341971088862612316041098802018707372282265300049097"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9986660306624784275418833635207859937413515762596004612464758972112780210325"),
+            MontFp!("2858159749953484040511453938304642541719662519328425576664082834986297896067"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12776305589212463957036624378670593241067360041305396894462969505106890434271"
+            ),
+            MontFp!("1239133148272939235154157950470551016505988562758563171937693397933032904131"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17731705778762039472194850134683876463850911340948207928875774108379430081175"
+            ),
+            MontFp!(
+                "20919001986710312200752977023007723284926933878463761552563184673764305533652"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("4609261227866689087962851295588219157557857533993019743895049037049910007977"),
+            MontFp!("7514247399483479189240117641653087213731258166286687271303305535550415730759"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4068107002487462694861859226271994267531542037311772548883006823849504142455"),
+            MontFp!(
+                "12676363936920405171468980707390133933113040232973361858255850950637685449044"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21538989465394934847218529714246273683452167831072778853165203366198547270888"
+            ),
+            MontFp!(
+                "11117530466158002176279375940369363967570164028914903035934503380679473951566"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("811472830850042912621832713681755117678581787895283948175448625430751409804"),
+            MontFp!(
+                "12437397465583854687898983440893101182288209004413845856798172551731662233937"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6423477282223871288053516931225140048168658678115111588949698373926920067876"),
+            MontFp!(
+                "17333452601850449035324485115607848696417252386675124806918372138018327446443"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20729178635101547006791010593877801244174882347183113047476036376569375516599"
+            ),
+            MontFp!("4456602107380495231024927765192609443123696398870466950254874217552714623594"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20713110140295504311085308077129573223798730434916984323863290368957225690577"
+            ),
+            MontFp!(
+                "21682891120228152807600509382016794625558158304437964915437971073980968343266"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14419429594139418501450190324386942708310197242714114537379147928133305897864"
+            ),
+            MontFp!(
+                "15922632026015550649989885424808088074017415072019398593845046361871355856843"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21713655108261872435735292030345367049597093276417149681210314235496836931748"
+            ),
+            MontFp!(
+                "20824721178437066362128325986987397655134946191653357925678441839443869392572"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20771353604557873779501255242355227254012825844381925304809892363178097700008"
+            ),
+            MontFp!(
+                "15170617038999412748322345810632224309513984179469039711951679685292961533603"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1496223669423037636641576761719272827344319936591512464304772540973079416122"),
+            MontFp!(
+                "18242505775762046228575549216130016042473185223598823908463887391416609366664"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4334857091115639445485335710443678878289937963653283157467964170140825820870"),
+            MontFp!(
+                "10080233156507572987158455777239099070977307092883390788835918640919580867592"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13781950790340525511053177860957609696364936276877400216796401207511546611909"
+            ),
+            MontFp!("28005518931734706998675852872788272949886092537674810844956062105523613412"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3446283988172459599527361060238779588950478329024508110420817041289534353696"),
+            MontFp!(
+                "11222924327801986641046850953628688984671893277625713872509271671189390489869"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3486923407930300210730186521129015509398739580354330378488427173180644703907"),
+            MontFp!(
+                "11283660898251306413055640027688730062316823495036410278167429908720131533624"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16737714893491379770770528721035800780787896464870103912725995767541496719790"
+            ),
+            MontFp!(
+                "20662632037018641342455029072009121025968422104850797621629617897983058092664"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16764638822458977327186786061593466055111162619481137081092838603273621419560"
+            ),
+            MontFp!("1127439671418127976375250254135856815185956293144029847351582396768017092966"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17950724888091640066291436888136748245101529136707239993193391784577224508466"
+            ),
+            MontFp!(
+                "18939340910837581048041367064723623541534665012693298804609091313872430178576"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3293358929191817467061914576155499768001211979536133592158377577000978251631"),
+            MontFp!("4794307363660497653799784044970126208423128215032450176358860525053306595435"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11184646480383582139436548388263671118611202483392084439588648551700082070872"
+            ),
+            MontFp!("2033040850803863807287368547134342622311714994116507359015165105503785154532"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16646055380088043492448024726949563055815488347120573153744132041279631938200"
+            ),
+            MontFp!("2177718059813391911154033004637057066239737649262046828236577336123378891230"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8694051253319109001450909679138265676710641974230174404674587913292391969090"),
+            MontFp!(
+                "15063587457563196596143756200253471394996052224139138185507235690268383819054"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11738058920701007871439980309186408188396677666869485612181784559695960061976"
+            ),
+            MontFp!("8754925776122226698486151284320795744859568809028211439232753019103497437590"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4435482281760813086641367496471405515443177494589624068416740780841028281557"),
+            MontFp!(
+                "15364265370405345910291588767091049001307084379828663042293477981804030169856"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13823089312715856636368233978473392308455005342781861010989415019506022880413"
+            ),
+            MontFp!(
+                "13556419251966618824387848274902472393793206125390204553981523313321176666432"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14117524690770708908293992888155424022716450618431518766568521014941281821906"
+            ),
+            MontFp!(
+                "16049232270206131544604934434879990409150077230341900858065369729730721555179"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13806994492414431026231881951743911136197291199010319879013809896975733859627"
+            ),
+            MontFp!("2455786912917124056963432612618603106032245472695446690296199584376928222046"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21059560753493150717008188003258603191088039261995657815462129422261671494707"
+            ),
+            MontFp!("3259813792158051106878181215875299718310778924790333142545658339197221964491"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6180852778572198932903598258033705024713728452449404598482669683624013585937"),
+            MontFp!(
+                "12350537226357405033140284546569521054071529648595647159841973848139081610583"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1597490657307610832727784816343684716720898534765102138520111730967551679558"),
+            MontFp!("3074947129655515102942324980850954581305840390426230607312103521212902947986"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2832305888969682553413873013597140280645933816059074890416519613533181802113"),
+            MontFp!("147762822525456210110965565545810971649409229511006857149223965432246616969"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7487337601196003827584993339941885302791204854776598117785366324781704904098"),
+            MontFp!("4426533023125050717852591839726920578442966030904845872980706571095262764250"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10978044511409153323023184041639249894841402787624793335367487536643360060938"
+            ),
+            MontFp!(
+                "20351318861589637186942586059529728107218658444615645969039342849826923148452"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11063707641101149224675072520722162589112429712972507758345515207461036598771"
+            ),
+            MontFp!("7357811154888451005377103688773654614369808980502125269290896984328803163591"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18952617242177672101976957700824260655068995429312256197613280217296583299376"
+            ),
+            MontFp!(
+                "17237818228099236243640010868989850744858039545664192711270047236152801396885"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2892489805202699071974292372784232288056150226233792180172679971704267876172"),
+            MontFp!("200676681044717313432007287756732334642723954870529570230069458831802816735"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8776934412460938177903884652370460607842682991568983874464262833008532171294"),
+            MontFp!(
+                "13433943486933745297022207866134034772825283372097000269708354676229555343018"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10128147241761793604370477367275391826687958940676349141886377680678633789606"
+            ),
+            MontFp!("6031550205507595995677614566307777549088438019901805017070478190645134145515"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17458030735762834476105048858224100712253567669773921619693551479614076641503"
+            ),
+            MontFp!(
+                "21857953235590380602668110101958241749515298883377935583933271702169076594808"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14105730577164136932280581373839500479556933870985221532237161950873034878802"
+            ),
+            MontFp!(
+                "21595339641999389342338711033899453440051659244909171715926810788403726304545"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("7077645323475878146475089717721772407825969332619197499264777822110146740483"),
+            MontFp!(
+                "11813566083933244923112115177594896722288317477699481901919074486734708857094"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2625793561905345955170555461658686065168924761954376495574300907672989926978"),
+            MontFp!(
+                "20160525129992937460790739769094427160693659433418863294402429521780087311964"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17581714027196705314083862733464818955606878956389523161350516596566416595663"
+            ),
+            MontFp!("8789681168540477151878883517961094755966832440338596414774518843193822373256"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1324699898075260892982017871834065932537817746047313517795646938651695889927"),
+            MontFp!("5136051877680009513339517203699033327687543182071147089771384513516651908643"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12567027570246225386147118538376314049126225968247067764084738134570863449160"
+            ),
+            MontFp!(
+                "20614259906251420170099000123801295907470573366074206512021370072668765271372"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6540830066241386651225561708909433350480340395857449049360481296946532528292"),
+            MontFp!(
+                "16262253645226986227065119121543335663727533911278890056319545686842548868071"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2838984964334101323678354587234472333192770862957848566505263148482934866424"),
+            MontFp!(
+                "17671512077717945477908081085682656936952876823106283030802986302800248360386"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7432369993766101529483947359347930383449521632167074584259543913611335312570"),
+            MontFp!(
+                "13918710928086603443321982398831411430378961675017697602784318388381536620127"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16872713021045149922689773382406605144070175075610102140607545346840285239395"
+            ),
+            MontFp!(
+                "18934104016907768217364122461565578892886649652469998782399324507240509443773"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("123943172127229503284334346813305548437786836075195410708834670753623062003"),
+            MontFp!(
+                "20949382362122952001049892474629750967238740957736091372783886524341941419737"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10818115081124052128583718543280201365008405159539313514130752063460051491432"
+            ),
+            MontFp!("6245763012460288554389503129338950893168591772146183872575126404579918389781"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7207972695621011632198462864324044032049175442698880856046000897015853114324"),
+            MontFp!(
+                "10317884576412086363116763942069250944363778335099060903519484031541544603897"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20270121099154748873236712462340316599952503656665532099527363385165244847461"
+            ),
+            MontFp!(
+                "11427074395857641833157300720962250903642420501487555361624300610423904657318"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10383277406172499337138978774806750577885763345852443873450949081446251566647"
+            ),
+            MontFp!(
+                "18252276651394992519529451233673347824542692683235559447547212086623049419496"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18671884341748890055305219907604015490982420055397350204019643608617388706757"
+            ),
+            MontFp!("3217478866725846493100924263799409925530747497460925753163496023621832790539"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8098047196736241169921424296416628953778369760273649068011892617224322938439"),
+            MontFp!(
+                "17789687620172293006440499687663432304804624978775647939427355567148534383244"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("7353977873245457279924918292804957182290077454206159933064409691059280039381"),
+            MontFp!("8719323696245555724374447931988492404903947256740503895244820022785166349127"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19554964279138075796105785929523938995843897730897320770293920090151857983658"
+            ),
+            MontFp!("6251770748439952898738802976138737054043882319334197003278905219441937135288"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11424116342099665790843153513405806346851883364810469807243205712195330571813"
+            ),
+            MontFp!(
+                "11238311708741600846769213199534759990208320996539849000313513231564093531193"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18895463501259048465819007295793535855169782503875170646374058189759943464310"
+            ),
+            MontFp!(
+                "13493128635301319774654236837345166574198862188207005487838575756541746497529"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6453900111354856052943559747093428607697638517963342698452956670648394101468"),
+            MontFp!(
+                "17433573101220998424433571977703891776531560291092978316527844087018882678012"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11581813603605538809346330634299808967471469529905125520416363696639646630258"
+            ),
+            MontFp!(
+                "16653003956778078079824154482427526047179521811200039256369895567596155835517"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5310291491004346607486739443693468854915668306704754076521783268017750075084"),
+            MontFp!("1266714679205571875253073826072090747866900558701629455369068867449216217153"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10855733428794067401980525676431222062808567249661103749529430834101148434815"
+            ),
+            MontFp!("3746255701087119630482826152238913995108201665062518383875526569869691887447"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13650053902620393455982956085548748811751067335501585767816283159461918406685"
+            ),
+            MontFp!(
+                "13068443780877595894810144716209356417402056054742667198082244360275910029034"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13661572510741586310907022796023433072411357803013451206039430910914809136413"
+            ),
+            MontFp!("2582596668791051992017855485971623717841143839122353192903561552226725113665"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8664502057750570548339600623739510926566567854046426368562174187751113471657"),
+            MontFp!(
+                "21490211507507114362035991948835599012143547773901911703001823934178136967738"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1954885857793627167937677119268961022744822536988328924321925715698824260308"),
+            MontFp!("4662762652723095553610230111989106157395916908129108919752364292847846762528"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10162019662120369547441738423088071098527329167252030108886334943239980035550"
+            ),
+            MontFp!(
+                "18649563177083502495478314699299216046485813146134634445522962044133198814874"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2424315757694206922054724378419870403856406654137497350396215742934581711412"),
+            MontFp!(
+                "12459820782773282995161750809330253052038274835226144282989487028124126529892"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17425783828396749976418105601059528556858610191890519732983764921886070494969"
+            ),
+            MontFp!(
+                "16096420209186699572293702132539146395570145404756143421664753941851086084860"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18896203598951479933203019961388716651820649349948420809067748176847887376210"
+            ),
+            MontFp!("2164777567651442042580647918081179182258865322213803630836436476654601294415"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("7870009699444358771108305132375241429966527590237371779525727788857246611518"),
+            MontFp!(
+                "12640641634624758288784406658768679285196416306396376543924089082575700270554"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15022681818230760268821641996000205164192050456463713391197545543230715140419"
+            ),
+            MontFp!(
+                "10110093281737319919005734425701001509948313250906747566467111738260319466540"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5177700731980536742759499533528958029523345252531357799821404126916087368580"),
+            MontFp!(
+                "11703920640385379268835219584222563782946428024979778452387105857400023794651"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2434610545413250866755623546869315550220439394321150225873151345139851412204"),
+            MontFp!(
+                "17894345946530961031969628205499056757687353595230071721495111881830618517234"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8013192679866671934935966092151231576044733552896195180742628723322414609636"),
+            MontFp!(
+                "21777662275471419340053918945482169852697118549324223598065199988557949974092"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4952194712650592945591441281871037326034957295304287706683800220078663692702"),
+            MontFp!("6444364804483585316401423733747234052474800422932941399642995227233162789020"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19821951868026578885187703054244949550089361297238469922135356487681223539821"
+            ),
+            MontFp!("801172717688466585463709158445179926663993901303621595842133072703167373935"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13275368739947230139472146836792278137229926246989758993229077266618407966889"
+            ),
+            MontFp!("7320809664650883561755818828927856748391309889060586934516230757891031929897"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18896593303542233803388997006800684009311432157842440053726874230584965556284"
+            ),
+            MontFp!(
+                "12430144338611588192426217343884856927487992389290497067376762023895430115054"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4877718897113394998575159692057590423007722204871729089145029187795913504671"),
+            MontFp!(
+                "17853319844400775048308541733820208970752969704231149686183433628696793512366"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5662725131939085620567647348905907734531361203473966827495069142199592682497"),
+            MontFp!(
+                "10375754426985061089245370268470721673132611145727124852158884889031540257739"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9133493153533384087038530792050972618529729525189293763094351134176474979230"),
+            MontFp!(
+                "16957397977912996589008240850174389008399428994313515759591133988364245306650"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4506411543676424206650274992365003606356419932198747475715791581551475134417"),
+            MontFp!("3968109176864011059731742277974575514683584411778653150337433647957209781891"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11448137194609175011850650942412025042671374549001576740736679421605982619701"
+            ),
+            MontFp!("7159778397686538591312665298815287144407348485117683514501811827548891273107"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16942892979022548908330708939569017105930968308455133202139346617611780115514"
+            ),
+            MontFp!(
+                "19701597743342857679079130446864196275801042342514846801252525259943397849064"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3341153178335358273186752335394973744065402005866610231122985455279865973961"),
+            MontFp!("878828442674810412649894343637911633445401449471609554799519417932737264501"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10114273146143037434377844808908403150345064568708198747598648519545104472952"
+            ),
+            MontFp!("2717288445871543707022361301538656504540252330484178842375450354081622661674"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14089911555064213535811443860704226489376702222908041728222278233126385889305"
+            ),
+            MontFp!("9621521832294482653759268873019994947394360162920287289606397379819805421432"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14882176796245556909135190999921897587149064502546357102883586164926853845861"
+            ),
+            MontFp!(
+                "21421922836738699642601726506777109416054914591523109694601412374280451679859"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6597376575009372370958545176693127656118295468965135238229226564094479202104"),
+            MontFp!("1972640743496423324152534230823119479898062954005589386326654529257581024730"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4043669498114375305212966144448189940668815309546551678908557542207281485597"),
+            MontFp!("9416722019359285333788078288639099535645982760623978161568180618317716773776"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13386189060615687312621012857686677915288705142300684285732373877405755145986"
+            ),
+            MontFp!(
+                "13343623855212762116015933059661665085768754551964454741793464855824597479867"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2012256304304127791156400660774049062738806644914705613776899876190756583149"),
+            MontFp!("365112390170267448539904717251286449753211314003052463594256449292427503100"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3892075572019233470199352937008600231030917758641538246877856343833253348131"),
+            MontFp!("4744021023117654634660848738313713656617315885995495065779697914388202871183"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("9899273547872852652627194856058410251144393368386959709915130163219970148813"),
+            MontFp!("8715892660470224483781927835758840942876905247882290847598732950323377003244"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("693398094317607794573158187381243975731462354669984543176625306431102210795"),
+            MontFp!(
+                "17091242607597684999438718329856021630187743559439342765100621168825616845377"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16120692250642914668171883758619414123839016493282219549743255997987420025431"
+            ),
+            MontFp!("4857063030705334395641799317674698548831191610687530941367778952275552705224"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8321442760794040270440544081024975525162750452498773078898679038263474684405"),
+            MontFp!("9845101263869041587009071174670024574032903959023303552754115914118146000543"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13620746289201909451738397814119359992791034535779580795271656861483887467837"
+            ),
+            MontFp!(
+                "10138370764683962457303662464197883831294764619819911954743386777271615614975"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11876454497365897984049595660935487608687036746922838321096812131036210689481"
+            ),
+            MontFp!(
+                "12775871161953724877453714090562362251885511561147547920590234244926004919564"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16167705977981999868465671247642249432793349658437061576397294889181584153522"
+            ),
+            MontFp!(
+                "20190677209716938560246588369518771754877261608376279526479533365849669026677"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18211269500535566839260330636384815815457572337264953861160944460712354297892"
+            ),
+            MontFp!("8718234741368560253781777821943440366916054326144261405765881101055754355635"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("8644783915018389684232973393109429391901928103227511277296994674513569434440"),
+            MontFp!("3158872409265269225120083888468895950198834919076332399612341110976067120073"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18639886500889007843570859641609467255489393398360918369481720930526352975641"
+            ),
+            MontFp!("7658668909936882966347999545907226135474077924674407727338341279731029165491"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2605754798133543557218518511931259103714003738916893617399825093122190784316"),
+            MontFp!(
+                "18258424347869115394375469840082529314706656670594789734179416256602422202809"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5402438956776233350721920072595265363348998727991038660312774610642837856577"),
+            MontFp!("5093841731716781137305459863382693581854425228765375145742728625226638064885"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12483166888596923026489590079651049038442712870008579754024518054052476938638"
+            ),
+            MontFp!(
+                "16253857394877222088711979363901321655410394447483697823928898711820797020592"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2660190220854310279671972679463004443628624836151908722131530915668080940049"),
+            MontFp!(
+                "15039302366391547777074038633392606657265206065721820694387403569941340645391"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6941545842698653842458954969793889741519025571508655584854800537252556129795"),
+            MontFp!(
+                "11152296682827942921344390199565318782225304418097832384844273694396013935072"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1956267260590775957011416071156231399763609491134631680120961421384721301235"),
+            MontFp!("8242652765745411061480526050247364277115626340883347866902328157852536065653"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6248049042342730801676136699668454605641266703358933411499447504196065248407"),
+            MontFp!("2486286498781682111028455111415647393816499164258727627660433326322569007318"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3948863526164669667340726123804843698560999389999066895480938573621464201914"),
+            MontFp!(
+                "15095491952214361001881989217192953218661088542170965250107322596422360489893"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15573100947717051827778977205532290633172225202518414097538188320214553111974"
+            ),
+            MontFp!("3033363735740264113566264902398048571239765734221542757571821043116700166762"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10436817334566592836673163157066174354962487206309502058534731058620964484130"
+            ),
+            MontFp!(
+                "20994897281268879481428472342514450138690828846097570476603069336445345044849"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1925646597224127690593688217080171816251655878870838629148044338673747628217"),
+            MontFp!(
+                "21179522698101680758118503043100794894762894759691147990937158433722840279225"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8794105016454847673231119180545157629673509864537855181649254213824527307106"),
+            MontFp!(
+                "11210182604313353754691299670097764441917917014815050480559969751255608220085"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21655962647372637269224489420966282627229270220558425759562885743405745675718"
+            ),
+            MontFp!(
+                "19020145598900027521034852554221221459197293361029491585040315362604086828712"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15221319512869015143490957560563809953976052410780834733128520716045002475223"
+            ),
+            MontFp!(
+                "10685953263944041271118175720242634593098062369974675902529309280683001851217"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("8914943600868916235734584419283274885233001010467008776672072325478010776834"),
+            MontFp!(
+                "18748451494166649099940462985282095514337866594711534684943292259615103169085"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13478115137849985395357419013836490626190478321980953096762985752781862905543"
+            ),
+            MontFp!(
+                "16799508042241215679805627065419682473639219492743642380938192312059714231889"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3134185834368571338545222402754588036030706277841340925622458436988756204257"),
+            MontFp!("345889167308209568114604384002226181441109232198945857683157672142551827290"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18653468903112364246248804569792905529015048116088001117375113560704316589202"
+            ),
+            MontFp!("2598138591058086752394434999092593820266319980335660558941591681492107923450"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8651110757312961128632917977569997485869955221315013630620218875122403318729"),
+            MontFp!(
+                "20866002023108976919622913570118288091122665530793792221034527994939402830219"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7603724309149879582547947074145921993251896330334262120989584810138869483170"),
+            MontFp!(
+                "13727568449414728819564781638368423048800133305104755104201504170744748465714"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14400937615773577678698269993420353510026735309400903469317388661877052836237"
+            ),
+            MontFp!(
+                "14837134271588380695175832073009270035495411957715877064195090953418139690952"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1548903026454565617896025422820722746735544316130885739435922136446487300083"),
+            MontFp!(
+                "11002431391549121127962725950923766962146462793834868517214514631348494356424"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13302863704348626255247142609109887658715909579507359595131714715788432128925"
+            ),
+            MontFp!("270745718185399994435774541620855974412130442984612400610644356876336336767"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16541514113907688828140301979629760319046918185849813937156338455169633309794"
+            ),
+            MontFp!("3639104765332013442756606403533373340963044794564321453302297201592945434329"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4246462263305471663455836637239862475908950755323973081559267502872020151242"),
+            MontFp!("1300018053744923813547847897012788167392695538655886724788339369534808568738"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("407005325116997490248728731209238689343136027748351456769031199849733852351"),
+            MontFp!("7607282185378030818196920645706928161192945673027909957973362386040860359835"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4482030111753530728534620996204022709090025986683900492906685025400132210784"),
+            MontFp!(
+                "21435832420444458107179549624652437713171892616498827607513911602450266216157"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2279554042456749648307644079744309029635575449086905998879946048416840046688"),
+            MontFp!("6265765915887304120757024011571444796367144719318153466859292822788815900755"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7021519588794166557178511907392636101997636556222217758983534423924553034699"),
+            MontFp!("6007432568534667175395156774594570486188626401419132638567166883695416002144"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9810854338087866015221785336780049553256684886405309078589594045227590582006"),
+            MontFp!("8721184945650803679250140922703270216778788376989464225195744177154686648239"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6372314886335030891374293937379966736250953160843828586320915405375668666129"),
+            MontFp!("5682178371955920180522357076201071873291730561905048271712601474983751498058"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14883537080013620145881499563412702256024875474786297851221793436827496306138"
+            ),
+            MontFp!(
+                "11752099628469221112885754887337391630347332173330370661455782299789758988998"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2198392595803183805872192085216341143301140454708055733190229737407384705455"),
+            MontFp!(
+                "11588975949988592132817417327937424503584214613208102713596435985113318182887"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("676583362441941884904849513306435237997697751417913180854758603789978062322"),
+            MontFp!(
+                "16598826128984567586970516975504581535000905301776882198018384134335919147983"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12694513211328332334574361044212934468747984805132388754959707315480834255838"
+            ),
+            MontFp!(
+                "17592697517917514100992456268982198521095330007660461535226315161981917367470"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12629125572234572148689053560320220550705733136686455001629284828195065211910"
+            ),
+            MontFp!(
+                "18888314159152403666380080255137587020089962721868498994267485466850424078518"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16454195062736075599598775807769265123055410244298216427093685521174005560394"
+            ),
+            MontFp!("4970605802100651984578081762155193264932094966112228866860909477544266601231"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10711996111964199685347916601784101748408275090782195430640140983222150540126"
+            ),
+            MontFp!("2201018300873950736007718658887855005855709262514182592310236706351593324716"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10288974518820505514752818269060448351810381487680098163350900759224851730336"
+            ),
+            MontFp!(
+                "21651882603086694405693098948972420980963565681985490126120123423300778618929"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12862814070426821830032772031905477772215236093271638886792320133097091662321"
+            ),
+            MontFp!(
+                "20728801537065575200220871767503957852787064423241271869179117069272601425640"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5887655737798923918329207931267118376906310400487688397746642534397037341793"),
+            MontFp!("3803820712803024518039097475215151807873434338482078376377762979404242731659"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15831644631497195825058711412699108719319718559236689644581297972391242428266"
+            ),
+            MontFp!("5170623923927370028826767949757661806866135602670102939648682646045496912973"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7265279834254600826468554316301367129434018100574724847168786215125314268654"),
+            MontFp!(
+                "19052732855699404454273957359568917411012221430639117695712741755732190650279"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8447005066226616586323932992683343465330477967830473538648898220132462389567"),
+            MontFp!(
+                "20510986990400243596817318910805012746925969191078080453708952347793202417485"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9306779833503421723076451712285595406563005569011063221658670738074776552339"),
+            MontFp!("5209574987776631607126491108795116233949530062275752138515618475380760852517"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17490674886141821114261243862753629736354068589817024523387615619146306866337"
+            ),
+            MontFp!(
+                "18790551310986193494571556057535103703011767374084323655555787331216583764851"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("5079048234336978213340200158027050528148508727698587510154888615437066045598"),
+            MontFp!("5016496752852685159650377201461293376288381686104673134839516571522950281639"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2756923660815785903412162480713311210269368715355769335884387001364078869542"),
+            MontFp!(
+                "19934811516391708581189788870384182316674070783324245702447333550491885438213"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19564696397257980327819853656077917776484609205391536849971803540259565829380"
+            ),
+            MontFp!(
+                "20082588378366395583410421045483017505515049999473321701248839313344044079287"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2948054090009025942814798478371812968352166179039554378681603068666228343900"),
+            MontFp!("4715500910436417957826294295760244051262970467193047980696057554880414729165"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18759719181929442060699879648998214048295237687942818327174147398144358239180"
+            ),
+            MontFp!("8884661874762685399294321602542162764506274090441191242113346263184042791647"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8289640383308156064691596239173877552907288705688835464276563459394322556324"),
+            MontFp!("1903320242505780297083595705577155801736204209371167496427436668785211503274"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("787895931989222952114798534570419728489142520841332727654339140877653718536"),
+            MontFp!(
+                "17320911649355733658171055608554512446762466383370371127106441076642493027604"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18040991090214835348778866218236111966812716690606841483840150457121160705500"
+            ),
+            MontFp!("864901652872155634660415399948337546550876975798113377554498829871696472356"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18905071495524654478069556580163675726776769232298510201587615914505921119104"
+            ),
+            MontFp!("5663018614146207446343697212999621605425217453319805520829939328865770589363"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4069187415913990179266103957934902108967308077870994085512049147450982557143"),
+            MontFp!("9812487442015774842444261704807794016482403939299389256977608073253672117005"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1708580757093356856006220778454343138412685125890518379320864435673497134914"),
+            MontFp!(
+                "13799293161684926306831373193745465065978713858594777579310503076424635344348"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13292251513784204679728311976842681245471289697498404503828184959998848964673"
+            ),
+            MontFp!("5657472042636692585542089509213115110994625145613051048946009150864991939898"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10892158868420801240289432338593575526343115503756457289447793429449339477032"
+            ),
+            MontFp!(
+                "19043183677100508524975262402517770599350157571621504215737944149080668230786"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19890125104424585891145616880033386409810289919066296689151755677993283697083"
+            ),
+            MontFp!(
+                "13254157256501361087596239015860925222908367917142340063817826644982786865137"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("73955376722563187331368403233226417628554353486040731119381191535610510195"),
+            MontFp!(
+                "12098396046810486243453213417696800291784986956990622606478819248539248208964"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16096461564138763786316399872718957716747208206622416245320611294326236805361"
+            ),
+            MontFp!("7095223579965327997708259606056868709666207120734925502485879996781159766572"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19948230843829418355246103443595227435367503124820551044840689287625724875562"
+            ),
+            MontFp!("9247276207807186098590522850993885755610415270774481392426179992583777981763"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18078587995639042845266278543819104576657603737679003135929933311007147677573"
+            ),
+            MontFp!("433783653657962300712169010630526283936413887729531785156686850494049833820"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5512069137045078619190538879613598796451986169789929294309997275970135445229"),
+            MontFp!("7546912448927545059156949005605322316473952413512517096496020410248162997014"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("971999376842705805940748029607564871764541381709871005906969669216857534299"),
+            MontFp!("1952317639551332544376745605658965432816167254535632677476581217176411959731"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16138918002824878555800417626596897387312117397657237815805868538954149796855"
+            ),
+            MontFp!(
+                "10288504636849380211451686237166984102089336390129306862867158790403471781461"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19742022380118263444539538038336438131989037974585059078289663501772436572027"
+            ),
+            MontFp!(
+                "17420499616584516218382254695722984321524912549964105727388838668550210410530"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21284853343955207795655806712117841277185639350231202037130531734994070008211"
+            ),
+            MontFp!(
+                "12020079372865332798821142854125285189028528944965154947187489529915234226074"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6503988821230857227882360121201717128604328948011914106783814796441717567363"),
+            MontFp!(
+                "18990840910381322600740339257244736344859147300821318671164466855384495068598"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("3942640364905314263134088693201010210915481499434836368887730592470021493008"),
+            MontFp!(
+                "18017017411376140155542943054870752929167161027323856747511929810182885078541"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2873203522003730597777818223641057376072307829568384488663915313705750436345"),
+            MontFp!(
+                "21561960936704907769532345172918475484748509438847618719474478227066226274567"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7501488739131812052146054825217446682244869778003394730957201407418996244030"),
+            MontFp!("3643588484837295645304496859011158688116255258411710533475162407637612800781"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20748757235868345447544673787736069831104394608374369239391582504633992646118"
+            ),
+            MontFp!("5330960125712070605040396222638776137585375127307747592546715250089908824096"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13959355435755596240287871035245781254262448078123562713415998595084401200343"
+            ),
+            MontFp!(
+                "18812539192328763499823886866598169174701900629350373633251692275989391037682"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7140361094526449326926569730349868936765933343796319036633123346212181464851"),
+            MontFp!(
+                "12513359915811143725568717242988901021935278944209440245821599469764268099942"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18698359885193219152694229150437231541742889059980759221808337187963724259731"
+            ),
+            MontFp!("8171497719438699214387603219803123346707805489164214160069532126005197363560"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20214133434410786988343308166107553632478373296605774872601512374950439311312"
+            ),
+            MontFp!(
+                "20440813314706907386994932442329737049680495595487269010465762304801898265558"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6902995848487401757361460475840319768682999140791970715415799548392379044176"),
+            MontFp!(
+                "13971981709322897375518975346533168038386160750033449815505979864771759008467"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3663540440198001111532595785956193475765772308572456547686649209767661077313"),
+            MontFp!(
+                "20203912468906281573941419973661579539795287386924854547173017921989782861924"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7881563880137531160043385230843660817178522195509049305920324118877860724701"),
+            MontFp!("9227675260446610544587627315366466886696387523242519981362018878215917471325"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16087571668586716177164188637215701937407761250094686726412385171029950523917"
+            ),
+            MontFp!(
+                "18791981151660865223651703283334510711889789682210730939212452769105974133275"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4300320796775639406334220060167401669416294332861687137028542067009434595711"),
+            MontFp!(
+                "12301770586034991720552177258568510706029593921241261403188247512344741429485"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6865495110960383547453886696082635107265366882741305163991334071247111034475"),
+            MontFp!("8591475930166155961565254163798689448296329080039358619799591293297413778878"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9897363914252860197713020924893427632838569192412002415008865551615034731001"),
+            MontFp!("7346553335301572575830299538532373058456306078953525900257523455653357450049"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9300651692843398247405776033902350098147129364098244165782875542412036699432"),
+            MontFp!(
+                "13593974147234101153452287046707643242006180056535539949759797082695638090615"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18756421728711809326541662244313168532863409376729692175835102632551058796160"
+            ),
+            MontFp!("8217270997241278154546227574233276004066731697374950641033473435041021404494"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7919596744979066433880443329331579033210883366934125137907710139950675508745"),
+            MontFp!(
+                "12192743673107462688029196994354021993143253823689207093730372712866251672229"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1844821877428779153582768622721176955714080483536314476612512352447688519396"),
+            MontFp!(
+                "12408366679276346583594407198810903564279904803254745669315058446606439146469"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21057126966090974515726654511528093595326782961719662602185090435600119012422"
+            ),
+            MontFp!("2397192929943775705704034520464331497473266373953052762714833002044264451509"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14314649913947135893484379501874838908941227272467462056951284467919061563157"
+            ),
+            MontFp!("8231609114872902664268184930606367633404331467854278392303463206412709408451"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17965247097040032715891330053631661483257398478315856651736199191993819451802"
+            ),
+            MontFp!(
+                "21530350835876325423157594403621785658377019681473892051211305844815113937516"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19138289193466064690802750717270782841742755720109969293173167512341824566726"
+            ),
+            MontFp!("3062589515435979801469496654899996377035635866187739786874202617910703350366"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2659397599719577901836176513122803731295731113953685397548547597945370008501"),
+            MontFp!(
+                "14807851379589828535560738628417280762726722210536398205235334202060943804769"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19714425413448256436270625917186595989949621892068444574696640461775450118243"
+            ),
+            MontFp!(
+                "19521992621632526881005228567671133303429130442290493747954402061353138784066"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1945180360738686733827310324148581222833589520266107026478654434278223418132"),
+            MontFp!("7668574154598364807656757387551810991139514511037353763600703711320259828602"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10130750861124551863691425752503448739696967566512841472135100762015202100611"
+            ),
+            MontFp!("9977305720405883132076903199263316314213562850663226311613505899323560677336"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7993015071965337688612577875197734205768942106427738802172227155345498501379"),
+            MontFp!(
+                "12322233490939020823789386945409712221971527379203470152004806756990188479855"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16900908323312604263463290286358726988581630322764445506365664394503262993836"
+            ),
+            MontFp!(
+                "18830872052446187764254876567600619379430504438411722317163717559734066905441"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1496092124022096690137215281681185236107939654928505143808317126400353524673"),
+            MontFp!("4912141313624033356975989770311290846733950479037737691961342382805354014800"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18373677453319033665064174503509393662489424710175463332711832161916994958534"
+            ),
+            MontFp!("5354289967067002244375912267299847688986157553915558128807752547413438564634"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17956650350345346971138977829377963933376730508302742369581836170197314656956"
+            ),
+            MontFp!(
+                "20769645164507719936138478504682312330595270787493978236318437393588567510951"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17832573847650214406920365064811324664880902283917194092834109207713191814139"
+            ),
+            MontFp!(
+                "15028307989854508826950035631060242035909150449380776747282653874910632018841"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3105910163804607644891245715861502924094630495286383892538172143787421351966"),
+            MontFp!(
+                "20367203226271157411685806313705295143180981546200139617619344407585166696373"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20820551946892461531400063179298342005183382998639077170409689482148243200315"
+            ),
+            MontFp!(
+                "20951477175941014653170812060485978569036661283635387381772148367038582481852"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15759134233466714291335441163128030314941164897469832158606612526162200490536"
+            ),
+            MontFp!(
+                "10593574770473729786735109968549312993348592760534824691431855111607054634059"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15518566536014721167632396421283559038191250969214023203245728492709157697174"
+            ),
+            MontFp!("888264402479953136885057870066065170453977294155595077979087375821200423175"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18092932123702039993740919348926556974457278300492228107995999980019732406944"
+            ),
+            MontFp!(
+                "20064716100648274827622021414234363621493790267403532202124564170868569190600"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21564615371742388735897893657404644546498489933732573335575547451374765535022"
+            ),
+            MontFp!(
+                "15906941412842307301865507773706898881126113214957044190435929797964108735307"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6710104337961521721571549697151135926602288348039444224735039791906369983373"),
+            MontFp!("145447008361265953002828279555443789078442833508824950678161403034507312358"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20946641049708578016319856272172977547140960466257403397380867145248258653516"
+            ),
+            MontFp!("2260412488400389493846297982105253911562780727235869984579558727893158012354"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14311283168062922286002996311566952225517459596749536390347472455743622228023"
+            ),
+            MontFp!(
+                "10854962729032266920365552285267984725147417429595998891921959090276035848137"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18406268055070426722590720341766855826059163386384326428444118418568020537531"
+            ),
+            MontFp!(
+                "12273985309410203628808306084210981150190432244653005923943430319039223717459"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21667696622031295117456058752926369941447682286909084742535134385547194299910"
+            ),
+            MontFp!("1448534544892662153889868964088925308473112781387627351585829428193196191233"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21797268155014475639346809849851623543368012404659985066075261861270666838354"
+            ),
+            MontFp!("3776662526696228178765844465753517120338057967483514905703931668015727690608"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7130290723001631053801343237376898081193460939702034710294250790646059282864"),
+            MontFp!("9432470135323678931787943776395880267761743365720208391396792901524386217522"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3952024874567874941854454932181996494584434259349308075554824768659985868693"),
+            MontFp!(
+                "11474285854617568951359680481031943159112340765767735988787929183864881536564"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12820418760107777980993531675953754030719879496717243895614179600077600836095"
+            ),
+            MontFp!(
+                "12645171810661173919305098814413820086200714019135035337719448793612431116849"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19324878011497297838085837584405195701265359513869322488390798241703610348430"
+            ),
+            MontFp!(
+                "14253451451861702904179223846473230156777765841495663459945620141167354193902"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9594905001421447329467713825512663410107371436568618312733926796176097128016"),
+            MontFp!(
+                "13650715148341055955544374277916039050382899960084033015112956091453895394593"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14612886411583009022536140383852992991734587155198204947025905502244213400659"
+            ),
+            MontFp!(
+                "16933321501776225065815703898429062028822777015768798078860922884360792448305"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21156634623941570312543141180376706611820294735524616840098479777873047914486"
+            ),
+            MontFp!(
+                "14278447206160592504576861337045909856922736517792320961142605009509481539077"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12212163258092344084083612777392975388767324262823925550795961539576018410748"
+            ),
+            MontFp!(
+                "10232559420668809821354902061445761379412217541585216554800992890656896839674"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12264522348206456752724367799867583896003321082584236066133219647578407338111"
+            ),
+            MontFp!("3269975805980204876190770139246910983571788498486604949201082446427599613147"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16438781045984139484557634897901663316974311179485010492580415647507942548967"
+            ),
+            MontFp!(
+                "19016748837135457325981730480182362475334989085810966894641628126300922756341"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12279991469293970836324810704887287738214658290706264188431884481985279868257"
+            ),
+            MontFp!("4835594873250599049802938058659959675850427600233348490782165743934367500921"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18109820537509628013544845040636137613157599986098407188911716734596105474889"
+            ),
+            MontFp!("7570593150327132517082519221781604935395013814284138201478599554072494128256"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14515792885039363928685403180388500670176852488814904191176292452454370776256"
+            ),
+            MontFp!(
+                "12584115283538843031164139134102187779433099697039236033079549279918567994802"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11283286479606530689178870878092011739994626581894077266599330032310443396730"
+            ),
+            MontFp!(
+                "10632549209057701039019980110044448160650852422443571812206082134957247310655"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16902128808329164585466410168727766100377880496971275859710873142053314153737"
+            ),
+            MontFp!("253398144512666199685236700442081333627507762851499327195880063728161330970"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8568870485844697154094750278507577087667606558511760744008447755140706670070"),
+            MontFp!(
+                "14068920128411227554125212421104048853441002701243498454991829492410856488492"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20512093908593131930454008369935588138620033809602808282613291295113073227933"
+            ),
+            MontFp!(
+                "21377166335014460002443717829914474336032021213339453476180032780625111680408"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19841778866492417854913087214717408603507594873805005508685859843530107580169"
+            ),
+            MontFp!("20597239271891378386812125890985504719527742750187769736244416947104728675"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9486793511426521429385539541725507627068052724054627773121863789123808037657"),
+            MontFp!("6777868069115964920260403841188059851710927376044216538465464879610985806182"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("3865106370513697642445135581554793317341448120833695161623853645516209892850"),
+            MontFp!("141202996790423611215439268472529114665165921027441772194517341042851522719"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16026086789296048661946694333331282153340119280294515316625929316099363591468"
+            ),
+            MontFp!("8281747946839150983953109661394279915838622797671296100372236110312010502007"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11656379814851932696243331264526252971711572960599643266834636907714437657122"
+            ),
+            MontFp!("6984284626817902120395438049157989072703684769999801899964964220824044737778"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8272252355496153070375600507297503341818164489707282779826686103887426394266"),
+            MontFp!(
+                "13291522031328964133111809729266014850225809262102219857118535637691498000424"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20735704358272842779553898193252521965929780602048714411642827604688996242946"
+            ),
+            MontFp!(
+                "18806724035177040645427626894175730967052030613882438171359522136342187896048"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14877587317569526439260782546287250192785160437597351198088608107396919486802"
+            ),
+            MontFp!("3766647999835982966746257473978586167498154968198180130610866199489005392752"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8629366011595949099558508588210246194744697551872583868195342355146279353911"),
+            MontFp!("4088422393337666688876699278251287296161026396745490540792019706561857579941"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14616258890795804180093683085849899902550428795099517385818408985367260844800"
+            ),
+            MontFp!("467062466373395183480590994299875912263352378193588542033073872269611397761"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12354755047119208278074922674420704753354797064979596665336639467285955827863"
+            ),
+            MontFp!("8530506158974351692563966292922217971141565219415853979583777697211862951160"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12562753277141579278135994519095653496433818046731990218640601454950882151688"
+            ),
+            MontFp!(
+                "14599004868433034303972862582910265388238406268822232382751177189346372693353"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9072189990779043490334047204755295411155151193689205794021217473843501844640"),
+            MontFp!(
+                "15367417750879640107342224966329444794413628108070803311678925201821946868632"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20671353404449008551829232670517903144789423342935869552974460273102406919148"
+            ),
+            MontFp!("5857248543216914486123605098290333724460980855062064659729668602478874326178"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14626446960720418607000834007900840689608121436746578395796800477714555398323"
+            ),
+            MontFp!("3173716235331659648421586905508224015108123012648225271421407582240990110395"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6470641225744851595055084091292590025809916695927865595710365692376300142245"),
+            MontFp!(
+                "12972432565476148451418008646228470695014486365299588787277388166989566405593"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12794036716629708922001410942204563584676487051521692763809033358095029400892"
+            ),
+            MontFp!(
+                "17976376636595145327639586630721869966609706343791346500171507941890502586402"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21026910499498137571917458075591786887261522479165824075752596259910530682839"
+            ),
+            MontFp!(
+                "12942077288700699485022811417492381801100880733270449701440605741858265735863"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15868362509547698248962879670842530709576605856240688694653007075232475888163"
+            ),
+            MontFp!(
+                "19067762653888688245560216701698559564080662400057918181472999978545431288331"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19947034467359050966504424142076995194645383567965264172778372399637698088664"
+            ),
+            MontFp!(
+                "20518701832889031391620160662590243499413987461379157740773871562772141774747"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13132019760057601842164857929413192210788719944650678549731276030288216611113"
+            ),
+            MontFp!(
+                "19974605684127422427638869109252106871854250810054233788916051570387853769190"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12853890557274341420193726186245178222491095767191340297474489359725367407259"
+            ),
+            MontFp!("4012164650668920106633199655336653542168274760483159725871864874908548808887"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20317277897401827197222498084961306565604921535978014628546676210480541403437"
+            ),
+            MontFp!(
+                "14957525747859446518678607454620355514233788073806776670322987157455484718663"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7742194539492707778694300903146918618529521928189974486056564117168897422423"),
+            MontFp!("8368632586071280181523083980570460369627675504594623643917924003432519957596"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20693095168373254213796925532994255220744090109983496906116692863013568144006"
+            ),
+            MontFp!(
+                "15681015034228913415984515392904043228309068913446281286217003769015638475570"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1667210290082521111052370325860671761316917944097750706780308729438865841705"),
+            MontFp!("4186681720036987874796963782400922329261151087689700333313852727545263822449"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6034041747487870421349910926119768201264036337462952160635576003451337733086"),
+            MontFp!("4976609209745464085117713053029727252309660332932791902822051822029589034246"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9565933528458921713979499421139137970927902031849667102685285938238077249012"),
+            MontFp!("5111787792287118033749423257776565291186605061650395011469453239025033545837"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13304947245930139155153006845832869816415299029222605129411711977324595288469"
+            ),
+            MontFp!(
+                "19502407046880380980720783719951892003647055190093975194903997456749036393710"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4665612624541174650892577348116638270419234278301672403490255484076727671917"),
+            MontFp!("2708779711680449316373998420060384690929423097669418122091271901354054565967"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7087712667522722175866374521501938730613279937157308629724395872234094433070"),
+            MontFp!(
+                "15417526635628183558531214133540022501935619184261177117824109325872537782228"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9115244658853644561311767741758372221086275271348034737857039976375375549308"),
+            MontFp!(
+                "20151035727915645356477141437764176010446305566909210942908533526689752162781"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13779876131879786858112694289082826776124087627948043629046051121209784129890"
+            ),
+            MontFp!("4002657035010705617486685441329326693793905242958708452507140330840713947216"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16626302884075457524130192163294492480504562895249527301125214215280566794644"
+            ),
+            MontFp!(
+                "21414474327158385392150188147574225137187648489711250448548395500127457992594"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("3705748894728097837204393961270869529851801919083195621279801862077192033000"),
+            MontFp!("102290427861389392717804922119629308109793629901400883474787996162414356218"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5667597917070318833680071322792015540303313239690352831423637856910707503400"),
+            MontFp!(
+                "12407027708714783103382847080620937113860766170001335755889251116300298024983"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8205261569934448419964646545118344986466021248488040929395069326407621435655"),
+            MontFp!(
+                "17199141045861817281468385481538484687652518091912241095294653421542346619598"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1863862801816364256770257484169791113958455358517637286782498738780013588520"),
+            MontFp!(
+                "21365548713519610139911256874813893901221821988470404948880079673443901145869"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12445474328379705919018148026222199947915106963310701484556572025237447412542"
+            ),
+            MontFp!(
+                "11182733276184840986003211190286314425482346106376472295328377216971566097193"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13873772246419833385865863708127306448872612835327803686012424634249119589862"
+            ),
+            MontFp!(
+                "14066942306404735503269098479373634942710968768875923872596499563827182542867"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17144299763751641336155333754017646900284548766987235215471329245675166727155"
+            ),
+            MontFp!(
+                "20512624936885577776359942683845073471361209999830720356662318306394081455456"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21329919605547106129398282245272508478408338039725901356752579780977768352744"
+            ),
+            MontFp!(
+                "20667151460668904833281776150948027487771554026470184158852907970887283414104"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("5339250354565540983624337888398624895091415784107032249113187710932423440951"),
+            MontFp!("7464388092530550777263181394200023196702615084309628546630983743032802709301"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11045053094309175760800868426410139183715587621699082107463419844970604579604"
+            ),
+            MontFp!("863980324334998336320021431769325287050395171620327236891443158482033277065"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14721894251348571821020164872341215711510619346229891984473094308470121147866"
+            ),
+            MontFp!("5481294921265180889043739193606600782062664991378562212915117561479389903999"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21636255170395997639969691006876856732203174042557194931457822536780962774221"
+            ),
+            MontFp!("6456265092174412691557917179308794433358382742351655931853812609352749911814"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6151523766885216148950234892028997125616742890310855401213797177050781398108"),
+            MontFp!("9750280840330154034581933224961708927020623430089615757662781305164166834577"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("504671155405114647778432156358632321497105793621922444678282822034798951034"),
+            MontFp!(
+                "15606640646218874482680205784925780649894213278908538843889468562485578676247"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9193990775320511814506164842179869228673629812912434632428898978147033011378"),
+            MontFp!(
+                "11170948704922910616393466234643319569048104035826868204511385511927117662726"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16905194542111300796394613305726519084754924680475523473368144762489007204534"
+            ),
+            MontFp!("8234497811415585671483390063627601200375201363173799221180667840632232536863"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("5724625655608868645689535268422070084543995927732212059724113152068754891373"),
+            MontFp!(
+                "10308923700401579816603597606813811960552754413890927913080984195249222032507"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6415199545995144128192406980577729592136453757690453917738278191932962989241"),
+            MontFp!("508732211478975231120557164369310333917472569034838519214664665624413625136"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6067746050006554711512813065046613333993503070513019364321671388087190173795"),
+            MontFp!(
+                "11326688733985040621297658022936224403038190708104397609800329854482575035068"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10708527891671357989133513830177661867514487421458583395074312146641705056046"
+            ),
+            MontFp!("9044810538568980521490533209832115329351009697707715597283200503770073952859"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15402988058405286268930287312180852797921117744078964834771421515682628888917"
+            ),
+            MontFp!(
+                "20002799644516266482785769368029760194012972479884586516951317428611747956092"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1907547328918408812710825427292006878533356969327482879765409643405194724524"),
+            MontFp!(
+                "16124029641338546868204005605089514106066369931879134223366661936558590811534"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13201147229459567378763662345105555020469871923270892663776489941269910040517"
+            ),
+            MontFp!(
+                "13155354723977379409575017435685469517977628495190946177360430151366055377624"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5757875670161352627628877854573399900889439086113153488345671257370967211755"),
+            MontFp!("4074736026485790805367892850935365987164840374605213452438286346088530162346"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11477257176508053191844082102665264382942512018934412629972361503731082169603"
+            ),
+            MontFp!("3888668753532157817137219333721384953051891377388398518492577491257839400141"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21653993229114152964668356325524493144558341291007205484088426394699534938777"
+            ),
+            MontFp!(
+                "19103728899212132655338366285953424145997704616740238581044733288360967243039"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10373992828351310617510525163336109583384959598561719675534165637073131801455"
+            ),
+            MontFp!(
+                "17556831381642559721700431291880384908896926270242596699238780480162058446060"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4737671142346544996302872283273888653417391805451983548313655498309021638478"),
+            MontFp!("4904946739366063286149240163440193038814789564669395489332548846853829580573"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1830470693640603955589471627406209499157177355399345052089649559179914687642"),
+            MontFp!("7031196225275445058815206879342798675723711125634646214550199596647227554961"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1775830219309835253303839762511849709426297071883595752287378294361903733930"),
+            MontFp!(
+                "15597127237360565132138592653337438521132036039507297560667839338289276905211"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21820250440208943076089325213281760319250884977667110932010272091277778919430"
+            ),
+            MontFp!(
+                "21281030633071107517569949403835729497255174401540018931337160672370991792188"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19723630415461250765078307643596431268497020766308850443746812137513180793677"
+            ),
+            MontFp!(
+                "16863754499016433428266370531400309877110010842792208512122181387423690693580"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6826149046257472318758150162702356721489789010159715178302315568677119043"),
+            MontFp!("5590060906243811642192361782866454364469663905287293782868420695781348851988"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4525239687238791599400641157621520614013452373006774239880828140246069217494"),
+            MontFp!("8852384089944014379472917644588382108833284912886421215416200584047666321986"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18821646158416335838429827338908147694235472556615218890373257497198316436983"
+            ),
+            MontFp!(
+                "17170107208240858901856347393826667393249924459264396047207457857184101371457"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15084391058222517623188742111009884980109733210118995681928289547023824364735"
+            ),
+            MontFp!(
+                "20673475563890900547831743556008288040197768889350131714304493990343640712105"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19761568394045751384506835465980844714106014928549026836817040050382374925332"
+            ),
+            MontFp!("1446767443701597349747254782640505623867955032001267285593401852077730657628"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19650017927264731609055344257686160963311519511493218804640538725275840722501"
+            ),
+            MontFp!(
+                "14474145898385768493219374711472665721339064668268111871541132664767227851555"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21552280792876209083002959992969429459108560160875727486959470800065195566626"
+            ),
+            MontFp!(
+                "14673433256904160091401479127544940827523979250437850523820840238373614425697"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11439422426070754712254427092043863259326999834139544367950985434370911691762"
+            ),
+            MontFp!(
+                "18933337903020033947828363568302275259360954458986035575923273645970669358900"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12345921871604312772096478994182638536478440759476115432022451208294262161679"
+            ),
+            MontFp!(
+                "11103900768297349509880152396036264655361074356920569058234067528342513443474"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6550588973810015181715624100374852812372677550393773058589051646760160756317"),
+            MontFp!(
+                "15697325923610978679943932694483361775427927876365150333581552542737389187369"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3607765893555122810212352150741104223210195408154196503955247639161244934391"),
+            MontFp!(
+                "10403364643047088680744057175428195353670149710519304439707342610337800110965"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20816938872213376368762275119088965404305318078648320078557382967478043356418"
+            ),
+            MontFp!(
+                "20474602590200558786601154519593804186614595283876421080985367874368749830683"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1306322485128222526167858061944951236612567518671029265064465587775312350188"),
+            MontFp!(
+                "21686676247640498790787771342804916988343508789618822192146764673775661512795"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16975769506135249269525230991676664902983053449581740356959600200159676763352"
+            ),
+            MontFp!("9343935033297097093041820367559455866835145406206789987955402471284991778390"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5858095363792962753008157182601100881670565001899050233175158083901080151151"),
+            MontFp!(
+                "16300156054012461330571323344705014702675156870716969260901384685577194666528"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11404009316188164897826411490190834723509794297179688279179243499662416672722"
+            ),
+            MontFp!(
+                "14807397269781016576706665513177691518668899754380034239254202264901761693358"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("2632946016263195398547888841760246345102686124228422389698390520596019228098"),
+            MontFp!(
+                "11884734304562612233406023626992523413465681385143113327867825354026307046150"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16305151008866555107525263521465115946896858668603590338356083667154979597659"
+            ),
+            MontFp!(
+                "18784479509196042287904586335690285170945506956881740729422419673864126946132"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18189795970711450328386812928886685622631919198075730715439608797070032258409"
+            ),
+            MontFp!("4805313738535047499994277592423864628325335027567415896845245720801408169039"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6133519501913028371777857122105899713211833378448012368236975538521298277881"),
+            MontFp!(
+                "18239340960929595164666305522005735598352936251648315039082448139194283862319"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15730211851286279374234523999157968569142967472805764629292227270349871789730"
+            ),
+            MontFp!("4042583131695069680037767157796680631375670816950861466160755089420546806181"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2270606356566287379257043382187336112375619706891559734822812982283649721039"),
+            MontFp!("3841985428811217121467746836409877360556086412618621857477266767294583954378"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15864531121554572824453540367686962252712073259549060828239347759062861185656"
+            ),
+            MontFp!("316512877725124671521598835796879144556984419128742817964353162064960431847"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11102700140878249447613260017808748050398523591189597434759697121811102122817"
+            ),
+            MontFp!(
+                "18178142932948360555711234223812569077429903612243747300877226965791834009412"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17261171471750689430076443525127138496769237135133787192236071830616075213204"
+            ),
+            MontFp!(
+                "17269325333093496200432897943461707824817255226757270857641558624201045522715"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10696291362639886158899982788827669729427734198596017471691766441725145258966"
+            ),
+            MontFp!(
+                "13239762714081467371289873127410192035639196756196091493436725532575859370620"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("188632690449629545006003990954113862262937847377062577753459270464293861879"),
+            MontFp!(
+                "16257941853608280369683761492338334809128144291860235322635674589038957683201"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17064290401144974550289576966465669248298727554708733574685800159318520510895"
+            ),
+            MontFp!("5477440375418908257522321121715516512260174701139180806634239654672368978928"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5258213787562042650369746932934117245742150387044509006864496842999739264390"),
+            MontFp!("4838729326759939194737998435138138291967894971412299265143463829876241807966"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("49055529784243139564571195648377203048950997408496415912027016235448096488"),
+            MontFp!("6243274274273493823797099891468534773409313944629390353885139678947124750341"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "21077664261188624828454638861169401622456484051025715718214748992828333149107"
+            ),
+            MontFp!(
+                "13015069082532642045738711232471423949649575517462742428608520237203404701472"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1351989364744702047808405121356218573358896800528516473895699016149947790453"),
+            MontFp!(
+                "16265441513554435832262538580668280438082287796205562262243109198013255448682"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18456645900957154843786848802504288387321034796596551991238651908448595835462"
+            ),
+            MontFp!(
+                "10649305757300261149501955182354265319084577328219935046341945437983778496206"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("267214382286628971399537655941431308198400996259133005716543464115537893211"),
+            MontFp!(
+                "16674455456252435884502495533129492356969191873550269187167059230303548976834"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19990811887668304900373461523221541071862645902140568599829759153532918718799"
+            ),
+            MontFp!(
+                "13296563848822558532193176162170569942456447847777612222513507549461632965631"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("3153810280694673653775355511032882350468833500929747447514015136526674143839"),
+            MontFp!(
+                "15858245573315742409711645453795138685924344787621995467077107467552357285707"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("783291925733375212396189537095454756068134812191492753755533627096251480949"),
+            MontFp!(
+                "14089416900257942475182663715781527616107419122217968225310746804362751886726"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16938524442850894240285972990526773104163925723727497965910066126313763638577"
+            ),
+            MontFp!(
+                "10430515615151797323571646847700848387880038710287259666820497366739390148510"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9860477617616846150193806566688559932861631722406366674417570817885439875605"),
+            MontFp!("5242407431939451857207059546718219042120050420963313868286157692243898024806"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8360146156083222760376925987216922020816822003262768889402572998368994351553"),
+            MontFp!("7123849381894495843470941508032044746475185308487618299523661064281621590514"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17936829959895908229127760462426488752610331519841722287353102191117197395699"
+            ),
+            MontFp!("9349564834305991032726128899569098725855655706319304065823631645601555511526"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15651721469404208777536888616019206836240326941839730116722789839883705706896"
+            ),
+            MontFp!(
+                "17991528325060672439723056971567779596992270625858547626253969112700353993730"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9928844645395486238518729248396717987473122777598463961746199198147321660132"),
+            MontFp!(
+                "21219073012829902160104565302941655467122447499436907663875247962730023758931"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12129618786017794222046411167813685027784362917159164101925010131916039535809"
+            ),
+            MontFp!("4625860008076069820579528959194868531240038385980209936023636743834792601338"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8270950977709247524914649483320110678351292934935586992876489988886023407204"),
+            MontFp!("1596676805723792014877428945149841728181828777278199500872387139288636182327"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4828180621093304650976569970917341758629490414706274503529908355382922463864"),
+            MontFp!("4535817852406335240136741603712354844350437607717690961900285105876553662527"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1714536781839828309593435446013451807940073389592260133548136486200216519275"),
+            MontFp!(
+                "12795861938744822839307553470552737148425451099884901989361835375708379179457"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9988127771503980439604075976946755137019850580096159393787449524892310275569"),
+            MontFp!("2552623156220062235275289092773405250358207373164467836096105561321728735375"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "10200040740023897506276667839279347711831838584474855445093088844455619430900"
+            ),
+            MontFp!("8541013504708003526960107274778590638432595536873828476168361749770617050093"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15628916762983777324748152347179000809611244211222578888182891962604607908556"
+            ),
+            MontFp!("7803492202838832096616633861709985095100722097395790284961387366654439009716"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15438153883162423931445039100892345988698488568644639590519417756259253658426"
+            ),
+            MontFp!(
+                "17500338496929146759990011977093873611359711516075177808180573215411758333961"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18667282379215974520779105820819117791655037453958838761324157632577287066687"
+            ),
+            MontFp!(
+                "15187431086367736491324177208478285126445640035699175833678143349949363742986"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6677019938243053948432298078758121211619985357035056962275056128619972986110"),
+            MontFp!("1133208705816718820816932209024531059419660270148334882250927468971624595818"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "20476734898364499576212136303429301534955270922103762355268738594068924197114"
+            ),
+            MontFp!(
+                "12333813308685324871728883192588888652974987465531260329131304497780152207055"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13744179815405386100584751370778461175291633571589364191219015682220048335230"
+            ),
+            MontFp!(
+                "20048346452949871498525072954681071870921234625044991585939994380644142419170"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("1443695812121085597226302620410922737987943769125389302266234344473301467144"),
+            MontFp!("9906824484671303755131602868292337197677610515031271387244041948952419971466"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("7639883602969203993843880205942915138859218883144004722833372361118783035123"),
+            MontFp!(
+                "13695878519736308628153958420177201326172354088839501898642431855436607634133"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16334320188735612564997669446071055414383249952747412747584054440536134403459"
+            ),
+            MontFp!("4287098819158178198081383788935109561770479053053250875120754150495649850908"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2085461423460012616446556107182317292201889590765503638495293993294249399408"),
+            MontFp!("6177192347983914159078950520959265990921872562750829146070625996893334028281"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4395954441067162227912936997551677805169123264127653263394598068507735100955"),
+            MontFp!(
+                "10531058705679276005296511148271576132213050292943602657199464509407065433935"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9932471224375996903732545085293567043288515437464122899356805605043539274348"),
+            MontFp!("1765840165250571830190150721976030775218224674950179900173235102471192359504"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("2401214653059957531933692918575193723641037939610212391456009952086895735221"),
+            MontFp!(
+                "11094521136566524241705215872831467875484293878641323209291082887845136410995"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15220784327651931184802558751880922627551969419733814214857352217739061098720"
+            ),
+            MontFp!(
+                "19129764809168737527675414267308116958028723517415214970405311374787573473838"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("491896894915771632463198774729895436238778145705583036377211876050734417437"),
+            MontFp!(
+                "12792704157860871723088608963288815261065543401616700743082174321559448515662"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15884465147355431597872978755952287417457997701239076301792562582211716349893"
+            ),
+            MontFp!("6425369991486593535757320685100201164481826335205559545199785136805279410577"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11277074276903309996913681295651202915098754224015622918120761473925641497077"
+            ),
+            MontFp!(
+                "14130875911183800312997334715449078883995501007988238827587595837702221716361"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7317396791771279665378114279445466587978083509495859180132914173959083493411"),
+            MontFp!("5734228682093904621690487342478373624487534429961546008655380059195739745192"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19917287942850200989795103912531661919899283562997757811308901676845000940174"
+            ),
+            MontFp!(
+                "21782661812150097054758994165076218692216420112595512981403181329617888716172"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8626613744416765446207929273691601091167436897834997384209324841431593314383"),
+            MontFp!("4577908015060665942905712631551605864252752904305802964236687411411004545236"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "18760106272211804939325902961316509124935068913463545869243063713271796127640"
+            ),
+            MontFp!("6674577204480121033203425601551285830295667788244275179717572007903026502743"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14718112677868400852547383847862692209974019318988190275626201239260506500769"
+            ),
+            MontFp!(
+                "19787002253553357485030154459717133771790779800703685127822875368342335132397"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13591278656580725974538809093652579734041530065580939399910303884508677349338"
+            ),
+            MontFp!(
+                "16892921154637577666617950030076240898792682863520349289375519205384829409980"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("437830350418612079113607070238477979311366620337428438334977540233370165408"),
+            MontFp!(
+                "15860893515322495944486437168460217407094757880116893981531544806176576049134"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "17859157829712061478840401852099123448321350344410326845302536173845978282979"
+            ),
+            MontFp!(
+                "12386963392264417545944613845371941172467729634418090245338233940428408076451"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6524952481544799929886533116118075139222058264678092929449699512949890882326"),
+            MontFp!("9779114031663847098633430822380336148479909356648593889042927265121345186919"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8448361671856611094368094893873528300213448016874278049008935486981108396466"),
+            MontFp!("3719130040286663224590128240427185068966755285258175280098621282171226423213"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9983803172751512014768739166259159015045848762414450174052045701438020695153"),
+            MontFp!(
+                "10322829643148397848065706319690702918654240251867225243935440258308274515171"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("9019153624043568860377780903108577126090541238256257054108305034000443551075"),
+            MontFp!(
+                "11960253870645326871820889766417733104138702313561308550157413340755266120074"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("7426040454917947179533245424099086179521582496585306090759949725539701311742"),
+            MontFp!(
+                "19101037726282350790359618161793610876485937529614986187093577789360810462461"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("656664048562521973466419256715499020845725485662946447370378703096238650872"),
+            MontFp!("1233483711299226689843291173597276405126073556217603037694946776191569791631"),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6627039242741567253720108977807967903640237853660963235862267660302275149970"),
+            MontFp!(
+                "10297314759674437170693296998290212191303882453090853125630851749220298986543"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19770875486055357231464976665155595723245424221724704572412615515366030800948"
+            ),
+            MontFp!(
+                "11426680139972549304394780604480797483302238743554080027960742755442896461615"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19204155369361776130311139235503351014436933953362610706620723951718889764186"
+            ),
+            MontFp!(
+                "21478906723864047648121963761506673720068947783944541589242050635796255170228"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13954438557671558111989431802538312830703752934913127401738198249543007519536"
+            ),
+            MontFp!(
+                "18470584551415129579199247001078606733569507783840135981816074768215083677829"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19721344797570971131063111156106730800769120118045984015350780087245379925914"
+            ),
+            MontFp!("523471948015348366957606335097340843539546989103461418868382841408926982727"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("24823122231724330894313564661660043301698970777519893569900261930584649900"),
+            MontFp!(
+                "14367875606070542067663076484097518968530628521948819781022515428717513761708"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("6370707485651641547058130650702983103418175226742204018911791412000780705569"),
+            MontFp!(
+                "20001776210576290942559058400396469897519210573940367199693635470763306125533"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8655053728553930580635932803450054562699969735613599472553519062460921774421"),
+            MontFp!(
+                "15249488394945361745397791323570379908242511300328815012469245432233652436318"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("2804455449971704844846902771359263528558049942842015341031030459215355425849"),
+            MontFp!("4908820804137610238479783986953946137849366450143740413356524292946974364171"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11442838843010920200987124236381046990413412306061849620555104260513476564711"
+            ),
+            MontFp!("1546787711467127135265359368331802992527265292358767805130453611590664485775"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "19215200743218641688761997214681505323466128848167467658485081964588962741777"
+            ),
+            MontFp!(
+                "20626951122066270025693313824904149879593685192677463197693016660440129945027"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("8758568581607664587393137782739897129689704749021147636129402116634657241384"),
+            MontFp!("2218909707834507373146775231765023325805981001515788375501787055093581124560"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12406626418048598473937256794076593330940083182435842147732054354895571384997"
+            ),
+            MontFp!("6267651040352119155041368431613589698173938446025543181877997318820094225331"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16623340878914951188534534640078260352558642509537247860392955754891858582099"
+            ),
+            MontFp!("430357125881102538463853674114703411203211571540296594750669239804605095897"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4854619971702494425408505915312269938604794731482750949627581894228483845585"),
+            MontFp!(
+                "14994659807617816260281296867571091769424335079669852691225275140567127452212"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "11443462148998891663893224707624419743327126502342180493010892147941521715093"
+            ),
+            MontFp!(
+                "20911046906734068563636985839419149165001773265561602462043858477758763302523"
+            ),
+        ),
+    ],
+    [
+        EdwardsAffine::new_unchecked(
+            MontFp!("6445834438287712160468527383156314788880602027176122612837881699635278939704"),
+            MontFp!(
+                "10878464411334583767006776191388468980759930027521682852153518071949653494816"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "16457008034960530119058837793507498186432014715277260521174629826435845558188"
+            ),
+            MontFp!(
+                "19260182853041135622772233659903418393756174726540371472288505822374126270017"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "12399445498761715216275972124509587516925257009292001962727473508101670760643"
+            ),
+            MontFp!("9491931933279004327825227925120276780001144263630021927475118803315584260503"),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "15400457040786764911308314725592801585893470938959606215323580340462780616637"
+            ),
+            MontFp!(
+                "11839255424873185076679191864006811529693090701571094110978112550338481017013"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("4106226955224571230777722816176338651840953598480735238134706456116749896352"),
+            MontFp!(
+                "15031473986324381054157810891564556812534021060848080724623000085685119439592"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!("5878588700139856146248449923242822603467645739548928361585576169729107113585"),
+            MontFp!(
+                "11869963277210886158077929430479124661168900522248866758069800053255106982203"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "14015222306434730722670837185829621347194326652323290087922759676772714117125"
+            ),
+            MontFp!(
+                "20214532842946641300710788391671580746663152357716252124436380333568190186491"
+            ),
+        ),
+        EdwardsAffine::new_unchecked(
+            MontFp!(
+                "13257059337735582262974949505009616147168581551220985053084388341686129616048"
+            ),
+            MontFp!("3453075767096930412120722566416549766915836308035568160854238388982874721390"),
+        ),
+    ],
+];
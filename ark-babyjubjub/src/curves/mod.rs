@@ -1,14 +1,19 @@
 use ark_ec::{
-    AdditiveGroup,
+    AdditiveGroup, PrimeGroup,
+    hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
-use ark_ff::{BigInt, Field, MontFp, Zero};
+use ark_ff::{Field, MontFp, PrimeField, Zero};
+use subtle::{Choice, ConditionallySelectable};
 
-use crate::{Fq, Fr};
+use crate::{Ct, Fq, Fr};
 
+mod fixed_base;
+mod generator_table;
 #[cfg(test)]
 mod tests;
+mod wnaf;
 
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
@@ -49,26 +54,16 @@ impl TECurveConfig for EdwardsConfig {
     type MontCurveConfig = EdwardsConfig;
 
     /// We override this since the default implementation uses double-and-add and skips all leading zero bits of the scalar,
-    /// which is not constant time. This implementation uses the same amount of instructions regardless of the scalar, at the cost of performance.
+    /// which is not constant time. This is [`EdwardsConfig::mul_ct`], such that the scalar multiplication of arkworks (e.g., `point * scalar`) is constant time by default.
+    /// Use [`EdwardsConfig::mul_vartime`] for public scalars instead.
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let mut r0 = Projective::<Self>::zero();
-        let mut r1 = *base;
-        let mut prev_bit = false;
-        for b in ark_ff::BitIteratorBE::new(scalar) {
-            let swap = prev_bit ^ b;
-            prev_bit = b;
-            conditional_swap(&mut r0, &mut r1, swap);
-            r1 += r0;
-            r0.double_in_place();
-        }
-        conditional_select(&mut r0, &r1, prev_bit);
-        r0
+        Self::mul_ct(base, scalar)
     }
 
     /// Also override mul_affine to use our constant-time mul_projective.
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
         let base = Projective::<Self>::from(*base);
-        Self::mul_projective(&base, scalar)
+        Self::mul_ct(&base, scalar)
     }
 }
 
@@ -81,49 +76,109 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
-/// GENERATOR_X =
-/// 5299619240641551281634865583518297030282874472190772894086521144482721001553
-pub const GENERATOR_X: Fq =
-    MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553");
+impl EdwardsConfig {
+    /// Multiplies `base` with the little-endian limbs `scalar` in constant time. Use this for secret scalars, e.g., private keys and nonces.
+    ///
+    /// This is a Montgomery ladder, which uses the same amount of instructions regardless of the scalar, at the cost of performance.
+    /// Multiples of the generator use the faster constant-time fixed-base multiplication, see [`Self::mul_generator`].
+    pub fn mul_ct(base: &EdwardsProjective, scalar: &[u64]) -> EdwardsProjective {
+        // The generator is public, so checking for it does not leak anything about the scalar.
+        if scalar.len() <= 4 && *base == EdwardsProjective::generator() {
+            return fixed_base::mul_generator(scalar);
+        }
+        let mut r0 = Ct(EdwardsProjective::zero());
+        let mut r1 = Ct(*base);
+        let mut prev_bit = false;
+        for b in ark_ff::BitIteratorBE::new(scalar) {
+            let swap = prev_bit ^ b;
+            prev_bit = b;
+            Ct::conditional_swap(&mut r0, &mut r1, Choice::from(u8::from(swap)));
+            r1.0 += r0.0;
+            r0.0.double_in_place();
+        }
+        r0.conditional_assign(&r1, Choice::from(u8::from(prev_bit)));
+        r0.0
+    }
 
-/// GENERATOR_Y =
-/// 16950150798460657717958625567821834550301663161624707787222815936182638968203
-pub const GENERATOR_Y: Fq =
-    MontFp!("16950150798460657717958625567821834550301663161624707787222815936182638968203");
+    /// Multiplies `base` with the little-endian limbs `scalar` in variable time. Use this only if the scalar is public, e.g., for signature verification.
+    ///
+    /// This uses the width-4 NAF of the scalar, which is several times faster than the ladder of [`Self::mul_ct`].
+    /// Multiples of the generator use the fixed-base multiplication, see [`Self::mul_generator`], which is even faster.
+    pub fn mul_vartime(base: &EdwardsProjective, scalar: &[u64]) -> EdwardsProjective {
+        if scalar.len() <= 4 && *base == EdwardsProjective::generator() {
+            return fixed_base::mul_generator(scalar);
+        }
+        wnaf::mul_vartime(base, scalar)
+    }
 
-// Helper functions for constant-time conditional swap and select, used in the montgomery ladder implementation.
-#[inline(always)]
-fn conditional_swap(a: &mut EdwardsProjective, b: &mut EdwardsProjective, c: bool) {
-    let mask = (c as u64).wrapping_neg(); // all 1s if c is true, all 0s if c is false
-    conditionally_swap_bigint(&mut a.x.0, &mut b.x.0, mask);
-    conditionally_swap_bigint(&mut a.y.0, &mut b.y.0, mask);
-    conditionally_swap_bigint(&mut a.z.0, &mut b.z.0, mask);
-    conditionally_swap_bigint(&mut a.t.0, &mut b.t.0, mask);
+    /// Multiplies the generator with `scalar` in constant time, using a precomputed table of multiples of the generator.
+    ///
+    /// This is several times faster than the ladder of [`Self::mul_ct`], which uses this method automatically if the base is the generator.
+    /// The table is generated by `scripts/generator_table.py` and compiled into the binary, so it works without `std` and without any initialization at runtime.
+    pub fn mul_generator(scalar: &Fr) -> EdwardsProjective {
+        fixed_base::mul_generator(&scalar.into_bigint().0)
+    }
 }
 
-#[inline(always)]
-fn conditional_select(a: &mut EdwardsProjective, b: &EdwardsProjective, c: bool) {
-    let mask = (c as u64).wrapping_neg(); // all 1s if c is true, all 0s if c is false
-    conditionally_select_bigint(&mut a.x.0, b.x.0, mask);
-    conditionally_select_bigint(&mut a.y.0, b.y.0, mask);
-    conditionally_select_bigint(&mut a.z.0, b.z.0, mask);
-    conditionally_select_bigint(&mut a.t.0, b.t.0, mask);
+/// Explicitly constant-time or variable-time scalar multiplication of points, see [`EdwardsConfig::mul_ct`] and [`EdwardsConfig::mul_vartime`].
+///
+/// The `*` operator of arkworks is constant time, i.e., `point * scalar` is the same as `point.mul_ct(&scalar)`.
+///
+/// This trait is sealed and implemented by [`EdwardsProjective`] and [`EdwardsAffine`].
+pub trait EdwardsScalarMul: private::Sealed {
+    /// Multiplies the point with `scalar` in constant time. Use this for secret scalars.
+    fn mul_ct(&self, scalar: &Fr) -> EdwardsProjective;
+
+    /// Multiplies the point with `scalar` in variable time. Use this only if the scalar is public.
+    fn mul_vartime(&self, scalar: &Fr) -> EdwardsProjective;
 }
 
-#[inline(always)]
-fn conditionally_select_bigint<const N: usize>(a: &mut BigInt<N>, b: BigInt<N>, mask: u64) {
-    // Since this is a compile-time constant N, the compiler should unroll this loop.
-    for i in 0..N {
-        a.0[i] ^= mask & (a.0[i] ^ b.0[i]);
+impl EdwardsScalarMul for EdwardsProjective {
+    fn mul_ct(&self, scalar: &Fr) -> EdwardsProjective {
+        EdwardsConfig::mul_ct(self, &scalar.into_bigint().0)
+    }
+
+    fn mul_vartime(&self, scalar: &Fr) -> EdwardsProjective {
+        EdwardsConfig::mul_vartime(self, &scalar.into_bigint().0)
     }
 }
 
-#[inline(always)]
-fn conditionally_swap_bigint<const N: usize>(a: &mut BigInt<N>, b: &mut BigInt<N>, mask: u64) {
-    // Since this is a compile-time constant N, the compiler should unroll this loop.
-    for i in 0..N {
-        let swap = mask & (a.0[i] ^ b.0[i]);
-        a.0[i] ^= swap;
-        b.0[i] ^= swap;
+impl EdwardsScalarMul for EdwardsAffine {
+    fn mul_ct(&self, scalar: &Fr) -> EdwardsProjective {
+        EdwardsProjective::from(*self).mul_ct(scalar)
+    }
+
+    fn mul_vartime(&self, scalar: &Fr) -> EdwardsProjective {
+        EdwardsProjective::from(*self).mul_vartime(scalar)
     }
 }
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::EdwardsProjective {}
+    impl Sealed for super::EdwardsAffine {}
+}
+
+/// Parameters for hashing to the curve with the Elligator 2 map on the Montgomery form, see
+/// [`Elligator2Map`](ark_ec::hashing::curve_maps::elligator2::Elligator2Map).
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 5, the non-square with the lowest absolute value as mandated by RFC 9380
+    /// (-1 is a square since q = 1 mod 4, so the smallest positive non-square is chosen).
+    const Z: Fq = MontFp!("5");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / 1^2 = 1
+    const ONE_OVER_COEFF_B_SQUARE: Fq = Fq::ONE;
+
+    /// COEFF_A_OVER_COEFF_B = 168698 / 1 = 168698
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("168698");
+}
+
+/// GENERATOR_X =
+/// 5299619240641551281634865583518297030282874472190772894086521144482721001553
+pub const GENERATOR_X: Fq =
+    MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553");
+
+/// GENERATOR_Y =
+/// 16950150798460657717958625567821834550301663161624707787222815936182638968203
+pub const GENERATOR_Y: Fq =
+    MontFp!("16950150798460657717958625567821834550301663161624707787222815936182638968203");
@@ -2,3 +2,141 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+
+#[test]
+fn elligator2_maps_to_curve() {
+    use ark_ec::AffineRepr;
+    use ark_ec::hashing::{curve_maps::elligator2::Elligator2Map, map_to_curve_hasher::MapToCurve};
+    use ark_std::{UniformRand, test_rng};
+
+    Elligator2Map::<EdwardsConfig>::check_parameters().expect("parameters are valid");
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let point = Elligator2Map::<EdwardsConfig>::map_to_curve(Fq::rand(&mut rng))
+            .expect("Elligator 2 is total");
+        assert!(point.is_on_curve());
+    }
+    // u = 0 maps to the Montgomery point (0, 0), which is an exceptional case of the rational map to the Edwards form and maps to the identity
+    let point =
+        Elligator2Map::<EdwardsConfig>::map_to_curve(Fq::from(0u64)).expect("Elligator 2 is total");
+    assert!(point.is_zero());
+}
+
+#[test]
+fn generator_table_is_correct() {
+    use super::generator_table::GENERATOR_TABLE;
+    use ark_ec::{AdditiveGroup, PrimeGroup};
+
+    let mut base = EdwardsProjective::generator();
+    for row in &GENERATOR_TABLE {
+        let mut point = base;
+        for entry in row {
+            assert_eq!(EdwardsProjective::from(*entry), point);
+            point += base;
+        }
+        for _ in 0..4 {
+            base.double_in_place();
+        }
+    }
+}
+
+#[test]
+fn mul_generator_matches_ladder() {
+    use ark_ec::{AdditiveGroup, AffineRepr, PrimeGroup, twisted_edwards::TECurveConfig};
+    use ark_ff::{PrimeField, Zero};
+    use ark_std::{UniformRand, test_rng};
+
+    // The ladder is used for every base except the generator, so compare with the ladder on 2 * G.
+    let generator = EdwardsProjective::generator();
+    let double = generator.double();
+    let mut rng = test_rng();
+    let edge_cases = [0u64, 1, 8, 15].map(Fr::from);
+    let random = (0..50).map(|_| Fr::rand(&mut rng));
+    for scalar in edge_cases
+        .into_iter()
+        .chain([-Fr::from(1u64)])
+        .chain(random)
+    {
+        let expected = EdwardsConfig::mul_projective(&double, &scalar.into_bigint().0);
+        assert_eq!(EdwardsConfig::mul_generator(&scalar).double(), expected);
+        assert_eq!((generator * scalar).double(), expected);
+        assert_eq!((EdwardsAffine::generator() * scalar).double(), expected);
+    }
+
+    // mul_projective also accepts unreduced scalars
+    let mut limbs = [u64::MAX; 4];
+    assert_eq!(
+        EdwardsConfig::mul_projective(&generator, &limbs).double(),
+        EdwardsConfig::mul_projective(&double, &limbs)
+    );
+    limbs = Fr::MODULUS.0;
+    assert!(EdwardsConfig::mul_projective(&generator, &limbs).is_zero());
+    assert_eq!(
+        EdwardsConfig::mul_projective(&generator, &[3]),
+        generator + generator + generator
+    );
+}
+
+#[test]
+fn mul_vartime_matches_mul_ct() {
+    use ark_ec::{AdditiveGroup, CurveGroup, PrimeGroup};
+    use ark_ff::PrimeField;
+    use ark_std::{UniformRand, test_rng};
+
+    let mut rng = test_rng();
+    // includes points outside of the prime-order subgroup
+    let small_order = EdwardsAffine::new_unchecked(Fq::from(0u64), -Fq::from(1u64));
+    for base in [
+        EdwardsProjective::generator(),
+        EdwardsProjective::rand(&mut rng),
+        EdwardsProjective::rand(&mut rng) + small_order,
+    ] {
+        for _ in 0..20 {
+            let scalar = Fr::rand(&mut rng);
+            let expected = EdwardsConfig::mul_ct(&base, &scalar.into_bigint().0);
+            assert_eq!(base.mul_vartime(&scalar), expected);
+            assert_eq!(base.mul_ct(&scalar), expected);
+            assert_eq!(base.into_affine().mul_vartime(&scalar), expected);
+            assert_eq!(base.into_affine().mul_ct(&scalar), expected);
+            assert_eq!(base * scalar, expected);
+        }
+        for limbs in [
+            &[][..],
+            &[0],
+            &[1],
+            &[8],
+            &[u64::MAX; 4],
+            &[u64::MAX, 0, 7, u64::MAX, 3],
+        ] {
+            assert_eq!(
+                EdwardsConfig::mul_vartime(&base, limbs),
+                EdwardsConfig::mul_ct(&base, limbs)
+            );
+        }
+        assert_eq!(
+            EdwardsConfig::mul_vartime(&base, &[2]),
+            base.double(),
+            "2 * base"
+        );
+    }
+}
+
+#[test]
+fn subgroup_check() {
+    use ark_ec::CurveGroup;
+    use ark_std::{UniformRand, test_rng};
+
+    let mut rng = test_rng();
+    let small_order = EdwardsAffine::new_unchecked(Fq::from(0u64), -Fq::from(1u64));
+    for _ in 0..10 {
+        let point = EdwardsProjective::rand(&mut rng).into_affine();
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        assert!(
+            !(point + small_order)
+                .into_affine()
+                .is_in_correct_subgroup_assuming_on_curve()
+        );
+    }
+    assert!(EdwardsAffine::zero().is_in_correct_subgroup_assuming_on_curve());
+    assert!(!small_order.is_in_correct_subgroup_assuming_on_curve());
+}
@@ -0,0 +1,100 @@
+//! Variable-time scalar multiplication with the width-4 non-adjacent form (wNAF) of the scalar.
+//!
+//! The scalar is recoded into digits that are either zero or odd in `(-8, 8)`, where at most one of any 4 consecutive digits is non-zero.
+//! With a table of the odd multiples `base, 3 * base, ..., 7 * base`, the multiplication needs one doubling per bit and one addition per non-zero digit, i.e., about a fifth of the bits.
+//! The running time depends on the scalar, so this must only be used with public scalars.
+
+use ark_ec::AdditiveGroup;
+use ark_ff::Zero;
+use ark_std::vec::Vec;
+
+use super::EdwardsProjective;
+
+/// The width of the NAF.
+const WINDOW: u32 = 4;
+
+/// Computes `scalar * base` for little-endian limbs, in variable time.
+pub(super) fn mul_vartime(base: &EdwardsProjective, scalar: &[u64]) -> EdwardsProjective {
+    // table[i] = (2i + 1) * base
+    let double = base.double();
+    let mut table = [*base; 1 << (WINDOW - 2)];
+    for i in 1..table.len() {
+        table[i] = table[i - 1] + double;
+    }
+
+    let mut acc = EdwardsProjective::zero();
+    for digit in wnaf(scalar).into_iter().rev() {
+        acc.double_in_place();
+        if digit > 0 {
+            acc += &table[usize::from(digit.unsigned_abs() / 2)];
+        } else if digit < 0 {
+            acc -= &table[usize::from(digit.unsigned_abs() / 2)];
+        }
+    }
+    acc
+}
+
+/// Returns the width-4 NAF of the scalar, least significant digit first.
+fn wnaf(scalar: &[u64]) -> Vec<i8> {
+    // One additional limb, such that adding the absolute value of a negative digit cannot overflow.
+    let mut e = scalar.to_vec();
+    e.push(0);
+    let mut digits = Vec::with_capacity(64 * e.len());
+    while e.iter().any(|limb| *limb != 0) {
+        let digit = if e[0] & 1 == 1 {
+            // the signed residue of e mod 2^WINDOW, which is odd and in (-2^(WINDOW - 1), 2^(WINDOW - 1))
+            let residue = (e[0] & ((1 << WINDOW) - 1)) as i8;
+            let digit = if residue >= 1 << (WINDOW - 1) {
+                residue - (1 << WINDOW)
+            } else {
+                residue
+            };
+            // e -= digit, such that the next WINDOW - 1 digits are zero
+            if digit > 0 {
+                sub_small(&mut e, u64::from(digit.unsigned_abs()));
+            } else {
+                add_small(&mut e, u64::from(digit.unsigned_abs()));
+            }
+            digit
+        } else {
+            0
+        };
+        digits.push(digit);
+        shr1(&mut e);
+    }
+    digits
+}
+
+/// Subtracts `x` from the little-endian limbs `e`, which must be at least `x`.
+fn sub_small(e: &mut [u64], mut x: u64) {
+    for limb in e {
+        let (result, borrow) = limb.overflowing_sub(x);
+        *limb = result;
+        if !borrow {
+            return;
+        }
+        x = 1;
+    }
+}
+
+/// Adds `x` to the little-endian limbs `e`, which must not overflow.
+fn add_small(e: &mut [u64], mut x: u64) {
+    for limb in e {
+        let (result, carry) = limb.overflowing_add(x);
+        *limb = result;
+        if !carry {
+            return;
+        }
+        x = 1;
+    }
+}
+
+/// Shifts the little-endian limbs `e` right by one bit.
+fn shr1(e: &mut [u64]) {
+    let mut carry = 0;
+    for limb in e.iter_mut().rev() {
+        let next_carry = *limb << 63;
+        *limb = (*limb >> 1) | carry;
+        carry = next_carry;
+    }
+}
@@ -24,8 +24,10 @@
 
 #[cfg(feature = "r1cs")]
 pub mod constraints;
+mod ct;
 mod curves;
 mod fields;
 
+pub use ct::*;
 pub use curves::*;
 pub use fields::*;
@@ -0,0 +1,48 @@
+//! Runs the valgrind secret-marking check on all targets, see [`ct_audit::ctgrind`].
+//!
+//! Usage: `valgrind target/profiling/ctgrind [--canary]`, after `cargo build --profile profiling -p ct-audit --bin ctgrind`.
+//! The [`Canary`] is checked before the targets, with `--canary` only the canary is checked.
+//! Do not pass `--error-exitcode` to valgrind, since the canary is supposed to produce errors. The exit status reflects the targets instead.
+
+use std::process::ExitCode;
+
+use ct_audit::{
+    Harness,
+    ctgrind::{Canary, Ctgrind, running_on_valgrind},
+};
+
+fn main() -> ExitCode {
+    if !running_on_valgrind() {
+        eprintln!(
+            "ctgrind must run under valgrind on x86-64, e.g.:\n  cargo build --profile profiling -p ct-audit --bin ctgrind\n  valgrind target/profiling/ctgrind"
+        );
+        return ExitCode::FAILURE;
+    }
+    let canary_only = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--canary") => true,
+        Some(_) => {
+            eprintln!("usage: ctgrind [--canary]");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut harness = Ctgrind::new();
+    let canary = harness.check(&Canary::default());
+    if canary.passed {
+        eprintln!(
+            "ctgrind: the canary was not detected, so valgrind does not recognize the client requests and the audit is meaningless"
+        );
+        return ExitCode::FAILURE;
+    }
+    println!("ctgrind: the canary was detected ({})", canary.detail);
+    if canary_only {
+        return ExitCode::SUCCESS;
+    }
+
+    println!(
+        "ctgrind: secret-marking check with valgrind, FAIL on any secret-dependent branch or memory access"
+    );
+    let reports = ct_audit::targets::check_all(&mut harness);
+    ct_audit::print_reports(&reports, ct_audit::targets::KNOWN_FAILURES)
+}
@@ -0,0 +1,26 @@
+//! Runs the dudect timing test on all targets, see [`ct_audit::dudect`].
+//!
+//! Usage: `dudect [MEASUREMENTS]`, where `MEASUREMENTS` is the number of measurements per target (default 20000).
+//! Build with `--release`, otherwise the measurements are dominated by unoptimized code.
+
+use std::process::ExitCode;
+
+use ct_audit::dudect::{Dudect, THRESHOLD};
+
+const DEFAULT_MEASUREMENTS: usize = 20_000;
+
+fn main() -> ExitCode {
+    let measurements = match std::env::args().nth(1).map(|arg| arg.parse()) {
+        None => DEFAULT_MEASUREMENTS,
+        Some(Ok(measurements)) if measurements >= 100 => measurements,
+        Some(_) => {
+            eprintln!("usage: dudect [MEASUREMENTS], with at least 100 measurements");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "dudect: fixed-vs-random timing test with {measurements} measurements per target, FAIL if max |t| > {THRESHOLD}"
+    );
+    let reports = ct_audit::targets::check_all(&mut Dudect::new(measurements));
+    ct_audit::print_reports(&reports, ct_audit::targets::KNOWN_FAILURES)
+}
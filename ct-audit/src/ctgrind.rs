@@ -0,0 +1,174 @@
+//! A secret-marking check with valgrind in the style of ctgrind.
+//!
+//! The memcheck tool of valgrind tracks which bits of memory and registers are undefined and reports an error whenever a conditional branch or a memory address depends on undefined bits.
+//! Marking the secret as undefined with [`poison`] before running the target thus reports every secret-dependent branch and memory access, no matter how small the timing difference is.
+//! The output is marked as defined with [`unpoison`] afterwards, since it is public.
+//! The target fails if valgrind reported any error while running it. Valgrind prints the location of each error, use `--track-origins=yes` to also see where the undefined value came from.
+//!
+//! The client requests are only implemented for x86-64, on other architectures (and outside of valgrind) they do nothing.
+//! Since a harness whose client requests are not recognized reports nothing, the [`Canary`] with a deliberately secret-dependent branch is checked first: the audit is only meaningful if the canary fails.
+
+#![allow(unsafe_code, reason = "valgrind client requests need inline assembly")]
+
+use std::hint::black_box;
+
+use rand::{CryptoRng, Rng, SeedableRng, rngs::StdRng};
+
+use crate::{Harness, Report, Target};
+
+/// `VG_USERREQ__RUNNING_ON_VALGRIND` of `valgrind.h`.
+const RUNNING_ON_VALGRIND: usize = 0x1001;
+/// `VG_USERREQ__COUNT_ERRORS` of `valgrind.h`.
+const COUNT_ERRORS: usize = 0x1201;
+/// `VG_USERREQ__MAKE_MEM_UNDEFINED` of `memcheck.h`.
+const MAKE_MEM_UNDEFINED: usize = 0x4D43_0001;
+/// `VG_USERREQ__MAKE_MEM_DEFINED` of `memcheck.h`.
+const MAKE_MEM_DEFINED: usize = 0x4D43_0002;
+
+/// The ctgrind harness, see the [module documentation](self).
+#[derive(Debug)]
+pub struct Ctgrind {
+    rng: StdRng,
+}
+
+impl Ctgrind {
+    /// Creates the harness.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl Default for Ctgrind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness for Ctgrind {
+    fn check<T: Target>(&mut self, target: &T) -> Report {
+        // Run once without marking, such that one-time initialization (e.g., CPU feature detection) is not attributed to the secret.
+        let secret = target.random_secret(&mut self.rng);
+        black_box(target.run(&secret));
+
+        let secret = target.random_secret(&mut self.rng);
+        let before = count_errors();
+        poison(&secret);
+        let output = target.run(&secret);
+        unpoison(&output);
+        unpoison(&secret);
+        let errors = count_errors() - before;
+        black_box(output);
+
+        Report {
+            target: target.name(),
+            passed: errors == 0,
+            detail: format!("{errors} secret-dependent branches or memory accesses"),
+        }
+    }
+}
+
+/// A target with a loop whose number of iterations depends on the secret, which valgrind must report.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct Canary;
+
+impl Target for Canary {
+    type Secret = u64;
+    type Output = u64;
+
+    fn name(&self) -> &'static str {
+        "canary (secret-dependent loop)"
+    }
+
+    fn fixed_secret(&self) -> u64 {
+        0
+    }
+
+    fn random_secret<R: Rng + CryptoRng>(&self, rng: &mut R) -> u64 {
+        rng.next_u64()
+    }
+
+    fn run(&self, secret: &u64) -> u64 {
+        let mut acc = 0;
+        // A loop cannot be compiled into a conditional move, so this is a secret-dependent branch.
+        for _ in 0..black_box(*secret) % 16 {
+            acc = black_box(acc + 1);
+        }
+        acc
+    }
+}
+
+/// Returns whether the program runs under valgrind.
+#[must_use]
+pub fn running_on_valgrind() -> bool {
+    client_request(0, [RUNNING_ON_VALGRIND, 0, 0, 0, 0, 0]) != 0
+}
+
+/// Returns the number of errors valgrind reported so far.
+#[must_use]
+pub fn count_errors() -> usize {
+    client_request(0, [COUNT_ERRORS, 0, 0, 0, 0, 0])
+}
+
+/// Marks the memory of `value` as undefined, such that valgrind reports every branch and memory access that depends on it.
+pub fn poison<T>(value: &T) {
+    client_request(
+        0,
+        [
+            MAKE_MEM_UNDEFINED,
+            core::ptr::from_ref(value).addr(),
+            size_of_val(value),
+            0,
+            0,
+            0,
+        ],
+    );
+}
+
+/// Marks the memory of `value` as defined again.
+pub fn unpoison<T>(value: &T) {
+    client_request(
+        0,
+        [
+            MAKE_MEM_DEFINED,
+            core::ptr::from_ref(value).addr(),
+            size_of_val(value),
+            0,
+            0,
+            0,
+        ],
+    );
+}
+
+/// Issues a valgrind client request and returns its result, or `default` if not running under valgrind.
+///
+/// This is `VALGRIND_DO_CLIENT_REQUEST_EXPR` of `valgrind.h` for amd64: valgrind recognizes the special instruction sequence, reads the request from the array pointed to by `rax`
+/// and writes the result to `rdx`. On a real CPU the sequence does nothing, since the rotations of `rdi` add up to 128 bits and `xchg rbx, rbx` is a no-op.
+#[cfg(target_arch = "x86_64")]
+fn client_request(default: usize, args: [usize; 6]) -> usize {
+    let result;
+    // SAFETY: The instructions only rotate rdi by a multiple of 64 bits and exchange rbx with itself, so no register or memory is changed outside of valgrind.
+    // Under valgrind, the request only reads `args`, which outlives the call, and changes valgrind's bookkeeping of the memory, but not the memory itself.
+    unsafe {
+        core::arch::asm!(
+            "rol rdi, 3",
+            "rol rdi, 13",
+            "rol rdi, 61",
+            "rol rdi, 51",
+            "xchg rbx, rbx",
+            in("rax") args.as_ptr(),
+            inlateout("rdx") default => result,
+            options(nostack),
+        );
+    }
+    result
+}
+
+/// Client requests are not implemented on this architecture and always return `default`.
+#[cfg(not(target_arch = "x86_64"))]
+fn client_request(default: usize, _args: [usize; 6]) -> usize {
+    default
+}
@@ -0,0 +1,181 @@
+//! A statistical timing test in the style of dudect.
+//!
+//! For each measurement, the secret is chosen at random from one of two classes: a fixed secret or a uniformly random secret.
+//! If the running time does not depend on the secret, both classes have the same distribution of running times.
+//! Welch's t-test compares the means of both classes, once for all measurements and once for each of several upper percentiles of the running time,
+//! which removes the long tail caused by interrupts and other noise.
+//! The target fails if the largest absolute t-statistic exceeds [`THRESHOLD`].
+//!
+//! A pass does not prove constant time: it only means that no difference was measurable with the given number of measurements on this machine.
+
+use std::{hint::black_box, time::Instant};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{Harness, Report, Target};
+
+/// The absolute t-statistic above which a target is considered not constant time, as in dudect.
+pub const THRESHOLD: f64 = 4.5;
+
+/// The absolute t-statistic above which a target is definitely not constant time, as in dudect.
+const DEFINITE_THRESHOLD: f64 = 10.0;
+
+/// The number of percentiles at which the measurements are cropped.
+const PERCENTILES: u32 = 100;
+
+/// The dudect harness, see the [module documentation](self).
+#[derive(Debug)]
+pub struct Dudect {
+    measurements: usize,
+    rng: StdRng,
+}
+
+impl Dudect {
+    /// Creates a harness that takes the given number of measurements per target.
+    #[must_use]
+    pub fn new(measurements: usize) -> Self {
+        Self {
+            measurements,
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl Harness for Dudect {
+    fn check<T: Target>(&mut self, target: &T) -> Report {
+        // Prepare all inputs in advance, such that only the target is measured.
+        let classes = (0..self.measurements)
+            .map(|_| self.rng.r#gen::<bool>())
+            .collect::<Vec<_>>();
+        let secrets = classes
+            .iter()
+            .map(|fixed| {
+                if *fixed {
+                    target.fixed_secret()
+                } else {
+                    target.random_secret(&mut self.rng)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Warm up caches and branch predictors.
+        for secret in secrets.iter().take(self.measurements / 10) {
+            black_box(target.run(black_box(secret)));
+        }
+        let times = secrets
+            .iter()
+            .map(|secret| {
+                let start = Instant::now();
+                let output = target.run(black_box(secret));
+                let time = start.elapsed().as_secs_f64();
+                black_box(output);
+                time
+            })
+            .collect::<Vec<_>>();
+
+        let t = max_t(&classes, &times);
+        let detail = format!(
+            "max |t| = {t:.2} over {} measurements{}",
+            self.measurements,
+            if t > DEFINITE_THRESHOLD {
+                ", definitely not constant time"
+            } else if t > THRESHOLD {
+                ", probably not constant time"
+            } else {
+                ""
+            }
+        );
+        Report {
+            target: target.name(),
+            passed: t <= THRESHOLD,
+            detail,
+        }
+    }
+}
+
+/// Returns the largest absolute t-statistic of all measurements and of the measurements below each cropping percentile.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    reason = "the percentile is in [0, 1), so the index is in range"
+)]
+fn max_t(classes: &[bool], times: &[f64]) -> f64 {
+    let mut sorted = times.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    // The same percentiles as dudect, which are concentrated at the upper end.
+    let thresholds = (0..PERCENTILES).map(|i| {
+        let p = 1.0 - 0.5f64.powf(10.0 * f64::from(i + 1) / f64::from(PERCENTILES));
+        sorted[((p * sorted.len() as f64) as usize).min(sorted.len() - 1)]
+    });
+    core::iter::once(f64::INFINITY)
+        .chain(thresholds)
+        .map(|threshold| {
+            let mut stats = [Welford::default(), Welford::default()];
+            for (fixed, time) in classes.iter().zip(times) {
+                if *time <= threshold {
+                    stats[usize::from(*fixed)].push(*time);
+                }
+            }
+            welch_t(&stats[0], &stats[1]).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Welch's t-statistic of two samples, or zero if a sample is too small.
+fn welch_t(a: &Welford, b: &Welford) -> f64 {
+    if a.n < 2.0 || b.n < 2.0 {
+        return 0.0;
+    }
+    let se = (a.variance() / a.n + b.variance() / b.n).sqrt();
+    if se == 0.0 {
+        return 0.0;
+    }
+    (a.mean - b.mean) / se
+}
+
+/// The online mean and variance of a sample with Welford's algorithm.
+#[derive(Debug, Default)]
+struct Welford {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn welch_t_detects_different_means() {
+        let classes = (0..1000).map(|i| i % 2 == 0).collect::<Vec<_>>();
+        let jitter = |i: i32| f64::from(i % 7);
+        let equal = (0..1000).map(jitter).collect::<Vec<_>>();
+        let shifted = classes
+            .iter()
+            .zip(0..)
+            .map(|(fixed, i)| jitter(i) + if *fixed { 1.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+        assert!(
+            max_t(&classes, &equal) < THRESHOLD,
+            "equal distributions should pass"
+        );
+        assert!(
+            max_t(&classes, &shifted) > DEFINITE_THRESHOLD,
+            "shifted distributions should fail"
+        );
+    }
+}
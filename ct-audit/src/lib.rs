@@ -0,0 +1,98 @@
+//! Audits the code paths of the Baby Jubjub crates that handle secrets for constant-time behavior.
+//!
+//! The constant-time scalar multiplication of `taceo-ark-babyjubjub` relies on hand-written masks instead of branches, which the compiler is free to undo.
+//! This crate checks the compiled code of the [`targets`] in two complementary ways, each as a binary that prints a pass/fail report and exits with a non-zero status if any target fails that is not in [`targets::KNOWN_FAILURES`]:
+//!
+//! - `dudect` ([`dudect::Dudect`]) measures the running time for a fixed secret and for random secrets and compares both distributions with Welch's t-test,
+//!   following "Dude, is my code constant time?" by Reparaz, Balasch and Verbauwhede. It runs anywhere, but only detects leaks that are large enough to be measured.
+//! - `ctgrind` ([`ctgrind::Ctgrind`]) marks the secret as undefined memory for the memcheck tool of valgrind, which then reports every branch and memory access that depends on the secret,
+//!   following ctgrind by Adam Langley. It finds leaks independently of their size, but requires valgrind on x86-64.
+//!
+//! Run both with `just ct-audit`, see the README for details.
+
+use std::process::ExitCode;
+
+use rand::{CryptoRng, Rng};
+
+pub mod ctgrind;
+pub mod dudect;
+pub mod targets;
+
+/// An operation on a secret, which must run in constant time.
+pub trait Target {
+    /// The secret input of the operation.
+    type Secret;
+    /// The output of the operation, which is public.
+    type Output;
+
+    /// The name of the target in the report.
+    fn name(&self) -> &'static str;
+
+    /// A fixed secret, which should trigger special cases of the implementation if there are any, e.g., zero.
+    fn fixed_secret(&self) -> Self::Secret;
+
+    /// A uniformly random secret.
+    fn random_secret<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::Secret;
+
+    /// Runs the operation.
+    fn run(&self, secret: &Self::Secret) -> Self::Output;
+}
+
+/// A check of a [`Target`] for constant-time behavior.
+pub trait Harness {
+    /// Checks the target and returns the result.
+    fn check<T: Target>(&mut self, target: &T) -> Report;
+}
+
+/// The result of checking a single [`Target`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Report {
+    /// The name of the target.
+    pub target: &'static str,
+    /// Whether the target passed the check.
+    pub passed: bool,
+    /// A human-readable summary of the measurement.
+    pub detail: String,
+}
+
+/// Prints the reports, one line per target, and returns [`ExitCode::FAILURE`] if any target failed that is not in `known_failures`.
+///
+/// Known failures are reported as `XFAIL`, such that the audit only fails on regressions. A known failure that passes is reported as `XPASS`, which means it should be removed from the list.
+#[must_use]
+pub fn print_reports(reports: &[Report], known_failures: &[&str]) -> ExitCode {
+    let width = reports.iter().map(|r| r.target.len()).max().unwrap_or(0);
+    let mut regressions = 0;
+    for report in reports {
+        let known = known_failures.contains(&report.target);
+        let verdict = match (report.passed, known) {
+            (true, false) => "PASS",
+            (true, true) => "XPASS",
+            (false, true) => "XFAIL",
+            (false, false) => {
+                regressions += 1;
+                "FAIL"
+            }
+        };
+        println!(
+            "  {verdict:<5}  {:<width$}  {}",
+            report.target, report.detail
+        );
+    }
+    let passed = reports.iter().filter(|r| r.passed).count();
+    println!(
+        "{passed} of {} targets passed, {regressions} unexpected failures",
+        reports.len()
+    );
+    if reports
+        .iter()
+        .any(|r| r.passed && known_failures.contains(&r.target))
+    {
+        println!("XPASS targets pass now, remove them from the known failures");
+    }
+    if regressions == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,135 @@
+//! The audited operations, see [`check_all`].
+
+use ark_babyjubjub::{EdwardsConfig, EdwardsProjective, Fq, Fr};
+use ark_ec::{AdditiveGroup, PrimeGroup, twisted_edwards::TECurveConfig};
+use ark_ff::{PrimeField, UniformRand};
+use eddsa_babyjubjub::{EdDSAPrivateKey, EdDSASignature};
+use rand::{CryptoRng, Rng};
+
+use crate::{Harness, Report, Target};
+
+/// The targets that are known to fail both checks, which [`print_reports`](crate::print_reports) does not count as a failure.
+///
+/// The field arithmetic of `ark-ff` underneath all targets ends with a conditional subtraction of the modulus, which is a branch on the value, see the README.
+/// A target must be removed from this list once it passes, such that it cannot regress unnoticed.
+pub const KNOWN_FAILURES: &[&str] = &[
+    "mul_projective (ladder)",
+    "mul_projective (generator)",
+    "EdDSAPrivateKey::sign",
+    "derive_sk",
+];
+
+/// Checks all targets with the harness.
+pub fn check_all<H: Harness>(harness: &mut H) -> Vec<Report> {
+    vec![
+        harness.check(&MulProjective::ladder()),
+        harness.check(&MulProjective::generator()),
+        harness.check(&Sign::default()),
+        harness.check(&DeriveSk),
+    ]
+}
+
+/// [`EdwardsConfig::mul_projective`] of a public base with a secret scalar.
+///
+/// The scalar is passed as limbs, such that the conversion out of the Montgomery form is not part of the measurement.
+#[derive(Debug, Clone)]
+pub struct MulProjective {
+    name: &'static str,
+    base: EdwardsProjective,
+}
+
+impl MulProjective {
+    /// A base other than the generator, which uses the Montgomery ladder.
+    #[must_use]
+    pub fn ladder() -> Self {
+        Self {
+            name: "mul_projective (ladder)",
+            base: EdwardsProjective::generator().double(),
+        }
+    }
+
+    /// The generator as base, which uses the fixed-base multiplication with the precomputed table.
+    #[must_use]
+    pub fn generator() -> Self {
+        Self {
+            name: "mul_projective (generator)",
+            base: EdwardsProjective::generator(),
+        }
+    }
+}
+
+impl Target for MulProjective {
+    type Secret = [u64; 4];
+    type Output = EdwardsProjective;
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn fixed_secret(&self) -> [u64; 4] {
+        [0; 4]
+    }
+
+    fn random_secret<R: Rng + CryptoRng>(&self, rng: &mut R) -> [u64; 4] {
+        Fr::rand(rng).into_bigint().0
+    }
+
+    fn run(&self, secret: &[u64; 4]) -> EdwardsProjective {
+        EdwardsConfig::mul_projective(&self.base, secret)
+    }
+}
+
+/// [`EdDSAPrivateKey::sign`] of a public message with a secret key.
+#[derive(Debug, Clone, Default)]
+pub struct Sign {
+    message: Fq,
+}
+
+impl Target for Sign {
+    type Secret = EdDSAPrivateKey;
+    type Output = EdDSASignature;
+
+    fn name(&self) -> &'static str {
+        "EdDSAPrivateKey::sign"
+    }
+
+    fn fixed_secret(&self) -> EdDSAPrivateKey {
+        EdDSAPrivateKey::from_bytes([0; 32])
+    }
+
+    fn random_secret<R: Rng + CryptoRng>(&self, rng: &mut R) -> EdDSAPrivateKey {
+        EdDSAPrivateKey::random(rng)
+    }
+
+    fn run(&self, secret: &EdDSAPrivateKey) -> EdDSASignature {
+        secret.sign(self.message)
+    }
+}
+
+/// The derivation of the secret scalar from the Blake3 hash of the private key, which is private to `taceo-eddsa-babyjubjub` and exposed by its `ct-audit` feature.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DeriveSk;
+
+impl Target for DeriveSk {
+    type Secret = [u8; 64];
+    type Output = Fr;
+
+    fn name(&self) -> &'static str {
+        "derive_sk"
+    }
+
+    fn fixed_secret(&self) -> [u8; 64] {
+        [0; 64]
+    }
+
+    fn random_secret<R: Rng + CryptoRng>(&self, rng: &mut R) -> [u8; 64] {
+        let mut secret = [0; 64];
+        rng.fill_bytes(&mut secret);
+        secret
+    }
+
+    fn run(&self, secret: &[u8; 64]) -> Fr {
+        EdDSAPrivateKey::derive_sk_for_audit(secret)
+    }
+}
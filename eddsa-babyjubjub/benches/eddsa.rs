@@ -0,0 +1,29 @@
+//! Benchmarks for the `EdDSA` signature scheme.
+
+use ark_ff::UniformRand;
+use criterion::{Criterion, criterion_group, criterion_main};
+use taceo_eddsa_babyjubjub::EdDSAPrivateKey;
+
+type BaseField = ark_babyjubjub::Fq;
+
+fn eddsa_bench(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let sk = EdDSAPrivateKey::random(&mut rng);
+    let message = BaseField::rand(&mut rng);
+
+    c.bench_function("EdDSA Key Generation", |b| {
+        b.iter(|| std::hint::black_box(&sk).public());
+    });
+    c.bench_function("EdDSA Sign", |b| {
+        b.iter(|| std::hint::black_box(&sk).sign(std::hint::black_box(message)));
+    });
+
+    let pk = sk.public();
+    let signature = sk.sign(message);
+    c.bench_function("EdDSA Verify", |b| {
+        b.iter(|| std::hint::black_box(&pk).verify(std::hint::black_box(message), &signature));
+    });
+}
+
+criterion_group!(benches, eddsa_bench);
+criterion_main!(benches);
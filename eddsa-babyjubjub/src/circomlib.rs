@@ -0,0 +1,295 @@
+//! `EdDSA` signatures compatible with circomlib's `EdDSAPoseidonVerifier`, circomlibjs and zk-kit's `eddsa-poseidon`.
+//!
+//! The signatures of the crate root use Poseidon2 and Blake3 and therefore do not verify with circuits deployed against circomlib.
+//! This module implements the original scheme instead, on the same key and signature types:
+//! 1. The private key is expanded with BLAKE-512 to `h`. The first 32 bytes of `h` are pruned (as in [`EdDSAPrivateKey`]) to the scalar `s`, and the public key is `A = (s >> 3) * B8`, where `B8` is the generator of the prime-order subgroup.
+//! 2. The nonce is `r = BLAKE-512(h[32..64] || m) mod L`, where the message `m` is encoded as 32 bytes little-endian, and `R8 = r * B8`.
+//! 3. The challenge is the circomlib Poseidon hash `c = Poseidon(R8.x, R8.y, A.x, A.y, m)` (see [`poseidon2::poseidon1`]), and `S = r + c * s mod L`.
+//! 4. A signature verifies if `S * B8 = R8 + 8c * A`.
+//!
+//! Consequently, the same [`EdDSAPrivateKey`] has a different public key in this mode than [`EdDSAPrivateKey::public`]. Keys and signatures of the two modes must not be mixed.
+//!
+//! Points and signatures are serialized with the circomlib point packing, see [`pack_point`] and [`pack_signature`].
+
+use ark_babyjubjub::{EdwardsConfig, EdwardsScalarMul};
+use ark_ec::{AdditiveGroup, AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ark_serialize::SerializationError;
+
+use crate::{
+    Affine, BaseField, EdDSAPrivateKey, EdDSAPublicKey, EdDSASignature, ScalarField,
+    convert_base_to_scalar,
+};
+
+mod blake512;
+
+use blake512::blake512;
+
+/// Expands the private key with BLAKE-512 and returns the pruned scalar `s` (not yet shifted) and the upper half of the hash, which seeds the nonce.
+fn expand_key(sk: &EdDSAPrivateKey) -> (ScalarField, [u8; 32]) {
+    let h = blake512(&sk.0);
+    let s = EdDSAPrivateKey::derive_sk(&h);
+    let mut nonce_seed = [0u8; 32];
+    nonce_seed.copy_from_slice(&h[32..64]);
+    (s, nonce_seed)
+}
+
+/// Returns `s >> 3` for the pruned scalar `s`, which is exact since the lowest three bits of `s` are cleared.
+fn shifted(s: ScalarField) -> ScalarField {
+    s * ScalarField::from(8u64)
+        .inverse()
+        .expect("8 is invertible mod L")
+}
+
+/// Derive the circomlib public key corresponding to the private key.
+///
+/// This corresponds to `prv2pub` of circomlibjs and `derivePublicKey` of zk-kit.
+#[must_use]
+pub fn public_key(sk: &EdDSAPrivateKey) -> EdDSAPublicKey {
+    let (s, _) = expand_key(sk);
+    let pk = (Affine::generator() * shifted(s)).into_affine();
+    EdDSAPublicKey { pk }
+}
+
+/// The challenge hash `Poseidon(R8.x, R8.y, A.x, A.y, m)` with the circomlib Poseidon parameters.
+fn challenge_hash(message: BaseField, nonce_r: Affine, pk: Affine) -> BaseField {
+    poseidon2::poseidon1::poseidon(&[nonce_r.x, nonce_r.y, pk.x, pk.y, message])
+        .expect("5 inputs are supported")
+}
+
+/// Sign a message (a `BaseField` element) such that the signature is identical to `signPoseidon` of circomlibjs.
+#[must_use]
+pub fn sign(sk: &EdDSAPrivateKey, message: BaseField) -> EdDSASignature {
+    let (s, nonce_seed) = expand_key(sk);
+    let pk = (Affine::generator() * shifted(s)).into_affine();
+
+    let mut nonce_input = [0u8; 64];
+    nonce_input[..32].copy_from_slice(&nonce_seed);
+    nonce_input[32..].copy_from_slice(&message.into_bigint().to_bytes_le());
+    let r = ScalarField::from_le_bytes_mod_order(&blake512(&nonce_input));
+    let nonce_r = (Affine::generator() * r).into_affine();
+
+    let c = convert_base_to_scalar(challenge_hash(message, nonce_r, pk));
+    EdDSASignature {
+        r: nonce_r,
+        s: r + c * s,
+    }
+}
+
+/// Verify a circomlib signature against the given message and public key.
+///
+/// This performs the same checks as `verifyPoseidon` of circomlibjs and the `EdDSAPoseidonVerifier` template of circomlib, i.e., the signature is accepted if `S * B8 = R8 + 8c * A`.
+/// In contrast to [`EdDSAPublicKey::verify`], the public key is only required to be on the curve and to have a non-zero x-coordinate (as enforced by the circuit), but it is not checked to be in the prime-order subgroup.
+#[must_use]
+pub fn verify(pk: &EdDSAPublicKey, message: BaseField, signature: &EdDSASignature) -> bool {
+    // `S < L` is guaranteed by the type of `signature.s`
+    if pk.pk.x.is_zero() || !pk.pk.is_on_curve() || !signature.r.is_on_curve() {
+        return false;
+    }
+    let challenge = challenge_hash(message, signature.r, pk.pk);
+
+    let mut pk8 = pk.pk.into_group();
+    pk8.double_in_place();
+    pk8.double_in_place();
+    pk8.double_in_place();
+    // All inputs are public, so the variable-time scalar multiplication can be used.
+    let right = EdwardsConfig::mul_vartime(&pk8, &challenge.into_bigint().0) + signature.r;
+    let left = Affine::generator().mul_vartime(&signature.s);
+    left == right
+}
+
+/// Pack a point into 32 bytes as in circomlib's `packPoint`.
+///
+/// The encoding is the y-coordinate in little-endian, where the most significant bit is set if the x-coordinate is larger than `(p - 1) / 2`.
+#[must_use]
+pub fn pack_point(point: &Affine) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&point.y.into_bigint().to_bytes_le());
+    if point.x > -point.x {
+        bytes[31] |= 0x80;
+    }
+    bytes
+}
+
+/// Unpack a point that was packed with [`pack_point`] or circomlib's `packPoint`.
+///
+/// # Errors
+/// Returns an error if the y-coordinate is not canonical or if there is no point on the curve with this y-coordinate.
+pub fn unpack_point(mut bytes: [u8; 32]) -> Result<Affine, SerializationError> {
+    let greatest = bytes[31] & 0x80 != 0;
+    bytes[31] &= 0x7F;
+    let y = BaseField::from_le_bytes_mod_order(&bytes);
+    if y.into_bigint().to_bytes_le() != bytes {
+        // the y-coordinate is not canonical
+        return Err(SerializationError::InvalidData);
+    }
+    Affine::get_point_from_y_unchecked(y, greatest).ok_or(SerializationError::InvalidData)
+}
+
+/// Pack a signature into 64 bytes as in circomlib's `packSignature`, i.e., the packed nonce point followed by `S` in little-endian.
+#[must_use]
+pub fn pack_signature(signature: &EdDSASignature) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&pack_point(&signature.r));
+    bytes[32..].copy_from_slice(&signature.s.into_bigint().to_bytes_le());
+    bytes
+}
+
+/// Unpack a signature that was packed with [`pack_signature`] or circomlib's `packSignature`.
+///
+/// # Errors
+/// Returns an error if the nonce point cannot be unpacked or if `S` is not smaller than the scalar field order.
+pub fn unpack_signature(bytes: [u8; 64]) -> Result<EdDSASignature, SerializationError> {
+    let mut r = [0u8; 32];
+    r.copy_from_slice(&bytes[..32]);
+    let r = unpack_point(r)?;
+    let s = ScalarField::from_le_bytes_mod_order(&bytes[32..]);
+    if s.into_bigint().to_bytes_le() != bytes[32..] {
+        // s is not smaller than the scalar field order
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(EdDSASignature { r, s })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::UniformRand;
+    use std::{fmt::Write, str::FromStr};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut s, b| {
+            write!(s, "{b:02x}").expect("writing to a string succeeds");
+            s
+        })
+    }
+
+    #[test]
+    fn circomlibjs_kat() {
+        // "Sign (using Poseidon) a single 10 bytes from 0 to 9" from the circomlibjs test suite
+        let seed = std::array::from_fn(|i| u8::try_from(i % 10).expect("digit fits in u8"));
+        assert_eq!(
+            hex(&seed),
+            "0001020304050607080900010203040506070809000102030405060708090001",
+            "private key of the test vector"
+        );
+        let sk = EdDSAPrivateKey::from_bytes(seed);
+        let message = BaseField::from_le_bytes_mod_order(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let pk = public_key(&sk);
+        assert_eq!(
+            pk.pk.x,
+            BaseField::from_str(
+                "13277427435165878497778222415993513565335242147425444199013288855685581939618"
+            )
+            .expect("Is in BaseField"),
+            "public key x-coordinate should match circomlibjs"
+        );
+        assert_eq!(
+            pk.pk.y,
+            BaseField::from_str(
+                "13622229784656158136036771217484571176836296686641868549125388198837476602820"
+            )
+            .expect("Is in BaseField"),
+            "public key y-coordinate should match circomlibjs"
+        );
+
+        let signature = sign(&sk, message);
+        assert_eq!(
+            signature.r.x,
+            BaseField::from_str(
+                "11384336176656855268977457483345535180380036354188103142384839473266348197733"
+            )
+            .expect("Is in BaseField"),
+            "nonce point x-coordinate should match circomlibjs"
+        );
+        assert_eq!(
+            signature.r.y,
+            BaseField::from_str(
+                "15383486972088797283337779941324724402501462225528836549661220478783371668959"
+            )
+            .expect("Is in BaseField"),
+            "nonce point y-coordinate should match circomlibjs"
+        );
+        assert_eq!(
+            signature.s,
+            ScalarField::from_str(
+                "1672775540645840396591609181675628451599263765380031905495115170613215233181"
+            )
+            .expect("Is in ScalarField"),
+            "signature scalar should match circomlibjs"
+        );
+        let packed = pack_signature(&signature);
+        assert_eq!(
+            hex(&packed),
+            "dfedb4315d3f2eb4de2d3c510d7a987dcab67089c8ace06308827bf5bcbe02a29d043ece562a8f82bfc0adb640c0107a7d3a27c1c7c1a6179a0da73de5c1b203",
+            "packed signature should match circomlibjs"
+        );
+        assert_eq!(
+            unpack_signature(packed).expect("valid packed signature"),
+            signature,
+            "signature should roundtrip through packing"
+        );
+        assert!(
+            verify(&pk, message, &signature),
+            "KAT signature should verify"
+        );
+    }
+
+    #[test]
+    fn sign_verify() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let sk = EdDSAPrivateKey::random(&mut rng);
+            let pk = public_key(&sk);
+            let message = BaseField::rand(&mut rng);
+            let signature = sign(&sk, message);
+            assert!(
+                verify(&pk, message, &signature),
+                "valid signature should verify"
+            );
+            assert!(
+                !verify(&pk, message + BaseField::from(1u64), &signature),
+                "signature over a different message should not verify"
+            );
+            assert!(
+                !verify(&sk.public(), message, &signature),
+                "signature should not verify with the Poseidon2 mode public key"
+            );
+            assert!(
+                !sk.public().verify(message, &signature),
+                "circomlib signature should not verify in the Poseidon2 mode"
+            );
+            assert_eq!(
+                unpack_point(pack_point(&pk.pk)).expect("valid packed point"),
+                pk.pk,
+                "public key should roundtrip through packing"
+            );
+        }
+    }
+
+    #[test]
+    fn unpack_rejects_invalid() {
+        // y = p is not canonical
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&BaseField::MODULUS.to_bytes_le());
+        assert!(
+            unpack_point(bytes).is_err(),
+            "non-canonical y-coordinate should be rejected"
+        );
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&pack_point(&Affine::generator()));
+        signature[32..].copy_from_slice(&ScalarField::MODULUS.to_bytes_le());
+        assert!(
+            unpack_signature(signature).is_err(),
+            "s >= L should be rejected"
+        );
+        // y = 2 has no corresponding x-coordinate
+        let mut y = [0u8; 32];
+        y[0] = 2;
+        assert!(
+            unpack_point(y).is_err(),
+            "y-coordinate without a point should be rejected"
+        );
+    }
+}
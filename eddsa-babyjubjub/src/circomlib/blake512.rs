@@ -0,0 +1,167 @@
+//! The BLAKE-512 hash function (the SHA-3 finalist, not `BLAKE2b`), as used by circomlib for the key expansion and the nonce.
+//!
+//! See <https://www.aumasson.jp/blake/blake.pdf> for the specification. Only the one-shot hash without salt is implemented.
+
+const BLOCK_LEN: usize = 128;
+
+const IV: [u64; 8] = [
+    0x6A09_E667_F3BC_C908,
+    0xBB67_AE85_84CA_A73B,
+    0x3C6E_F372_FE94_F82B,
+    0xA54F_F53A_5F1D_36F1,
+    0x510E_527F_ADE6_82D1,
+    0x9B05_688C_2B3E_6C1F,
+    0x1F83_D9AB_FB41_BD6B,
+    0x5BE0_CD19_137E_2179,
+];
+
+// The first digits of pi
+const C: [u64; 16] = [
+    0x243F_6A88_85A3_08D3,
+    0x1319_8A2E_0370_7344,
+    0xA409_3822_299F_31D0,
+    0x082E_FA98_EC4E_6C89,
+    0x4528_21E6_38D0_1377,
+    0xBE54_66CF_34E9_0C6C,
+    0xC0AC_29B7_C97C_50DD,
+    0x3F84_D5B5_B547_0917,
+    0x9216_D5D9_8979_FB1B,
+    0xD131_0BA6_98DF_B5AC,
+    0x2FFD_72DB_D01A_DFB7,
+    0xB8E1_AFED_6A26_7E96,
+    0xBA7C_9045_F12C_7F99,
+    0x24A1_9947_B391_6CF7,
+    0x0801_F2E2_858E_FC16,
+    0x6369_20D8_7157_4E69,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+const ROUNDS: usize = 16;
+
+/// The quarter-round function `G_i` on the columns (`i < 4`) and diagonals (`i >= 4`) of the state.
+#[allow(
+    clippy::many_single_char_names,
+    reason = "follows the notation of the specification"
+)]
+fn g(v: &mut [u64; 16], m: &[u64; 16], sigma: &[usize; 16], i: usize, idx: [usize; 4]) {
+    let [a, b, c, d] = idx;
+    let (s0, s1) = (sigma[2 * i], sigma[2 * i + 1]);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[s0] ^ C[s1]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(25);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[s1] ^ C[s0]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(11);
+}
+
+/// Compresses one block into the chain value `h`, where `counter` is the number of message bits up to and including this block.
+fn compress(h: &mut [u64; 8], block: &[u8], counter: u128) {
+    let mut m = [0u64; 16];
+    for (m, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *m = u64::from_be_bytes(chunk.try_into().expect("chunk has length 8"));
+    }
+    let counter = counter.to_be_bytes();
+    let t1 = u64::from_be_bytes(counter[..8].try_into().expect("slice has length 8"));
+    let t0 = u64::from_be_bytes(counter[8..].try_into().expect("slice has length 8"));
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..12].copy_from_slice(&C[..4]);
+    v[12] = t0 ^ C[4];
+    v[13] = t0 ^ C[5];
+    v[14] = t1 ^ C[6];
+    v[15] = t1 ^ C[7];
+
+    for round in 0..ROUNDS {
+        let sigma = &SIGMA[round % 10];
+        g(&mut v, &m, sigma, 0, [0, 4, 8, 12]);
+        g(&mut v, &m, sigma, 1, [1, 5, 9, 13]);
+        g(&mut v, &m, sigma, 2, [2, 6, 10, 14]);
+        g(&mut v, &m, sigma, 3, [3, 7, 11, 15]);
+        g(&mut v, &m, sigma, 4, [0, 5, 10, 15]);
+        g(&mut v, &m, sigma, 5, [1, 6, 11, 12]);
+        g(&mut v, &m, sigma, 6, [2, 7, 8, 13]);
+        g(&mut v, &m, sigma, 7, [3, 4, 9, 14]);
+    }
+
+    for (i, h) in h.iter_mut().enumerate() {
+        *h ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// Computes the BLAKE-512 hash of `input`.
+pub(super) fn blake512(input: &[u8]) -> [u8; 64] {
+    // Padding: a one bit, zeros, a one bit and the 128-bit message length in bits
+    let mut padded = input.to_vec();
+    padded.push(0x80);
+    while padded.len() % BLOCK_LEN != BLOCK_LEN - 16 {
+        padded.push(0);
+    }
+    *padded.last_mut().expect("padding is not empty") |= 0x01;
+    let len_bits = (input.len() as u128) * 8;
+    padded.extend_from_slice(&len_bits.to_be_bytes());
+
+    let mut h = IV;
+    for (i, block) in padded.chunks_exact(BLOCK_LEN).enumerate() {
+        // Blocks without any message bits use a counter of zero
+        let counter = if i * BLOCK_LEN < input.len() {
+            (((i + 1) * BLOCK_LEN).min(input.len()) as u128) * 8
+        } else {
+            0
+        };
+        compress(&mut h, block, counter);
+    }
+
+    let mut output = [0u8; 64];
+    for (chunk, h) in output.chunks_exact_mut(8).zip(h) {
+        chunk.copy_from_slice(&h.to_be_bytes());
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blake512;
+    use std::fmt::Write;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut s, b| {
+            write!(s, "{b:02x}").expect("writing to a string succeeds");
+            s
+        })
+    }
+
+    #[test]
+    fn blake512_kat() {
+        // Test vectors from the BLAKE specification and the reference implementation
+        assert_eq!(
+            hex(&blake512(&[])),
+            "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8",
+            "hash of the empty message"
+        );
+        assert_eq!(
+            hex(&blake512(&[0])),
+            "97961587f6d970faba6d2478045de6d1fabd09b61ae50932054d52bc29d31be4ff9102b9f69e2bbdb83be13d4b9c06091e5fa0b48bd081b634058be0ec49beb3",
+            "hash of a single zero byte"
+        );
+        assert_eq!(
+            hex(&blake512(&[0; 144])),
+            "313717d608e9cf758dcb1eb0f0c3cf9fc150b2d500fb33f51c52afc99d358a2f1374b8a38bba7974e7f6ef79cab16f22ce1e649d6e01ad9589c213045d545dde",
+            "hash of 144 zero bytes"
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! R1CS gadgets for verifying `EdDSA` signatures in-circuit.
+//!
+//! The constraint field is the base field of Baby Jubjub (i.e., the scalar field of BN254), such that the gadgets can be used in Groth16 proofs over BN254.
+//!
+//! [`EdDSAPublicKeyVar::verify`] enforces exactly the same checks as the native [`EdDSAPublicKey::verify`], i.e.,
+//! 1. `s` is smaller than the scalar field order `L`,
+//! 2. the public key `A` is not the identity and lies in the prime-order subgroup,
+//! 3. `A` and the nonce point `R` are on the curve (enforced during allocation),
+//! 4. the challenge is computed with the same Poseidon2 hash and reduced mod `L`,
+//! 5. `8(s*G - R - c*A) = 0`.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use ark_babyjubjub::constraints::{EdwardsVar, FqVar};
+use ark_ec::{AdditiveGroup, AffineRepr};
+use ark_ff::{Field, One, PrimeField};
+use ark_r1cs_std::{fields::fp::FpVar, prelude::*};
+use ark_relations::{
+    gr1cs::{Namespace, SynthesisError},
+    ns,
+};
+use num_bigint::BigUint;
+
+use crate::{Affine, BaseField, EdDSAPublicKey, EdDSASignature, ScalarField};
+
+type Projective = ark_babyjubjub::EdwardsProjective;
+
+/// The R1CS equivalent of [`EdDSAPublicKey`].
+#[derive(Debug, Clone)]
+#[allow(
+    clippy::exhaustive_structs,
+    reason = "Only wraps the inner point variable - not planned to add something"
+)]
+pub struct EdDSAPublicKeyVar {
+    /// The public key point on the curve.
+    pub pk: EdwardsVar,
+}
+
+/// The R1CS equivalent of [`EdDSASignature`].
+#[derive(Debug, Clone)]
+#[allow(
+    clippy::exhaustive_structs,
+    reason = "Only wraps the inner point variable and field variable - not planned to add something"
+)]
+pub struct EdDSASignatureVar {
+    /// The nonce point of the signature.
+    pub r: EdwardsVar,
+    /// The scalar part of the signature, represented as an element of the constraint field.
+    ///
+    /// This is not range checked during allocation, [`EdDSAPublicKeyVar::verify`] enforces that it is smaller than the scalar field order.
+    pub s: FqVar,
+}
+
+impl AllocVar<EdDSAPublicKey, BaseField> for EdDSAPublicKeyVar {
+    /// Allocates the public key, enforcing that it is on the curve.
+    ///
+    /// The subgroup check is deferred to [`EdDSAPublicKeyVar::verify`], such that an invalid public key leads to a rejected signature instead of a different point being allocated.
+    fn new_variable<T: Borrow<EdDSAPublicKey>>(
+        cs: impl Into<Namespace<BaseField>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let pk = EdwardsVar::new_variable_omit_prime_order_check(
+            ns!(cs, "pk"),
+            || f().map(|pk| pk.borrow().pk.into()),
+            mode,
+        )?;
+        Ok(Self { pk })
+    }
+}
+
+impl AllocVar<EdDSASignature, BaseField> for EdDSASignatureVar {
+    /// Allocates the signature, enforcing that the nonce point is on the curve.
+    fn new_variable<T: Borrow<EdDSASignature>>(
+        cs: impl Into<Namespace<BaseField>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let signature = f().map(|signature| signature.borrow().clone());
+        let r = EdwardsVar::new_variable_omit_prime_order_check(
+            ns!(cs, "r"),
+            || signature.clone().map(|signature| signature.r.into()),
+            mode,
+        )?;
+        // s < L < q, so s is always a valid base field element
+        let s = FqVar::new_variable(
+            ns!(cs, "s"),
+            || signature.map(|signature| BaseField::from(signature.s.into_bigint())),
+            mode,
+        )?;
+        Ok(Self { r, s })
+    }
+}
+
+impl EdDSAPublicKeyVar {
+    /// Enforce that the signature is valid for the given message and public key.
+    ///
+    /// The constraint system is satisfiable if and only if [`EdDSAPublicKey::verify`] accepts the signature, see the [module documentation](self) for the individual checks.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn verify(
+        &self,
+        message: &FqVar,
+        signature: &EdDSASignatureVar,
+    ) -> Result<(), SynthesisError> {
+        // 1. Enforce s in [0, L-1]
+        let (s_bits, _) = signature
+            .s
+            .to_bits_le_with_top_bits_zero(ScalarField::MODULUS_BIT_SIZE as usize)?;
+        Boolean::enforce_smaller_or_equal_than_le(&s_bits, (-ScalarField::one()).into_bigint())?;
+
+        // 2. Enforce that the public key A is not one of the 8 small order points.
+        self.pk.is_zero()?.enforce_equal(&Boolean::FALSE)?;
+        self.enforce_prime_order()?;
+
+        // 3. A and R are on the curve, which is enforced during allocation. They are canonical, since they consist of field elements.
+
+        // 4. Compute the hash and reduce it mod the scalar field order L
+        let challenge = challenge_hash_var(message, &signature.r, &self.pk)?;
+        let c_bits = convert_base_to_scalar_var(&challenge)?;
+
+        // 5. Enforce 8(s*G - R - c*Pk) = 0
+        let mut s_g = EdwardsVar::zero();
+        s_g.precomputed_base_scalar_mul_le(s_bits.iter().zip(generator_powers().iter()))?;
+        let c_pk = self.pk.scalar_mul_le(c_bits.iter())?;
+        let mut v = s_g - &signature.r - c_pk;
+        // multiply by the cofactor 8
+        v.double_in_place()?;
+        v.double_in_place()?;
+        v.double_in_place()?;
+        v.enforce_equal(&EdwardsVar::zero())
+    }
+
+    /// Enforce that the public key lies in the prime-order subgroup.
+    ///
+    /// The group of Baby Jubjub points has order 8*L, thus a point is in the subgroup of order L if and only if it is 8 times some other point.
+    /// We allocate that other point as a witness, which is much cheaper than multiplying by L.
+    fn enforce_prime_order(&self) -> Result<(), SynthesisError> {
+        let cs = self.pk.cs();
+        let mut p = EdwardsVar::new_variable_omit_prime_order_check(
+            ns!(cs, "pk_div_cofactor"),
+            || {
+                // `EdwardsVar::value` panics for points outside of the subgroup, so we construct the point manually
+                let pk = Affine::new_unchecked(self.pk.x.value()?, self.pk.y.value()?);
+                Ok(pk.mul_by_cofactor_inv().into())
+            },
+            AllocationMode::Witness,
+        )?;
+        p.double_in_place()?;
+        p.double_in_place()?;
+        p.double_in_place()?;
+        p.enforce_equal(&self.pk)
+    }
+}
+
+/// The R1CS equivalent of the challenge hash used by [`EdDSAPublicKey::verify`].
+///
+/// # Errors
+/// Returns an error if allocating the intermediate variables fails.
+pub fn challenge_hash_var(
+    message: &FqVar,
+    nonce_r: &EdwardsVar,
+    pk: &EdwardsVar,
+) -> Result<FqVar, SynthesisError> {
+    let state = [
+        FpVar::constant(EdDSASignature::get_chall_ds()), // Domain separator in capacity element
+        nonce_r.x.clone(),
+        nonce_r.y.clone(),
+        pk.x.clone(),
+        pk.y.clone(),
+        message.clone(),
+        FpVar::zero(),
+        FpVar::zero(),
+    ];
+    let [_, challenge, ..] = poseidon2::constraints::bn254::t8::permutation_var(&state)?;
+    Ok(challenge)
+}
+
+/// The R1CS equivalent of the reduction of a base field element mod the scalar field order `L`.
+///
+/// Returns the little-endian bits of the reduced value `c`. We allocate `c` and `k` such that `f = k*L + c` with `c < L` and `k < 8`.
+/// Since `8*L > q`, we additionally enforce `k*L + c < q` to guarantee that the equation does not wrap around the modulus, which makes the decomposition unique.
+///
+/// # Errors
+/// Returns an error if allocating the intermediate variables fails.
+pub fn convert_base_to_scalar_var(
+    input: &FqVar,
+) -> Result<Vec<Boolean<BaseField>>, SynthesisError> {
+    let cs = input.cs();
+    let modulus = BigUint::from(ScalarField::MODULUS);
+    let (quotient, remainder) = match input.value() {
+        Ok(value) => {
+            let value = BigUint::from(value);
+            (
+                Ok(BaseField::from(&value / &modulus)),
+                Ok(BaseField::from(&value % &modulus)),
+            )
+        }
+        Err(e) => (Err(e), Err(e)),
+    };
+    let mode = if input.is_constant() {
+        AllocationMode::Constant
+    } else {
+        AllocationMode::Witness
+    };
+    let quotient = FqVar::new_variable(ns!(cs, "k"), || quotient, mode)?;
+    let remainder = FqVar::new_variable(ns!(cs, "c"), || remainder, mode)?;
+
+    // c < L
+    let (c_bits, _) =
+        remainder.to_bits_le_with_top_bits_zero(ScalarField::MODULUS_BIT_SIZE as usize)?;
+    Boolean::enforce_smaller_or_equal_than_le(&c_bits, (-ScalarField::one()).into_bigint())?;
+    // k < 8
+    let (k_bits, _) = quotient.to_bits_le_with_top_bits_zero(3)?;
+    // f = k*L + c
+    let modulus = BaseField::from(ScalarField::MODULUS);
+    (&quotient * modulus + &remainder).enforce_equal(input)?;
+    // k*L + c < q, which can only be violated if k = 7. In that case we need c < q - 7L, which we enforce by checking that c + 2^251 - (q - 7L) fits into 251 bits.
+    let k_is_7 = Boolean::kary_and(&k_bits)?;
+    let offset = BaseField::from(2u64).pow([u64::from(ScalarField::MODULUS_BIT_SIZE)])
+        + modulus * BaseField::from(7u64);
+    let shifted = remainder + FqVar::from(k_is_7) * offset;
+    let (_range_checked_bits, _) =
+        shifted.to_bits_le_with_top_bits_zero(ScalarField::MODULUS_BIT_SIZE as usize)?;
+    Ok(c_bits)
+}
+
+/// Returns `2^i * G` for all bits of the scalar field, used for the fixed-base scalar multiplication with the generator.
+fn generator_powers() -> Vec<Projective> {
+    let mut g = Affine::generator().into_group();
+    (0..ScalarField::MODULUS_BIT_SIZE)
+        .map(|_| {
+            let res = g;
+            g.double_in_place();
+            res
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdDSAPrivateKey;
+    use ark_ec::CurveGroup;
+    use ark_ff::{BigInteger, UniformRand};
+    use ark_groth16::Groth16;
+    use ark_relations::gr1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef};
+    use ark_snark::SNARK;
+    use rand::Rng;
+
+    #[derive(Clone)]
+    struct VerifyCircuit {
+        pk: EdDSAPublicKey,
+        message: BaseField,
+        signature: EdDSASignature,
+    }
+
+    impl ConstraintSynthesizer<BaseField> for VerifyCircuit {
+        fn generate_constraints(
+            self,
+            cs: ConstraintSystemRef<BaseField>,
+        ) -> Result<(), SynthesisError> {
+            let pk = EdDSAPublicKeyVar::new_input(ns!(cs, "pk"), || Ok(self.pk))?;
+            let message = FqVar::new_input(ns!(cs, "message"), || Ok(self.message))?;
+            let signature =
+                EdDSASignatureVar::new_witness(ns!(cs, "signature"), || Ok(self.signature))?;
+            pk.verify(&message, &signature)
+        }
+    }
+
+    fn is_satisfied(circuit: VerifyCircuit) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        circuit
+            .generate_constraints(cs.clone())
+            .expect("constraint generation succeeds");
+        cs.is_satisfied().expect("constraint system is consistent")
+    }
+
+    fn random_circuit(rng: &mut impl rand::Rng) -> VerifyCircuit {
+        let sk = EdDSAPrivateKey::from_bytes(rng.r#gen());
+        let message = BaseField::rand(rng);
+        VerifyCircuit {
+            pk: sk.public(),
+            message,
+            signature: sk.sign(message),
+        }
+    }
+
+    #[test]
+    fn test_verify_var() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let circuit = random_circuit(&mut rng);
+            assert!(
+                circuit.pk.verify(circuit.message, &circuit.signature),
+                "valid signature should verify natively"
+            );
+            assert!(
+                is_satisfied(circuit),
+                "valid signature should verify in-circuit"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_var_rejects() {
+        let mut rng = rand::thread_rng();
+        let circuit = random_circuit(&mut rng);
+
+        let mut wrong_message = circuit.clone();
+        wrong_message.message += BaseField::one();
+        assert!(
+            !is_satisfied(wrong_message),
+            "wrong message should not verify"
+        );
+
+        let mut wrong_pk = circuit.clone();
+        wrong_pk.pk = EdDSAPrivateKey::from_bytes(rng.r#gen()).public();
+        assert!(
+            !is_satisfied(wrong_pk),
+            "wrong public key should not verify"
+        );
+
+        let mut wrong_s = circuit.clone();
+        wrong_s.signature.s += ScalarField::one();
+        assert!(!is_satisfied(wrong_s), "wrong s should not verify");
+
+        let mut small_order_pk = circuit;
+        small_order_pk.pk.pk = Affine::zero();
+        assert!(
+            !is_satisfied(small_order_pk),
+            "small order public key should not verify"
+        );
+    }
+
+    #[test]
+    fn test_verify_var_rejects_non_canonical_s() {
+        let mut rng = rand::thread_rng();
+        let circuit = random_circuit(&mut rng);
+        let cs = ConstraintSystem::new_ref();
+        let pk = EdDSAPublicKeyVar::new_input(ns!(cs, "pk"), || Ok(circuit.pk.clone()))
+            .expect("allocation succeeds");
+        let message = FqVar::new_input(ns!(cs, "message"), || Ok(circuit.message))
+            .expect("allocation succeeds");
+        let mut signature =
+            EdDSASignatureVar::new_witness(ns!(cs, "signature"), || Ok(circuit.signature.clone()))
+                .expect("allocation succeeds");
+        // s + L leads to the same point s*G, but must be rejected
+        signature.s += BaseField::from(ScalarField::MODULUS);
+        pk.verify(&message, &signature)
+            .expect("constraint generation succeeds");
+        assert!(
+            !cs.is_satisfied().expect("constraint system is consistent"),
+            "non-canonical s should not verify"
+        );
+    }
+
+    #[test]
+    fn test_verify_var_rejects_small_order_pk_component() {
+        let mut rng = rand::thread_rng();
+        let mut circuit = random_circuit(&mut rng);
+        // (0, -1) is the point of order 2
+        let torsion = Affine::new_unchecked(BaseField::from(0u64), -BaseField::one());
+        circuit.pk.pk = (circuit.pk.pk + torsion).into_affine();
+        assert!(
+            !circuit.pk.verify(circuit.message, &circuit.signature),
+            "public key outside of the subgroup should not verify natively"
+        );
+        assert!(
+            !is_satisfied(circuit),
+            "public key outside of the subgroup should not verify in-circuit"
+        );
+    }
+
+    #[test]
+    fn test_convert_base_to_scalar_var() {
+        let mut rng = rand::thread_rng();
+        let l = BaseField::from(ScalarField::MODULUS);
+        let q_minus_1 = -BaseField::one();
+        for f in [
+            BaseField::from(0u64),
+            l - BaseField::one(),
+            l,
+            l * BaseField::from(7u64),
+            q_minus_1,
+            BaseField::rand(&mut rng),
+        ] {
+            let cs = ConstraintSystem::new_ref();
+            let f_var = FqVar::new_witness(cs.clone(), || Ok(f)).expect("allocation succeeds");
+            let bits = convert_base_to_scalar_var(&f_var).expect("constraint generation succeeds");
+            let bits = bits
+                .iter()
+                .map(|b| b.value().expect("value is assigned"))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ScalarField::from_bigint(<ScalarField as PrimeField>::BigInt::from_bits_le(&bits)),
+                Some(crate::convert_base_to_scalar(f)),
+                "in-circuit reduction should match native reduction"
+            );
+            assert!(
+                cs.is_satisfied().expect("constraint system is consistent"),
+                "constraints should be satisfied"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_groth16() {
+        let mut rng = rand::thread_rng();
+        let circuit = random_circuit(&mut rng);
+        let (pk, vk) =
+            Groth16::<ark_bn254::Bn254>::circuit_specific_setup(circuit.clone(), &mut rng)
+                .expect("setup succeeds");
+        let proof = Groth16::<ark_bn254::Bn254>::prove(&pk, circuit.clone(), &mut rng)
+            .expect("proving succeeds");
+        let public_inputs = [circuit.pk.pk.x, circuit.pk.pk.y, circuit.message];
+        assert!(
+            Groth16::<ark_bn254::Bn254>::verify(&vk, &public_inputs, &proof)
+                .expect("verification succeeds"),
+            "proof should verify"
+        );
+        let wrong_inputs = [
+            circuit.pk.pk.x,
+            circuit.pk.pk.y,
+            circuit.message + BaseField::one(),
+        ];
+        assert!(
+            !Groth16::<ark_bn254::Bn254>::verify(&vk, &wrong_inputs, &proof)
+                .expect("verification succeeds"),
+            "proof should not verify for a different message"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Hashing to the Baby Jubjub curve, following the `hash_to_curve` construction of RFC 9380 with the Elligator 2 map on the Montgomery form.
+//!
+//! This is useful wherever a point with unknown discrete logarithm must be derived from a message, e.g., for VRFs, OPRFs or nothing-up-my-sleeve generators.
+//! Instead of `expand_message_xmd`, the message is expanded with a sponge over the Poseidon2 permutation, such that the hash can be computed efficiently in a circuit.
+//!
+//! # Construction
+//!
+//! 1. [`expand_message`] hashes the message to `count` base field elements with a [`Poseidon2Sponge`] with state size 8, rate 7 and the IO pattern `[Absorb(1 + k), Squeeze(count)]`.
+//!    The message is split into `k` chunks of [`BYTES_PER_FIELD_ELEMENT`] (=31) bytes, which are interpreted as little-endian integers like in [`hash_bytes_to_field`], and the absorbed elements are `[len(msg), chunk_0, ..., chunk_{k-1}]`.
+//!    The domain separator of the sponge is `DST_prime = DST || I2OSP(len(DST), 1)`. Following Section 5.3.3 of RFC 9380, a DST longer than 255 bytes is first replaced by the 32-byte little-endian encoding of `hash_bytes_to_field(b"H2C-OVERSIZE-DST-" || DST)`.
+//! 2. `hash_to_field(msg, 2)` is the output of `expand_message(msg, DST, 2)`. Since the sponge outputs uniform field elements, no bytes have to be reduced modulo `q` as in RFC 9380.
+//! 3. `map_to_curve` is the Elligator 2 map to the Montgomery form `v^2 = u^3 + 168698 u^2 + u` with `Z = 5`, followed by the rational map to the twisted Edwards form (see Appendix D of RFC 9380), as implemented by [`Elligator2Map`].
+//! 4. `hash_to_curve(msg) = 8 * (map_to_curve(u_0) + map_to_curve(u_1))`, where the multiplication with the cofactor 8 clears the cofactor, such that the result is in the prime-order subgroup.
+//!
+//! The DST must be non-empty and should be unique to the application, e.g., `b"MY-APP-V01-CS01-with-BabyJubJub_POSEIDON2_ELL2_RO_"`, which contains the [`SUITE_ID`].
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let point = hash_to_curve(b"message", b"MY-APP-V01-CS01-with-BabyJubJub_POSEIDON2_ELL2_RO_");
+//! ```
+
+use alloc::vec::Vec;
+use ark_babyjubjub::{EdwardsConfig, EdwardsProjective};
+use ark_ec::hashing::{
+    HashToCurve, curve_maps::elligator2::Elligator2Map, map_to_curve_hasher::MapToCurveBasedHasher,
+};
+use ark_ff::{BigInteger, PrimeField, field_hashers::HashToField};
+use poseidon2::sponge::{Poseidon2Sponge, SpongeOp};
+
+use crate::{Affine, BYTES_PER_FIELD_ELEMENT, BaseField, hash_bytes_to_field};
+
+/// The suite ID of this hash-to-curve suite in the naming scheme of RFC 9380, which should be part of the DST.
+pub const SUITE_ID: &[u8] = b"BabyJubJub_POSEIDON2_ELL2_RO_";
+
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// The state size of the Poseidon2 permutation used by [`expand_message`].
+const STATE_SIZE: usize = 8;
+
+/// A hasher from byte messages to the prime-order subgroup of Baby Jubjub, implementing [`HashToCurve`].
+///
+/// The hasher is constructed with the DST, see the [module documentation](self).
+pub type Poseidon2Elligator2Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, Poseidon2FieldHasher, Elligator2Map<EdwardsConfig>>;
+
+/// Hashes the message to a point in the prime-order subgroup of Baby Jubjub, see the [module documentation](self) for the exact construction.
+///
+/// # Panics
+/// Panics if `dst` is empty.
+pub fn hash_to_curve(message: &[u8], dst: &[u8]) -> Affine {
+    Poseidon2Elligator2Hasher::new(dst)
+        .and_then(|hasher| hasher.hash(message))
+        .expect("Elligator 2 maps every field element to the curve")
+}
+
+/// Hashes the message to `count` uniformly distributed base field elements with a Poseidon2 sponge, see the [module documentation](self) for the exact construction.
+///
+/// # Panics
+/// Panics if `dst` is empty, if `count` is zero or at least `2^31`, or if the message is longer than about 66 GB.
+#[must_use]
+pub fn expand_message(message: &[u8], dst: &[u8], count: usize) -> Vec<BaseField> {
+    assert!(!dst.is_empty(), "the DST must not be empty");
+    let dst_prime = dst_prime(dst);
+
+    let len = BaseField::from(message.len() as u64);
+    let chunks = message
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(BaseField::from_le_bytes_mod_order);
+    let input = core::iter::once(len).chain(chunks).collect::<Vec<_>>();
+
+    let absorb_len = u32::try_from(input.len()).expect("message is too long");
+    let squeeze_len = u32::try_from(count).expect("count is too large");
+    let mut sponge = Poseidon2Sponge::<BaseField, STATE_SIZE>::new(
+        poseidon2::bn254::t8::permutation_in_place,
+        STATE_SIZE - 1,
+        &[SpongeOp::Absorb(absorb_len), SpongeOp::Squeeze(squeeze_len)],
+        &dst_prime,
+    )
+    .expect("count and message length are valid");
+    sponge
+        .absorb(&input)
+        .expect("absorbing matches the IO pattern");
+    let output = sponge
+        .squeeze(count)
+        .expect("squeezing matches the IO pattern");
+    sponge.finish().expect("the IO pattern is completed");
+    output
+}
+
+/// Computes `DST_prime = DST || I2OSP(len(DST), 1)`, reducing DSTs longer than 255 bytes first.
+fn dst_prime(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = match u8::try_from(dst.len()) {
+        Ok(_) => dst.to_vec(),
+        Err(_) => hash_bytes_to_field(&[OVERSIZE_DST_PREFIX, dst].concat())
+            .into_bigint()
+            .to_bytes_le(),
+    };
+    let len = u8::try_from(dst_prime.len()).expect("reduced DST is 32 bytes long");
+    dst_prime.push(len);
+    dst_prime
+}
+
+/// The `hash_to_field` function of the hash-to-curve suite, implementing [`HashToField`] with [`expand_message`].
+#[derive(Debug, Clone)]
+pub struct Poseidon2FieldHasher {
+    dst: Vec<u8>,
+}
+
+impl HashToField<BaseField> for Poseidon2FieldHasher {
+    /// Creates a new hasher for the given DST.
+    ///
+    /// # Panics
+    /// Panics if `dst` is empty.
+    fn new(dst: &[u8]) -> Self {
+        assert!(!dst.is_empty(), "the DST must not be empty");
+        Self { dst: dst.to_vec() }
+    }
+
+    fn hash_to_field<const N: usize>(&self, message: &[u8]) -> [BaseField; N] {
+        expand_message(message, &self.dst, N)
+            .try_into()
+            .expect("expand_message returns N elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::AffineRepr;
+    use ark_ff::Zero;
+    use std::str::FromStr;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BabyJubJub_POSEIDON2_ELL2_RO_";
+
+    fn fq(s: &str) -> BaseField {
+        BaseField::from_str(s).expect("Is in BaseField")
+    }
+
+    #[test]
+    fn hash_to_curve_lands_in_subgroup() {
+        for i in 0..20u8 {
+            let point = hash_to_curve(&[i; 5], DST);
+            assert!(point.is_on_curve(), "point should be on the curve");
+            assert!(
+                point.is_in_correct_subgroup_assuming_on_curve(),
+                "point should be in the prime-order subgroup"
+            );
+            assert!(!point.is_zero(), "point should not be the identity");
+        }
+        assert_ne!(
+            hash_to_curve(b"abc", DST),
+            hash_to_curve(b"abd", DST),
+            "different messages should give different points"
+        );
+        assert_ne!(
+            hash_to_curve(b"abc", DST),
+            hash_to_curve(b"abc", b"QUUX-V01-CS02-with-BabyJubJub_POSEIDON2_ELL2_NU_"),
+            "different DSTs should give different points"
+        );
+    }
+
+    #[test]
+    fn expand_message_separates_lengths() {
+        let two = expand_message(b"abc", DST, 2);
+        let three = expand_message(b"abc", DST, 3);
+        assert_eq!(two.len(), 2, "should output the requested number");
+        assert_ne!(
+            two[..],
+            three[..2],
+            "the output length should be part of the domain separation"
+        );
+        assert_ne!(
+            expand_message(b"", DST, 1),
+            expand_message(&[0], DST, 1),
+            "the message length should be absorbed"
+        );
+    }
+
+    #[test]
+    fn expand_message_matches_documented_construction() {
+        // Rebuilds expand_message from the sponge and the encoding described in the module documentation.
+        fn by_hand(message: &[u8], dst: &[u8], count: usize) -> Vec<BaseField> {
+            let dst_prime = [dst, &[u8::try_from(dst.len()).expect("DST is short")]].concat();
+            let mut input = vec![BaseField::from(message.len() as u64)];
+            for chunk in message.chunks(31) {
+                // little-endian integer
+                let chunk = chunk.iter().rev().fold(BaseField::zero(), |acc, &b| {
+                    acc * BaseField::from(256u64) + BaseField::from(b)
+                });
+                input.push(chunk);
+            }
+            Poseidon2Sponge::<BaseField, 8>::hash(
+                poseidon2::bn254::t8::permutation_in_place,
+                7,
+                &dst_prime,
+                &input,
+                count,
+            )
+            .expect("valid sponge parameters")
+        }
+
+        let long_dst = [b'x'; 255];
+        for dst in [DST, b"A".as_slice(), &long_dst] {
+            for len in [0u8, 1, 30, 31, 32, 62, 63, 200, 217] {
+                let message = (0..len)
+                    .map(|i| i.wrapping_mul(7).wrapping_add(3))
+                    .collect::<Vec<_>>();
+                for count in [1, 2, 7, 8, 15] {
+                    assert_eq!(
+                        expand_message(&message, dst, count),
+                        by_hand(&message, dst, count),
+                        "expand_message should match the documented construction"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn expand_message_oversize_dst() {
+        let long_dst = [b'x'; 256];
+        let reduced = hash_bytes_to_field(&[OVERSIZE_DST_PREFIX, &long_dst].concat())
+            .into_bigint()
+            .to_bytes_le();
+        assert_eq!(
+            expand_message(b"abc", &long_dst, 2),
+            expand_message(b"abc", &reduced, 2),
+            "a long DST should be replaced by its hash"
+        );
+        assert_ne!(
+            expand_message(b"abc", &long_dst[..255], 2),
+            expand_message(b"abc", &long_dst, 2),
+            "a DST of 255 bytes should be used as is"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the DST must not be empty")]
+    fn empty_dst_panics() {
+        let _point = hash_to_curve(b"abc", b"");
+    }
+
+    #[test]
+    fn hash_to_curve_kat() {
+        // Regression vectors generated with this implementation, as (msg, [u_0, u_1, P.x, P.y]).
+        // RFC 9380 has no Baby Jubjub suite, so only the messages are taken from its test vectors and the values are not an external cross-check.
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let vectors: [(&[u8], [&str; 4]); 5] = [
+            (
+                b"",
+                [
+                    "4648431414788297206998259305499160700788977436674940973130485807575493897603",
+                    "14914356689431192253814634643431850423355526710153149784483305930903678682728",
+                    "6679795290096012899635093197563761182135563613430924526222975088096484739645",
+                    "20037223944896468942541728342277423247805483599994066790712114259888413284598",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "4231934314316531650330017207612350065068716817102366631809728570308750906843",
+                    "16211396242104605207471532386134895892428257215935265781085354166628648139882",
+                    "13317814709055777089307530009156239472523072763127182189808727566927306446835",
+                    "8329425821680450077089082109187990809181126816799490138574213716436332017341",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "5810886457643059281045590222153085364059172820811992621491727536318832672032",
+                    "9550795378048052083586183979706260506221331020208127895607620898524747937280",
+                    "10148795822729004949656746796067392032337901381804178340614534947116539800015",
+                    "1897280217893581227108336649659340854147544212873356465811106796723647023",
+                ],
+            ),
+            (
+                &q128,
+                [
+                    "3795190496139538599754373072279189869336566642544428887057203551771327236223",
+                    "4523430399178762970549117827480560977424520379646023627272239137675079953981",
+                    "16527729464747933656759481094873153663291469018649983739294874304045091849182",
+                    "19400085127972203157765534746066042069765250376928822257096796733509520821488",
+                ],
+            ),
+            (
+                &a512,
+                [
+                    "3253836676777124336729045461585090090236646239140660034784817817703419103786",
+                    "11157130511979718981221405853224359504768463354125692884561183818039092851401",
+                    "10285404418506604961962603969547656404003164651932604709180263336344970564370",
+                    "19087737799183191173382018714983195375667669971836736865337588366680177609582",
+                ],
+            ),
+        ];
+        for (msg, [u0, u1, x, y]) in vectors {
+            assert_eq!(
+                expand_message(msg, DST, 2),
+                [fq(u0), fq(u1)],
+                "hash_to_field should match KAT"
+            );
+            assert_eq!(
+                hash_to_curve(msg, DST),
+                Affine::new(fq(x), fq(y)),
+                "hash_to_curve should match KAT"
+            );
+        }
+    }
+}
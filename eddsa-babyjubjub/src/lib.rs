@@ -1,13 +1,28 @@
 //! `EdDSA` signatures over the Baby Jubjub curve, using Poseidon2 as the internal hash function for the Fiat-Shamir transform.
+//!
+//! The crate supports `no_std` environments with `alloc` when the default `std` feature is disabled. The `serde` implementations of the key and signature types are gated behind the default `serde` feature, which requires `std`.
 
-use ark_ec::{AffineRepr, CurveGroup};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use ark_babyjubjub::EdwardsScalarMul;
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
 use ark_ff::{AdditiveGroup, BigInteger, PrimeField, Zero};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use num_bigint::BigUint;
 use rand::{CryptoRng, Rng};
-use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+#[cfg(feature = "circomlib")]
+pub mod circomlib;
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+pub mod hash_to_curve;
+pub mod transcript;
+
 type ScalarField = ark_babyjubjub::Fr;
 type BaseField = ark_babyjubjub::Fq;
 type Affine = ark_babyjubjub::EdwardsAffine;
@@ -29,6 +44,25 @@ impl EdDSAPrivateKey {
         self.0
     }
 
+    /// Returns `a` if `choice` is 0 and `b` if `choice` is 1, in constant time.
+    ///
+    /// This is [`ConditionallySelectable::conditional_select`], which cannot be implemented for the private key, since the trait requires `Copy` and the key is zeroized on drop.
+    #[must_use]
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(core::array::from_fn(|i| {
+            u8::conditional_select(&a.0[i], &b.0[i], choice)
+        }))
+    }
+
+    /// Assigns `other` to `self` if `choice` is 1 and leaves `self` unchanged if `choice` is 0, in constant time.
+    ///
+    /// This is [`ConditionallySelectable::conditional_assign`], see [`Self::conditional_select`].
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for (byte, other) in self.0.iter_mut().zip(&other.0) {
+            byte.conditional_assign(other, choice);
+        }
+    }
+
     /// Generate a random private key using the given RNG.
     pub fn random<R: Rng + CryptoRng>(rng: &mut R) -> Self {
         let mut bytes = [0u8; 32];
@@ -65,6 +99,19 @@ impl EdDSAPrivateKey {
         ScalarField::from_le_bytes_mod_order(&sk_buf)
     }
 
+    /// Exposes the derivation of the secret scalar from the Blake3 output for the constant-time audit harness in `ct-audit`.
+    ///
+    /// This is not part of the public API and may change at any time.
+    ///
+    /// # Panics
+    /// Panics if `input` is shorter than 32 bytes.
+    #[cfg(feature = "ct-audit")]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn derive_sk_for_audit(input: &[u8]) -> ark_babyjubjub::Fr {
+        Self::derive_sk(input)
+    }
+
     /// Derive the public key corresponding to this private key.
     #[must_use]
     pub fn public(&self) -> EdDSAPublicKey {
@@ -88,20 +135,38 @@ impl EdDSAPrivateKey {
         ScalarField::from_le_bytes_mod_order(&output)
     }
 
-    /// Sign a message (a `BaseField` element) with the given secret key (a `ScalarField` element).
+    /// Same as [`Self::deterministic_nonce`], but for a message consisting of multiple `BaseField` elements.
     ///
-    /// The message should be hashed to a `BaseField` element if it is not encodable as one before signing.
-    #[must_use]
-    pub fn sign(&self, message: BaseField) -> EdDSASignature {
+    /// The hashed data is prefixed with a domain separator and the number of elements. Thus, the input to Blake3 never has the same length as in [`Self::deterministic_nonce`],
+    /// which guarantees that signing `m` with [`Self::sign`] and `[m]` with [`Self::sign_many`] does not reuse the nonce for two different challenges.
+    fn deterministic_nonce_many(messages: &[BaseField], sk: ScalarField) -> ScalarField {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&sk.into_bigint().to_bytes_le());
+        hasher.update(MANY_DS);
+        hasher.update(&(messages.len() as u64).to_le_bytes());
+        for message in messages {
+            hasher.update(&message.into_bigint().to_bytes_le());
+        }
+        let mut r = hasher.finalize_xof();
+        let mut output = [0u8; 64]; // 512 bits to get no bias when doing mod reduction
+        r.fill(&mut output);
+        ScalarField::from_le_bytes_mod_order(&output)
+    }
+
+    fn sign_with(
+        &self,
+        nonce: impl FnOnce(ScalarField) -> ScalarField,
+        challenge: impl FnOnce(Affine, Affine) -> BaseField,
+    ) -> EdDSASignature {
         let out = self.hash_blake();
         let sk = Self::derive_sk(&out);
         let nonce_secret = ScalarField::from_le_bytes_mod_order(&out[32..64]);
 
-        let r = Self::deterministic_nonce(message, nonce_secret);
+        let r = nonce(nonce_secret);
         let nonce_r = Affine::generator() * r;
 
         let pk = Affine::generator() * sk;
-        let challenge = challenge_hash(message, nonce_r.into_affine(), pk.into_affine());
+        let challenge = challenge(nonce_r.into_affine(), pk.into_affine());
         let c = convert_base_to_scalar(challenge);
         let s = r + c * sk;
 
@@ -110,17 +175,67 @@ impl EdDSAPrivateKey {
             s,
         }
     }
+
+    /// Sign a message (a `BaseField` element) with the given secret key (a `ScalarField` element).
+    ///
+    /// The message should be hashed to a `BaseField` element if it is not encodable as one before signing.
+    #[must_use]
+    pub fn sign(&self, message: BaseField) -> EdDSASignature {
+        self.sign_with(
+            |nonce_secret| Self::deterministic_nonce(message, nonce_secret),
+            |nonce_r, pk| challenge_hash(message, nonce_r, pk),
+        )
+    }
+
+    /// Sign a message consisting of multiple `BaseField` elements.
+    ///
+    /// All elements are absorbed into the challenge hash, see [`challenge_hash_many`] for the exact layout.
+    /// Signatures produced by this function only verify with [`EdDSAPublicKey::verify_many`], even if `messages` has length 1.
+    #[must_use]
+    pub fn sign_many(&self, messages: &[BaseField]) -> EdDSASignature {
+        self.sign_with(
+            |nonce_secret| Self::deterministic_nonce_many(messages, nonce_secret),
+            |nonce_r, pk| challenge_hash_many(messages, nonce_r, pk),
+        )
+    }
+
+    /// Sign an arbitrary byte message.
+    ///
+    /// The message is first hashed to a `BaseField` element using [`hash_bytes_to_field`] and the result is signed using [`Self::sign`].
+    #[must_use]
+    pub fn sign_bytes(&self, message: &[u8]) -> EdDSASignature {
+        self.sign(hash_bytes_to_field(message))
+    }
+}
+
+impl ConstantTimeEq for EdDSAPrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Compares the keys in constant time with [`ConstantTimeEq`].
+impl PartialEq for EdDSAPrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
 }
 
+impl Eq for EdDSAPrivateKey {}
+
 /// A public key for the `EdDSA` signature scheme over the `BabyJubJubCurve`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(
     clippy::exhaustive_structs,
     reason = "Only wraps the inner Affine point - not planned to add something"
 )]
 pub struct EdDSAPublicKey {
     /// The public key point on the curve.
-    #[serde(with = "ark_serde_compat::babyjubjub::affine")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "ark_serde_compat::babyjubjub::affine")
+    )]
     pub pk: Affine,
 }
 
@@ -133,6 +248,54 @@ impl EdDSAPublicKey {
     /// The only assumption is that both the public key and the nonce point R are canonical, i.e., their encoding is using valid field elements, which must be checked during deserialization.
     #[must_use]
     pub fn verify(&self, message: BaseField, signature: &EdDSASignature) -> bool {
+        if !self.check_preconditions(signature) {
+            return false;
+        }
+
+        // 4. Compute the hash and reduce it mod the scalar field order L
+        let challenge = challenge_hash(message, signature.r, self.pk);
+        self.check_equation(challenge, signature)
+    }
+
+    /// Verify the signature against the given multi-element message and public key.
+    ///
+    /// This is the counterpart to [`EdDSAPrivateKey::sign_many`] and performs the same checks as [`Self::verify`], except that the challenge is computed using [`challenge_hash_many`].
+    #[must_use]
+    pub fn verify_many(&self, messages: &[BaseField], signature: &EdDSASignature) -> bool {
+        if !self.check_preconditions(signature) {
+            return false;
+        }
+        let challenge = challenge_hash_many(messages, signature.r, self.pk);
+        self.check_equation(challenge, signature)
+    }
+
+    /// Verify the signature against the given byte message and public key.
+    ///
+    /// The message is hashed to a `BaseField` element using [`hash_bytes_to_field`], which is then verified using [`Self::verify`].
+    #[must_use]
+    pub fn verify_bytes(&self, message: &[u8], signature: &EdDSASignature) -> bool {
+        self.verify(hash_bytes_to_field(message), signature)
+    }
+
+    /// Steps 4-5 of the verification, given the challenge hash.
+    fn check_equation(&self, challenge: BaseField, signature: &EdDSASignature) -> bool {
+        let c = convert_base_to_scalar(challenge);
+        // 5. Accept if 8*(s*G) = 8*R + 8*(c*Pk)
+        // Implemented by checking that 8(s*G - R - c*Pk) = 0, according to Section 4 of the above paper.
+        // All inputs are public, so the variable-time scalar multiplication can be used.
+        let mut v =
+            Affine::generator().mul_vartime(&signature.s) - signature.r - self.pk.mul_vartime(&c);
+        // multiply by the cofactor 8
+        v.double_in_place();
+        v.double_in_place();
+        v.double_in_place();
+        v.is_zero()
+    }
+
+    /// Steps 1-3 of the verification, i.e., everything that does not depend on the message.
+    ///
+    /// Shared between [`Self::verify`] and [`verify_batch`], such that both reject exactly the same inputs.
+    fn check_preconditions(&self, signature: &EdDSASignature) -> bool {
         // 1. Reject the signature if s not in [0, L-1]
         // The following check is required to prevent malleability of the proofs by using different s, such as s + p, if s is given as a BaseField element.
         // In Rust this check is not required since self.s is a ScalarField element already, but we keep it to have the same implementation as in circom (where it is required).
@@ -153,25 +316,14 @@ impl EdDSAPublicKey {
         }
         // 3. Reject the signature if A or R are non-canonical. We do not do this directly here, instead leaving this to the rust type system which ensure that the field elements are canonical.
         // All deserialization routines need to ensure that only canonical field elements are accepted.
-
-        // 4. Compute the hash and reduce it mod the scalar field order L
-        let challenge = challenge_hash(message, signature.r, self.pk);
-        let c = convert_base_to_scalar(challenge);
-        // 5. Accept if 8*(s*G) = 8*R + 8*(c*Pk)
-        // Implemented by checking that 8(s*G - R - c*Pk) = 0, according to Section 4 of the above paper.
-        let mut v = (Affine::generator() * signature.s) - signature.r - (self.pk * c);
-        // multiply by the cofactor 8
-        v.double_in_place();
-        v.double_in_place();
-        v.double_in_place();
-        v.is_zero()
+        true
     }
 
     /// Serialize the public key to a compressed byte array.
     ///
     /// # Errors
     /// Returns an error if the public key point fails to serialize.
-    pub fn to_compressed_bytes(&self) -> eyre::Result<[u8; 32]> {
+    pub fn to_compressed_bytes(&self) -> Result<[u8; 32], SerializationError> {
         let mut buf = Vec::new();
         self.pk.serialize_compressed(&mut buf)?;
         let mut bytes = [0u8; 32];
@@ -183,24 +335,28 @@ impl EdDSAPublicKey {
     ///
     /// # Errors
     /// Returns an error if `bytes` does not encode a valid compressed curve point.
-    pub fn from_compressed_bytes(bytes: [u8; 32]) -> eyre::Result<Self> {
+    pub fn from_compressed_bytes(bytes: [u8; 32]) -> Result<Self, SerializationError> {
         let pk = Affine::deserialize_compressed(&bytes[0..32])?;
         Ok(Self { pk })
     }
 }
 
 /// An `EdDSA` signature on the Baby Jubjub curve, using Poseidon2 as the internal hash function for the Fiat-Shamir transform.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(
     clippy::exhaustive_structs,
     reason = "Only wraps the inner Affine point and ScalarField - not planned to add something"
 )]
 pub struct EdDSASignature {
     /// The nonce point of the signature.
-    #[serde(with = "ark_serde_compat::babyjubjub::affine")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "ark_serde_compat::babyjubjub::affine")
+    )]
     pub r: Affine,
     /// The scalar part of the signature.
-    #[serde(with = "ark_serde_compat::field")]
+    #[cfg_attr(feature = "serde", serde(with = "ark_serde_compat::field"))]
     pub s: ScalarField,
 }
 
@@ -216,7 +372,7 @@ impl EdDSASignature {
     ///
     /// # Errors
     /// Returns an error if `r` or `s` fail to serialize.
-    pub fn to_compressed_bytes(&self) -> eyre::Result<[u8; 64]> {
+    pub fn to_compressed_bytes(&self) -> Result<[u8; 64], SerializationError> {
         let mut buf = Vec::new();
         self.r.serialize_compressed(&mut buf)?;
         self.s.serialize_compressed(&mut buf)?;
@@ -229,13 +385,116 @@ impl EdDSASignature {
     ///
     /// # Errors
     /// Returns an error if `bytes` does not encode a valid compressed point and scalar.
-    pub fn from_compressed_bytes(bytes: [u8; 64]) -> eyre::Result<Self> {
+    pub fn from_compressed_bytes(bytes: [u8; 64]) -> Result<Self, SerializationError> {
         let r = Affine::deserialize_compressed(&bytes[0..32])?;
         let s: ScalarField = ScalarField::deserialize_compressed(&bytes[32..64])?;
         Ok(Self { r, s })
     }
 }
 
+/// Verify a batch of signatures at once.
+///
+/// Each entry consists of the public key, the message and the signature, i.e., the same arguments as for [`EdDSAPublicKey::verify`].
+/// Returns `true` if and only if calling [`EdDSAPublicKey::verify`] on every entry would return `true`. An empty batch is accepted.
+///
+/// Since verification is cofactored, the individual equations `8(s_i*G - R_i - c_i*A_i) = 0` can be combined with random 128-bit weights `z_i` into the single check
+/// `8((sum z_i*s_i)*G - sum z_i*R_i - sum (z_i*c_i)*A_i) = 0`, which is evaluated with one multi-scalar multiplication (see Section 4 of <https://eprint.iacr.org/2020/1244.pdf>).
+/// The weights are sampled from `rng`, which therefore must be a cryptographically secure RNG.
+#[must_use]
+pub fn verify_batch<R: Rng + CryptoRng>(
+    batch: &[(EdDSAPublicKey, BaseField, EdDSASignature)],
+    rng: &mut R,
+) -> bool {
+    let mut bases = Vec::with_capacity(2 * batch.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * batch.len() + 1);
+    let mut s_sum = ScalarField::zero();
+    for (pk, message, signature) in batch {
+        if !pk.check_preconditions(signature) {
+            return false;
+        }
+        let challenge = challenge_hash(*message, signature.r, pk.pk);
+        let c = convert_base_to_scalar(challenge);
+        // 128 bits of randomness are sufficient for a soundness error of 2^-128
+        let z = ScalarField::from(rng.r#gen::<u128>());
+
+        s_sum += z * signature.s;
+        bases.push(signature.r);
+        scalars.push(-z);
+        bases.push(pk.pk);
+        scalars.push(-(z * c));
+    }
+    bases.push(Affine::generator());
+    scalars.push(s_sum);
+
+    let mut v = ark_babyjubjub::EdwardsProjective::msm_unchecked(&bases, &scalars);
+    // multiply by the cofactor 8
+    v.double_in_place();
+    v.double_in_place();
+    v.double_in_place();
+    v.is_zero()
+}
+
+/// Verify a batch of signatures at once and report the entries that do not verify.
+///
+/// Behaves like [`verify_batch`], but if the batch is rejected every entry is verified individually using [`EdDSAPublicKey::verify`] to find the culprits.
+///
+/// # Errors
+/// Returns the indices (in ascending order) of all entries of `batch` whose signature is invalid.
+pub fn verify_batch_with_failures<R: Rng + CryptoRng>(
+    batch: &[(EdDSAPublicKey, BaseField, EdDSASignature)],
+    rng: &mut R,
+) -> Result<(), Vec<usize>> {
+    if verify_batch(batch, rng) {
+        return Ok(());
+    }
+    let failures = batch
+        .iter()
+        .enumerate()
+        .filter(|(_, (pk, message, signature))| !pk.verify(*message, signature))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    // The batch check only fails with negligible probability if all signatures are valid, so this is never empty in practice
+    debug_assert!(
+        !failures.is_empty(),
+        "batch verification failed but all signatures are valid"
+    );
+    Err(failures)
+}
+
+/// The number of message bytes packed into a single `BaseField` element in [`hash_bytes_to_field`].
+pub const BYTES_PER_FIELD_ELEMENT: usize = 31;
+
+const BYTES_DS: &[u8] = b"EdDSA Bytes Message";
+
+/// Hash an arbitrary byte message to a `BaseField` element, as used by [`EdDSAPrivateKey::sign_bytes`] and [`EdDSAPublicKey::verify_bytes`].
+///
+/// The hash is a sponge over the Poseidon2 permutation with state size 8, i.e., rate 7 and capacity 1. To reproduce it (e.g., in a circuit), do the following:
+/// 1. Split the message into chunks of [`BYTES_PER_FIELD_ELEMENT`] (=31) bytes, where the last chunk may be shorter. An empty message results in no chunks.
+///    Each chunk is interpreted as a little-endian integer, which is always smaller than the field modulus.
+/// 2. The input to the sponge is the sequence `[len, chunk_0, ..., chunk_{k-1}]`, where `len` is the length of the message in bytes.
+///    This sequence is padded with zeros to a multiple of 7 elements. The length prefix makes the padding unambiguous.
+/// 3. The state is initialized with the domain separator `BaseField::from_be_bytes_mod_order(b"EdDSA Bytes Message")` in the capacity element `state[0]` and zeros in all other elements.
+/// 4. For each block of 7 elements, the block is added to `state[1..8]` and the permutation is applied afterwards.
+/// 5. The output is `state[1]` after the last permutation.
+#[must_use]
+pub fn hash_bytes_to_field(message: &[u8]) -> BaseField {
+    let len = BaseField::from(message.len() as u64);
+    let chunks = message
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(BaseField::from_le_bytes_mod_order);
+    let input = core::iter::once(len).chain(chunks).collect::<Vec<_>>();
+
+    let mut state = [BaseField::zero(); 8];
+    state[0] = BaseField::from_be_bytes_mod_order(BYTES_DS);
+    for block in input.chunks(7) {
+        for (s, m) in state[1..].iter_mut().zip(block) {
+            *s += m;
+        }
+        poseidon2::bn254::t8::permutation_in_place(&mut state);
+    }
+    state[1]
+}
+
 fn challenge_hash(message: BaseField, nonce_r: Affine, pk: Affine) -> BaseField {
     poseidon2::bn254::t8::permutation(&[
         EdDSASignature::get_chall_ds(), // Domain separator in capacity element
@@ -249,6 +508,43 @@ fn challenge_hash(message: BaseField, nonce_r: Affine, pk: Affine) -> BaseField
     ])[1]
 }
 
+const MANY_DS: &[u8] = b"EdDSA Signature Many";
+
+/// The challenge hash used by [`EdDSAPrivateKey::sign_many`] and [`EdDSAPublicKey::verify_many`].
+///
+/// This extends the single-message challenge hash, which uses the Poseidon2 permutation with state size 8 and leaves the last two elements of the state unused.
+/// For `n` messages `m_0, ..., m_{n-1}` the challenge is computed as follows:
+/// 1. The initial state is `[ds, R.x, R.y, A.x, A.y, n, m_0, m_1]`, where `ds = BaseField::from_be_bytes_mod_order(b"EdDSA Signature Many")` is the domain separator in the capacity element,
+///    `R` is the nonce point and `A` is the public key. If `n < 2`, the missing messages are replaced by zero.
+/// 2. The permutation is applied to the state.
+/// 3. The remaining messages `m_2, ..., m_{n-1}` are split into blocks of 7 elements, where the last block is padded with zeros.
+///    For each block, the block is added to `state[1..8]` and the permutation is applied afterwards.
+/// 4. The challenge is `state[1]`.
+///
+/// Since `n` is part of the first state, the zero padding is unambiguous. The different domain separator ensures that this hash never collides with the single-message challenge hash.
+#[must_use]
+pub fn challenge_hash_many(messages: &[BaseField], nonce_r: Affine, pk: Affine) -> BaseField {
+    let (first, rest) = messages.split_at(messages.len().min(2));
+    let mut state = [
+        BaseField::from_be_bytes_mod_order(MANY_DS), // Domain separator in capacity element
+        nonce_r.x,
+        nonce_r.y,
+        pk.x,
+        pk.y,
+        BaseField::from(messages.len() as u64),
+        first.first().copied().unwrap_or_default(),
+        first.get(1).copied().unwrap_or_default(),
+    ];
+    poseidon2::bn254::t8::permutation_in_place(&mut state);
+    for block in rest.chunks(7) {
+        for (s, m) in state[1..].iter_mut().zip(block) {
+            *s += m;
+        }
+        poseidon2::bn254::t8::permutation_in_place(&mut state);
+    }
+    state[1]
+}
+
 // This is just a modular reduction. We show in the docs why this does not introduce a bias when applied to a uniform element of the base field.
 pub(crate) fn convert_base_to_scalar(f: BaseField) -> ScalarField {
     let bytes = f.into_bigint().to_bytes_le();
@@ -423,6 +719,215 @@ mod tests {
         );
     }
 
+    /// KAT generated with the first implementation of `sign_many`. Ensures the layout of
+    /// [`challenge_hash_many`] and the nonce derivation for multiple messages stay stable.
+    #[test]
+    fn test_signature_many_kat() {
+        let sk = EdDSAPrivateKey::from_bytes(*b"a4a7d3b1e2f60b9c8d5e1f2a3b4c5d6e");
+        let pk = sk.public();
+        // (number of messages, R.x, s, challenge), signing the messages 1, 2, ..., n
+        let kats = [
+            (
+                1,
+                "4302221561135270827860201028047030379755032203945539593766646273580352185750",
+                "1553136794116432210965245637725663653692635667051987574587953042813438444359",
+                "7178534789688155519707517860285064673098059506664423848531067924692343414702",
+            ),
+            (
+                3,
+                "15124200069864011664662574958781162107499526431966405355505591308034545473929",
+                "2659516013840205378310153178458025490564776667537306489899124781679387638576",
+                "10352211061639862159696502398670281075168239310777462337611607986238706834056",
+            ),
+            (
+                8,
+                "21490560911042809444654031212390147287965910722917320752810076916576435647447",
+                "140252115558554429977764299742912696002143213142430263597015242649726141674",
+                "3000953484130974005775502133710756056905695869526206369252297986497183477136",
+            ),
+        ];
+        for (n, r_x, s, challenge) in kats {
+            let messages = (1..=n).map(BaseField::from).collect::<Vec<_>>();
+            let signature = sk.sign_many(&messages);
+            assert_eq!(
+                signature.r.x,
+                BaseField::from_str(r_x).expect("Is in BaseField"),
+                "signature nonce point x-coordinate for {n} messages should match KAT"
+            );
+            assert_eq!(
+                signature.s,
+                ScalarField::from_str(s).expect("Is in ScalarField"),
+                "signature scalar for {n} messages should match KAT"
+            );
+            assert_eq!(
+                challenge_hash_many(&messages, signature.r, pk.pk),
+                BaseField::from_str(challenge).expect("Is in BaseField"),
+                "challenge hash for {n} messages should match KAT"
+            );
+            assert!(
+                pk.verify_many(&messages, &signature),
+                "KAT signature for {n} messages should verify"
+            );
+        }
+    }
+
+    #[test]
+    fn test_eddsa_bytes() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 30, 31, 32, 216, 217, 218, 1000] {
+            let sk = EdDSAPrivateKey::random(&mut rng);
+            let pk = sk.public();
+            let mut message = vec![0u8; len];
+            rng.fill(message.as_mut_slice());
+            let signature = sk.sign_bytes(&message);
+            assert!(
+                pk.verify_bytes(&message, &signature),
+                "valid signature should verify"
+            );
+            assert!(
+                pk.verify(hash_bytes_to_field(&message), &signature),
+                "signature should be over the hashed message"
+            );
+            message.push(0);
+            assert!(
+                !pk.verify_bytes(&message, &signature),
+                "signature over a different message should not verify"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_bytes_to_field_kat() {
+        let message = BaseField::from_str(
+            "18531978644603051261090036176884464691626516403820931411344998302908084283991",
+        )
+        .expect("Is in BaseField");
+        assert_eq!(
+            hash_bytes_to_field(b"eddsa-babyjubjub"),
+            message,
+            "hash of byte message should match KAT"
+        );
+        assert_ne!(
+            hash_bytes_to_field(&[]),
+            hash_bytes_to_field(&[0]),
+            "zero padding must not lead to collisions"
+        );
+        assert_ne!(
+            hash_bytes_to_field(&[0; 31]),
+            hash_bytes_to_field(&[0; 32]),
+            "zero padding must not lead to collisions"
+        );
+    }
+
+    #[test]
+    fn test_eddsa_many() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 2, 3, 5, 9, 10, 30] {
+            let sk = EdDSAPrivateKey::random(&mut rng);
+            let pk = sk.public();
+            let mut messages = (0..len)
+                .map(|_| BaseField::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let signature = sk.sign_many(&messages);
+            assert!(
+                pk.verify_many(&messages, &signature),
+                "valid signature should verify"
+            );
+            if len > 0 {
+                let mut tampered = messages.clone();
+                tampered[len - 1] += BaseField::from(1u64);
+                assert!(
+                    !pk.verify_many(&tampered, &signature),
+                    "signature over a different message should not verify"
+                );
+            }
+            messages.push(BaseField::zero());
+            assert!(
+                !pk.verify_many(&messages, &signature),
+                "appending a zero should change the message"
+            );
+        }
+    }
+
+    #[test]
+    fn test_eddsa_many_differs_from_single() {
+        let mut rng = rand::thread_rng();
+        let sk = EdDSAPrivateKey::random(&mut rng);
+        let pk = sk.public();
+        let message = BaseField::rand(&mut rng);
+        let single = sk.sign(message);
+        let many = sk.sign_many(&[message]);
+        assert_ne!(single.r, many.r, "nonce must not be reused");
+        assert!(
+            !pk.verify_many(&[message], &single),
+            "single-message signature should not verify as multi-message signature"
+        );
+        assert!(
+            !pk.verify(message, &many),
+            "multi-message signature should not verify as single-message signature"
+        );
+    }
+
+    fn random_batch(
+        n: usize,
+        rng: &mut impl rand::Rng,
+    ) -> Vec<(EdDSAPublicKey, BaseField, EdDSASignature)> {
+        (0..n)
+            .map(|_| {
+                let sk = EdDSAPrivateKey::from_bytes(rng.r#gen());
+                let message = BaseField::rand(rng);
+                (sk.public(), message, sk.sign(message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let mut rng = rand::thread_rng();
+        assert!(
+            verify_batch(&[], &mut rng),
+            "empty batch should be accepted"
+        );
+        let batch = random_batch(20, &mut rng);
+        assert!(
+            verify_batch(&batch, &mut rng),
+            "batch of valid signatures should verify"
+        );
+        assert_eq!(
+            verify_batch_with_failures(&batch, &mut rng),
+            Ok(()),
+            "batch of valid signatures should verify"
+        );
+    }
+
+    #[test]
+    fn test_verify_batch_reports_failures() {
+        let mut rng = rand::thread_rng();
+        let mut batch = random_batch(20, &mut rng);
+        // wrong message
+        batch[3].1 += BaseField::from(1u64);
+        // signature of a different key
+        batch[11].2 = batch[12].2.clone();
+        // small order public key
+        batch[17].0 = EdDSAPublicKey { pk: Affine::zero() };
+        assert!(
+            !verify_batch(&batch, &mut rng),
+            "batch with invalid signatures should not verify"
+        );
+        assert_eq!(
+            verify_batch_with_failures(&batch, &mut rng),
+            Err(vec![3, 11, 17]),
+            "invalid entries should be reported"
+        );
+        for (i, (pk, message, signature)) in batch.iter().enumerate() {
+            assert_eq!(
+                verify_batch(&[(pk.clone(), *message, signature.clone())], &mut rng),
+                pk.verify(*message, signature),
+                "single entry batch {i} should match individual verification"
+            );
+        }
+    }
+
     #[test]
     fn test_encoding_roundtrip() {
         let sk = b"1cc01b8ddd6851915a42e0cfc6b7088c";
@@ -454,4 +959,30 @@ mod tests {
             "roundtripped signature should still verify"
         );
     }
+
+    #[test]
+    fn test_private_key_ct_eq_select() {
+        let mut rng = rand::thread_rng();
+        let a = EdDSAPrivateKey::random(&mut rng);
+        let b = EdDSAPrivateKey::random(&mut rng);
+        assert!(a == a.clone(), "key should equal its clone");
+        assert!(a != b, "random keys should differ");
+        assert!(
+            bool::from(a.ct_eq(&a.clone())),
+            "key should equal its clone"
+        );
+        assert!(
+            EdDSAPrivateKey::conditional_select(&a, &b, Choice::from(0)) == a,
+            "choice 0 should select a"
+        );
+        assert!(
+            EdDSAPrivateKey::conditional_select(&a, &b, Choice::from(1)) == b,
+            "choice 1 should select b"
+        );
+        let mut c = a.clone();
+        c.conditional_assign(&b, Choice::from(0));
+        assert!(c == a, "choice 0 should not assign");
+        c.conditional_assign(&b, Choice::from(1));
+        assert!(c == b, "choice 1 should assign");
+    }
 }
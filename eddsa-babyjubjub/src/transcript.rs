@@ -0,0 +1,217 @@
+//! A Fiat-Shamir transcript for sigma protocols over Baby Jubjub, built on a duplex sponge over the Poseidon2 permutation.
+//!
+//! The prover and the verifier append the same labeled messages (base field elements, scalars and points) to a [`Transcript`] and derive challenges from it.
+//! With the `r1cs` feature, `constraints::TranscriptVar` derives exactly the same challenges in a circuit.
+//!
+//! # Construction
+//!
+//! The transcript uses the Poseidon2 permutation with state size 8 as a duplex sponge with rate 7 (`state[1..8]`) and capacity 1 (`state[0]`).
+//! 1. The state is initialized with `hash_bytes_to_field(b"Poseidon2 Transcript" || domain_separator)` (see [`hash_bytes_to_field`]) in the capacity element and zeros in all other elements.
+//! 2. Every operation first absorbs a label element `hash_bytes_to_field(kind || label)`, where `kind` is a single byte identifying the operation:
+//!    `b'F'` for [`Transcript::append_field`], `b'S'` for [`Transcript::append_scalar`], `b'P'` for [`Transcript::append_point`] and `b'C'` for challenges.
+//!    Since the kind determines the number of absorbed elements, the sequence of absorbed elements uniquely encodes the sequence of operations.
+//! 3. Appending absorbs the label element followed by the value: a base field element as is, a scalar as the base field element with the same integer value (scalars are smaller than the base field modulus), and a point as its affine coordinates `x, y`.
+//!    Absorbing adds each element to the next position of the rate part, and applies the permutation whenever the rate part is full.
+//! 4. A challenge absorbs its label element, applies the permutation, and returns `state[1]`. Absorbing afterwards starts again at the first position of the rate part.
+//!    Challenges in the scalar field are reduced mod `L` like the `EdDSA` challenge.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let mut transcript = Transcript::new(b"my sigma protocol");
+//! transcript.append_point(b"commitment", commitment);
+//! let challenge = transcript.challenge_scalar(b"challenge");
+//! ```
+
+use ark_ff::{PrimeField, Zero};
+
+use crate::{Affine, BaseField, ScalarField, convert_base_to_scalar, hash_bytes_to_field};
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+const TRANSCRIPT_DS: &[u8] = b"Poseidon2 Transcript";
+
+/// The number of state elements which are absorbed into and squeezed from.
+const RATE: usize = 7;
+
+const FIELD_KIND: u8 = b'F';
+const SCALAR_KIND: u8 = b'S';
+const POINT_KIND: u8 = b'P';
+const CHALLENGE_KIND: u8 = b'C';
+
+/// A Fiat-Shamir transcript over a Poseidon2 duplex sponge, see the [module documentation](self) for the exact construction.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    state: [BaseField; 8],
+    /// The next position in the rate part to absorb into.
+    pos: usize,
+}
+
+impl Transcript {
+    /// Creates a new transcript for the protocol identified by `domain_separator`.
+    #[must_use]
+    pub fn new(domain_separator: &[u8]) -> Self {
+        let mut state = [BaseField::zero(); 8];
+        state[0] = hash_bytes_to_field(&[TRANSCRIPT_DS, domain_separator].concat());
+        Self { state, pos: 0 }
+    }
+
+    /// Appends a labeled base field element to the transcript.
+    pub fn append_field(&mut self, label: &[u8], value: BaseField) {
+        self.absorb(&[label_element(FIELD_KIND, label), value]);
+    }
+
+    /// Appends a labeled scalar to the transcript.
+    pub fn append_scalar(&mut self, label: &[u8], value: ScalarField) {
+        self.absorb(&[
+            label_element(SCALAR_KIND, label),
+            BaseField::from(value.into_bigint()),
+        ]);
+    }
+
+    /// Appends a labeled point to the transcript.
+    ///
+    /// The point is absorbed by its affine coordinates, so it is not checked to be in the prime-order subgroup.
+    pub fn append_point(&mut self, label: &[u8], point: Affine) {
+        self.absorb(&[label_element(POINT_KIND, label), point.x, point.y]);
+    }
+
+    /// Derives a labeled challenge in the base field from the transcript.
+    ///
+    /// The challenge depends on all previously appended messages and challenges.
+    #[must_use]
+    pub fn challenge_base(&mut self, label: &[u8]) -> BaseField {
+        self.absorb(&[label_element(CHALLENGE_KIND, label)]);
+        poseidon2::bn254::t8::permutation_in_place(&mut self.state);
+        self.pos = 0;
+        self.state[1]
+    }
+
+    /// Derives a labeled challenge in the scalar field from the transcript.
+    ///
+    /// This is [`Self::challenge_base`] reduced mod the scalar field order `L`, like the `EdDSA` challenge. The bias of the reduction is negligible, since the base field modulus is close to `8L`.
+    #[must_use]
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> ScalarField {
+        convert_base_to_scalar(self.challenge_base(label))
+    }
+
+    /// Adds the elements to the rate part of the state, applying the permutation whenever it is full.
+    fn absorb(&mut self, input: &[BaseField]) {
+        for x in input {
+            self.state[1 + self.pos] += x;
+            self.pos += 1;
+            if self.pos == RATE {
+                poseidon2::bn254::t8::permutation_in_place(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+}
+
+/// Encodes the kind of an operation and its label as a single base field element.
+fn label_element(kind: u8, label: &[u8]) -> BaseField {
+    hash_bytes_to_field(&[&[kind], label].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::UniformRand;
+    use std::str::FromStr;
+
+    fn run(transcript: &mut Transcript, point: Affine) -> (BaseField, ScalarField) {
+        transcript.append_point(b"commitment", point);
+        transcript.append_scalar(b"response", ScalarField::from(42u64));
+        for i in 0..5u64 {
+            transcript.append_field(b"message", BaseField::from(i));
+        }
+        let c1 = transcript.challenge_base(b"first");
+        let c2 = transcript.challenge_scalar(b"second");
+        (c1, c2)
+    }
+
+    #[test]
+    fn transcript_is_deterministic() {
+        let mut rng = rand::thread_rng();
+        let point = (Affine::generator() * ScalarField::rand(&mut rng)).into_affine();
+        let (c1, c2) = run(&mut Transcript::new(b"test"), point);
+        assert_eq!(
+            (c1, c2),
+            run(&mut Transcript::new(b"test"), point),
+            "same transcript should give the same challenges"
+        );
+        assert_ne!(
+            BaseField::from(c2.into_bigint()),
+            c1,
+            "consecutive challenges should differ"
+        );
+        assert_ne!(
+            (c1, c2),
+            run(&mut Transcript::new(b"test2"), point),
+            "different domain separators should give different challenges"
+        );
+        assert_ne!(
+            (c1, c2),
+            run(&mut Transcript::new(b"test"), Affine::generator()),
+            "different messages should give different challenges"
+        );
+    }
+
+    #[test]
+    fn transcript_separates_labels_and_kinds() {
+        let challenge = |f: &dyn Fn(&mut Transcript)| {
+            let mut transcript = Transcript::new(b"test");
+            f(&mut transcript);
+            transcript.challenge_base(b"challenge")
+        };
+        let value = BaseField::from(7u64);
+        let field = challenge(&|t| t.append_field(b"a", value));
+        assert_ne!(
+            field,
+            challenge(&|t| t.append_field(b"b", value)),
+            "different labels should give different challenges"
+        );
+        assert_ne!(
+            field,
+            challenge(&|t| t.append_scalar(b"a", ScalarField::from(7u64))),
+            "scalars and base field elements should be separated"
+        );
+        assert_ne!(
+            challenge(&|t| {
+                t.append_field(b"a", BaseField::zero());
+                t.append_field(b"a", BaseField::zero());
+            }),
+            challenge(&|t| t.append_field(b"a", BaseField::zero())),
+            "the number of messages should matter"
+        );
+        assert_ne!(
+            challenge(&|_| {}),
+            Transcript::new(b"test").challenge_base(b"other"),
+            "challenge labels should matter"
+        );
+    }
+
+    #[test]
+    fn transcript_kat() {
+        let mut transcript = Transcript::new(b"test");
+        let (c1, c2) = run(&mut transcript, Affine::generator());
+        assert_eq!(
+            c1,
+            BaseField::from_str(
+                "9535861137034141168962597084754357544872321747004571543301030703973254190609"
+            )
+            .expect("Is in BaseField"),
+            "base challenge should match KAT"
+        );
+        assert_eq!(
+            c2,
+            ScalarField::from_str(
+                "2525092095297831513274992400764581807748877299853674998113059031944624304632"
+            )
+            .expect("Is in ScalarField"),
+            "scalar challenge should match KAT"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! The R1CS equivalent of the [`Transcript`].
+//!
+//! [`TranscriptVar`] performs the same operations as the native transcript on variables, such that both derive identical challenges for identical messages.
+//! Labels and the domain separator are constants of the circuit, so their encodings are computed natively and cost no constraints. Each permutation costs the constraints of one Poseidon2 permutation with state size 8.
+
+use alloc::vec::Vec;
+use ark_babyjubjub::constraints::{EdwardsVar, FqVar};
+use ark_r1cs_std::{fields::fp::FpVar, prelude::*};
+use ark_relations::gr1cs::SynthesisError;
+
+use super::{CHALLENGE_KIND, FIELD_KIND, POINT_KIND, RATE, SCALAR_KIND, Transcript, label_element};
+use crate::{BaseField, constraints::convert_base_to_scalar_var};
+
+/// The R1CS equivalent of [`Transcript`].
+#[derive(Debug, Clone)]
+pub struct TranscriptVar {
+    state: [FqVar; 8],
+    /// The next position in the rate part to absorb into.
+    pos: usize,
+}
+
+impl TranscriptVar {
+    /// Creates a new transcript for the protocol identified by `domain_separator`, like [`Transcript::new`].
+    #[must_use]
+    pub fn new(domain_separator: &[u8]) -> Self {
+        let transcript = Transcript::new(domain_separator);
+        Self {
+            state: transcript.state.map(FpVar::constant),
+            pos: transcript.pos,
+        }
+    }
+
+    /// Appends a labeled base field element to the transcript, like [`Transcript::append_field`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn append_field(&mut self, label: &[u8], value: &FqVar) -> Result<(), SynthesisError> {
+        self.absorb(&[
+            FpVar::constant(label_element(FIELD_KIND, label)),
+            value.clone(),
+        ])
+    }
+
+    /// Appends a labeled scalar to the transcript, like [`Transcript::append_scalar`].
+    ///
+    /// The scalar is represented as an element of the constraint field with the same integer value. It is not range checked, callers have to enforce that it is smaller than the scalar field order if required by the protocol.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn append_scalar(&mut self, label: &[u8], value: &FqVar) -> Result<(), SynthesisError> {
+        self.absorb(&[
+            FpVar::constant(label_element(SCALAR_KIND, label)),
+            value.clone(),
+        ])
+    }
+
+    /// Appends a labeled point to the transcript, like [`Transcript::append_point`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn append_point(&mut self, label: &[u8], point: &EdwardsVar) -> Result<(), SynthesisError> {
+        self.absorb(&[
+            FpVar::constant(label_element(POINT_KIND, label)),
+            point.x.clone(),
+            point.y.clone(),
+        ])
+    }
+
+    /// Derives a labeled challenge in the base field from the transcript, like [`Transcript::challenge_base`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn challenge_base(&mut self, label: &[u8]) -> Result<FqVar, SynthesisError> {
+        self.absorb(&[FpVar::constant(label_element(CHALLENGE_KIND, label))])?;
+        poseidon2::constraints::bn254::t8::permutation_in_place_var(&mut self.state)?;
+        self.pos = 0;
+        Ok(self.state[1].clone())
+    }
+
+    /// Derives a labeled challenge in the scalar field from the transcript, like [`Transcript::challenge_scalar`].
+    ///
+    /// Returns the little-endian bits of the challenge, see [`convert_base_to_scalar_var`], which can be used directly for scalar multiplications.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn challenge_scalar(
+        &mut self,
+        label: &[u8],
+    ) -> Result<Vec<Boolean<BaseField>>, SynthesisError> {
+        let challenge = self.challenge_base(label)?;
+        convert_base_to_scalar_var(&challenge)
+    }
+
+    /// Adds the elements to the rate part of the state, applying the permutation whenever it is full.
+    fn absorb(&mut self, input: &[FqVar]) -> Result<(), SynthesisError> {
+        for x in input {
+            self.state[1 + self.pos] += x;
+            self.pos += 1;
+            if self.pos == RATE {
+                poseidon2::constraints::bn254::t8::permutation_in_place_var(&mut self.state)?;
+                self.pos = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Affine, ScalarField};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField, UniformRand};
+    use ark_relations::gr1cs::ConstraintSystem;
+
+    #[test]
+    fn transcript_var_matches_native() {
+        let mut rng = rand::thread_rng();
+        let point = (Affine::generator() * ScalarField::rand(&mut rng)).into_affine();
+        let scalar = ScalarField::rand(&mut rng);
+        let messages = (0..5)
+            .map(|_| BaseField::rand(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut transcript = Transcript::new(b"test");
+        transcript.append_point(b"commitment", point);
+        transcript.append_scalar(b"response", scalar);
+        for message in &messages {
+            transcript.append_field(b"message", *message);
+        }
+        let c1 = transcript.challenge_base(b"first");
+        transcript.append_field(b"message", messages[0]);
+        let c2 = transcript.challenge_scalar(b"second");
+
+        let cs = ConstraintSystem::<BaseField>::new_ref();
+        let point_var =
+            EdwardsVar::new_witness(cs.clone(), || Ok(point)).expect("allocation succeeds");
+        let scalar_var =
+            FqVar::new_witness(cs.clone(), || Ok(BaseField::from(scalar.into_bigint())))
+                .expect("allocation succeeds");
+        let message_vars = messages
+            .iter()
+            .map(|message| FqVar::new_witness(cs.clone(), || Ok(*message)))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("allocation succeeds");
+
+        let mut transcript_var = TranscriptVar::new(b"test");
+        transcript_var
+            .append_point(b"commitment", &point_var)
+            .expect("absorbing succeeds");
+        transcript_var
+            .append_scalar(b"response", &scalar_var)
+            .expect("absorbing succeeds");
+        for message in &message_vars {
+            transcript_var
+                .append_field(b"message", message)
+                .expect("absorbing succeeds");
+        }
+        let c1_var = transcript_var
+            .challenge_base(b"first")
+            .expect("squeezing succeeds");
+        transcript_var
+            .append_field(b"message", &message_vars[0])
+            .expect("absorbing succeeds");
+        let c2_var = transcript_var
+            .challenge_scalar(b"second")
+            .expect("squeezing succeeds");
+
+        assert_eq!(
+            c1_var.value().expect("value is assigned"),
+            c1,
+            "base challenge should match native transcript"
+        );
+        let c2_bits = c2_var
+            .iter()
+            .map(|bit| bit.value().expect("value is assigned"))
+            .collect::<Vec<_>>();
+        let mut expected_bits = c2.into_bigint().to_bits_le();
+        expected_bits.truncate(c2_bits.len());
+        assert_eq!(
+            c2_bits, expected_bits,
+            "scalar challenge should match native transcript"
+        );
+        assert!(
+            cs.is_satisfied().expect("constraint system is consistent"),
+            "constraints should be satisfied"
+        );
+    }
+}
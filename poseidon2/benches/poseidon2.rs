@@ -1,6 +1,6 @@
 //! Benchmarks for the Poseidon2 permutation.
 
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
 
 fn poseidon2_bench(c: &mut Criterion) {
     c.bench_function("Poseidon2 Permutation (t=3)", |b| {
@@ -24,5 +24,49 @@ fn poseidon2_bench(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, poseidon2_bench);
+const BATCH_SIZE: usize = 1 << 12;
+
+/// Compares permuting the states one by one with the batch permutation (which is also parallel with the `parallel` feature).
+fn batch_bench<const T: usize>(
+    c: &mut Criterion,
+    permutation_in_place: fn(&mut [ark_bn254::Fr; T]),
+    permutation_batch: fn(&mut [[ark_bn254::Fr; T]]),
+) {
+    let input = (0..BATCH_SIZE as u64)
+        .map(|i| std::array::from_fn(|j| ark_bn254::Fr::from(i * T as u64 + j as u64)))
+        .collect::<Vec<[ark_bn254::Fr; T]>>();
+
+    let mut group = c.benchmark_group(format!("Poseidon2 Batch Permutation (t={T})"));
+    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+    group.bench_function("one by one", |b| {
+        b.iter_batched_ref(
+            || input.clone(),
+            |states| states.iter_mut().for_each(permutation_in_place),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("batch", |b| {
+        b.iter_batched_ref(
+            || input.clone(),
+            |states| permutation_batch(states),
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+fn poseidon2_batch_bench(c: &mut Criterion) {
+    batch_bench(
+        c,
+        taceo_poseidon2::bn254::t3::permutation_in_place,
+        taceo_poseidon2::bn254::t3::permutation_batch,
+    );
+    batch_bench(
+        c,
+        taceo_poseidon2::bn254::t16::permutation_in_place,
+        taceo_poseidon2::bn254::t16::permutation_batch,
+    );
+}
+
+criterion_group!(benches, poseidon2_bench, poseidon2_batch_bench);
 criterion_main!(benches);
@@ -0,0 +1,288 @@
+//! Authenticated encryption of field elements with associated data, based on a duplex sponge over the Poseidon2 permutation.
+//!
+//! The scheme follows the spirit of the Poseidon encryption scheme from "Encryption with Poseidon" by Dmitry Khovratovich, which is used by zk-kit and MACI, but is built on Poseidon2 and additionally authenticates associated data.
+//! Since encryption and decryption only consist of permutations and field additions, decryption can be proven efficiently in a circuit.
+//!
+//! # Construction
+//!
+//! The state of size `T >= 4` is split into the capacity `state[0]` and the rate part `state[1..T]`, i.e., the rate is `T - 1`.
+//! 1. The state is initialized with `[IV, key[0], key[1], nonce, 0, ..., 0]` and permuted, where `IV = ad.len() + 2^64 * plaintext.len()`.
+//! 2. The associated data is absorbed in chunks of `T - 1` elements, adding each chunk to the rate part and applying the permutation afterwards.
+//! 3. The plaintext is processed in chunks of `T - 1` elements: each element is added to its position in the rate part, the resulting rate elements are the ciphertext, and the permutation is applied afterwards.
+//!    The last chunk may be shorter than the rate, no padding is applied since the lengths are part of the `IV`.
+//! 4. The tag is `state[1]` after the last permutation.
+//!
+//! Decryption recomputes the state by subtracting the rate elements from the ciphertext and replacing the rate elements with the ciphertext, and only returns the plaintext if the tag matches.
+//! The tags are compared limb-wise in constant time.
+//!
+//! The `IV` encodes both lengths injectively only if the field has more than 2^128 elements, so [`Poseidon2Aead::new`] rejects fields with `MODULUS_BIT_SIZE <= 128`, e.g., Goldilocks, `BabyBear` and Mersenne-31, at compile time.
+//!
+//! # Security
+//!
+//! A nonce must never be used twice with the same key, otherwise the confidentiality of both plaintexts is lost. The key should be uniformly random in `F^2`, e.g., the coordinates of an ECDH shared secret.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use poseidon2::aead::POSEIDON2_AEAD_BN254_T4;
+//!
+//! let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T4.encrypt(&key, nonce, &ad, &plaintext);
+//! let decrypted = POSEIDON2_AEAD_BN254_T4.decrypt(&key, nonce, &ad, &ciphertext, tag)?;
+//! ```
+
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+use subtle::ConstantTimeEq;
+
+/// The errors that can occur when decrypting with a [`Poseidon2Aead`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum AeadError {
+    /// The tag does not match the key, nonce, associated data and ciphertext.
+    #[error("invalid authentication tag")]
+    InvalidTag,
+}
+
+/// An authenticated encryption scheme over the Poseidon2 permutation with state size `T`, see the [module documentation](self) for the exact construction.
+#[derive(Debug, Clone, Copy)]
+pub struct Poseidon2Aead<F: PrimeField, const T: usize> {
+    permutation: fn(&mut [F; T]),
+}
+
+/// The authenticated encryption scheme over the `bn254` Poseidon2 permutation with state size 4, i.e., rate 3.
+#[cfg(all(feature = "bn254", feature = "t4"))]
+pub const POSEIDON2_AEAD_BN254_T4: Poseidon2Aead<ark_bn254::Fr, 4> =
+    Poseidon2Aead::new(crate::bn254::t4::permutation_in_place);
+
+/// The authenticated encryption scheme over the `bn254` Poseidon2 permutation with state size 8, i.e., rate 7.
+#[cfg(all(feature = "bn254", feature = "t8"))]
+pub const POSEIDON2_AEAD_BN254_T8: Poseidon2Aead<ark_bn254::Fr, 8> =
+    Poseidon2Aead::new(crate::bn254::t8::permutation_in_place);
+
+impl<F: PrimeField, const T: usize> Poseidon2Aead<F, T> {
+    /// Creates a new authenticated encryption scheme from the given in-place permutation.
+    ///
+    /// State sizes smaller than 4 are rejected at compile time, as the key and the nonce have to fit into the rate part.
+    /// Fields with at most 128 bits are rejected at compile time as well, since the `IV` would not encode the lengths injectively, see the [module documentation](self).
+    #[must_use]
+    pub const fn new(permutation: fn(&mut [F; T])) -> Self {
+        const {
+            assert!(T >= 4, "state size T must be at least 4");
+            assert!(
+                F::MODULUS_BIT_SIZE > 128,
+                "the field must have more than 128 bits"
+            );
+        }
+        Self { permutation }
+    }
+
+    /// Encrypts `plaintext` under `key` and `nonce` and authenticates it together with `ad`.
+    ///
+    /// Returns the ciphertext, which has the same length as the plaintext, and the tag.
+    /// The `nonce` must be unique for each encryption under the same `key`.
+    pub fn encrypt(&self, key: &[F; 2], nonce: F, ad: &[F], plaintext: &[F]) -> (Vec<F>, F) {
+        let mut state = self.init(key, nonce, ad, plaintext.len());
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for chunk in plaintext.chunks(T - 1) {
+            for (s, m) in state[1..].iter_mut().zip(chunk) {
+                *s += m;
+                ciphertext.push(*s);
+            }
+            (self.permutation)(&mut state);
+        }
+        (ciphertext, state[1])
+    }
+
+    /// Decrypts `ciphertext` under `key` and `nonce` and checks the `tag` for the ciphertext and `ad`.
+    ///
+    /// # Errors
+    /// Returns [`AeadError::InvalidTag`] if the tag does not match, e.g., because the ciphertext, the associated data, the key or the nonce differ from the encryption.
+    pub fn decrypt(
+        &self,
+        key: &[F; 2],
+        nonce: F,
+        ad: &[F],
+        ciphertext: &[F],
+        tag: F,
+    ) -> Result<Vec<F>, AeadError> {
+        let mut state = self.init(key, nonce, ad, ciphertext.len());
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        for chunk in ciphertext.chunks(T - 1) {
+            for (s, c) in state[1..].iter_mut().zip(chunk) {
+                plaintext.push(*c - *s);
+                *s = *c;
+            }
+            (self.permutation)(&mut state);
+        }
+        let expected = state[1].into_bigint();
+        let tag = tag.into_bigint();
+        if bool::from(expected.as_ref().ct_eq(tag.as_ref())) {
+            Ok(plaintext)
+        } else {
+            Err(AeadError::InvalidTag)
+        }
+    }
+
+    /// Initializes the state with the key, the nonce and the lengths, and absorbs the associated data.
+    fn init(self, key: &[F; 2], nonce: F, ad: &[F], message_len: usize) -> [F; T] {
+        let mut state = [F::zero(); T];
+        state[0] = F::from(ad.len() as u64) + F::from(u128::from(message_len as u64) << 64);
+        state[1] = key[0];
+        state[2] = key[1];
+        state[3] = nonce;
+        (self.permutation)(&mut state);
+
+        for chunk in ad.chunks(T - 1) {
+            for (s, x) in state[1..].iter_mut().zip(chunk) {
+                *s += x;
+            }
+            (self.permutation)(&mut state);
+        }
+        state
+    }
+}
+
+#[cfg(all(feature = "bn254", feature = "t4", feature = "t8"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254;
+    use ark_std::{UniformRand, rand::thread_rng};
+    use std::str::FromStr;
+
+    type Scalar = ark_bn254::Fr;
+
+    fn random_vec(len: usize) -> Vec<Scalar> {
+        let mut rng = thread_rng();
+        (0..len).map(|_| Scalar::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn aead_roundtrip() {
+        let mut rng = thread_rng();
+        let key = [Scalar::rand(&mut rng), Scalar::rand(&mut rng)];
+        let nonce = Scalar::rand(&mut rng);
+        for (ad_len, len) in [(0, 0), (0, 1), (1, 3), (2, 4), (7, 7), (8, 10), (0, 15)] {
+            let ad = random_vec(ad_len);
+            let plaintext = random_vec(len);
+
+            let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T4.encrypt(&key, nonce, &ad, &plaintext);
+            assert_eq!(ciphertext.len(), len, "ciphertext has the plaintext length");
+            let decrypted = POSEIDON2_AEAD_BN254_T4
+                .decrypt(&key, nonce, &ad, &ciphertext, tag)
+                .expect("tag is valid");
+            assert_eq!(decrypted, plaintext, "t4 decryption differs for {len}");
+
+            let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T8.encrypt(&key, nonce, &ad, &plaintext);
+            let decrypted = POSEIDON2_AEAD_BN254_T8
+                .decrypt(&key, nonce, &ad, &ciphertext, tag)
+                .expect("tag is valid");
+            assert_eq!(decrypted, plaintext, "t8 decryption differs for {len}");
+        }
+    }
+
+    #[test]
+    fn aead_rejects_modifications() {
+        let mut rng = thread_rng();
+        let key = [Scalar::rand(&mut rng), Scalar::rand(&mut rng)];
+        let nonce = Scalar::rand(&mut rng);
+        let ad = random_vec(2);
+        let plaintext = random_vec(5);
+        let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T4.encrypt(&key, nonce, &ad, &plaintext);
+        let decrypt = |key: &[Scalar; 2], nonce, ad: &[Scalar], ciphertext: &[Scalar], tag| {
+            POSEIDON2_AEAD_BN254_T4.decrypt(key, nonce, ad, ciphertext, tag)
+        };
+
+        let one = Scalar::from(1u64);
+        let mut modified = ciphertext.clone();
+        modified[4] += one;
+        assert_eq!(
+            decrypt(&key, nonce, &ad, &modified, tag),
+            Err(AeadError::InvalidTag),
+            "modified ciphertext should be rejected"
+        );
+        assert_eq!(
+            decrypt(&key, nonce, &ad, &ciphertext[..4], tag),
+            Err(AeadError::InvalidTag),
+            "truncated ciphertext should be rejected"
+        );
+        assert_eq!(
+            decrypt(&key, nonce, &ad, &ciphertext, tag + one),
+            Err(AeadError::InvalidTag),
+            "modified tag should be rejected"
+        );
+        assert_eq!(
+            decrypt(&key, nonce, &ad[..1], &ciphertext, tag),
+            Err(AeadError::InvalidTag),
+            "modified associated data should be rejected"
+        );
+        assert_eq!(
+            decrypt(&key, nonce + one, &ad, &ciphertext, tag),
+            Err(AeadError::InvalidTag),
+            "wrong nonce should be rejected"
+        );
+        assert_eq!(
+            decrypt(&[key[0], key[1] + one], nonce, &ad, &ciphertext, tag),
+            Err(AeadError::InvalidTag),
+            "wrong key should be rejected"
+        );
+    }
+
+    #[test]
+    fn aead_matches_manual_construction() {
+        let key = [Scalar::from(1u64), Scalar::from(2u64)];
+        let nonce = Scalar::from(3u64);
+        let ad = random_vec(3);
+        let plaintext = random_vec(4);
+        let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T4.encrypt(&key, nonce, &ad, &plaintext);
+
+        let two_64 = Scalar::from(u128::from(u64::MAX) + 1);
+        let mut state = [
+            Scalar::from(3u64) + two_64 * Scalar::from(4u64),
+            key[0],
+            key[1],
+            nonce,
+        ];
+        bn254::t4::permutation_in_place(&mut state);
+        state[1..].iter_mut().zip(&ad).for_each(|(s, x)| *s += x);
+        bn254::t4::permutation_in_place(&mut state);
+        state[1..]
+            .iter_mut()
+            .zip(&plaintext[..3])
+            .for_each(|(s, m)| *s += m);
+        let mut expected = state[1..].to_vec();
+        bn254::t4::permutation_in_place(&mut state);
+        state[1] += plaintext[3];
+        expected.push(state[1]);
+        bn254::t4::permutation_in_place(&mut state);
+
+        assert_eq!(
+            ciphertext, expected,
+            "ciphertext should match manual construction"
+        );
+        assert_eq!(tag, state[1], "tag should match manual construction");
+    }
+
+    #[test]
+    fn aead_kat() {
+        let key = [Scalar::from(1u64), Scalar::from(2u64)];
+        let nonce = Scalar::from(3u64);
+        let ad = [Scalar::from(4u64)];
+        let plaintext = [Scalar::from(5u64), Scalar::from(6u64)];
+        let (ciphertext, tag) = POSEIDON2_AEAD_BN254_T4.encrypt(&key, nonce, &ad, &plaintext);
+        let expected = [
+            "13784563484298281616378201196657668659599152003018422257878571349157354306794",
+            "1861250340034857672568044299054409616115582107021931590614292874817055918000",
+        ]
+        .map(|x| Scalar::from_str(x).expect("Is in Fr"));
+        assert_eq!(ciphertext, expected, "ciphertext should match KAT");
+        assert_eq!(
+            tag,
+            Scalar::from_str(
+                "15603107298723751911762300122140783678623208962026878169604197516513432280047"
+            )
+            .expect("Is in Fr"),
+            "tag should match KAT"
+        );
+    }
+}
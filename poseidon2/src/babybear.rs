@@ -0,0 +1,31 @@
+//! Poseidon2 permutation methods for the `BabyBear` field `p = 15 * 2^27 + 1`, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
+//!
+//! This module provides the Poseidon2 permutation on the state sizes of the reference implementation (`t16`, `t24`).
+//!
+//! As specified in the paper, the S-box for `BabyBear` is defined as:
+//! $$ x^7 $$
+//!
+//! Parameters are compatible with the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let mut state = [...];
+//! poseidon2::babybear::t16::permutation(&state);
+//! poseidon2::babybear::t16::permutation_in_place(&mut state);
+//! ```
+
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+/// The Montgomery configuration of [`Fp`].
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+#[derive(MontConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+pub struct BabyBearConfig;
+
+/// The `BabyBear` field `p = 15 * 2^27 + 1`.
+pub type Fp = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+pub mod t16;
+pub mod t24;
@@ -0,0 +1,275 @@
+//! Poseidon2 implementation for `t=16` over `BabyBear`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 16;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 13;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("174271892"),
+    MontFp!("1840666551"),
+    MontFp!("1459346590"),
+    MontFp!("86719882"),
+    MontFp!("863261185"),
+    MontFp!("1543704716"),
+    MontFp!("199992187"),
+    MontFp!("629943947"),
+    MontFp!("302161721"),
+    MontFp!("1766394318"),
+    MontFp!("1831016228"),
+    MontFp!("566473250"),
+    MontFp!("1007223973"),
+    MontFp!("30846170"),
+    MontFp!("661675139"),
+    MontFp!("1378994178"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("1774958255"),
+        MontFp!("1185780729"),
+        MontFp!("1621102414"),
+        MontFp!("1796380621"),
+        MontFp!("588815102"),
+        MontFp!("1932426223"),
+        MontFp!("1925334750"),
+        MontFp!("747903232"),
+        MontFp!("89648862"),
+        MontFp!("360728943"),
+        MontFp!("977184635"),
+        MontFp!("1425273457"),
+        MontFp!("256487465"),
+        MontFp!("1200041953"),
+        MontFp!("572403254"),
+        MontFp!("448208942"),
+    ],
+    [
+        MontFp!("1215789478"),
+        MontFp!("944884184"),
+        MontFp!("953948096"),
+        MontFp!("547326025"),
+        MontFp!("646827752"),
+        MontFp!("889997530"),
+        MontFp!("1536873262"),
+        MontFp!("86189867"),
+        MontFp!("1065944411"),
+        MontFp!("32019634"),
+        MontFp!("333311454"),
+        MontFp!("456061748"),
+        MontFp!("1963448500"),
+        MontFp!("1827584334"),
+        MontFp!("1391160226"),
+        MontFp!("1348741381"),
+    ],
+    [
+        MontFp!("88424255"),
+        MontFp!("104111868"),
+        MontFp!("1763866748"),
+        MontFp!("79691676"),
+        MontFp!("1988915530"),
+        MontFp!("1050669594"),
+        MontFp!("359890076"),
+        MontFp!("573163527"),
+        MontFp!("222820492"),
+        MontFp!("159256268"),
+        MontFp!("669703072"),
+        MontFp!("763177444"),
+        MontFp!("889367200"),
+        MontFp!("256335831"),
+        MontFp!("704371273"),
+        MontFp!("25886717"),
+    ],
+    [
+        MontFp!("51754520"),
+        MontFp!("1833211857"),
+        MontFp!("454499742"),
+        MontFp!("1384520381"),
+        MontFp!("777848065"),
+        MontFp!("1053320300"),
+        MontFp!("1851729162"),
+        MontFp!("344647910"),
+        MontFp!("401996362"),
+        MontFp!("1046925956"),
+        MontFp!("5351995"),
+        MontFp!("1212119315"),
+        MontFp!("754867989"),
+        MontFp!("36972490"),
+        MontFp!("751272725"),
+        MontFp!("506915399"),
+    ],
+    // Second external
+    [
+        MontFp!("1922082829"),
+        MontFp!("1870549801"),
+        MontFp!("1502529704"),
+        MontFp!("1990744480"),
+        MontFp!("1700391016"),
+        MontFp!("1702593455"),
+        MontFp!("321330495"),
+        MontFp!("528965731"),
+        MontFp!("183414327"),
+        MontFp!("1886297254"),
+        MontFp!("1178602734"),
+        MontFp!("1923111974"),
+        MontFp!("744004766"),
+        MontFp!("549271463"),
+        MontFp!("1781349648"),
+        MontFp!("542259047"),
+    ],
+    [
+        MontFp!("1536158148"),
+        MontFp!("715456982"),
+        MontFp!("503426110"),
+        MontFp!("340311124"),
+        MontFp!("1558555932"),
+        MontFp!("1226350925"),
+        MontFp!("742828095"),
+        MontFp!("1338992758"),
+        MontFp!("1641600456"),
+        MontFp!("1843351545"),
+        MontFp!("301835475"),
+        MontFp!("43203215"),
+        MontFp!("386838401"),
+        MontFp!("1520185679"),
+        MontFp!("1235297680"),
+        MontFp!("904680097"),
+    ],
+    [
+        MontFp!("1491801617"),
+        MontFp!("1581784677"),
+        MontFp!("913384905"),
+        MontFp!("247083962"),
+        MontFp!("532844013"),
+        MontFp!("107190701"),
+        MontFp!("213827818"),
+        MontFp!("1979521776"),
+        MontFp!("1358282574"),
+        MontFp!("1681743681"),
+        MontFp!("1867507480"),
+        MontFp!("1530706910"),
+        MontFp!("507181886"),
+        MontFp!("695185447"),
+        MontFp!("1172395131"),
+        MontFp!("1250800299"),
+    ],
+    [
+        MontFp!("1503161625"),
+        MontFp!("817684387"),
+        MontFp!("498481458"),
+        MontFp!("494676004"),
+        MontFp!("1404253825"),
+        MontFp!("108246855"),
+        MontFp!("59414691"),
+        MontFp!("744214112"),
+        MontFp!("890862029"),
+        MontFp!("1342765939"),
+        MontFp!("1417398904"),
+        MontFp!("1897591937"),
+        MontFp!("1066647396"),
+        MontFp!("1682806907"),
+        MontFp!("1015795079"),
+        MontFp!("1619482808"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("1518359488"),
+    MontFp!("1765533241"),
+    MontFp!("945325693"),
+    MontFp!("422793067"),
+    MontFp!("311365592"),
+    MontFp!("1311448267"),
+    MontFp!("1629555936"),
+    MontFp!("1009879353"),
+    MontFp!("190525218"),
+    MontFp!("786108885"),
+    MontFp!("557776863"),
+    MontFp!("212616710"),
+    MontFp!("605745517"),
+];
+
+/// The Poseidon2 parameters for `BabyBear` with a internal state of size t=16.
+pub static POSEIDON2_BABYBEAR_T16_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 16-element state over `BabyBear`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::babybear::Fp; 16]`).
+///
+/// # Returns
+/// A permuted state as `[crate::babybear::Fp; 16]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 16]) -> [Scalar; 16] {
+    POSEIDON2_BABYBEAR_T16_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 16-element state over `BabyBear`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::babybear::Fp; 16]`).
+pub fn permutation_in_place(state: &mut [Scalar; 16]) {
+    POSEIDON2_BABYBEAR_T16_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        babybear::t16::POSEIDON2_BABYBEAR_T16_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_babybear_t16_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BABYBEAR_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_babybear_t16_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BABYBEAR_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_babybear_t16_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 16] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("896560466"),
+            MontFp!("771677727"),
+            MontFp!("128113032"),
+            MontFp!("1378976435"),
+            MontFp!("160019712"),
+            MontFp!("1452738514"),
+            MontFp!("682850273"),
+            MontFp!("223500421"),
+            MontFp!("501450187"),
+            MontFp!("1804685789"),
+            MontFp!("1671399593"),
+            MontFp!("1788755219"),
+            MontFp!("1736880027"),
+            MontFp!("1352180784"),
+            MontFp!("1928489698"),
+            MontFp!("1128802977"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BABYBEAR_T16_PARAMS, &input, &expected);
+    }
+}
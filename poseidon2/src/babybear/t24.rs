@@ -0,0 +1,363 @@
+//! Poseidon2 implementation for `t=24` over `BabyBear`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 24;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 21;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("1083257840"),
+    MontFp!("375892129"),
+    MontFp!("111593398"),
+    MontFp!("1867716110"),
+    MontFp!("658182609"),
+    MontFp!("51866717"),
+    MontFp!("1928969209"),
+    MontFp!("1942928017"),
+    MontFp!("1558116381"),
+    MontFp!("20525701"),
+    MontFp!("1188752902"),
+    MontFp!("106789798"),
+    MontFp!("1389833583"),
+    MontFp!("98371040"),
+    MontFp!("1001081699"),
+    MontFp!("1792686146"),
+    MontFp!("801504236"),
+    MontFp!("1997365680"),
+    MontFp!("1461037801"),
+    MontFp!("65998480"),
+    MontFp!("1974912880"),
+    MontFp!("606789471"),
+    MontFp!("13683276"),
+    MontFp!("918610824"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("262278199"),
+        MontFp!("127253399"),
+        MontFp!("314968988"),
+        MontFp!("246143118"),
+        MontFp!("157582794"),
+        MontFp!("118043943"),
+        MontFp!("454905424"),
+        MontFp!("815798990"),
+        MontFp!("1004040026"),
+        MontFp!("1773108264"),
+        MontFp!("1066694495"),
+        MontFp!("1930780904"),
+        MontFp!("1180307149"),
+        MontFp!("1464793095"),
+        MontFp!("1660766320"),
+        MontFp!("1389166148"),
+        MontFp!("343354132"),
+        MontFp!("1307439985"),
+        MontFp!("638242172"),
+        MontFp!("525458520"),
+        MontFp!("1964135730"),
+        MontFp!("1751797115"),
+        MontFp!("1421525369"),
+        MontFp!("831813382"),
+    ],
+    [
+        MontFp!("695835963"),
+        MontFp!("1845603984"),
+        MontFp!("540703332"),
+        MontFp!("1333667262"),
+        MontFp!("1917861751"),
+        MontFp!("1170029417"),
+        MontFp!("1989924532"),
+        MontFp!("1518763784"),
+        MontFp!("1339793538"),
+        MontFp!("622609176"),
+        MontFp!("686842369"),
+        MontFp!("1737016378"),
+        MontFp!("1282239129"),
+        MontFp!("897025192"),
+        MontFp!("716894289"),
+        MontFp!("1997503974"),
+        MontFp!("395622276"),
+        MontFp!("1201063290"),
+        MontFp!("1917549072"),
+        MontFp!("1150912935"),
+        MontFp!("1687379185"),
+        MontFp!("1507936940"),
+        MontFp!("241306552"),
+        MontFp!("989176635"),
+    ],
+    [
+        MontFp!("1147522062"),
+        MontFp!("27129487"),
+        MontFp!("1257820264"),
+        MontFp!("142102402"),
+        MontFp!("217046702"),
+        MontFp!("1664590951"),
+        MontFp!("855276054"),
+        MontFp!("1215259350"),
+        MontFp!("946500736"),
+        MontFp!("552696906"),
+        MontFp!("1424297384"),
+        MontFp!("538103555"),
+        MontFp!("1608853840"),
+        MontFp!("162510541"),
+        MontFp!("623051854"),
+        MontFp!("1549062383"),
+        MontFp!("1908416316"),
+        MontFp!("1622328571"),
+        MontFp!("1079030649"),
+        MontFp!("1584033957"),
+        MontFp!("1099252725"),
+        MontFp!("1910423126"),
+        MontFp!("447555988"),
+        MontFp!("862495875"),
+    ],
+    [
+        MontFp!("128479034"),
+        MontFp!("1587822577"),
+        MontFp!("608401422"),
+        MontFp!("1290028279"),
+        MontFp!("342857858"),
+        MontFp!("825405577"),
+        MontFp!("427731030"),
+        MontFp!("1718628547"),
+        MontFp!("588764636"),
+        MontFp!("204228775"),
+        MontFp!("1454563174"),
+        MontFp!("1740472809"),
+        MontFp!("1338899225"),
+        MontFp!("1269493554"),
+        MontFp!("53007114"),
+        MontFp!("1647670797"),
+        MontFp!("306391314"),
+        MontFp!("172614232"),
+        MontFp!("51256176"),
+        MontFp!("1221257987"),
+        MontFp!("1239734761"),
+        MontFp!("273790406"),
+        MontFp!("1781980094"),
+        MontFp!("1291790245"),
+    ],
+    // Second external
+    [
+        MontFp!("53041581"),
+        MontFp!("723038058"),
+        MontFp!("1439947916"),
+        MontFp!("1136469704"),
+        MontFp!("205609311"),
+        MontFp!("1883820770"),
+        MontFp!("14387587"),
+        MontFp!("720724951"),
+        MontFp!("1854174607"),
+        MontFp!("1629316321"),
+        MontFp!("530151394"),
+        MontFp!("1679178250"),
+        MontFp!("1549779579"),
+        MontFp!("48375137"),
+        MontFp!("976057819"),
+        MontFp!("463976218"),
+        MontFp!("875839332"),
+        MontFp!("1946596189"),
+        MontFp!("434078361"),
+        MontFp!("1878280202"),
+        MontFp!("1363837384"),
+        MontFp!("1470845646"),
+        MontFp!("1792450386"),
+        MontFp!("1040977421"),
+    ],
+    [
+        MontFp!("1209164052"),
+        MontFp!("714957516"),
+        MontFp!("390340387"),
+        MontFp!("1213686459"),
+        MontFp!("790726260"),
+        MontFp!("117294666"),
+        MontFp!("140621810"),
+        MontFp!("993455846"),
+        MontFp!("1889603648"),
+        MontFp!("78845751"),
+        MontFp!("925018226"),
+        MontFp!("708123747"),
+        MontFp!("1647665372"),
+        MontFp!("1649953458"),
+        MontFp!("942439428"),
+        MontFp!("1006235079"),
+        MontFp!("238616145"),
+        MontFp!("930036496"),
+        MontFp!("1401020792"),
+        MontFp!("989618631"),
+        MontFp!("1545325389"),
+        MontFp!("1715719711"),
+        MontFp!("755691969"),
+        MontFp!("150307788"),
+    ],
+    [
+        MontFp!("1567618575"),
+        MontFp!("1663353317"),
+        MontFp!("1950429111"),
+        MontFp!("1891637550"),
+        MontFp!("192082241"),
+        MontFp!("1080533265"),
+        MontFp!("1463323727"),
+        MontFp!("890243564"),
+        MontFp!("158646617"),
+        MontFp!("1402624179"),
+        MontFp!("59510015"),
+        MontFp!("1198261138"),
+        MontFp!("1065075039"),
+        MontFp!("1150410028"),
+        MontFp!("1293938517"),
+        MontFp!("76770019"),
+        MontFp!("1478577620"),
+        MontFp!("1748789933"),
+        MontFp!("457372011"),
+        MontFp!("1841795381"),
+        MontFp!("760115692"),
+        MontFp!("1042892522"),
+        MontFp!("1507649755"),
+        MontFp!("1827572010"),
+    ],
+    [
+        MontFp!("1206940496"),
+        MontFp!("1896271507"),
+        MontFp!("1003792297"),
+        MontFp!("738091882"),
+        MontFp!("1124078057"),
+        MontFp!("1889898"),
+        MontFp!("813674331"),
+        MontFp!("228520958"),
+        MontFp!("1832911930"),
+        MontFp!("781141772"),
+        MontFp!("459826664"),
+        MontFp!("202271745"),
+        MontFp!("1296144415"),
+        MontFp!("1111203133"),
+        MontFp!("1090783436"),
+        MontFp!("641665156"),
+        MontFp!("1393671120"),
+        MontFp!("1303271640"),
+        MontFp!("809508074"),
+        MontFp!("162506101"),
+        MontFp!("1262312258"),
+        MontFp!("1672219447"),
+        MontFp!("1608891156"),
+        MontFp!("1380248020"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("497520322"),
+    MontFp!("1930103076"),
+    MontFp!("1052077299"),
+    MontFp!("1540960371"),
+    MontFp!("924863639"),
+    MontFp!("1365519753"),
+    MontFp!("1726563304"),
+    MontFp!("440300254"),
+    MontFp!("1891545577"),
+    MontFp!("822033215"),
+    MontFp!("1111544260"),
+    MontFp!("308575117"),
+    MontFp!("1708681573"),
+    MontFp!("1240419708"),
+    MontFp!("1199068823"),
+    MontFp!("1186174623"),
+    MontFp!("1551596046"),
+    MontFp!("1886977120"),
+    MontFp!("1327682690"),
+    MontFp!("1210751726"),
+    MontFp!("1810596765"),
+];
+
+/// The Poseidon2 parameters for `BabyBear` with a internal state of size t=24.
+pub static POSEIDON2_BABYBEAR_T24_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 24-element state over `BabyBear`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::babybear::Fp; 24]`).
+///
+/// # Returns
+/// A permuted state as `[crate::babybear::Fp; 24]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 24]) -> [Scalar; 24] {
+    POSEIDON2_BABYBEAR_T24_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 24-element state over `BabyBear`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::babybear::Fp; 24]`).
+pub fn permutation_in_place(state: &mut [Scalar; 24]) {
+    POSEIDON2_BABYBEAR_T24_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        babybear::t24::POSEIDON2_BABYBEAR_T24_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_babybear_t24_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BABYBEAR_T24_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_babybear_t24_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BABYBEAR_T24_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_babybear_t24_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 24] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("785637949"),
+            MontFp!("311566256"),
+            MontFp!("241540729"),
+            MontFp!("1641553353"),
+            MontFp!("851108667"),
+            MontFp!("1648913123"),
+            MontFp!("510139232"),
+            MontFp!("616108837"),
+            MontFp!("707720633"),
+            MontFp!("1357404478"),
+            MontFp!("1539840236"),
+            MontFp!("275323287"),
+            MontFp!("899761440"),
+            MontFp!("732341189"),
+            MontFp!("664618988"),
+            MontFp!("1426148993"),
+            MontFp!("1498654335"),
+            MontFp!("792736017"),
+            MontFp!("1804085503"),
+            MontFp!("402731039"),
+            MontFp!("659103866"),
+            MontFp!("1036635937"),
+            MontFp!("1016617890"),
+            MontFp!("1470732388"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BABYBEAR_T24_PARAMS, &input, &expected);
+    }
+}
@@ -0,0 +1,34 @@
+//! Poseidon2 permutation methods for the scalar field of `BLS12-381`, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
+//!
+//! This module provides the Poseidon2 permutation on the state sizes of the reference implementation (`t2`, `t3`, `t4`, `t8`).
+//!
+//! As for `bn254`, the S-box for `BLS12-381` is defined as:
+//! $$ x^5 $$
+//!
+//! Parameters are compatible with the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
+//! The field is defined in this module with the same modulus and generator as `ark_bls12_381::Fr`, so elements can be converted with [`PrimeField::from_bigint`](ark_ff::PrimeField::from_bigint).
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let mut state = [...];
+//! poseidon2::bls12_381::t2::permutation(&state);
+//! poseidon2::bls12_381::t2::permutation_in_place(&mut state);
+//! ```
+
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+
+/// The Montgomery configuration of [`Fr`].
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+#[derive(MontConfig)]
+#[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+#[generator = "7"]
+pub struct FrConfig;
+
+/// The scalar field of `BLS12-381`.
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+pub mod t2;
+pub mod t3;
+pub mod t4;
+pub mod t8;
@@ -0,0 +1,181 @@
+//! Poseidon2 implementation for `t=2` over `BLS12-381`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fr;
+
+const T: usize = 2;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 56;
+
+const MAT_DIAG_M_1: [Scalar; T] = [MontFp!("1"), MontFp!("2")];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("44510337639712444877093863969199054965277800588455612249278638908194748645831"),
+        MontFp!("21803715039317278198490310228838761820084178670568647145430631061363562182159"),
+    ],
+    [
+        MontFp!("7624865858307587153533893753671854337113466346291121078558552645350285711947"),
+        MontFp!("40816250157678830542785454550323790288400761867270997552332922267166370848099"),
+    ],
+    [
+        MontFp!("26700489303136047462599262740180012654857443933973506452655094204874268181798"),
+        MontFp!("29300041198680547975810813644545348954050411371551740473502764872245855641482"),
+    ],
+    [
+        MontFp!("26494260871076350781917504826961109818301921647993891506179327799406892257760"),
+        MontFp!("51471943067203395853539598076816386277188697473371359746626216561944728278869"),
+    ],
+    // Second external
+    [
+        MontFp!("49940012233787551970719440300197866554675665187348390484098206972627022147562"),
+        MontFp!("46156268877611784805956766593634373731938578230585206172333523828368963221701"),
+    ],
+    [
+        MontFp!("22953533088186447995354081903847946065608888217162100116127853233026059928601"),
+        MontFp!("52274456635025394989373456728632213614198023598074828050923870917877714774032"),
+    ],
+    [
+        MontFp!("10763107301772445560209819564880953581415947909035345171575074311681161298071"),
+        MontFp!("18925434003927090811791086956853651992753402455627802833236246367412669605539"),
+    ],
+    [
+        MontFp!("44640541969065703218376218137336488126193962189090743983027473909004591005110"),
+        MontFp!("6325804276918590364991192431609508508777152352802958405080031341778877821773"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("48874150250826827063647140518997592549563417409147246235831213929889330889464"),
+    MontFp!("4957296567799842922524759318027693610815701909959689401077625970883603151110"),
+    MontFp!("49787130886622940646628207982474849305464467960406760686521606845929813913147"),
+    MontFp!("38626507234346048667761615866199783635070759234617387640403950557591257611930"),
+    MontFp!("43672937506493322470130890010227422460105683953151094688032165492686807529714"),
+    MontFp!("10282858088808039236495153687326481750629167926398528104702176163827531439774"),
+    MontFp!("5929373583590601619353793840106929273025491048347772550388315300478156302480"),
+    MontFp!("40523767159781096993564794726793344971416282562716017669035314514589021856544"),
+    MontFp!("35087653160263082011551011896785452178273871331090954735353760094574180797326"),
+    MontFp!("44066134544197993553720315073514236799698542066082224906667320314729128689851"),
+    MontFp!("46811190561503483095087189032015959148465356044838419985597715002375968521789"),
+    MontFp!("35502138198479058392251639631217384470706251578088034693745546686222031522574"),
+    MontFp!("3011951966042824356793101436014075881633742606023423824609791334873649401619"),
+    MontFp!("36441783079799715976603149530703751751672873737838939240893549516900746063885"),
+    MontFp!("5949000965032854376013985161729805610095473216976505768565157587449663833146"),
+    MontFp!("39334547265154726054631299624100840161191136653442409769156478840344483284117"),
+    MontFp!("44407194440944549422962884120864337491414458688079798116475114348830479824132"),
+    MontFp!("17726376508115223453307205134714318843193912409715438117420622264717671262663"),
+    MontFp!("756868613004458973360577644537468651009832005811964377612175868388980341238"),
+    MontFp!("3421893741771938128946389260799798658478598753415463644298734220953059738355"),
+    MontFp!("13293243933107737951928282334791569607692876620282367672054893180625816893632"),
+    MontFp!("11906453198605884256628058547608350794281153234160543833653104246224561572116"),
+    MontFp!("41007993900563419378450318427807675773574107233531791780559911428122060668864"),
+    MontFp!("48053112103639043655338341411293547635466618118313162578053483741000954697443"),
+    MontFp!("32234194819559922425974652761643838211443296225838831687358769666643041225472"),
+    MontFp!("48939529425812404248175324082406620877605698116805848434311747711965735603142"),
+    MontFp!("23172302197508009638107441698229967178757578829167657081073715837516157038684"),
+    MontFp!("26828245904250884987904133111377098838723772557669646848196202419087853870872"),
+    MontFp!("36025164071480125389137319620343252251920437049927443652919962965645042660420"),
+    MontFp!("42147865145919705097445974287709456827305052297675196211654971979806749888911"),
+    MontFp!("20585606416170880487041307637777839232517038894653375498347934603702403525799"),
+    MontFp!("1047663270527934381838445994762652910090839507177449076034186708210288801902"),
+    MontFp!("49864990265274912108645272682223261996354786042911445790248422528668444967688"),
+    MontFp!("20274910123179255493744356413243132767746258718293295072669857132542604950741"),
+    MontFp!("39934722872842035804029775488645871956511886709858512717725127998627130523912"),
+    MontFp!("33295937568441654166303959882114891655347924209941192993151592385097862772126"),
+    MontFp!("39198754144978337534654702520273605486297255614756323128663775493042981926264"),
+    MontFp!("48114214484211668830722398263059235215883885642960292320018016482221617479308"),
+    MontFp!("20857205525756474383857323509517945359548153106777905032259140536596758842151"),
+    MontFp!("20481512031474492331394869498229505122694442073123511672315331466779200648987"),
+    MontFp!("5512348932066875222255592673449822544023582557729178288775446420395599163714"),
+    MontFp!("14748707870289380337081091822758247948394420380976550635416667891847265434773"),
+    MontFp!("20722592968207591585193709289557966995643707360519106502308701025990663556112"),
+    MontFp!("30345071158541998337681526950804671230825251993252779307899796752848528236301"),
+    MontFp!("37998633152333475045376762610205951441947316428701472139959038991258723083573"),
+    MontFp!("25187075483245106412039082847435291293567789993240499080077480139071082713811"),
+    MontFp!("19421269742609173994970218921590288196829015512476170234735579602917648585528"),
+    MontFp!("44645626649350902490681022627010246390651823839290377812847048196342039743308"),
+    MontFp!("38015410591674700109176981164929629504879929166582206795827935147005325179107"),
+    MontFp!("15907673084411204300870039215095416489657280867726923876605554605918361454411"),
+    MontFp!("4839910768263945909615643698821897421248623201436406727882801614226282796736"),
+    MontFp!("11993166323725114372511567048380837525145267539902083755578961856890086640616"),
+    MontFp!("13920948384274828210917386586592591296235909076917892178237942711445546791673"),
+    MontFp!("890670937435713979056767019654860866935017937714294844928044822115961948695"),
+    MontFp!("17228860181078068965008756660486864527552317469394243328944319614877053158985"),
+    MontFp!("10077644153064320976006893555780056518106113458749153233744229760482343163199"),
+];
+
+/// The Poseidon2 parameters for `BLS12-381` with a internal state of size t=2.
+pub static POSEIDON2_BLS12_381_T2_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 2-element state over `BLS12-381`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::bls12_381::Fr; 2]`).
+///
+/// # Returns
+/// A permuted state as `[crate::bls12_381::Fr; 2]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 2]) -> [Scalar; 2] {
+    POSEIDON2_BLS12_381_T2_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 2-element state over `BLS12-381`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::bls12_381::Fr; 2]`).
+pub fn permutation_in_place(state: &mut [Scalar; 2]) {
+    POSEIDON2_BLS12_381_T2_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        bls12_381::t2::POSEIDON2_BLS12_381_T2_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn poseidon2_bls12_381_t2_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T2_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t2_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BLS12_381_T2_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t2_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 2] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            Scalar::from_str(
+                "52363037649740716030766703085959398089247109082552119369664912680823987490382",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "14046768006860121393795564977526877746513733133602551947789337117320794882996",
+            )
+            .expect("Is in field"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BLS12_381_T2_PARAMS, &input, &expected);
+    }
+}
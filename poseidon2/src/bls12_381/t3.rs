@@ -0,0 +1,193 @@
+//! Poseidon2 implementation for `t=3` over `BLS12-381`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fr;
+
+const T: usize = 3;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 56;
+
+const MAT_DIAG_M_1: [Scalar; T] = [MontFp!("1"), MontFp!("1"), MontFp!("2")];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("50207570499218320245539736680169582180207201335688461025883902752909290481781"),
+        MontFp!("24448666467656506447555018649749346340705294023832615387641453784702583464707"),
+        MontFp!("34092944507611308604157957266676007619644244199372265837364557849561670729974"),
+    ],
+    [
+        MontFp!("46954129210702959446093971191783182601726081775951103310666314834569091037713"),
+        MontFp!("38612156878839717097806285947575477749087608521505464809942918879152074545066"),
+        MontFp!("19752610610343814834081989345964253902282700341539483876504601969121084774539"),
+    ],
+    [
+        MontFp!("46567545048462867923299713424766325689670511126407629551256255807498976196546"),
+        MontFp!("9520793415506326549109545537894287560752519598132096386048093015534488804808"),
+        MontFp!("22814234098357034097599682726494820560934925862581927123816510593532324971186"),
+    ],
+    [
+        MontFp!("3277621627834606517208177071759088097855048183641615082769528872043050020787"),
+        MontFp!("29230456498980145088774069819561206654397510279226264474986155631775387918911"),
+        MontFp!("19087113294497892618475669593723876605785307026981218038380435259594863105240"),
+    ],
+    // Second external
+    [
+        MontFp!("49179786922858759927440465310900376749726765337268308911471491527044937447403"),
+        MontFp!("31668552784983283483593666924944066737680315058069542500069213700768949573692"),
+        MontFp!("47303630019147536941220901582952982856517915740884282232588733470564849742080"),
+    ],
+    [
+        MontFp!("41561182787858915334837446901194440640033856888621022207410120224293681204923"),
+        MontFp!("40208795410444394963490428737133513683110766973508056822474493355065333491217"),
+        MontFp!("24620569969402072776192280888011017497854992833864712509770555543278833718751"),
+    ],
+    [
+        MontFp!("31418811028946653724823259636547682581071379929451162101915628592655152015310"),
+        MontFp!("25964807298150242099204032696543021731332498792173212422070959505270506288817"),
+        MontFp!("31766013031271106581980804902159064978010553325475976472264348555438361464655"),
+    ],
+    [
+        MontFp!("15107529391758643095716794813038523751713309080738989300826699946985294497278"),
+        MontFp!("26149402682269665088314773514719203730233986608723938665192802061570851149320"),
+        MontFp!("35053126320072620250684851851709987160095640397875384355477447570643983599564"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("39932371919358015185769877859035474336011770016475087638554815294278664040916"),
+    MontFp!("17645770319151120318035258350885823104235488352935695302274836429012504407725"),
+    MontFp!("17990728141399065004015538797609951295983853332644474801890158217822768128628"),
+    MontFp!("12607949331462269429981198199999740921418125994747028428126661151190418292729"),
+    MontFp!("33067617079394435172767143524489677593390850035349407507374659268468278200906"),
+    MontFp!("10025233623562179533044093426455032352895184661359005809314430689113735312874"),
+    MontFp!("20398677688057466110325934731430812468657996794663167456321709689030080949228"),
+    MontFp!("32085671199853825909918260218834827339732598508827083525700252644622592932757"),
+    MontFp!("36451986593067827349794003109666944974266236856145879921902940325507228739480"),
+    MontFp!("51835224419566813714481533481210630888564327175625175437244377303858990291964"),
+    MontFp!("1944662263588038198375346521900053780907777056656211622999059135594196413076"),
+    MontFp!("12995068374816903282074967132431954020410301768622808407703775963080983755183"),
+    MontFp!("13278128079226679628648689279705910775020794457648431336050464485837924986341"),
+    MontFp!("39207195481789228835625472428521288347432218258431761869689775532020546099642"),
+    MontFp!("21081768833381902942114733002158882075348844281359283013642620389621494952015"),
+    MontFp!("20751788049060260683191405008569080723662271828149227137187075968560831545739"),
+    MontFp!("20820291785607398388900832350860967875629907105847554413318238165275470374689"),
+    MontFp!("6971878585215744613467847324629115462668098071102846520957717612260531709386"),
+    MontFp!("42421164250058173810994728364144776180689735894673627964404703973460802099146"),
+    MontFp!("32890116643831560295329417521056875595733120141391587236744387068135440602102"),
+    MontFp!("42670005614507618780436482775021159957307712089310941922452133588875084445464"),
+    MontFp!("21120353743307986506720883740380468652053382764895882204680310593048134053982"),
+    MontFp!("7853308243263055176258751393326645428041138029306706980470113526802326214700"),
+    MontFp!("17545076036297840030021082424260289805456380863517895917265467158332801090765"),
+    MontFp!("29526223376722400691172584788126610514669516909826971155598997488361793726636"),
+    MontFp!("48421712782536172546302502401679048379568171245541707202282458591545347755349"),
+    MontFp!("10740853637774754893036062076749871837371049036966225040269105665447180116170"),
+    MontFp!("34042041521558704677804677569712674569738576001717295340556848855085089618161"),
+    MontFp!("24290796201833228559129233924595614281891670608675107544294264860003803501509"),
+    MontFp!("26722678647461522072509896114724736555938247563993442152746954157222882824350"),
+    MontFp!("20252491387019425681551488261397157776479297799360691728406809731508542196845"),
+    MontFp!("50322025264206689090790987370440439179141270613911973034521438238687587958097"),
+    MontFp!("17070806525931584028449131949070191143344166668070820337429561524629464200550"),
+    MontFp!("25856554324149146992239414502939942208580094928192925471532421030223074525051"),
+    MontFp!("17714998974036855356530338446243137421735047395517260588250413348153258772076"),
+    MontFp!("44833315250334176776685835079382312848180252180173884969157994737319426976437"),
+    MontFp!("35603718839327251012037553292043899153393807438387129505923567878785822738162"),
+    MontFp!("20515196301761603016197694845695272699608637099106794944737311528118558777570"),
+    MontFp!("10100400556460905874275078234698187530913105549037797180493988678937053918124"),
+    MontFp!("29943022708270799252522211109308629054849337552699067311814388215768905671554"),
+    MontFp!("33400164627534996188947689774080657908147988421361870074239537729877153299092"),
+    MontFp!("45574161704098228712016716221086232277248798839906622903502141601878895917316"),
+    MontFp!("40623265267364613450776577487319920007897396936924051398790906883872334022964"),
+    MontFp!("37929176440858430683261948300797278761072096845318183419284347376614069989808"),
+    MontFp!("12242010394227909997626655999345208835040087302065045201635069094289920778463"),
+    MontFp!("38947272924417356803622776795797899233194116520680026665045628837194239730633"),
+    MontFp!("6838505804652359252670794375725267665530548946030641535297433541475260948424"),
+    MontFp!("21345718918993308853491352363460625447157796362108157527364130872100101143328"),
+    MontFp!("26397988737034501095129796920971941795766209722106383463197090306632188634870"),
+    MontFp!("47092791129593573928369881528796435131623991381197863072979392492232678100884"),
+    MontFp!("36850972241154890671857874025605504779963735054128436776319531005864791472123"),
+    MontFp!("27893799443241349360688137159923920340185830261519093384488134540544971987330"),
+    MontFp!("34031071010517479317003393843135868322188010660871691856659878788331169912272"),
+    MontFp!("3102550735908358465878301372253437950829524988677083749179431098369388780259"),
+    MontFp!("2963742902601529003553690631564645593518709846059084207036841793643477514707"),
+    MontFp!("34538583661636382515652368664945657625216404085453317149263146639486246251503"),
+];
+
+/// The Poseidon2 parameters for `BLS12-381` with a internal state of size t=3.
+pub static POSEIDON2_BLS12_381_T3_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 3-element state over `BLS12-381`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::bls12_381::Fr; 3]`).
+///
+/// # Returns
+/// A permuted state as `[crate::bls12_381::Fr; 3]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 3]) -> [Scalar; 3] {
+    POSEIDON2_BLS12_381_T3_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 3-element state over `BLS12-381`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::bls12_381::Fr; 3]`).
+pub fn permutation_in_place(state: &mut [Scalar; 3]) {
+    POSEIDON2_BLS12_381_T3_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        bls12_381::t3::POSEIDON2_BLS12_381_T3_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn poseidon2_bls12_381_t3_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T3_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t3_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BLS12_381_T3_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t3_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 3] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            Scalar::from_str(
+                "12249794248008371943965195507194171345311258135909954438653399945366036280816",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "34530512148863939455675662080244961267438888698342943852832905748800656324753",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "14376704056201869785286496167433956486206399269351247466538836868879344524395",
+            )
+            .expect("Is in field"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BLS12_381_T3_PARAMS, &input, &expected);
+    }
+}
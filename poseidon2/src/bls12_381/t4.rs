@@ -0,0 +1,210 @@
+//! Poseidon2 implementation for `t=4` over `BLS12-381`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fr;
+
+const T: usize = 4;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 56;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("3318655301660913042182619814759206048255383170476851554953749170663204936509"),
+    MontFp!("44109141949870474831175246250426841318460684547410744873114578168622501784228"),
+    MontFp!("29659240597933972492294679304149537656111319867730125083673280425768884331730"),
+    MontFp!("22737209252563391582737936950219322780303060945177735953720593600577551054727"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("11865901593870436687704696210307853465124332568266803587887584059192277437537"),
+        MontFp!("37413344849675497106163505103761203874617077416461933389729149896951619083615"),
+        MontFp!("32493856687297537788073517556470839888070933486712636845483468275561142904504"),
+        MontFp!("8106572321306448561272383558012749963748358844705299406391447161298410877832"),
+    ],
+    [
+        MontFp!("11707331945334514286120137391947350087632085623772869951863164361695922976568"),
+        MontFp!("50176353669915139758684707864014381736527453065793678083699453978150986704353"),
+        MontFp!("7614231165138437703715796351400512419034157550777684039873810826440625723695"),
+        MontFp!("17108745804308684637964438487237723214541645477949369557189249702220750722331"),
+    ],
+    [
+        MontFp!("28482709556494724328894800736802198653800073555798724636385135549439085356742"),
+        MontFp!("22084621272529558534346674593668266856649195963035162420257478396107381285157"),
+        MontFp!("11065046937453971018193111360820446155970823623967390073553725256595768408791"),
+        MontFp!("20163386569362559253936962862374791389308839238220230985809310822791529262025"),
+    ],
+    [
+        MontFp!("3941572053547598429065422950522133819874343789089916995553450661687375302109"),
+        MontFp!("26478286544099137612981910043301624023925078357447813396543283503388535703096"),
+        MontFp!("20695873604353009531429015276900191600064214090812802713214397507580078182919"),
+        MontFp!("13125722302273298866746961565666978849814465870768123336569424431819752980076"),
+    ],
+    // Second external
+    [
+        MontFp!("48373517651545249281510690416218268384343400250317171450103976311090286221260"),
+        MontFp!("27752440147182328733098243645400559151338503658043397110598417983425635093551"),
+        MontFp!("43156725395743020846958899706072234263962738024394096815001388170789961679788"),
+        MontFp!("48906049704561774201639151262665470255437206145980347197443063657678740507943"),
+    ],
+    [
+        MontFp!("24918160465086526594937065443815615610757370328053649165018481775513828479869"),
+        MontFp!("36462368786443951186110721729238677880688318912401935278190656741197184273952"),
+        MontFp!("37367696075403883562827939745268661270924157618310868295845006320225084994632"),
+        MontFp!("42130603320119794983803262970740129474583503116320676437504579920473229006778"),
+    ],
+    [
+        MontFp!("12096038367976885628335054904138821822550042039079703385879844461517464118581"),
+        MontFp!("34671362090033614505367959844073659507869267381932134573786004532584171425818"),
+        MontFp!("15908652423714359894720614650322760756461828514699821946843077879932200328081"),
+        MontFp!("7518568119601342737128460613704294443674406422237476295695786631549469567412"),
+    ],
+    [
+        MontFp!("33513737101700389003254558060695049730922342329295390135821890558696123720054"),
+        MontFp!("49765425774819103826723198731734445691737353182147628471479513204868044796119"),
+        MontFp!("48731721046471530891684818884908827036844194399863789073273030545326532602503"),
+        MontFp!("1994879948378542466338304292753049990663872919840272992167645879411261807091"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("36198064501926046106576802008779486932990788729159233810880339338049275796987"),
+    MontFp!("44304460846758155168737768840994398685118878625607355725968797162919830864102"),
+    MontFp!("33427295080737122973704388239917504856466726697805464799279074730831006493899"),
+    MontFp!("33676819385378678616140345579679379172852136878360832374035200947147349341877"),
+    MontFp!("44698579309865383047943970651850256651158236406641741596599260270592257060333"),
+    MontFp!("9785548396072733556484927856146778907814788851086349315764873426432645263872"),
+    MontFp!("13443943788901083053739342733252043423900693132812974385986650498262359453435"),
+    MontFp!("50643729733611061821734405325096960434556494526582143101458996741611494836986"),
+    MontFp!("9762302758250004682914036756566790454206292929079802149918241419991743778078"),
+    MontFp!("19475373737975172049750799347581927313285945820087657933615778552974211829387"),
+    MontFp!("26326676308398320579169539788392437654921007121904386041440053251920191437301"),
+    MontFp!("33434337088018971767011967694326287068660679954245122268447231545106574191053"),
+    MontFp!("21854837019991553667332010956652219921642250105983660108852707854862240766704"),
+    MontFp!("1855873836256370364169888814967543928768577071445146807885001909257610924575"),
+    MontFp!("48105724357874736702308498318239595022868478610964925776544998873327877770469"),
+    MontFp!("6893757226114776013992120610353413647048965744053221939747330482283347049271"),
+    MontFp!("49492933790401867565879330847407697876101917412920943837692305231776568086150"),
+    MontFp!("43522959518323197786977932091172575965428037444858136419745890338308804820810"),
+    MontFp!("39369220628770987071776708725487093142438968678975788148890686830600891659237"),
+    MontFp!("24332002500271167754445178113059124234684848763578719088484322891936508359054"),
+    MontFp!("32863475623207582419161401899951874256189430526558709698459253237664899579477"),
+    MontFp!("8315390532973093090228198037973187268458339671135900692256692995983001224287"),
+    MontFp!("41596164941281344945126840056611498785955123869980989807278169650894369778621"),
+    MontFp!("41140021013127548285923961611241892352480288807646515822914427245468443615449"),
+    MontFp!("42624333566444295089232230699974262280460377983015559040916764999567317327294"),
+    MontFp!("29425841969458336716648866633284898031574592123216791821970989517602546368463"),
+    MontFp!("20194832349178074328255630030474794676357522951312816945265283318496141911576"),
+    MontFp!("33508834389330212986852784163678812323448884912646004437785005736522859730449"),
+    MontFp!("20197489266521008707527755143868210833027985912465941510937472218208331469324"),
+    MontFp!("38189796622106345878699238475711002255025750905901925248625120562682573353793"),
+    MontFp!("48437331749916394313065146750618123382683254942785601073233866557242834888501"),
+    MontFp!("34815884667490928168338620954175830688114531237099462583592020572423301193334"),
+    MontFp!("1316079587764339149090919530288539945185249635387918305928554726824292235069"),
+    MontFp!("33564480841331620167847153616337187248054503582700661803825728035418602546478"),
+    MontFp!("4635634898381888421672273828316335969974599848444510316738469345444620659008"),
+    MontFp!("8644506076646842294589324870931361199184791348209582052726445382015132439419"),
+    MontFp!("39498585060657083972778194861599167626335350278223243726925173218749695943806"),
+    MontFp!("42901602831339057007425445486193581840749112246589631302873671293308101878875"),
+    MontFp!("20119933204882102974459031584507100339282292349398588923453836079377072829543"),
+    MontFp!("7917862289043363038204972116125424279857433068189510615392330392863075948512"),
+    MontFp!("41284417024025222157952919191031968108126105524670279472881067727309802924938"),
+    MontFp!("11213861995768467857413038001306057240793870929626059934261458727946548965379"),
+    MontFp!("2291742710611132809700323762125675349484016058554275673428643410085506076100"),
+    MontFp!("30099159053997341705317995418169313532098300934328131162175924134794709943047"),
+    MontFp!("447035513285578307783519781307142266645679652807941291454847780415896684065"),
+    MontFp!("19941446202184504378547837635870560393064630187876613630546846906393007677289"),
+    MontFp!("7595261399959684629699197426920893479848768772071384660164934610968891053864"),
+    MontFp!("51598580281806900142260694365187051410317675046136337884836978415482902327015"),
+    MontFp!("12450848281586712352554721829724230078424064515794153380314705783292880037478"),
+    MontFp!("5237102499670441785007944785581992844697685968922355014280712201430686167152"),
+    MontFp!("1412524057853628881005630586377727487233247150373319518286783509614859257068"),
+    MontFp!("38519766408760192821848550196157518411386556623071006612683448412823634200875"),
+    MontFp!("11338671486975802181674275776989710780888734229624346786700048285586675342901"),
+    MontFp!("23124572501783393477231165425714476214042723292141825213493635111951207504070"),
+    MontFp!("21528356110015199451243279738115385806356940590132503530639630620611521954326"),
+    MontFp!("6284174238932569340060925799940162325946442751185026413727709496271066916876"),
+];
+
+/// The Poseidon2 parameters for `BLS12-381` with a internal state of size t=4.
+pub static POSEIDON2_BLS12_381_T4_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 4-element state over `BLS12-381`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::bls12_381::Fr; 4]`).
+///
+/// # Returns
+/// A permuted state as `[crate::bls12_381::Fr; 4]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 4]) -> [Scalar; 4] {
+    POSEIDON2_BLS12_381_T4_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 4-element state over `BLS12-381`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::bls12_381::Fr; 4]`).
+pub fn permutation_in_place(state: &mut [Scalar; 4]) {
+    POSEIDON2_BLS12_381_T4_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        bls12_381::t4::POSEIDON2_BLS12_381_T4_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn poseidon2_bls12_381_t4_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T4_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t4_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BLS12_381_T4_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t4_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 4] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            Scalar::from_str(
+                "18543807459877364126908352029685462281488659954858558933392966590401135434349",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "6486003883477478427469307271780113565813206904808083202373087381132280912685",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "49531065568108882186803339532638377985898634706171140442815716917306497904848",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "3533173620704471731420930490618911963424635467018145440601039502713526898230",
+            )
+            .expect("Is in field"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BLS12_381_T4_PARAMS, &input, &expected);
+    }
+}
@@ -0,0 +1,263 @@
+//! Poseidon2 implementation for `t=8` over `BLS12-381`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fr;
+
+const T: usize = 8;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 57;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("7732979325129739474072075598164119647813127014922269108624940796467920361962"),
+    MontFp!("41447642396868819548349047028101655384565319378437484023595183877097624515163"),
+    MontFp!("3439336837802708181653673921968716874407237878643494508999217982998177947234"),
+    MontFp!("34553508127002884115962224834740325346805504616982989469969352267984749112607"),
+    MontFp!("32671406204272902583270661755413137358907071447387097374433881400433447792205"),
+    MontFp!("28480556991529538532751332851947241634953905454905822036174481234089874213113"),
+    MontFp!("4112168812044788973703473380856065913303561409691332576018601904745063182859"),
+    MontFp!("31632764687123005113506253059993842233838997226661444513880913319205500450339"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("37848764121158464546907147011864524711588624175161409526679215525602690343051"),
+        MontFp!("37380723485164799731946492905846960314525440335810155667437231310533111503574"),
+        MontFp!("26903338106277901356364565270841019622119542739282182600970667476525453600291"),
+        MontFp!("11290211092469263046266981784277142070933464607064178677127178846744898168339"),
+        MontFp!("14212628255278766345174812256885522627938411026966312913431669623016793503715"),
+        MontFp!("25311612137243372748352680680378610229923476553013915662421367301033051657538"),
+        MontFp!("27045958182524516741634779972389198622342643102589040509166709819223502285469"),
+        MontFp!("30290729601609729438175445738601150088597193057475860823612909639188028015935"),
+    ],
+    [
+        MontFp!("13908578078284010816530479261279414871855098049175706490609693847600815527019"),
+        MontFp!("36517271752943658588708567424654139115072875636265544043244302837339445308050"),
+        MontFp!("10497300553142343791989247925807432162861606615948827497433662150262103873105"),
+        MontFp!("7376684968955183762946241144416028815665610021949922032637175022933305179160"),
+        MontFp!("26531196973068464574874141592308940590740041674096628169500801391524237962662"),
+        MontFp!("15170193040783440917482922615510916687226012545041653082239923655393145935710"),
+        MontFp!("31735754602557451014583234112995345470555732516781137254428660392854035219212"),
+        MontFp!("35595684349903807002098261796829870443637609891157060038317211227489292230040"),
+    ],
+    [
+        MontFp!("35711677751796790973617912597805465301217382023007824486520592118533101738843"),
+        MontFp!("39073058326233264922249768527689631428851585001763440130418054522636621601668"),
+        MontFp!("8554849924876618667422962384253457870281651246514171032488384050717213054021"),
+        MontFp!("33089422485338116841402030094765658691984663211624075471756010584420363955015"),
+        MontFp!("4170721420402215682882459927063317041817395647325445337586079316946339682283"),
+        MontFp!("45784457678746835798154133328461046475563227050309499400359684149386846796994"),
+        MontFp!("22924281401280628763967029387713849266724852119810266685838716874924324753475"),
+        MontFp!("9420165315903882477719622617255004699480296174339918772607081816762264610997"),
+    ],
+    [
+        MontFp!("46070170886043942889548430557566467596138016965658612283563338764490106133437"),
+        MontFp!("21943457835628019405536540273046966261046580124713220282249466436591209512133"),
+        MontFp!("19735855144938608512793226660651315076351972830334963459903627223682963094964"),
+        MontFp!("32976703856496795410288469060495487263757382717105342784026227372878131337207"),
+        MontFp!("51900631104672262510013319082427842880573840800767341520449974169874593002881"),
+        MontFp!("598552439739886632327117871664348904354924925660178962126380780734682753147"),
+        MontFp!("3345534000340757641902126760196076877233918264273108331661430451687787228093"),
+        MontFp!("2780799861749500960281492828469867710770527703163569802788420284525245238784"),
+    ],
+    // Second external
+    [
+        MontFp!("35680908346105257376810344499739337646901976618271696272739559201581287125954"),
+        MontFp!("33413120952835358856691542480104666510190903841240771761103544081671287775992"),
+        MontFp!("3284194631882944529538783176852606679449925602112675464457700267420440503854"),
+        MontFp!("34461524383450940037072176937324559050316258323925439346370333414291794500628"),
+        MontFp!("29524954881309163893926912390028015575685477065566367434228465604641118498943"),
+        MontFp!("24207873888204444921624332711214180098100557109826238939552985669697608728682"),
+        MontFp!("51210365599477995464594665427607467517897301279592097534487648196645214473582"),
+        MontFp!("22347342119590089672754603472075735240501109709740057237330214683302512285499"),
+    ],
+    [
+        MontFp!("1637662539832045101610564461827851457682910576726350336780725810801164459220"),
+        MontFp!("26456093037902684982244505746457132918094256231474939849383181248687028875280"),
+        MontFp!("3934532936070387373550015061212405403529119042403333217583123983340410306691"),
+        MontFp!("18286415093847792275532876176474738493657243852074440349589424742945731723874"),
+        MontFp!("41985306058979590414437125852118882607291489356697432070246324166852748784732"),
+        MontFp!("46251568785693922492362941991388948351824335696500143107007054172421108588369"),
+        MontFp!("657508305891506791334781705373165178165639824001233337760678556613830297591"),
+        MontFp!("29469234356191690836834981695189622595035215603149992114099606186765659118966"),
+    ],
+    [
+        MontFp!("24504791063553571164320898865281250923281514054381424253921159620811243557152"),
+        MontFp!("43244348279780039870197023931371873608113900540852618454437931702326369822787"),
+        MontFp!("31257414123854639264682279263381350710244133316730881945478448031234728940064"),
+        MontFp!("49655050743817549236979000547450364133610503644325673169718947247558757965295"),
+        MontFp!("12126421103926849557700242725338249325971025093559341782673061396942477969620"),
+        MontFp!("43461918235632695538316263716055723485805465237373972827964895808930137156546"),
+        MontFp!("20017582635378333547716142455977023385541732800599454371869251689383339826691"),
+        MontFp!("7508961219868045458952082289971027212248981171389971299249039337545718817852"),
+    ],
+    [
+        MontFp!("50819383835409584776311984805903624587584874526474677106642435314272329550224"),
+        MontFp!("25073859683089043571833921250206772880568258498396878980739367777712283118850"),
+        MontFp!("43817227963239969571096623685765761632911355878463510428213128400425487850600"),
+        MontFp!("29526748851524110579753061545073670792700775636914013366481338464241333364164"),
+        MontFp!("27800019373115076620541330009043672138709307925536868589274786683580956769694"),
+        MontFp!("30072110049607764617350388203505345903763403226580668277932276398495263184701"),
+        MontFp!("35308514333174168516886298372887479940801292525875164145838557553479245376374"),
+        MontFp!("47775957002086834939269715645146429607594983335393110512699503168470458307488"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("50005636454984765028932764346208296507187632478754394750509259512484969520184"),
+    MontFp!("27113791578882807018474365116114810830837708411238174901863961797247855189261"),
+    MontFp!("15511801366759526829714220935042417299838522399386916416447279139799397514070"),
+    MontFp!("16982306331703853386098458268936069990136685241067426093760803565374590696677"),
+    MontFp!("39650740612642295036752809003138651084796831210091514755828251709463808420267"),
+    MontFp!("48529118222857412540309886245651930316117064626986534765019761839050118776798"),
+    MontFp!("45421109653711890975151156342864550758852627463697529548334625790660494720896"),
+    MontFp!("51933426184069235611472057717646852972457372277499806635399807528160781178837"),
+    MontFp!("6658018331812338210979074983279465599349434890432099981813212195332881039588"),
+    MontFp!("10153015054586429122487756321022949232358152849911863645170166761782321634548"),
+    MontFp!("43525482988144956953538495271936107530050687613990637495675851546965059523396"),
+    MontFp!("49375674546903113846146882554359544428127043200613867570185894768824950883607"),
+    MontFp!("5109387287128588771103267713832219177344464123609814838196279240157243944964"),
+    MontFp!("39790445139069635908314532647117527393184683456392945916454011474842618321995"),
+    MontFp!("15363878350537067099689786362570220205697276343164925425640399079688763617685"),
+    MontFp!("16813070948345041707159314008949993895553530360476146058530764549829024657188"),
+    MontFp!("33415082525206816894550653861934598200063637689619631799183164078029373504786"),
+    MontFp!("18206370224813637708027276770173489307853878891710079959433640649380565241901"),
+    MontFp!("30381705511917381975758702773414558176760964396228402532577090801273450827422"),
+    MontFp!("15521154400599533316306819939482012551838476654628860269512178782252101809955"),
+    MontFp!("15181969009146668027946946826233603770611198621685297823567510302691854880740"),
+    MontFp!("12956539157803053018634919199444387070078473351802814010941753805623493459712"),
+    MontFp!("9016678236842923515125010015015451161389491442994579865675667483748362688339"),
+    MontFp!("22268285431627403752583702547683834412827632539917917152854684869384937080650"),
+    MontFp!("51491175881056222145953843779829038915747055878548647019821551178088140864990"),
+    MontFp!("42352143572689113233256820560724852027702060388949925592904542079481937105631"),
+    MontFp!("38508030598798028892189566726862461733995420470238063374429453840289898586457"),
+    MontFp!("26229567830495225771003306960198802488950768049762733387501441395625143195817"),
+    MontFp!("3178996106171971813825253100794750247014895855590179507774800829088589868946"),
+    MontFp!("5701775573539022647083326435059544411444103686222620097728781292637411491533"),
+    MontFp!("5322518622095506923783296632223855754280194423158044767649244214361921376788"),
+    MontFp!("9411901489259305895423263885925492873040053698990249900690838263595689178520"),
+    MontFp!("46693161270881553105774084994096832399309831446505997892671364680087629790624"),
+    MontFp!("12155030350845593054676056333348800803343089261991154297465268770314860110333"),
+    MontFp!("23579890582534545940234607194089455137950832188487520581578879561409812059995"),
+    MontFp!("23279837073484836960320588964874062948631711515877417556844620089915656530363"),
+    MontFp!("37591780050950424365248890633522797416130124425816356482246221818215181169018"),
+    MontFp!("13516809124317631813040820175582853253861960436603986500331105894057936570759"),
+    MontFp!("27315446906777795112221465563159412739845868348587362649718620144860300089154"),
+    MontFp!("18081302174335434729067755614145625421000348384964343608645400803209852778961"),
+    MontFp!("8647530914139627091801700867104104332442539475489832928613145800141288646044"),
+    MontFp!("33025317275195890429407618307548509724415876730394076774263892209364134810208"),
+    MontFp!("44677382503084252890061118404276516256003905018257913141203150811675718765579"),
+    MontFp!("13810119202832958216309168272955134287854717954333511048847321822953879580377"),
+    MontFp!("3637116958263579505354354717875254138630366782434775306387840025286268403971"),
+    MontFp!("15387621107291675161126058472213863911309393574806308533095706343556413746647"),
+    MontFp!("15296373468444809471618946502097442840832461503907537421737942526578576318959"),
+    MontFp!("4848202185607633258580694008280332511746694808413990759162317361910493079151"),
+    MontFp!("4128696369446011627429645051244749183375454070981302317323449925228287823005"),
+    MontFp!("44503245091843520545489523029884947702549049148754507522530906219563985157605"),
+    MontFp!("44182918121378337933525725673647604846581037253628860796468380261428881539754"),
+    MontFp!("30837481525844469253602487609679086218670662112173692951290655270596989408962"),
+    MontFp!("29070062358833439344057306174693138305489483086037209532684999928824198424249"),
+    MontFp!("14990890841120388178641705975509726336690397160521230415302781328909432552050"),
+    MontFp!("43894761733368791653716065341286234877994093348240781016156819543172398243818"),
+    MontFp!("34786011378115990624602952168061318092447693308760900284026258796418904097288"),
+    MontFp!("22720205158153053577294972722398671783506870826494594228638304039396721130053"),
+];
+
+/// The Poseidon2 parameters for `BLS12-381` with a internal state of size t=8.
+pub static POSEIDON2_BLS12_381_T8_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 8-element state over `BLS12-381`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::bls12_381::Fr; 8]`).
+///
+/// # Returns
+/// A permuted state as `[crate::bls12_381::Fr; 8]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 8]) -> [Scalar; 8] {
+    POSEIDON2_BLS12_381_T8_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 8-element state over `BLS12-381`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::bls12_381::Fr; 8]`).
+pub fn permutation_in_place(state: &mut [Scalar; 8]) {
+    POSEIDON2_BLS12_381_T8_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        bls12_381::t8::POSEIDON2_BLS12_381_T8_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn poseidon2_bls12_381_t8_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T8_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t8_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BLS12_381_T8_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bls12_381_t8_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 8] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            Scalar::from_str(
+                "45023576091328930800798472733118116775397224859590160517291143669552562830660",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "23440990877543270467889245981909238918797393890596681905773899867045702389585",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "4253772904182762458846944718801225675217997117576903101220328402884827340195",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "18785979536072156189661134564269179724487600154606720590929686423104929946685",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "13585808216633178065246031152750530659238187730277389096501483116290828311109",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "49440349395358120058298788034860058979842571536605159980229696481333058913682",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "28875863919461740205785716118566321556317398482656623216017887749633053082913",
+            )
+            .expect("Is in field"),
+            Scalar::from_str(
+                "22506582438218737418455347866460246044145728256488319668840350925811225860680",
+            )
+            .expect("Is in field"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BLS12_381_T8_PARAMS, &input, &expected);
+    }
+}
@@ -1,6 +1,6 @@
 //! Poseidon2 permutation methods for the `bn254` field, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
 //!
-//! This module provides efficient, pure-Rust, minimal APIs to compute the Poseidon2 permutation (not hash) on all supported state sizes (`t2`, `t3`, `t4`, `t8`, `t12`, `t16`).
+//! This module provides efficient, pure-Rust, minimal APIs to compute the Poseidon2 permutation (not hash) on all supported state sizes (`t2`, `t3`, `t4`, `t8`, `t12`, `t16`, `t20`, `t24`).
 //!
 //! As specified in the paper, the S-box for `bn254` is defined as:
 //! $$ x^5 $$
@@ -15,13 +15,22 @@
 //! poseidon2::bn254::t4::permutation_in_place(&mut state);
 //! ```
 //! All permutations are feature-gated, so enable only the once you need.
+//!
+//! Additionally, this module provides the 2-to-1 and 4-to-1 compression functions `compress2` (requires `t3`) and `compress4` (requires `t8`), which are intended for Merkle trees.
+//! See the documentation of the functions for the exact definition.
 
+#[cfg(any(feature = "t3", feature = "t8"))]
+mod compression;
 #[cfg(feature = "t12")]
 pub mod t12;
 #[cfg(feature = "t16")]
 pub mod t16;
 #[cfg(feature = "t2")]
 pub mod t2;
+#[cfg(feature = "t20")]
+pub mod t20;
+#[cfg(feature = "t24")]
+pub mod t24;
 #[cfg(feature = "t3")]
 pub mod t3;
 #[cfg(feature = "t4")]
@@ -29,59 +38,7 @@ pub mod t4;
 #[cfg(feature = "t8")]
 pub mod t8;
 
-#[cfg(any(
-    feature = "t2",
-    feature = "t3",
-    feature = "t4",
-    feature = "t8",
-    feature = "t12",
-    feature = "t16"
-))]
-#[cfg(test)]
-mod test {
-
-    use crate::perm::Poseidon2Permutation;
-
-    use ark_ff::PrimeField;
-    use ark_std::rand::thread_rng;
-
-    pub(crate) const TESTRUNS: usize = 10;
-
-    pub(crate) fn poseidon2_kat<
-        F: PrimeField,
-        const T: usize,
-        const D: u64,
-        const ROUNDS_F: usize,
-        const ROUNDS_P: usize,
-    >(
-        poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
-        input: &[F; T],
-        expected: &[F; T],
-    ) {
-        let result = poseidon2_perm.permutation(input);
-        assert_eq!(&result, expected, "permutation output does not match KAT");
-    }
-
-    pub(crate) fn poseidon2_consistent_perm<
-        F: PrimeField,
-        const T: usize,
-        const D: u64,
-        const ROUNDS_F: usize,
-        const ROUNDS_P: usize,
-    >(
-        poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
-    ) {
-        let mut rng = &mut thread_rng();
-        let input1: Vec<F> = (0..T).map(|_| F::rand(&mut rng)).collect();
-        let mut input2 = input1.clone();
-        input2.rotate_right(T / 2);
-
-        let perm1 =
-            poseidon2_perm.permutation(input1.as_slice().try_into().expect("vec has length T"));
-        let perm2 = poseidon2_perm.permutation(&input1.try_into().expect("vec has length T"));
-        let perm3 = poseidon2_perm.permutation(&input2.try_into().expect("vec has length T"));
-
-        assert_eq!(perm1, perm2, "same input should give same output");
-        assert_ne!(perm1, perm3, "different input should give different output");
-    }
-}
+#[cfg(feature = "t3")]
+pub use compression::compress2;
+#[cfg(feature = "t8")]
+pub use compression::compress4;
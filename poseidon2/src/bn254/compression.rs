@@ -0,0 +1,77 @@
+//! Poseidon2 in compression mode for the `bn254` field.
+//!
+//! In contrast to the [sponge](crate::sponge), which hashes inputs of arbitrary length, a compression function maps a fixed number of field elements to a single field element.
+//! This is the mode used to compute inner nodes of Merkle trees.
+//!
+//! The inputs are written to the first elements of the state, all remaining elements are set to zero. After applying the permutation, the first input is added to the first output element (feed-forward)
+//! and the result is truncated to this single element:
+//! `compress(x_0, ..., x_{k-1}) = P(x_0, ..., x_{k-1}, 0, ..., 0)[0] + x_0`.
+//! The feed-forward makes the function non-invertible, which is required for collision resistance of the compression mode.
+
+type Scalar = ark_bn254::Fr;
+
+/// Compresses two field elements into one, using the Poseidon2 permutation with state size `t=3`.
+///
+/// Computes `P([left, right, 0])[0] + left`, i.e., the permutation is truncated to its first output element, to which the first input is added (feed-forward).
+/// This compression mode is distinct from the [sponge](crate::sponge) and intended for binary Merkle trees.
+#[cfg(feature = "t3")]
+#[must_use]
+pub fn compress2(left: Scalar, right: Scalar) -> Scalar {
+    let mut state = [left, right, Scalar::from(0u64)];
+    super::t3::permutation_in_place(&mut state);
+    state[0] + left
+}
+
+/// Compresses four field elements into one, using the Poseidon2 permutation with state size `t=8`.
+///
+/// Computes `P([inputs[0], inputs[1], inputs[2], inputs[3], 0, 0, 0, 0])[0] + inputs[0]`, i.e., the permutation is truncated to its first output element, to which the first input is added (feed-forward).
+/// This compression mode is distinct from the [sponge](crate::sponge) and intended for Merkle trees of arity 4.
+#[cfg(feature = "t8")]
+#[must_use]
+pub fn compress4(inputs: &[Scalar; 4]) -> Scalar {
+    let mut state = [Scalar::from(0u64); 8];
+    state[..4].copy_from_slice(inputs);
+    super::t8::permutation_in_place(&mut state);
+    state[0] + inputs[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[cfg(feature = "t3")]
+    #[test]
+    fn poseidon2_bn254_compress2_kat() {
+        let output = compress2(Scalar::from(1u64), Scalar::from(2u64));
+        let expected = Scalar::from_str(
+            "19440202363237281411582519622441422429699333916864112080167601237210978582483",
+        )
+        .expect("Is in Fr");
+        assert_eq!(output, expected, "compression output does not match KAT");
+        assert_ne!(
+            output,
+            compress2(Scalar::from(2u64), Scalar::from(1u64)),
+            "compression should not be symmetric"
+        );
+    }
+
+    #[cfg(feature = "t8")]
+    #[test]
+    fn poseidon2_bn254_compress4_kat() {
+        let inputs = std::array::from_fn(|i| Scalar::from(i as u64 + 1));
+        let output = compress4(&inputs);
+        let expected = Scalar::from_str(
+            "10375418653052005193755141575252795042105107661476102930646031361642256514597",
+        )
+        .expect("Is in Fr");
+        assert_eq!(output, expected, "compression output does not match KAT");
+        let mut permuted = [Scalar::from(0u64); 8];
+        permuted[..4].copy_from_slice(&inputs);
+        assert_eq!(
+            output,
+            crate::bn254::t8::permutation(&permuted)[0] + inputs[0],
+            "compression should be the truncated permutation with feed-forward"
+        );
+    }
+}
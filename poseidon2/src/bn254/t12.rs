@@ -204,13 +204,8 @@ const INTERNAL_RC: [Scalar; ROUNDS_P] = [
 ];
 
 /// The Poseidon2 parameters for the BN254 curve with a internal state of size t=12.
-pub(crate) const POSEIDON2_BN254_T12_PARAMS: Poseidon2Permutation<
-    Scalar,
-    T,
-    D,
-    ROUNDS_F,
-    ROUNDS_P,
-> = Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+pub static POSEIDON2_BN254_T12_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
 
 /// Applies the Poseidon2 permutation to a 12-element state over `bn254`.
 ///
@@ -236,12 +231,40 @@ pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 12]) {
     POSEIDON2_BN254_T12_PARAMS.permutation_in_place(state);
 }
 
+/// Applies the Poseidon2 permutation in place to each of the 12-element states over `bn254`.
+///
+/// Equivalent to calling [`permutation_in_place`] on every state. With the `parallel` feature, the states are permuted in parallel, which is faster for large batches.
+/// Without it, this is not measurably faster than the loop, see [`Poseidon2Permutation::permutation_batch`].
+///
+/// # Arguments
+/// * `states` - A mutable slice of state arrays (`[ark_bn254::Fr; 12]`).
+pub fn permutation_batch(states: &mut [[ark_bn254::Fr; 12]]) {
+    POSEIDON2_BN254_T12_PARAMS.permutation_batch(states);
+}
+
+/// Applies the inverse of the Poseidon2 permutation to a 12-element state over `bn254`.
+///
+/// Returns the state `x` with `permutation(&x) == *state`. This is considerably slower than [`permutation`], see [`Poseidon2Permutation::permutation_inverse_in_place`].
+///
+/// # Arguments
+/// * `state` - A reference to the permuted state array (`[ark_bn254::Fr; 12]`).
+///
+/// # Returns
+/// The preimage of the state under the permutation as `[ark_bn254::Fr; 12]`.
+#[must_use]
+pub fn permutation_inverse(state: &[ark_bn254::Fr; 12]) -> [ark_bn254::Fr; 12] {
+    POSEIDON2_BN254_T12_PARAMS.permutation_inverse(state)
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::bn254::{
-        t12::POSEIDON2_BN254_T12_PARAMS,
-        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_kat},
+    use crate::{
+        bn254::t12::POSEIDON2_BN254_T12_PARAMS,
+        test::{
+            TESTRUNS, poseidon2_batch_perm, poseidon2_consistent_perm, poseidon2_inverse_perm,
+            poseidon2_kat,
+        },
     };
     use std::str::FromStr;
 
@@ -252,6 +275,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn poseidon2_bn254_t12_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BN254_T12_PARAMS);
+        }
+    }
+    #[test]
+    fn poseidon2_bn254_t12_batch() {
+        poseidon2_batch_perm(&POSEIDON2_BN254_T12_PARAMS);
+    }
+
     #[test]
     fn poseidon2_bn254_t12_kat1() {
         // Parameters are compatible with the original Poseidon2 parameter generation script found at:
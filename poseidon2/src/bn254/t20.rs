@@ -0,0 +1,450 @@
+//! Poseidon2 implementation for `t=20`.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+//!
+//! The reference implementation does not ship this state size, so the parameters were generated with `params::Poseidon2Params`, which ports this script.
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 20;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 57;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("8267231756750011136315965558540610329411696658415922414305387194170706662549"),
+    MontFp!("2404321875528294677487673804663866753258074065050544796723910240855595814508"),
+    MontFp!("6510732224077195216798763525467359043503261043685829544421646860097004715176"),
+    MontFp!("11122904785915661089126058872409522087452235072061515026794809699554293522717"),
+    MontFp!("8907934209865242923998541894129615211559158114355983728533414629136406080782"),
+    MontFp!("4051549035256930285380938668511596439515059076355071315562877108824680007359"),
+    MontFp!("2498861741414444311955522620098630083011902213746450937900936253284941408743"),
+    MontFp!("7508218406256498808597602879692308892787232063178504820085477061654069901849"),
+    MontFp!("664075808703114527666164697726695678971807903451453203951029322522911793989"),
+    MontFp!("626502286260112693614995091761208317249869477119133161357009815056371087569"),
+    MontFp!("19647084024517291292099140468870150658174160755926852942197770836592506813839"),
+    MontFp!("6684822771728228400408088736692459872025733860339402894846412769189954916306"),
+    MontFp!("9190517920880907164902641544372114643725923198160176361265202612344073096482"),
+    MontFp!("1400641649618514844308247298749078867545203547644853745291145760443130172547"),
+    MontFp!("7043559652349928195784156372769306593946408301002675644603736075412092594658"),
+    MontFp!("3554333240844410926241963145422580072609608622199945323156541605579817197907"),
+    MontFp!("7191635683242820232664394838541888527495471004050291849034066987528306005905"),
+    MontFp!("21860431344903425118508139200272585750998486757758389107924306481564828453658"),
+    MontFp!("1087240496469811084297509508593302751051750498745977663521191980329215579408"),
+    MontFp!("20600254039764255097880652901200228022547490034097942654439561842733431604429"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("18272643443569106653070183715830310475777669429124162345106700533109053165587"),
+        MontFp!("1986544331135737011040594205050037604765726994030825643163497344127791587586"),
+        MontFp!("21237978961652694415396119906110343431112852560473699354822802547399922665186"),
+        MontFp!("8990471444542029155567905592666926743537735523522791974102378616579227878992"),
+        MontFp!("3583720234537393512351312668225424258235643496991310462084482639168050838140"),
+        MontFp!("16205332271176134181124663734580926173981777727371396896987970583941943353305"),
+        MontFp!("4843353816831604390500475948437824883937076145342776756326862309575653810994"),
+        MontFp!("10144308097250875932192848242861560732697547377548941126480985052235745017583"),
+        MontFp!("3902702650132933742815451425260048326813571931953783470823730308357540768893"),
+        MontFp!("11417707940163644915090716424173843586024257295294803251340346298295525296628"),
+        MontFp!("5187733562925606576418490582937323039917254730230421651048341373429523870897"),
+        MontFp!("12101609209064140987587943494612410976542330902644545888890252849380619453502"),
+        MontFp!("1669590255153609315769681998178590800312137751985156988002855971340547153726"),
+        MontFp!("3758656540679219333456727716740207393381223269958948804391439097876173011623"),
+        MontFp!("20928377535894200388282954361655011223278292580265518648183238427791438688667"),
+        MontFp!("3210512661410609608413057344608701539977364364034328553088190305604017044458"),
+        MontFp!("20724104380792702822139459066567008105377236216958595251793298364303358329452"),
+        MontFp!("3419871779125585185847636761657377627898167326896396876672641783508535747229"),
+        MontFp!("11166290228792423535100993448137848268416132962504996432515617248735888327759"),
+        MontFp!("3473299116567459141888787685817660977751183309604945233157823165761000746528"),
+    ],
+    [
+        MontFp!("10718427219902616734914682468789227007447805030886282593282540056066396083307"),
+        MontFp!("9575400177266775943656071355432751371085756720500218465331028315115985724790"),
+        MontFp!("20622192382675029921764108280945895413025444462666821415736680452608846829130"),
+        MontFp!("18123536127705031756056204540478012554436917815872750660729177819553468155025"),
+        MontFp!("16474695961110424513330465843899953981481343663371860222961481221969897952198"),
+        MontFp!("2209817576856774806892968552992596694709958046027675757917973541795331813663"),
+        MontFp!("8886909411661435806262103670565814337403591980418583691957452730799770256580"),
+        MontFp!("21146597911478558521817874509339753943536471366778323028172015067245554518122"),
+        MontFp!("6964879317404480094615449544671144137989717220698263972759812146804428662043"),
+        MontFp!("15013493697879459837714239113565150394749547847762421910091469844445534649866"),
+        MontFp!("12873494258308490535425105522937536694643688139331584134384255368635555495856"),
+        MontFp!("8148702221188932642558597775495036514950508879563161444371283651889193871808"),
+        MontFp!("11652559249858728460469148604149859328291007058456974915763631376599821149100"),
+        MontFp!("12622635953375710781043046360669048487379345914683818785491908406580147362689"),
+        MontFp!("9760726472446360463999494247753708067891456817627382193093693256614883070621"),
+        MontFp!("7424274168369404031495703693556829102902786462623509975981417999106667361921"),
+        MontFp!("10768148164866512318719166193214067946096709962442576192018815820620398451915"),
+        MontFp!("6373136742443344992454278222109678581933619615723282860342408384689321431145"),
+        MontFp!("1341276563811626108094669793584468699235924039803566600256767652091493458150"),
+        MontFp!("10231727528447895315547055135476630908795406724248450811052392040385962718652"),
+    ],
+    [
+        MontFp!("18523757596685551927095287519532228129271460295426069591512386434544773551264"),
+        MontFp!("18657116623643965308896821827855578174508366377523857807008750329866291157801"),
+        MontFp!("6345826537806875573565021106595818303973553032238034836799899428954070426301"),
+        MontFp!("10516725903395703211820871799124869988446020824871666829087159497279389062186"),
+        MontFp!("2262333557083762146997726296855376279715902469584671692414379141022932186843"),
+        MontFp!("4883718266288493105959046067245723758875534559029652592178324489221722167755"),
+        MontFp!("10531583642414723774322002778300664332520758238880750342897670011963036761797"),
+        MontFp!("3042148347411032886831711579752375598368118032374763466898251387278255721770"),
+        MontFp!("11133396147982171673915699933805432813605489620346003552655297832181283241716"),
+        MontFp!("9858268020638956510422874080032674416136634740186304189729073717751158947388"),
+        MontFp!("3049762505346639070985891638080961813506241289843802131727862953658746758264"),
+        MontFp!("5179738479314717330423229024418863527754038753692288908657503487873526196819"),
+        MontFp!("5540867143305772946239702398234538711135245050504530492654552988155108721571"),
+        MontFp!("11077983068429491778190602224470444891823256743211871537432199905871711775980"),
+        MontFp!("14912847873773796209399774828412163025383282673774986359995449470353250934844"),
+        MontFp!("17088673353207248869079433634834117336127739714525076879381357219129977733841"),
+        MontFp!("7853414014808883732680288463058061954936043442241537961363653177034362594999"),
+        MontFp!("13088550668002070675767646943298647300399361265717903035495071675912643489556"),
+        MontFp!("21561020965316865601719176731881494489397786197919388563615416325322268795014"),
+        MontFp!("3453752901898132284478262108701580138725841126818674484466758423029376330794"),
+    ],
+    [
+        MontFp!("19686723023267871107505092985969723390249859984206241003836989718078082918172"),
+        MontFp!("19379206752371462919548526111468905865705370947779689755888043805891989711853"),
+        MontFp!("11128057592054357382900051378378650743597291847306814963245053665762987369223"),
+        MontFp!("17094432442793555999670914195919630429323908655188889837950188981612331984644"),
+        MontFp!("7056295723313355600885890348735246874585636099377507867297641350188541771675"),
+        MontFp!("21103788659052494516056138860664210990384298093090200947209070164195146162269"),
+        MontFp!("16683233238832933648822428377745340007974858851154657110860744477836567514637"),
+        MontFp!("752194234288582790290658756108957454686777888566529365868647335772022524961"),
+        MontFp!("19471633920455265011239718880131621220249102732390036361959042574011532601430"),
+        MontFp!("6764564301353845564041752241756855765647102629977914308467394994121999382839"),
+        MontFp!("10557219986010707665295052201205331153464951753482096262485921503285955334572"),
+        MontFp!("19174034861977205237354160133906722139445386246586230056295311364810163410150"),
+        MontFp!("2944137304813280067207322507549862361215784818537693915892935932489475702764"),
+        MontFp!("2312935192894235280632030783890082808441774400150988862459019615523915530353"),
+        MontFp!("15397844264239681176333200126608562725153458990916838316489269421465037192250"),
+        MontFp!("15431848426259136081908385004406368984956356326377122595357414524995580162004"),
+        MontFp!("14246891219492184572646339126864300364635348718371565818804239011857489330854"),
+        MontFp!("704306921799971705927696140918892878063209065787989559957702099958117539492"),
+        MontFp!("12927449176462213818673313955402010433768485914097048861339257263081293560952"),
+        MontFp!("10665507610894722437791550428148344528322075042723311661607460072897650901099"),
+    ],
+    // Second external
+    [
+        MontFp!("16848252246928095676054712398596373459855143970171409339100831110853501566854"),
+        MontFp!("19870650774939512654208264890265146970603102906884627840605740981838130467813"),
+        MontFp!("6381253005077577212084270499058801972624786092867103823246106814245645246733"),
+        MontFp!("4932497506641722929781987547327107321591375608596539244234000883191831350491"),
+        MontFp!("9709806596121885888395955658480958956670704559955914321691438704139768837133"),
+        MontFp!("13463215268054207234579539110209185254874201477469041606405151984443853146937"),
+        MontFp!("20172142989664739328983729247513057924577049822120055860607910650679797698922"),
+        MontFp!("21838239590726505837704837771999295570344712325101757707323480329116216327055"),
+        MontFp!("9910391382808195000204727080239392093411013319106302119962040151723981906454"),
+        MontFp!("18595239465350057702340671001200125966466269461303206528761345471739376525744"),
+        MontFp!("283007473959371826898725078361521222632799913781637546466058489441538593932"),
+        MontFp!("15051565279248322446955957388628412030790121916147697850372364594190335465987"),
+        MontFp!("7975292086441991585273694106654204892214979539151834766164690061181544924840"),
+        MontFp!("19204660688498099256836066127870048223511931702798492784567871592712172439010"),
+        MontFp!("12967809119662197605261961777214863928989604958821415694977777305889801045241"),
+        MontFp!("6096815321087899259327071865702197193123256325292816858362599132912150944479"),
+        MontFp!("15770258790089714698821588361363900003249821472993111867200225510350825186859"),
+        MontFp!("21879871387256716910463749860459134078710786133559749459246559034251462954280"),
+        MontFp!("15580687879582909396854357114177876478695367455747615909699040097048835158574"),
+        MontFp!("8680551448651733795396286956938310229855503283049570115937917391059237337176"),
+    ],
+    [
+        MontFp!("2718842207346165682298493256892612317355045405315880239091296066658221963574"),
+        MontFp!("16848220645491750454391162229621395892453446434075447434127234490761763745177"),
+        MontFp!("1767460339275669125715409033976718499614550380707079863706251894390362974210"),
+        MontFp!("5968147581846270140121484790351732813854283827141828938576137217822398505384"),
+        MontFp!("17145868565139342449979009514145647988530697327763505280583886846245090397308"),
+        MontFp!("19692222660901507475153864990255621942465913018822054922437094388778086274289"),
+        MontFp!("761672532162682737431418573777529871100739107975982957350869178585497296052"),
+        MontFp!("3711032987051540662228456758841268342634865482880374552196754171441173255944"),
+        MontFp!("20507720204532377886048753949576655084168486049234298958443516615294093008466"),
+        MontFp!("10336922867628420967518226802296114772281711525181320681609808294908555576423"),
+        MontFp!("8912549882919437184875883858992852094445012318426238274880991946552702747602"),
+        MontFp!("13870160093792346904228079904116632651315091233253721067361909187482386831540"),
+        MontFp!("8179127892542272484965988224313049974050540052920697419746079229865885581618"),
+        MontFp!("15073655630739678580399933126211865407574343649622299876125571598996895077019"),
+        MontFp!("18262497152748625975578484812604422000666169414409578479585879783190650990962"),
+        MontFp!("18896806878066464650465449982193068632842722174866603818544755299522346213136"),
+        MontFp!("10552074223381013662384264934465840186765523132327979201365233797173921877665"),
+        MontFp!("2386967748072325024309315151862023521171208035745956339832529501346022039497"),
+        MontFp!("10047100419794602391770700264621731354089256513910876997689218827622224217456"),
+        MontFp!("4263615235109250592622528083915188979734910602558744899457747236681898785941"),
+    ],
+    [
+        MontFp!("5636323690426749992521280461961561066970857003079053460463253177089088673062"),
+        MontFp!("20167583724335099407220557261510763960510930621184345020746255713701824059380"),
+        MontFp!("15296113435179553217972925994933844391865559471242544088284961968592564532677"),
+        MontFp!("4075890623225401057302827360273719607580263149923231934687870569170149584714"),
+        MontFp!("5512158337974900149415289768073249289175313464673719992631376404934919124738"),
+        MontFp!("21342436872210085168731624256070819404294405450600695179070841657682135114834"),
+        MontFp!("20490280280249525305390334110757512594154748037063351781315509650430821865825"),
+        MontFp!("6543742553410605438741604319971433752919622365824784052282561751220640636461"),
+        MontFp!("19275273331282170741496318285751754859035722308631192238431665537881923039129"),
+        MontFp!("21110958451579637368599774106827918036837773032504907013237683787878775535330"),
+        MontFp!("15644234365731470399598521854526700873728496282302098473387375509108444002754"),
+        MontFp!("7854947433755739223315039879786895290161273676364616111647586749879281715387"),
+        MontFp!("18723913626328933326444335925484593439018979908186591196636390518610383537975"),
+        MontFp!("7613107309657770815903053840576303283083460129173501253220055253494060745367"),
+        MontFp!("3873302623921851877525098482638649903122537812862152738096167146705099359179"),
+        MontFp!("21474869656160108870579322902122539321864673992699947019193466077826489256307"),
+        MontFp!("21526154424534421192172099650485323743426858217854152569791991171544237029027"),
+        MontFp!("8109325221311557542771489637711735629094339144319056112363222781593818362910"),
+        MontFp!("8390128412720893385763582336922240937909478777347989147771456763819726064254"),
+        MontFp!("18433704174922509921860378959354847964324004666476013628781167425155728946761"),
+    ],
+    [
+        MontFp!("12842650553341584717777179266673441360699355201653293565325895559724241291535"),
+        MontFp!("6574683294788342045005478293354141316489873975086286621637831578955895829733"),
+        MontFp!("10777616599828116284684336757230992602691475845309356694287204397912070383814"),
+        MontFp!("5739878502474958857131606219427508589931028856800176191875646238353378111488"),
+        MontFp!("17852162335546350602269474126150087503859226876851530142283558051991410469409"),
+        MontFp!("2049627774275088802381632325684552055555540530630456755010850015240905965230"),
+        MontFp!("10336129849785434353142311445624215664371069332110088023649068125803616245200"),
+        MontFp!("67720515861206172736940751314778848763778157117672864611233357963636345159"),
+        MontFp!("4644098356990445799188665867533852880233546091421931120722344201026165721380"),
+        MontFp!("18577027865102219755838501822457233980813236590616441714400394760520468868996"),
+        MontFp!("18194673862978861161329352175791345488390868092422703131132125746863877362808"),
+        MontFp!("13176191235209427347559064808053273272278131627472206917861585112752859103015"),
+        MontFp!("20568238511336612696767363234767956943421174987649109650619153259547319269996"),
+        MontFp!("1680715536419202876564524132540885529121230505965882770126597507720526837459"),
+        MontFp!("21032966979066658213765728626181564550547859773771481863098931792148989525674"),
+        MontFp!("19633346622084897050685953899775740513937510436492055785657697679096816089098"),
+        MontFp!("20078928537468549753584734450899567095966442619834360095390722918440339234374"),
+        MontFp!("14072546895359692531253234561298993140991853807313416845149605524968445288940"),
+        MontFp!("21744059816766953716313815420105093708633147548619333848398701178872006333461"),
+        MontFp!("14663022791224961079763289537060457661954148551298081608870150421264737683683"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("15900792803593066389440779220924633117478136293703903656559421120957606246214"),
+    MontFp!("11288050567433518708319767602097930963618167307926777535814871222595788260499"),
+    MontFp!("14157702676468198709509236605608391473661978202429320219955856770812373047434"),
+    MontFp!("19885831129235950475308684599968579371638977180751005241217655189978373391542"),
+    MontFp!("20001272064873420228779604657588932570993325807258247163289095614914153990299"),
+    MontFp!("12215176662043980169470450035845675244504926601760710689654415014323285242036"),
+    MontFp!("4813345111802325301897032731786656722320298665504925757782846526355241153891"),
+    MontFp!("9822837798551513142473795790928867021852413771909147832656987998526785969587"),
+    MontFp!("15199136041247220256281159681836500994756272723871496599589893476361676210439"),
+    MontFp!("18312829584407316959892568150607676708662117298750718090854921564267396957614"),
+    MontFp!("4253016409191078172602277523642701854922871431524919586192275028584088329248"),
+    MontFp!("5439483794704719434853836599700497948863768170641002724558560825834262706794"),
+    MontFp!("10571126365581588612230347575151597113117033896174199509403038771768883545880"),
+    MontFp!("17065947893614727917712118675934669023460199099164482269743935501312021009705"),
+    MontFp!("9444302984568855137533605032681359335893975679634681350807905984169416627090"),
+    MontFp!("14149459586410990802462843695973758719738851628896110092233420060782730730452"),
+    MontFp!("8347122554977611913562437715994022369411194553051080256549905207712650555900"),
+    MontFp!("11582648906750437237809331485260544096311109125324716403476455412124607587381"),
+    MontFp!("16014058744761675868732347166524253837853906886109658262996235553049434569595"),
+    MontFp!("1976606495993338067581057595747820814975025088197629103292265223476519274245"),
+    MontFp!("9994439979660607841557540063870864084930072338739869464900396609456571394037"),
+    MontFp!("8888830010175216281412526155316841597185925686995233591250486551490374550394"),
+    MontFp!("17488508884597133167412123100074750905604792706523024961420326272031523509960"),
+    MontFp!("16813791808257894331100791850368633727470644486981937637282581688831468804073"),
+    MontFp!("6812296352486773875115037902293329450705811571250443275358037242786492480042"),
+    MontFp!("9539956798097795325569959857388569535159520938109487180901311282408033411384"),
+    MontFp!("17405361819436843886672375685760174834013351626399324134395848394337173228146"),
+    MontFp!("20905183492074879274909020300376103630880503688113352695267563799440410851419"),
+    MontFp!("19510399841973912552104635737305430930077714097368546946385599463249969579212"),
+    MontFp!("16726129490002471011291548017933335249671279263017354496498312794859049702646"),
+    MontFp!("10088965152068785517940280714704022196509883993691415415388559228626739506693"),
+    MontFp!("21509393276058557356255380827948232245289859936355655658200234309907277382281"),
+    MontFp!("16769976397087847047207614456689104786176769217561735149674372884840148167665"),
+    MontFp!("7995695566917540982636265351477813901970034120411462139405294964957217975272"),
+    MontFp!("19663740630436013252619316510482953441796338079461208152931856991902115645703"),
+    MontFp!("12563091845949087981948165434617906367227201877221871989645056569898177782333"),
+    MontFp!("4410808291591251682332431397751504182792798489728486880491781935792314178363"),
+    MontFp!("16351813101128554045219694168946417061778213155057998545082709709524355474252"),
+    MontFp!("7507249612215503470310347871065146572616160893515253199840132946238161101283"),
+    MontFp!("5782613545325623701609852937695691125117332825017581647768434881962884571873"),
+    MontFp!("6693917245355128444355665923601790059830529361500727253595565202244643932645"),
+    MontFp!("5799759828969881199961794582798000946895659473332613905852465713083593083380"),
+    MontFp!("5877174927202408305037880307644127506561981898947438302420463140435303242987"),
+    MontFp!("6712750116576300154999657871566796870915531338193334005450528736317734302022"),
+    MontFp!("17303723648265389641187569031418162224307155223285887427995096670235045461174"),
+    MontFp!("9865122181200346791613061826601269182483036697346068745552017219220898801168"),
+    MontFp!("7998585899074822614289708394293540739320924882260595792320164401228098934255"),
+    MontFp!("18705174434399031218588168344664973009432052710094382225335863698606362029674"),
+    MontFp!("5548427164284965386861232900822085998722755822069566508628321992236052955085"),
+    MontFp!("6145584153702157435767692972530503458329613949255431953127686285916748717495"),
+    MontFp!("2712603420154025173424195970799066193744565684251789127879651606319666426834"),
+    MontFp!("21254771150699310978528634825689600307020565807481594088276693578761234592869"),
+    MontFp!("17008106832293036318547901542967758344928639924178605677920981699898537464466"),
+    MontFp!("16010218155244568808142441905265747766330763488567388047511689252937210774373"),
+    MontFp!("2809038184984187032637125569007056279266703542216658681597012322824431116080"),
+    MontFp!("3193860380542625722932813098784420573013494343055960499381515367116511001227"),
+    MontFp!("5824357777208272359371950291124832198271001231234700640855780714069299515659"),
+];
+
+/// The Poseidon2 parameters for the BN254 curve with a internal state of size t=20.
+pub static POSEIDON2_BN254_T20_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 20-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 20]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 20]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 20]) -> [ark_bn254::Fr; 20] {
+    POSEIDON2_BN254_T20_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 20-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 20]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 20]) {
+    POSEIDON2_BN254_T20_PARAMS.permutation_in_place(state);
+}
+
+/// Applies the Poseidon2 permutation in place to each of the 20-element states over `bn254`.
+///
+/// Equivalent to calling [`permutation_in_place`] on every state. With the `parallel` feature, the states are permuted in parallel, which is faster for large batches.
+/// Without it, this is not measurably faster than the loop, see [`Poseidon2Permutation::permutation_batch`].
+///
+/// # Arguments
+/// * `states` - A mutable slice of state arrays (`[ark_bn254::Fr; 20]`).
+pub fn permutation_batch(states: &mut [[ark_bn254::Fr; 20]]) {
+    POSEIDON2_BN254_T20_PARAMS.permutation_batch(states);
+}
+
+/// Applies the inverse of the Poseidon2 permutation to a 20-element state over `bn254`.
+///
+/// Returns the state `x` with `permutation(&x) == *state`. This is considerably slower than [`permutation`], see [`Poseidon2Permutation::permutation_inverse_in_place`].
+///
+/// # Arguments
+/// * `state` - A reference to the permuted state array (`[ark_bn254::Fr; 20]`).
+///
+/// # Returns
+/// The preimage of the state under the permutation as `[ark_bn254::Fr; 20]`.
+#[must_use]
+pub fn permutation_inverse(state: &[ark_bn254::Fr; 20]) -> [ark_bn254::Fr; 20] {
+    POSEIDON2_BN254_T20_PARAMS.permutation_inverse(state)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        bn254::t20::POSEIDON2_BN254_T20_PARAMS,
+        test::{
+            TESTRUNS, poseidon2_batch_perm, poseidon2_consistent_perm, poseidon2_inverse_perm,
+            poseidon2_kat,
+        },
+    };
+    use std::str::FromStr;
+    #[test]
+    fn poseidon2_bn254_t20_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BN254_T20_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_bn254_t20_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_BN254_T20_PARAMS);
+        }
+    }
+    #[test]
+    fn poseidon2_bn254_t20_batch() {
+        poseidon2_batch_perm(&POSEIDON2_BN254_T20_PARAMS);
+    }
+    #[test]
+    fn poseidon2_bn254_t20_kat1() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2), instantiated with the parameters above
+        let input = std::array::from_fn(|i| ark_bn254::Fr::from(i as u64));
+        let expected = [
+            ark_bn254::Fr::from_str(
+                "3124720921688574450553074802826859585544286381323531416215313284891292691802",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "19276634348134507777080441164479436119742300569581318993815805767848155635863",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "5047326132906045511352744536277753729505194021189956557097678315706147020875",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "5428393737267008022042025913465079396459458994067769299228012301074158203085",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "14638189900363246302622719902904820321115670364902258203588885160927267770095",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "16820452521750065564299926681790914239389272041306605835647417243216081902338",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "1526255891945118022770875803930674372934816925730617731766678203298345191968",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "11935537980753182563820588640744814469847719855213702514516370749713901109252",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "20317688892762075820189521284672495187746571070117120644112584864438828985272",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "6320535382507926162683957577988233206820322670602652698938057329726369757040",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "12135739247210638305244406060446993590039066807700106127506455209139942978833",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "10280720212435720030443747868094343734086553037250208779092110294282277672204",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "6620817200265824449149466297826507342368579158169426121616574853008461755432",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "18260022061159125339855847675224794053483596780873199376958167943045259747758",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "5541920013236010831177918075183940276156605882343135177232422315013263710470",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "14608088881731411510285243435119429783629045371885995720797206959820349250406",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "12542260029397685640537976603285837011529443982451787014627954874244835398565",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "16939666007990608650344598227916059476529273424046464183284803325316038597832",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "6788283764909257076681096876231288279935596252892042816823189719480991082981",
+            )
+            .expect("Is in Fr"),
+            ark_bn254::Fr::from_str(
+                "2504904351737343743893579703940953930995547778963425283885491502912552625910",
+            )
+            .expect("Is in Fr"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_BN254_T20_PARAMS, &input, &expected);
+    }
+}
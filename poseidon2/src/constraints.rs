@@ -0,0 +1,14 @@
+//! R1CS gadgets for the Poseidon2 permutation.
+//!
+//! The gadgets use the same parameters as the native permutations and enforce that the output state is the Poseidon2 permutation of the input state.
+//! Each S-box of degree 5 costs three constraints, all linear layers are free.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let state: [FpVar<ark_bn254::Fr>; 8] = ...;
+//! let permuted = poseidon2::constraints::bn254::t8::permutation_var(&state)?;
+//! ```
+
+#[cfg(feature = "bn254")]
+pub mod bn254;
@@ -0,0 +1,390 @@
+//! R1CS gadgets for the Poseidon2 permutation over the `bn254` scalar field.
+//!
+//! All gadgets are feature-gated in the same way as their native counterparts in [`crate::bn254`].
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=2`.
+#[cfg(feature = "t2")]
+pub mod t2 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t2::POSEIDON2_BN254_T2_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 2-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t2::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 2],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 2], SynthesisError> {
+        POSEIDON2_BN254_T2_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 2-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t2::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 2],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T2_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=3`.
+#[cfg(feature = "t3")]
+pub mod t3 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t3::POSEIDON2_BN254_T3_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 3-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t3::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 3],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 3], SynthesisError> {
+        POSEIDON2_BN254_T3_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 3-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t3::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 3],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T3_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=4`.
+#[cfg(feature = "t4")]
+pub mod t4 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t4::POSEIDON2_BN254_T4_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 4-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t4::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 4],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 4], SynthesisError> {
+        POSEIDON2_BN254_T4_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 4-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t4::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 4],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T4_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=8`.
+#[cfg(feature = "t8")]
+pub mod t8 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t8::POSEIDON2_BN254_T8_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 8-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t8::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 8],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 8], SynthesisError> {
+        POSEIDON2_BN254_T8_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 8-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t8::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 8],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T8_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=12`.
+#[cfg(feature = "t12")]
+pub mod t12 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t12::POSEIDON2_BN254_T12_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 12-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t12::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 12],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 12], SynthesisError> {
+        POSEIDON2_BN254_T12_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 12-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t12::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 12],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T12_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=16`.
+#[cfg(feature = "t16")]
+pub mod t16 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t16::POSEIDON2_BN254_T16_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 16-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t16::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 16],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 16], SynthesisError> {
+        POSEIDON2_BN254_T16_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 16-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t16::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 16],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T16_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=20`.
+#[cfg(feature = "t20")]
+pub mod t20 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t20::POSEIDON2_BN254_T20_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 20-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t20::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 20],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 20], SynthesisError> {
+        POSEIDON2_BN254_T20_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 20-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t20::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 20],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T20_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+/// R1CS gadgets for the Poseidon2 permutation with state size `t=24`.
+#[cfg(feature = "t24")]
+pub mod t24 {
+    use ark_r1cs_std::fields::fp::FpVar;
+    use ark_relations::gr1cs::SynthesisError;
+
+    use crate::bn254::t24::POSEIDON2_BN254_T24_PARAMS;
+
+    /// Enforces the Poseidon2 permutation on a 24-element state over `bn254`.
+    ///
+    /// Returns a new permuted state, see [`crate::bn254::t24::permutation`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_var(
+        state: &[FpVar<ark_bn254::Fr>; 24],
+    ) -> Result<[FpVar<ark_bn254::Fr>; 24], SynthesisError> {
+        POSEIDON2_BN254_T24_PARAMS.permutation_var(state)
+    }
+
+    /// Enforces the Poseidon2 permutation in place on a 24-element state over `bn254`.
+    ///
+    /// See [`crate::bn254::t24::permutation_in_place`].
+    ///
+    /// # Errors
+    /// Returns an error if allocating the intermediate variables fails.
+    pub fn permutation_in_place_var(
+        state: &mut [FpVar<ark_bn254::Fr>; 24],
+    ) -> Result<(), SynthesisError> {
+        POSEIDON2_BN254_T24_PARAMS.permutation_in_place_var(state)
+    }
+}
+
+#[cfg(any(
+    feature = "t2",
+    feature = "t3",
+    feature = "t4",
+    feature = "t8",
+    feature = "t12",
+    feature = "t16",
+    feature = "t20",
+    feature = "t24"
+))]
+#[cfg(test)]
+mod tests {
+    use ark_ff::PrimeField;
+    use ark_r1cs_std::{GR1CSVar, alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+    use ark_relations::gr1cs::ConstraintSystem;
+    use ark_std::rand::thread_rng;
+
+    use crate::{perm::Poseidon2Permutation, test::TESTRUNS};
+
+    fn poseidon2_permutation_var<
+        F: PrimeField,
+        const T: usize,
+        const D: u64,
+        const ROUNDS_F: usize,
+        const ROUNDS_P: usize,
+    >(
+        poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
+    ) {
+        let mut rng = thread_rng();
+        for _ in 0..TESTRUNS {
+            let input: [F; T] = std::array::from_fn(|_| F::rand(&mut rng));
+            let expected = poseidon2_perm.permutation(&input);
+
+            let cs = ConstraintSystem::<F>::new_ref();
+            let input_var = input
+                .map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).expect("allocation succeeds"));
+            let output_var = poseidon2_perm
+                .permutation_var(&input_var)
+                .expect("gadget succeeds");
+            let output = output_var.map(|x| x.value().expect("value is assigned"));
+            assert_eq!(
+                output, expected,
+                "gadget output should match native permutation"
+            );
+            assert!(
+                cs.is_satisfied().expect("constraint system is consistent"),
+                "constraints should be satisfied"
+            );
+            // Each x^5 S-box costs three constraints, the linear layers are free
+            assert_eq!(
+                cs.num_constraints(),
+                3 * (ROUNDS_F * T + ROUNDS_P),
+                "unexpected number of constraints"
+            );
+
+            // Changing an output must violate the constraints
+            let cs = ConstraintSystem::<F>::new_ref();
+            let input_var = input
+                .map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).expect("allocation succeeds"));
+            let output_var = poseidon2_perm
+                .permutation_var(&input_var)
+                .expect("gadget succeeds");
+            let wrong = FpVar::new_witness(cs.clone(), || Ok(expected[0] + F::one()))
+                .expect("allocation succeeds");
+            output_var[0]
+                .enforce_equal(&wrong)
+                .expect("constraint generation succeeds");
+            assert!(
+                !cs.is_satisfied().expect("constraint system is consistent"),
+                "wrong output should not satisfy the constraints"
+            );
+        }
+    }
+
+    #[cfg(feature = "t2")]
+    #[test]
+    fn poseidon2_bn254_t2_var() {
+        poseidon2_permutation_var(&crate::bn254::t2::POSEIDON2_BN254_T2_PARAMS);
+    }
+
+    #[cfg(feature = "t3")]
+    #[test]
+    fn poseidon2_bn254_t3_var() {
+        poseidon2_permutation_var(&crate::bn254::t3::POSEIDON2_BN254_T3_PARAMS);
+    }
+
+    #[cfg(feature = "t4")]
+    #[test]
+    fn poseidon2_bn254_t4_var() {
+        poseidon2_permutation_var(&crate::bn254::t4::POSEIDON2_BN254_T4_PARAMS);
+    }
+
+    #[cfg(feature = "t8")]
+    #[test]
+    fn poseidon2_bn254_t8_var() {
+        poseidon2_permutation_var(&crate::bn254::t8::POSEIDON2_BN254_T8_PARAMS);
+    }
+
+    #[cfg(feature = "t12")]
+    #[test]
+    fn poseidon2_bn254_t12_var() {
+        poseidon2_permutation_var(&crate::bn254::t12::POSEIDON2_BN254_T12_PARAMS);
+    }
+
+    #[cfg(feature = "t16")]
+    #[test]
+    fn poseidon2_bn254_t16_var() {
+        poseidon2_permutation_var(&crate::bn254::t16::POSEIDON2_BN254_T16_PARAMS);
+    }
+
+    #[cfg(feature = "t20")]
+    #[test]
+    fn poseidon2_bn254_t20_var() {
+        poseidon2_permutation_var(&crate::bn254::t20::POSEIDON2_BN254_T20_PARAMS);
+    }
+
+    #[cfg(feature = "t24")]
+    #[test]
+    fn poseidon2_bn254_t24_var() {
+        poseidon2_permutation_var(&crate::bn254::t24::POSEIDON2_BN254_T24_PARAMS);
+    }
+}
@@ -0,0 +1,33 @@
+//! Poseidon2 permutation methods for the Goldilocks field `p = 2^64 - 2^32 + 1`, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
+//!
+//! This module provides the Poseidon2 permutation on the state sizes of the reference implementation (`t8`, `t12`, `t16`, `t20`).
+//!
+//! As specified in the paper, the S-box for Goldilocks is defined as:
+//! $$ x^7 $$
+//!
+//! Parameters are compatible with the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let mut state = [...];
+//! poseidon2::goldilocks::t8::permutation(&state);
+//! poseidon2::goldilocks::t8::permutation_in_place(&mut state);
+//! ```
+
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+/// The Montgomery configuration of [`Fp`].
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+
+/// The Goldilocks field `p = 2^64 - 2^32 + 1`.
+pub type Fp = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+pub mod t12;
+pub mod t16;
+pub mod t20;
+pub mod t8;
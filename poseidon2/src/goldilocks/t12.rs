@@ -0,0 +1,244 @@
+//! Poseidon2 implementation for `t=12` over Goldilocks.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 12;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("14102670999874605824"),
+    MontFp!("15585654191999307702"),
+    MontFp!("940187017142450255"),
+    MontFp!("8747386241522630711"),
+    MontFp!("6750641561540124747"),
+    MontFp!("7440998025584530007"),
+    MontFp!("6136358134615751536"),
+    MontFp!("12413576830284969611"),
+    MontFp!("11675438539028694709"),
+    MontFp!("17580553691069642926"),
+    MontFp!("892707462476851331"),
+    MontFp!("15167485180850043744"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("1431286215153372998"),
+        MontFp!("3509349009260703107"),
+        MontFp!("2289575380984896342"),
+        MontFp!("10625215922958251110"),
+        MontFp!("17137022507167291684"),
+        MontFp!("17143426961497010024"),
+        MontFp!("9589775313463224365"),
+        MontFp!("7736066733515538648"),
+        MontFp!("2217569167061322248"),
+        MontFp!("10394930802584583083"),
+        MontFp!("4612393375016695705"),
+        MontFp!("5332470884919453534"),
+    ],
+    [
+        MontFp!("8724526834049581439"),
+        MontFp!("17673787971454860688"),
+        MontFp!("2519987773101056005"),
+        MontFp!("7999687124137420323"),
+        MontFp!("18312454652563306701"),
+        MontFp!("15136091233824155669"),
+        MontFp!("1257110570403430003"),
+        MontFp!("5665449074466664773"),
+        MontFp!("16178737609685266571"),
+        MontFp!("52855143527893348"),
+        MontFp!("8084454992943870230"),
+        MontFp!("2597062441266647183"),
+    ],
+    [
+        MontFp!("3342624911463171251"),
+        MontFp!("6781356195391537436"),
+        MontFp!("4697929572322733707"),
+        MontFp!("4179687232228901671"),
+        MontFp!("17841073646522133059"),
+        MontFp!("18340176721233187897"),
+        MontFp!("13152929999122219197"),
+        MontFp!("6306257051437840427"),
+        MontFp!("4974451914008050921"),
+        MontFp!("11258703678970285201"),
+        MontFp!("581736081259960204"),
+        MontFp!("18323286026903235604"),
+    ],
+    [
+        MontFp!("10250026231324330997"),
+        MontFp!("13321947507807660157"),
+        MontFp!("13020725208899496943"),
+        MontFp!("11416990495425192684"),
+        MontFp!("7221795794796219413"),
+        MontFp!("2607917872900632985"),
+        MontFp!("2591896057192169329"),
+        MontFp!("10485489452304998145"),
+        MontFp!("9480186048908910015"),
+        MontFp!("2645141845409940474"),
+        MontFp!("16242299839765162610"),
+        MontFp!("12203738590896308135"),
+    ],
+    // Second external
+    [
+        MontFp!("14306783492963476045"),
+        MontFp!("12653264875831356889"),
+        MontFp!("10887434669785806501"),
+        MontFp!("7221072982690633460"),
+        MontFp!("9953585853856674407"),
+        MontFp!("13497620366078753434"),
+        MontFp!("18140292631504202243"),
+        MontFp!("17311934738088402529"),
+        MontFp!("6686302214424395771"),
+        MontFp!("11193071888943695519"),
+        MontFp!("10233795775801758543"),
+        MontFp!("3362219552562939863"),
+    ],
+    [
+        MontFp!("8595401306696186761"),
+        MontFp!("7753411262943026561"),
+        MontFp!("12415218859476220947"),
+        MontFp!("12517451587026875834"),
+        MontFp!("3257008032900598499"),
+        MontFp!("2187469039578904770"),
+        MontFp!("657675168296710415"),
+        MontFp!("8659969869470208989"),
+        MontFp!("12526098871288378639"),
+        MontFp!("12525853395769009329"),
+        MontFp!("15388161689979551704"),
+        MontFp!("7880966905416338909"),
+    ],
+    [
+        MontFp!("2911694411222711481"),
+        MontFp!("6420652251792580406"),
+        MontFp!("323544930728360053"),
+        MontFp!("11718666476052241225"),
+        MontFp!("2449132068789045592"),
+        MontFp!("17993014181992530560"),
+        MontFp!("15161788952257357966"),
+        MontFp!("3788504801066818367"),
+        MontFp!("1282111773460545571"),
+        MontFp!("8849495164481705550"),
+        MontFp!("8380852402060721190"),
+        MontFp!("2161980224591127360"),
+    ],
+    [
+        MontFp!("2440151485689245146"),
+        MontFp!("17521895002090134367"),
+        MontFp!("13821005335130766955"),
+        MontFp!("17513705631114265826"),
+        MontFp!("17068447856797239529"),
+        MontFp!("17964439003977043993"),
+        MontFp!("5685000919538239429"),
+        MontFp!("11615940660682589106"),
+        MontFp!("2522854885180605258"),
+        MontFp!("12584118968072796115"),
+        MontFp!("17841258728624635591"),
+        MontFp!("10821564568873127316"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("5395176197344543510"),
+    MontFp!("17941136338888340715"),
+    MontFp!("7559392505546762987"),
+    MontFp!("549633128904721280"),
+    MontFp!("15658455328409267684"),
+    MontFp!("10078371877170729592"),
+    MontFp!("2349868247408080783"),
+    MontFp!("13105911261634181239"),
+    MontFp!("12868653202234053626"),
+    MontFp!("9471330315555975806"),
+    MontFp!("4580289636625406680"),
+    MontFp!("13222733136951421572"),
+    MontFp!("4555032575628627551"),
+    MontFp!("7619130111929922899"),
+    MontFp!("4547848507246491777"),
+    MontFp!("5662043532568004632"),
+    MontFp!("15723873049665279492"),
+    MontFp!("13585630674756818185"),
+    MontFp!("6990417929677264473"),
+    MontFp!("6373257983538884779"),
+    MontFp!("1005856792729125863"),
+    MontFp!("17850970025369572891"),
+];
+
+/// The Poseidon2 parameters for Goldilocks with a internal state of size t=12.
+pub static POSEIDON2_GOLDILOCKS_T12_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 12-element state over Goldilocks.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::goldilocks::Fp; 12]`).
+///
+/// # Returns
+/// A permuted state as `[crate::goldilocks::Fp; 12]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 12]) -> [Scalar; 12] {
+    POSEIDON2_GOLDILOCKS_T12_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 12-element state over Goldilocks.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::goldilocks::Fp; 12]`).
+pub fn permutation_in_place(state: &mut [Scalar; 12]) {
+    POSEIDON2_GOLDILOCKS_T12_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        goldilocks::t12::POSEIDON2_GOLDILOCKS_T12_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_goldilocks_t12_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_GOLDILOCKS_T12_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t12_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_GOLDILOCKS_T12_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t12_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 12] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("138186169299091649"),
+            MontFp!("2237493815125627916"),
+            MontFp!("7098449130000758157"),
+            MontFp!("16681569560651424230"),
+            MontFp!("2885694034573886267"),
+            MontFp!("1987263728465303211"),
+            MontFp!("4895658260063552408"),
+            MontFp!("16782691522897809445"),
+            MontFp!("6250362358359317026"),
+            MontFp!("8723968546836371205"),
+            MontFp!("17025428646788054631"),
+            MontFp!("7660698892044183277"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_GOLDILOCKS_T12_PARAMS, &input, &expected);
+    }
+}
@@ -0,0 +1,284 @@
+//! Poseidon2 implementation for `t=16` over Goldilocks.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 16;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("16040574633112940480"),
+    MontFp!("14263299814608977431"),
+    MontFp!("770395855193680981"),
+    MontFp!("3459277367440070515"),
+    MontFp!("17087697094293314027"),
+    MontFp!("6694380135428747348"),
+    MontFp!("2034408310088972836"),
+    MontFp!("3434575637390274478"),
+    MontFp!("6052753985947965968"),
+    MontFp!("13608362914817483670"),
+    MontFp!("18163707672964630459"),
+    MontFp!("14373610220374016704"),
+    MontFp!("6226282807566121054"),
+    MontFp!("3643354756180461803"),
+    MontFp!("13046961313070095543"),
+    MontFp!("8594143216561850811"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("1579613653969377219"),
+        MontFp!("15509510893087893340"),
+        MontFp!("10090715174060125222"),
+        MontFp!("5625716564419252202"),
+        MontFp!("3006005019077469174"),
+        MontFp!("18314693207014427912"),
+        MontFp!("10170571510627764565"),
+        MontFp!("2027625550790675754"),
+        MontFp!("3983470257916202094"),
+        MontFp!("3423470109396435354"),
+        MontFp!("3450488264035752368"),
+        MontFp!("3151070045406026687"),
+        MontFp!("13462781804006123550"),
+        MontFp!("13288575772684627216"),
+        MontFp!("13745549378090937523"),
+        MontFp!("7780139165529418388"),
+    ],
+    [
+        MontFp!("11699769881923825909"),
+        MontFp!("4349260652212695338"),
+        MontFp!("8228348357294662898"),
+        MontFp!("12037145371429260559"),
+        MontFp!("7743383997471469184"),
+        MontFp!("7842006993218978420"),
+        MontFp!("18369471830829996810"),
+        MontFp!("10157815501749214163"),
+        MontFp!("11128005290408113568"),
+        MontFp!("12272917402811871099"),
+        MontFp!("106820976709770637"),
+        MontFp!("6178857985076893256"),
+        MontFp!("14036787039817180193"),
+        MontFp!("1659458828486927362"),
+        MontFp!("15938183887757779615"),
+        MontFp!("10060334973073197601"),
+    ],
+    [
+        MontFp!("12437259172286625042"),
+        MontFp!("3143835545170851304"),
+        MontFp!("14361575850007152549"),
+        MontFp!("11810209965918630788"),
+        MontFp!("17667700303515849362"),
+        MontFp!("13451932870729425780"),
+        MontFp!("17496644301504593329"),
+        MontFp!("1778669202480014256"),
+        MontFp!("11124488067381312952"),
+        MontFp!("14273583775934080755"),
+        MontFp!("13257379154210125715"),
+        MontFp!("15182093233826554989"),
+        MontFp!("5343815959848316783"),
+        MontFp!("5696193414571279556"),
+        MontFp!("6089787229781325813"),
+        MontFp!("3717595218375990594"),
+    ],
+    [
+        MontFp!("12355842859741995537"),
+        MontFp!("14160921043125059199"),
+        MontFp!("13682913322779819091"),
+        MontFp!("12343504462597575239"),
+        MontFp!("14249643564311509087"),
+        MontFp!("15849758561085927402"),
+        MontFp!("12186564686511703262"),
+        MontFp!("690520822232718983"),
+        MontFp!("684200944081655198"),
+        MontFp!("6008958449718781068"),
+        MontFp!("3098079696536837500"),
+        MontFp!("12083506578988585239"),
+        MontFp!("6489163969976034646"),
+        MontFp!("1186411428667285248"),
+        MontFp!("11192474480021157091"),
+        MontFp!("17095617549195818058"),
+    ],
+    // Second external
+    [
+        MontFp!("15766898731627159621"),
+        MontFp!("6714370142978148338"),
+        MontFp!("5906664861467821209"),
+        MontFp!("2197120128337974909"),
+        MontFp!("9400421283898153864"),
+        MontFp!("16740473708727660723"),
+        MontFp!("7287335615015370599"),
+        MontFp!("12399217337488717232"),
+        MontFp!("12655834778591227242"),
+        MontFp!("7516917516498792203"),
+        MontFp!("17291898461659961460"),
+        MontFp!("3483661079725019706"),
+        MontFp!("18038566855851505564"),
+        MontFp!("6166154478921479976"),
+        MontFp!("8824538844032254328"),
+        MontFp!("17138720803294251067"),
+    ],
+    [
+        MontFp!("4158286797471309733"),
+        MontFp!("1589482450994236426"),
+        MontFp!("4836112672767737407"),
+        MontFp!("6108317238076822824"),
+        MontFp!("11174425919404088598"),
+        MontFp!("2755155124184744585"),
+        MontFp!("13202523778257173956"),
+        MontFp!("6643835433125335951"),
+        MontFp!("2748965361441888477"),
+        MontFp!("14297205492001640506"),
+        MontFp!("12595977886586773319"),
+        MontFp!("257624298448087449"),
+        MontFp!("2744326799038764281"),
+        MontFp!("9097808034015176417"),
+        MontFp!("5523099006222423939"),
+        MontFp!("7256201493271212720"),
+    ],
+    [
+        MontFp!("9738621014989498753"),
+        MontFp!("237412858668409460"),
+        MontFp!("6405904454922718574"),
+        MontFp!("16343835012250148340"),
+        MontFp!("9342245617122491936"),
+        MontFp!("824922755340087111"),
+        MontFp!("5398764608665671277"),
+        MontFp!("16030622638137836774"),
+        MontFp!("4921899302518525668"),
+        MontFp!("10676667276121395674"),
+        MontFp!("16304445044992359788"),
+        MontFp!("587812931475326787"),
+        MontFp!("7627897473303434798"),
+        MontFp!("2633542348821978290"),
+        MontFp!("5714662808802647771"),
+        MontFp!("15665352327749112015"),
+    ],
+    [
+        MontFp!("8041781683128153420"),
+        MontFp!("13172746155287932121"),
+        MontFp!("6611413049825339003"),
+        MontFp!("9076340683484238287"),
+        MontFp!("12295614633782496176"),
+        MontFp!("11414162089917699064"),
+        MontFp!("13658053783093222498"),
+        MontFp!("16216654076620539925"),
+        MontFp!("17782598995085730495"),
+        MontFp!("18362903988365127450"),
+        MontFp!("11596821426909532371"),
+        MontFp!("13499421621575350806"),
+        MontFp!("15256312875610823807"),
+        MontFp!("1558929959921731273"),
+        MontFp!("17567284148478398281"),
+        MontFp!("4615393526180652407"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("2949845317987848448"),
+    MontFp!("6935557324168256069"),
+    MontFp!("2055390472619727748"),
+    MontFp!("12399309956582731760"),
+    MontFp!("3088058410657255303"),
+    MontFp!("8790959064194804235"),
+    MontFp!("4582664136559626757"),
+    MontFp!("14821962908959377092"),
+    MontFp!("2002685046256118388"),
+    MontFp!("15689593639089632368"),
+    MontFp!("10512046881800463697"),
+    MontFp!("11973738501993105496"),
+    MontFp!("13009070935614847359"),
+    MontFp!("12908033183314664096"),
+    MontFp!("9530849385991780809"),
+    MontFp!("17528314872631229800"),
+    MontFp!("17869527288639567155"),
+    MontFp!("7829055113315023688"),
+    MontFp!("3256047469251174543"),
+    MontFp!("3015723851705964382"),
+    MontFp!("5177282273995529875"),
+    MontFp!("16035152896984012190"),
+];
+
+/// The Poseidon2 parameters for Goldilocks with a internal state of size t=16.
+pub static POSEIDON2_GOLDILOCKS_T16_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 16-element state over Goldilocks.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::goldilocks::Fp; 16]`).
+///
+/// # Returns
+/// A permuted state as `[crate::goldilocks::Fp; 16]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 16]) -> [Scalar; 16] {
+    POSEIDON2_GOLDILOCKS_T16_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 16-element state over Goldilocks.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::goldilocks::Fp; 16]`).
+pub fn permutation_in_place(state: &mut [Scalar; 16]) {
+    POSEIDON2_GOLDILOCKS_T16_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        goldilocks::t16::POSEIDON2_GOLDILOCKS_T16_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_goldilocks_t16_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_GOLDILOCKS_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t16_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_GOLDILOCKS_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t16_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 16] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("9639188652563994454"),
+            MontFp!("12273372933164734616"),
+            MontFp!("2905147255612444119"),
+            MontFp!("17581461329934617288"),
+            MontFp!("14390794100096760072"),
+            MontFp!("5468485695976078057"),
+            MontFp!("2832370985856357627"),
+            MontFp!("1116111836864400812"),
+            MontFp!("14997632823506024332"),
+            MontFp!("3976503894892102369"),
+            MontFp!("14874978986912301676"),
+            MontFp!("12458748982184310703"),
+            MontFp!("103345454961107931"),
+            MontFp!("3354965064850558444"),
+            MontFp!("14413825288474057217"),
+            MontFp!("4214638127285300968"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_GOLDILOCKS_T16_PARAMS, &input, &expected);
+    }
+}
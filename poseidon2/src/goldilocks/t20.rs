@@ -0,0 +1,324 @@
+//! Poseidon2 implementation for `t=20` over Goldilocks.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 20;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("10791612058482899543"),
+    MontFp!("17541496269468922924"),
+    MontFp!("9941094606838397252"),
+    MontFp!("1184203619175752402"),
+    MontFp!("7515999401375846673"),
+    MontFp!("2829206547735581838"),
+    MontFp!("4521283194234715927"),
+    MontFp!("18250187347072443340"),
+    MontFp!("5244727945020272063"),
+    MontFp!("16199929961294420813"),
+    MontFp!("15983719411720232395"),
+    MontFp!("10159649407817228825"),
+    MontFp!("12026060288613385508"),
+    MontFp!("7536754592428076059"),
+    MontFp!("1044216119540211576"),
+    MontFp!("5274370420249928547"),
+    MontFp!("13485809136811574720"),
+    MontFp!("11639526920339249411"),
+    MontFp!("13129604340743162136"),
+    MontFp!("807996637816103828"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("17655927299676208962"),
+        MontFp!("10030612701677799931"),
+        MontFp!("346719106490453683"),
+        MontFp!("6820911385854134397"),
+        MontFp!("15585540514402831926"),
+        MontFp!("6571742652146576292"),
+        MontFp!("16968644441763699873"),
+        MontFp!("17053700544531324259"),
+        MontFp!("3107379598568846778"),
+        MontFp!("12381131979067527978"),
+        MontFp!("2886785126162167484"),
+        MontFp!("9521002403585135203"),
+        MontFp!("15515800817020402330"),
+        MontFp!("16455282610484243347"),
+        MontFp!("16685102364060984170"),
+        MontFp!("6303700883315993212"),
+        MontFp!("9346522723959597879"),
+        MontFp!("1110327270646169905"),
+        MontFp!("10914606321963430436"),
+        MontFp!("2407304067898843003"),
+    ],
+    [
+        MontFp!("6503536824669470848"),
+        MontFp!("5151938673613466571"),
+        MontFp!("6905495928635539866"),
+        MontFp!("1885985396095488527"),
+        MontFp!("17811615615226360505"),
+        MontFp!("5239660700391553842"),
+        MontFp!("8989881679249916786"),
+        MontFp!("15167620044895211012"),
+        MontFp!("17394997327625120003"),
+        MontFp!("8710795153964261480"),
+        MontFp!("6404509997834168342"),
+        MontFp!("6589706609917000743"),
+        MontFp!("27313646548520428"),
+        MontFp!("4997435810968868455"),
+        MontFp!("8113463800332512532"),
+        MontFp!("3559968517723491851"),
+        MontFp!("16470463235032324449"),
+        MontFp!("3614603123524719553"),
+        MontFp!("11033180882494639976"),
+        MontFp!("12541427802818321816"),
+    ],
+    [
+        MontFp!("7551109693539359912"),
+        MontFp!("9860021623411782573"),
+        MontFp!("10686114915806840923"),
+        MontFp!("12469011167046317750"),
+        MontFp!("5254324337938220971"),
+        MontFp!("817522768745943461"),
+        MontFp!("8512065838457015026"),
+        MontFp!("15853117412928930594"),
+        MontFp!("16126889734016250213"),
+        MontFp!("14021387165303025751"),
+        MontFp!("18059593332548789075"),
+        MontFp!("3156188168442237352"),
+        MontFp!("14896491194975973697"),
+        MontFp!("3282120481253225435"),
+        MontFp!("14305239928074816115"),
+        MontFp!("17962644851926742599"),
+        MontFp!("3349692641894680693"),
+        MontFp!("9160742206429647613"),
+        MontFp!("10810725700651206491"),
+        MontFp!("7946391019200245569"),
+    ],
+    [
+        MontFp!("8808022140012780318"),
+        MontFp!("3816273101550995731"),
+        MontFp!("4057383053694268239"),
+        MontFp!("995473015476712005"),
+        MontFp!("14391156111610764448"),
+        MontFp!("6529095508377542579"),
+        MontFp!("7073980020063020702"),
+        MontFp!("1446199599734722403"),
+        MontFp!("7144481023135165015"),
+        MontFp!("7282267210180139644"),
+        MontFp!("12939196145906014987"),
+        MontFp!("2677370514553977514"),
+        MontFp!("13514497221415738245"),
+        MontFp!("2300793714771917117"),
+        MontFp!("15715903922707426493"),
+        MontFp!("16018829070784767824"),
+        MontFp!("1067296338773300833"),
+        MontFp!("14300107703128555504"),
+        MontFp!("2295998998874996999"),
+        MontFp!("16098229091654265268"),
+    ],
+    // Second external
+    [
+        MontFp!("6082472028639076131"),
+        MontFp!("13360303746020154399"),
+        MontFp!("16946961551542720036"),
+        MontFp!("6904669830576070140"),
+        MontFp!("8844316135308274229"),
+        MontFp!("14470348940670949726"),
+        MontFp!("3462344535182646272"),
+        MontFp!("1645123023752022082"),
+        MontFp!("15520523563851976072"),
+        MontFp!("13277387370474765147"),
+        MontFp!("8762030864412478488"),
+        MontFp!("619257635389659934"),
+        MontFp!("6900570558529971639"),
+        MontFp!("16728116662951941613"),
+        MontFp!("2055255907731773492"),
+        MontFp!("5291441514518380121"),
+        MontFp!("2796168239892950340"),
+        MontFp!("3288624334325611854"),
+        MontFp!("2605087886652128515"),
+        MontFp!("15082760638613156830"),
+    ],
+    [
+        MontFp!("11633994962294266501"),
+        MontFp!("1968847698072358908"),
+        MontFp!("600029679121739660"),
+        MontFp!("12772317521963687314"),
+        MontFp!("3597699872367098922"),
+        MontFp!("13237423265869638952"),
+        MontFp!("14058804243569217428"),
+        MontFp!("17503188606311436557"),
+        MontFp!("4651323252135583109"),
+        MontFp!("7449902046069123347"),
+        MontFp!("1099406127352499677"),
+        MontFp!("3489945818369257829"),
+        MontFp!("12798317438982294659"),
+        MontFp!("6451767622661672443"),
+        MontFp!("14775473667862410148"),
+        MontFp!("11481777309617688591"),
+        MontFp!("5972173115481711955"),
+        MontFp!("5619707952390005919"),
+        MontFp!("7431282503516158671"),
+        MontFp!("9582222060496790354"),
+    ],
+    [
+        MontFp!("13077058508620695971"),
+        MontFp!("2248571258319469919"),
+        MontFp!("7919846023439814119"),
+        MontFp!("8723459018094598979"),
+        MontFp!("1127971773097775431"),
+        MontFp!("4736360898600492470"),
+        MontFp!("10317828089001536529"),
+        MontFp!("6706486164491401803"),
+        MontFp!("79189767891723502"),
+        MontFp!("2694946104279144603"),
+        MontFp!("9012524147736268092"),
+        MontFp!("16919543882210910315"),
+        MontFp!("7814123362541675948"),
+        MontFp!("12747506774226384740"),
+        MontFp!("18066724346903275515"),
+        MontFp!("934480250190456158"),
+        MontFp!("14063061749114225595"),
+        MontFp!("5745799608309183152"),
+        MontFp!("14324061688289741013"),
+        MontFp!("10157064979094129842"),
+    ],
+    [
+        MontFp!("9982916154476301452"),
+        MontFp!("8079705052605604589"),
+        MontFp!("13271139488131362038"),
+        MontFp!("15788355238366005949"),
+        MontFp!("9941504702484786798"),
+        MontFp!("15395145735950871925"),
+        MontFp!("126882512446994601"),
+        MontFp!("8403606992597284083"),
+        MontFp!("5940599433656199376"),
+        MontFp!("7792473867804146649"),
+        MontFp!("5205688674317544944"),
+        MontFp!("7423996852795231289"),
+        MontFp!("11727247982218399096"),
+        MontFp!("3689793140299501611"),
+        MontFp!("4072732276510170657"),
+        MontFp!("10231004510525457236"),
+        MontFp!("14884207140962074841"),
+        MontFp!("9876548396825956055"),
+        MontFp!("17620732712704282403"),
+        MontFp!("13041626216399966582"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("13025646434196306251"),
+    MontFp!("4210259766096389933"),
+    MontFp!("16474512694074459032"),
+    MontFp!("13314004004850599878"),
+    MontFp!("4302188279843827161"),
+    MontFp!("2859004902274110532"),
+    MontFp!("1529605154731907120"),
+    MontFp!("10772649751248828858"),
+    MontFp!("16548911070496837906"),
+    MontFp!("18396104966180523211"),
+    MontFp!("4540541080790310979"),
+    MontFp!("8762139140410109331"),
+    MontFp!("6903107304943489246"),
+    MontFp!("1302830609454020594"),
+    MontFp!("16880382004754185353"),
+    MontFp!("11409921296471382138"),
+    MontFp!("15939744905060115051"),
+    MontFp!("16236650994369692333"),
+    MontFp!("11304049081959549111"),
+    MontFp!("6583721883776950505"),
+    MontFp!("15973705279745490296"),
+    MontFp!("16777439708225768201"),
+];
+
+/// The Poseidon2 parameters for Goldilocks with a internal state of size t=20.
+pub static POSEIDON2_GOLDILOCKS_T20_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 20-element state over Goldilocks.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::goldilocks::Fp; 20]`).
+///
+/// # Returns
+/// A permuted state as `[crate::goldilocks::Fp; 20]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 20]) -> [Scalar; 20] {
+    POSEIDON2_GOLDILOCKS_T20_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 20-element state over Goldilocks.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::goldilocks::Fp; 20]`).
+pub fn permutation_in_place(state: &mut [Scalar; 20]) {
+    POSEIDON2_GOLDILOCKS_T20_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        goldilocks::t20::POSEIDON2_GOLDILOCKS_T20_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_goldilocks_t20_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_GOLDILOCKS_T20_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t20_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_GOLDILOCKS_T20_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t20_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 20] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("8286735709083276653"),
+            MontFp!("11149088023565435727"),
+            MontFp!("15023266237519995777"),
+            MontFp!("18365691466371460274"),
+            MontFp!("15198135344673678767"),
+            MontFp!("9869463076898621604"),
+            MontFp!("12965708132840729832"),
+            MontFp!("15595675086112225077"),
+            MontFp!("5836975862472295314"),
+            MontFp!("1853169328081266881"),
+            MontFp!("12836148871588596568"),
+            MontFp!("15731985806474814876"),
+            MontFp!("6320666304123080912"),
+            MontFp!("3019943795842614033"),
+            MontFp!("9099475684075142071"),
+            MontFp!("14330929933832353468"),
+            MontFp!("10807874726192307928"),
+            MontFp!("12714083393272583450"),
+            MontFp!("8213618377940538408"),
+            MontFp!("12335597015055980975"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_GOLDILOCKS_T20_PARAMS, &input, &expected);
+    }
+}
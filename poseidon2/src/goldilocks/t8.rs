@@ -0,0 +1,204 @@
+//! Poseidon2 implementation for `t=8` over Goldilocks.
+//!
+//! Parameters are compatible with the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 8;
+const D: u64 = 7;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("12216033376705242021"),
+    MontFp!("2072934925475504800"),
+    MontFp!("16432743296706583078"),
+    MontFp!("1287600597097751715"),
+    MontFp!("10482065724875379356"),
+    MontFp!("3057917794534811537"),
+    MontFp!("4460508886913832365"),
+    MontFp!("4574242228824269566"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("15949291268843349465"),
+        MontFp!("14644164809401934923"),
+        MontFp!("18420360874837380316"),
+        MontFp!("4756469047455716334"),
+        MontFp!("8685499049481102115"),
+        MontFp!("3799221349720045367"),
+        MontFp!("13676397835037157930"),
+        MontFp!("6566439050423619635"),
+    ],
+    [
+        MontFp!("17428268347612331188"),
+        MontFp!("2833135872454503769"),
+        MontFp!("4767009016213040191"),
+        MontFp!("2797635963551733652"),
+        MontFp!("5312339450141126694"),
+        MontFp!("5356668452102813289"),
+        MontFp!("1234059326449530173"),
+        MontFp!("7724302552453704877"),
+    ],
+    [
+        MontFp!("14868588146468890290"),
+        MontFp!("12825281145595371185"),
+        MontFp!("13097885453579304196"),
+        MontFp!("7905326782341128063"),
+        MontFp!("14167525334039893569"),
+        MontFp!("2082169701994688927"),
+        MontFp!("12190787523818595537"),
+        MontFp!("12602917751946636"),
+    ],
+    [
+        MontFp!("14890907856876319003"),
+        MontFp!("16552240149997473409"),
+        MontFp!("5634093690795187558"),
+        MontFp!("4883714163685656967"),
+        MontFp!("12440776365164557866"),
+        MontFp!("3923800234666204307"),
+        MontFp!("9858064884105950259"),
+        MontFp!("16040043470428402038"),
+    ],
+    // Second external
+    [
+        MontFp!("94277733998400326"),
+        MontFp!("10891359798487446420"),
+        MontFp!("18280773820738154043"),
+        MontFp!("13714589910668449566"),
+        MontFp!("10639034072771185213"),
+        MontFp!("14148790895768484219"),
+        MontFp!("18341268649720100165"),
+        MontFp!("3096672942770686236"),
+    ],
+    [
+        MontFp!("12277596046563557393"),
+        MontFp!("400461754528604020"),
+        MontFp!("12955488253560265444"),
+        MontFp!("11773677676764285572"),
+        MontFp!("4833837465239476573"),
+        MontFp!("17645852643693996619"),
+        MontFp!("6605134696140007471"),
+        MontFp!("588040525114200273"),
+    ],
+    [
+        MontFp!("11001741536026769411"),
+        MontFp!("17917086578469406776"),
+        MontFp!("14893530806420712543"),
+        MontFp!("727997185253761138"),
+        MontFp!("3443873847340254325"),
+        MontFp!("13095911531247069692"),
+        MontFp!("8330737046680948619"),
+        MontFp!("6014364575875986011"),
+    ],
+    [
+        MontFp!("16851679856681761121"),
+        MontFp!("17817965496543149594"),
+        MontFp!("12823640325246269760"),
+        MontFp!("13685256787930775147"),
+        MontFp!("4682652317564502291"),
+        MontFp!("4233879762155685988"),
+        MontFp!("11097258179564187322"),
+        MontFp!("10804761421745472094"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("5226594323142090582"),
+    MontFp!("1243120476974621208"),
+    MontFp!("12100812801659301173"),
+    MontFp!("11228203327983058121"),
+    MontFp!("13891617888374767564"),
+    MontFp!("5742893160230537107"),
+    MontFp!("3763472116988983643"),
+    MontFp!("2466655769425769160"),
+    MontFp!("6254574254498162968"),
+    MontFp!("14183251225809189357"),
+    MontFp!("11565357354521717084"),
+    MontFp!("17300657704266685688"),
+    MontFp!("310485250821938281"),
+    MontFp!("16853586468012618118"),
+    MontFp!("1978800426240373849"),
+    MontFp!("6948188224235462572"),
+    MontFp!("1486402152218690509"),
+    MontFp!("5669161690283398991"),
+    MontFp!("17943970877073781734"),
+    MontFp!("17926851897715769433"),
+    MontFp!("13052837496695000666"),
+    MontFp!("18138113741095562305"),
+];
+
+/// The Poseidon2 parameters for Goldilocks with a internal state of size t=8.
+pub static POSEIDON2_GOLDILOCKS_T8_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 8-element state over Goldilocks.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::goldilocks::Fp; 8]`).
+///
+/// # Returns
+/// A permuted state as `[crate::goldilocks::Fp; 8]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 8]) -> [Scalar; 8] {
+    POSEIDON2_GOLDILOCKS_T8_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 8-element state over Goldilocks.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::goldilocks::Fp; 8]`).
+pub fn permutation_in_place(state: &mut [Scalar; 8]) {
+    POSEIDON2_GOLDILOCKS_T8_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        goldilocks::t8::POSEIDON2_GOLDILOCKS_T8_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_goldilocks_t8_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_GOLDILOCKS_T8_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t8_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_GOLDILOCKS_T8_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_goldilocks_t8_kat() {
+        // Computed with the Poseidon2 reference implementation (https://github.com/HorizenLabs/poseidon2)
+        let input: [Scalar; 8] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("14266028122062624699"),
+            MontFp!("5353147180106052723"),
+            MontFp!("15203350112844181434"),
+            MontFp!("17630919042639565165"),
+            MontFp!("16601551015858213987"),
+            MontFp!("10184091939013874068"),
+            MontFp!("16774100645754596496"),
+            MontFp!("12047415603622314780"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_GOLDILOCKS_T8_PARAMS, &input, &expected);
+    }
+}
@@ -1,8 +1,10 @@
 //! Poseidon2 permutation methods, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
 //!
-//! This crate provides efficient, pure-Rust, minimal APIs to compute the Poseidon2 permutation (not hash) on all supported state sizes (`t2`, `t3`, `t4`, `t8`, `t12`, `t16`).
+//! This crate provides efficient, pure-Rust, minimal APIs to compute the Poseidon2 permutation (not hash) on all supported state sizes (`t2`, `t3`, `t4`, `t8`, `t12`, `t16`, `t20`, `t24`).
 //!
-//! Currently, the only supported field is the scalar field of `bn254`.
+//! Each state size is behind a feature of the same name. The default `full` feature enables `bn254` with the state sizes `t2` to `t16`, while `t20` and `t24` are opt-in.
+//!
+//! The main supported field is the scalar field of `bn254`. Additionally, the opt-in modules `bls12_381`, `goldilocks`, `babybear` and `m31` provide the standard Poseidon2 instances over these fields.
 //!
 //! Parameters are compatible with the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
 //!
@@ -14,8 +16,72 @@
 //! poseidon2::bn254::t4::permutation_in_place(&mut state);
 //! ```
 //!
+//! To permute many states at once (e.g., the leaves of a Merkle tree), every state size provides a `permutation_batch` function, which runs in parallel with the `parallel` feature. Without this feature, it is not faster than a loop over the states.
+//! The inverse permutation is available as `permutation_inverse`, e.g., for debugging circuits or for encryption modes that need to decrypt with the inverse.
+//! For witness generation in custom provers, [`Poseidon2Permutation::permutation_with_trace`] returns all intermediate states and S-box inputs of a permutation as a [`Poseidon2Trace`].
+//!
+//! The generic [`Poseidon2Permutation`] is public as well, so custom parameter sets (other fields, state sizes or round numbers) can be used with the same implementation.
+//! The built-in parameter sets are exposed as `POSEIDON2_BN254_T*_PARAMS` statics in the respective modules.
+//! New parameter sets can be generated with the `params` module (requires the `std` feature), which ports the reference parameter generation script.
+//!
 //! This crate is suitable for cryptographic circuits, SNARKs, and low-level integrations requiring only the permutation (not hashing).
+//! For hashing, the [`sponge`] module provides a sponge construction with configurable rate and capacity on top of the permutation, following the SAFE API.
+//! For commitments, the `merkle` module provides dense and sparse Merkle trees with inclusion proofs, built on the `bn254` compression functions.
+//! For encrypting field elements, the [`aead`] module provides an authenticated encryption scheme based on a duplex sponge, whose decryption can be proven in a circuit.
+//!
+//! With the `poseidon1` feature, the `poseidon1` module provides the original, circomlib-compatible Poseidon hash over `bn254`, to interoperate with existing deployments.
+//!
+//! With the `r1cs` feature, the `constraints` module provides R1CS gadgets for the permutation on all supported state sizes, using the same round constants as the native implementation.
+//!
+//! The crate supports `no_std` environments with `alloc` when the default `std` feature is disabled. The `parallel` feature and the `params` module require `std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+pub mod aead;
+#[cfg(feature = "babybear")]
+pub mod babybear;
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381;
 #[cfg(feature = "bn254")]
 pub mod bn254;
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+#[cfg(feature = "goldilocks")]
+pub mod goldilocks;
+#[cfg(feature = "m31")]
+pub mod m31;
+#[cfg(all(feature = "bn254", any(feature = "t3", feature = "t8")))]
+pub mod merkle;
+#[cfg(feature = "std")]
+pub mod params;
 mod perm;
+#[cfg(feature = "poseidon1")]
+pub mod poseidon1;
+pub mod sponge;
+#[cfg(all(
+    test,
+    any(
+        all(
+            feature = "bn254",
+            any(
+                feature = "t2",
+                feature = "t3",
+                feature = "t4",
+                feature = "t8",
+                feature = "t12",
+                feature = "t16",
+                feature = "t20",
+                feature = "t24"
+            )
+        ),
+        feature = "bls12_381",
+        feature = "goldilocks",
+        feature = "babybear",
+        feature = "m31"
+    )
+))]
+mod test;
+
+pub use perm::{Poseidon2Permutation, Poseidon2Trace};
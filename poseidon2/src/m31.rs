@@ -0,0 +1,34 @@
+//! Poseidon2 permutation methods for the Mersenne-31 field `p = 2^31 - 1`, based on [eprint.iacr.org/2023/323](https://eprint.iacr.org/2023/323).
+//!
+//! This module provides the Poseidon2 permutation on the state sizes used by Plonky3 (`t16`, `t24`).
+//!
+//! The S-box for Mersenne-31 is defined as
+//! $$ x^5 $$
+//! since 5 is the smallest exponent coprime to `p - 1`.
+//!
+//! Parameters are compatible with the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
+//! The reference implementation does not provide instances for Mersenne-31, so the parameters were generated with `params::Poseidon2Params`, which ports this script.
+//! Only the state sizes and round numbers match Plonky3. Plonky3 samples its round constants differently, so its outputs are not compatible with this module.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let mut state = [...];
+//! poseidon2::m31::t16::permutation(&state);
+//! poseidon2::m31::t16::permutation_in_place(&mut state);
+//! ```
+
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+/// The Montgomery configuration of [`Fp`].
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+#[derive(MontConfig)]
+#[modulus = "2147483647"]
+#[generator = "7"]
+pub struct M31Config;
+
+/// The Mersenne-31 field `p = 2^31 - 1`.
+pub type Fp = Fp64<MontBackend<M31Config, 1>>;
+
+pub mod t16;
+pub mod t24;
@@ -0,0 +1,276 @@
+//! Poseidon2 implementation for `t=16` over Mersenne-31.
+//!
+//! Parameters were generated with `params::Poseidon2Params`, which ports the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 16;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 14;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("129501892"),
+    MontFp!("1809435443"),
+    MontFp!("1223573407"),
+    MontFp!("1331944729"),
+    MontFp!("415581875"),
+    MontFp!("1526242955"),
+    MontFp!("1341275624"),
+    MontFp!("1333308150"),
+    MontFp!("1404946132"),
+    MontFp!("1549369918"),
+    MontFp!("709303410"),
+    MontFp!("1284988537"),
+    MontFp!("1490838740"),
+    MontFp!("115945821"),
+    MontFp!("754131590"),
+    MontFp!("800486749"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("1988864850"),
+        MontFp!("1893772157"),
+        MontFp!("1025928330"),
+        MontFp!("1839472709"),
+        MontFp!("1611656994"),
+        MontFp!("1104858731"),
+        MontFp!("1694088660"),
+        MontFp!("1564660990"),
+        MontFp!("1991332205"),
+        MontFp!("1875486487"),
+        MontFp!("1890340790"),
+        MontFp!("1658614"),
+        MontFp!("582370530"),
+        MontFp!("528029397"),
+        MontFp!("1196956642"),
+        MontFp!("655401251"),
+    ],
+    [
+        MontFp!("1652877415"),
+        MontFp!("26032894"),
+        MontFp!("1576640243"),
+        MontFp!("1277052539"),
+        MontFp!("1450142396"),
+        MontFp!("697623591"),
+        MontFp!("1401580866"),
+        MontFp!("1568404175"),
+        MontFp!("2145004971"),
+        MontFp!("265835716"),
+        MontFp!("1183985610"),
+        MontFp!("1031234465"),
+        MontFp!("436012490"),
+        MontFp!("172735299"),
+        MontFp!("352802897"),
+        MontFp!("1032863094"),
+    ],
+    [
+        MontFp!("757665783"),
+        MontFp!("1082171296"),
+        MontFp!("1507509996"),
+        MontFp!("309929890"),
+        MontFp!("1807683232"),
+        MontFp!("43258895"),
+        MontFp!("611592566"),
+        MontFp!("1854193793"),
+        MontFp!("575164234"),
+        MontFp!("894217817"),
+        MontFp!("72613857"),
+        MontFp!("1061659596"),
+        MontFp!("8921166"),
+        MontFp!("1617355017"),
+        MontFp!("998001536"),
+        MontFp!("1800758877"),
+    ],
+    [
+        MontFp!("1002748055"),
+        MontFp!("1935405944"),
+        MontFp!("1351462722"),
+        MontFp!("411368491"),
+        MontFp!("1913975372"),
+        MontFp!("1956167178"),
+        MontFp!("442558016"),
+        MontFp!("855898408"),
+        MontFp!("699687798"),
+        MontFp!("1553382248"),
+        MontFp!("1708169125"),
+        MontFp!("490049183"),
+        MontFp!("1251643415"),
+        MontFp!("1193594742"),
+        MontFp!("880473871"),
+        MontFp!("511174042"),
+    ],
+    // Second external
+    [
+        MontFp!("1460209171"),
+        MontFp!("530850056"),
+        MontFp!("398192464"),
+        MontFp!("536338716"),
+        MontFp!("75179210"),
+        MontFp!("1309934197"),
+        MontFp!("1335920373"),
+        MontFp!("127611036"),
+        MontFp!("291093831"),
+        MontFp!("1832379621"),
+        MontFp!("123571662"),
+        MontFp!("303176864"),
+        MontFp!("2137685056"),
+        MontFp!("1759609530"),
+        MontFp!("1418928155"),
+        MontFp!("71608334"),
+    ],
+    [
+        MontFp!("6616262"),
+        MontFp!("1684515814"),
+        MontFp!("1721194338"),
+        MontFp!("720801691"),
+        MontFp!("878392254"),
+        MontFp!("460379263"),
+        MontFp!("87930647"),
+        MontFp!("940673483"),
+        MontFp!("1136203256"),
+        MontFp!("551499412"),
+        MontFp!("256220454"),
+        MontFp!("2007034235"),
+        MontFp!("796124985"),
+        MontFp!("410436345"),
+        MontFp!("1705042586"),
+        MontFp!("1286336446"),
+    ],
+    [
+        MontFp!("1522340456"),
+        MontFp!("1295296352"),
+        MontFp!("309794713"),
+        MontFp!("1772145068"),
+        MontFp!("956898901"),
+        MontFp!("2137070800"),
+        MontFp!("988829146"),
+        MontFp!("2059451359"),
+        MontFp!("1846491684"),
+        MontFp!("1105442551"),
+        MontFp!("1236497773"),
+        MontFp!("1452000568"),
+        MontFp!("549485016"),
+        MontFp!("385992492"),
+        MontFp!("1987107948"),
+        MontFp!("1514377269"),
+    ],
+    [
+        MontFp!("2090065934"),
+        MontFp!("1444920141"),
+        MontFp!("293113979"),
+        MontFp!("41120774"),
+        MontFp!("855319793"),
+        MontFp!("1663284746"),
+        MontFp!("1789994008"),
+        MontFp!("1120509162"),
+        MontFp!("358222743"),
+        MontFp!("1406256810"),
+        MontFp!("735183687"),
+        MontFp!("664485235"),
+        MontFp!("1331641456"),
+        MontFp!("38121324"),
+        MontFp!("595810771"),
+        MontFp!("1234594393"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("2139014335"),
+    MontFp!("69309039"),
+    MontFp!("1368974953"),
+    MontFp!("886780232"),
+    MontFp!("1130937085"),
+    MontFp!("1718115455"),
+    MontFp!("2027103386"),
+    MontFp!("1612216449"),
+    MontFp!("1994053242"),
+    MontFp!("110146615"),
+    MontFp!("514413329"),
+    MontFp!("1088763546"),
+    MontFp!("955319292"),
+    MontFp!("488794657"),
+];
+
+/// The Poseidon2 parameters for Mersenne-31 with a internal state of size t=16.
+pub static POSEIDON2_M31_T16_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 16-element state over Mersenne-31.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::m31::Fp; 16]`).
+///
+/// # Returns
+/// A permuted state as `[crate::m31::Fp; 16]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 16]) -> [Scalar; 16] {
+    POSEIDON2_M31_T16_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 16-element state over Mersenne-31.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::m31::Fp; 16]`).
+pub fn permutation_in_place(state: &mut [Scalar; 16]) {
+    POSEIDON2_M31_T16_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        m31::t16::POSEIDON2_M31_T16_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_m31_t16_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_M31_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_m31_t16_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_M31_T16_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_m31_t16_kat() {
+        // The reference implementation has no Mersenne-31 instances, so this KAT guards against regressions only.
+        let input: [Scalar; 16] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("1348310665"),
+            MontFp!("996460804"),
+            MontFp!("2044919169"),
+            MontFp!("1269301599"),
+            MontFp!("615961333"),
+            MontFp!("595876573"),
+            MontFp!("1377780500"),
+            MontFp!("1776267289"),
+            MontFp!("715842585"),
+            MontFp!("1823756332"),
+            MontFp!("1870636634"),
+            MontFp!("1979645732"),
+            MontFp!("311256455"),
+            MontFp!("1364752356"),
+            MontFp!("58674647"),
+            MontFp!("323699327"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_M31_T16_PARAMS, &input, &expected);
+    }
+}
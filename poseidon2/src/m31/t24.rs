@@ -0,0 +1,364 @@
+//! Poseidon2 implementation for `t=24` over Mersenne-31.
+//!
+//! Parameters were generated with `params::Poseidon2Params`, which ports the original Poseidon2 parameter generation script found at:
+//! [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+use ark_ff::MontFp;
+
+use crate::perm::Poseidon2Permutation;
+
+type Scalar = super::Fp;
+
+const T: usize = 24;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+const MAT_DIAG_M_1: [Scalar; T] = [
+    MontFp!("215477482"),
+    MontFp!("122291998"),
+    MontFp!("1722461950"),
+    MontFp!("473123685"),
+    MontFp!("1292455088"),
+    MontFp!("797276585"),
+    MontFp!("654919449"),
+    MontFp!("1895863245"),
+    MontFp!("1406416300"),
+    MontFp!("1929814287"),
+    MontFp!("1465288965"),
+    MontFp!("1231542495"),
+    MontFp!("2138333822"),
+    MontFp!("109188185"),
+    MontFp!("1200452900"),
+    MontFp!("514638376"),
+    MontFp!("1696534848"),
+    MontFp!("1123260399"),
+    MontFp!("618961622"),
+    MontFp!("1554208511"),
+    MontFp!("1123221737"),
+    MontFp!("802164868"),
+    MontFp!("682124419"),
+    MontFp!("272892596"),
+];
+const EXTERNAL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First external
+    [
+        MontFp!("535476833"),
+        MontFp!("1394754644"),
+        MontFp!("1808710114"),
+        MontFp!("1343879604"),
+        MontFp!("1215456658"),
+        MontFp!("723701928"),
+        MontFp!("1632746489"),
+        MontFp!("793201441"),
+        MontFp!("783676215"),
+        MontFp!("996433287"),
+        MontFp!("155373296"),
+        MontFp!("1696762962"),
+        MontFp!("2030023439"),
+        MontFp!("1294481352"),
+        MontFp!("236432463"),
+        MontFp!("89417350"),
+        MontFp!("74188492"),
+        MontFp!("189907894"),
+        MontFp!("1452128099"),
+        MontFp!("951268580"),
+        MontFp!("1475718384"),
+        MontFp!("433268241"),
+        MontFp!("2010132299"),
+        MontFp!("1820518840"),
+    ],
+    [
+        MontFp!("1900593590"),
+        MontFp!("134642805"),
+        MontFp!("1188161737"),
+        MontFp!("815217415"),
+        MontFp!("543248187"),
+        MontFp!("1547696209"),
+        MontFp!("810562345"),
+        MontFp!("1232267212"),
+        MontFp!("47306940"),
+        MontFp!("165003174"),
+        MontFp!("158401728"),
+        MontFp!("2074384948"),
+        MontFp!("1242022652"),
+        MontFp!("616689257"),
+        MontFp!("682011457"),
+        MontFp!("1569786016"),
+        MontFp!("980963783"),
+        MontFp!("500471289"),
+        MontFp!("313827316"),
+        MontFp!("2074957664"),
+        MontFp!("1609393346"),
+        MontFp!("1727383916"),
+        MontFp!("2125446402"),
+        MontFp!("968055099"),
+    ],
+    [
+        MontFp!("1248148297"),
+        MontFp!("1963531713"),
+        MontFp!("1457817824"),
+        MontFp!("386179925"),
+        MontFp!("1952337907"),
+        MontFp!("959377774"),
+        MontFp!("836976607"),
+        MontFp!("46304358"),
+        MontFp!("918751290"),
+        MontFp!("822399482"),
+        MontFp!("1526975907"),
+        MontFp!("1715593647"),
+        MontFp!("930433878"),
+        MontFp!("963599097"),
+        MontFp!("437929498"),
+        MontFp!("1754146546"),
+        MontFp!("1870829444"),
+        MontFp!("465116521"),
+        MontFp!("1534623766"),
+        MontFp!("1883940293"),
+        MontFp!("25265820"),
+        MontFp!("1092689494"),
+        MontFp!("216531872"),
+        MontFp!("599782304"),
+    ],
+    [
+        MontFp!("402354276"),
+        MontFp!("1194422816"),
+        MontFp!("1989233491"),
+        MontFp!("563878149"),
+        MontFp!("375739688"),
+        MontFp!("1307792708"),
+        MontFp!("1619035237"),
+        MontFp!("860944435"),
+        MontFp!("62705095"),
+        MontFp!("176625674"),
+        MontFp!("24812021"),
+        MontFp!("1855215398"),
+        MontFp!("356799748"),
+        MontFp!("2013606833"),
+        MontFp!("999407416"),
+        MontFp!("1070038705"),
+        MontFp!("1263375886"),
+        MontFp!("1020869934"),
+        MontFp!("245688694"),
+        MontFp!("491980492"),
+        MontFp!("981123716"),
+        MontFp!("247605556"),
+        MontFp!("1301780243"),
+        MontFp!("1369709669"),
+    ],
+    // Second external
+    [
+        MontFp!("476455577"),
+        MontFp!("92050778"),
+        MontFp!("2058797298"),
+        MontFp!("631680266"),
+        MontFp!("1971653464"),
+        MontFp!("1224554387"),
+        MontFp!("188966577"),
+        MontFp!("266145097"),
+        MontFp!("2108955129"),
+        MontFp!("1236913167"),
+        MontFp!("1217697558"),
+        MontFp!("700668048"),
+        MontFp!("1984189573"),
+        MontFp!("472371980"),
+        MontFp!("279607867"),
+        MontFp!("821252022"),
+        MontFp!("1876976693"),
+        MontFp!("34823885"),
+        MontFp!("1823128214"),
+        MontFp!("1051889872"),
+        MontFp!("587042107"),
+        MontFp!("1988826405"),
+        MontFp!("1542019092"),
+        MontFp!("1379762153"),
+    ],
+    [
+        MontFp!("1441352940"),
+        MontFp!("1204039199"),
+        MontFp!("447036254"),
+        MontFp!("802291326"),
+        MontFp!("1495469713"),
+        MontFp!("1953684070"),
+        MontFp!("1682174687"),
+        MontFp!("765321629"),
+        MontFp!("1870621316"),
+        MontFp!("2004878384"),
+        MontFp!("251743176"),
+        MontFp!("2079576213"),
+        MontFp!("649057048"),
+        MontFp!("1478844919"),
+        MontFp!("1591941516"),
+        MontFp!("1791754240"),
+        MontFp!("791764518"),
+        MontFp!("667598912"),
+        MontFp!("630523934"),
+        MontFp!("1499707354"),
+        MontFp!("2114401637"),
+        MontFp!("1827927537"),
+        MontFp!("167289764"),
+        MontFp!("229166334"),
+    ],
+    [
+        MontFp!("1623073637"),
+        MontFp!("2128036597"),
+        MontFp!("1720418353"),
+        MontFp!("1269838996"),
+        MontFp!("1493275443"),
+        MontFp!("1879976056"),
+        MontFp!("482629718"),
+        MontFp!("487987191"),
+        MontFp!("2073595302"),
+        MontFp!("1320457068"),
+        MontFp!("2026502270"),
+        MontFp!("1674460820"),
+        MontFp!("1903703067"),
+        MontFp!("1169960838"),
+        MontFp!("95909308"),
+        MontFp!("1883963972"),
+        MontFp!("703517304"),
+        MontFp!("1207185458"),
+        MontFp!("556249829"),
+        MontFp!("429363263"),
+        MontFp!("615763459"),
+        MontFp!("204614028"),
+        MontFp!("2099998522"),
+        MontFp!("402434689"),
+    ],
+    [
+        MontFp!("1928560623"),
+        MontFp!("807987113"),
+        MontFp!("1591561622"),
+        MontFp!("529946683"),
+        MontFp!("1289130378"),
+        MontFp!("344461070"),
+        MontFp!("1763115865"),
+        MontFp!("389856563"),
+        MontFp!("157935604"),
+        MontFp!("1853825963"),
+        MontFp!("1635969671"),
+        MontFp!("1305157468"),
+        MontFp!("1482662163"),
+        MontFp!("1922872246"),
+        MontFp!("28549754"),
+        MontFp!("463565799"),
+        MontFp!("1261681898"),
+        MontFp!("1675180987"),
+        MontFp!("1799339929"),
+        MontFp!("1863838161"),
+        MontFp!("205379770"),
+        MontFp!("197261736"),
+        MontFp!("1691612016"),
+        MontFp!("1180330520"),
+    ],
+];
+const INTERNAL_RC: [Scalar; ROUNDS_P] = [
+    MontFp!("578251281"),
+    MontFp!("1604534888"),
+    MontFp!("336941709"),
+    MontFp!("1447017748"),
+    MontFp!("888427076"),
+    MontFp!("302948790"),
+    MontFp!("638806181"),
+    MontFp!("661020353"),
+    MontFp!("918431749"),
+    MontFp!("878462852"),
+    MontFp!("401595585"),
+    MontFp!("960390448"),
+    MontFp!("1839248568"),
+    MontFp!("837148371"),
+    MontFp!("1734027180"),
+    MontFp!("183012400"),
+    MontFp!("1467498956"),
+    MontFp!("1608543724"),
+    MontFp!("1454401818"),
+    MontFp!("1916984863"),
+    MontFp!("1010267433"),
+    MontFp!("865826083"),
+];
+
+/// The Poseidon2 parameters for Mersenne-31 with a internal state of size t=24.
+pub static POSEIDON2_M31_T24_PARAMS: Poseidon2Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon2Permutation::new(MAT_DIAG_M_1, EXTERNAL_RC, INTERNAL_RC);
+
+/// Applies the Poseidon2 permutation to a 24-element state over Mersenne-31.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[crate::m31::Fp; 24]`).
+///
+/// # Returns
+/// A permuted state as `[crate::m31::Fp; 24]`.
+#[must_use]
+pub fn permutation(state: &[Scalar; 24]) -> [Scalar; 24] {
+    POSEIDON2_M31_T24_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon2 permutation in place to a 24-element state over Mersenne-31.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[crate::m31::Fp; 24]`).
+pub fn permutation_in_place(state: &mut [Scalar; 24]) {
+    POSEIDON2_M31_T24_PARAMS.permutation_in_place(state);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Scalar;
+    use crate::{
+        m31::t24::POSEIDON2_M31_T24_PARAMS,
+        test::{TESTRUNS, poseidon2_consistent_perm, poseidon2_inverse_perm, poseidon2_kat},
+    };
+    use ark_ff::MontFp;
+
+    #[test]
+    fn poseidon2_m31_t24_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_M31_T24_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_m31_t24_inverse() {
+        for _ in 0..TESTRUNS {
+            poseidon2_inverse_perm(&POSEIDON2_M31_T24_PARAMS);
+        }
+    }
+
+    #[test]
+    fn poseidon2_m31_t24_kat() {
+        // The reference implementation has no Mersenne-31 instances, so this KAT guards against regressions only.
+        let input: [Scalar; 24] = std::array::from_fn(|i| Scalar::from(i as u64));
+        let expected = [
+            MontFp!("813042329"),
+            MontFp!("956159494"),
+            MontFp!("2017691352"),
+            MontFp!("906353481"),
+            MontFp!("1909737181"),
+            MontFp!("1568930368"),
+            MontFp!("1051192156"),
+            MontFp!("1915448194"),
+            MontFp!("114779228"),
+            MontFp!("1695016063"),
+            MontFp!("56353577"),
+            MontFp!("991257558"),
+            MontFp!("1283398606"),
+            MontFp!("1782986529"),
+            MontFp!("89100699"),
+            MontFp!("1011002020"),
+            MontFp!("71058136"),
+            MontFp!("1382771657"),
+            MontFp!("1734747710"),
+            MontFp!("184579357"),
+            MontFp!("1201113333"),
+            MontFp!("2002016011"),
+            MontFp!("1347833245"),
+            MontFp!("1026595486"),
+        ];
+
+        poseidon2_kat(&POSEIDON2_M31_T24_PARAMS, &input, &expected);
+    }
+}
@@ -0,0 +1,662 @@
+//! Merkle trees over the `bn254` scalar field, using the Poseidon2 compression functions of the [`bn254`](crate::bn254) module.
+//!
+//! Two tree types are provided:
+//! * [`DenseMerkleTree`] stores all nodes of a tree of fixed depth and is filled from left to right. It is suited for trees where most leaves are used.
+//! * [`SparseMerkleTree`] only stores the non-empty nodes and allows setting leaves at arbitrary indices. It is suited for large, mostly empty trees, e.g., nullifier sets.
+//!
+//! Both trees use the same node hashing, so they compute the same root for the same leaves, and produce [`MerkleProof`]s which can be checked with [`verify`].
+//!
+//! Leaves and inner nodes are hashed with the same compression function. An inner node together with a proof that is shorter by the levels below it would thus imply the same root as a leaf.
+//! [`verify`] therefore takes the depth of the tree and rejects proofs of any other length, which ties the leaf to level 0.
+//!
+//! The arity of the tree is selected with a type parameter implementing [`MerkleArity`]:
+//! * `Binary` (requires the `t3` feature) hashes two children with `bn254::compress2`,
+//! * `Quaternary` (requires the `t8` feature) hashes four children with `bn254::compress4`.
+//!
+//! Empty leaves have the value zero, the root of an empty subtree is the hash of its (empty) children.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use poseidon2::merkle::{Binary, DenseMerkleTree};
+//!
+//! let mut tree = DenseMerkleTree::<Binary>::new(20)?;
+//! let index = tree.insert(leaf)?;
+//! let proof = tree.prove(index)?;
+//! assert!(poseidon2::merkle::verify(tree.root(), tree.depth(), leaf, &proof));
+//! ```
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use ark_ff::Zero;
+
+type Scalar = ark_bn254::Fr;
+
+/// The arity of a Merkle tree, i.e., the number of children of each inner node.
+///
+/// This trait is sealed and implemented by `Binary` and `Quaternary`, depending on the enabled features.
+pub trait MerkleArity: private::Sealed + Copy + Eq + core::fmt::Debug {
+    /// The number of children of each inner node.
+    const ARITY: usize;
+
+    /// Computes the parent node from exactly [`Self::ARITY`] children.
+    fn compress(children: &[Scalar]) -> Scalar;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Marker type for binary Merkle trees, using [`compress2`](crate::bn254::compress2).
+#[cfg(feature = "t3")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+pub struct Binary;
+
+#[cfg(feature = "t3")]
+impl private::Sealed for Binary {}
+
+#[cfg(feature = "t3")]
+impl MerkleArity for Binary {
+    const ARITY: usize = 2;
+
+    fn compress(children: &[Scalar]) -> Scalar {
+        crate::bn254::compress2(children[0], children[1])
+    }
+}
+
+/// Marker type for Merkle trees of arity 4, using [`compress4`](crate::bn254::compress4).
+#[cfg(feature = "t8")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs, reason = "Unit marker type")]
+pub struct Quaternary;
+
+#[cfg(feature = "t8")]
+impl private::Sealed for Quaternary {}
+
+#[cfg(feature = "t8")]
+impl MerkleArity for Quaternary {
+    const ARITY: usize = 4;
+
+    fn compress(children: &[Scalar]) -> Scalar {
+        crate::bn254::compress4(children.try_into().expect("exactly 4 children"))
+    }
+}
+
+/// The errors that can occur when using a Merkle tree.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum MerkleError {
+    /// The number of leaves of a tree with the given depth does not fit into a `u64`, the depth is zero, or the nodes of a [`DenseMerkleTree`] cannot be allocated.
+    #[error("invalid tree depth {0}")]
+    InvalidDepth(usize),
+    /// The index is larger than the number of leaves of the tree.
+    #[error("leaf index {0} out of range")]
+    IndexOutOfRange(u64),
+    /// All leaves of the dense tree are already occupied.
+    #[error("tree is full")]
+    TreeFull,
+}
+
+/// An inclusion proof for a leaf in a Merkle tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<A: MerkleArity> {
+    index: u64,
+    siblings: Vec<Vec<Scalar>>,
+    arity: PhantomData<A>,
+}
+
+impl<A: MerkleArity> MerkleProof<A> {
+    /// Creates a proof from the leaf index and the siblings on the path from the leaf to the root.
+    ///
+    /// `siblings[i]` contains the `A::ARITY - 1` siblings on level `i` (where level 0 are the leaves) in their order in the tree, i.e., without the node on the path.
+    #[must_use]
+    pub fn new(index: u64, siblings: Vec<Vec<Scalar>>) -> Self {
+        Self {
+            index,
+            siblings,
+            arity: PhantomData,
+        }
+    }
+
+    /// The index of the leaf.
+    #[must_use]
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// The siblings on the path from the leaf to the root, see [`Self::new`].
+    #[must_use]
+    pub fn siblings(&self) -> &[Vec<Scalar>] {
+        &self.siblings
+    }
+
+    /// The depth of the tree implied by this proof, i.e., the number of levels of siblings.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Computes the root implied by this proof and the given leaf.
+    ///
+    /// The depth is taken from the proof, so the result is only meaningful for a tree of depth [`Self::depth`]. Use [`verify`] to check a proof against a tree of known depth.
+    ///
+    /// Returns `None` if the proof is malformed.
+    #[must_use]
+    pub fn compute_root(&self, leaf: Scalar) -> Option<Scalar> {
+        let depth = u32::try_from(self.siblings.len()).ok()?;
+        let arity = A::ARITY as u64;
+        if self.index >= arity.checked_pow(depth)? {
+            return None;
+        }
+        let mut index = self.index;
+        let mut node = leaf;
+        let mut children = Vec::with_capacity(A::ARITY);
+        for siblings in &self.siblings {
+            if siblings.len() != A::ARITY - 1 {
+                return None;
+            }
+            #[allow(clippy::cast_possible_truncation, reason = "index % arity < arity")]
+            let position = (index % arity) as usize;
+            children.clear();
+            children.extend_from_slice(&siblings[..position]);
+            children.push(node);
+            children.extend_from_slice(&siblings[position..]);
+            node = A::compress(&children);
+            index /= arity;
+        }
+        Some(node)
+    }
+}
+
+/// Verifies that `leaf` is included in the tree of the given `depth` with the given `root`, at the index stored in the proof.
+///
+/// Proofs with a different depth are rejected, since an inner node with a shortened proof would otherwise verify as a leaf, see the [module documentation](self).
+#[must_use]
+pub fn verify<A: MerkleArity>(
+    root: Scalar,
+    depth: usize,
+    leaf: Scalar,
+    proof: &MerkleProof<A>,
+) -> bool {
+    proof.depth() == depth && proof.compute_root(leaf) == Some(root)
+}
+
+/// Returns the roots of empty subtrees for all levels `0..=depth`.
+fn empty_roots<A: MerkleArity>(depth: usize) -> Vec<Scalar> {
+    let mut roots = Vec::with_capacity(depth + 1);
+    roots.push(Scalar::zero());
+    for level in 0..depth {
+        roots.push(A::compress(&vec![roots[level]; A::ARITY]));
+    }
+    roots
+}
+
+/// Returns the number of leaves of a tree with the given depth, checking that it fits into a `u64`.
+fn num_leaves<A: MerkleArity>(depth: usize) -> Result<u64, MerkleError> {
+    if depth == 0 {
+        return Err(MerkleError::InvalidDepth(depth));
+    }
+    u32::try_from(depth)
+        .ok()
+        .and_then(|d| (A::ARITY as u64).checked_pow(d))
+        .ok_or(MerkleError::InvalidDepth(depth))
+}
+
+/// A Merkle tree of fixed depth which stores all nodes, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DenseMerkleTree<A: MerkleArity> {
+    /// All nodes of the tree by level, `levels[0]` are the leaves and `levels[depth]` only contains the root.
+    levels: Vec<Vec<Scalar>>,
+    /// The number of inserted leaves.
+    next_index: usize,
+    arity: PhantomData<A>,
+}
+
+impl<A: MerkleArity> DenseMerkleTree<A> {
+    /// Creates an empty tree with `A::ARITY^depth` leaves.
+    ///
+    /// Since all nodes are stored, the depth should be chosen accordingly.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is zero or the nodes of the tree cannot be allocated.
+    /// Note that with memory overcommit (the default on Linux), an allocation which is larger than the available memory may still succeed and abort the process later.
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        let mut level_size = usize::try_from(num_leaves::<A>(depth)?)
+            .map_err(|_| MerkleError::InvalidDepth(depth))?;
+        let mut levels = Vec::with_capacity(depth + 1);
+        for empty in empty_roots::<A>(depth) {
+            let mut level = Vec::new();
+            level
+                .try_reserve_exact(level_size)
+                .map_err(|_| MerkleError::InvalidDepth(depth))?;
+            level.resize(level_size, empty);
+            levels.push(level);
+            level_size /= A::ARITY;
+        }
+        Ok(Self {
+            levels,
+            next_index: 0,
+            arity: PhantomData,
+        })
+    }
+
+    /// Creates a tree with the given leaves, all remaining leaves are empty.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is invalid or there are more leaves than fit into the tree.
+    pub fn from_leaves(depth: usize, leaves: &[Scalar]) -> Result<Self, MerkleError> {
+        let mut tree = Self::new(depth)?;
+        if leaves.len() > tree.levels[0].len() {
+            return Err(MerkleError::TreeFull);
+        }
+        tree.levels[0][..leaves.len()].copy_from_slice(leaves);
+        tree.next_index = leaves.len();
+        for level in 1..tree.levels.len() {
+            let (lower, upper) = tree.levels.split_at_mut(level);
+            for (parent, children) in upper[0]
+                .iter_mut()
+                .zip(lower[level - 1].chunks_exact(A::ARITY))
+            {
+                *parent = A::compress(children);
+            }
+        }
+        Ok(tree)
+    }
+
+    /// The depth of the tree.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// The number of inserted leaves, which is also the index of the next inserted leaf.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.next_index
+    }
+
+    /// Returns `true` if no leaf was inserted yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.next_index == 0
+    }
+
+    /// The root of the tree.
+    #[must_use]
+    pub fn root(&self) -> Scalar {
+        self.levels[self.depth()][0]
+    }
+
+    /// The leaf at the given index, which is zero for empty leaves.
+    #[must_use]
+    pub fn leaf(&self, index: usize) -> Option<Scalar> {
+        self.levels[0].get(index).copied()
+    }
+
+    /// Inserts the leaf at the next free index and returns this index.
+    ///
+    /// # Errors
+    /// Returns an error if the tree is full.
+    pub fn insert(&mut self, leaf: Scalar) -> Result<usize, MerkleError> {
+        let index = self.next_index;
+        if index >= self.levels[0].len() {
+            return Err(MerkleError::TreeFull);
+        }
+        self.set(index, leaf)?;
+        self.next_index += 1;
+        Ok(index)
+    }
+
+    /// Overwrites the leaf at the given index, which must have been inserted before.
+    ///
+    /// # Errors
+    /// Returns an error if the index is not smaller than [`Self::len`].
+    pub fn update(&mut self, index: usize, leaf: Scalar) -> Result<(), MerkleError> {
+        if index >= self.next_index {
+            return Err(MerkleError::IndexOutOfRange(index as u64));
+        }
+        self.set(index, leaf)
+    }
+
+    fn set(&mut self, index: usize, leaf: Scalar) -> Result<(), MerkleError> {
+        let leaves = &mut self.levels[0];
+        *leaves
+            .get_mut(index)
+            .ok_or(MerkleError::IndexOutOfRange(index as u64))? = leaf;
+        let mut index = index;
+        for level in 1..self.levels.len() {
+            let start = index - index % A::ARITY;
+            let parent = A::compress(&self.levels[level - 1][start..start + A::ARITY]);
+            index /= A::ARITY;
+            self.levels[level][index] = parent;
+        }
+        Ok(())
+    }
+
+    /// Creates an inclusion proof for the leaf at the given index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is larger than the number of leaves of the tree.
+    pub fn prove(&self, index: usize) -> Result<MerkleProof<A>, MerkleError> {
+        if index >= self.levels[0].len() {
+            return Err(MerkleError::IndexOutOfRange(index as u64));
+        }
+        let mut position = index;
+        let siblings = self.levels[..self.depth()]
+            .iter()
+            .map(|nodes| {
+                let start = position - position % A::ARITY;
+                let siblings = (start..start + A::ARITY)
+                    .filter(|i| *i != position)
+                    .map(|i| nodes[i])
+                    .collect();
+                position /= A::ARITY;
+                siblings
+            })
+            .collect();
+        Ok(MerkleProof::new(index as u64, siblings))
+    }
+}
+
+/// A Merkle tree of fixed depth which only stores non-empty nodes, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<A: MerkleArity> {
+    depth: usize,
+    num_leaves: u64,
+    /// The non-empty nodes, indexed by level and index within the level.
+    nodes: BTreeMap<(usize, u64), Scalar>,
+    /// The roots of empty subtrees by level.
+    empty_roots: Vec<Scalar>,
+    arity: PhantomData<A>,
+}
+
+impl<A: MerkleArity> SparseMerkleTree<A> {
+    /// Creates an empty tree with `A::ARITY^depth` leaves.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is zero or the number of leaves does not fit into a `u64`.
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        Ok(Self {
+            depth,
+            num_leaves: num_leaves::<A>(depth)?,
+            nodes: BTreeMap::new(),
+            empty_roots: empty_roots::<A>(depth),
+            arity: PhantomData,
+        })
+    }
+
+    /// The depth of the tree.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The root of the tree.
+    #[must_use]
+    pub fn root(&self) -> Scalar {
+        self.node(self.depth, 0)
+    }
+
+    /// The leaf at the given index, which is zero for empty leaves.
+    #[must_use]
+    pub fn leaf(&self, index: u64) -> Option<Scalar> {
+        (index < self.num_leaves).then(|| self.node(0, index))
+    }
+
+    fn node(&self, level: usize, index: u64) -> Scalar {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.empty_roots[level])
+    }
+
+    /// Sets the leaf at the given index. Setting a leaf to zero removes it from the tree.
+    ///
+    /// # Errors
+    /// Returns an error if the index is larger than the number of leaves of the tree.
+    pub fn insert(&mut self, index: u64, leaf: Scalar) -> Result<(), MerkleError> {
+        if index >= self.num_leaves {
+            return Err(MerkleError::IndexOutOfRange(index));
+        }
+        let arity = A::ARITY as u64;
+        self.set_node(0, index, leaf);
+        let mut index = index;
+        for level in 1..=self.depth {
+            let start = index - index % arity;
+            let children = (start..start + arity)
+                .map(|i| self.node(level - 1, i))
+                .collect::<Vec<_>>();
+            index /= arity;
+            self.set_node(level, index, A::compress(&children));
+        }
+        Ok(())
+    }
+
+    fn set_node(&mut self, level: usize, index: u64, node: Scalar) {
+        if node == self.empty_roots[level] {
+            self.nodes.remove(&(level, index));
+        } else {
+            self.nodes.insert((level, index), node);
+        }
+    }
+
+    /// Creates an inclusion proof for the leaf at the given index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is larger than the number of leaves of the tree.
+    pub fn prove(&self, index: u64) -> Result<MerkleProof<A>, MerkleError> {
+        if index >= self.num_leaves {
+            return Err(MerkleError::IndexOutOfRange(index));
+        }
+        let arity = A::ARITY as u64;
+        let mut position = index;
+        let siblings = (0..self.depth)
+            .map(|level| {
+                let start = position - position % arity;
+                let siblings = (start..start + arity)
+                    .filter(|i| *i != position)
+                    .map(|i| self.node(level, i))
+                    .collect();
+                position /= arity;
+                siblings
+            })
+            .collect();
+        Ok(MerkleProof::new(index, siblings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{UniformRand, rand::thread_rng};
+
+    fn dense_and_sparse_agree<A: MerkleArity>(depth: usize) {
+        let mut rng = thread_rng();
+        let mut dense = DenseMerkleTree::<A>::new(depth).expect("valid depth");
+        let mut sparse = SparseMerkleTree::<A>::new(depth).expect("valid depth");
+        assert_eq!(
+            dense.root(),
+            sparse.root(),
+            "empty trees should have the same root"
+        );
+
+        let leaves = (0..10).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+        for leaf in &leaves {
+            let index = dense.insert(*leaf).expect("tree is not full");
+            sparse
+                .insert(index as u64, *leaf)
+                .expect("index is in range");
+            assert_eq!(dense.root(), sparse.root(), "roots should agree");
+        }
+        assert_eq!(
+            DenseMerkleTree::<A>::from_leaves(depth, &leaves)
+                .expect("leaves fit")
+                .root(),
+            dense.root(),
+            "from_leaves should compute the same root"
+        );
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = dense.prove(index).expect("index is in range");
+            assert_eq!(
+                proof,
+                sparse.prove(index as u64).expect("index is in range"),
+                "proofs should agree"
+            );
+            assert!(
+                verify(dense.root(), depth, *leaf, &proof),
+                "valid proof should verify"
+            );
+            assert!(
+                !verify(dense.root(), depth, *leaf + Scalar::from(1u64), &proof),
+                "proof for a different leaf should not verify"
+            );
+            let wrong_index = MerkleProof::<A>::new(index as u64 + 1, proof.siblings().to_vec());
+            assert!(
+                !verify(dense.root(), depth, *leaf, &wrong_index),
+                "proof with a different index should not verify"
+            );
+        }
+
+        // Empty leaves can be proven as well
+        let proof = sparse.prove(42).expect("index is in range");
+        assert!(
+            verify(sparse.root(), sparse.depth(), Scalar::zero(), &proof),
+            "empty leaf should verify"
+        );
+
+        // Updating a leaf changes the root, resetting it restores the root
+        let root = dense.root();
+        dense
+            .update(3, Scalar::from(42u64))
+            .expect("leaf was inserted");
+        sparse
+            .insert(3, Scalar::from(42u64))
+            .expect("index is in range");
+        assert_ne!(dense.root(), root, "root should change");
+        assert_eq!(dense.root(), sparse.root(), "roots should agree");
+        dense.update(3, leaves[3]).expect("leaf was inserted");
+        assert_eq!(dense.root(), root, "root should be restored");
+
+        // An inner node with the proof truncated to the levels above it implies the root, but must not verify as a leaf
+        let proof = dense.prove(0).expect("index is in range");
+        let inner = dense.levels[2][0];
+        let truncated = MerkleProof::<A>::new(0, proof.siblings()[2..].to_vec());
+        assert_eq!(
+            truncated.compute_root(inner),
+            Some(dense.root()),
+            "the truncated proof implies the root"
+        );
+        assert!(
+            !verify(dense.root(), depth, inner, &truncated),
+            "inner node with a truncated proof should not verify as a leaf"
+        );
+        assert!(
+            !verify(dense.root(), depth - 2, leaves[0], &proof),
+            "proof should not verify for a different depth"
+        );
+    }
+
+    fn tree_bounds<A: MerkleArity>(depth: usize) {
+        assert_eq!(
+            DenseMerkleTree::<A>::new(0).map(|_| ()),
+            Err(MerkleError::InvalidDepth(0)),
+            "depth 0 should be rejected"
+        );
+        // The number of leaves fits into a u64, but not into memory
+        let max_depth = 64 / A::ARITY.trailing_zeros() as usize - 1;
+        for depth in [max_depth - 1, max_depth] {
+            assert_eq!(
+                DenseMerkleTree::<A>::new(depth).map(|_| ()),
+                Err(MerkleError::InvalidDepth(depth)),
+                "dense tree of depth {depth} cannot be allocated"
+            );
+        }
+        let mut dense = DenseMerkleTree::<A>::new(depth).expect("valid depth");
+        let num_leaves = A::ARITY.pow(u32::try_from(depth).expect("small depth"));
+        for i in 0..num_leaves {
+            assert_eq!(
+                dense.insert(Scalar::from(i as u64)),
+                Ok(i),
+                "leaf should be inserted at the next index"
+            );
+        }
+        assert_eq!(
+            dense.insert(Scalar::from(0u64)),
+            Err(MerkleError::TreeFull),
+            "insert into a full tree should fail"
+        );
+        assert_eq!(
+            dense.prove(num_leaves).map(|_| ()),
+            Err(MerkleError::IndexOutOfRange(num_leaves as u64)),
+            "proof for out of range index should fail"
+        );
+        let mut sparse = SparseMerkleTree::<A>::new(depth).expect("valid depth");
+        assert_eq!(
+            sparse.insert(num_leaves as u64, Scalar::from(0u64)),
+            Err(MerkleError::IndexOutOfRange(num_leaves as u64)),
+            "insert at out of range index should fail"
+        );
+    }
+
+    #[cfg(feature = "t3")]
+    #[test]
+    fn merkle_binary() {
+        dense_and_sparse_agree::<Binary>(10);
+        tree_bounds::<Binary>(3);
+        assert!(
+            SparseMerkleTree::<Binary>::new(64).is_err(),
+            "depth 64 should be rejected"
+        );
+        let sparse = SparseMerkleTree::<Binary>::new(63).expect("valid depth");
+        let proof = sparse.prove(u64::MAX / 2).expect("index is in range");
+        assert!(
+            verify(sparse.root(), sparse.depth(), Scalar::zero(), &proof),
+            "empty leaf should verify"
+        );
+    }
+
+    #[cfg(feature = "t3")]
+    #[test]
+    fn merkle_binary_kat() {
+        let leaves = [1u64, 2, 3, 4].map(Scalar::from);
+        let tree = DenseMerkleTree::<Binary>::from_leaves(2, &leaves).expect("leaves fit");
+        let expected = crate::bn254::compress2(
+            crate::bn254::compress2(leaves[0], leaves[1]),
+            crate::bn254::compress2(leaves[2], leaves[3]),
+        );
+        assert_eq!(
+            tree.root(),
+            expected,
+            "root should match manual computation"
+        );
+    }
+
+    #[cfg(feature = "t8")]
+    #[test]
+    fn merkle_quaternary() {
+        dense_and_sparse_agree::<Quaternary>(5);
+        tree_bounds::<Quaternary>(2);
+        assert!(
+            SparseMerkleTree::<Quaternary>::new(32).is_err(),
+            "depth 32 should be rejected"
+        );
+    }
+
+    #[cfg(feature = "t8")]
+    #[test]
+    fn merkle_quaternary_kat() {
+        let leaves = (1..=16).map(Scalar::from).collect::<Vec<_>>();
+        let tree = DenseMerkleTree::<Quaternary>::from_leaves(2, &leaves).expect("leaves fit");
+        let inner: [Scalar; 4] = std::array::from_fn(|i| {
+            crate::bn254::compress4(leaves[4 * i..4 * i + 4].try_into().expect("4 leaves"))
+        });
+        assert_eq!(
+            tree.root(),
+            crate::bn254::compress4(&inner),
+            "root should match manual computation"
+        );
+    }
+}
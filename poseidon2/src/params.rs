@@ -0,0 +1,613 @@
+//! Generation of Poseidon2 parameters, ported from the original Poseidon2 [parameter generation script](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage).
+//!
+//! The generation consists of three steps, which are all reproduced bit-for-bit:
+//! 1. The number of full and partial rounds is computed from the security level, such that all known attacks are prevented. As in the reference script, a security margin of two full rounds and 7.5% partial rounds is added.
+//! 2. The round constants are sampled from the Grain LFSR, which is initialized with the field size, the state size and the round numbers.
+//! 3. For state sizes `t >= 4`, the diagonal of the internal matrix is sampled from the same LFSR until the matrix fulfills the minimal polynomial condition of the Poseidon2 paper.
+//!    For `t = 2` and `t = 3`, the internal matrix is fixed.
+//!
+//! The built-in `bn254` parameters can be regenerated with `Poseidon2Params::<ark_bn254::Fr>::generate(t, 5, 128)`.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use poseidon2::params::Poseidon2Params;
+//!
+//! let params = Poseidon2Params::<ark_bn254::Fr>::generate(4, 5, 128)?;
+//! let perm = params.to_permutation::<4, 5, 8, 56>()?;
+//! let output = perm.permutation(&input);
+//! ```
+
+mod matrix;
+
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::Poseidon2Permutation;
+
+/// The errors that can occur when generating Poseidon2 parameters.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParamsError {
+    /// The state size is not supported by [`Poseidon2Permutation`], i.e., it is not 2, 3 or a multiple of 4 up to 24.
+    #[error("unsupported state size {0}")]
+    InvalidStateSize(usize),
+    /// The S-box `x^d` is not a permutation of the field, or `d < 3`.
+    #[error("x^{0} is not a valid S-box for this field")]
+    InvalidSbox(u64),
+    /// The number of full rounds is odd or the round numbers are too large to be encoded in the Grain LFSR.
+    #[error("invalid round numbers: {rounds_f} full rounds, {rounds_p} partial rounds")]
+    InvalidRoundNumbers {
+        /// The number of full rounds.
+        rounds_f: usize,
+        /// The number of partial rounds.
+        rounds_p: usize,
+    },
+    /// No round numbers within the search bounds of the reference script achieve the security level.
+    #[error("no round numbers achieve a security level of {0} bits")]
+    NoSecureRoundNumbers(u32),
+    /// The parameters do not match the const generics of the requested [`Poseidon2Permutation`].
+    #[error("parameters do not match the requested permutation")]
+    ShapeMismatch,
+}
+
+/// A set of Poseidon2 parameters, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Poseidon2Params<F: PrimeField> {
+    /// The state size.
+    pub t: usize,
+    /// The degree of the S-box `x^d`.
+    pub d: u64,
+    /// The number of full (external) rounds.
+    pub rounds_f: usize,
+    /// The number of partial (internal) rounds.
+    pub rounds_p: usize,
+    /// The diagonal of the internal matrix, with 1 subtracted from each element.
+    pub mat_internal_diag_m_1: Vec<F>,
+    /// The round constants of the external rounds, `rounds_f` arrays of `t` elements each.
+    pub round_constants_external: Vec<Vec<F>>,
+    /// The round constants of the internal rounds.
+    pub round_constants_internal: Vec<F>,
+}
+
+impl<F: PrimeField> Poseidon2Params<F> {
+    /// Generates the parameters for state size `t` and S-box `x^d`, with round numbers achieving the given security level (in bits), see [`round_numbers`].
+    ///
+    /// # Errors
+    /// Returns an error if the state size or the S-box are not supported, or no secure round numbers are found.
+    pub fn generate(t: usize, d: u64, security_level: u32) -> Result<Self, ParamsError> {
+        let (rounds_f, rounds_p) = round_numbers::<F>(t, d, security_level)?;
+        Self::generate_with_rounds(t, d, rounds_f, rounds_p)
+    }
+
+    /// Generates the parameters for state size `t` and S-box `x^d`, with the given round numbers.
+    ///
+    /// No security checks are performed on the round numbers.
+    ///
+    /// # Errors
+    /// Returns an error if the state size, the S-box or the round numbers are not supported.
+    pub fn generate_with_rounds(
+        t: usize,
+        d: u64,
+        rounds_f: usize,
+        rounds_p: usize,
+    ) -> Result<Self, ParamsError> {
+        check_state_size(t)?;
+        check_sbox::<F>(d)?;
+        if rounds_f % 2 != 0 || rounds_f >= 1 << 10 || rounds_p >= 1 << 10 {
+            return Err(ParamsError::InvalidRoundNumbers { rounds_f, rounds_p });
+        }
+
+        let mut grain = GrainLfsr::new(F::MODULUS_BIT_SIZE, t, rounds_f, rounds_p);
+        // The internal constants are sampled between the two halves of the external constants
+        let mut round_constants_external = (0..rounds_f / 2)
+            .map(|_| grain.next_field_elements(t))
+            .collect::<Vec<_>>();
+        let round_constants_internal = grain.next_field_elements(rounds_p);
+        round_constants_external.extend((0..rounds_f / 2).map(|_| grain.next_field_elements(t)));
+
+        let mat_internal_diag_m_1 = match t {
+            2 => vec![F::one(), F::from(2u64)],
+            3 => vec![F::one(), F::one(), F::from(2u64)],
+            _ => loop {
+                let diag = (0..t)
+                    .map(|_| grain.next_field_element_mod_order())
+                    .collect::<Vec<F>>();
+                if matrix::minpoly_condition(&matrix::internal_matrix(&diag)) {
+                    break diag.into_iter().map(|d| d - F::one()).collect();
+                }
+            },
+        };
+
+        Ok(Self {
+            t,
+            d,
+            rounds_f,
+            rounds_p,
+            mat_internal_diag_m_1,
+            round_constants_external,
+            round_constants_internal,
+        })
+    }
+
+    /// Converts the parameters into a [`Poseidon2Permutation`] with matching const generics.
+    ///
+    /// # Errors
+    /// Returns an error if the const generics do not match the parameters.
+    pub fn to_permutation<
+        const T: usize,
+        const D: u64,
+        const ROUNDS_F: usize,
+        const ROUNDS_P: usize,
+    >(
+        &self,
+    ) -> Result<Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>, ParamsError> {
+        if self.t != T || self.d != D || self.rounds_f != ROUNDS_F || self.rounds_p != ROUNDS_P {
+            return Err(ParamsError::ShapeMismatch);
+        }
+        let round_constants_external = self
+            .round_constants_external
+            .iter()
+            .map(|round| <[F; T]>::try_from(round.as_slice()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParamsError::ShapeMismatch)?;
+        Ok(Poseidon2Permutation::new(
+            self.mat_internal_diag_m_1
+                .as_slice()
+                .try_into()
+                .map_err(|_| ParamsError::ShapeMismatch)?,
+            round_constants_external
+                .try_into()
+                .map_err(|_| ParamsError::ShapeMismatch)?,
+            self.round_constants_internal
+                .as_slice()
+                .try_into()
+                .map_err(|_| ParamsError::ShapeMismatch)?,
+        ))
+    }
+}
+
+/// Computes the number of full and partial rounds for state size `t` and S-box `x^d`, such that the permutation achieves the given security level (in bits).
+///
+/// As in the reference script, the round numbers minimize the number of S-boxes `rounds_f * t + rounds_p`, and include a security margin of two full rounds and 7.5% partial rounds.
+/// For `bn254` with `d = 5` and a security level of 128 bits, this gives 8 full rounds and 56 (for `t <= 4`) or 57 (for `t > 4`) partial rounds.
+///
+/// # Errors
+/// Returns an error if the state size or the S-box are not supported, or no secure round numbers are found.
+pub fn round_numbers<F: PrimeField>(
+    t: usize,
+    d: u64,
+    security_level: u32,
+) -> Result<(usize, usize), ParamsError> {
+    check_state_size(t)?;
+    check_sbox::<F>(d)?;
+    let t_u32 = u32::try_from(t).map_err(|_| ParamsError::InvalidStateSize(t))?;
+    let d_u32 = u32::try_from(d).map_err(|_| ParamsError::InvalidSbox(d))?;
+    let bounds = SecurityBounds::new::<F>(t_u32, d_u32, security_level);
+
+    // (cost, rounds_f, rounds_p)
+    let mut best: Option<(u32, u32, u32)> = None;
+    for rounds_p in 1..500 {
+        // The reference script overwrites the loop variable of the partial rounds when adding the security margin,
+        // which affects the remaining iterations of the inner loop. We do the same to get the same results.
+        let mut rounds_p = rounds_p;
+        for rounds_f in (4..100).step_by(2) {
+            if bounds.is_secure(rounds_f, rounds_p) {
+                let rounds_f = rounds_f + 2;
+                // ceil(rounds_p * 1.075)
+                rounds_p = (rounds_p * 43).div_ceil(40);
+                let cost = rounds_f * t_u32 + rounds_p;
+                if best.is_none_or(|(min_cost, min_rounds_f, _)| {
+                    cost < min_cost || (cost == min_cost && rounds_f < min_rounds_f)
+                }) {
+                    best = Some((cost, rounds_f, rounds_p));
+                }
+            }
+        }
+    }
+    best.map(|(_, rounds_f, rounds_p)| (rounds_f as usize, rounds_p as usize))
+        .ok_or(ParamsError::NoSecureRoundNumbers(security_level))
+}
+
+fn check_state_size(t: usize) -> Result<(), ParamsError> {
+    if t == 2 || t == 3 || (t <= 24 && t % 4 == 0 && t != 0) {
+        Ok(())
+    } else {
+        Err(ParamsError::InvalidStateSize(t))
+    }
+}
+
+/// Checks that `x^d` is a permutation, i.e., `gcd(d, p - 1) = 1`.
+fn check_sbox<F: PrimeField>(d: u64) -> Result<(), ParamsError> {
+    if d < 3 {
+        return Err(ParamsError::InvalidSbox(d));
+    }
+    // p mod d, from the most significant limb
+    let p_mod_d = F::MODULUS.as_ref().iter().rev().fold(0u64, |acc, limb| {
+        let acc = ((u128::from(acc) << 64) | u128::from(*limb)) % u128::from(d);
+        u64::try_from(acc).expect("remainder is smaller than d")
+    });
+    let (mut a, mut b) = (d, (p_mod_d + d - 1) % d);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 1 {
+        Ok(())
+    } else {
+        Err(ParamsError::InvalidSbox(d))
+    }
+}
+
+/// The inputs to the security inequalities of the reference script.
+struct SecurityBounds {
+    /// `log2(p)`
+    log2_p: f64,
+    /// The bit size of `p`.
+    field_bits: f64,
+    t: f64,
+    alpha: f64,
+    security_level: f64,
+    /// `ceil(log_alpha(t))`
+    ceil_log_alpha_t: f64,
+}
+
+impl SecurityBounds {
+    fn new<F: PrimeField>(t: u32, alpha: u32, security_level: u32) -> Self {
+        // log2(p) from the 53 most significant bits
+        let bits = F::MODULUS.to_bits_be();
+        let bits = &bits[bits.iter().position(|b| *b).unwrap_or(bits.len())..];
+        let top = bits.len().min(53);
+        let mantissa = bits[..top]
+            .iter()
+            .fold(0.0, |acc, b| acc * 2.0 + f64::from(u8::from(*b)));
+        let log2_p = mantissa.log2() + f64::from(u32::try_from(bits.len() - top).unwrap_or(0));
+
+        let mut ceil_log_alpha_t = 0;
+        let mut power = 1u64;
+        while power < u64::from(t) {
+            power *= u64::from(alpha);
+            ceil_log_alpha_t += 1;
+        }
+
+        Self {
+            log2_p,
+            field_bits: f64::from(F::MODULUS_BIT_SIZE),
+            t: f64::from(t),
+            alpha: f64::from(alpha),
+            security_level: f64::from(security_level),
+            ceil_log_alpha_t: f64::from(ceil_log_alpha_t),
+        }
+    }
+
+    /// Checks whether the round numbers prevent the statistical, interpolation and Groebner basis attacks, including the attack from [eprint.iacr.org/2023/537](https://eprint.iacr.org/2023/537).
+    fn is_secure(&self, rounds_f: u32, rounds_p: u32) -> bool {
+        let Self {
+            log2_p,
+            field_bits,
+            t,
+            alpha,
+            security_level: m,
+            ceil_log_alpha_t,
+        } = *self;
+        let rounds_f = f64::from(rounds_f);
+        let rounds_p = f64::from(rounds_p);
+        let log_alpha_2 = 2f64.ln() / alpha.ln();
+
+        // Statistical
+        let r_f_1: f64 = if m <= (log2_p - (alpha - 1.0) / 2.0).floor() * (t + 1.0) {
+            6.0
+        } else {
+            10.0
+        };
+        // Interpolation
+        let r_f_2 = 1.0 + (log_alpha_2 * m.min(field_bits)).ceil() + ceil_log_alpha_t - rounds_p;
+        // Groebner 1
+        let r_f_3 = log_alpha_2 * m.min(log2_p) - rounds_p;
+        // Groebner 2
+        let r_f_4 = t - 1.0 + log_alpha_2 * (m / (t + 1.0)).min(log2_p / 2.0) - rounds_p;
+        // Groebner 3
+        let r_f_5 = (t - 2.0 + m / (2.0 * alpha.log2()) - rounds_p) / (t - 1.0);
+        let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+            .into_iter()
+            .map(f64::ceil)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        // Groebner 4, eprint.iacr.org/2023/537
+        let r_temp = (t / 3.0).floor();
+        let over =
+            (rounds_f - 1.0) * t + rounds_p + r_temp + r_temp * (rounds_f / 2.0) + rounds_p + alpha;
+        let under = r_temp * (rounds_f / 2.0) + rounds_p + alpha;
+        let cost_gb4 = (2.0 * log2_binomial(over, under)).ceil();
+
+        rounds_f >= r_f_max && cost_gb4 >= m
+    }
+}
+
+/// Computes `log2(binomial(n, k))` for integral `n >= k >= 0`.
+fn log2_binomial(n: f64, k: f64) -> f64 {
+    let k = k.min(n - k);
+    let mut result = 0.0;
+    let mut i = 1.0;
+    while i <= k {
+        result += ((n - k + i) / i).log2();
+        i += 1.0;
+    }
+    result
+}
+
+/// The Grain LFSR in self-shrinking mode, as used by the reference script to sample the parameters.
+struct GrainLfsr {
+    state: [bool; 80],
+}
+
+impl GrainLfsr {
+    /// Initializes the LFSR for a prime field and the S-box `x^d` and discards the first 160 output bits.
+    fn new(field_bits: u32, t: usize, rounds_f: usize, rounds_p: usize) -> Self {
+        let mut state = [true; 80];
+        let mut pos = 0;
+        // (value, number of bits): field type (1 = prime field), S-box type (0 = x^d), field size, state size, rounds
+        for (value, len) in [
+            (1, 2),
+            (0, 4),
+            (field_bits as usize, 12),
+            (t, 12),
+            (rounds_f, 10),
+            (rounds_p, 10),
+        ] {
+            for i in (0..len).rev() {
+                state[pos] = (value >> i) & 1 == 1;
+                pos += 1;
+            }
+        }
+        // the remaining 30 bits are set to one
+
+        let mut grain = Self { state };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let s = &self.state;
+        let new_bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = new_bit;
+        new_bit
+    }
+
+    /// Returns the next output bit: pairs of bits are produced, and the second bit is output only if the first bit is one.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            if self.step() {
+                return self.step();
+            }
+            self.step();
+        }
+    }
+
+    /// Returns the next `F::MODULUS_BIT_SIZE` bits, most significant bit first.
+    fn next_bigint<F: PrimeField>(&mut self) -> F::BigInt {
+        let bits = (0..F::MODULUS_BIT_SIZE)
+            .map(|_| self.next_bit())
+            .collect::<Vec<_>>();
+        F::BigInt::from_bits_be(&bits)
+    }
+
+    /// Samples a field element by rejection sampling, as for the round constants.
+    fn next_field_element<F: PrimeField>(&mut self) -> F {
+        loop {
+            if let Some(element) = F::from_bigint(self.next_bigint::<F>()) {
+                return element;
+            }
+        }
+    }
+
+    /// Samples `n` field elements by rejection sampling.
+    fn next_field_elements<F: PrimeField>(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.next_field_element()).collect()
+    }
+
+    /// Samples a field element by reducing the sampled integer, as for the internal matrix.
+    fn next_field_element_mod_order<F: PrimeField>(&mut self) -> F {
+        F::from_le_bytes_mod_order(&self.next_bigint::<F>().to_bytes_le())
+    }
+}
+
+#[cfg(all(test, feature = "bn254"))]
+mod tests {
+    use super::*;
+
+    type Fr = ark_bn254::Fr;
+
+    #[test]
+    fn bn254_round_numbers() {
+        for (t, rounds_p) in [(2, 56), (3, 56), (4, 56), (8, 57), (12, 57), (16, 57)] {
+            assert_eq!(
+                round_numbers::<Fr>(t, 5, 128),
+                Ok((8, rounds_p)),
+                "wrong round numbers for t={t}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert_eq!(
+            round_numbers::<Fr>(5, 5, 128),
+            Err(ParamsError::InvalidStateSize(5)),
+            "t=5 is not supported"
+        );
+        // 3 divides p - 1
+        assert_eq!(
+            Poseidon2Params::<Fr>::generate(4, 3, 128),
+            Err(ParamsError::InvalidSbox(3)),
+            "x^3 is not a permutation"
+        );
+        assert_eq!(
+            Poseidon2Params::<Fr>::generate_with_rounds(4, 5, 7, 56),
+            Err(ParamsError::InvalidRoundNumbers {
+                rounds_f: 7,
+                rounds_p: 56
+            }),
+            "odd number of full rounds"
+        );
+        assert_eq!(
+            Poseidon2Params::<Fr>::generate_with_rounds(4, 5, 8, 56)
+                .expect("valid parameters")
+                .to_permutation::<4, 5, 8, 57>()
+                .map(|_| ()),
+            Err(ParamsError::ShapeMismatch),
+            "wrong number of partial rounds"
+        );
+    }
+
+    #[cfg(feature = "t2")]
+    #[test]
+    fn bn254_t2() {
+        let params = Poseidon2Params::<Fr>::generate(2, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<2, 5, 8, 56>(),
+            Ok(crate::bn254::t2::POSEIDON2_BN254_T2_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t3")]
+    #[test]
+    fn bn254_t3() {
+        let params = Poseidon2Params::<Fr>::generate(3, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<3, 5, 8, 56>(),
+            Ok(crate::bn254::t3::POSEIDON2_BN254_T3_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t4")]
+    #[test]
+    fn bn254_t4() {
+        let params = Poseidon2Params::<Fr>::generate(4, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<4, 5, 8, 56>(),
+            Ok(crate::bn254::t4::POSEIDON2_BN254_T4_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t8")]
+    #[test]
+    fn bn254_t8() {
+        let params = Poseidon2Params::<Fr>::generate(8, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<8, 5, 8, 57>(),
+            Ok(crate::bn254::t8::POSEIDON2_BN254_T8_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t12")]
+    #[test]
+    fn bn254_t12() {
+        let params = Poseidon2Params::<Fr>::generate(12, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<12, 5, 8, 57>(),
+            Ok(crate::bn254::t12::POSEIDON2_BN254_T12_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t16")]
+    #[test]
+    fn bn254_t16() {
+        let params = Poseidon2Params::<Fr>::generate(16, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<16, 5, 8, 57>(),
+            Ok(crate::bn254::t16::POSEIDON2_BN254_T16_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t20")]
+    #[test]
+    fn bn254_t20() {
+        let params = Poseidon2Params::<Fr>::generate(20, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<20, 5, 8, 57>(),
+            Ok(crate::bn254::t20::POSEIDON2_BN254_T20_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "t24")]
+    #[test]
+    fn bn254_t24() {
+        let params = Poseidon2Params::<Fr>::generate(24, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<24, 5, 8, 57>(),
+            Ok(crate::bn254::t24::POSEIDON2_BN254_T24_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "bls12_381",
+        feature = "goldilocks",
+        feature = "babybear",
+        feature = "m31"
+    )
+))]
+mod field_tests {
+    use super::*;
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn bls12_381_t4() {
+        let params =
+            Poseidon2Params::<crate::bls12_381::Fr>::generate(4, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<4, 5, 8, 56>(),
+            Ok(crate::bls12_381::t4::POSEIDON2_BLS12_381_T4_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "goldilocks")]
+    #[test]
+    fn goldilocks_t8() {
+        let params = Poseidon2Params::<crate::goldilocks::Fp>::generate(8, 7, 128)
+            .expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<8, 7, 8, 22>(),
+            Ok(crate::goldilocks::t8::POSEIDON2_GOLDILOCKS_T8_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "babybear")]
+    #[test]
+    fn babybear_t16() {
+        let params =
+            Poseidon2Params::<crate::babybear::Fp>::generate(16, 7, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<16, 7, 8, 13>(),
+            Ok(crate::babybear::t16::POSEIDON2_BABYBEAR_T16_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+
+    #[cfg(feature = "m31")]
+    #[test]
+    fn m31_t16() {
+        let params =
+            Poseidon2Params::<crate::m31::Fp>::generate(16, 5, 128).expect("valid parameters");
+        assert_eq!(
+            params.to_permutation::<16, 5, 8, 14>(),
+            Ok(crate::m31::t16::POSEIDON2_M31_T16_PARAMS.clone()),
+            "generated parameters do not match"
+        );
+    }
+}
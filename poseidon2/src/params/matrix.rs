@@ -0,0 +1,234 @@
+//! Linear algebra over prime fields, used to check the internal matrix of Poseidon2.
+//!
+//! Polynomials are represented by their coefficients in ascending order.
+
+use ark_ff::{BigInteger, PrimeField};
+
+/// Returns the internal matrix `M` with `M[i][i] = diag[i]` and `M[i][j] = 1` for `i != j`.
+pub(super) fn internal_matrix<F: PrimeField>(diag: &[F]) -> Vec<Vec<F>> {
+    (0..diag.len())
+        .map(|i| {
+            (0..diag.len())
+                .map(|j| if i == j { diag[i] } else { F::one() })
+                .collect()
+        })
+        .collect()
+}
+
+/// Multiplies two square matrices of the same size.
+pub(super) fn mat_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|j| row.iter().zip(b).map(|(x, b_row)| *x * b_row[j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+/// Checks the condition of the reference script for the internal matrix: for all `i` in `1..=2t`, the minimal polynomial of `M^i` has degree `t` and is irreducible.
+///
+/// Since the minimal polynomial divides the characteristic polynomial, which has degree `t`, this is equivalent to the characteristic polynomials being irreducible.
+pub(super) fn minpoly_condition<F: PrimeField>(m: &[Vec<F>]) -> bool {
+    let mut power = m.to_vec();
+    for i in 1..=2 * m.len() {
+        if !is_irreducible(&charpoly(&power)) {
+            return false;
+        }
+        if i < 2 * m.len() {
+            power = mat_mul(m, &power);
+        }
+    }
+    true
+}
+
+/// Computes the (monic) characteristic polynomial `det(xI - A)` with the Faddeev-LeVerrier algorithm.
+///
+/// Requires that the characteristic of the field is larger than the size of the matrix.
+fn charpoly<F: PrimeField>(a: &[Vec<F>]) -> Vec<F> {
+    let n = a.len();
+    let mut coeffs = vec![F::zero(); n + 1];
+    coeffs[n] = F::one();
+    let mut m = vec![vec![F::zero(); n]; n];
+    for k in 1..=n {
+        // M_k = A * M_{k-1} + c_{n-k+1} * I
+        m = mat_mul(a, &m);
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] += coeffs[n - k + 1];
+        }
+        // c_{n-k} = -tr(A * M_k) / k
+        let trace: F = a
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .zip(&m)
+                    .map(|(x, m_row)| *x * m_row[i])
+                    .sum::<F>()
+            })
+            .sum();
+        coeffs[n - k] = -trace / F::from(k as u64);
+    }
+    coeffs
+}
+
+/// Checks whether the monic polynomial `f` is irreducible, using Berlekamp's criterion:
+/// A square-free polynomial of degree `n` has `n - rank(Q - I)` distinct irreducible factors, where the rows of `Q` are `x^(i*p) mod f`.
+fn is_irreducible<F: PrimeField>(f: &[F]) -> bool {
+    let n = f.len() - 1;
+    if n <= 1 {
+        return n == 1;
+    }
+    if degree(&gcd(f.to_vec(), derivative(f))) != Some(0) {
+        return false;
+    }
+
+    // x^p mod f
+    let mut x_p = vec![F::zero(); n];
+    x_p[0] = F::one();
+    for bit in F::MODULUS.to_bits_be().into_iter().skip_while(|b| !b) {
+        x_p = mul_mod(&x_p, &x_p, f);
+        if bit {
+            x_p.insert(0, F::zero());
+            x_p = reduce(x_p, f);
+        }
+    }
+
+    // Q - I
+    let mut row = vec![F::zero(); n];
+    row[0] = F::one();
+    let mut q = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut q_row = row.clone();
+        q_row[i] -= F::one();
+        q.push(q_row);
+        row = mul_mod(&row, &x_p, f);
+    }
+    rank(q) == n - 1
+}
+
+/// Returns the degree of the polynomial, or `None` for the zero polynomial.
+fn degree<F: PrimeField>(f: &[F]) -> Option<usize> {
+    f.iter().rposition(|c| !c.is_zero())
+}
+
+fn derivative<F: PrimeField>(f: &[F]) -> Vec<F> {
+    f.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| *c * F::from(i as u64))
+        .collect()
+}
+
+/// Reduces `a` modulo the monic polynomial `f`.
+fn reduce<F: PrimeField>(mut a: Vec<F>, f: &[F]) -> Vec<F> {
+    let n = f.len() - 1;
+    for i in (n..a.len()).rev() {
+        let c = a[i];
+        if !c.is_zero() {
+            for (j, f_j) in f.iter().enumerate().take(n) {
+                a[i - n + j] -= c * f_j;
+            }
+        }
+    }
+    a.resize(n, F::zero());
+    a
+}
+
+/// Multiplies `a` and `b` modulo the monic polynomial `f`.
+fn mul_mod<F: PrimeField>(a: &[F], b: &[F], f: &[F]) -> Vec<F> {
+    let mut product = vec![F::zero(); a.len() + b.len()];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            product[i + j] += *a_i * b_j;
+        }
+    }
+    reduce(product, f)
+}
+
+/// Computes the remainder of `a` divided by the non-zero polynomial `b`.
+fn rem<F: PrimeField>(mut a: Vec<F>, b: &[F]) -> Vec<F> {
+    let deg_b = degree(b).expect("divisor is not zero");
+    let lead_inv = b[deg_b].inverse().expect("leading coefficient is not zero");
+    while let Some(deg_a) = degree(&a).filter(|deg_a| *deg_a >= deg_b) {
+        let c = a[deg_a] * lead_inv;
+        for (j, b_j) in b.iter().enumerate().take(deg_b + 1) {
+            a[deg_a - deg_b + j] -= c * b_j;
+        }
+    }
+    a
+}
+
+fn gcd<F: PrimeField>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+    while degree(&b).is_some() {
+        let r = rem(a, &b);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Computes the rank of the matrix with Gaussian elimination.
+fn rank<F: PrimeField>(mut m: Vec<Vec<F>>) -> usize {
+    let rows = m.len();
+    let cols = m.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    for col in 0..cols {
+        let Some(pivot) = (rank..rows).find(|r| !m[*r][col].is_zero()) else {
+            continue;
+        };
+        m.swap(rank, pivot);
+        let inv = m[rank][col].inverse().expect("pivot is not zero");
+        let pivot_row = m[rank].iter().map(|x| *x * inv).collect::<Vec<_>>();
+        for row in m.iter_mut().skip(rank + 1) {
+            let c = row[col];
+            if !c.is_zero() {
+                for (x, p) in row.iter_mut().zip(&pivot_row) {
+                    *x -= c * p;
+                }
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+#[cfg(all(test, feature = "bn254"))]
+mod tests {
+    use super::*;
+
+    type Fr = ark_bn254::Fr;
+
+    fn poly(coeffs: &[i64]) -> Vec<Fr> {
+        coeffs.iter().map(|c| Fr::from(*c)).collect()
+    }
+
+    #[test]
+    fn charpoly_small() {
+        // [[1, 2], [3, 4]] has characteristic polynomial x^2 - 5x - 2
+        let m = vec![poly(&[1, 2]), poly(&[3, 4])];
+        assert_eq!(
+            charpoly(&m),
+            poly(&[-2, -5, 1]),
+            "wrong characteristic polynomial"
+        );
+    }
+
+    #[test]
+    fn irreducibility() {
+        // x^2 - 1 = (x - 1)(x + 1)
+        assert!(!is_irreducible(&poly(&[-1, 0, 1])), "x^2 - 1 is reducible");
+        // (x - 1)^2 is not square-free
+        assert!(
+            !is_irreducible(&poly(&[1, -2, 1])),
+            "(x - 1)^2 is reducible"
+        );
+        // 5 is not a square modulo the bn254 scalar field, so x^2 - 5 is irreducible
+        assert!(is_irreducible(&poly(&[-5, 0, 1])), "x^2 - 5 is irreducible");
+        // (x^2 - 5)(x^2 - 20) has no roots, but is reducible
+        assert!(
+            !is_irreducible(&poly(&[100, 0, -25, 0, 1])),
+            "(x^2 - 5)(x^2 - 20) is reducible"
+        );
+    }
+}
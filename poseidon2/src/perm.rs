@@ -1,8 +1,23 @@
 #![allow(dead_code, reason = "not all state sizes are used by every feature")]
+use alloc::{boxed::Box, vec::Vec};
 use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+
+#[cfg(feature = "r1cs")]
+mod constraints;
+
+/// The number of states that [`Poseidon2Permutation::permutation_batch`] processes in lockstep.
+const BATCH_LANES: usize = 4;
 
 /// A struct representing the Poseidon2 permutation.
-pub(crate) struct Poseidon2Permutation<
+///
+/// The permutation is generic over the field `F`, the state size `T`, the S-box degree `D` and the number of full (`ROUNDS_F`) and partial (`ROUNDS_P`) rounds.
+/// This allows to use custom parameter sets, e.g., for other fields, with the same implementation as the built-in instances such as `bn254::t4::POSEIDON2_BN254_T4_PARAMS`.
+///
+/// Supported state sizes are `T = 2`, `T = 3` and multiples of 4 up to 24. For `T = 2` and `T = 3`, the internal matrix is fixed by the paper to `[[2, 1], [1, 3]]` and `[[2, 1, 1], [1, 2, 1], [1, 1, 3]]`, respectively, so the diagonal passed to [`Self::new`] has to match these matrices.
+#[derive(Debug, Clone)]
+pub struct Poseidon2Permutation<
     F: PrimeField,
     const T: usize,
     const D: u64,
@@ -15,12 +30,38 @@ pub(crate) struct Poseidon2Permutation<
     round_constants_external: [[F; T]; ROUNDS_F],
     /// The round constants of the internal rounds.
     round_constants_internal: [F; ROUNDS_P],
+    /// The constants of the inverse permutation, computed on the first call of [`Self::permutation_inverse_in_place`].
+    inverse_constants: OnceBox<InverseConstants<F, T>>,
+}
+
+impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const ROUNDS_P: usize>
+    PartialEq for Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>
+{
+    fn eq(&self, other: &Self) -> bool {
+        // The inverse constants are derived from the parameters, so they are not compared
+        self.mat_internal_diag_m_1 == other.mat_internal_diag_m_1
+            && self.round_constants_external == other.round_constants_external
+            && self.round_constants_internal == other.round_constants_internal
+    }
+}
+
+impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const ROUNDS_P: usize> Eq
+    for Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>
+{
 }
 
 impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const ROUNDS_P: usize>
     Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>
 {
-    /// Creates a new instance of the Poseidon2 permutation with given parameters
+    /// Creates a new instance of the Poseidon2 permutation with given parameters.
+    ///
+    /// # Arguments
+    /// * `mat_internal_diag_m_1` - The diagonal of the internal matrix, with 1 subtracted from each element.
+    /// * `round_constants_external` - The round constants of the external rounds, the first `ROUNDS_F / 2` are used before the internal rounds.
+    /// * `round_constants_internal` - The round constants of the internal rounds, which are only added to the first state element.
+    ///
+    /// Invalid combinations of the const generics are rejected at compile time.
+    #[must_use]
     pub const fn new(
         mat_internal_diag_m_1: [F; T],
         round_constants_external: [[F; T]; ROUNDS_F],
@@ -39,6 +80,7 @@ impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const R
             mat_internal_diag_m_1,
             round_constants_external,
             round_constants_internal,
+            inverse_constants: OnceBox::new(),
         }
     }
 
@@ -96,7 +138,7 @@ impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const R
     }
 
     /// The matrix multiplication in the external rounds of the Poseidon2 permutation.
-    pub fn matmul_external(input: &mut [F; T]) {
+    fn matmul_external(input: &mut [F; T]) {
         match T {
             2 => {
                 // Matrix circ(2, 1)
@@ -139,7 +181,7 @@ impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const R
     }
 
     /// The matrix multiplication in the internal rounds of the Poseidon2 permutation.
-    pub fn matmul_internal(&self, input: &mut [F; T]) {
+    fn matmul_internal(&self, input: &mut [F; T]) {
         match T {
             2 => {
                 // Matrix [[2, 1], [1, 3]]
@@ -195,21 +237,21 @@ impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const R
     }
 
     /// The round constant addition in the external rounds of the Poseidon2 permutation.
-    pub fn add_rc_external(input: &mut [F; T], rc_e: &[F; T]) {
+    fn add_rc_external(input: &mut [F; T], rc_e: &[F; T]) {
         for (s, rc) in input.iter_mut().zip(rc_e.iter()) {
             *s += rc;
         }
     }
 
     /// One external round of the Poseidon2 permutation.
-    pub fn external_round(state: &mut [F; T], rc_e: &[F; T]) {
+    fn external_round(state: &mut [F; T], rc_e: &[F; T]) {
         Self::add_rc_external(state, rc_e);
         Self::sbox(state);
         Self::matmul_external(state);
     }
 
     /// One internal round of the Poseidon2 permutation.
-    pub fn internal_round(&self, state: &mut [F; T], rc_i: F) {
+    fn internal_round(&self, state: &mut [F; T], rc_i: F) {
         // add internal round constant
         state[0] += rc_i;
         Self::single_sbox(&mut state[0]);
@@ -244,4 +286,512 @@ impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const R
         self.permutation_in_place(&mut state);
         state
     }
+
+    /// Computes the constants needed by [`Self::permutation_inverse_in_place`], which only depend on the parameters.
+    ///
+    /// # Panics
+    /// Panics if `x^D` is not a permutation of `F` or if the internal matrix cannot be inverted as described in [`Self::matmul_internal_inverse`].
+    fn compute_inverse_constants(&self) -> InverseConstants<F, T> {
+        let modulus: BigUint = F::MODULUS.into();
+        let sbox_exponent = BigUint::from(D)
+            .modinv(&(modulus - 1u32))
+            .expect("x^D is a permutation, i.e., gcd(D, p - 1) = 1")
+            .to_u64_digits();
+
+        let internal_diag_inv = self.mat_internal_diag_m_1.map(|d| {
+            d.inverse()
+                .expect("diagonal of the internal matrix is nonzero")
+        });
+        let internal_factor = (F::one() + internal_diag_inv.iter().sum::<F>())
+            .inverse()
+            .expect("internal matrix is invertible");
+
+        // The external matrix is (I + 1 * 1^T) for t = 2, 3 and (I + 1 * 1^T) (x) M4 with t / 4 blocks otherwise
+        let blocks = if T < 4 { T } else { T / 4 };
+        let external_factor = F::from((blocks + 1) as u64)
+            .inverse()
+            .expect("field characteristic is larger than the state size");
+        let m4_factor = F::from(8u64)
+            .inverse()
+            .expect("field characteristic is not 2");
+
+        InverseConstants {
+            sbox_exponent,
+            internal_diag_inv,
+            internal_factor,
+            external_factor,
+            m4_factor,
+        }
+    }
+
+    /**
+     * evaluates the matrix multiplication with the inverse of the 4x4 MDS matrix of [`Self::matmul_m4`]:
+     *       /             \
+     *       | -1  3 -6  7 |
+     * 1/8 * |  1 -1  4 -5 |
+     *       | -6  7 -1  3 |
+     *       |  4 -5  1 -1 |
+     *       \             /
+     */
+    fn matmul_m4_inverse(input: &mut [F; 4], m4_factor: F) {
+        let [x_0, x_1, x_2, x_3] = *input;
+        let three = F::from(3u64);
+        let four = F::from(4u64);
+        let five = F::from(5u64);
+        let six = F::from(6u64);
+        let seven = F::from(7u64);
+        input[0] = (three * x_1 + seven * x_3 - x_0 - six * x_2) * m4_factor;
+        input[1] = (x_0 + four * x_2 - x_1 - five * x_3) * m4_factor;
+        input[2] = (seven * x_1 + three * x_3 - six * x_0 - x_2) * m4_factor;
+        input[3] = (four * x_0 + x_2 - five * x_1 - x_3) * m4_factor;
+    }
+
+    /// The matrix multiplication with the inverse of the external matrix, see [`Self::matmul_external`].
+    fn matmul_external_inverse(input: &mut [F; T], constants: &InverseConstants<F, T>) {
+        match T {
+            2 | 3 => {
+                // (I + 1 * 1^T)^-1 = I - 1 * 1^T / (t + 1)
+                let sum = input.iter().sum::<F>() * constants.external_factor;
+                for s in input.iter_mut() {
+                    *s -= sum;
+                }
+            }
+            4 => {
+                Self::matmul_m4_inverse(
+                    input.as_mut_slice().try_into().expect("slice has length 4"),
+                    constants.m4_factor,
+                );
+            }
+            8 | 12 | 16 | 20 | 24 => {
+                // Undo the second cheap matrix, (I + 1 * 1^T)^-1 = I - 1 * 1^T / (t / 4 + 1) on the 4-element parts
+                let mut stored = [F::zero(); 4];
+                for (i, s) in input.iter().enumerate() {
+                    stored[i % 4] += s;
+                }
+                for (i, s) in input.iter_mut().enumerate() {
+                    *s -= stored[i % 4] * constants.external_factor;
+                }
+
+                // Undo the 4x4 MDS matrix on each 4-element part
+                for state in input.chunks_exact_mut(4) {
+                    Self::matmul_m4_inverse(
+                        state.try_into().expect("chunk has length 4"),
+                        constants.m4_factor,
+                    );
+                }
+            }
+            _ => {
+                panic!("Invalid state size");
+            }
+        }
+    }
+
+    /// The matrix multiplication with the inverse of the internal matrix `diag(d) + 1 * 1^T`, where `d` is [`Self::mat_internal_diag_m_1`].
+    ///
+    /// By the Sherman-Morrison formula, the inverse is `diag(d)^-1 - diag(d)^-1 * 1 * 1^T * diag(d)^-1 / (1 + sum(d^-1))`.
+    fn matmul_internal_inverse(input: &mut [F; T], constants: &InverseConstants<F, T>) {
+        for (s, d) in input.iter_mut().zip(constants.internal_diag_inv.iter()) {
+            *s *= d;
+        }
+        let sum = input.iter().sum::<F>() * constants.internal_factor;
+        for (s, d) in input.iter_mut().zip(constants.internal_diag_inv.iter()) {
+            *s -= sum * d;
+        }
+    }
+
+    /// The inverse of [`Self::external_round`].
+    fn external_round_inverse(
+        state: &mut [F; T],
+        rc_e: &[F; T],
+        constants: &InverseConstants<F, T>,
+    ) {
+        Self::matmul_external_inverse(state, constants);
+        for (s, rc) in state.iter_mut().zip(rc_e.iter()) {
+            *s = s.pow(&constants.sbox_exponent) - rc;
+        }
+    }
+
+    /// The inverse of [`Self::internal_round`].
+    fn internal_round_inverse(state: &mut [F; T], rc_i: F, constants: &InverseConstants<F, T>) {
+        Self::matmul_internal_inverse(state, constants);
+        state[0] = state[0].pow(&constants.sbox_exponent);
+        state[0] -= rc_i;
+    }
+
+    /// Performs the inverse of the Poseidon2 Permutation on the given state.
+    ///
+    /// The rounds are undone in reverse order, using the inverse S-box `x^(1/D)` and the inverses of the external and internal matrices.
+    /// This is considerably slower than the permutation itself, as the inverse S-box is an exponentiation with an exponent of the size of the field.
+    /// The constants of the inverse matrices and S-box are computed on the first call and reused by later calls on the same instance.
+    ///
+    /// # Panics
+    /// Panics if `x^D` is not a permutation of `F` or if the internal matrix or a diagonal element of it is not invertible. Neither is the case for the built-in instances and parameters generated with the `params` module.
+    pub fn permutation_inverse_in_place(&self, state: &mut [F; T]) {
+        let constants = self
+            .inverse_constants
+            .get_or_init(|| Box::new(self.compute_inverse_constants()));
+        let (first_external, last_external) = self.round_constants_external.split_at(ROUNDS_F / 2);
+
+        // Remaining external rounds
+        for rc_e in last_external.iter().rev() {
+            Self::external_round_inverse(state, rc_e, constants);
+        }
+
+        // Internal rounds
+        for rc_i in self.round_constants_internal.into_iter().rev() {
+            Self::internal_round_inverse(state, rc_i, constants);
+        }
+
+        // First set of external rounds
+        for rc_e in first_external.iter().rev() {
+            Self::external_round_inverse(state, rc_e, constants);
+        }
+
+        // Linear layer at beginning
+        Self::matmul_external_inverse(state, constants);
+    }
+
+    /// Performs the inverse of the Poseidon2 Permutation on the given state, such that `permutation_inverse(&permutation(x)) == x`.
+    ///
+    /// See [`Self::permutation_inverse_in_place`] for details.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`Self::permutation_inverse_in_place`].
+    pub fn permutation_inverse(&self, input: &[F; T]) -> [F; T] {
+        let mut state = *input;
+        self.permutation_inverse_in_place(&mut state);
+        state
+    }
+
+    /// Performs the Poseidon2 Permutation on the given state and records all intermediate states.
+    ///
+    /// The output of the permutation is available via [`Poseidon2Trace::output`]. See [`Poseidon2Trace`] for the layout of the trace.
+    pub fn permutation_with_trace(
+        &self,
+        input: &[F; T],
+    ) -> Poseidon2Trace<F, T, ROUNDS_F, ROUNDS_P> {
+        let mut state = *input;
+
+        // Linear layer at beginning
+        Self::matmul_external(&mut state);
+        let initial_linear_layer = state;
+
+        let mut external_sbox_inputs = [[F::zero(); T]; ROUNDS_F];
+        let mut external_states = [[F::zero(); T]; ROUNDS_F];
+        let mut internal_sbox_inputs = [F::zero(); ROUNDS_P];
+        let mut internal_states = [[F::zero(); T]; ROUNDS_P];
+
+        let mut external_round = |round: usize, state: &mut [F; T]| {
+            Self::add_rc_external(state, &self.round_constants_external[round]);
+            external_sbox_inputs[round] = *state;
+            Self::sbox(state);
+            Self::matmul_external(state);
+            external_states[round] = *state;
+        };
+
+        // First set of external rounds
+        for round in 0..ROUNDS_F / 2 {
+            external_round(round, &mut state);
+        }
+
+        // Internal rounds
+        for (round, rc_i) in self.round_constants_internal.into_iter().enumerate() {
+            state[0] += rc_i;
+            internal_sbox_inputs[round] = state[0];
+            Self::single_sbox(&mut state[0]);
+            self.matmul_internal(&mut state);
+            internal_states[round] = state;
+        }
+
+        // Remaining external rounds
+        for round in ROUNDS_F / 2..ROUNDS_F {
+            external_round(round, &mut state);
+        }
+
+        Poseidon2Trace {
+            input: *input,
+            initial_linear_layer,
+            external_sbox_inputs,
+            external_states,
+            internal_sbox_inputs,
+            internal_states,
+        }
+    }
+
+    /// Performs the Poseidon2 Permutation on [`BATCH_LANES`] states in lockstep.
+    ///
+    /// The loops run over the rounds first and over the states second, such that the field operations of consecutive iterations are independent of each other.
+    /// A single permutation is a long chain of dependent operations, whereas this layout gives the compiler and the CPU independent work to interleave and shares the loads of the round constants between the states.
+    fn permutation_interleaved(&self, states: &mut [[F; T]; BATCH_LANES]) {
+        // Linear layer at beginning
+        states.iter_mut().for_each(Self::matmul_external);
+        let mut round_constants_external = self.round_constants_external.iter();
+
+        // First set of external rounds
+        for rc_e in round_constants_external.by_ref().take(ROUNDS_F / 2) {
+            for state in states.iter_mut() {
+                Self::external_round(state, rc_e);
+            }
+        }
+
+        // Internal rounds
+        for rc_i in self.round_constants_internal {
+            for state in states.iter_mut() {
+                self.internal_round(state, rc_i);
+            }
+        }
+
+        // Remaining external rounds
+        for rc_e in round_constants_external {
+            for state in states.iter_mut() {
+                Self::external_round(state, rc_e);
+            }
+        }
+    }
+
+    /// Permutes a chunk of at most [`BATCH_LANES`] states, using the interleaved permutation for full chunks.
+    fn permutation_chunk(&self, chunk: &mut [[F; T]]) {
+        match chunk.try_into() {
+            Ok(lanes) => self.permutation_interleaved(lanes),
+            Err(_) => chunk
+                .iter_mut()
+                .for_each(|state| self.permutation_in_place(state)),
+        }
+    }
+
+    /// Performs the Poseidon2 Permutation in place on each of the given states.
+    ///
+    /// This is equivalent to calling [`Self::permutation_in_place`] for every state, but processes four states at once in an interleaved fashion.
+    /// With the `parallel` feature, the batch is additionally distributed over the rayon thread pool, which is where the speedup comes from.
+    /// Without the `parallel` feature, the interleaving alone is not measurably faster than permuting the states one by one, see the `Poseidon2 Batch Permutation` benchmarks.
+    pub fn permutation_batch(&self, states: &mut [[F; T]]) {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            states
+                .par_chunks_mut(BATCH_LANES)
+                .for_each(|chunk| self.permutation_chunk(chunk));
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            states
+                .chunks_mut(BATCH_LANES)
+                .for_each(|chunk| self.permutation_chunk(chunk));
+        }
+    }
+}
+
+/// The constants of the inverse permutation, see [`Poseidon2Permutation::permutation_inverse_in_place`].
+#[derive(Debug, Clone)]
+struct InverseConstants<F: PrimeField, const T: usize> {
+    /// The exponent of the inverse S-box, `D^-1 mod (p - 1)`, as little-endian limbs.
+    sbox_exponent: Vec<u64>,
+    /// The inverses of the diagonal of the internal matrix (without the subtracted 1).
+    internal_diag_inv: [F; T],
+    /// `1 / (1 + sum(internal_diag_inv))`
+    internal_factor: F,
+    /// `1 / (t + 1)` for `t = 2, 3` and `1 / (t / 4 + 1)` otherwise.
+    external_factor: F,
+    /// `1 / 8`, the inverse of the determinant (up to sign) of the 4x4 MDS matrix.
+    m4_factor: F,
+}
+
+/// The witness trace of a single Poseidon2 permutation, as returned by [`Poseidon2Permutation::permutation_with_trace`].
+///
+/// The permutation consists of the initial linear layer, `ROUNDS_F / 2` external rounds, `ROUNDS_P` internal rounds and another `ROUNDS_F / 2` external rounds.
+/// An external round adds the round constants to all state elements, applies the S-box to all state elements and multiplies with the external matrix.
+/// An internal round adds the round constant to the first state element, applies the S-box to the first state element and multiplies with the internal matrix.
+///
+/// The trace records the state at the following points:
+/// * `input` - the input state of the permutation.
+/// * `initial_linear_layer` - the state after the initial multiplication with the external matrix.
+/// * `external_sbox_inputs[i]` - the state after adding the round constants of external round `i`, i.e., the inputs of its `T` S-boxes.
+/// * `external_states[i]` - the state after external round `i`.
+/// * `internal_sbox_inputs[i]` - the first state element after adding the round constant of internal round `i`, i.e., the input of its S-box.
+/// * `internal_states[i]` - the state after internal round `i`.
+///
+/// External rounds are indexed from `0` to `ROUNDS_F - 1` in the order they are applied, so indices below `ROUNDS_F / 2` are the rounds before the internal rounds.
+/// The S-box outputs are not stored, as they are the `D`-th powers of the S-box inputs. Consequently, `external_states[i]` is the external matrix applied to the S-box outputs of `external_sbox_inputs[i]`,
+/// and `internal_states[i]` is the internal matrix applied to the previous state with its first element replaced by the S-box output of `internal_sbox_inputs[i]`.
+/// The output of the permutation is `external_states[ROUNDS_F - 1]`, see [`Poseidon2Trace::output`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Poseidon2Trace<
+    F: PrimeField,
+    const T: usize,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+> {
+    /// The input state of the permutation.
+    pub input: [F; T],
+    /// The state after the initial linear layer.
+    pub initial_linear_layer: [F; T],
+    /// The inputs of the S-boxes of each external round, i.e., the states after adding the round constants.
+    pub external_sbox_inputs: [[F; T]; ROUNDS_F],
+    /// The state after each external round.
+    pub external_states: [[F; T]; ROUNDS_F],
+    /// The input of the S-box of each internal round, i.e., the first state element after adding the round constant.
+    pub internal_sbox_inputs: [F; ROUNDS_P],
+    /// The state after each internal round.
+    pub internal_states: [[F; T]; ROUNDS_P],
+}
+
+impl<F: PrimeField, const T: usize, const ROUNDS_F: usize, const ROUNDS_P: usize>
+    Poseidon2Trace<F, T, ROUNDS_F, ROUNDS_P>
+{
+    /// Returns the output state of the permutation, i.e., the state after the last external round.
+    ///
+    /// For an instance without external rounds, this is the state after the internal rounds or the initial linear layer.
+    #[must_use]
+    pub fn output(&self) -> [F; T] {
+        self.external_states
+            .last()
+            .or(self.internal_states.last())
+            .copied()
+            .unwrap_or(self.initial_linear_layer)
+    }
+}
+
+#[cfg(all(test, feature = "bn254"))]
+mod tests {
+    use super::Poseidon2Permutation;
+    use ark_ff::{Field, MontFp, UniformRand};
+    use ark_std::rand::thread_rng;
+
+    type Fq = ark_bn254::Fq;
+
+    // A toy instance over a different field, as it could be defined outside of this crate.
+    static CUSTOM: Poseidon2Permutation<Fq, 4, 5, 2, 1> = Poseidon2Permutation::new(
+        [MontFp!("1"), MontFp!("2"), MontFp!("3"), MontFp!("4")],
+        [
+            [MontFp!("5"), MontFp!("6"), MontFp!("7"), MontFp!("8")],
+            [MontFp!("9"), MontFp!("10"), MontFp!("11"), MontFp!("12")],
+        ],
+        [MontFp!("13")],
+    );
+
+    #[test]
+    fn custom_instance() {
+        let mut rng = thread_rng();
+        let input: [Fq; 4] = std::array::from_fn(|_| Fq::rand(&mut rng));
+        let output = CUSTOM.permutation(&input);
+        let mut state = input;
+        CUSTOM.permutation_in_place(&mut state);
+        assert_eq!(output, state, "permutation and in-place permutation differ");
+        assert_ne!(output, input, "permutation should change the state");
+
+        // Linear layer, external round, internal round, external round, computed by hand
+        let mut expected = input;
+        Poseidon2Permutation::<Fq, 4, 5, 2, 1>::matmul_external(&mut expected);
+        Poseidon2Permutation::<Fq, 4, 5, 2, 1>::external_round(
+            &mut expected,
+            &[5u64, 6, 7, 8].map(Fq::from),
+        );
+        CUSTOM.internal_round(&mut expected, Fq::from(13u64));
+        Poseidon2Permutation::<Fq, 4, 5, 2, 1>::external_round(
+            &mut expected,
+            &[9u64, 10, 11, 12].map(Fq::from),
+        );
+        assert_eq!(
+            output, expected,
+            "permutation does not match round structure"
+        );
+    }
+
+    #[test]
+    fn custom_instance_inverse_caches_constants() {
+        let perm = Poseidon2Permutation::<Fq, 4, 5, 2, 1>::new(
+            CUSTOM.mat_internal_diag_m_1,
+            CUSTOM.round_constants_external,
+            CUSTOM.round_constants_internal,
+        );
+        assert!(
+            perm.inverse_constants.get().is_none(),
+            "constants should not be computed before the first inverse"
+        );
+        let mut rng = thread_rng();
+        let input: [Fq; 4] = std::array::from_fn(|_| Fq::rand(&mut rng));
+        assert_eq!(
+            perm.permutation_inverse(&perm.permutation(&input)),
+            input,
+            "inverse does not undo the permutation"
+        );
+        let constants = core::ptr::from_ref(
+            perm.inverse_constants
+                .get()
+                .expect("constants are computed by the first inverse"),
+        );
+        assert_eq!(
+            perm.permutation(&perm.permutation_inverse(&input)),
+            input,
+            "permutation does not undo the inverse"
+        );
+        assert!(
+            perm.inverse_constants
+                .get()
+                .is_some_and(|c| core::ptr::eq(c, constants)),
+            "constants should be reused by later calls"
+        );
+        assert_eq!(perm, CUSTOM, "cached constants should not affect equality");
+    }
+
+    #[test]
+    fn custom_instance_trace() {
+        type Perm = Poseidon2Permutation<Fq, 4, 5, 2, 1>;
+        let mut rng = thread_rng();
+        let input: [Fq; 4] = std::array::from_fn(|_| Fq::rand(&mut rng));
+        let trace = CUSTOM.permutation_with_trace(&input);
+        assert_eq!(trace.input, input, "trace does not record the input");
+        assert_eq!(
+            trace.output(),
+            CUSTOM.permutation(&input),
+            "trace output does not match the permutation"
+        );
+
+        let mut expected = input;
+        Perm::matmul_external(&mut expected);
+        assert_eq!(
+            trace.initial_linear_layer, expected,
+            "wrong state after the initial linear layer"
+        );
+
+        // First external round
+        Perm::add_rc_external(&mut expected, &[5u64, 6, 7, 8].map(Fq::from));
+        assert_eq!(
+            trace.external_sbox_inputs[0], expected,
+            "wrong S-box inputs in the first external round"
+        );
+        Perm::sbox(&mut expected);
+        Perm::matmul_external(&mut expected);
+        assert_eq!(
+            trace.external_states[0], expected,
+            "wrong state after the first external round"
+        );
+
+        // Internal round
+        expected[0] += Fq::from(13u64);
+        assert_eq!(
+            trace.internal_sbox_inputs[0], expected[0],
+            "wrong S-box input in the internal round"
+        );
+        expected[0] = expected[0].pow([5]);
+        CUSTOM.matmul_internal(&mut expected);
+        assert_eq!(
+            trace.internal_states[0], expected,
+            "wrong state after the internal round"
+        );
+
+        // Second external round
+        Perm::add_rc_external(&mut expected, &[9u64, 10, 11, 12].map(Fq::from));
+        assert_eq!(
+            trace.external_sbox_inputs[1], expected,
+            "wrong S-box inputs in the second external round"
+        );
+        Perm::sbox(&mut expected);
+        Perm::matmul_external(&mut expected);
+        assert_eq!(
+            trace.external_states[1], expected,
+            "wrong state after the second external round"
+        );
+    }
 }
@@ -0,0 +1,173 @@
+//! R1CS gadgets for the generic Poseidon2 permutation.
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::FieldVar, fields::fp::FpVar};
+use ark_relations::gr1cs::SynthesisError;
+
+use super::Poseidon2Permutation;
+
+impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const ROUNDS_P: usize>
+    Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>
+{
+    fn sbox_var(input: &mut [FpVar<F>; T]) -> Result<(), SynthesisError> {
+        input.iter_mut().try_for_each(Self::single_sbox_var)
+    }
+
+    fn single_sbox_var(input: &mut FpVar<F>) -> Result<(), SynthesisError> {
+        match D {
+            3 => {
+                let input2 = input.square()?;
+                *input *= input2;
+            }
+            5 => {
+                let input2 = input.square()?;
+                let input4 = input2.square()?;
+                *input *= input4;
+            }
+            7 => {
+                let input2 = input.square()?;
+                let input4 = input2.square()?;
+                let input6 = input4 * &input2;
+                *input *= input6;
+            }
+            _ => {
+                *input = input.pow_by_constant([D])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The R1CS equivalent of `matmul_m4`. Only consists of linear operations, thus does not add any constraints.
+    fn matmul_m4_var(input: &mut [FpVar<F>]) {
+        let t_0 = &input[0] + &input[1]; // A + B
+        let t_1 = &input[2] + &input[3]; // C + D
+        let t_2 = &input[1] + &input[1] + &t_1; // 2B + C + D
+        let t_3 = &input[3] + &input[3] + &t_0; // A + B + 2D
+        let t_4 = &t_1 * F::from(4u64) + &t_3; // A + B + 4C + 6D
+        let t_5 = &t_0 * F::from(4u64) + &t_2; // 4A + 6B + C + D
+        let t_6 = &t_3 + &t_5; // 5A + 7B + C + 3D
+        let t_7 = t_2 + &t_4; // A + 3B + 5C + 7D
+        input[0] = t_6;
+        input[1] = t_5;
+        input[2] = t_7;
+        input[3] = t_4;
+    }
+
+    /// The R1CS equivalent of [`Self::matmul_external`].
+    fn matmul_external_var(input: &mut [FpVar<F>; T]) {
+        match T {
+            2 => {
+                // Matrix circ(2, 1)
+                let sum = &input[0] + &input[1];
+                input[0] += &sum;
+                input[1] += sum;
+            }
+            3 => {
+                // Matrix circ(2, 1, 1)
+                let sum = &input[0] + &input[1] + &input[2];
+                input[0] += &sum;
+                input[1] += &sum;
+                input[2] += sum;
+            }
+            4 => {
+                Self::matmul_m4_var(input);
+            }
+            8 | 12 | 16 | 20 | 24 => {
+                // Applying cheap 4x4 MDS matrix to each 4-element part of the state
+                for state in input.chunks_exact_mut(4) {
+                    Self::matmul_m4_var(state);
+                }
+
+                // Applying second cheap matrix for t > 4
+                let stored: [FpVar<F>; 4] = core::array::from_fn(|l| {
+                    (1..T / 4).fold(input[l].clone(), |acc, j| acc + &input[4 * j + l])
+                });
+                for (i, s) in input.iter_mut().enumerate() {
+                    *s += &stored[i % 4];
+                }
+            }
+            _ => {
+                panic!("Invalid state size");
+            }
+        }
+    }
+
+    /// The R1CS equivalent of [`Self::matmul_internal`].
+    fn matmul_internal_var(&self, input: &mut [FpVar<F>; T]) {
+        // Compute input sum
+        let sum = input.iter().fold(FpVar::zero(), |acc, s| acc + s);
+        // Add sum + diag entry * element to each element
+        for (s, m) in input.iter_mut().zip(self.mat_internal_diag_m_1.iter()) {
+            *s *= *m;
+            *s += &sum;
+        }
+    }
+
+    /// The R1CS equivalent of [`Self::add_rc_external`].
+    fn add_rc_external_var(input: &mut [FpVar<F>; T], rc_e: &[F; T]) {
+        for (s, rc) in input.iter_mut().zip(rc_e.iter()) {
+            *s += *rc;
+        }
+    }
+
+    /// One external round of the Poseidon2 permutation in R1CS.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the S-box outputs fails.
+    fn external_round_var(state: &mut [FpVar<F>; T], rc_e: &[F; T]) -> Result<(), SynthesisError> {
+        Self::add_rc_external_var(state, rc_e);
+        Self::sbox_var(state)?;
+        Self::matmul_external_var(state);
+        Ok(())
+    }
+
+    /// One internal round of the Poseidon2 permutation in R1CS.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the S-box output fails.
+    fn internal_round_var(&self, state: &mut [FpVar<F>; T], rc_i: F) -> Result<(), SynthesisError> {
+        // add internal round constant
+        state[0] += rc_i;
+        Self::single_sbox_var(&mut state[0])?;
+        self.matmul_internal_var(state);
+        Ok(())
+    }
+
+    /// Enforces the Poseidon2 Permutation on the given state in R1CS.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the S-box outputs fails.
+    pub fn permutation_in_place_var(
+        &self,
+        state: &mut [FpVar<F>; T],
+    ) -> Result<(), SynthesisError> {
+        // Linear layer at beginning
+        Self::matmul_external_var(state);
+        let mut round_constants_external = self.round_constants_external.iter();
+
+        // First set of external rounds
+        for rc_e in round_constants_external.by_ref().take(ROUNDS_F / 2) {
+            Self::external_round_var(state, rc_e)?;
+        }
+
+        // Internal rounds
+        for rc_i in self.round_constants_internal {
+            self.internal_round_var(state, rc_i)?;
+        }
+
+        // Remaining external rounds
+        for rc_e in round_constants_external {
+            Self::external_round_var(state, rc_e)?;
+        }
+        Ok(())
+    }
+
+    /// Enforces the Poseidon2 Permutation on the given state in R1CS.
+    ///
+    /// # Errors
+    /// Returns an error if allocating the S-box outputs fails.
+    pub fn permutation_var(&self, input: &[FpVar<F>; T]) -> Result<[FpVar<F>; T], SynthesisError> {
+        let mut state = input.clone();
+        self.permutation_in_place_var(&mut state)?;
+        Ok(state)
+    }
+}
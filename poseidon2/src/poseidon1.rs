@@ -0,0 +1,287 @@
+//! Circomlib-compatible Poseidon hash for the `bn254` field, based on [eprint.iacr.org/2019/458](https://eprint.iacr.org/2019/458).
+//!
+//! This module implements the original Poseidon permutation with the parameters used by [circomlib](https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom) and [circomlibjs](https://github.com/iden3/circomlibjs/blob/main/src/poseidon_reference.js), and thereby by Semaphore and iden3.
+//! This allows to verify and produce hashes of existing deployments while migrating to Poseidon2, within the same dependency tree.
+//!
+//! The instances for all state sizes `t2` to `t17` are exposed as `POSEIDON1_BN254_T*_PARAMS` statics in the respective modules. They use the S-box `x^5`, 8 full rounds and the partial round numbers of circomlib.
+//! [`poseidon`] supports 1 to 12 inputs, whose outputs are tested against circomlibjs. circomlib also supports 13 to 16 inputs, but the state sizes `t14` to `t17` are not cross-checked against circomlibjs yet,
+//! so [`poseidon`] rejects these lengths. Their permutations are only available through the respective modules.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let hash = poseidon2::poseidon1::poseidon(&[ark_bn254::Fr::from(1), ark_bn254::Fr::from(2)])?;
+//! ```
+//!
+//! The hash of `n` inputs uses the state size `t = n + 1`, where the first state element is initialized to zero and the inputs are placed in the remaining elements. The output is the first element of the permuted state.
+
+use ark_ff::PrimeField;
+
+pub mod t10;
+pub mod t11;
+pub mod t12;
+pub mod t13;
+pub mod t14;
+pub mod t15;
+pub mod t16;
+pub mod t17;
+pub mod t2;
+pub mod t3;
+pub mod t4;
+pub mod t5;
+pub mod t6;
+pub mod t7;
+pub mod t8;
+pub mod t9;
+
+/// The maximum number of inputs supported by [`poseidon`].
+///
+/// This is smaller than the 16 inputs of circomlib, since the larger state sizes are not cross-checked against circomlibjs yet, see the [module documentation](self).
+pub const MAX_INPUTS: usize = 12;
+
+/// The errors that can occur when hashing with [`poseidon`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum Poseidon1Error {
+    /// The number of inputs is zero or larger than [`MAX_INPUTS`].
+    #[error("invalid number of inputs {0}, expected 1 to {MAX_INPUTS}")]
+    InvalidInputLength(usize),
+}
+
+/// A struct representing the original Poseidon permutation.
+///
+/// The permutation is generic over the field `F`, the state size `T`, the S-box degree `D` and the number of full (`ROUNDS_F`) and partial (`ROUNDS_P`) rounds, like [`Poseidon2Permutation`](crate::Poseidon2Permutation).
+/// In contrast to Poseidon2, every round adds `T` round constants and multiplies the state with a dense MDS matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poseidon1Permutation<
+    F: PrimeField,
+    const T: usize,
+    const D: u64,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+> {
+    /// The t x t MDS matrix, applied as `state = mds * state`.
+    mds: [[F; T]; T],
+    /// The round constants of the full rounds.
+    round_constants_full: [[F; T]; ROUNDS_F],
+    /// The round constants of the partial rounds.
+    round_constants_partial: [[F; T]; ROUNDS_P],
+}
+
+impl<F: PrimeField, const T: usize, const D: u64, const ROUNDS_F: usize, const ROUNDS_P: usize>
+    Poseidon1Permutation<F, T, D, ROUNDS_F, ROUNDS_P>
+{
+    /// Creates a new instance of the Poseidon permutation with given parameters.
+    ///
+    /// # Arguments
+    /// * `mds` - The MDS matrix.
+    /// * `round_constants_full` - The round constants of the full rounds, the first `ROUNDS_F / 2` are used before the partial rounds.
+    /// * `round_constants_partial` - The round constants of the partial rounds.
+    ///
+    /// Invalid combinations of the const generics are rejected at compile time.
+    #[must_use]
+    pub const fn new(
+        mds: [[F; T]; T],
+        round_constants_full: [[F; T]; ROUNDS_F],
+        round_constants_partial: [[F; T]; ROUNDS_P],
+    ) -> Self {
+        const {
+            assert!(T >= 2, "unsupported state size T");
+            assert!(D % 2 == 1, "D must be odd");
+            assert!(ROUNDS_F % 2 == 0, "ROUNDS_F must be even");
+        }
+
+        Self {
+            mds,
+            round_constants_full,
+            round_constants_partial,
+        }
+    }
+
+    fn single_sbox(input: &mut F) {
+        match D {
+            3 => {
+                let input2 = input.square();
+                *input *= input2;
+            }
+            5 => {
+                let input2 = input.square();
+                let input4 = input2.square();
+                *input *= input4;
+            }
+            7 => {
+                let input2 = input.square();
+                let input4 = input2.square();
+                *input *= input4;
+                *input *= input2;
+            }
+            _ => {
+                *input = input.pow([D]);
+            }
+        }
+    }
+
+    /// The round constant addition of the Poseidon permutation.
+    fn add_rc(state: &mut [F; T], rc: &[F; T]) {
+        for (s, rc) in state.iter_mut().zip(rc.iter()) {
+            *s += rc;
+        }
+    }
+
+    /// The matrix multiplication with the dense MDS matrix.
+    fn matmul_mds(&self, state: &mut [F; T]) {
+        let input = *state;
+        for (s, row) in state.iter_mut().zip(self.mds.iter()) {
+            *s = row.iter().zip(input.iter()).map(|(m, x)| *m * x).sum();
+        }
+    }
+
+    /// One full round of the Poseidon permutation.
+    fn full_round(&self, state: &mut [F; T], rc: &[F; T]) {
+        Self::add_rc(state, rc);
+        state.iter_mut().for_each(Self::single_sbox);
+        self.matmul_mds(state);
+    }
+
+    /// One partial round of the Poseidon permutation.
+    fn partial_round(&self, state: &mut [F; T], rc: &[F; T]) {
+        Self::add_rc(state, rc);
+        Self::single_sbox(&mut state[0]);
+        self.matmul_mds(state);
+    }
+
+    /// Performs the Poseidon Permutation on the given state.
+    pub fn permutation_in_place(&self, state: &mut [F; T]) {
+        let mut round_constants_full = self.round_constants_full.iter();
+
+        // First set of full rounds
+        for rc in round_constants_full.by_ref().take(ROUNDS_F / 2) {
+            self.full_round(state, rc);
+        }
+
+        // Partial rounds
+        for rc in &self.round_constants_partial {
+            self.partial_round(state, rc);
+        }
+
+        // Remaining full rounds
+        for rc in round_constants_full {
+            self.full_round(state, rc);
+        }
+    }
+
+    /// Performs the Poseidon Permutation on the given state.
+    pub fn permutation(&self, input: &[F; T]) -> [F; T] {
+        let mut state = *input;
+        self.permutation_in_place(&mut state);
+        state
+    }
+
+    /// Hashes `T - 1` inputs the way circomlib does, i.e., permutes `[0, inputs...]` and returns the first element.
+    fn hash(&self, inputs: &[F]) -> F {
+        debug_assert_eq!(inputs.len() + 1, T, "wrong number of inputs");
+        let mut state = [F::zero(); T];
+        state[1..].copy_from_slice(inputs);
+        self.permutation_in_place(&mut state);
+        state[0]
+    }
+}
+
+/// Computes the circomlib Poseidon hash of 1 to [`MAX_INPUTS`] field elements.
+///
+/// The result is equal to `poseidon(inputs)` of circomlibjs and to the output of the `Poseidon(n)` template of circomlib.
+///
+/// # Errors
+/// Returns [`Poseidon1Error::InvalidInputLength`] if `inputs` is empty or contains more than [`MAX_INPUTS`] elements.
+pub fn poseidon(inputs: &[ark_bn254::Fr]) -> Result<ark_bn254::Fr, Poseidon1Error> {
+    let hash = match inputs.len() {
+        1 => t2::POSEIDON1_BN254_T2_PARAMS.hash(inputs),
+        2 => t3::POSEIDON1_BN254_T3_PARAMS.hash(inputs),
+        3 => t4::POSEIDON1_BN254_T4_PARAMS.hash(inputs),
+        4 => t5::POSEIDON1_BN254_T5_PARAMS.hash(inputs),
+        5 => t6::POSEIDON1_BN254_T6_PARAMS.hash(inputs),
+        6 => t7::POSEIDON1_BN254_T7_PARAMS.hash(inputs),
+        7 => t8::POSEIDON1_BN254_T8_PARAMS.hash(inputs),
+        8 => t9::POSEIDON1_BN254_T9_PARAMS.hash(inputs),
+        9 => t10::POSEIDON1_BN254_T10_PARAMS.hash(inputs),
+        10 => t11::POSEIDON1_BN254_T11_PARAMS.hash(inputs),
+        11 => t12::POSEIDON1_BN254_T12_PARAMS.hash(inputs),
+        12 => t13::POSEIDON1_BN254_T13_PARAMS.hash(inputs),
+        len => return Err(Poseidon1Error::InvalidInputLength(len)),
+    };
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::{UniformRand, Zero};
+    use ark_std::rand::thread_rng;
+    use std::str::FromStr;
+
+    #[test]
+    fn circomlib_one_two() {
+        // poseidon([1, 2]) from the circomlib test suite
+        let expected = Fr::from_str(
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+        )
+        .expect("Is in Fr");
+        let hash = poseidon(&[Fr::from(1u64), Fr::from(2u64)]).expect("valid input length");
+        assert_eq!(hash, expected, "hash does not match circomlib");
+        assert_eq!(
+            t3::permutation(&[Fr::zero(), Fr::from(1u64), Fr::from(2u64)])[0],
+            expected,
+            "hash is the first element of the permuted state"
+        );
+    }
+
+    #[test]
+    fn circomlib_ones() {
+        // poseidon([1; n]), computed with circomlibjs
+        let expected: [&str; MAX_INPUTS] = [
+            "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+            "217234377348884654691879377518794323857294947151490278790710809376325639809",
+            "1243904711429961858774220647610724273798918457991486031567244100767259239747",
+            "3697322215802076228208066929658130683674438861307808350825760082336385039729",
+            "7336984428078952600237169304321914358474313324708186973411450536267727944123",
+            "19202028150024867662780481379838409946689630430681355990725140707642055614070",
+            "15587463278332567001059474568613449501521353873733009860467391356348271308984",
+            "10625958540850080708582130653289575059817043739407999759992126519343968888964",
+            "6586682917253118491448025671540200955685514868114810493222463743094482488391",
+            "21154062150014026117492338523992511721617667294911573944785740169656578526614",
+            "200076502165720972416075356324368328696787933591141519854940792926668635035",
+            "9147049232282027787779787872110122248895227518511153992880076767178851520446",
+        ];
+        for (n, expected) in (1..=MAX_INPUTS).zip(expected) {
+            let expected = Fr::from_str(expected).expect("Is in Fr");
+            let hash = poseidon(&vec![Fr::from(1u64); n]).expect("valid input length");
+            assert_eq!(hash, expected, "hash of {n} inputs does not match");
+        }
+    }
+
+    #[test]
+    fn invalid_input_length() {
+        assert_eq!(
+            poseidon(&[]),
+            Err(Poseidon1Error::InvalidInputLength(0)),
+            "empty input is rejected"
+        );
+        assert_eq!(
+            poseidon(&[Fr::zero(); MAX_INPUTS + 1]),
+            Err(Poseidon1Error::InvalidInputLength(MAX_INPUTS + 1)),
+            "too many inputs are rejected"
+        );
+    }
+
+    #[test]
+    fn consistent_perm() {
+        let mut rng = thread_rng();
+        let input: [Fr; 5] = std::array::from_fn(|_| Fr::rand(&mut rng));
+        let output = t5::permutation(&input);
+        let mut state = input;
+        t5::permutation_in_place(&mut state);
+        assert_eq!(output, state, "permutation and in-place permutation differ");
+        assert_ne!(output, input, "permutation should change the state");
+    }
+}
@@ -0,0 +1,991 @@
+//! Circomlib-compatible Poseidon implementation for `t=10`, i.e., 9 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 10 8 60 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 10;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 60;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("5029285279710800539227619495938136407778783814400587102957398897867261120664"),
+        MontFp!("21661833903534656620291231766157513264428291380933208423519374035927473262119"),
+        MontFp!("21013170147855726227668315492699186959893088673047129690411646575996043835024"),
+        MontFp!("15893628062504267735591398483514002406192781085288489283447316241330749546879"),
+        MontFp!("9860639032243003377544947110034203265885715041305770375052648470285182020229"),
+        MontFp!("10431760628292478929366440566994655480900443273305000842144090945543100651218"),
+        MontFp!("4662341343242273661833461144031815716144681076466659112993661636426666579986"),
+        MontFp!("6674279191498784183427663914511569570797862586816649467168170855788360268943"),
+        MontFp!("16895097041920841073767278653214275321407577186751547609698446652984399225877"),
+        MontFp!("8168606076413192332279322347673356872630772122089948509553934257426773045038"),
+    ],
+    [
+        MontFp!("12091567755121016869657080116466607855522522017768906776539212195551888602502"),
+        MontFp!("4684576201081771194613696765517034834984066296253124029929753160055156611363"),
+        MontFp!("16693488266039456124835102259365515976900969074532557489095946797080826193662"),
+        MontFp!("7638443036775258881709317582832080783911189229963788890221615286494482929025"),
+        MontFp!("10111436214822932149781668218956845833675824936886829015449750181332010388640"),
+        MontFp!("896682691957564465177669890535917423987915406885797833670239687119295318467"),
+        MontFp!("12612639059115228106858238115822505521432423470330120640591982767272085175034"),
+        MontFp!("1851711744209473345586117150836616408053748535684022739058625441026889320297"),
+        MontFp!("14132260688735080257390420980422269734275443926576061985351678038992087770902"),
+        MontFp!("487493866037948515547037886552479973316400139387425953088274857424154262588"),
+    ],
+    [
+        MontFp!("7712516772901240105339429973116360243232161870164307482409826131312962380842"),
+        MontFp!("20295556720945067049585659016570679551265845058805648954004989969704769135170"),
+        MontFp!("378208946912325140295069471345064814132951473534378635003955801655986417900"),
+        MontFp!("15111601008893945567629460471315838423301021468457758533702272669431620017222"),
+        MontFp!("1503682435556321218669089857094247703956565058167121192612334331910088441071"),
+        MontFp!("13084874799693933186811120569396911285611047490876409383659779579088985591229"),
+        MontFp!("17464483161247836988344436558341194021876261750085348252730901647076441211862"),
+        MontFp!("6628743087463083391707355927377412170189936607932592258517748766250528223430"),
+        MontFp!("15153763588458144568353947674975114179172744555450771328418442212716084083525"),
+        MontFp!("11217853102739260248713425002157925483291370125178251466195670948291389406199"),
+    ],
+    [
+        MontFp!("11275485266433075885440484136400353724892671196084163231314370685019444807048"),
+        MontFp!("20167106354875398113371399754994549089359568833089630824992752829251678891797"),
+        MontFp!("14151330869211746069130604993916224881047448810615413435448712767752320095045"),
+        MontFp!("17260356243574396880210370581740651566334589568095587416844511054569255137183"),
+        MontFp!("12436078462666286197074526218535647721230687376129721353230123441759960021666"),
+        MontFp!("12001627458343654011606323250787666795709808266974343548842843520227918922255"),
+        MontFp!("15944850302839498288636342399223012131590208876255723227505947857641523034493"),
+        MontFp!("8444103924869263585176528654612076203716402818569041992813095331662367021655"),
+        MontFp!("13015682914180762871967848617514355587762125694235380084430680565032083402270"),
+        MontFp!("16200183380426364054409550129683752323493215428097334915015688753327665325485"),
+    ],
+    [
+        MontFp!("13717643109958965551675619584464549580820722892266661529182798599670194908199"),
+        MontFp!("19801725181447377274232761944437523251067599053402428862557912155522673980500"),
+        MontFp!("8260354277364856843022982286494019620277496829494935775254726797533957063267"),
+        MontFp!("124621144162335766862972192337737579448571172779117809776129849377329817478"),
+        MontFp!("16488884047551411705397223604196364132975353217876182634038895586664127388979"),
+        MontFp!("17336432076451490238716890901095007360946878388179175784603587179384718443321"),
+        MontFp!("1210338460555723584699132156502555539583432069430631008706741082485009017102"),
+        MontFp!("5933432012048351362807861976737945204535374770355507745694008880123055490802"),
+        MontFp!("5127952499969178010015035020598142881788437616516517827214405489972695632240"),
+        MontFp!("21100924218139544842807404598627913291698574448527131003096325470925085906016"),
+    ],
+    [
+        MontFp!("7683521602764604419863026286445694988900727173175219514555132623764360793654"),
+        MontFp!("20928394065137007852706990901925870323120588543710137320004640014111073449000"),
+        MontFp!("21375535333469484792161302750563386607223088895810564711097025913956371171769"),
+        MontFp!("8663517227154706072248636076587789834246541965140682871530851124960776424787"),
+        MontFp!("9182938389356039217318590654716613493414550996824701664670650439783557720226"),
+        MontFp!("8327338979442122743919832154397496089418582414082199116629974300650113777515"),
+        MontFp!("2474727241701323049333019668054716886184808783449917153147248751503852312804"),
+        MontFp!("8543922237501430855864877057711792269479294116675004771113148647309219620030"),
+        MontFp!("7863611214303285947093025404346084345102544167615769255495752297507346719791"),
+        MontFp!("1448902069752048144992778676670381235906144579949631101518897035253311063307"),
+    ],
+    [
+        MontFp!("19501657783346989621892787238946890715709847672294934508902622542828235185048"),
+        MontFp!("17076525025777667838921778388186176564387475624769926249793144074465528465933"),
+        MontFp!("2381176586418291387279201678056498732033435079507661703992537801751492053086"),
+        MontFp!("20723508866659831749949206314442193102431573526415976696387848305764994281574"),
+        MontFp!("17461795780729443663350296040956479984433953861306521086706732257263430387445"),
+        MontFp!("14849025218838139413138931958408289986915143240245452275066866730847749323920"),
+        MontFp!("21207204042106390965753782189145584243052148578812105334769740484186308017901"),
+        MontFp!("3105302592226642624386332562899903659948819667537402316192380465808886843623"),
+        MontFp!("8765266846991616382097124552983206033439769882065573909634090515268812396114"),
+        MontFp!("9950016446092650730639179912416912603745831292536616469358668786853463197224"),
+    ],
+    [
+        MontFp!("11739731747351277092817771330729393674312591071236310446088293450266807414263"),
+        MontFp!("5424991773995591044103668717299468589013142114099340604018933512575789323446"),
+        MontFp!("14582885509715812510585748465607279869582209618804039923778041514988867577359"),
+        MontFp!("12468934763690970929325823037406509081405444759649987929912706732364016057892"),
+        MontFp!("2792793293657306144108993077959195845478902430027171873963281969527327256602"),
+        MontFp!("8841327809851437433386666692145437950603022633472031964220924157605803799391"),
+        MontFp!("7845859360796082275932181771457755704129556353505380746504571839006944723429"),
+        MontFp!("10731793207832149137187382442869034250153492853628224932026933458041993639295"),
+        MontFp!("5597792614864287090861003890414825257635680048696075527563498604714157576447"),
+        MontFp!("2638669099010916296300870639816763122907432841565512299246441500223692345671"),
+    ],
+    [
+        MontFp!("7150832464835357604208338666096132398994318721877322228060899549998179405057"),
+        MontFp!("5470477812928960639347760417261508685840724903499112719517942324191018679706"),
+        MontFp!("1063854480993555660259858748055514950231824974684462401269695511649059715242"),
+        MontFp!("14508243449586598349750829047481358081191713699373322296041764577478835760927"),
+        MontFp!("14872220983064543437506211589956319796231014912750035729896461676577407407598"),
+        MontFp!("9523202653584689553554068772241228948237208444616905879849472383190180438058"),
+        MontFp!("10557133197819890801524243760013157188954914093770589635201319240903423455316"),
+        MontFp!("4973822148190287060777561091733583032026446820262414806412485028147721872972"),
+        MontFp!("12017319043066808147670914562193696608548297038020764496633388575589573229927"),
+        MontFp!("20958507279974171556413354796214800332148109902768069171659933168603089927180"),
+    ],
+    [
+        MontFp!("16142225389165963605704721785850680620029805525816101628767304750729950332962"),
+        MontFp!("21691255103889531967215183091383836488808797368461467004501598817850515277674"),
+        MontFp!("13360009791215314413428942977255018953699328534302248245107197249816193370823"),
+        MontFp!("5270206696221786165451075835596925139630328202641350960582852969440862939023"),
+        MontFp!("2626561181956261201864606929566987806068271006198808163435823619705436605447"),
+        MontFp!("5520368836328496672510351296660387187466158872913871354651108826881774455909"),
+        MontFp!("21597143280250120305740582323272730661347349587666707484376745221123282421748"),
+        MontFp!("5891209530846741397700015863630938364586207627850850447237189083999656313978"),
+        MontFp!("1202436381171550812585103405636986166232789491390007497511342220946215395818"),
+        MontFp!("9920320882147650877649039705433660083926352954797066179512349368247190410310"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("6377232663526537440095439257883018477761342422116697881186123375221738885878"),
+        MontFp!("851539971462439380385862352460596759101811723695394639617127852578681769809"),
+        MontFp!("8777577262325190174206575699458733195047013200879424709893142671840513604890"),
+        MontFp!("21694543997668766291509756109744969193435163886467863962355853609369758783238"),
+        MontFp!("9577278996811393500051721677710083593799044422389686435650597107832854019185"),
+        MontFp!("16323954252044716897246121150114593642230197187021287621193086593549237094775"),
+        MontFp!("9789909425016820105251161906130605326280235056822272235912508431951118212950"),
+        MontFp!("5766700650277227528545902607164112169119010038912902265869378685414299620760"),
+        MontFp!("14342521005374081251816746055115831251291272287569749723238975882435091047876"),
+        MontFp!("2566050045458470252423704003188705777658084864238473334290159653618543192811"),
+    ],
+    [
+        MontFp!("8762700051029310248153110133778709519032029454737126719215892745208105815416"),
+        MontFp!("4708553466520767412303631379034292236924119642035476122997253385705160556618"),
+        MontFp!("4755252554118675759917549980023743559070421272488077422007409392838436797712"),
+        MontFp!("8781462767081720534606018702554359272062136386754094559457527802951016005606"),
+        MontFp!("19167810216492792969016670752653089791475857662598893252819620255611011677188"),
+        MontFp!("12379801295054424513880366937656969081677178004556540562031393564676230427743"),
+        MontFp!("3873349522143254287251699452075145107916086554326675869006906246349942638560"),
+        MontFp!("1683302064923931554193379270562867202085645938091131834974486624990867609624"),
+        MontFp!("2777362700160137801933468204963311934247500177582714816722898763176642740860"),
+        MontFp!("5330041075666088752029210636784758218847391095319460299231210692948196701638"),
+    ],
+    [
+        MontFp!("11849341704739004206642161112350419905150271791787570525216826204427280723792"),
+        MontFp!("7477184099861050355308565098520563835117942875101546634259876195229073147282"),
+        MontFp!("18811741129290507103385501216699521500514849038287903802256059864942452310117"),
+        MontFp!("15644162092778325718506673614750051639809307056147336506838023349115605106787"),
+        MontFp!("15072682042494620166496832302000289519302436589952610199010633012972669445593"),
+        MontFp!("7385535266916101728534366006042662339391797772494836337087929961280561819695"),
+        MontFp!("10606300178546340442574451452231017670874690381506662581848460294140286741651"),
+        MontFp!("343808333592012682122858022517390819973432303579818622412786360520154826142"),
+        MontFp!("6686378289544833739489172513893542192299224296746579469451376125664696638046"),
+        MontFp!("9325668720082019512834072623751272154060473105966456255302021143714657867878"),
+    ],
+    [
+        MontFp!("13237356616132921941407245964289360960304194019926733744472216846697663447262"),
+        MontFp!("1723892942664599421365079138681309575413323508685958773158319650163306910931"),
+        MontFp!("6845174279248890961319599668687600484787455619619716546069389087383603254137"),
+        MontFp!("14429592766972645051919899517480716657546426049902884218808698177731678278944"),
+        MontFp!("2012555589304829161260427679955782678928810146332132910441113793264100264511"),
+        MontFp!("15162287124358358727307007568219331690174000191414576263088727973180750593247"),
+        MontFp!("6171544970310792508799412092397912280594923286679674322244394636145740843662"),
+        MontFp!("11560360683323732335070294251287274796083850957500974817278726137032659811346"),
+        MontFp!("7954890646285422519425515982220441977570181574595597355546782742910060927756"),
+        MontFp!("2121066076676892095526555416241546752515994960009188371572036715916593676611"),
+    ],
+    // Second full
+    [
+        MontFp!("1165301643600280009322317413038719889346104283514223548696059636052018656756"),
+        MontFp!("19642265897347119192967952847445594557926149874981543320111550169192389374838"),
+        MontFp!("18546070171142820328487316603855788669722411491547154393382552167546945234264"),
+        MontFp!("7547722208697813989490512139624144757215998986985181672151192221427497361833"),
+        MontFp!("21765466939205480830726051696775103538365853268148356693813986094780619132689"),
+        MontFp!("18066916718526031105430041386116904278122614509807635157495047212689329422097"),
+        MontFp!("5567247965934318360602471432895130358745239227464728512128895794990091560111"),
+        MontFp!("5268155242325913085615961568740884974081158441192412337454790453319219641639"),
+        MontFp!("15275184776514682919662155241139211655693258637032715569152004434925162363584"),
+        MontFp!("2179932316974455074499483491372661376888522799119184614149917286749483028849"),
+    ],
+    [
+        MontFp!("4621405833740121725678441855520519041228014222881228746009997774516764304847"),
+        MontFp!("18479339496682302897017710580780845640172065272400836860950352488372424120096"),
+        MontFp!("18557033214894447033261016367420154444088029564584596463030003672186270737859"),
+        MontFp!("17200647521217215683722635843299601233863161230648748208816378327209201596867"),
+        MontFp!("2688200399163263049122461117289599907708945179806531635658550456745433215543"),
+        MontFp!("17415923022624960613510570384321832458760537169605919976471719634183381965151"),
+        MontFp!("8535568676654346647569376193583594529721160524940659464514765031328360321359"),
+        MontFp!("2988350233499987577118772810582267364198360724494195915351933486792072227259"),
+        MontFp!("6210620559937378902878629998722853418159056595764204463986805098114518009318"),
+        MontFp!("18310997638100731876764014221687199913435941820864752235028122857262977059320"),
+    ],
+    [
+        MontFp!("13808863093029019329144013239638801163784179422135484562563156294580677444841"),
+        MontFp!("12914098780625672320372746314998075565042263956380764233047047668841824625393"),
+        MontFp!("3305953095296069725576430940963322680842934544212876613078125492458854044556"),
+        MontFp!("17822002370183286453254419050088898676184956527990121400547957986510775899261"),
+        MontFp!("14649614548507754711891632411080554775651572143141206447218484052001553765684"),
+        MontFp!("8109105787614676314388565737575005920261787167569229710081822989465469152070"),
+        MontFp!("10883787415840547710621559198296269932318487849486120162467244238276174398529"),
+        MontFp!("19330421887539219495728995383446553835046303504116353064676615752269857951508"),
+        MontFp!("10124601385542856860656799235536724683363368315551234368605365576161456958605"),
+        MontFp!("12586681054057195636996904714881904902720157016325364273875514276164279959735"),
+    ],
+    [
+        MontFp!("3757165144866249284026269096089043031459271594379869902321741804365836799544"),
+        MontFp!("462405354647627586345675602760959074379157140949064478801734872040710235207"),
+        MontFp!("1920260561025181767428737283255569518557618670101985233779064285550376374001"),
+        MontFp!("16427781514757709627041602025461703818581117100125480080881962557048804215419"),
+        MontFp!("11285710669637766333985664845741699850953316015510407645702772350620723934913"),
+        MontFp!("12651159368793078896654108663982020308442449053255003548814357252262150375808"),
+        MontFp!("5416603170144910655254279621683623582654125103494176617805338836184837812634"),
+        MontFp!("11735378404903665808621855768545179642393017572035737886573368447383433091210"),
+        MontFp!("12201557541429330882554855185927797569372240536806738921957792139394552229540"),
+        MontFp!("12315234857861194987612137097457466405047598720463755079786381945885951345726"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("1030002705665772802770205305890036009903459272665864721338890073927102958060"),
+        MontFp!("16664112528630425414995233349042921759383114978671010908728891678245502701008"),
+        MontFp!("16224205339340335222764551648549356562936176805367408466634736640263613613659"),
+        MontFp!("5567916191875465998022755280584031341089937668974064792042640432034217833475"),
+        MontFp!("10561503261915825576621563677167739482566911623771072553907339314680805249099"),
+        MontFp!("1281495108038254322634503929806042733441491866895195579580212370919762081047"),
+        MontFp!("9600700315845518751455006692480832601523246124684033595437676082879283709816"),
+        MontFp!("15989333248905201890715282122260615227836016238448185882687783867814184655170"),
+        MontFp!("3846245593630362971844915233982274952290718501967612724027782949411933001202"),
+        MontFp!("9981027954269438386336412342904724691774209648042702865994578173145958992921"),
+    ],
+    [
+        MontFp!("8623595877941915162474742420309695649920307514068323484728910858137792561119"),
+        MontFp!("21103940025922636831675399050467233863786411927021772979799068688191712316972"),
+        MontFp!("18892924253208304354853962839524897599416779246859691035714354037906441368765"),
+        MontFp!("17137414752196927825772499610314584261795745874954692214656847237011815603711"),
+        MontFp!("20412422497099028107138997806006051244688526968840932637543351831550882135155"),
+        MontFp!("11225636734520002481404086272590673372060731353304957503311626880321065568136"),
+        MontFp!("18380442589598191047463232737740533198002604231823107797039491680652883496794"),
+        MontFp!("1080201698768913889646664841066956319958767123758689784419321296338840961295"),
+        MontFp!("14348402455238680465583355269916779409600823120873923092214453448424409970818"),
+        MontFp!("3841435364722615893087024818655055436552226081083242159440517874888324292581"),
+    ],
+    [
+        MontFp!("3408210599862246992363134715624815235769905293647431849706383726509064300506"),
+        MontFp!("12828232946525727915578787875290899244261094596690184893334123105536745936334"),
+        MontFp!("5483797730688489537248191960281635992343565537360149542110268773175134131314"),
+        MontFp!("15646042484365011867018844828962923289034117590475224947755290094723626891273"),
+        MontFp!("2658047411395048849255440353544966399245817841159701280361972904541325691434"),
+        MontFp!("19496407504291857422030801612379213952698163884670351003527359060477191854359"),
+        MontFp!("11599969200544990318778456235768317543324325704256981991953010275435791017626"),
+        MontFp!("12534635949431553834868179572769836881352677117158862189611147293522496413113"),
+        MontFp!("14223314197724082301050736397492110631416043159307338723464864105007185825079"),
+        MontFp!("19822547161504065277026677714514212915462043072809743766437313193660041742198"),
+    ],
+    [
+        MontFp!("18248624683501549165279508462273639851850239430868786828229911137041335077425"),
+        MontFp!("1772507929668430466250295341031184282507314702999122972093244182511342701791"),
+        MontFp!("12698826328883589821004991321815018437184890565199562478819948777685095680390"),
+        MontFp!("2107256591274868946942358544310209350935597133418111664653447540003390113607"),
+        MontFp!("8347096431391887603955816523197766644723983907921702200049607244690524226105"),
+        MontFp!("7546736802459880596530318577784618006564482951653292089248497980343037655783"),
+        MontFp!("7337317896163766810388205540011597034395961854295494001017429381228506327036"),
+        MontFp!("7657535373588628884973408484470050620893383237179421367090832333743641042323"),
+        MontFp!("13132621069544809006163499228792832380417930375502811639756010731409020775733"),
+        MontFp!("3045981446877420174701593721028589257508837379178848429319604486793747007869"),
+    ],
+    [
+        MontFp!("1665034234802535695418712526119528879364535660712727125979361452433857586005"),
+        MontFp!("7153904853002570654968228858836861252211159237410458977141045356668538557384"),
+        MontFp!("20486065252261216388496191302294274939758504298167574880569796877079451248375"),
+        MontFp!("21146476302842253436461025615017889905755773199293419435978649511293941220143"),
+        MontFp!("4692883070549935264853696204165792104522817067387529940796053065681435988854"),
+        MontFp!("6240088307004733902463222083449545201088681283438656231355545734118814247048"),
+        MontFp!("11555561118019341206516697020813127391202363312629469259248586095720628837415"),
+        MontFp!("19260547999655668000047734411254185932378393753746099027853756019009568507886"),
+        MontFp!("20469506109273046972497148219051635976793704979896239651651205124084812945608"),
+        MontFp!("1236647274759658638933992315999684238758461477931896092313814863963831171033"),
+    ],
+    [
+        MontFp!("11384423918232921171964979139440160725835135313593824548598134875347314405204"),
+        MontFp!("19785250372370249720518667471906851686135385809175031332352733767471970846466"),
+        MontFp!("4246521523867165828929729227115582186945308737715737226156399485201514735146"),
+        MontFp!("9952732737001449699912255226665360960719170484486452179287528363081995818191"),
+        MontFp!("16411145939754797754686024918808322973332629854064127851496226070432060579489"),
+        MontFp!("18478056933955827759744830164752062474839918604932227276753757763884050277828"),
+        MontFp!("13204687970556138498219183195522996570326298997850204255083662628089078309770"),
+        MontFp!("6486083806326529246393301553077241033740361238170679962888274443184188794118"),
+        MontFp!("9215573806816888307072467120643373006129084289252457249266574218300367297487"),
+        MontFp!("5157456141970297671458245970390083650482632128904852982724214364911239574334"),
+    ],
+    [
+        MontFp!("17822680498490868828738779948851745357227318213932493146619109948725716270324"),
+        MontFp!("8322423511882718045936027421959946860221136505721064786938368517081088404769"),
+        MontFp!("1146280240837664421126981150154328736275224000612293306261498532925677882509"),
+        MontFp!("20006445160687044351950305884447426432577260116801089758873885688911862838124"),
+        MontFp!("13132217654381318972692935199671458140461723506405656953229384472487720023845"),
+        MontFp!("2321904844688587860096390475332685957247396755436885306389445060312694195758"),
+        MontFp!("17673723499361727802425795357032445257876321564734597671004472729727016538966"),
+        MontFp!("17836648739374245973743495166940645001620159031723548669336786509810303589036"),
+        MontFp!("19509523664323410269318198214695596019790796169778932865716910251136766472034"),
+        MontFp!("21365014298519541792222476772118358897898683224332026502540401938408420183049"),
+    ],
+    [
+        MontFp!("2443777802329356458012563966932795162347891060116795715814546844741577072487"),
+        MontFp!("6373148417441446230918754690291753760232604931431996749195267137425734054207"),
+        MontFp!("17543938461501434657363693054851238526018672792888706636605942303973500302856"),
+        MontFp!("13900881200135928840365427722717255359580153642574547892815287601924416317614"),
+        MontFp!("9982616108044216660339683982954165936737826707471259937628917232293660834440"),
+        MontFp!("16457765153339087464480638859689501343872608914611554385236118860039346779707"),
+        MontFp!("6882633521418674793651640056518599843365736128725139938457347614662141371026"),
+        MontFp!("6558481420440543921623853603383694405865402572023705828527015346924767906364"),
+        MontFp!("21119564418700154632542186570611885700968350571284986971813890102274419338575"),
+        MontFp!("15668498634043584871060292933787839904928585875204605204028656239629550300891"),
+    ],
+    [
+        MontFp!("17754567428791571673016885915321661773655444664247443414002133544771398853149"),
+        MontFp!("11486839919314218506003227795241691164988634920977758623356018460082101365168"),
+        MontFp!("7521215937712438604222096500164256001666624136838511497877267672752282058366"),
+        MontFp!("1168385489601974578347279341199760237159478798101796567718644776242789923601"),
+        MontFp!("13117296414138131801834212262010987517820472685866772554743932452738843734333"),
+        MontFp!("7749628482107487230728683638475509704638633069294493147970362304145477016517"),
+        MontFp!("17102526463093059579604328209955502975564943362848110499048688895825859834607"),
+        MontFp!("11877937469390065191819717631885427975032604373385026940117533544572408798485"),
+        MontFp!("9490483077873795676333795591814325768750891664453067983811838122306462917887"),
+        MontFp!("10191097995163502256819397252907242166733175440759424521233105453843778654820"),
+    ],
+    [
+        MontFp!("20532048353899648065110204116821557712236052515292993984913500569982446829913"),
+        MontFp!("17354150523998248091397848718695616500251280749145663998809707730330346369346"),
+        MontFp!("7747355680464214426243190602078017576496231133574539162185621451748634683393"),
+        MontFp!("8756715326391069596985357282435500586860011252775122994945356255643854963530"),
+        MontFp!("21536474090524236379254986352553305027867958936847041677436373000730213533274"),
+        MontFp!("19764807787330426181831011653714787282097837960248105858049804952757136862708"),
+        MontFp!("16451394978386784206980716591328721244692005310628203853347165858510983157051"),
+        MontFp!("9958807580185090358651106618892828843813431270139145526116189671892797920190"),
+        MontFp!("8667474404638571999095228348352836564567923532278597424241711463350637692261"),
+        MontFp!("9754527193113710990714009078343561220541479581342251031659489697296746296505"),
+    ],
+    [
+        MontFp!("11755501121260346797952875679164705763574741595098729931802001980529024314166"),
+        MontFp!("17343653273660706017905395390969914833245644319150049521732399082825162090569"),
+        MontFp!("1010378412861729818622385301577181571311206842734096023690850995284550560689"),
+        MontFp!("12422787992288066268619146495902983268274452848893191113634050431879037454803"),
+        MontFp!("13351916057777123695069150362950067630454763506856720439068955445038740053267"),
+        MontFp!("3268740447474291563746626019604727880178668296496938516526099943483641022899"),
+        MontFp!("17039539378002212101604857371251026489627400179253250833603358068705093844865"),
+        MontFp!("12719626976782614661983190476189661930540289684710840404911817755168256363569"),
+        MontFp!("7334691511591452788631693316255271478502517924558055271367172394856435073355"),
+        MontFp!("4177321927122082121158728850724807513004613701936483594734414988911849675880"),
+    ],
+    [
+        MontFp!("8517156232219806038206488131493677748028421797072860831547349043281348142926"),
+        MontFp!("5916342138159497146131772146268734765710150180676587683832045824388433290036"),
+        MontFp!("21136252379072914855830890952695340864582847490462136128874077543348574696616"),
+        MontFp!("10470343058787342159878702644341062172468027793693540114390435145428370707552"),
+        MontFp!("9367903847960780027900264774906616911120367129803429048363499797310012009648"),
+        MontFp!("9181708529218875829085211480957344367690955470310754169594385873272587183681"),
+        MontFp!("5161879954208731149141751476094480416185338457043041781556700389106447006281"),
+        MontFp!("14144603730790033561496908848503636331176898859925995171200576238014458649562"),
+        MontFp!("17528475461722173509900495818763366567939364295306035018228011778228457876695"),
+        MontFp!("449678858200791083139507971543902712490268199763356935472396275788444419520"),
+    ],
+    [
+        MontFp!("16677139862336739737616497869711537864422474730319606529800927093596292773684"),
+        MontFp!("5307258894824770781811695261046705160386034275400321369052582330095447609528"),
+        MontFp!("7066455454850758706236264136180958260707829859148436565416678574940588976717"),
+        MontFp!("17464972536694182038180604993012612781624892485542021128713500864406129068272"),
+        MontFp!("1827574381303563082711258077787177320452649985803311391711223171032580182910"),
+        MontFp!("5005224603218153811845694200896301756909926774436577539307979565859865998867"),
+        MontFp!("13761569869627153404623558442531816440237410187883129666707204029577726405280"),
+        MontFp!("7377645231791556592153877817212695036000405157132509030366572797100109551371"),
+        MontFp!("18929000938053222386693378771208400814166536156735504775432130183653620422676"),
+        MontFp!("10277912490419902146704238375228669373848088391763413912903062245600018539005"),
+    ],
+    [
+        MontFp!("18100670309576234559559738539779745691447892297890679181953368845735547051936"),
+        MontFp!("19792596568315968292503371918803163404916721074550555295039247624601141857094"),
+        MontFp!("6568943725774928078767297883788056758405958920376813493652098209237338819058"),
+        MontFp!("9708751028820311560873537735196353024741491886920686045780276405320332052014"),
+        MontFp!("20999195425108372543557431885250084840784235258564621629580763574508120639473"),
+        MontFp!("4668167020556593094762451685509419403613005848434427529155545560914783238805"),
+        MontFp!("10260166712816802791730167674468655124354432849926388591536360669342400828562"),
+        MontFp!("3588854010476278115364011192514859807683141010842317346600561233941024545452"),
+        MontFp!("955642453625490778540666824328669289325333312941525596301926803494785606357"),
+        MontFp!("4102026113333601512449185655242077481750021570821512149654744152647996823622"),
+    ],
+    [
+        MontFp!("12901729067332459436197297782018174449541114313233855185609986472102830633274"),
+        MontFp!("1432400515841095916662233518576616625504866912337953671606919993429647264779"),
+        MontFp!("13448330934580056368019676193029108114576729981976748604441994201646531786832"),
+        MontFp!("18698774355061680075847219006041299002465669495077065798256778682757699200357"),
+        MontFp!("6114255237222848342826972972054203376750041293553715842262723528673741797160"),
+        MontFp!("8290432657858704891136963220676191057527195528510584103201077577863568506432"),
+        MontFp!("10245893420315465808958329213978599152040050245022584266757834865645078424612"),
+        MontFp!("16235075160725310956334026818354575166666144493784149178325740173109469893465"),
+        MontFp!("6096223185593495310139379444667947750109489326578429517185410779366192202063"),
+        MontFp!("15140535409353326038030605492985074291044727716595244779768492745470176024609"),
+    ],
+    [
+        MontFp!("2176086602170005476358821348469239586548222322021168089824748815230407069862"),
+        MontFp!("13619789468668594404222482251836770591464929359372018436289664558758704681508"),
+        MontFp!("8310543107961996371575168146304294641952910047046695374038288287522235989972"),
+        MontFp!("7738370036488385965043396010845927300705713772735513648600973583706126470834"),
+        MontFp!("4479339978160586717158719172802732929733916533373426058515465717943672025882"),
+        MontFp!("4249199078635815430904933856748414549211196022798648243994671515262509861644"),
+        MontFp!("4217767457132611540965149700331359170343442048612092095364557557503970133933"),
+        MontFp!("13153296757017742961007840475261159306564053749538202546045661292791402275573"),
+        MontFp!("4396888098805340064553349190742819413046668458070694926676742098287729413444"),
+        MontFp!("16734434548572604008363129496559919254718993826492605430831689587940707338899"),
+    ],
+    [
+        MontFp!("9015659000250675923210953833943081286931414181352124970688626484896488861379"),
+        MontFp!("7859006238840384066905305454236928888746240833462349287375399918976884871408"),
+        MontFp!("20111156231978127386472936347996655414872475039138926155687856551161732442682"),
+        MontFp!("20628144438246471187747981572742727430082255446467380395647482352559593647287"),
+        MontFp!("10829450719086027299358038584474043478547531034689618353624096053194488185624"),
+        MontFp!("3900905003848877440680433966861518022290921312029158328541666844523704712962"),
+        MontFp!("17855611209216805679188603521771950395726550847102335142668673883933213178620"),
+        MontFp!("3545647030011914165273791133303433140616659042668342263053968795372726840341"),
+        MontFp!("12285059161807384662955183653994648298401051593713604819454219983692697182696"),
+        MontFp!("11819552939527124997493513022814576182004246358800352547817016502521627790011"),
+    ],
+    [
+        MontFp!("14301577158059901977856927221571457807294693699285069296999743154546478489569"),
+        MontFp!("3571634356329355229397931369891424491520267531585441552581855575412868351910"),
+        MontFp!("4493956823795845864156868703591503758707793967931549819151350879135230170242"),
+        MontFp!("18261935892851512416084887686097384445742684392402688030129684935728742717201"),
+        MontFp!("11603603642132262206403092178979219208473125803069223229133184466185736048060"),
+        MontFp!("4847487817017177565347080569283215504545281846426948697937793918200171528656"),
+        MontFp!("4069745589764729706654816299792297539062872486670505242875943264008484198412"),
+        MontFp!("21282151145529600768369623290936085172906963145870658008436208961308948924585"),
+        MontFp!("10721916615176439690683002129869911178402752315827226965537395702918089626824"),
+        MontFp!("20400924989628432852029073867249809947097995745931036434033577251949709693425"),
+    ],
+    [
+        MontFp!("13612038717302251316998414209460162307179960669236088330389280060785328588738"),
+        MontFp!("2142054298626034610320009155682451576863946725173133307732467701538715335347"),
+        MontFp!("6814074799679801916559787533428482395152765022569535278039545933747386331226"),
+        MontFp!("3320993272550636151137746220977818986579019792097013138902071906802378678391"),
+        MontFp!("14404588996507110096126959822135132305375204264088975725278990285893078946890"),
+        MontFp!("19449156048766944910033639666691724350749810714682158108539166281157709899569"),
+        MontFp!("21600390672948543610212878389553096169635598817477527886039588952230732642418"),
+        MontFp!("16122909565998431497578901034409662715618749437754826295511086000491610510803"),
+        MontFp!("3814026203802323919937341565517130280297397500968227915639071188757380515963"),
+        MontFp!("6912908852134560099979027891279882003635111070588372993239339154823206466274"),
+    ],
+    [
+        MontFp!("8991012532130902495044589989450658026455069044478725949828656540931441650779"),
+        MontFp!("19794616058753707346170576299297623557371037336156984230370345545981446397931"),
+        MontFp!("2577593820399732466692625387687370505160505291664134162589397465829320209836"),
+        MontFp!("10545990182245838392125531729060296377668723705525703355179325185018108067002"),
+        MontFp!("12532592142366733026886391992589159605208721772700692225488484422366892623887"),
+        MontFp!("19135911891605926936423877585461852787990719411437518367185457251216578059981"),
+        MontFp!("11635603342092216271740512684448806260427922119693878652222869987036671146111"),
+        MontFp!("7718247137511759231158297248913810065531288952022630354624599924037308251451"),
+        MontFp!("15449533941190926955831618735652142785144234166497064450979389633622292725920"),
+        MontFp!("14793399192194938994493676084408874396657844744757917843286252680102153699293"),
+    ],
+    [
+        MontFp!("9379880417179271734210305738187417887144762048262218697049423795232738616822"),
+        MontFp!("10001585874846875226646763599153358317197291234293545431914341192928883246454"),
+        MontFp!("17564611789675170872923370710570629576001514727841256489502945348076745512773"),
+        MontFp!("13526676577413987112607573245391605865887231830025935262602066777025060147701"),
+        MontFp!("18771091487566471187260929156402254761992313431761027006794035379840343952064"),
+        MontFp!("17672790933843778353408361605666344788858296349839035375070469185645819063077"),
+        MontFp!("10136925806345148466019786355963896194230642602748938687391144254701550469628"),
+        MontFp!("11669469369568255529354542182318275079656673415035219767446071893709388727608"),
+        MontFp!("8717156787967537877037123555054580463721012975068417092258849467938967272751"),
+        MontFp!("3574689732222366081898222156809576015147776290993716837975298246375762980084"),
+    ],
+    [
+        MontFp!("2936447189567283726966987004410103389002644634186413346432130822474131530801"),
+        MontFp!("1699723231413680239740710996339133622208402062115605498128909025253321290927"),
+        MontFp!("20239438661176091033530372775196947702401783521122338633601531691101072063415"),
+        MontFp!("509222199143055079823531599510182326541217708021228426946045537726376153595"),
+        MontFp!("12460587031004227589188413497497959758507406039249371767737225675508588807598"),
+        MontFp!("9172361948368872306701383997811949982264909388810393902913358291336142380374"),
+        MontFp!("15024321518919789320143737927991052999071746110692384451602809887435324670247"),
+        MontFp!("19363337726355099236128975299462078599854604247644073095764375371642393487744"),
+        MontFp!("11352512845451687563998689687452223516295911399723160879302208800753615647616"),
+        MontFp!("5033097489048897691788022265636063060230313291173145751178865731392231547832"),
+    ],
+    [
+        MontFp!("9342768693529219155995840295623046316860027403351256239528640640660995546250"),
+        MontFp!("11343407235843410518451234635552443892628096773317032816539735746541252484029"),
+        MontFp!("20271637634427257791277080766628733956630399511643807969496831759934416995624"),
+        MontFp!("12979118904307784600641734806775453265865076574307149193300157552133646759000"),
+        MontFp!("2609049561347471594361989318849223604030501563821990609941361801853208873325"),
+        MontFp!("16638136645184843973996251461253142824084602885453534706330402604048300209367"),
+        MontFp!("6290150467317840195062942193162131367777911299731759519747208884085640022080"),
+        MontFp!("21846903793348064415550139579740558481601211918214432128739680250084492380404"),
+        MontFp!("6881355315007836102200696266576401649721953812561017678365140505591991478449"),
+        MontFp!("11991852144633415808902015898168146769921125504309617193757255165202163636329"),
+    ],
+    [
+        MontFp!("20579180498569037366675392921380645532641402855187025365767529341564826966764"),
+        MontFp!("4127941604046459390852849136716344563624191244387948802112999500161867081345"),
+        MontFp!("12726445769512078351769013120614118104254671239018619408743816227993876252991"),
+        MontFp!("12822824504588887927083519548290538468815267612767490908011540889502830894241"),
+        MontFp!("3525790082239104371118456157894419087904422095178764587616607951352330724979"),
+        MontFp!("5534817540911273750657456222142677256882873311813581893871590176089715612985"),
+        MontFp!("1615881228089658726040568147025008122728572958650432110106281742560315865517"),
+        MontFp!("1471725164982594409495579793735446246197281099521356897919523009694047660159"),
+        MontFp!("1375309198078109412495220212570536673190607625762682203229827372752214429058"),
+        MontFp!("19114911117517497826908513598723039822664580418797141695087511229965144677908"),
+    ],
+    [
+        MontFp!("9628666313906709051161166309431160628627430386029173286325286404453712266410"),
+        MontFp!("21852518218578549606473925058864730694915463701150591631085298334480610743316"),
+        MontFp!("12775432117186202301959614842766511797651599815903402927721712100175714308106"),
+        MontFp!("139892473068642488659633517109052420816080027074176062905422560867217142259"),
+        MontFp!("8678567564479314009205848092936065091488089332028298130303782323700697895584"),
+        MontFp!("11749646464324896227459490085151303579078783519261033148424203540751860385879"),
+        MontFp!("20522934943803615109303532925965718163549240564060985032796290524829499285217"),
+        MontFp!("20661244899066232726889114470941159662948096319289349895724712936883638757146"),
+        MontFp!("1712076112157842791409364964341168524175271666408017461435914200921357859979"),
+        MontFp!("5274198338007371549113715286886410970476178374473353058525495747031417868052"),
+    ],
+    [
+        MontFp!("6737897812641394021946938592351495323837784050553060267876717564065727066209"),
+        MontFp!("7802413308864463219891658906834234180067201307743855789866460725804890591074"),
+        MontFp!("10598878996622948168711729113266592565050867869138946404948068606726933771770"),
+        MontFp!("8999501853368885436259381006393167420075229434053961224100936590306072807402"),
+        MontFp!("10154159140828416502096070052350440839365634698281866510618130671547713671046"),
+        MontFp!("10116420503162714112005525549243891887026278400783073704446798028762825422117"),
+        MontFp!("5545266571933610687233921232979606259360579780771113897229483385986421780729"),
+        MontFp!("21233107093610116862049125654360754386798111684938073821049243264439651570366"),
+        MontFp!("5307392033140435516838295705521564813869712246929667484768640666687057034707"),
+        MontFp!("21375317482759736213193607973501605926935171024163842355374965533706641104549"),
+    ],
+    [
+        MontFp!("2517892809920213533018018674089754443879541674948230773132283920676903837393"),
+        MontFp!("14360345633113115894388501084706102426582517876835778852281477705852716869669"),
+        MontFp!("17053269301717416242405268053150416723822210193991005718303172171427452536837"),
+        MontFp!("9906602428995106334942925928993621430906117671319208771657883136890126991982"),
+        MontFp!("10354603022009709342018106446249264303223237761462844795940043835225457441783"),
+        MontFp!("13398916116699661698644750814188836272580610770712272177556442659081018605804"),
+        MontFp!("3191149493139822128538617654520106298782669019011287540692938944153323592958"),
+        MontFp!("4691984423256762483396977170887219469164634656601998705889064679728271695271"),
+        MontFp!("17844101314007938193168524989091446035911779338131517299817551328197378135054"),
+        MontFp!("635635940269936042377013194809642013073936660346940411744079076043939544740"),
+    ],
+    [
+        MontFp!("12630888356903717277892931534313436641457206712723665520324533786104375264085"),
+        MontFp!("14140466574121870700874387789856251566070511990708575066948924661369491559256"),
+        MontFp!("20370174501238434846223710470633284656345430614321812270231526837854520663574"),
+        MontFp!("654339196866659831266130941576738975707930915283825590200406138066808189370"),
+        MontFp!("5107706503321722709363385752813500904785775244074262870879244969961234309572"),
+        MontFp!("12198789333458214522406820255828653820578540569170284513887146039452722146485"),
+        MontFp!("16249136895399135618027043741098607972773831911496869243661084436243711109565"),
+        MontFp!("11074204104909151859533339603597929732173550047640253831218390211736882449440"),
+        MontFp!("17207343273400097590016935219508528858538698779767314862863101225959250875891"),
+        MontFp!("21262587236682681589242692923329018584317630804742733118429592061667487058638"),
+    ],
+    [
+        MontFp!("2280753183511690306986430331340197673251265546818209861935234085004534230414"),
+        MontFp!("10254003274920664842497725816382563578440196723429688051808776569200020879745"),
+        MontFp!("7759959068226022198572347902743272598191759849179433231578496544131111538092"),
+        MontFp!("14214316923200364820492127575076874888948881174491573576707716667988807435892"),
+        MontFp!("9095544195644789922175839073929462149959586189940443682544660563790242551416"),
+        MontFp!("12649782796197868227327830841571149621604197483943737926454728454326532192518"),
+        MontFp!("16667983042241619901264223140344714132852698906756725392221839503187142280785"),
+        MontFp!("18383173644675687530390651274503384113459403614974472825953064896216388971884"),
+        MontFp!("14628141534803375030737634780152921077373561843078668533631869027351905379871"),
+        MontFp!("4561456211241649459019745200365185624320798675863426759321227045069329801664"),
+    ],
+    [
+        MontFp!("12289778497980320566229781933786841623485008232338420865642173363101571189872"),
+        MontFp!("11975067175623680843959076988032062157059134808170430074549557650028254491562"),
+        MontFp!("13608090049838182253377471459358669610089588259298499702848843266957511619603"),
+        MontFp!("18788677463812827554544269966162484344784391485047475243938219549074331613206"),
+        MontFp!("4881667965195655156201239071358064948072146968570883219541497388280721871638"),
+        MontFp!("16809375763554448903183355905513828131657823301396908506126469252462160193891"),
+        MontFp!("11654980193951434743713680917141406309927211470940308818720103124789012440740"),
+        MontFp!("5948986090649283108120495678646398398833638108013243775975352725637369743753"),
+        MontFp!("13726437662355203524944601292802877111764435687388166013301616156301223567122"),
+        MontFp!("17457684154769676997584569814978347304006793119360850469355899127743508915640"),
+    ],
+    [
+        MontFp!("12768218426713967613672155360932690915682228245638743313771591055379281067565"),
+        MontFp!("18615157783057763780308448635283319685644271696762198401466573335150909781420"),
+        MontFp!("17036103965935103170115214353570299052411478859579659720326549902160146217969"),
+        MontFp!("7150547909167136034355368387927463942994048322819447869013206336674786486676"),
+        MontFp!("2719491185384365067577935615529406253538979948934035375021293885648964670289"),
+        MontFp!("855254098406697810192507782360318218871612459371212156821698546331701832542"),
+        MontFp!("17895542168549626634871327801987932137768300191314912757298899767762396172426"),
+        MontFp!("1291731410872228901975204398582554998148747251553901848273154130903421546804"),
+        MontFp!("11405841699040163552814729751623598889450140001218648787184675782417123019196"),
+        MontFp!("20354628821067577915648505449000078769730992376974982139779446148223962828730"),
+    ],
+    [
+        MontFp!("10728669080369994464716817080074001337835696213713101955141340296929303809038"),
+        MontFp!("12274798389677182426524706446934567986140214102647161938159083665963012760572"),
+        MontFp!("9739537323825422719175243872981907235223292084323462340852187679763167385510"),
+        MontFp!("6755543715589769777862111458854566169790920787355600259792850662088606657716"),
+        MontFp!("12126305553295538434422174339664597983843351746744739132557070380077267264590"),
+        MontFp!("21398878068159838390422213444802849205194012142590812651530393856342428295355"),
+        MontFp!("2774987220129009778448086748836270418387857350731020745311729965596748575420"),
+        MontFp!("12913355749231079157637607439722332211156795881932799993926379679617800720875"),
+        MontFp!("14002442102691266344434178456827064608589741346471232083579257521903268988567"),
+        MontFp!("3222000382376546717389770889292702303294866053055569943089969635516575396692"),
+    ],
+    [
+        MontFp!("3351662579522271904655802780809448930926900026265684873057471441126697202347"),
+        MontFp!("8791346592452796050710862947776730913908702488104309880248248201823569029744"),
+        MontFp!("9103312554290751080966931020955359921020213554904162168782078094097630022273"),
+        MontFp!("11400762474859869669799163954742952133400547669811496311736883651700759825519"),
+        MontFp!("21705862854411927719091657128107442151318579935925212397760983799549487960024"),
+        MontFp!("43293469326476059594440269130071583321324378720711508957751360314337673988"),
+        MontFp!("15189563636069530033236842376689049504908622054060814965474910283072587672143"),
+        MontFp!("8828581874201341688220445093730125081012615712886123840297097551587278948881"),
+        MontFp!("4848334665131642773713411076824550175891079883511516252808850475348208758729"),
+        MontFp!("12614662329834759430739626588497950629642362637723265950380896878486239042155"),
+    ],
+    [
+        MontFp!("14266355110863104530316810105072189601641436180167942829728212140901379263956"),
+        MontFp!("17294822327168915058983104150822364346325840621309612804535067234675456850670"),
+        MontFp!("455077676048323303580854786776522812365753723694705688462762996845811407009"),
+        MontFp!("1883008531326217838820507370543781882290544271112198177704053499351851681273"),
+        MontFp!("4529982458238957976485223202768823175919650810452913863593242825418350872543"),
+        MontFp!("2955110035783314707155321084983433537364088072013879095266124577362993635626"),
+        MontFp!("12967684942790110900749491528799008728014372120122130310632754743394775825471"),
+        MontFp!("2505175694719163834796124853767853797404065196427012487113935936321224863546"),
+        MontFp!("3664644243909527501109080877083112285282350623935581596506736195569817810680"),
+        MontFp!("13149615309511991949247749473495035486060198385636687198656459619774102038819"),
+    ],
+    [
+        MontFp!("11675634552285986901460163850174089221624080337542386824203554725353862698973"),
+        MontFp!("13792241875469760333807839408609766753472484847219134966123237707758891089388"),
+        MontFp!("10227619387285566606296112062328719372414665296090743089679008143127205545123"),
+        MontFp!("12205580044090621048077686897527020579927082420674484835775119696613313405371"),
+        MontFp!("15208177648579495968812696435767989756568450150931633443711377393141060828911"),
+        MontFp!("6203604398339912597796330237774861234098507297133105016255969208311447220376"),
+        MontFp!("8763991852009928642943035844463232737815423121200913107779495834216057423172"),
+        MontFp!("94997075732443240393502290740081268967228130793200255689370554790238240979"),
+        MontFp!("5269706528648934838705302424114829386876854870088873864481541735257304941866"),
+        MontFp!("16262872163060208420029150392453401749302720607531142669546071916932112668551"),
+    ],
+    [
+        MontFp!("8143677473154806611855981628530143157024134934286519271401846470427527147543"),
+        MontFp!("11345763412284980808950171535310000445785575947246043879446971501610507015402"),
+        MontFp!("11334003190684962190461352102674801284421167648666972792173808801060633658405"),
+        MontFp!("16679481501466111466382494296272414977838924060754574747550155015005991797168"),
+        MontFp!("8717276046214261706367755700328685033217888501922395351508483646143935055357"),
+        MontFp!("7961818553714372394804939036958306706260485011393197807672306728909086465660"),
+        MontFp!("11525513262393719593247460022637862437588876074380005892794471847411833011017"),
+        MontFp!("606475753731577839300896422047168640216288859417610094202862133247546805011"),
+        MontFp!("16216814510391154599441870283663624434960433363418315765904453229709017881340"),
+        MontFp!("15952802892860522124812654660125132147349553268606042022775941410073735145502"),
+    ],
+    [
+        MontFp!("21186206422036180717988803903908106965745591204944021637150186904241796046981"),
+        MontFp!("6944713991333056327587649834988524981544213700413795245346955488518554424877"),
+        MontFp!("10185921515443194095530439914620341794217076478391514698779175769548747359464"),
+        MontFp!("2855919726997577698604215361496800379220625688194012864111223639042676660976"),
+        MontFp!("10433229026112136773123800168383892031427061365180473556460194903407247351339"),
+        MontFp!("19427072191035469870347195569940358526740232693667547123354147821192789102650"),
+        MontFp!("15644214414239800285411872105206002044880495869535621299007907341206661859610"),
+        MontFp!("17127893436675976263942308327239908450163069123121279064123261885014993455970"),
+        MontFp!("14398850115453023364458586202624524741971410970483408578015632196929131043640"),
+        MontFp!("2352925542671141920909613532344506652984644250240272091365991738093178607788"),
+    ],
+    [
+        MontFp!("10172679680316429884824619312723045840067903978738893283037233244389430249509"),
+        MontFp!("5772194565812391639885669321239603226420464592665765672957012371288977540856"),
+        MontFp!("1472565977275775489403659554782408548523807638115405629626295474159727078930"),
+        MontFp!("13025952383827165156872004962772766979272981810832459552230024272976389350529"),
+        MontFp!("17564758020035152957739819749712385631015761969283204715352893305611222727560"),
+        MontFp!("16405514590762165467156068648660873304776064039873164250553927264160414855875"),
+        MontFp!("3690038285204558326066250230248548873947936562327070117434442040765096220855"),
+        MontFp!("17531140782998595396669833094850381400841695523035934903128529012616296113949"),
+        MontFp!("13168227885444297954910232183764567700890648537512256626187184942939569199780"),
+        MontFp!("18839532764289221356950914594613087164434882227921649252297932780104554074257"),
+    ],
+    [
+        MontFp!("15690093187279544523035828189537219904387587938956853550130942856247262425989"),
+        MontFp!("4880718242480815050016604653617276367751146942554754727539176934739417585000"),
+        MontFp!("5767740184065294852679113060036925102298292735253600173647375101014669432601"),
+        MontFp!("15274885987576180935369267696345371924274924872703997373047266442038483500100"),
+        MontFp!("3761934589481780435501384301483991135823312027596840500386086136996196777919"),
+        MontFp!("17076314579416028845718050745024649043011795764818118471181221546277962595385"),
+        MontFp!("15977291426665432194805519962451891500931039499992781917651419845757747435870"),
+        MontFp!("16614058019242709692964725060926415535596693985378737640811775269245183858531"),
+        MontFp!("1462290731020299561638622134991867491216642860791478796650694102212515942203"),
+        MontFp!("15285513431606451603488599544624561225569394909979737124945690710031449859251"),
+    ],
+    [
+        MontFp!("2108108609418057784389651014871434275919186190456064517027979476451544519949"),
+        MontFp!("20785453923508177251995968212118965193411372702846000862603422090322205734041"),
+        MontFp!("10673883530097564269607463521683039178512337022363698173454334902283390457863"),
+        MontFp!("5454676180617583613153450533396644120109614173104204009158828769649771925347"),
+        MontFp!("2308424025465899393970422846699273218513861797562089060060302466408498727592"),
+        MontFp!("7521209677407171946300925747807233725946342430543358547121236461545495788103"),
+        MontFp!("20573024021944297233730031260403312250074182051596062256738574515403295322803"),
+        MontFp!("9021049816696497696749267473705619787233696328020950183323376943545740004437"),
+        MontFp!("1865524799085825018345305851095200435959748376626055576269783687778950437080"),
+        MontFp!("4246459257342056987998012962024687622955192847494871592608632692951161262129"),
+    ],
+    [
+        MontFp!("19427245695591302750338395658196139112041609989862338102918570832807123117503"),
+        MontFp!("21526088318229932910068935959652342185575362039375447361171773752278498069664"),
+        MontFp!("16191911454644420751852228901282854304136684923356871310294001321086062307024"),
+        MontFp!("19158667047991453406718020435447139785846629820299132383267410641682384370576"),
+        MontFp!("17700355947456307421977232728703295781949761612179494776757369350093380226828"),
+        MontFp!("4397993077875324081650432554749300545148338322818494126385494799541152486594"),
+        MontFp!("7333345984549245920976934899658584162392463672999208699449239323807521633803"),
+        MontFp!("16152786000399924760932165522677937364378936868710087711558780050692283598645"),
+        MontFp!("11094138063712503930108269043784479707229515809275605844595768164840752472377"),
+        MontFp!("1142389644011176261530925136868504263430131536560167284105696375014595703389"),
+    ],
+    [
+        MontFp!("7947253178759456244011070491140902256859556283134055577001208685178146652802"),
+        MontFp!("10134714213573683528928794197545260748403530346308318902760067810116839777206"),
+        MontFp!("10883490621674448000789965625707254871804535943755669299023415555830424281655"),
+        MontFp!("3066669045698349285650257964282463204141692000014900401186330770537030238642"),
+        MontFp!("20081606580658665084706560391544166403289461596409550084066896490552960642479"),
+        MontFp!("6155695406737593769799569079058291297835886186017961489006277167996716002247"),
+        MontFp!("8515542419918302650566740947332213674125125387478669670818010011334680546574"),
+        MontFp!("20110138544602147391149732696810223660265845787019234225223667638537351747051"),
+        MontFp!("10742562600839811890186721654855708402836531506887842079602293928208078373859"),
+        MontFp!("1525909842404376057305890458989201366856740974115647536551418424527131666169"),
+    ],
+    [
+        MontFp!("9234236770964972467234889051483942687447344616202849085905582739078803045160"),
+        MontFp!("2606008597707158849245186414604531348516676367660138989733829835914698874012"),
+        MontFp!("11796760152309688912186437926169763667717914107947289133346750725444306914631"),
+        MontFp!("3851377590989618761627509080069107348568977801140383151662774695134986557597"),
+        MontFp!("3154796336225549288800236024241376487736097402096695364582935878596928093781"),
+        MontFp!("5510420220665221034838403251084456079592641926247054056939582862556776533398"),
+        MontFp!("16879696361470925506825934570612427394067380616879319126544582610342257894977"),
+        MontFp!("17284649816450637986813517327310560148892948427878914253815654865007623581799"),
+        MontFp!("2833030338755501226260678429168722114253235114087325082893557705288230185302"),
+        MontFp!("7251727070132476220556183457711849452579027647540936257172964240637390843337"),
+    ],
+    [
+        MontFp!("9575600794079237337218029036087316899348403790684983148717343996829930177678"),
+        MontFp!("5979306342004680208572077566967420789575741134560343288662733429960668391430"),
+        MontFp!("6933647198431187357226909706624729057428619440859131169201765838043283476077"),
+        MontFp!("19168763196217781731628598897969332495895458624888700544934409934147951462602"),
+        MontFp!("19829437720109810470479999873902618673477593710269745617035524777507262876935"),
+        MontFp!("10801527657674630131387061424571233632050383122132208702068470808513632048030"),
+        MontFp!("3324631869609728132956796851449123815871495758162118183753705724979320742705"),
+        MontFp!("15660020223439851550062020388305149722004313049334322631321250103301499692604"),
+        MontFp!("5967221174170233933880215190156392465136752773253659731223749128915793467895"),
+        MontFp!("11552412840249358443126681313794466948899653896063684296070673264241626555196"),
+    ],
+    [
+        MontFp!("18888906666917921069717332466525186303442592625253328171173131193932302764004"),
+        MontFp!("9002714106167484391528827546180572105064179104525774089102236612700713696717"),
+        MontFp!("11048302793662652213631623254022096575352405761944396370134943300004028917865"),
+        MontFp!("16407259962762436968310192538354523615684343650696199890141991953040162326636"),
+        MontFp!("2164382359471710099213275616027528225843146809226250465653585796261822737346"),
+        MontFp!("10534211142437421383941598776341383955315039281979396571221999789915575101599"),
+        MontFp!("10666069653734320813631520107249402109728041265475244772290972049841823847727"),
+        MontFp!("1065893272480854115563003062650162503958098706206084024581187831004515125777"),
+        MontFp!("7996693710845330472615496754914137367924796497947601638820266498225398515015"),
+        MontFp!("779460584637732468426955849206233546264346430125102128427414524469939157403"),
+    ],
+    [
+        MontFp!("18123487244613769854380966736011971128129435530397043581264858565849669710522"),
+        MontFp!("16483639590865705054952634993109499660754781472434181022666579985294889582777"),
+        MontFp!("15847717171578788545161470441065692492419364914649476971042627117577578420712"),
+        MontFp!("20212509548766945890162316166677458350544092615956421310024681889193656903241"),
+        MontFp!("11915838540527285091679839305488591026042934314354878896592571753630253407873"),
+        MontFp!("1911170159637126384084881836969666801716554202345435461162520643252230842951"),
+        MontFp!("2519681707616189243873692429706701363981726227724944939887394039132311807155"),
+        MontFp!("10525548620009931418869190498282964825273460634626042574365806552478535675090"),
+        MontFp!("1007287689974936217568163398662729361983046876939574893360458678319961439943"),
+        MontFp!("8918601076290071318953673276836220960249911431394510238963515695083601259416"),
+    ],
+    [
+        MontFp!("1730552670090087588255812224149698933189694835321291448382024953001539943933"),
+        MontFp!("2692212389857059051251821082045515561682372012597270034725442717100828072430"),
+        MontFp!("5182752960118030069130081328243349382982053565156074602369672154634481788415"),
+        MontFp!("4186223293158399083027342405464955373429347225209836842209182753945871255191"),
+        MontFp!("20698226478040525601636369286446332773903307913544069889209490555501069209140"),
+        MontFp!("7848961029873906562639009619165977234892385836769337804546327846501965814365"),
+        MontFp!("13921935366749820642106107225856381492084238464963488385755265898574906919842"),
+        MontFp!("16615913254044390064369275838835406299949293202833109553557777908673881552518"),
+        MontFp!("17884323890135880903838623664692905800576770771455839982202688346472867240539"),
+        MontFp!("7671240599961467753478651569648667266853727017714987691902517051845809028276"),
+    ],
+    [
+        MontFp!("20540170969725852651721054123169504183251616229771312133024479630289328600435"),
+        MontFp!("10885911618876068300178014171685162848957603467763239389113505729594133953822"),
+        MontFp!("1821956738503711086168534921235313501143676686594517809385967874500584768914"),
+        MontFp!("13900019517803309945097308036151373598859036005433194049333364013307418921490"),
+        MontFp!("5611521340578197244418404931199594069340846248086844299279812110836336410894"),
+        MontFp!("3871676825880602831129014665958446397148564007925285835033897270539554583982"),
+        MontFp!("8660883522091722908446182204212531338997991855689061026827047565345925404852"),
+        MontFp!("21369377758054514487929500216341215576252815582773920801000959327560535036910"),
+        MontFp!("2412441365534353741053138780929948381707801794421791967644299920632399699760"),
+        MontFp!("3962365750665624098182798369136001622041855947680873409890054621414139629589"),
+    ],
+    [
+        MontFp!("15001230629104674518532645948322038846835064889071294141298776340346988584355"),
+        MontFp!("14880674002301694876681052915629907300219131310128131182663986425102259721980"),
+        MontFp!("1824158054562585980316309768551123828629842232225429989383412402628330419987"),
+        MontFp!("2859240361399565691265380107352020662064288346296545444794991049234114013603"),
+        MontFp!("5166447910779952920543762328108096228333459621541947567522578212254733957716"),
+        MontFp!("1007490453560275100906582245944853446313521002614529198832736321283620962455"),
+        MontFp!("12357031909288499920427512282658173574064133416008366625033457013843709684703"),
+        MontFp!("4908601693798278768903637729333835430381717019653632816739154645536641464365"),
+        MontFp!("3707521788266753982537350852704241176639571081938875735935799519005785641932"),
+        MontFp!("843648473424733414090549470297108007717585086811320449394192229155836735429"),
+    ],
+    [
+        MontFp!("15745469357122732280411707850429686485179261034785562466267026969795211925342"),
+        MontFp!("10805521614324416411410037641243182724170403739698507241460822321812492198235"),
+        MontFp!("12065996971764501469604703952781437881524721789450462435182323617647626062291"),
+        MontFp!("18470487586372818033539023969236693307855263683332928699228943202064060965843"),
+        MontFp!("9678881733477238994400069917737562227773718359505757479334487914298656155782"),
+        MontFp!("14188770590313091787681039051598250350716760439974403531322107017294575035366"),
+        MontFp!("1689506610383677575502055715917181866056070036146379850885318902788859831848"),
+        MontFp!("7506643906911078844726412866492372822643011138706243954480387034094577464841"),
+        MontFp!("8878900588521598109866546176968107824348718386295453778616479348498221694856"),
+        MontFp!("6870283245627392817090238076492510507322368840526195161203457308149059064709"),
+    ],
+    [
+        MontFp!("13770891113056770539767147807436112954402870897635701688432832537357791153049"),
+        MontFp!("8780360192411651104527843471573635887257900701816701590232913021169255451999"),
+        MontFp!("16171874311798197525909736376097249943669070404511356154621097034937309906406"),
+        MontFp!("5735048971584791925371366175024708991668126697294479025115320761565490442773"),
+        MontFp!("13229825087263355390945471875664433936256658682181329545626347347763439801377"),
+        MontFp!("7075776235062840728475772028477434335481293337646194082436681309306082067951"),
+        MontFp!("3621624535897102185304108550400513064448572525424419762329290603569136345699"),
+        MontFp!("8909048763816678806291702062491546272181098732106818908949274412295033032278"),
+        MontFp!("5679617488269393681126385387791795896201443046828191787132461584904242222704"),
+        MontFp!("10105279386430545635207898937992374339634464824905552041741267814807859103503"),
+    ],
+    [
+        MontFp!("1875089455725008938828188584567435691926878726057441578850433616115151436992"),
+        MontFp!("7477746898926143788312536189451854889707733370802231032871868774692083118085"),
+        MontFp!("16386910598448767875426039948412876247096832715449382492730559004257126956048"),
+        MontFp!("6033432876275593677037331179852069653085950234927263672572175689211500790655"),
+        MontFp!("791047936106933006999775822964245334290371384592876255418502363688874468612"),
+        MontFp!("3694863804142244539430013086697918876101028434198177049748051320963196192691"),
+        MontFp!("13376682105243214738309576815193178135837123827716063891638620885853287922296"),
+        MontFp!("13805679067029770984721773402288418271927678860935425166689344094492548637057"),
+        MontFp!("17136756160106085935819446871311843510549583818138636089816199715569134828084"),
+        MontFp!("8109923439508142364330425377154151984256104223553589804214354752109994937475"),
+    ],
+    [
+        MontFp!("11286249512402084538299269911568404171399278286810916548813396237061620605376"),
+        MontFp!("2584359677745194465235893977254941998996461095474372764450843406506578038395"),
+        MontFp!("416048929518775256195106483480946138344969950953407172345234478269849793039"),
+        MontFp!("13382468529403508085121262401866099849753365548098731797553497976873204205709"),
+        MontFp!("18439376867491626480684697800985907843592103465829293677253579863338320195923"),
+        MontFp!("17849262151524731521717998935547978553691621192854239431428238124396600612632"),
+        MontFp!("14944765584636478787586231666077419063063746476683640145827104348311750508175"),
+        MontFp!("5770158920816863002535760768793204546036568907423666489027939717423021627834"),
+        MontFp!("8964766646616906071571735802649575322546984330618572612076303377141821583837"),
+        MontFp!("12741361066585979303854420163873489016934487106922559130204774618881492938249"),
+    ],
+    [
+        MontFp!("17155650977068989655844853330647332708031817408933941319367040661444377002549"),
+        MontFp!("15853135222927269109853709968587618568449580274775781838704992776508260190624"),
+        MontFp!("18643405831130205120436044797258461126994691116505008303058249039104682298143"),
+        MontFp!("18381699905134802759607923737176411134148483921733890751508148644565049938661"),
+        MontFp!("20771413818150322305462626795715653983446198751749952090107489229813229842550"),
+        MontFp!("6565230743597462418756949526565014729968376635326046194605417268756666319845"),
+        MontFp!("7773589221172310936780704579925742277511016906417542067089964629576305572209"),
+        MontFp!("14322739515259154048217383149571029180760938501429981700631245079741667837826"),
+        MontFp!("2211777926093208827662641428669122029599076421656786665814449320380720581777"),
+        MontFp!("11959359909854207341226535525210590494408522984553921513181278512110987087969"),
+    ],
+    [
+        MontFp!("15631964544474412103208297614910610111786652867610037534463100143218625118186"),
+        MontFp!("6469947677147265061709088012775208497425320831040679910475868641704170242154"),
+        MontFp!("14273192286218513903657400373354426033843730885130815917604222619132646117025"),
+        MontFp!("1642305946822726119533692746690241550779067996073465721872854402290979482744"),
+        MontFp!("12434472654594642340204970901697561562492434882039006954954548364905225683590"),
+        MontFp!("18258333676501263903599077497799178704676070138517903780843551776313851126197"),
+        MontFp!("3154393734777301102615783657097778679162307608952237328943250741895135803910"),
+        MontFp!("21442924273325571860480847088112128477994226721014369494483203254968684948447"),
+        MontFp!("12633492209679682779165226815843032818326050497846354378961630197925565487104"),
+        MontFp!("5676035885913185017175294353150288993325590895201150557890586619719458353554"),
+    ],
+    [
+        MontFp!("7971989338965840486933559372839713095797443968865735128939127037392334228821"),
+        MontFp!("21734085994605079916025200907658739534755548178328895704833631044593482792710"),
+        MontFp!("2240615781815075893018901266381250882106533308854256044823388761533658366337"),
+        MontFp!("19418757086351270137870941178188715468785231202435740939860626745964359442005"),
+        MontFp!("17658978545567285097910442499706845586224912649469751675828983048696346031860"),
+        MontFp!("5266889563561183172939683250833485521316204021724495164611029326138684723583"),
+        MontFp!("11128996685041402949333841527417312168812141699419947558499636572258705639448"),
+        MontFp!("4876070090528783965375514438749842173086453529590096369168455769565070437109"),
+        MontFp!("21181864929467364713132566956143162901336986129207766576689052262132856884478"),
+        MontFp!("6923705508410326350945166370499325965219890886201185955752583121521122421736"),
+    ],
+    [
+        MontFp!("21369826512764035509040463750392604795868027299873193291279213169276615858803"),
+        MontFp!("4704097774288345414783119239472343512761071550401447208590340029300816575296"),
+        MontFp!("19335571041256357691347835994830129551703141581491004773597606130380555930148"),
+        MontFp!("13594634273515260829141229000646270213542265375428591690952060475706589483636"),
+        MontFp!("9015254066149037983117828022107604427546915195306105494160093361353622246415"),
+        MontFp!("4212072016320343773172520900223699632828574585381041109390033407447488634385"),
+        MontFp!("1102272748190005338904701274717935307889530536456982005034282115032583335111"),
+        MontFp!("2736300324710728232038909644774832551915915663932530690557461126955645530086"),
+        MontFp!("4835822801818378290852301048163831283974282641105089345434084063764482095069"),
+        MontFp!("8984658187927452678459930452346646248663512083082894198317485712426204609811"),
+    ],
+    [
+        MontFp!("16633394668571656740012069555016467267297836103775110968049070854163114237249"),
+        MontFp!("19001311544890254637467757524866604779430904938238046630395635640108752440416"),
+        MontFp!("1519708912837962563938791348422981399413816048437970967955664087668434079252"),
+        MontFp!("21331841844372791543710397499630585987940452814579176990050363535517182938392"),
+        MontFp!("8907423080053260966677751803467685389883909962381980865910698188903614425511"),
+        MontFp!("20461083176034684313938738010980183835826228331514122093950995340802162488751"),
+        MontFp!("5841016603634386755712428070725428370463826644379529834480138063613116133165"),
+        MontFp!("6169966163271811065481708286709332934104602048421028222790926506913444959185"),
+        MontFp!("11123503881380576416779399092503011764979780788355198614717104650873109848686"),
+        MontFp!("5910862578643213547409014086432565110080048906429291885019036113399453987536"),
+    ],
+    [
+        MontFp!("15326247930625028665035962093121096584742207235393215425729927190450227004180"),
+        MontFp!("11869108106148533163877422279522558694860624980410176225979111384418155447592"),
+        MontFp!("11642189959177792725300751489041811935498998846917246538427709581655066866054"),
+        MontFp!("17383982459896791073091103411713034950019457760840905433100761135071197717377"),
+        MontFp!("12914884881288305014413720371780941499071473169305332026567402160980899728234"),
+        MontFp!("1208570539962036907751277903184876797538221568150857585830629441131315144451"),
+        MontFp!("2323312703274674937703540014228631818729280611690694743543061204714949346767"),
+        MontFp!("17526158529185414889134597474769916490357130956125485429641429836800727655323"),
+        MontFp!("16037781653504828212828867735133494813590828457250361954137565662868257318996"),
+        MontFp!("5407222038113928707955890035984954296613370389143420455278606330416003035740"),
+    ],
+    [
+        MontFp!("6601323218218927237946555844476405370566012808107313139506360075146521702852"),
+        MontFp!("21534867504549849931394770956914633425223988570769925897030241609788158519056"),
+        MontFp!("11391084204734238133980784274225569005339670690396227409155789097373857915450"),
+        MontFp!("19888681657658973285687297761078939800531741094128408699392512189997402853670"),
+        MontFp!("5332577406232753436405602237019361733171667669604896195130680826397119398314"),
+        MontFp!("9059600173937645065621009092166260440988612068018047518892978276246308722168"),
+        MontFp!("5301647598491778367767843695092267888185214608095464352349739315757388259345"),
+        MontFp!("15071875841892141860455823800612444188659593796102096590428526661356131692597"),
+        MontFp!("435778089283152858387915635050172265804266241934673566488879021420875384052"),
+        MontFp!("7253178577349028822394834490127746885621571949621301778647260365262978601526"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=10.
+pub static POSEIDON1_BN254_T10_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 10-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 10]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 10]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 10]) -> [ark_bn254::Fr; 10] {
+    POSEIDON1_BN254_T10_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 10-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 10]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 10]) {
+    POSEIDON1_BN254_T10_PARAMS.permutation_in_place(state);
+}
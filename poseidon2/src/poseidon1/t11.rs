@@ -0,0 +1,1160 @@
+//! Circomlib-compatible Poseidon implementation for `t=11`, i.e., 10 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 11 8 66 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 11;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 66;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("1098498142837982582047608372723518751721607512716925277273595859756333857326"),
+        MontFp!("498382712248562027578374863343601618793781182132084383060312181008958381971"),
+        MontFp!("19040726265283429618662679510157690394832296024968480927415996691029230011306"),
+        MontFp!("10367579130776133414495805974535693744211249758950881275217429221792836643614"),
+        MontFp!("1229596364469449066712193908302977020022727834238778132871229393863406546866"),
+        MontFp!("5594347757215876411130934611555467571639435097442631641074898978663329410864"),
+        MontFp!("625275312666547608222628560378372315159605662141936411119837279426221363981"),
+        MontFp!("15485529557721639677666143827295121022852505628489596851713462276650737776670"),
+        MontFp!("12156576509577081554587930818670905775536581975823788207855134544267814269606"),
+        MontFp!("1981640929928975005466842670997136169304057407742291166386016130552621471939"),
+        MontFp!("9375079124430521740651903984797221620963928972304905809259607327125669559872"),
+    ],
+    [
+        MontFp!("268697279437287801043057266739136500465135819021738115532631740070584831216"),
+        MontFp!("9310725094036396036773344350803037792624399505581573214229419814378683970851"),
+        MontFp!("6144934044671205976376028664002834283864020049596457260475210339996948797436"),
+        MontFp!("4985941506647510031967748765284991041503308370910665002557248958100799063851"),
+        MontFp!("15851062719909725150709309168582658649310704358483047683106225599004779349418"),
+        MontFp!("9869770840966008659377598457679699092337106962689936558150689057592239644963"),
+        MontFp!("4964286354328869036674130011248598806906438908586967212984901377099285878228"),
+        MontFp!("13408525694456518383125684465410538061086669117275911801498275369395798296201"),
+        MontFp!("18263306792332242197764383101132914152275840410710698264525919817458731671889"),
+        MontFp!("10401786441956087930118823951510684636068781082958380915651220354850381871543"),
+        MontFp!("12496745101887166473879957440401384727148915595227764657145046356182346897947"),
+    ],
+    [
+        MontFp!("56825204182651219072479187681186238157981743937496557304633023935549648224"),
+        MontFp!("7949519580094467639897040111470236633243836928348452962417270559805860514707"),
+        MontFp!("3509286722306670968352119363633866055096352721394520084890481975258162907251"),
+        MontFp!("21359945526252146173553061920944871506626324563977081669248710516265311530589"),
+        MontFp!("14649491209868365229844087258057697734286269047837985905275053819765825128984"),
+        MontFp!("12122186136173879572357400046587658543826161883897136171993927935307093999926"),
+        MontFp!("2666476328185593105035429309804341325262753927547102747066987631391232293139"),
+        MontFp!("21005241858197204874543384881533661499138265185107903730534607574687765896488"),
+        MontFp!("4866331653274711303641000079325074227730641553230218424779550288347820225149"),
+        MontFp!("938689939079340009195180604139206414955240264736983491692686499992823741696"),
+        MontFp!("950493909161345219342597929783079468041198261349024441783356363638640688155"),
+    ],
+    [
+        MontFp!("8227093387774305505218050843028014038423742476679149203160700406235271548925"),
+        MontFp!("298899716277443866412562171123535849674476895336539413683307522836440058745"),
+        MontFp!("6985094123716229565713211140430519589886023406928617334981414752732877292051"),
+        MontFp!("4561102873171162160916461632027561255705058072826965137552144392802414262261"),
+        MontFp!("15422356128912397775473168682864290042256748428952418907369066530964035265216"),
+        MontFp!("21534011877473706794700774934355764894917955655606512952257743854629820348396"),
+        MontFp!("9461908500272520643111839486963426035162115487175673718316249722520977894185"),
+        MontFp!("9042969964854694648113546554619141983055960736166619708191725199599555275062"),
+        MontFp!("1441104948831954255692318866730011748129225465895791664253095290347818907280"),
+        MontFp!("19417400621113450826458192671383621002793369580946623762558060167661227354799"),
+        MontFp!("8244773274459817591888745631242804467035454174608673362960589130536385507190"),
+    ],
+    [
+        MontFp!("17088086767144106377842029064730946925009348520592888187451688601493882340857"),
+        MontFp!("12886019902209719236096958359125451092745638766392722988311451127550961945660"),
+        MontFp!("20280862819329644063010032903732505647194710429034928708829957501178343790858"),
+        MontFp!("13239701144341900586601825324587185682073736334523805955933121583949546821724"),
+        MontFp!("2994618864933374534869864629648211464657674590007913715843569952783382900518"),
+        MontFp!("3072221011986428615228338853345294533299624086589539664037325300531050793357"),
+        MontFp!("13594276105600327401961157952766116939399999497643063180657161489419638074478"),
+        MontFp!("12904364780884039213184464580277965622079185353283126471569179129906875486852"),
+        MontFp!("15088962493677593800057541234990587773412340265413268221386103386021880406010"),
+        MontFp!("14138285403526705785804535000245522290348086552790608567368815987904186155718"),
+        MontFp!("13481415964846572771441311017814910258609608797603836070350286657768815710822"),
+    ],
+    [
+        MontFp!("15459769479990273742477151452466966963353767555965255520456901549474045452607"),
+        MontFp!("8586052864861352028352866296665876117392195296860481710367953704812400661703"),
+        MontFp!("180502622991267551120688532508657597773982647209049478186474242637299204110"),
+        MontFp!("4785745751361586866577727263713743688205421961646731269452058881240942369409"),
+        MontFp!("4583871856798894230250707953295146343968130822948818555994825096960225600041"),
+        MontFp!("12377924729639905725281972784629126900954187435957722012223715002490809152047"),
+        MontFp!("21554415644278070156493674075483844873249829791940344144484983897474364915950"),
+        MontFp!("8390225843490125870104241611355504124284851919520955291024552578484662824128"),
+        MontFp!("2330476067094130593913781764168287559468546989640021387799865123741354870445"),
+        MontFp!("15749497374252464770935521609391859230015300749964554524771184068776070217841"),
+        MontFp!("16817654103281917947623051388088441309787140809596505043937473012669498321704"),
+    ],
+    [
+        MontFp!("9987656178378986905964646161927549614205785047077068310684205046327286932204"),
+        MontFp!("21450061958292240283686535241652971764195183478875921481624114699420928365160"),
+        MontFp!("3904617432242099936494425054740854886663050476318725032541401300619628714123"),
+        MontFp!("21454964104289781104446533610149551385791852085041524046710270949744081353102"),
+        MontFp!("10768409462143965702783360646769759623397882338491564999208626639994081655791"),
+        MontFp!("19385613828688830964519526099114207553837496617978489639408163709100497624509"),
+        MontFp!("9385292780799468553063371906778802189174789542685475364513544798199315486080"),
+        MontFp!("19882577122462819381545089778080532575686772634821281258975533828284349988146"),
+        MontFp!("1462201549484596350490921057903425036211202388283463006651220816599917679116"),
+        MontFp!("16564642856725628254155356607086672564976261497486137590399143770170930986182"),
+        MontFp!("11606470848655267736219046910932382494518380844147406842964119623341701511194"),
+    ],
+    [
+        MontFp!("546921055225672463086391798419385468083264065960104350335293012629066408625"),
+        MontFp!("12676737821548820987278730174038033161886561534502963159950183188070064038340"),
+        MontFp!("16429180804851559661054910451008618941371882312211198495282444364589225325606"),
+        MontFp!("8318514508896823373027050528521007144041407638548138855564062559664141902892"),
+        MontFp!("18546910687432012966956995548470714600618104024117576926439677823609854961263"),
+        MontFp!("12006683905722730408249989907056432037202625403043550391187503858618155798348"),
+        MontFp!("10816814135685807143320832554644398181525372167669730953193258726693903362148"),
+        MontFp!("1969445073620598650457101028079888612893685228913473332116076918643068711808"),
+        MontFp!("16873795316557869761040796336264749169213884122126281483001377666183529927793"),
+        MontFp!("8441268321647668856014389726368355391497206989491787976537908376817970369132"),
+        MontFp!("3378086906271763133245748026584767009750550242946195995254881868035794898559"),
+    ],
+    [
+        MontFp!("12721353531573613369892164015903035636498816100971168742462654106875931342664"),
+        MontFp!("14969430369156214890953989610124286618925370029259450629468188666450865580556"),
+        MontFp!("8545723361883060050915916338313252821252873299513393695440138873537985282439"),
+        MontFp!("947668284380905375962163908708231363459059635485281084900173592739603282382"),
+        MontFp!("4418352807772484492818068921024797225893951828921880350002134747344565378254"),
+        MontFp!("18146914067008843660990756743559427698617136456156926109157771781314720068545"),
+        MontFp!("2353279078725994188579023195684884389261433430819033940093641668202046052763"),
+        MontFp!("18228226015329570627220992288018909552101992748538110505558715089403194764144"),
+        MontFp!("2251557590571495628913478692960173580728135227602564510397207128937882297417"),
+        MontFp!("20421664597091787362209209474226188711714308866665750343509458297343168321800"),
+        MontFp!("8187951594294388715811532560312339537604737243977265499957088579012554679278"),
+    ],
+    [
+        MontFp!("15810834190411667509425096842396102750984990364193499272150958331088983323159"),
+        MontFp!("16884308240478579935994044823717491481297317573500280152191710196639752382061"),
+        MontFp!("234497484353824748419812158321111328486478789224631887096763967543932891899"),
+        MontFp!("21452418791072076854500976656696245147472896609273403517249960331326136475572"),
+        MontFp!("10860322289080285812992522532751459911253736747190334349942615321085283282595"),
+        MontFp!("149826608572716492570322179195234088797160854886751475825283168005807771516"),
+        MontFp!("11491761442863092383423796629001188933840969144934642247702733820824608517603"),
+        MontFp!("12099180244453415217270377899736157198045626379801787493348249001794558732373"),
+        MontFp!("13177983303979037999809722097100345612970493007300007493855625634642663397908"),
+        MontFp!("6849052800275826145043024580348093078809773712986428314364827674907764829568"),
+        MontFp!("21486255029472594818259653174918852363002807142725698741685253190938680807594"),
+    ],
+    [
+        MontFp!("11451503340703054732459437884000132607423536025797075877436151438425159994269"),
+        MontFp!("8462539135531767509735697608276067216182907546891182278996691315801807234639"),
+        MontFp!("19944711893825824667372913293784300313762563232409638194240029859435259601775"),
+        MontFp!("10396631238556297232793544122243237485091433966091043100758266678889110827200"),
+        MontFp!("20667999270580360504376758654763163152764187226267414436968564661080084475852"),
+        MontFp!("10424436665500877000658892169756884171624649701456443210945810183301667922053"),
+        MontFp!("13894422482417998868290238401966517700776990643618129177567797594771207188055"),
+        MontFp!("9076475964444407787992938909179730031379198268423789105813333967195259669658"),
+        MontFp!("20479003631920854685589262232015009286810147171298477411667705150903826855301"),
+        MontFp!("9928015403359312830073752955992978705151208358029077246413002475277600546387"),
+        MontFp!("13981618256931763962905358530247354996931923386029793318275706908114940457317"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("3312280834382673867321630616941760639861515464094877629805120494360011490649"),
+        MontFp!("2977163727414618213643725802224710174200189681501907689708278449275625624600"),
+        MontFp!("17077799405481633745546679084486353025600250694578521370656758513725453934742"),
+        MontFp!("16210306379465933080277173890273457210762404430253284947889895341785601089390"),
+        MontFp!("12306944281360832043572721821716739944034354411349456264300159795863030116042"),
+        MontFp!("18510116198433364516004461541873904955505055486081639325191415467980353992665"),
+        MontFp!("20171004681243290383397478639749648403640483231061520437292129889020927831789"),
+        MontFp!("14216817867363924461443287436236130110420963767734530602158091056747850914504"),
+        MontFp!("1744244489393376249430799522637230427855625055124494576876957852023791759325"),
+        MontFp!("7129585843913921821110812028399979173266826966429993304814409727296136874103"),
+        MontFp!("13988404968409685403326663187671698975733642730286219911002602918437679901860"),
+    ],
+    [
+        MontFp!("16920638563061193407835052036305459051417920006020130846290964059193276218943"),
+        MontFp!("13427470979587753937642822251845898994765547507421045262408080469011197338416"),
+        MontFp!("4951343674183369875263494313821909834781608226183187917185914650592996842297"),
+        MontFp!("8735432128376864913532575297691608527208524295369583136876379560735670033436"),
+        MontFp!("11506191123509320764309490571835590914265043835723578099434891464809664894670"),
+        MontFp!("11860937155601787389575216601739962955686948385964703064981923542694893487713"),
+        MontFp!("10828014814384016644406621862814561467981545748254521286078083584574739936124"),
+        MontFp!("4776443388323724363690349417451836132533385226194261923508005690866227183177"),
+        MontFp!("16639991477904781874568583121789695884424899367435699700192357030587346907362"),
+        MontFp!("63646460855616590932366082663720069120195784636826940928051354727568777173"),
+        MontFp!("13872046592464170980392921000435473279488582870563366687909512908597101541579"),
+    ],
+    [
+        MontFp!("2903974073963148433036990643522953333905364588702098342158750794553468542208"),
+        MontFp!("13972003294927197976860685316247379324920372226280483226377669417062686947906"),
+        MontFp!("13868504415208879955736997036981136359599594082926486546672963310629609974499"),
+        MontFp!("8628454286960990606041697926325623221475249983044062173729208432417417750989"),
+        MontFp!("11883151436851199698252725037318235080760967011947670552161788839027156740653"),
+        MontFp!("12343286145222861673187914724623142745362406729785896500578210646320713733295"),
+        MontFp!("13240444180513188371213070455300718988708567038226911680564203350475184135088"),
+        MontFp!("17225520781620743741263824011599257737446498734177523099795883619924078366351"),
+        MontFp!("19655364901125310778629982245392767297984820167114789563931170716695750865678"),
+        MontFp!("10695212283696096281132213692300627346215324066996398339089365835910730561368"),
+        MontFp!("17455827037964560948521638828961298975187156274841334841074216120311558804710"),
+    ],
+    [
+        MontFp!("4382628454630801450077593442523632674983545561122254229775876033334437860489"),
+        MontFp!("2989679808908579687765205532943224072820070594304724633625888454150084631070"),
+        MontFp!("11805110513334566809098991762079653200819786354513225301120697798603509678793"),
+        MontFp!("11155601993809199490094826448585668454159517005968097566996288487138255635079"),
+        MontFp!("20815808565222781796761554019974947099963702926722486604443826341123930622896"),
+        MontFp!("13761986205307878615460321377264044874010177269812603883540272922422883198979"),
+        MontFp!("4128370443753246025606114169149035926146394950057754901868026654335917771101"),
+        MontFp!("9124459754124711043815747649012164723935008821275709951889811488049411892152"),
+        MontFp!("9090302853448501945809598864330384875455576758214379286451409039855851539841"),
+        MontFp!("2611158043123761410817152601927516372222739024190920897371539155273224585905"),
+        MontFp!("7810155525349201315441227323507184944737106980192978514457375337581213081055"),
+    ],
+    // Second full
+    [
+        MontFp!("18328503113772388769765260234570641415081417900145352728842854263561232438288"),
+        MontFp!("237123037398988309289930744284813441581716633481073379308753775064025864085"),
+        MontFp!("1703922543704124573472350868371820728886443372137290454699485327716109471338"),
+        MontFp!("11303106151640297276547682977648977961130813769323129464984120148868796657869"),
+        MontFp!("780835119818217499025356174332702814577120774118181250962295467182217505278"),
+        MontFp!("7513776436281774240010548782707301011961852707217067098086617267751060890346"),
+        MontFp!("7053386733865795782344919942070418964042982090173077686333893187474076408826"),
+        MontFp!("15388377229685250353999590264475768848666849601695837177100149716633411294502"),
+        MontFp!("18619784384287698574679414094727959444475916860934398666937358687019863556637"),
+        MontFp!("6843956467116800550015577789758902600855297665349663446001269088726857226048"),
+        MontFp!("15027464760260005079316932218316611292633334115528481474392667994765319146064"),
+    ],
+    [
+        MontFp!("3113497944921123135464097580210634621999169473508798646842600013742294203321"),
+        MontFp!("13922875552185745353363519166943408041900501129716418917715862175817917028807"),
+        MontFp!("6529143033058355347608548436453630783222625692384446386966623567146527415664"),
+        MontFp!("10224300829854530831381318053279338710395220281107133842738514322747972858570"),
+        MontFp!("20406735123273907201110501114056492687992916259913310564107896450511212255632"),
+        MontFp!("14266011897697023567365526625467367950062937074006135166584691625548787309637"),
+        MontFp!("6266823350995327247595338658995148275348018579417278062676982980758032485160"),
+        MontFp!("13382224019010393958018966744541120649420499028243672845532376433487586111361"),
+        MontFp!("17219221786488657036851893458562210759167416100910882352030211878162886142909"),
+        MontFp!("20162856829897101788693886235042939518715128237037117615656868509223185121680"),
+        MontFp!("19255871117980259796107225408238738190418971755264416661458453217281817348088"),
+    ],
+    [
+        MontFp!("14978990393744959794726413403754476561984809686673856442658550453303982912228"),
+        MontFp!("19671827853594912540136789344716532059747421261482796306917878259275666282337"),
+        MontFp!("10732390517629598846596313835253769945253387192277550975463036830136687386844"),
+        MontFp!("17133415435299159639005309603217597288690767318016826234871049396559867937550"),
+        MontFp!("4238294829801619171082019239832000810131476156600856298143082034448277968560"),
+        MontFp!("7722327574874647121890494265704613777043492303801725732359520952354380593844"),
+        MontFp!("12398521387704975769381094211621106917110829744392349946808293674617176531795"),
+        MontFp!("11200776058775831613393435187103560072186926574904464246036376746754002391683"),
+        MontFp!("1438209332219971409989076556134294085612745796338690516891328286502107803440"),
+        MontFp!("2937570830929161760131625263024599999319751748792625900610926821498514203833"),
+        MontFp!("11043761204766219739712866580984621876221567501998807492499956572251345283273"),
+    ],
+    [
+        MontFp!("17161703263657344245212174231645949667297741070344916641526821962829393621412"),
+        MontFp!("18619070492541478298708071132730313137437250499943292747935022132254737182602"),
+        MontFp!("8379242582671699656964937417808578910591927550726975853520669689659712748923"),
+        MontFp!("4461581878275014470922963327129159901508275066328903674248853463321568123734"),
+        MontFp!("8814779309876923204577653695802478749454337142645142889405691969158642344463"),
+        MontFp!("5916090197404556150255058868159426544438846565532168821258584401868379000295"),
+        MontFp!("13450130187167769463125976820336122102195298233114472585228206936434118870547"),
+        MontFp!("16312137321831916960674847439149202523298938366384118998520447019250845846949"),
+        MontFp!("3523905992883153108179926090860559860264215076129040324355490516029055984441"),
+        MontFp!("9941544263434232818617581658227915119072214833223636007382930887032695504069"),
+        MontFp!("5866421591020072450638507367259603444231034674337808358938713064987239652864"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("15249792920950763850993517571920601821568311809174912509031437118050063777525"),
+        MontFp!("9921562618684201533699293485620188871082795988970107962425978745591461148033"),
+        MontFp!("5414565587238798987874533039507833069864773781235217517734130524969939419113"),
+        MontFp!("11085088558016600803149927568829483866020077054827488081829062938808919799801"),
+        MontFp!("14625818170426402577283649455001357862456467374537021301451880577607106726350"),
+        MontFp!("11633423263664104506250995123678976351167359685182986641330218055115412525059"),
+        MontFp!("850899676511673590463501492472742017261347013078251845952824541653369171366"),
+        MontFp!("1151196396804070641219917031443763313740170846357000808196046482153806050391"),
+        MontFp!("14335865562369989392722415692597018596062508514718062574213639729550753584518"),
+        MontFp!("16165235837546690396792041022833296046736592940683337326949013823518030323769"),
+        MontFp!("6408098377682813619850142098164780329355428625834955152400958576961120931380"),
+    ],
+    [
+        MontFp!("21423078154100258346688828020904418548554497199114189102725137286930868022538"),
+        MontFp!("21410217312460027364452766052404349950133415202888862471159726884511205456308"),
+        MontFp!("11096458349387432633781125315606380225216758142658673665437375204143306261827"),
+        MontFp!("8325016245207932555950059013310928498109578689420634828690059159861280125058"),
+        MontFp!("19120379171572846193451984132488836287003157955913585086569467602620121544962"),
+        MontFp!("15548527549295346334479152412121638073047322389003226521019746206475690857238"),
+        MontFp!("18510388922178653264949279980605433471616208226685385338922150015851847524587"),
+        MontFp!("10348792093961922144290405029634579505420677504732581441616360506905620826544"),
+        MontFp!("15620530873228503134208333316448182712901874887467615537478242394290577386453"),
+        MontFp!("20675095206674416295505702734315255961699360732886837937979451051773488542601"),
+        MontFp!("21623072135556656816074223163769126264910877148099315666172910436076676028248"),
+    ],
+    [
+        MontFp!("20081866552983231525843337497912436927329172443916196026679813450860013966767"),
+        MontFp!("18951602909834406133815764463249536936478826139929746486734639774975812472830"),
+        MontFp!("2378298801465585253495854322066504889300467395616155363977621341822205588163"),
+        MontFp!("7488881447347091058309495011922138185162376888083687541108039236558708660027"),
+        MontFp!("7225960736862847948475548065508931552528126330579521291190635548005260333390"),
+        MontFp!("2970482373109443685421063487292183827150496377598629996362474558885993864176"),
+        MontFp!("21734396161383902760672518999845141937065773419899625136190973118533490737305"),
+        MontFp!("18986003927424880427453510819113519633513383085919786483726156674699889468820"),
+        MontFp!("9438591792749742425198760386567115998731905494024392289196006582112950891516"),
+        MontFp!("1973470346155075248881651300830631935042830364217214073906277058522882105581"),
+        MontFp!("13791817954605171888781711015587425735543149752618332811612012047724803625120"),
+    ],
+    [
+        MontFp!("19907506629242812934309078271817109951335091294976452370249234742626462372763"),
+        MontFp!("7913144809845970358468253448033359382532594356254540499933138806226450398795"),
+        MontFp!("18786719915196826164016145948631708915544945593653634575689240810328854731069"),
+        MontFp!("6216910690440344513687669938632060553287695043116654216600911463719413604341"),
+        MontFp!("13963849909448408572632889978998938642084255012365277702934245702305832007005"),
+        MontFp!("15044749345099947962217476120263824356898437745229321420257770262954985403569"),
+        MontFp!("16364542436173489908162544844694842746117292595057249540926274681176755002520"),
+        MontFp!("18582462045999492294572047486602601352613220856614634390669602502489215736186"),
+        MontFp!("17106926147340558311732938581031578597532846523271406141897942990261560966411"),
+        MontFp!("21322204968737434192679865858477095835509790782891920152044718985713652659654"),
+        MontFp!("5180799590809942717590072710973007480225145652031514943521639316776384894144"),
+    ],
+    [
+        MontFp!("17327427769240537767056224186269899694170666500703858813203303301835294225360"),
+        MontFp!("12795226231932513901983143961810913124288321956641191561823103283353203953207"),
+        MontFp!("21372571405151778511626450096485639101933984498294796899325401511071517993005"),
+        MontFp!("17332300051629640554924347563178488077648079908152291094969979591786473880576"),
+        MontFp!("15196566643676483159109284476812079407469293035334983717971092729005139246767"),
+        MontFp!("11231435533605861369104078150337976075098864909987541459896164149690464379912"),
+        MontFp!("2542242881481757350297627187544723135156543853134475677976341602496676452300"),
+        MontFp!("8414397814915895101029770303847330205837773505982623383796193602695811122514"),
+        MontFp!("20884120591077768210550361293303217187539921930869577755703150175746557517590"),
+        MontFp!("890887689926165781226591337399563217015154445631688026491595317246205069769"),
+        MontFp!("7911456647213080390137926613780820211986390960677915664339039845274033704843"),
+    ],
+    [
+        MontFp!("8086506254215085366863905182926378819432294332631815038288539116592866850975"),
+        MontFp!("17073560037383161517623747613125771654997798954263090273202401066518468858416"),
+        MontFp!("7837662874931236958961077774488634261606966280628598881837875736120392183663"),
+        MontFp!("1858593536177571875355498014960393860692560832490490994119420708212532861823"),
+        MontFp!("2029815832061982114925482969267067531992443107339599745974909462412297854269"),
+        MontFp!("9638056659007828434670080110322180928336402529095853481855440674860675897358"),
+        MontFp!("12340090961747329883452841216385303705169146011720720105809463143491658552866"),
+        MontFp!("8529510825845236935037301291679257193837435275552609577171369718448324660013"),
+        MontFp!("4191225223853834143599110761318464286196261585898512902425773974473427757456"),
+        MontFp!("21023319679135760820789157052868478773370737850886002789313940767424256194356"),
+        MontFp!("2142526213326613906831098262446296658577569180284637200640763867788179991570"),
+    ],
+    [
+        MontFp!("4585042017455545746057351957720948202590802829528217263388726369695890670411"),
+        MontFp!("10010708638531752335740573636867640657039858389864446260449096531856416238708"),
+        MontFp!("12350783923218275606957028867683130968505845112714635024839732952215498877130"),
+        MontFp!("11964414264447046767815837108295373861475122940092369903025175128013924386713"),
+        MontFp!("18615506936496648840383399989920850970951406181285583088268789582149764054418"),
+        MontFp!("14310480127014971068920522498055725465578544829224822580493909725079522528728"),
+        MontFp!("19209887743481507043830894286806384055543596834772181434542567255388724841969"),
+        MontFp!("9558553005299269735641084020216560749888705968385627813150807919487080187993"),
+        MontFp!("16359179198438658598155755638987769554841536772865629517841526255130745409879"),
+        MontFp!("13009386603860183254204895522650012288119732961876401784040013994677688723325"),
+        MontFp!("16165167702867558446013999607792716532235725666199142971707303433269257270111"),
+    ],
+    [
+        MontFp!("9526255138490973975321568846030367381138407886665647949792126204681244064012"),
+        MontFp!("11173959697998471600463637717719289778917744248391051771189332133876606070906"),
+        MontFp!("8924806383303834750473706479936998674798746387089627706877519918521882863256"),
+        MontFp!("12086306023907343271920056592137012844278234154616893254064962297944646888123"),
+        MontFp!("20523426725749375175935122656912302902564898343946638208821839160006976692150"),
+        MontFp!("5207650950010803883388912523741229153368067016192964642079270816449299041225"),
+        MontFp!("1323145474328028634780912405048390126320417262412962953050466352509016682042"),
+        MontFp!("15985641921260285694054233699922160344414776687043687582488491933807565444789"),
+        MontFp!("13031771899737217701535545098380455304311851135903656399978477292561795000214"),
+        MontFp!("12687226379083740035799525440904048416465917656850081330730343990236073218003"),
+        MontFp!("5180214195408850700722613770944601169370584228844657891855699394648642429923"),
+    ],
+    [
+        MontFp!("1934847650429153525882808430984088614158092322287601778579498068360011630130"),
+        MontFp!("13221691213669397834454903625729859574410480120034103769424898865047475910400"),
+        MontFp!("7358428584159841472154153892839275459119229492180053472830555381908577651936"),
+        MontFp!("10890590867941343184378544765218808644776443230231834463183835780142311216436"),
+        MontFp!("18106794932751537043075991633023418950145862470753313409722049187095789146702"),
+        MontFp!("20653121417027117499755994750040261936029675942959877982604976723194002882577"),
+        MontFp!("14917693547715740204091617501230563717468258023142717832164274356453628117609"),
+        MontFp!("12588115399854852983923905079011727575933343603816172388719899888494451203866"),
+        MontFp!("8737187804839661132607601320524529444256555649101505512380882940221377341037"),
+        MontFp!("20181178129036534248081631452735124104458169744675071995191727629040780050092"),
+        MontFp!("11847774883596070919125373409969812806188814542338127843683622869859801757028"),
+    ],
+    [
+        MontFp!("11792352762436629909341171173296810156800556812208317414203786807634354583560"),
+        MontFp!("5039046929001603921830951923781308597413960825857893817665425268295870771062"),
+        MontFp!("12894400705986579725245788498699203221654445738734999525700614560206785491732"),
+        MontFp!("791109735149055364851227398853119460170782218988367975658618461497391330160"),
+        MontFp!("9962476109282060862882002156903460806939219308562214446822701651882476052460"),
+        MontFp!("1691961422009775486402337895262889415967004356867228407841177856000156435254"),
+        MontFp!("13137802539241815005932694907183711832661205367415214501122657136334369092290"),
+        MontFp!("14887252261014844625921142022701177088174316290502741584519763408145964517832"),
+        MontFp!("4710184813279024135486906523528903891606006797755655291767343324947631364837"),
+        MontFp!("3801875732725114698561125696836343357073399124260852478317754268545097096329"),
+        MontFp!("9198233237012512530002016044631468704269680036230256629514440524866699707396"),
+    ],
+    [
+        MontFp!("19696310211066978020836317292330659233588020682368119069383469865122133603780"),
+        MontFp!("3893729283153055478198590025767324126269879907832064182575052081004216821718"),
+        MontFp!("17970322286541481482483545514302125656124172449128411566036569217140609597331"),
+        MontFp!("3173691464765770821621367532348960211199783691357143005417355252781889173280"),
+        MontFp!("2243542900289123996173306626857758207765925487218044091235839910321809834188"),
+        MontFp!("9813067765525696381929832954440764980538359181380263390331469145209686852182"),
+        MontFp!("7510903347384066721813722580189668539045635640361656120910145998846374499699"),
+        MontFp!("6798424040308056706713925899327404372610976624998869962003671422212921682045"),
+        MontFp!("13151104198292309578579089832681402880200764912297978086539824832412224275732"),
+        MontFp!("2808835590734075710953411057250272995919027932824027538775542577464926767888"),
+        MontFp!("12588027297759258617444062604210692398005597744782878589643963326768133974361"),
+    ],
+    [
+        MontFp!("20852428689501418456174033597851660248293428726014401905473174242849532966301"),
+        MontFp!("21296758342898944987847487225137782473520558597036376704831839057822676021941"),
+        MontFp!("17749269130031982625604134600446353874123371420684509963863640790293640598822"),
+        MontFp!("12696062143950532198094822890688877675740383270827145944853466311043725527586"),
+        MontFp!("10234631072965977425954576972633890272283449053659669736631819751429358646105"),
+        MontFp!("15090656934406651728161310654614148547687401766770336347796859883364028754099"),
+        MontFp!("20297283938807526130287519089364625872430415084303524848853727877999978340129"),
+        MontFp!("4196012712072569404315829685460688625941300450108222372646584487742105064258"),
+        MontFp!("7048902004425912498834833883033670218379565315927717075817051690008258707008"),
+        MontFp!("10997088676112427186865690409423506551772833502185768664867716439827194601092"),
+        MontFp!("16660341545280246485425051709291678711850828822636537086457121822086705580853"),
+    ],
+    [
+        MontFp!("7507040282824500274626435275567162035280839409294222666356976034789594111255"),
+        MontFp!("16582934771732736721752353176711248084060824111646755305570354937871610952840"),
+        MontFp!("8438441158635690733311342509710555256926785449251473129940736660330253698675"),
+        MontFp!("5997879363655967621652122271982901641685668225243330913415373444659935275798"),
+        MontFp!("377781155818540738714095913828188470350179572448699653767572496979822660266"),
+        MontFp!("21256042944489939677834126729197446597005830228623173527229465226360970832340"),
+        MontFp!("13462424045682641929202812263623136219335301688716109209361496824976128063539"),
+        MontFp!("16369985325316675741289392258637854017776166143141100299636544614904713590628"),
+        MontFp!("19352389303901189710313048379405187204819083083443266317372096723889889111665"),
+        MontFp!("10276552469336314142974101263431204498273961662600910736694134016860746072245"),
+        MontFp!("18939965576088516958025629296747895354615121399053610843001474455787438484084"),
+    ],
+    [
+        MontFp!("16990956322664851054201977827659189601868639970054898553161570861097544812211"),
+        MontFp!("9274107214677498542007885890977699632511964198928324688228114708372115109190"),
+        MontFp!("19938102848869576488504727710754065647382136279175127752338128473551869244856"),
+        MontFp!("10947279549049485525804912961584261533182913140524900494781425525696586998449"),
+        MontFp!("17561385783620224695956276284426542193322408499898012489406312644517964425011"),
+        MontFp!("18549237431132768472559432396178388563409301393950605704070488347475233640622"),
+        MontFp!("4658944912168763919889884489643908669977608714692544551960581141528777169667"),
+        MontFp!("14358732577825715965736448712278683090180078342840106686279626250756225140484"),
+        MontFp!("12281258853616996819181958497578187729715864708853144319900642420503103456870"),
+        MontFp!("4069586715276899433893814672543891090785738315323966746105563017207510306537"),
+        MontFp!("15392338154382717986608388646339008932890583126043490476751054331156284554276"),
+    ],
+    [
+        MontFp!("4517279215584833397018875849825808766631742728356316383241841280072723947253"),
+        MontFp!("11495036315995422771228762992950108862694073551665420165823433137577472792783"),
+        MontFp!("3488400840286926839516544735442894876148193865245875295688572119503866691461"),
+        MontFp!("4483680161275568275540790250446502401130629200290761781427443716038558729253"),
+        MontFp!("3942379126490099533582685851970104926928015397351808560877929535138811915511"),
+        MontFp!("11401623410099323944960285754389435394954200879740972352984594598244147798394"),
+        MontFp!("1704354226179051409424294551695786920602664449209459493691051505451583669264"),
+        MontFp!("6126483205301561395856828080510799275633402498512671992649940369928504550860"),
+        MontFp!("13606061849345999397793916269931170782757704442972401347887622528829626440142"),
+        MontFp!("15164492401059698496285802476110470458129619713288710918549993367398699891422"),
+        MontFp!("3351459252264231792942138342057542378418534651418845818443384064630601195925"),
+    ],
+    [
+        MontFp!("2825813294993932054460723098369264495005871082754465730530978747926493034970"),
+        MontFp!("10630213694333677464069113775948160876773011480314305970870772509710784295967"),
+        MontFp!("17166444840808098079862920467594387590120730690478215313452389257289409232274"),
+        MontFp!("15584725536763467888732670366582920601197464509911998898151204840990765872853"),
+        MontFp!("3842723796922381697995350228115144219456021393259361082360164730224542750465"),
+        MontFp!("4831891679443026612628331848829793784542170442278947325598774522135713804734"),
+        MontFp!("7600866468317146506151393588648544489445105617254544624521183070936649742997"),
+        MontFp!("10583687645778325936823793169279337717905917984785560251868239070559362307278"),
+        MontFp!("6634607933824414124502549710741812867975263049249231353927669720791801705509"),
+        MontFp!("21200609344869125199593456019818101515444150712862469998882355110348140005358"),
+        MontFp!("14361714336789127058022203168794208348007919167174968797533361168719704534209"),
+    ],
+    [
+        MontFp!("1575806469795254200103212820564180016945577757735332906646132503588215912546"),
+        MontFp!("10628306906313535336091114056786842183955113155572070406112143573768388478883"),
+        MontFp!("3089301712473516586340147971264840334080312453733093398291612940108596635146"),
+        MontFp!("8645795529068430110488497380845362622141451911891312096721911200350500642213"),
+        MontFp!("16022924422796267113820566797422451700895182144910797483992348492987850640540"),
+        MontFp!("21668559624091676943635408329170173151410363888407430983622558174006481336306"),
+        MontFp!("2072754212505196807037151376956574080111414803663052483852517767215769333105"),
+        MontFp!("344312312360359816789223515659978323664333965848417356296539814750513103748"),
+        MontFp!("8220091259162471818305840126401210949950597324344081712931466340258084434570"),
+        MontFp!("8081153019090920515448830088459971939645826679918559179389404338475619307845"),
+        MontFp!("21449241241855558649730447089457892378091011965030006823417837265982774602267"),
+    ],
+    [
+        MontFp!("6067510299282418337933484892144351034696356612154920492037935939636907603847"),
+        MontFp!("21026143172839641048848076059364862575379425612028621103404211601411048352556"),
+        MontFp!("6070438567473697541030303531314185338530006003062193748841965554522662407077"),
+        MontFp!("8255130291759907832990229603685682560848952190335394603030993899908706198932"),
+        MontFp!("9546680581091867708464426246566048171085730057856647964775027268282058986619"),
+        MontFp!("19031748019605060953238957886441808197649034817040423262874632275713470713752"),
+        MontFp!("17096522586030137478189913833428299115382271406265963567569748424828047092166"),
+        MontFp!("14391874539708946761001075594593462740437739522325069415219274013650732876769"),
+        MontFp!("5044944855410335767979170189040984719402886766879289090117403978287180516155"),
+        MontFp!("12039442478120524220891851909305716836138223152615593874775855237995395028479"),
+        MontFp!("3523930243951081542915282337519662387190599423550808129910485998252637662407"),
+    ],
+    [
+        MontFp!("21645562630446380089971004272761942543158290220498842219097308729146973947053"),
+        MontFp!("9635476273420949200636403482900193969961822409725076345852310152023632001652"),
+        MontFp!("20108896868320980092863545615065555118659548096203643467891646750271137801454"),
+        MontFp!("9185755445384903374596265352827852278482253313517817304360514098507824628136"),
+        MontFp!("15729448650969486701474329849747687235995473855623732466626603841221282908291"),
+        MontFp!("17997496197003273211403721001065741564292124821674411166555153239941325690779"),
+        MontFp!("1543484160386036195130212436149054066156454121707864502325718576708010327712"),
+        MontFp!("16363634132599571248968010449034783328746466576733841612125967259037812456762"),
+        MontFp!("11430550344567145066773020651025090506980499195413761807852670685184554749682"),
+        MontFp!("6562474642758509619875130343887830140546824045253242049540363976665708088792"),
+        MontFp!("3689788469446976140319903688077885060368720863836266384022598571957269060684"),
+    ],
+    [
+        MontFp!("946161443553297471232017467760713330784613638346698353392670707850882897488"),
+        MontFp!("17775152994890017803033120420023910950366134526347402563464114996856742054838"),
+        MontFp!("18013595561755224808896799093133992096629438550898908281663802197342115179235"),
+        MontFp!("19550347733494203322271272376490635125389454301454207200256111665786140278650"),
+        MontFp!("12387591141491143807678990173849961323772837586376210707337202980616282598236"),
+        MontFp!("15615445216195972598868146032329101929277001754848917412471279643674750521844"),
+        MontFp!("11157377570334948360516367332475698893237579888172217677485190937247699033770"),
+        MontFp!("7130958205578403403951515578418204249480908544151977781217247531796937245530"),
+        MontFp!("13077463747714835214281685128657845926832593706922024570198457201391820941874"),
+        MontFp!("20874866618752897980777992983172936086725928829995747401713185587049763764007"),
+        MontFp!("9529341163966534350579350780474173060797076273832542721772111561732792505333"),
+    ],
+    [
+        MontFp!("11142469391388881243303156644691476683457853027534314004453288809998298235798"),
+        MontFp!("15759641247899199119184149872340402267600476479959598970774176792522105838715"),
+        MontFp!("4932052011278518183097054145820640615211087138945458940865038794988474891006"),
+        MontFp!("10432003521315545222242540576886148188507417318972677351911763831484066879233"),
+        MontFp!("17415623007807480250676335953220588929877876653555913668269587494499596716638"),
+        MontFp!("6058502442301803313347169632367675883843035525640290470399543491756905404121"),
+        MontFp!("4937981332834016148179351587580087161307555148093009345111656135565068163315"),
+        MontFp!("4767276541447291442260238717093680167532207332378535289044385385412677713720"),
+        MontFp!("3479278227276561004751092786681066281912177752486503430342235086646969267132"),
+        MontFp!("7943278285140068849348170498689330975011840215116541312829451500334428180149"),
+        MontFp!("17789865444817191695636689430256327163274376252861234888599341795716694863906"),
+    ],
+    [
+        MontFp!("11313788642745794682263060233193097297009786646629777858110154245289610960322"),
+        MontFp!("15451318802981646436556355654707114069777551322432702430595933898879175343955"),
+        MontFp!("7517270022604650172540378758621070031306040808100514057036893386092388842581"),
+        MontFp!("10009597627594120061689516706238257978394705225993629762944338270984243419952"),
+        MontFp!("16287228641302237661911798504390175297327206600486567815634358152959586818336"),
+        MontFp!("16488249714776419229986970659608164306511103745532215897060984940903927940510"),
+        MontFp!("13354728687549843942265910857129412843774195483459170542959107134210371522923"),
+        MontFp!("5514821690255816781301952402733589181643174815400146100135235253605240813486"),
+        MontFp!("5770103106430309708547950317514210165673407448643264387718101428086149468878"),
+        MontFp!("2694023365380190286709306630457152471177911482921639231487592222438630988204"),
+        MontFp!("2596360308486910596331175398746479488324950265788021808703834738076552537175"),
+    ],
+    [
+        MontFp!("9039714509260386661160667890165090643713802650277924766536703740888044388658"),
+        MontFp!("9604908742305952316295642934650340892998619318487917447839433792965436657411"),
+        MontFp!("10397877232904834686750242142278095498016920001087513253065011131758634311960"),
+        MontFp!("9647356378465005298504489726754445992380752731297711041297431711301119823740"),
+        MontFp!("2901124847048448064485279495316677205686659456282057720583010224075223188929"),
+        MontFp!("19604711253175713682339631659824057587938105276478055043815802354580551412854"),
+        MontFp!("13814073762260094871340458282144499467492241477826265605381954643255652812563"),
+        MontFp!("19191277082146641271114112092268339387374938745710902056016346575725741574791"),
+        MontFp!("4351438127825195342433511764688522108908810411430938576079426214421798123584"),
+        MontFp!("13791103210113222833666019051739255444684344392875106989928046003279497788407"),
+        MontFp!("21004705702783423413965111534445421935934565517891538415275359891592731977145"),
+    ],
+    [
+        MontFp!("2271618466337144497382665490697115072125990892802659816927722507215828359501"),
+        MontFp!("17558902460062424775395394774553055038697408789830072471787389990226026812332"),
+        MontFp!("19541727150539905151936633341362726755459275099586077305409909896489919664394"),
+        MontFp!("13692791587272861333747931194991249996571271400946738920492512367046518055252"),
+        MontFp!("16261859926552105019169611795692937520291616941485096436900016176047007140288"),
+        MontFp!("13364750885171681175943985569408970262762845797040643237076315743322239541667"),
+        MontFp!("13621972138767941419375174302334978784396954063748685093059444353200957635883"),
+        MontFp!("7816366958996751828525267034982378444982423551198678280151852851213963203655"),
+        MontFp!("5387588357364980194077356588147257085032575072648295273044945567343418708153"),
+        MontFp!("5561769804258805723102666784326040807434856561620360208220095378441903055863"),
+        MontFp!("2759015372384926293713393955452664141507032820282479368223024343744582995438"),
+    ],
+    [
+        MontFp!("13981134543106614704319578938205751472742054796552637642168049339888724312989"),
+        MontFp!("7990257879146478669584929211133379459065259065980981194790047745364505663434"),
+        MontFp!("11876127927930413842997415069288338406075999315865409152476435081888707100248"),
+        MontFp!("16570551097531678234333612414250188586376313376162262758608423816369347322204"),
+        MontFp!("2912142129158840181609698148893520848427743435752721748118484524307819669362"),
+        MontFp!("21302711682094568025057587941584825132522581272692339045586256377620698610739"),
+        MontFp!("12312191496853376716581371884132892074646637957572159612619070514615509157537"),
+        MontFp!("6766172753918389200603997763713298721859536050788836585740520353915599111166"),
+        MontFp!("11495484070470791342676704007304510997655718568304345126153023374072812971624"),
+        MontFp!("20811664890504244597768955277493527673608205732118233091518542968150115526854"),
+        MontFp!("16063703627762167667966504135478113457485558849729490090362731172389730931393"),
+    ],
+    [
+        MontFp!("14866560644094965611504947304610358591133796414154206860995591970456047599477"),
+        MontFp!("14070826318654672995642970624813758954945583937085129314124463556918282721543"),
+        MontFp!("2479865882631638665546581282869746245766113042059506929860680221143260292935"),
+        MontFp!("17398990555602339276684995684280302899074008602689308101178965669933564447317"),
+        MontFp!("608876042484390223586509032355843816374709085426455763238940304845396291392"),
+        MontFp!("19093816648588316664468783915406437520968219066632631005915358745999525844761"),
+        MontFp!("6752057404464894771427129594636749868828376343179986455821808045648379935245"),
+        MontFp!("19888182199106435309043037013966294783272588579928340631894231065863897158437"),
+        MontFp!("10082296017960550499269875102368612265371627577709879253045116718197037435449"),
+        MontFp!("14616172738414901831631177911088055616005574740810051337096702495520738285374"),
+        MontFp!("14075121030661423148825920501776601474873747801291899275268094618775991305078"),
+    ],
+    [
+        MontFp!("186569155903133115291205664388893859453551519692185893083420327670792162388"),
+        MontFp!("21299949831814715974370318270705239305629929085336938013302553408219032200083"),
+        MontFp!("10073139629403946009284887013173459030563893496973388848686052378045073830551"),
+        MontFp!("1412198475257996126786641438141729921783447169582336707894995512036569315500"),
+        MontFp!("7779964092821851708726534513875342218194584080198381624983100710844846404634"),
+        MontFp!("948257338952437735532120031007610523249359469819114853137850805544224196883"),
+        MontFp!("21258332661476723168534084283213459804574003007208501208541743948207937371185"),
+        MontFp!("3571248067320885077052671052657329120832155265049255115581589211873377624342"),
+        MontFp!("18767668432875868101830472546043294158876614861521938962321471373462978174897"),
+        MontFp!("9300839292250613795491408219504842564421473000721822643979051375966405606193"),
+        MontFp!("7064697496603343927134791624768930499810256237088252983584462738613126284623"),
+    ],
+    [
+        MontFp!("11853953981626198865163123682396255427116155876021473995289924391523536239496"),
+        MontFp!("19771876990095842377875081223218001588478929046615599374496234715810020326293"),
+        MontFp!("14316898881193279100266501073998404340884941240727243537292505454856307190130"),
+        MontFp!("14519359582636080510571261582400983934670011637905562203617314435861115398211"),
+        MontFp!("15526562931305845620715599917553561556373761549853248996847605985788604583130"),
+        MontFp!("13078262487107679022841832022599167182597313495640194703513299650288211974881"),
+        MontFp!("8245559318515118189428376002847388656528865763199194533482833563489439004385"),
+        MontFp!("14003329258302578586391126522614048791257224051921678844212914592624869865893"),
+        MontFp!("11725000422609501650997806599141176528384787601555518111375908215801780233121"),
+        MontFp!("16350891150329350826705554113837971740496662498788954571303936439413078899269"),
+        MontFp!("6411076187467760129645481080704474318810486795627076355267125729971512535475"),
+    ],
+    [
+        MontFp!("21018151777353913573383620107187764987866574825379316352340980146357643078382"),
+        MontFp!("11358423056150266238825561031091840555431271536659827607676154506546242135862"),
+        MontFp!("19065833030377987569441381994102534825367123386452612836837050555888073644388"),
+        MontFp!("2562667974917478689245733861669928078902565222389031120733094553006681886960"),
+        MontFp!("3471326938285931978123873023764646019622642761349433536231294291602304868485"),
+        MontFp!("2563009390241284210001442885151389460822558817932742264596703331331069807625"),
+        MontFp!("1840397005312580276560341795534676176103474806443827954038672349702326279641"),
+        MontFp!("13608853165319143662474434916965520937512197511217746962619929048696566631710"),
+        MontFp!("19629513285164329969551640449451527909298649847319347893901982383162686335860"),
+        MontFp!("15394390010333989214892827255905093646577605209323130947826658910240751517983"),
+        MontFp!("12111906652703403073758441298171362667165880553934054594322507856217299245492"),
+    ],
+    [
+        MontFp!("4297972575899320549167090598678621826992022863628750628394635867928789323371"),
+        MontFp!("13452780308396674980210210234355016032880538147426066947029982271570560372078"),
+        MontFp!("2831837563806484576977992168295535809035697292555489411927347217736327891352"),
+        MontFp!("16435334356674630023459784576727565801947528558756855054741604645713144334059"),
+        MontFp!("4127342592978010203327455190780821692230702238156658795505993209368069797198"),
+        MontFp!("19169399182658468533946629204100516992531108319585640665183465573285725904462"),
+        MontFp!("8598406387986750884545663645357594697293280337319305106433909682884489291980"),
+        MontFp!("13371924317816745712309163118606793190008424593275425616115063429517298127531"),
+        MontFp!("6966840677637301630181597160079353321163372268840965853806074036299182143344"),
+        MontFp!("7360050884120437815429010939441335996325758450523147733433611465713273074289"),
+        MontFp!("18472779235592140583651215638681270421642809888489121199352426980884551699243"),
+    ],
+    [
+        MontFp!("20418315222771285674269327640703205319629639584244730201946908647328012868945"),
+        MontFp!("17026399731940643956418206353642733600139323525975091345342870617418039094945"),
+        MontFp!("12615211028716399762155462346481728092959316603121559521948867446476882144221"),
+        MontFp!("12396056279980752806946158389419170236239219009894918041607073720841998512650"),
+        MontFp!("3020267183116758520311575446823726815087071183512473845404855371056317502914"),
+        MontFp!("4656542918173262538192266136796850668036544742787442489947994092170369122585"),
+        MontFp!("6651580153776746866797134628474708113133308603326248742448594405127096716435"),
+        MontFp!("11937440848975251505322944134185172341897441221500443657141975704868428110206"),
+        MontFp!("1361826966800566560222081321267933925488529241685130208478269338961124266689"),
+        MontFp!("19943953771229014728170188452413560878330164558859793245021220185499985258316"),
+        MontFp!("5000555778712004519940673290591853777960346285299451454158729230859752281548"),
+    ],
+    [
+        MontFp!("8421588432304272431559534311544652626307929129739056740355326524785204863675"),
+        MontFp!("20295164315900629557422352618552527295246432971816033569783057482727719683327"),
+        MontFp!("19373368537162638225474243050103347264636555313351270279300938524044041811781"),
+        MontFp!("19301584842175411162376576546656332386749822306022512981007234449440059593206"),
+        MontFp!("10803063363791701506227503212696578672701333662552932509544627248354076467851"),
+        MontFp!("21190447586310863247879892773912245039992873717104751727825265558975676297878"),
+        MontFp!("10001943442280168315414950100438358800275893831224620600570013850341287546634"),
+        MontFp!("11419108205602584425734602189435177828356990665164795815514509894518490834107"),
+        MontFp!("9176365528060199110731574560388345526295591893454204513780413140739307927521"),
+        MontFp!("14645264015971411259428809172021065485340465742768211283864072707152051283922"),
+        MontFp!("2059680394752128527783641175137415159902594391639901766513696812385555753758"),
+    ],
+    [
+        MontFp!("1362210878544210374207986445190412893419873862912439530157828224644264948744"),
+        MontFp!("1767020623225215375042202191031932704135774649037849280381750341452687445659"),
+        MontFp!("12814227243430147774278313161816717185894261558098935373927981841389635793984"),
+        MontFp!("3650392274313221017884542662769899210301363377135174682150190146413571526895"),
+        MontFp!("8605030053804448166307797789010404548793085578661466947278557642849458591843"),
+        MontFp!("16243873345435203695859375103717652101719490281058055690129195139240095336348"),
+        MontFp!("18253688126813406708458581768662237883226849901952565733241285746065046225854"),
+        MontFp!("16894577548232574374120767026426945561995165201786189772245769116072691636300"),
+        MontFp!("6423863987901820348928069732046971656713531289251833729857903917381295249467"),
+        MontFp!("2313252375346549956530493743826026444705500798866360084159932659530274395286"),
+        MontFp!("14351648867218654569586186362113600409735374191851776253843321710353903071560"),
+    ],
+    [
+        MontFp!("12748602702906129786642754668169435482057869301200357523545630557138115816573"),
+        MontFp!("9709541611470508424169916733595066328177420935464065317839768365879702321494"),
+        MontFp!("7178910033786777709713498702843323736165699178795466682345863412805925816311"),
+        MontFp!("3353312211975166615897632007255179570864390972587901806985844706830647691440"),
+        MontFp!("21333017166590824659869638126407263965780255207193356400088734092911593056348"),
+        MontFp!("5028007188794597820037691911407426228966175740057746443994889279713514676932"),
+        MontFp!("10712123321028508395224612143524241532236128389312765281020405990000255535908"),
+        MontFp!("9585591632158092894254183832493550005011787447364800638915694118244958639233"),
+        MontFp!("19409055735214428219964362626158681016506721676720000843605743187110212915626"),
+        MontFp!("7013515054956821958187738653419570061640238138610196975216698509582854871893"),
+        MontFp!("14195757172805460680435532998286722310304400118130745528870652671654335152142"),
+    ],
+    [
+        MontFp!("21642947172805342858920270288264451136332732954445458690700494722054302963125"),
+        MontFp!("17447291201981220908729619112892222175870413627521563259423394750686485556049"),
+        MontFp!("7142752135376494645636618098683429493620847661912582079125447091506892873792"),
+        MontFp!("11965386305718013298615263282817897360134689279593443592649060011294217114060"),
+        MontFp!("3269287439460269094755519346810063037578116295811194645623863516503159243267"),
+        MontFp!("2159120529508493457616143785556394024190164584915596617279717229000244189542"),
+        MontFp!("13216089736556667969033672749174760723927614320685905176389819791237404133418"),
+        MontFp!("5739580235677403106179902507340026453834485061930671765794215270028117113169"),
+        MontFp!("10146496637166180435280660177294964473638207568055892239396905092844948797979"),
+        MontFp!("1559489237751880726567698923572583310388557924219345825351973445625480867548"),
+        MontFp!("2596197625765120503438747432434144655985802369868414306286213301002200644404"),
+    ],
+    [
+        MontFp!("9161937832793127953923149604860857118908349157525975074572071369698144813595"),
+        MontFp!("6530106539927673920262526515085998672101393587740796643755609207665748320714"),
+        MontFp!("3454043461578374982297475090824066193432500488323777575207325753404726668622"),
+        MontFp!("3344507163548616813143529013320782527939556912905450999614978312225536850079"),
+        MontFp!("3495919320773160724078129285494188621289377270658655167666727615227933095154"),
+        MontFp!("5516726577701478990891722326081439827260225708304904918582005618283443949920"),
+        MontFp!("15205180713187999862939063125387810274995561110852513151225711366795547591281"),
+        MontFp!("6648123555675422871529580981673252698816139021904779960285680145979314385726"),
+        MontFp!("15841058754930028584592543963971426098015345537516384042315342065435733026794"),
+        MontFp!("11980445534925903182700789375967305825089780148976633769394511063611931605378"),
+        MontFp!("13752792603594569707463845404055123799321888887568435005345905056744143696677"),
+    ],
+    [
+        MontFp!("451907199992567080921227130990235823262116394775607208182891587168397627680"),
+        MontFp!("18806959341268394516719451015233616843300011700855654586308376758853129828525"),
+        MontFp!("11666933396513215238559883168577675591860013867821037377342211197150469047378"),
+        MontFp!("18261858072527055096686249862512380033913481610225001662137639137871862029384"),
+        MontFp!("21560391742011390943072232187892257373961479111877969400490351307926336615662"),
+        MontFp!("21610883506787217615067001898587573081135488752397283684823910785658887961932"),
+        MontFp!("8408073424459585535553155824732179764716727837637392334926567945546036849813"),
+        MontFp!("15210371269884178529776581719481988665617111145372052101377092280056914506280"),
+        MontFp!("12126920168856651470145408021353708994570254510986190646194434994722466503351"),
+        MontFp!("5069682791790920726869832051575701989172948895827442056320603681340757243410"),
+        MontFp!("5206562653380935478898854557078389821615981777078527007269200218469247241072"),
+    ],
+    [
+        MontFp!("20147037355698495391549064646435751868583938479186070809834102358533096530919"),
+        MontFp!("21648765692141622487379563405180427559394486974406039602691179361899957186441"),
+        MontFp!("20151743655500786952430656789085402151537047806266385436562459773879995501836"),
+        MontFp!("5934394754413383977455838937623522376367295023125030069688154658727645145368"),
+        MontFp!("12603931706566171371403975785994747990660901389421654410200684089833302667483"),
+        MontFp!("10026374951811566765702661526223829303823304592138671853309110795200798195351"),
+        MontFp!("20990348049520375634430049514368373247447730079841716683325070058824866344618"),
+        MontFp!("21619384435032220705428130345438986008128830023896849505537357845578039663569"),
+        MontFp!("13891656325513979012692764566925054630715484836057215275096343649598208636064"),
+        MontFp!("10516755320080836193722788097986010349704472844888704193817055904209452357159"),
+        MontFp!("1187924241706018031195408222997566778745910130631759501304388015015493122577"),
+    ],
+    [
+        MontFp!("18176236836230977144874543476758297865658817104610686283126581582188371550406"),
+        MontFp!("12485836949850082215229286502965907740610097753180405472080386359153328488121"),
+        MontFp!("11294821091195763819640094447903397628795163768975434879119673473115769322204"),
+        MontFp!("15235618132532149904626305329048631193060570832794115447207190941624057752196"),
+        MontFp!("5247748280389397626917162873894276536157877881047135043409628106456158304439"),
+        MontFp!("12327729816009770868743571696284061855435639336028566901611215454206952326402"),
+        MontFp!("16602949418050455338609614423670909880567257158111141482461606053345944875474"),
+        MontFp!("19481131052077011331591495382924133179323957631986844309716638852480977994007"),
+        MontFp!("14534922095860994138017014871440884145230839148117198707882202478464336849118"),
+        MontFp!("14698138249781405938575978017673685687915842935570150501408655390412744761438"),
+        MontFp!("2268825110634009232539217412695133214837154986621027045784924577498202527005"),
+    ],
+    [
+        MontFp!("48564023730318244345588908855562476416102457310638224830024196372769683274"),
+        MontFp!("13911429573364091016107598756829373924944177606409768266066645212252032518222"),
+        MontFp!("3072739629129037084628601278178937800555134277149245900071628775426363271424"),
+        MontFp!("17673164704069533606602950374212967524179027652656167791832581708629862878595"),
+        MontFp!("13337993423730131992645864661190106010697884214050744564788997133642079615746"),
+        MontFp!("10864361443255322638825945640705455870794346349925711501403405779308411872877"),
+        MontFp!("12776251935559898232336391171201768361485692108948381787636663233683642720570"),
+        MontFp!("5216617488642405414264845781989153364217494006051772154230377224854279460364"),
+        MontFp!("19003202417167005941636726827739223707886908723467287344441657245420838333458"),
+        MontFp!("1782933238160323149082341139973263731861637960761110984576354403382733192508"),
+        MontFp!("14049307335094461452125704957817909153045723261672970005965857346428556786126"),
+    ],
+    [
+        MontFp!("602292860913070228058919352868668861608870961459967730841598276439118039884"),
+        MontFp!("4532222937868395031497077142687900610420285824272982687191592830322532698890"),
+        MontFp!("2099811981776987988652353966067242893298763890123265819355994207644365313244"),
+        MontFp!("14963517993601358850130080519783504904964131507655857293183966569857011289997"),
+        MontFp!("5628630657751613134050345890283140633799644494629647136869171968642164797575"),
+        MontFp!("20616991532748403949152312405329497795753361035307036075488592441729795933793"),
+        MontFp!("9449735601866166994904286297362590639752895462487835902681383517319419222042"),
+        MontFp!("4855933188947772127110139132590210785552455770275991391814399396690242166773"),
+        MontFp!("5236021116842873402180213939626155398638345682608182461380158609761274518585"),
+        MontFp!("8831771317829874397651604675303149337495578376535991692269465791612651585623"),
+        MontFp!("3088758799070584868972772977513492687395394215140736055156925465547111629717"),
+    ],
+    [
+        MontFp!("9647320052871832007164877116869148540664111922421469306708391658695763892785"),
+        MontFp!("13710265959446629407575266748628250489155448638018691055085275421674700880156"),
+        MontFp!("3990937300186809757319839603902135620837406120640478356311388415953195593911"),
+        MontFp!("14183797850975043036582133930342253303942122026304366900989797745492038794070"),
+        MontFp!("7977072622674677196160507229659485240881624765782396719467815964585050639939"),
+        MontFp!("19241094982310069445586766283605264689470697759972104264399773392459234417767"),
+        MontFp!("1955047691838825101723033568109446280275825112435318858983042058841632309045"),
+        MontFp!("19123726709557210987172343550395139275104840920797922301604764165695899093438"),
+        MontFp!("11017985516143054945716095457545428692165844636350104202156363406141221710733"),
+        MontFp!("6698050162913212144220380056054973138261583127187888344395668170533632399574"),
+        MontFp!("9966258961247618428931643789269822918034016436345337589754558490493039380634"),
+    ],
+    [
+        MontFp!("5992044103018260979899747087179477222620402658511576502983751749529532417546"),
+        MontFp!("4323136703775346257297028867845707816483717723305960372757928628051725499745"),
+        MontFp!("21794175040927871006132858751099842549067139289302899933403157116500725993565"),
+        MontFp!("4135205477235797120209733594064542822286242795709961420067213468324705376738"),
+        MontFp!("10502424667236605030274589825431626273558506810276755098371543260305259735173"),
+        MontFp!("14846816339594032738719600614652189538461600190221870378597670293625955865265"),
+        MontFp!("2524366986896165752091070306635323844562001546653320749202855717200081979142"),
+        MontFp!("20323646694453380576115350130108187509393437723558716335846490151009228206070"),
+        MontFp!("17922328316124774235198837264790120266787900323072144966876142176581863552114"),
+        MontFp!("1182636510606531787673615350114833983759075243161678674924493347851641505038"),
+        MontFp!("3207389967170019411093119580069334588690357331504170475184259410760933425232"),
+    ],
+    [
+        MontFp!("8369192092815635351510306100580533943309224255100566350954698456188479225517"),
+        MontFp!("12030967886392195699293618525716046167148513246572831396936379799444387335164"),
+        MontFp!("14022141444250217995147289887822373716179013971005541169826207913322588624137"),
+        MontFp!("1819830254074794257713310243249746692467827341614315920377358118637602832394"),
+        MontFp!("5214222934428733796770137135908910242359102117443480657729178969918179851403"),
+        MontFp!("18138416073511031005117669443972799853597050093891426716335475360173308913456"),
+        MontFp!("15787040542336098200132680028140910620950068073851656692331927514281562129727"),
+        MontFp!("821824702031897351041935750619058900741428025283533564678462466715419856156"),
+        MontFp!("13945650681120508256113693182498673378848691411623235404408416652983710360306"),
+        MontFp!("21565424898814963463657730321537398782420569248706699928677873449861325896156"),
+        MontFp!("19609389297655053996872511820224772969554473578452634334180619061868015512289"),
+    ],
+    [
+        MontFp!("11052162512235765382251445485034453773059444701618444976454738201341222665559"),
+        MontFp!("12166351899911550354968692629609980749815884172876848625284742219657445788950"),
+        MontFp!("9357004866878440721090407569238169828150449366762652916034388742054494762892"),
+        MontFp!("12114234396159313460221530030197986658691629189646106573755288837341527114909"),
+        MontFp!("20697910091918282046230152102481351960195609070206375824763685775389954486953"),
+        MontFp!("21217736707553075351032987119547432929010871168087324138739787787951139001106"),
+        MontFp!("17381364776399568820088774691862728632279841729367582228657079823795538435769"),
+        MontFp!("20943148878105082737615418415826125298002846495574584317442899738454169666093"),
+        MontFp!("7269215523851335046672197634746347471983448536436098948086942864058221294848"),
+        MontFp!("11453469784625194830295468663538063129244082440999065497133984430619118688913"),
+        MontFp!("21018146386490226380790917971415394820859127890200962707883416995754459780694"),
+    ],
+    [
+        MontFp!("4499764602796942905287899120539899295087557389188509075023605344029409757976"),
+        MontFp!("6253534478331781543005728896589051616547370996281199587944537185086057029207"),
+        MontFp!("11552217017582571341399470106065323830802102428782340770897703340827468796773"),
+        MontFp!("10819374663920577173719455195284609222419659549106826920462161534187158953031"),
+        MontFp!("14349899656862395011953779288216924779057701185080247070042551534943737976358"),
+        MontFp!("909510146269051589147289065017979551607491214019225341737807929286505047312"),
+        MontFp!("7822991049400510485087251716608572124487352922137650148085367861004077510787"),
+        MontFp!("725869830997965165130427627260429551001667760503255008495920045139979612999"),
+        MontFp!("16876874328062330437058858063404447748425817762641981976045186363782457609729"),
+        MontFp!("5386742002628492275210178331450945300388007254427357938003565527570148075266"),
+        MontFp!("5628231453725040085705711150750978455046683883113398227942371706440013519778"),
+    ],
+    [
+        MontFp!("5207331605257641510707403983352805778295440159078505882865873548456617745902"),
+        MontFp!("10535586157456772677635305329613970037048119551546559771279117980166707049697"),
+        MontFp!("10634858349767776643334691454267563140934605084766184133586786266071708634005"),
+        MontFp!("8194830686373761187394649827049013218313677678976649876752071409303459578949"),
+        MontFp!("7100588172111463994475475315895964854310052675103208262359133193362837864219"),
+        MontFp!("12698529806569743134165801249102690453926582725077752506169518961941228191732"),
+        MontFp!("3192504222109013167359339531059877081526474219178099463731713708944911915094"),
+        MontFp!("4863002263813052668246974534880517465192842993905851311847938713684622052826"),
+        MontFp!("5692128778265216733946522388190452257048514412064102477216511313048856040820"),
+        MontFp!("10126422206202050217110361146045359045967276838268265874082659894234609632351"),
+        MontFp!("5816818864633980489627669987512782856651289984181002178349070605011354887917"),
+    ],
+    [
+        MontFp!("6571596820513333489228887403836877987952738840623146829273932098108053062770"),
+        MontFp!("14089943097204317257370418561461590134190932157472606744328891293369209454647"),
+        MontFp!("15650892875459028952140088123225234819706577582764200222337535746289113550733"),
+        MontFp!("15234537030902822319330116055095343429285315370864285347784850359841976501754"),
+        MontFp!("19442144493035982963093880999374393289974808397308087180018619507528159896278"),
+        MontFp!("4787721462177588970261549242306006751465139812381940463247588627972131957846"),
+        MontFp!("21188266388402646362923963971922131649215999518682418035026104849712460470697"),
+        MontFp!("7670120618673949888866627327541465118295930474243507723982310418317698793909"),
+        MontFp!("5278076033158881738611446681468941913572289692389442858337761263328869905538"),
+        MontFp!("6812114686357362596670812436414685361641526159356770435320544521002690748983"),
+        MontFp!("14512519858142345768369977582630083215640443350401036244408402584958380156234"),
+    ],
+    [
+        MontFp!("2883669043634370578290606585906686768752718215322315377961330865510599230166"),
+        MontFp!("1390031372189262099215439174834826964629551818632345640955909690959488475368"),
+        MontFp!("2621604580741170950714666893803390793334876512814103152393976192943909783864"),
+        MontFp!("16238046050182542127054230631321316896861946156551854572617517416519877517341"),
+        MontFp!("17244586605437271554745538098470023522850216211045683035769492251097903894816"),
+        MontFp!("19367233841767536085266806989570452145418274769895229567119343509637411304432"),
+        MontFp!("60922694691641059020255299196606231058588207683679919735176896931621476211"),
+        MontFp!("11404572340596870708576661187798839793333697334049619025922813709736021654992"),
+        MontFp!("19297195320049214749532857744175644917624562154332216539771469808458364580570"),
+        MontFp!("20000477679980055675786972902810034261882771620364631737011618258163102706803"),
+        MontFp!("5145215345848317594229128468995966108988375785838942899274867896011577909800"),
+    ],
+    [
+        MontFp!("12745405048448113216886186019434813760835051470566703617056298583393105029140"),
+        MontFp!("6134671602520394562619244398452258969460576801019889394876295515660617122846"),
+        MontFp!("4914589878992627637690178006894707570950584383330674027731006564533772285206"),
+        MontFp!("12220903700447451099363813812957915640017509928028867600267100643383476677026"),
+        MontFp!("11628588304761135026225316417404056298817057087404654319307918387873030085506"),
+        MontFp!("17423007615206678675839176821651284038190814226425327926691523730687305701329"),
+        MontFp!("8965704645750924279210398083172931641857031322145961884520939048845530423732"),
+        MontFp!("20213900813945518409362660921232075042618186257655700796304283970465683248159"),
+        MontFp!("14023473417795456589963878717505287517669917599805973068283274798230783465678"),
+        MontFp!("9113725901633633321816296721315176702107873006937820201651085782607150391484"),
+        MontFp!("548172112211359610960958872299753762974328909291797522208085351481742658142"),
+    ],
+    [
+        MontFp!("21706861375052844683595820457538688901531393763031119579322611763049792865067"),
+        MontFp!("12400834297098033195346480176669546452877127463842848395269580039592533946307"),
+        MontFp!("18693408652472113120418656602007998722418946699666834214243410738193362834090"),
+        MontFp!("5734025542584829419095063290697837279993460564092839068131370421231150830964"),
+        MontFp!("18924630076511631254945042871273344212804227110396594419618744375738473997458"),
+        MontFp!("21814215076304113439159605823570645338718666334965210428557308969012775849083"),
+        MontFp!("15874497347478704100595228569012157674761828758432798819588001847304550645894"),
+        MontFp!("15630701992043410338260010633128892269216736379332650587736588580296378591400"),
+        MontFp!("21182316539430719202319661920312710080481096926576230463259085025268931402724"),
+        MontFp!("13427632056110349253603837202352903590918428276805663374267217475392463207302"),
+        MontFp!("10911219508671048309975190269088900191221091803425594935965400900508004506426"),
+    ],
+    [
+        MontFp!("9158857576095512666804740759126852985161480036845995629848333137432364744220"),
+        MontFp!("3061727900948996735547324485012991687515955796128872254459502313385493651603"),
+        MontFp!("9484087600338383596528641950981362023967545451847364854943599173866192324557"),
+        MontFp!("15244438304843092101886329998640757457334825678432810119480500702489267475870"),
+        MontFp!("966872391220567196912397735096848312956568724961332613947113843660023937964"),
+        MontFp!("11463695793311663093741054432425795718511021724948561649345772770204331282701"),
+        MontFp!("4301779131461344863695073848029046722184482171568650483218315300688393400530"),
+        MontFp!("503085225024315480816360605760267420357374543116157267432728664696709665901"),
+        MontFp!("2152733644489285468694016160698899213530839938744988003049661235638107035210"),
+        MontFp!("15311799268435674361916200869320882308334015315600749428161144697607223747926"),
+        MontFp!("8821276262247279528293843357774532114304424863546634692022624148120506964899"),
+    ],
+    [
+        MontFp!("15012215707563410212327951685975753138452396938302623171526151974041926043490"),
+        MontFp!("3832600555460338220245921307060619972944914111584149730708347861160918312276"),
+        MontFp!("4945512356937128186743764272299580525076021077689098424357488427223926571902"),
+        MontFp!("6352619611110096346414873193740535134097751304211531527845737802795592283759"),
+        MontFp!("10487432688972604512516548109465855881572886261854521534610720416206960307854"),
+        MontFp!("17622489873780167264774227253975567347204222125869234241462457566132215071928"),
+        MontFp!("7633353550826098030742486107449393642153730338494606903118637850284315354119"),
+        MontFp!("14024635247358847977026819464107185157647377185834445176147145227141238505750"),
+        MontFp!("3839080266490755896783758766005919524636645447105234209420661161997449147283"),
+        MontFp!("14295400414627547924873035695592309131547135626435254888679625991957098691157"),
+        MontFp!("9699346383521400413122931609905328425995515996718354291677850659748262244552"),
+    ],
+    [
+        MontFp!("15892775179188924258683368801772704553840476123482448500492733919344870447160"),
+        MontFp!("20199645131225911044918007618559374867899861460760047877106143132248914945273"),
+        MontFp!("12001320852019564045161245401305456578767647373680507608908849898281736831048"),
+        MontFp!("14109337051994798438391426281455499687696832970217465295841248395157195678143"),
+        MontFp!("16327788711327335703187954019173408665382603544801329348791567767829649582022"),
+        MontFp!("14165345295389244679058422302892574411018017024852263235622041585515310700440"),
+        MontFp!("18098328140605761490641355018350075852862336947591383947449899194910504851997"),
+        MontFp!("17531934292095415392849417096338882004139134123682574245576387314018394586354"),
+        MontFp!("1733676683546726275638177172968959379163822515869952716090548401850898249951"),
+        MontFp!("15390925956113897743988821133394062442365524479421281886945584164621467664839"),
+        MontFp!("6308234777769318028945248133710542338224131559387923000379414195599812605031"),
+    ],
+    [
+        MontFp!("18195940437872982705964842501287028751711989848794835251153117845868361639502"),
+        MontFp!("17490283477945958076039190253339223109810835246475119772790163703167194121322"),
+        MontFp!("15408396829836728115954873718864748500411741345264521674331031030247235075214"),
+        MontFp!("3551438511462216897614311415123740632223550882497302889988823516019801455881"),
+        MontFp!("1211190935634033568807015118513121103447278874630092924424376539010769615279"),
+        MontFp!("17406162668797550222384115208538224445727151774389004540678231208053715695274"),
+        MontFp!("20479453675762626017106163313206256229631927324901802475043478933123265498282"),
+        MontFp!("14782425647559744167182627327791886056751281195680302914811546501028210982186"),
+        MontFp!("925131908338441634745517290354664216337826881101842522634123686010539186420"),
+        MontFp!("8849707997196192490777875561563208206085973382242717199438345627124299133090"),
+        MontFp!("2076454320990941741925816538401596397546951763404696804371822796867698785368"),
+    ],
+    [
+        MontFp!("17173134576726258494214130646402838956462459094351663199002055284799185636563"),
+        MontFp!("8278507153110957550422039807446721030659313329793830361362606551912544734153"),
+        MontFp!("21454855413436031867439010732150284197408206511012140387650556740240705522088"),
+        MontFp!("8938141846612216610282603832016394896549074914747256157702516831379429923666"),
+        MontFp!("3753004427655295864092888874901033504634515083469621309422669489429783662244"),
+        MontFp!("2366435134561993720630980767268361780485124472309182242565985427615594260755"),
+        MontFp!("16165357231356434631067514709216775568766632800191759830231349057343507534434"),
+        MontFp!("19619001519241758891959372143573350428188652312535228885075978087065138604446"),
+        MontFp!("18529730720102625006097708392875275467779439015225330756494090875760858662324"),
+        MontFp!("5652897308997297007169294392190006281027830654965418415676327691132517009477"),
+        MontFp!("13575260089385236342970650047136574844156206796954949409405285738862382638131"),
+    ],
+    [
+        MontFp!("13051488122183433321152129519814259420249179451391427892934958106760313757814"),
+        MontFp!("16675947070785780493219656951197382898525312482169747446344506906972920892954"),
+        MontFp!("7175175603774065686796811506353067100604576034944842446058484596637779839013"),
+        MontFp!("1400986416499873501628131339252214540318552035055662222249409246587339999877"),
+        MontFp!("3464466866466806017745751012761954006390229805750136348643137486103734148919"),
+        MontFp!("18217858987033907489494644734206558680641423556261333390037659204834788684410"),
+        MontFp!("8406086726746620686898224574302006639796660679494669881199476338693847188630"),
+        MontFp!("17762341698784805474670945153199304225802481228485479435097247827908738620552"),
+        MontFp!("6223712186181276291315191942450380783893133091457934083606193355728586611122"),
+        MontFp!("4282900419919891825156385371067226030543529098387005106356966928605004273721"),
+        MontFp!("21245665052422749020204481966374990873300815343268950998971123603215733315757"),
+    ],
+    [
+        MontFp!("6982432590923765268313576400153302843651774940912624901824133236148956994132"),
+        MontFp!("14604328114465343527250129263480156133215257207190011581958944554537477242146"),
+        MontFp!("4419171346687102376610201013900704798965178967312178557853149472731770706501"),
+        MontFp!("13245918223750947891398096117311260221538002135539665270124077712098715822692"),
+        MontFp!("3347693769680689230259648405537595614327886881079646330652620517715295020391"),
+        MontFp!("11097962153704048182055796266608903112109780112107618586656031611021161690259"),
+        MontFp!("103842991820173997936966388104541957651469231887516072360536421395334472158"),
+        MontFp!("10458487052445928020397135451416595147106158179032040258831628955988702925482"),
+        MontFp!("10900270612365580629478084761129851570116267768979394602611763172198923647648"),
+        MontFp!("9903203205178879991225339006690459919299342990467545909511182892622541268992"),
+        MontFp!("7206476963459461034052924246171589839665793743462578906114104789862742647145"),
+    ],
+    [
+        MontFp!("6264046538214382166095990603552597379083154170591970728068583459736905241469"),
+        MontFp!("15208506074912456921421298757607137779904668310165212805848069781063650192568"),
+        MontFp!("14399898497646210477881830976333295219807765057833256267205543494652425724063"),
+        MontFp!("14270878504405431259159385066097540588788179903719271466546150744452837482681"),
+        MontFp!("2299469207691207005105135549235035285287116575059039549352935321891742095084"),
+        MontFp!("16821622844104951063765780536072686283845403348056960050391910562149383647957"),
+        MontFp!("9956020421344380373447956054462292461416421439742573500231969663699680679093"),
+        MontFp!("15930825774426452340437887971015387876460967360948156787859264923789690195070"),
+        MontFp!("10985728214199775151782154608793108221074450547445302100877022826701613291080"),
+        MontFp!("11760641126739413999815391346325702178866867588284525333479386263279622799779"),
+        MontFp!("12964764722311909921385583909124655151898642200440943348322605636391779313939"),
+    ],
+    [
+        MontFp!("18379853927966213921019716164768637873544788239921704156188954474082485441441"),
+        MontFp!("15296912762863693782000299085702814599512114536531293086824435743573000852798"),
+        MontFp!("11055306663322542945964813101077231103838051414460193023274311763885249453027"),
+        MontFp!("8057951225246999526336336507654165264870114340865557474750852826611325059180"),
+        MontFp!("7217454204491887928591297314854022623729750971598154127245160014826014134117"),
+        MontFp!("14565102336180706753392276751790538414003260023904084675564779334184014612773"),
+        MontFp!("16932806574869520697162166869588187543526028859439455500295852309668070598166"),
+        MontFp!("9179898117866495045233038402762881324885778157159220317172535561846474551689"),
+        MontFp!("18779105085403868166483619694881906385652758088668461143173550878413116028260"),
+        MontFp!("11214136111470281555815079365242861297786837712776840366750250043823307862209"),
+        MontFp!("11442992999493383524983810131599863922453401327420664873617599429059754849857"),
+    ],
+    [
+        MontFp!("16271323289864628346209913310699661044288655850612550590359075955707090062050"),
+        MontFp!("17108025350334840622180169650569770852833940341473970170517727066627050749958"),
+        MontFp!("13146248878892216294361188166665572028549000139712229260161108594416711673870"),
+        MontFp!("11427372820077962289622241022478467149286157602797953190515658164839928300751"),
+        MontFp!("893014835995617411380095483743111031917691099253220683676712243294883268295"),
+        MontFp!("8517095300100078150575343693630592663417209558229239005971749331683768522616"),
+        MontFp!("17927627171733468726764817859266182414919024487868681391571481635385817509424"),
+        MontFp!("2910874756516541331498135086483127887295864033618596807255973264881493597680"),
+        MontFp!("10351245804237587891981133156579115865490473264567370459691707360959684413970"),
+        MontFp!("12694211070090545997357986982035119982901819345677093614248046140600061957940"),
+        MontFp!("20283022323110075954579979019632104756139185475301167929737027848763705068147"),
+    ],
+    [
+        MontFp!("15931844873010780159923673780071721836876296130477744289335217834580073984287"),
+        MontFp!("21827223850924748993974869213271539530659373002293617263857321497283795014386"),
+        MontFp!("2216130285102621170883691491664557599377015082391517585865064633069426967470"),
+        MontFp!("11373405503815639839270612384753272139664561558477978654573651197371057815151"),
+        MontFp!("12313875735580427829620793313088533328915412934622924938520740599744871213525"),
+        MontFp!("15142465061035421304605695594042142745567585293979295525147576522422528415408"),
+        MontFp!("19911123862342914329674984568751978176595422003509172434076759943963715219260"),
+        MontFp!("14262659769499978824697304563726231560538953195673261355416096174585413608681"),
+        MontFp!("14610683638141173050828300939153196590488444327501938863403192781649951814095"),
+        MontFp!("6637311287977459850936997036417480664822288091481538821362996372726159029429"),
+        MontFp!("15501657635921439788956110615190078759397404083654787694613599184616807587119"),
+    ],
+    [
+        MontFp!("12415917438013473637314666005336278137449129158908612828862230927356476129214"),
+        MontFp!("3977073659694868689017260754925680384676839395821102222683036599314487912596"),
+        MontFp!("18021973030966989893231530982789547568199608264409750239459349680822956679629"),
+        MontFp!("4358661372125150502295103743733014495017362384424029473164385391234779773434"),
+        MontFp!("12150574573348685676904261170022340625206210894206500162919624199154445311926"),
+        MontFp!("19059564947466261856206182248882637129860510906425942015878496836349348075264"),
+        MontFp!("457803388293300831659933030795457353247367081441494540658944608302089214573"),
+        MontFp!("5212046878650303869536834084593989055518385481488493726863247051727387374999"),
+        MontFp!("10866963984944634834508529257930003255650324224099527428753028080514409460102"),
+        MontFp!("1567398629202166035061920213544077752133493577343656913517034032663760331227"),
+        MontFp!("1641623291671542299150077964313362112100008772415510478337935841080756871031"),
+    ],
+    [
+        MontFp!("1814807183609065925468700221459835599229505862471630844515076416397596161921"),
+        MontFp!("18676591284813153230980779135662585498854259214169962891045023401932640144792"),
+        MontFp!("14582715392475730800118541203172619815507444658499922254753619352141615271717"),
+        MontFp!("5971201190012257917828174333108220091030383280212145099110362036705465802488"),
+        MontFp!("9745736062659480171553506036948675113562718355498342585124081392171087285152"),
+        MontFp!("20654605762814031873434196876396785695406369410937473580199071780191334325949"),
+        MontFp!("11844011087342569590330041590202444612516395732736950853259915342942395929104"),
+        MontFp!("12960729918447154570349545809398065983315955190065107446283057843674060399978"),
+        MontFp!("1912969365260456210218541172655492769437123937988143458973053151759348812779"),
+        MontFp!("6751278463897225433246228986310134589363747158535699169746372748837067912354"),
+        MontFp!("1405433090804402720301319240257714728362644500702704160642087476923303165683"),
+    ],
+    [
+        MontFp!("17334203531491537592342457272578981899701055200802551766264091441355104806736"),
+        MontFp!("16575765689548766530893504204590870417122916172019674976779799591211403721688"),
+        MontFp!("12220976854598379181285138460526335801169364473128100040253401652070229420610"),
+        MontFp!("18565358629024373944198152078259356971563125515748886809957420832630624111647"),
+        MontFp!("18999218340435052500780578040305741910732238048488832712380773170203478504280"),
+        MontFp!("828469997232485965462944215365058139980461203417902215718115878220834198655"),
+        MontFp!("2385864265255813442011864217087321326832147642514277043179825610035123479208"),
+        MontFp!("11753087538734344167619786844012793078263845468062416000491296071906848786208"),
+        MontFp!("15297341509659920002867070368227904487875907462849401568145592054120947559782"),
+        MontFp!("17451904737564664855895845058410281315610351460549944647244100267528838462231"),
+        MontFp!("16288687708586503845827496209229686543350756101251970070831852982828263987682"),
+    ],
+    [
+        MontFp!("17372190154611010904681208309570706747429785600046317069452832284803213135091"),
+        MontFp!("15112572862198958048036722322696004521384539985617885597335257056722713402392"),
+        MontFp!("3090030985871039557449539908126365491166718509536148045184016492804246563853"),
+        MontFp!("7579986120189318071341140006570025802626137718385790787108832330451957404666"),
+        MontFp!("4656625991418301006397045145531617124589866163929416672123076523538862286851"),
+        MontFp!("19496020801558863926434816068743011465607626373023757648812447085667986307007"),
+        MontFp!("13805329389975072362759970417944582121050551902725997016013251740633448473430"),
+        MontFp!("18188229086122124131780255528737492392822604407289151260248713532509782736083"),
+        MontFp!("14611835504502434276576475613524726992333885650957002636192032800434268523844"),
+        MontFp!("10917400920268125157880112072188560060076995206390296416305594303287585051306"),
+        MontFp!("13972231301490257961012583958703459454850999751676553092417436936263553366685"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=11.
+pub static POSEIDON1_BN254_T11_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 11-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 11]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 11]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 11]) -> [ark_bn254::Fr; 11] {
+    POSEIDON1_BN254_T11_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 11-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 11]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 11]) {
+    POSEIDON1_BN254_T11_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,1175 @@
+//! Circomlib-compatible Poseidon implementation for `t=12`, i.e., 11 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 12 8 60 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 12;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 60;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("20214838738486568883466588390719332066160511773018226407137866846447805607366"),
+        MontFp!("7161524737853996242838650618412058002168848579199128467811556550737619970970"),
+        MontFp!("2264369418377007316930430297757084139629356094085160360541578125176213258694"),
+        MontFp!("18691044064909968568998201940845291098399339626807500263611343942450116503516"),
+        MontFp!("15978743992268694554518277110515494413411623432213713029162001242329212269562"),
+        MontFp!("6711615239704822975151699228936015251056551262955961924747531220602950448829"),
+        MontFp!("14954997163751606686696628499315041796272082739441018134122451910369305642115"),
+        MontFp!("21573550100361192110069886620445669562472881453105471211193858578537227040439"),
+        MontFp!("21785281999660091964290541777959906196912107196794342243439922177000502203701"),
+        MontFp!("2946923208312508080510106804563669422427642075683605437758174474435322095802"),
+        MontFp!("14039283821812338763616072949057938719426671560747126284782727998420210694521"),
+        MontFp!("2531474643515410792989587528850930504447014242967363822821359471367799986101"),
+    ],
+    [
+        MontFp!("14281461695965914110119049602449207565231627068856382054789426564141005041994"),
+        MontFp!("2155595480001027852247471998853878746887483662385654030663226564169133356539"),
+        MontFp!("6212474220474204735846033034823136351584003532895558668927059407038678087162"),
+        MontFp!("21589299957493491709069669042662513245508573637668760884022386808061869005942"),
+        MontFp!("5228547858762057503048110033821407961973668275986265942002757629551762149969"),
+        MontFp!("1151995769496843179907951142523838829938796346663877830241077357918848539138"),
+        MontFp!("16195901973518083237059346288792924649902586274815274684503783828189220931050"),
+        MontFp!("6205461827971201267719191643863468322713562983419848159871959495317073732623"),
+        MontFp!("21004710389082547785746156915318076260017385298749146368429985483091499557183"),
+        MontFp!("10094301525352802553607719810440185681054064961117719137647202357989110756759"),
+        MontFp!("1174362264673060234121108394303385502501621739298129145129042091221378391858"),
+        MontFp!("14586772089804608057953886654898255839796797046217599185042293580394420546552"),
+    ],
+    [
+        MontFp!("704103301411330239947625288325002010320119746677418877341164806595452864925"),
+        MontFp!("7447867166827402056774077383104558156866119014007569966692643297177923018546"),
+        MontFp!("4252152864489296917539284690826221964698345550054947572793948075436067436040"),
+        MontFp!("3675525234832046985215853449128143168168428943627479235047788418993254287405"),
+        MontFp!("20125795627598431311475910664717716586147044241536953058242999762934679572886"),
+        MontFp!("9159576094573932436478222856304524043339640337232471953289062354187369243885"),
+        MontFp!("10410289328536677868407694844650868516861553712016012272941004725559785872650"),
+        MontFp!("18813119519933909103102649065156934680537361290190751928265976568411443987994"),
+        MontFp!("15043786404237278119878717250753259786450872051876817420168142382486008024593"),
+        MontFp!("16614805203312302723146840789675006378900903626996105116400354962001922700157"),
+        MontFp!("899949298359737140980259063526066233582477211127560605822280959405167872532"),
+        MontFp!("8350589775626940122507262589996655703528509795097550101006133878991750882468"),
+    ],
+    [
+        MontFp!("10881253968160794744779175936360108103824976232977458894007732866457848744711"),
+        MontFp!("19359742822671794584060954988237182553116341604406926658049749172292672638977"),
+        MontFp!("10716853194721085390661796797973316855886234718612858006131046035921078793777"),
+        MontFp!("1194676839570189281149587289656564753779383829131008000754135056646064455278"),
+        MontFp!("11530412134598354110310733773537950950490005376234226554463355736782774653810"),
+        MontFp!("7158806839647137330333220334046918613209783693378018773439140974716028082046"),
+        MontFp!("18873459493111992992450800068055835432261777460679870727272006783676545919785"),
+        MontFp!("4597339034364379110034269874329162788488647975988086437272199171979371177111"),
+        MontFp!("16047595573111403874356093398802733070084530893238592035018321960924442437232"),
+        MontFp!("192949463851654477795020911703008125546432931266166268873310745978202434603"),
+        MontFp!("4826544617576366487123936439697751633333779280970103286526767080486441353413"),
+        MontFp!("10372441609969764399977561535165700928227575842447057367716683958896898456242"),
+    ],
+    [
+        MontFp!("21479608666927871465054861416648367371602717876964759897062141685818604541372"),
+        MontFp!("18757812710789932354215078701254559681588101606101822541277700443926569010598"),
+        MontFp!("8502339138598356500092304059172334649791727023646195989902300809704249803746"),
+        MontFp!("21240184871409684692673423121366677112492469214890212851758021155034260698420"),
+        MontFp!("2702659403779176675766431784851669876796725738129029887042678538644093630255"),
+        MontFp!("18760062461290937265331504644060340132840729161526164449611377215801441916965"),
+        MontFp!("9598514148929007169331478849372274288455651725546984183500169574552892743616"),
+        MontFp!("12460679873938368098608659480431260988399308425323633114529665233186673892475"),
+        MontFp!("20582262751655750693560201069767758489467289978119794831247596435694971251287"),
+        MontFp!("7495462389257720258504478831214292184152544822380786356126692935003910627822"),
+        MontFp!("15847020891468169726540675640439992039404102490965287792626266482436024810091"),
+        MontFp!("13444178956365729587956577087448840645730541657243126743158358416431709484781"),
+    ],
+    [
+        MontFp!("975733333906184480394673719901416555779305044861384485566696694336272649841"),
+        MontFp!("3016935868211088289963870855929013645268121688015888423636516996750583017171"),
+        MontFp!("20123197829824640950428347870445510232078708523077317828689832072338303017047"),
+        MontFp!("18496031799198869774970797646230665906722932354114482887753612521775690376535"),
+        MontFp!("448875332457320150287933426080386825611557032389972932765197125881964153702"),
+        MontFp!("15686083476904717209874986881961195356503069952883501862704199048297926079733"),
+        MontFp!("7399632407841430295111381086121470926608686430000074868388902950170939693998"),
+        MontFp!("19157441199146430337309347165554892283908758853741856357912555742738097866135"),
+        MontFp!("16120175937370916934366957179931217076202557540631878137626313655342796978134"),
+        MontFp!("17363463873417672052573440102339969267068334412527908172228668014397269133762"),
+        MontFp!("19764937897808275673467150361977575240242645746672288031015882089680753193420"),
+        MontFp!("4264866715026149043371443488601547814355809386242957666273811883512215893986"),
+    ],
+    [
+        MontFp!("15361071774597522987390988933793735468585435977940286138223232105339041682390"),
+        MontFp!("13444894080484049025660420839638753203298145906295848687612728375851966859563"),
+        MontFp!("21344396291142953621865942956005813155481114773979414786364869990164493168988"),
+        MontFp!("13658955537084761077271566555621122724333408573482369456630860179831273897019"),
+        MontFp!("12249794154563702076745009616085271813195258124596555920372455161542247237218"),
+        MontFp!("2325936171131642979629131064685171177284405924159468319138840745089808621723"),
+        MontFp!("3077792516542862676300186898187316876000625806438082696997401720196346610884"),
+        MontFp!("21531439209065692564653170259849715075994439889756241721092517464459744953429"),
+        MontFp!("765723669836774164873260120197059605145439283015732643840276151768662398969"),
+        MontFp!("18430565163341347334129211602477703661982280889871625277638214021594755964149"),
+        MontFp!("15168574820004856312411802521805000105968244665018577358614174215627811033660"),
+        MontFp!("15609931056593305381714243964783225295053513474263648739398875586829969929857"),
+    ],
+    [
+        MontFp!("13057901697952283349663465856361305032896972742145291496691475939407531431061"),
+        MontFp!("12802339382735521870414423620343194986509343830854539673006232369799979885569"),
+        MontFp!("7880620299082787885902391014823825400306816285911629201048471522567587002433"),
+        MontFp!("11085221899164994413080236199596538381402309021910771788195135649489784323294"),
+        MontFp!("5662061777175931509849062158785593075054461300898808576328927259801174692172"),
+        MontFp!("19620207415640534190314969542389551464821476135583607027506853686406083753807"),
+        MontFp!("1396012663571482634431038119696061726217340333644346862093678929991918911771"),
+        MontFp!("2833239640477482582925766504780450890721725782645633022528100604619065406714"),
+        MontFp!("9289278993548596713194730547769009982667061443580050906003394115646319823584"),
+        MontFp!("255970566924787837673441110425992267446525707891905710167559324774004600788"),
+        MontFp!("1607914894461957709182037732125046273691353312066921168498378132410220447224"),
+        MontFp!("18182823650001333075321511247233769219797858542696762318647781137777390858484"),
+    ],
+    [
+        MontFp!("7933393968545943401801081658073805133658457161128306876557740191220424567009"),
+        MontFp!("20347911076420909832061080138703827506796370399337016973599032573292195453934"),
+        MontFp!("21705355682416154516146726727072637540254191883301906287097797468012136754530"),
+        MontFp!("4393707213821090202627671673506613966066953344462172841905371093203947245835"),
+        MontFp!("20015218870609611793683104277034599032802947064261475029925414549380954228847"),
+        MontFp!("1857954279082383201486002148223947538500417662449637107611316684336169564836"),
+        MontFp!("17075622394357639776259605879156105089449277115338468776425087829255085998708"),
+        MontFp!("7849339039625631210191134106813689727565217371730065596482503614929894173038"),
+        MontFp!("21512601485458872387622978217203423557092449252654722032828165846830386134980"),
+        MontFp!("18829825907628826679915224363735067358668372607988097049308159580108100510295"),
+        MontFp!("20145344934445170391525281095510437469996020214770888614087514859850402376676"),
+        MontFp!("9313354461544201805378332544085825218707302313348145137439630918018863309279"),
+    ],
+    [
+        MontFp!("10073510764514576042491642785075889098754556687860419668420630560526859371108"),
+        MontFp!("11174613823246619246542059297257164876488140873425479553593597508295299674750"),
+        MontFp!("14486836623332191458290523271325176557072260063250466764030358280051240942286"),
+        MontFp!("1932726606175618877183776650118686155013167275195994452359992776843317764341"),
+        MontFp!("10586981584735794740885178709528523597770788130558769643251430679627096503451"),
+        MontFp!("17058731514535449611097320348142652958393616886312564975789110366863616539420"),
+        MontFp!("21546144187559470183347034044609056404441744756503592317087407561629774536920"),
+        MontFp!("5681882870231768621749544290358493454695957383787481123871436386675876219635"),
+        MontFp!("6603033703828934401094376159910456525337139277249114676008536852402499584614"),
+        MontFp!("1075347119451441392402288921187671249679641364496638526228862984392689015760"),
+        MontFp!("9887880282527621962449293235959776308591956208594163166185549404667941094205"),
+        MontFp!("3081779595493746844428351914840666042619592907445560123915127502392400574614"),
+    ],
+    [
+        MontFp!("11109844704163389102553826717541117344605357734084342755776036340022417198082"),
+        MontFp!("5060345909602600407449982784585458050225699107485058131116856520723613936306"),
+        MontFp!("20049893406143885619592680397871697055896501875354968455865404394786911398458"),
+        MontFp!("11740809795693360891733016778293494519471041728488086332325924371909574885493"),
+        MontFp!("21414777615318644939200434569971788107661288737252832241371433802590137831626"),
+        MontFp!("19926309906539946638451151936495517057597377615887396199326446649447508281702"),
+        MontFp!("5833294070005894544680949322571753681474561462111500624110195335953784263127"),
+        MontFp!("20316262126697618722223967532370347145297985363803056816800332573538115921683"),
+        MontFp!("20118897454905330779316757365327082825225674670546613715170828215358297124461"),
+        MontFp!("15893782176793316439240260419014348246083695310846638270933249304684265430800"),
+        MontFp!("12847296795001788271556697499714377689095182754228824085698104180563585670787"),
+        MontFp!("17258109440267943312537478894153608811927087776527641627893802618672319064807"),
+    ],
+    [
+        MontFp!("3727185744255496747036491258134142468721926815259510264718979349995349167789"),
+        MontFp!("2377620008282598351802066487452475263179928244128123362464911386705146759528"),
+        MontFp!("20330733534745333298462159658402131849518313653717741882717272744687077336453"),
+        MontFp!("3063488930518144343621406800230347607891775381984489334408858649400823600099"),
+        MontFp!("11822391183098027641060542512210687183510613996100060945754635806285989372827"),
+        MontFp!("2697686870567304805976687716601580249659499813659634827192211658186812105269"),
+        MontFp!("19466890284409856892962357589067669895394760875472697889494886746493744150398"),
+        MontFp!("1006970646211395884475799222625896618366447925898943829426435645426534803620"),
+        MontFp!("18668143903499292595688863135570950175417970684200058312498191992564173409237"),
+        MontFp!("374118929819602952730503470915153310582862106749955863047933775501492632816"),
+        MontFp!("8098759627317959799834443934069068232617039455327629644555780572940389866941"),
+        MontFp!("5647931789489182000343586961287147762347200093731102535565999902997464444183"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("9531912189466476916568861603725087174113765795321185751191491139118805372511"),
+        MontFp!("19478790955972117697384547134063865214385101762783978876290106323878777065880"),
+        MontFp!("1060742683967616999946346623778902980408439861665078741681079083728753884980"),
+        MontFp!("903807389911706192945621790390189278582333785459643079682311503388875632444"),
+        MontFp!("12234856961774107626670146718644057065701939000315796085771709903680607765980"),
+        MontFp!("7144222788160875459149511026237781941826966456792731247241581603475033054157"),
+        MontFp!("20698157631699630824067955298548696950174021158305550220448729049898476869972"),
+        MontFp!("2568732479597962725718653653192533652726437462680484211279054254158600287844"),
+        MontFp!("15284734447838568972934847154537240941736455475374750484902463133334986327455"),
+        MontFp!("11481338136657097309136761414013103615752281785975103234128467186355727286020"),
+        MontFp!("10612154017018914947135783711517517074090274235061056248497547614631916723906"),
+        MontFp!("3055604116750189009829046286798613018321687824856056202070161812694453230232"),
+    ],
+    [
+        MontFp!("15724356578668121294244613323219019733691132614053416867337612541744349705307"),
+        MontFp!("14233178571890186223833398248040498471414046807101676904418988635462870136058"),
+        MontFp!("15569884202072601673299042953201725479510195453493088500579693825511613908294"),
+        MontFp!("2457655896277475748047646151777860503559023325500398204274047419681119270433"),
+        MontFp!("18721554772384051287322049295337473476448259922773695010270945658995924622719"),
+        MontFp!("14475562052679553333706265906284533085428658760049287899372861273825967798347"),
+        MontFp!("15603408794229592986464670990674550905587371918421438132217255356917740776753"),
+        MontFp!("16291020844798157226549685169153735235751877205762229274467406522127105739380"),
+        MontFp!("1562912009705461355815110217333069399929165391790667011738168557818438924927"),
+        MontFp!("11269000847557922376283381139665491298931690005135341373215203639253515983197"),
+        MontFp!("7952882227157644034022652849613475711023423321505769231128945987664804912273"),
+        MontFp!("8477623463686474590677390307150152439632807913002306242882220286965657503044"),
+    ],
+    [
+        MontFp!("16240923770208341542260111895431885216031871921759466016566105290964433552216"),
+        MontFp!("3342849074804996662671621919352322750206307938435597035721084610748972602222"),
+        MontFp!("17931702006999673813463835283048793204134166638699010505988519059393386641671"),
+        MontFp!("20775104403560355444897460398679593487716638645215251442678183285176599574637"),
+        MontFp!("5119120619161234799144523865081220455479981813019860080498855561756227023701"),
+        MontFp!("14966757356872002341966020507506536570537078023526508344654458912397759281515"),
+        MontFp!("4186575643717625662604368224196455420957673000117335440321019421096129003102"),
+        MontFp!("19732993039041335023904970539797704576579318087957672270477209415993860238993"),
+        MontFp!("12070985110749915673363345667648496564808814323429491368289711883810537898097"),
+        MontFp!("11426729904237322553170474047093755836047277723343283963043397762276736900525"),
+        MontFp!("3316549680511845723553051012477874001156246623643069548980284069093192393275"),
+        MontFp!("8181145696278572952623145148622555644838658979012817598939443116317742063663"),
+    ],
+    [
+        MontFp!("5285104126733708771192424010645473580676014333664755598112717745696037070786"),
+        MontFp!("20879460004955312065246827250994614761073401947673232470833608851757692374701"),
+        MontFp!("9225423353187148331993357472363957540141522302215231248106234750413173980751"),
+        MontFp!("15882456781720382955442431000301149138213435084361689595107598612703885378636"),
+        MontFp!("740238561845104412391944006282242126007894120431256540495139829806405656182"),
+        MontFp!("180417058725083825889148913143008776006125349068680455724690397852076567353"),
+        MontFp!("14174293519955274733372251014027762210778831326913295280840585355009025244339"),
+        MontFp!("8589132560109336742397080501737400189892522466285798728529721670916644735009"),
+        MontFp!("18608579889305084432265993644262889161778187018060113992221234915464772250895"),
+        MontFp!("13255562856310211555141734542720764165551582487712004861593515585281326120780"),
+        MontFp!("20040426996876166323258012360708147873405329072458300306708975663127708222443"),
+        MontFp!("3623806905318973096995680510049954452579199046783492207512283522220396562822"),
+    ],
+    // Second full
+    [
+        MontFp!("17294754169238631079500422194295778880256675645147530605338890302947026045725"),
+        MontFp!("5743114444764763990137499217919738961538814043960695215081370978081114004215"),
+        MontFp!("1532354676442440269809361487929394117850339080897231885482935144216545001511"),
+        MontFp!("15429279362464933048379761813318395642886047028548664371898579661267949218379"),
+        MontFp!("3996365200571187062769515782559269970838089444839642643284597375210723401466"),
+        MontFp!("13855942579438265547807447657820171102227439902714241541267837936207338677600"),
+        MontFp!("10017315263017624310653373895363026608166903346797759979504062819071927130663"),
+        MontFp!("16907630432341625239068562338272519243551636457245225084107387534876995927261"),
+        MontFp!("7499064872525146201613148795250282369576850162788310832273877734938523830020"),
+        MontFp!("18958126705503391332983508022395142836904315587480202621370522508346095401093"),
+        MontFp!("10592492611778631944134585106195825750734295091365114316628335289401976557048"),
+        MontFp!("10233935978161235645487498670591630814869863266947595645533111837358567006403"),
+    ],
+    [
+        MontFp!("18003113120454128964459787917100973688362112412222897499908177070949705432002"),
+        MontFp!("7039143063746813662581342159908917130120555794319120640254054115394750527325"),
+        MontFp!("14642639620523941644124538953496946557379420056235185193901717503194398478844"),
+        MontFp!("12510354962778923417991098874785395513418703200631409704677335762559702597729"),
+        MontFp!("10937907264762102220987756825649855260366448774314976509103469790406549413612"),
+        MontFp!("2856291402269285412222070625188683697884675330846580808282552440813379388545"),
+        MontFp!("975737765008679829063246253813268073237541742979444038400325932865440884821"),
+        MontFp!("10846579585900798888776933082578183338805087990458930175500622018769094956268"),
+        MontFp!("4529602228982157635664617353676370544979061743349920696434925695574883870240"),
+        MontFp!("3217171861525061655884750653752887679288235310048470832136609603512214131719"),
+        MontFp!("8115945363448342140068934999604399115922267391140037880996539055087195710388"),
+        MontFp!("17017061429975823256838374218603998280878588780557395344098357759667769523464"),
+    ],
+    [
+        MontFp!("17414822882733297737417491176979790219384604825369331340721407024843872424842"),
+        MontFp!("21674124478965998545524545297025636759207453169144537816299040483190680515948"),
+        MontFp!("17404327139726954519140759696696303110669221199013129437266401728134593481209"),
+        MontFp!("17114525865368445983838697023323950751616885090116888636158517909348665004808"),
+        MontFp!("13606067029568271979635008642466621607360271211611257253834134991337929444625"),
+        MontFp!("12697011972510852002753203688496604009044404157472710768958968780456921052232"),
+        MontFp!("17908262237776925120663573891641823998363270265606050465713306950786509533013"),
+        MontFp!("21456469759562487171462776687926242591840822996019886989101706511495101340835"),
+        MontFp!("266521013932216168881283771008004557613060760037591295825839278412991410595"),
+        MontFp!("4426122336489578598096667658844019947877597098504167195068107491300030650784"),
+        MontFp!("9386708946433085177945274757444606300245243689157314090008872819375610588441"),
+        MontFp!("17571201661176038894845217669278055388543910674470132087585433795958011317108"),
+    ],
+    [
+        MontFp!("4455879691406517778738600662496288227132258098615032267607735047373372289231"),
+        MontFp!("10411615872931007756022633910690379435607807745769276655537160609832149769392"),
+        MontFp!("1369325193519585971093564667957731115918550810109208821335573114054160355015"),
+        MontFp!("11701493479673417478812558973813785951698004134589804881992366063053267216767"),
+        MontFp!("1604976076096057025943679983359889701146704427744088265030663068037609417365"),
+        MontFp!("5807894190026658662778129602314598491833803649976558289016319277596337255402"),
+        MontFp!("6604568830557716159440870492208510177395633789687151427836556682055398194130"),
+        MontFp!("13221322798326908033948171538573182873220915914806870794442015701313412462981"),
+        MontFp!("4276423994177015753526554880283981182675757973382385661312000468093685717116"),
+        MontFp!("20731982721228405909675906960993136550102478961136398510514645316644217252635"),
+        MontFp!("5084416110782735943752807643199425478022977025123417839387489101289418017943"),
+        MontFp!("2080632875247898509655097068576655654491657659787279629928499240720334125890"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("11410684734000900004860241940266501902598169149094443542781697771532926351566"),
+        MontFp!("17135477854627682759515989387435395911766329083966273406049980901836411403416"),
+        MontFp!("13593359321772915930716800157828568438953037754245269488538472861500074218774"),
+        MontFp!("8851367853862849158416384345517193602505667487160151588020171989122435915705"),
+        MontFp!("8486258745426030087193511835657366486234171438833758681014633470174920075557"),
+        MontFp!("14747490337504821136965789527401826047053378817043597026988257648138435336926"),
+        MontFp!("5328238162919446506214025777104049372124957345257645933410622558507658284713"),
+        MontFp!("11965770224330607826076459132274399741504292999992921785782448737931531131492"),
+        MontFp!("6397958737008655664539418210486834384969900796875514632281024739857683313517"),
+        MontFp!("19298424785220686762403958616740155152857782164943661260316780711617884649980"),
+        MontFp!("20019455348785333548837795973650078300677031008828877476192963238765813699604"),
+        MontFp!("498876295068369039941891884133922605442597373275805987652958540603151502762"),
+    ],
+    [
+        MontFp!("15673948845182330978914854481229214769118100412263880346214990198207268219918"),
+        MontFp!("15636974430517603355685549955558840639961287398389993551242671229593610041279"),
+        MontFp!("21849352155479133596816471850644588093828045783688624472176874329078465894038"),
+        MontFp!("3710541309080539224803365587698192640065043989988405183436118622332060826084"),
+        MontFp!("7400908409303343897763329119747918294591391269311551693625176644493777241247"),
+        MontFp!("19095563460120996369257805492605849292942048841569757166438202426234116219528"),
+        MontFp!("7450358777752652255259382961368280387692895530851667830759412613948095056314"),
+        MontFp!("12616362847029801456780520061399223912377922714639139395311897747307063246381"),
+        MontFp!("2962396389753936883856793158008198181187288822144777145656778061306062270271"),
+        MontFp!("20597083262311447290143345580862519411179427096464102505623468932397345199901"),
+        MontFp!("5990063075931255958499395880323163728626495197217954905778613462408195572191"),
+        MontFp!("9361904499252311797420064091418454593805808979929563324171298064587942042557"),
+    ],
+    [
+        MontFp!("15745983602860127054098117502599813732367772584805282887983680577406906842509"),
+        MontFp!("12155753549127535852899642287036910835293646693799050941038502705904075221647"),
+        MontFp!("17784550108352452858546269360461997898333463961938370347697582728197589684086"),
+        MontFp!("2486952113321483072183664368099776164387734014933893158352223909214365526945"),
+        MontFp!("4903545427310923282188595180893897246459463256398425200307382903876773643166"),
+        MontFp!("4976787025628832138140129068285715692634473783721132777844066348049003658995"),
+        MontFp!("17903848557396420363857191109854268785343114927997153696759251220232810232708"),
+        MontFp!("4112316506554809604392743006504568448930584669405889138267627731770713169451"),
+        MontFp!("12564921445775298869601661793895717261354052767541351424764087809438338341443"),
+        MontFp!("21338339987232841174606235608205622863643553456415651377028015070388899605425"),
+        MontFp!("15088854240518267391884187352051335059960134381458770666979402557846290860043"),
+        MontFp!("6521785672326022155410896706891346109402758862672817874198014517122929534795"),
+    ],
+    [
+        MontFp!("17713017733654015756718215699238009705617793897969373435335184169505068045344"),
+        MontFp!("17520588725173134454654213370481559878188684108641520017594192886986887410727"),
+        MontFp!("20370353420481573040209617326772538556774101029132132235011046989968543481853"),
+        MontFp!("1112630910445876674622022594317693604373148988518983600314476620278857862788"),
+        MontFp!("13947879294001229379895373879944689637841444307211953543137372048528771620266"),
+        MontFp!("19992733820130513366333925886960985070219788486605690639975080516132656879852"),
+        MontFp!("20463686239191469605722985205294798392601180601616445763439546206772797296794"),
+        MontFp!("2399836272490730222789561326076432853292090595513010294231690669763899804691"),
+        MontFp!("1034212741378241815550350670737161423052400169194393567236687087583799668630"),
+        MontFp!("11440929010875860174917763753574001672473682534215635955505970318252592490865"),
+        MontFp!("14189960926817818424784917441574757301766760887770685069486059337906765736963"),
+        MontFp!("12315331213328078044898516229848174792421271854142211159524426707506661472774"),
+    ],
+    [
+        MontFp!("19715153274097520886062594899069030152815615989925596868748132112713500919606"),
+        MontFp!("5263645674775021082527379900656937946372398687742691252725061831191269814647"),
+        MontFp!("17116922705336407879424247270343125398621621836566941362988393678055183546962"),
+        MontFp!("12965075932786028962944831711285166109972351055088920502397645336995474504411"),
+        MontFp!("12016029264981766758974787101200878559176627961323691741528038308088561486007"),
+        MontFp!("5729800253105809140207889626731612970039128485797787837679696331851897001188"),
+        MontFp!("12918441439057112476878371772096947366502166083699226943980929800631997660377"),
+        MontFp!("12122801182972566919042281354391366131886072331873675435499031824985283406080"),
+        MontFp!("2001381046477673840829875588313268487326797326656965151814712798463828403972"),
+        MontFp!("19581992323872865003173357646589705276192927057599589666190396129558198469295"),
+        MontFp!("12032241883699803399815479981073900218390137511663730251199663857029681112519"),
+        MontFp!("4486673663248066106745570699596745555749344770988892121755846988206654958447"),
+    ],
+    [
+        MontFp!("5880818668316784480273321916242537222449799562687899946001667784663774949284"),
+        MontFp!("2395463900919111658295342588362130947823185484448880299307422522723986076206"),
+        MontFp!("12950983061674357110606111858229311283119838941890561558124046774295323335099"),
+        MontFp!("2237414129351562764861995011211505967387529547007150899242139479636758409691"),
+        MontFp!("11297958435815311160313316754342324739290776717531068730041530178142477489469"),
+        MontFp!("5317255860197359110199894572100590405469568868980740298600361799734023336258"),
+        MontFp!("20883201721979021051679862781044901365707874143019054645125857460321063446040"),
+        MontFp!("8182241566037373547703812967620649098015366536333588850170211599244447070966"),
+        MontFp!("9597640642388296782056528355964209027337895123387144965957122193392739912194"),
+        MontFp!("17036534143228977344737945793420370811715806669058615712195620745133828881359"),
+        MontFp!("11612810822316258618979762480131695561483235138506664012253801075650313633340"),
+        MontFp!("8933421864748994361777741056369848147887887317449099739627452478628920292098"),
+    ],
+    [
+        MontFp!("14444070019150868677613599250973948199109012042816076287250439535772792130077"),
+        MontFp!("1409252976182963241946410463334709182528602279352691338387472032099813179046"),
+        MontFp!("11952347912832393217697966069578145988700552783923451999893230696985016550129"),
+        MontFp!("20459418532142116378042115609733769114184932636063412849272885250416180990630"),
+        MontFp!("3551971311295052916014184741437675944777358383038912487497306982998424802991"),
+        MontFp!("17338671457268891559841041605089015291458737421392337141908035764681448452092"),
+        MontFp!("20968343054904260510606682646995696017767749421435540168583945905747214877394"),
+        MontFp!("21712394213185895207403184789091961285240129068046002376558625468598692072071"),
+        MontFp!("7634967402785998572909457534910529497063727153788753124164657866643377741907"),
+        MontFp!("19849927917300065970782875128524194424999119369431340457180683372405088384137"),
+        MontFp!("15012913963479022629048645986836271605475934011232886489828149973693122241964"),
+        MontFp!("9429717687014890707005420840742234597648724772846627377968206537995029096421"),
+    ],
+    [
+        MontFp!("2567188197501474210757971384017028034832891166328657542206945684532913152990"),
+        MontFp!("8254698320328328672738727190211578815599233720695888584349629846354725718490"),
+        MontFp!("17284254811803472776585305793072871837922263190673228934354908231725144505079"),
+        MontFp!("14935255735939697495362584034949670161356891451287847368207930876840786524288"),
+        MontFp!("15799240079228766996307573606262831147697506141321254927976890662889015144612"),
+        MontFp!("6455095726770008351501849656650198371508317633852287113372325351871125339180"),
+        MontFp!("13561632639572229898860364095655372109051739280251390860766414218384828962360"),
+        MontFp!("3063643914261015068338656198636076696013594289440915753234546912875968584726"),
+        MontFp!("523490668760084285231907250925228161954264885492417167368043075448446094968"),
+        MontFp!("8963235882996992436242593243282162189452480942366129170093695041925502792493"),
+        MontFp!("2822355405499444122504244694377602321152414880107322020961544617958820110728"),
+        MontFp!("9900962978347352867015759419877923426715239847996616012083851486863818525240"),
+    ],
+    [
+        MontFp!("8479746689369630997477042503538860863372007378750903762662394891426272525808"),
+        MontFp!("16389683851395581748247134197181679668454182692374139436248933773102047859911"),
+        MontFp!("53288238128999697340898456182379718764153689572603829218558226366719788005"),
+        MontFp!("19367926616347613140684682635393090785537600078242140764999539757098535716540"),
+        MontFp!("15865351283225378120524944291419029041302905726785499676495076019805256191362"),
+        MontFp!("3306541431760940351422576606963790342897050957331449111434563465335195596604"),
+        MontFp!("15691440019263506851813897307599578949230726834044329611626721124859202292981"),
+        MontFp!("5471927110529676053035425189646230106778686168719884591627642899285735629074"),
+        MontFp!("21754232156244379849332999145646221923063494235010275675811923863906511018870"),
+        MontFp!("12691978963824033486319665437354495198660382717956695927101804737187302726210"),
+        MontFp!("18287930615191907073854709805749959615454583394829992950666668999967617218809"),
+        MontFp!("19651897318700114806760486800306423305046969021116395207725042165875775288623"),
+    ],
+    [
+        MontFp!("14108212236437313184992367338426565912378398742941435667080068116020546770682"),
+        MontFp!("16999325425454851471000400176480399381440753089661500361295692310681628123507"),
+        MontFp!("3115621817064730307934070024882122808835435845590131246374645701268123516395"),
+        MontFp!("7664237802103239138782764325077413451892718354904226049706189059570344004578"),
+        MontFp!("10628974555344045044380058670185713528581140293158876652923633181919907860739"),
+        MontFp!("14113596208322488293154755998142305972509624369829087071590744312673264784912"),
+        MontFp!("17464956897498328434098241327703906248729109105264442832066471889452262140737"),
+        MontFp!("3306236514619936703817193243747225028048646569484446722868278187142340885606"),
+        MontFp!("1623642646505969781806818562148062924427827461950798461560800231691312453957"),
+        MontFp!("20812904557023426950178015281581063697341146335603865156046444216576536158479"),
+        MontFp!("19408105996475064309045111496637572365855316086113347445820645560307476671120"),
+        MontFp!("19899769975875815048945884537401834080401837433178820364413791122141366658704"),
+    ],
+    [
+        MontFp!("19592039825831921790276944621603771771915464940035526138844575559711905257984"),
+        MontFp!("16653297576149039984587104062729832787430849403617977005988111857683169262526"),
+        MontFp!("9600749907793807254814822662348056400280037933879058284466549496283747987313"),
+        MontFp!("4481371968429561669477332540784950497549507725682080986909471734800926537000"),
+        MontFp!("901971752662861027246344512729868409066847439697888994727629776003122477075"),
+        MontFp!("13205641411901359105414809635566186722302364256582520884685762468367549487609"),
+        MontFp!("8771921370123544696277176259984315154171500395079224159374189372693912295384"),
+        MontFp!("5174975161282723002634664972730414710732925114695376584668768207090019053678"),
+        MontFp!("19270513804464040100949263044402755496385234769453916866026752765439740596759"),
+        MontFp!("21159175882383323828715178134104379154154907971797847682741774730382256211237"),
+        MontFp!("12713085776674794152643870369276859988930155976989577699924346924648017649124"),
+        MontFp!("14416880591724909781230787197474161383703110620356522560207527702042764430665"),
+    ],
+    [
+        MontFp!("5782775977590089860582593877799736603230821040322957359619681360364621989990"),
+        MontFp!("4476458740184410293724437897879594951652562625859170065641235900450054752275"),
+        MontFp!("2721806229175990973363729919729809746838498228946916302499795336059265315654"),
+        MontFp!("20919749045138523262313225773151110447007311142131682485436367417448435698000"),
+        MontFp!("8313744079461516868330165973726769605125778482558912200259619501953066297351"),
+        MontFp!("4735608165219550054732197605756732753518722979705458514715803339137012677807"),
+        MontFp!("7197734920840596436781335789800800949073525267054200029424794448358957228282"),
+        MontFp!("21023118349982419692339995216673708826739468972915634059252379895041124776477"),
+        MontFp!("7361684304536881013610906796097454038829033466585052789468836114776912784525"),
+        MontFp!("20982662530655024673207268539471241193218964077396502472563124088553292010609"),
+        MontFp!("16223276590978113205386347640415975693035764887550533934078089596214147119142"),
+        MontFp!("16855050273631391834996719887442993598537594692357829619807594900987141342279"),
+    ],
+    [
+        MontFp!("9447886870473301829371932239272857617552029212858469830955734178259213680272"),
+        MontFp!("20832064076469714454949991237859093636177561963691091143340405814107144265638"),
+        MontFp!("8639667923938888615556970427023258757087537766667818930469506422086574042603"),
+        MontFp!("17264766416417856804513499340974616744303058001571461329697855072803549043449"),
+        MontFp!("17850880215521300838724196369662075783108857625488733013926883881070746974079"),
+        MontFp!("2555423581835096020752363625782761123603321345233444467023623837801470232396"),
+        MontFp!("287393475373800420687070196983041262901299109116957212897422666775745916878"),
+        MontFp!("8502409129845334137896426506380979458386453989629214624807418474240644490658"),
+        MontFp!("17018903560361810748608552810594034468026623560117430817297647108682661433399"),
+        MontFp!("3048293276213593502083356886493678223968770697826563060833194090969377703128"),
+        MontFp!("21410637907835525984647506144589609474305790431496436733818309371828966617019"),
+        MontFp!("11884766464429332615888730245830253775469158860120272524933094891366345545984"),
+    ],
+    [
+        MontFp!("1404751133279371734382153067811682787285007352946819870030827863597870240066"),
+        MontFp!("18533103709771766357713924189614422821669511650512088621974348524596673777463"),
+        MontFp!("2245746843374377731985282335022648129668387693745835052018700591124673151499"),
+        MontFp!("7164798246992914028921284523335117888604816694373355756451086598952914018661"),
+        MontFp!("6547792475751145534229851742995938089417776974069115284886265458921995253635"),
+        MontFp!("20902177810420148842648372790754609922473216539157084210657172695295335305053"),
+        MontFp!("6725829188763173291829388608607411327488468861041838394166490103857184986505"),
+        MontFp!("14877246205681405878429147764831613204208078224683457409980647858475648710211"),
+        MontFp!("12044111044800943869325640584272709159106488829628178962818078442934011738582"),
+        MontFp!("19605231155471418918091518582371485038913937726379108169052428896528644150703"),
+        MontFp!("7703294969707260451899023373169584023358521879520637237253564638222384534453"),
+        MontFp!("8646812596577526500078198446696820195057640949335282980927180585777038852172"),
+    ],
+    [
+        MontFp!("21737243780210633072252501142816485256289884504027720271756348514717163502438"),
+        MontFp!("11106629219138713431737722416937344915839184447234152498273695046605412717853"),
+        MontFp!("10776231601191535915748483208366958548059216019309096513250460388757814780540"),
+        MontFp!("9570793764533387448268417521329594330897759769553789809193103230402046525968"),
+        MontFp!("17679865315281322154038759337512810633677645281028940369384987651076506608513"),
+        MontFp!("20575490035801628850469464797342943234291317406364190353186174477643080398289"),
+        MontFp!("1245923103633930975302215942611213577964303545113844948930979175833556792302"),
+        MontFp!("14007490679559289327503467618330290757962057021886611213555145469946035182448"),
+        MontFp!("11028796735236022300483437389742268989348582903251651368706557599156682851942"),
+        MontFp!("1556450270937619861275756908441730263281625715497049030992434005539032147429"),
+        MontFp!("7378353234583563315614681819214067843031828330274954124783678863055213281539"),
+        MontFp!("14666996645204144598922205770204386241062541706976832276647424877691625110244"),
+    ],
+    [
+        MontFp!("753795180391574509648864319525512763100129705111118203505048417049290278879"),
+        MontFp!("14153559413038217294172720643039696432149555359470735393924921410988488820985"),
+        MontFp!("17643076982867120574893553230100217333463357095994549461637738074442388603622"),
+        MontFp!("3578933943193517852178000450144796032210613197873582924120228282439809826887"),
+        MontFp!("15520463167953252157432945917399723977264972626433304226673421190651502497625"),
+        MontFp!("17211537329205623730168383274756825219539545439487707555783029872192419895198"),
+        MontFp!("9802003881898384149235538364488830129822967333024012959168976309447054827302"),
+        MontFp!("4277753965394311084543194762939932271526308682615262493526499362805330624922"),
+        MontFp!("3936260403240718180440931086768355327655463497858616640513270218914599723610"),
+        MontFp!("11517607066800361513856730397176372083814736323098377670788083637850655404741"),
+        MontFp!("2243213365229554429675654538157029776597503775940697016289614299870958139625"),
+        MontFp!("19764298184704281654348385808308820923079630109704889940202178107926508921613"),
+    ],
+    [
+        MontFp!("21440201729469627284915716136426033084639303793622913729895539439609852676483"),
+        MontFp!("18865357344271429482320975819341476664123167289165510208121280487753271844163"),
+        MontFp!("12062934463233700754743031150573591813637006411260363192982014497532786059086"),
+        MontFp!("7253500424782653645261846666860267886758998073654253013071356444451212225059"),
+        MontFp!("17688280285743970528696341296072681725398428119833052669003422836815517127146"),
+        MontFp!("7296931094727393709963826609746626294291079390951795401509403882666626382710"),
+        MontFp!("8944898010968990710934167772370816765379846965311172103743060979737069116954"),
+        MontFp!("2895100563060833104606048044246656016901407622471819180004918143679777127583"),
+        MontFp!("10049128690674011365994516711808882356477362224046294433720641467109283787161"),
+        MontFp!("20809590643316933764584815864182144455784236186778450054494378700964393080470"),
+        MontFp!("2552959198983852034601282535517138215015669324552132996051383405020865478519"),
+        MontFp!("90024510729703363025920398892759893841503303159824575434815310687587906738"),
+    ],
+    [
+        MontFp!("2438402150656393945636277445769226062775867161339657212187540106747284678617"),
+        MontFp!("10290839327200266675482043018636710429195359786802953032677212989307055380477"),
+        MontFp!("13366445998820331718698457472184546771717827833722684584206646838628538349970"),
+        MontFp!("12045715338147601791240214774093449384918104627310882799600088155093595426276"),
+        MontFp!("5295711531017416568724842720044505396040371477829249515344865310124246197980"),
+        MontFp!("16400063927954668864214504458520657934817841218321732614334739112437744352318"),
+        MontFp!("21834268150215228276838182444784793459928322632598421948695291555558497507284"),
+        MontFp!("4036405082017497222204927366812040809401891394189816634089482334837550533053"),
+        MontFp!("16758010699927998006904853632308429832737059675503937996784758385473294734767"),
+        MontFp!("6851323788614107612891077153396783509346340795017622764414273403342247098685"),
+        MontFp!("21242220940510077461844092816930517552035812681947928514550083154475902790925"),
+        MontFp!("13803558028343943472219115813916734264304316037691831164863336831915596589841"),
+    ],
+    [
+        MontFp!("3876897423511600793257560948470150518761848801469200306022138001232700430094"),
+        MontFp!("7873917719931107857936523614722924550340227737594948157729078597073258431354"),
+        MontFp!("17791290553613199208672721864188024787529313403544902623821727403694762316966"),
+        MontFp!("4509080040196139117885863635009327583647452661266153933806446923314670630597"),
+        MontFp!("7350149044068266301432651752105457672961955568647004218727518439545294630219"),
+        MontFp!("11503072465150450162639608555774152895393875487845468619329760555955360089418"),
+        MontFp!("20146546283112595992038998747954746814923105695946909075842241737548546646215"),
+        MontFp!("3500661581148278300592438598016870333493992384875150415517341312342838567317"),
+        MontFp!("16461061867640825231072403805782329353930546739796841505995525047519357319535"),
+        MontFp!("17419473457085759867681212087981664406569947154579733189885809269345147937957"),
+        MontFp!("10330084554893529954763451132860402555512473901655458870771994310647661645739"),
+        MontFp!("10271306411842332407495000297515719691188342515212338700550984155237667774587"),
+    ],
+    [
+        MontFp!("6010614376730465540824294088181704678570427743830238021642540164120787031818"),
+        MontFp!("12211956821531047081164047854393417207581329108889222238958146335322243725690"),
+        MontFp!("18026227011560759880520787750218090767851915271628616216035782860818091527867"),
+        MontFp!("18039053385352422125173521135868691523650276298214786020953324823686654441993"),
+        MontFp!("21572961947836757087236508142423227244602094617521885308238188781724980380450"),
+        MontFp!("6952631457061838308719934275464119179697248499343745875411129234786468691942"),
+        MontFp!("19811900079804396120677471049757957457743518480096470411626681111649768726526"),
+        MontFp!("163137711020707070877550733174177256426010367795106166965347507600009523229"),
+        MontFp!("1531253433144642057738530256773902523524280331634644205392973718192895734956"),
+        MontFp!("1179712150612295194734157884657760659826346075044485388437451245699680152105"),
+        MontFp!("2729710616419138683641657553469892671426309689560939345577118202079250084236"),
+        MontFp!("14266054344296739494440365684098525973737250786286154516241047965660978988270"),
+    ],
+    [
+        MontFp!("15026169712587706365294070206936700891511371850326743083547381843796350317368"),
+        MontFp!("18744742519058537072956390943103111325221767611576688631532967525091526599212"),
+        MontFp!("6993198712278030093898597400393962073007955430382142820465138278710889662382"),
+        MontFp!("2553141820754951085470014997086944345325965756649326165555729099768987826967"),
+        MontFp!("9720940197604032044323074196225989267295799246574437528002073812640512558241"),
+        MontFp!("17856885570331180579712689563952926777618369125836010409080422245803122826927"),
+        MontFp!("9316647413775248445962902207564207480723728241070369123275732644991331488677"),
+        MontFp!("14494957198204561202111268006350266984666563257512595231100502951029167863544"),
+        MontFp!("20990514914454458611724181917618445270429441499421925167963524958552424564602"),
+        MontFp!("4471904645719453756418626304970674400441871174741396875599881395188613340474"),
+        MontFp!("4684361390046158999250125407700867527620809473388545037966531069883415383449"),
+        MontFp!("21702205249470184149930308057282607474924454457414198304211876480322358864836"),
+    ],
+    [
+        MontFp!("14125417641286741606705374243071146588481196226954437992372061774013416347499"),
+        MontFp!("3703736300177825900679993762695155186404123107499914435631005694624510941992"),
+        MontFp!("11732888492654216406505460448043873705030240266921135732988822696780340208946"),
+        MontFp!("11933466978112969006229130396464073172307879223153980062784019174478752039844"),
+        MontFp!("15171000950368540171664824924290660122824429581656415561069429955358449308299"),
+        MontFp!("10369486846273450968605452628621668108353399309158716739554974304206007657467"),
+        MontFp!("13426080534643441487992009643036732384216019130391445221701162507604477874092"),
+        MontFp!("17202835976136422766224805235458239972845135749171745123647755691905320873240"),
+        MontFp!("13003013510914746051603710434071109936447243726946691715828935535255320452661"),
+        MontFp!("1672563195387555152025850519159013960714433928303467231569405674199001377183"),
+        MontFp!("3249806644396047380745964934114987054912135505311534093405371566690536038255"),
+        MontFp!("10852655605415322578561541069174913253244788503288539822168510897694861970183"),
+    ],
+    [
+        MontFp!("21180450898750475729531605846185254439124003420647553715507766256141444893981"),
+        MontFp!("17598208134643261198923845998804266441503487273381944592003474913329048411161"),
+        MontFp!("10560430398658881239820534975865220513135495522309722014743734708940471341227"),
+        MontFp!("15940735766678288146218967299383564752403182243037693045705028442779781161429"),
+        MontFp!("19840721391058424508364187473208857771270089687968326863727366457103504697646"),
+        MontFp!("15270655938735284505864085399423312069359806468400434801172200215370037695961"),
+        MontFp!("1774340315063362619348165557959238806046379939754082379283600857530102490105"),
+        MontFp!("1931473872849034457489264575043647960104881629328524494861951197166741512954"),
+        MontFp!("16037503795660448209256659529206619750469738788435653064537663524466496564778"),
+        MontFp!("11157294725944164923451173319705000360949753554936504404517553331688337467555"),
+        MontFp!("1860977382172821235372346764481514761526862185187436148114012977011560461667"),
+        MontFp!("7554608809888667328824255380474433026692004240460849082770678269699854930661"),
+    ],
+    [
+        MontFp!("3812849161473445621072899414157938525926417912914872301607900000816805333995"),
+        MontFp!("3248257538394266288019799770714987078883986769586407060306626631267726656239"),
+        MontFp!("8987711810247040851774885826425578893413569065851274558714617106373602624631"),
+        MontFp!("7002808931888012021033077336936962976185388694934605760542880449764655464580"),
+        MontFp!("16260071391005121724128685673030785454758719454582904114834030329423907463299"),
+        MontFp!("17301316944867831355470414388420250906247821380560618462329913428602672908525"),
+        MontFp!("19139125176792761113369565771916762091089412261263410641074341972472699592037"),
+        MontFp!("8186463001964086803315373078973607488567538876768905527322257145549601226661"),
+        MontFp!("19715283945499391078117757449557157262014864431503022516493444981357377102136"),
+        MontFp!("10201366877793384824708227219959809605313335011896439133665539069386849213406"),
+        MontFp!("18657679796594390529926518390837962343392692362549805385222541270238612706361"),
+        MontFp!("7486580570539115306934569904905950241452751461260355433571179343632374451808"),
+    ],
+    [
+        MontFp!("13210602081373832317780583229140785143149759155013988676929972636959898239126"),
+        MontFp!("20547921916591862189543062064340935912295194861078724580978244369580188659785"),
+        MontFp!("11184938862747662895633149920437248716375494917732149572183883382335006977362"),
+        MontFp!("4564430184172465843641043220857544376847031874739611967386619487659896927977"),
+        MontFp!("7141474497649513954000384074955704105249233034539438859110223132506810864380"),
+        MontFp!("18152904455695511183625765039917137650094202440231199346119983777378283365068"),
+        MontFp!("3538073603808733813419298816140509998806938194073503117538238879763400661830"),
+        MontFp!("9176198085009858977059589400831647890975463959467315677036348254379008625292"),
+        MontFp!("5358677810755468005042017406822742639656433122059352601335552810789858869197"),
+        MontFp!("14917263528903508585499167331159661105708948785920472586609175307188322712065"),
+        MontFp!("17459264445642522670023202416244479198596207282984954048459225794985333792860"),
+        MontFp!("18314375996391380435570814127239902609672007999779514985166492867417826496725"),
+    ],
+    [
+        MontFp!("3831982114680501270333115239154594098561342624259756350309439921335535690506"),
+        MontFp!("20912918104665637113589191778450022009791755974254243256571842103252066396138"),
+        MontFp!("13641033990672188411010402475025484333293999311081344333460071084676167264579"),
+        MontFp!("11812251882211406110219542299092601278938747815723382992634003397593468524422"),
+        MontFp!("6831378526897417143208721431885442730179700977603214290972417120210221051272"),
+        MontFp!("20566372962271447276247738296560940562676849411310335464134428317792972599703"),
+        MontFp!("12645078398048087182293658191360551774451388858142913314124550234755654392800"),
+        MontFp!("18848512616065065931619133098070120448249278799586409723288680340437325332421"),
+        MontFp!("1336951976108001940844939305921535183133161033109848951359362159969984671656"),
+        MontFp!("12850569062942139371781102794220985236887099863147625303610933201225464388254"),
+        MontFp!("17095240610637692323679811976243261972135774635008876108449261858372552686880"),
+        MontFp!("9827905280236638995189439521379169431346954052078412963417337148237294494508"),
+    ],
+    [
+        MontFp!("21756974284757687824674400821247092844224232050435881799264172836701664225928"),
+        MontFp!("21071024335098050648915070341467331164448998348673899538194227711074199728981"),
+        MontFp!("4575704980288845009798550678411618457862054601563658773482638217288811407473"),
+        MontFp!("17637889149961283902802970141414602882266203898461797433796173000208254839889"),
+        MontFp!("11333768795263093043465906246853631488076833683398258097056018834885708094795"),
+        MontFp!("13237413177387831469342322098638791101575804381099372225484902457329675766563"),
+        MontFp!("17644690723564191319649063794394358703071761592119981992193950976485565800738"),
+        MontFp!("4591566130221102246468298018361020201365534301234509687763578673293960293459"),
+        MontFp!("16880258892960147740975242226985692424894589139379261939284621634132328476631"),
+        MontFp!("10753253761433799332298845732798867046726308621524332340930656512278172577964"),
+        MontFp!("6294042736878992012448357827249736553756583459648169754872627240315677645950"),
+        MontFp!("2668606416345354198548309314080005930475082556427535177631964192773305378348"),
+    ],
+    [
+        MontFp!("5244780336143933367304708103198860683485779489995095073384345019218674347056"),
+        MontFp!("6113177497707123341972721698332981926287006839555002718473535918066824223034"),
+        MontFp!("19824522867272770281143825792424101858356781501070734931688923418771980499872"),
+        MontFp!("16889524920861245550560790459946848535789402393200784095555799980201051324212"),
+        MontFp!("8007523897573727144887255293212203546407757295680344011669395834627039767919"),
+        MontFp!("10043972607564040581522896355091368769007227979993058004755493078808025405133"),
+        MontFp!("10815879623965899246220301442969866254505652264417667341661157537312485515831"),
+        MontFp!("11534947289183790267549825879293816175921818072299730180286203718992863665552"),
+        MontFp!("19282679238458671513808783870622522462539288436443040483594699323647255140729"),
+        MontFp!("20993973658899710060621174143488118082242133512002997296378188507484929417581"),
+        MontFp!("2207137584463635998515616548473102748156816296061395772266322132360447742245"),
+        MontFp!("1906176303891379788266254493945992658935732580384991589403254257107793504984"),
+    ],
+    [
+        MontFp!("356009908103458769499047304270411564071705602811825215044685668712377389204"),
+        MontFp!("21267156990661889558161221297711049481880243497825013463587260785886036981575"),
+        MontFp!("9511026544995595131578865949819301816364113937202346971334793771967449171857"),
+        MontFp!("8732150772674776446232653795368043280414095192666412295836279503085501609742"),
+        MontFp!("10433912179719211724964521733928883750833304291050102060071171195374738464494"),
+        MontFp!("5892753211405353458072760353770523366305643800457149832130812257775605711230"),
+        MontFp!("6072173732058843459603530982418555383917328480859906190255818160437636798624"),
+        MontFp!("12537411810282601586400313857613201485446415283134353086651901233218273826014"),
+        MontFp!("6917382548294560607950289035591212885648538608975878502811670462230179895717"),
+        MontFp!("17522466317865452308300593635805699602531255301383258028599652149794243037329"),
+        MontFp!("13441087273853521452830121325700732493909228483089386934391461759499905593163"),
+        MontFp!("16343244966838333682425984150791963916204788641990311583878776392583665902296"),
+    ],
+    [
+        MontFp!("5360331632046740140575498209972912123753748791093482084409464010367952817372"),
+        MontFp!("17326261745640411514384830391871497358293487167841563962966663074154000109605"),
+        MontFp!("11832463539058003103012764436368900007479300735896611859760466513560696030373"),
+        MontFp!("9332530307651641794919337618798260519292245685937263028043423079258661105173"),
+        MontFp!("18589656636963228136343540063542372097923688201290293330159927366465643313091"),
+        MontFp!("19867733544550615468612533273987508641098463295160374186223389748717034468502"),
+        MontFp!("21122779377039628476144200947919650885809091682970982371472379500808529828990"),
+        MontFp!("3027016623356326893238890601376138710903222026620502687697483362378227239102"),
+        MontFp!("18688538985571458464198537125324538836996766056814806096466271703920561848229"),
+        MontFp!("7011851562043637216927989978271187524190844597096440024970832115577672150682"),
+        MontFp!("6976276474543022394298383474157051715295086098335344328214084449502467982887"),
+        MontFp!("17332130117530488369551999111592288939240424248347612731261799228734373086235"),
+    ],
+    [
+        MontFp!("11760005773828232229811117479200618747574322788890348748345009852749579147876"),
+        MontFp!("2298592572354833593457878362078773063587664198044761229360308644323231458063"),
+        MontFp!("17955258451898615415362730916130728340133607644716002859752428726933265234272"),
+        MontFp!("1495160066997898368260698151648234013994510168440470178901776412293213577673"),
+        MontFp!("9194083678217569822513593808499809262580238989709793956568701401108176409146"),
+        MontFp!("7873147134182176342443626581580817861100691419143183095128784103924898338758"),
+        MontFp!("16767569430250994260298195321407617265691776202802582359934917139510241475993"),
+        MontFp!("21740623993440608033243076692676917908105712281956015230238773795741552902992"),
+        MontFp!("13259811027098062609299383180292487555512077058811432839971137250443197786014"),
+        MontFp!("9862179315713982904488948286370860706178740748908626205136774943873439543978"),
+        MontFp!("5283798667794826693954690769969228006851703579605314207478989967594232871036"),
+        MontFp!("4252584733067990475653166725237187089775889157323429734580856783776820381431"),
+    ],
+    [
+        MontFp!("12973205666610532230766585121029838349435114222222352682635156927179891214439"),
+        MontFp!("287633827640277544463301235265949941438188349560881553375976425107945733447"),
+        MontFp!("9088334958857736274159987824864641712847858830802240840003039491617952671684"),
+        MontFp!("2339929702478917712535010770935571285116447513742167345028949204167372785538"),
+        MontFp!("15676860121778371383024352289403909294835655990865877574625753945822204636877"),
+        MontFp!("12764603061737711341313014678756180651482961849974746341425597804386430732092"),
+        MontFp!("4838308895208597109198370123135942497394705098532245211834944708850927126449"),
+        MontFp!("15227615340505775109084051882530634970803647261111629470904659446755110863564"),
+        MontFp!("4983488923883229416538630982432842594678976476580011041728398828823305128910"),
+        MontFp!("13108944499697608875788753758764556629418014383923261351989967790696566041318"),
+        MontFp!("12840723598548611269818280989252209606886290717050913748780108061152527561849"),
+        MontFp!("2952676596403552231673795576822772308483388566436384169804788313391241403511"),
+    ],
+    [
+        MontFp!("6122163114479319080164532464109940924328926458633830912986940513870140253539"),
+        MontFp!("10991285734105320972904604247873526149047475605919579896179431815938092849653"),
+        MontFp!("7998273930160782917435322581864589776803914924450929021174489205585585635343"),
+        MontFp!("18738565009620163104846733922742158284630011729404799909319718271414375079555"),
+        MontFp!("5267789578561581666385683546519898747246978052550117863765974145167441262779"),
+        MontFp!("11531529707082144080873135819545917882620969294873081450175882450957416920799"),
+        MontFp!("18780101368050367674291690806832757013309591034560713102040071432479406757422"),
+        MontFp!("4294785451272921756656022299749163922793391211928749628139961864310109196353"),
+        MontFp!("4709384663557124742915933403093691049837232917871836683405730215659929289723"),
+        MontFp!("14881387167977166163300411047605462807380624840482159906092434632182882848470"),
+        MontFp!("17882364021447094903093424534075826770336257800280425962604559742290433282224"),
+        MontFp!("11702502532561044172145178834042891352292518800414731965233576503757342967409"),
+    ],
+    [
+        MontFp!("14059864978850405613608096166397211820819484772138676470000521070869797461865"),
+        MontFp!("1780174207952381531321288553792322300359882548955331030522024911690744446923"),
+        MontFp!("13028727766195703514931384985263026051594836628118028800883341509333550916528"),
+        MontFp!("7588265429164678210439639249095081332309245305288210445601269417147124081569"),
+        MontFp!("9642265589138009721420730439928969906364025440120910401773575492361904450076"),
+        MontFp!("10203823545087224726196594521548654230876294164502500177215475003344697986058"),
+        MontFp!("17120058099854629688232575694825768634386944759592366006492580522962871073389"),
+        MontFp!("4222192359475448381394699451673800507222765289015074154697111370954996368735"),
+        MontFp!("3993784121910007141124454385846036053674166308991223986620192089439929299772"),
+        MontFp!("8723933019744566416337803833347252328328807961776170035099333968332078001000"),
+        MontFp!("9712660217038548658606833881897330347907173853654574936296015219821374200265"),
+        MontFp!("2760535358459121107870901826259907500576486637522442431581134088651955908758"),
+    ],
+    [
+        MontFp!("17117109873353435959712351313249763994000699552995926658366410602189225375298"),
+        MontFp!("15834046942685590805387820842211523319635967093317938586307927436446892526328"),
+        MontFp!("20540611330857822392080560199608407622162192943120548518785800532555099780387"),
+        MontFp!("859364761671967022958373408533303411107861007743219314752373993491065278500"),
+        MontFp!("2969887420026581030514962118165604753428498019600416703008438822193590952680"),
+        MontFp!("18165207804423071202274226128984095583162198441473954125845000361590859071975"),
+        MontFp!("1625906472120027348769657238152772240899619610263515830625917309020852034930"),
+        MontFp!("20463932955653127762144495520150037479624855783264043490725916287067071124695"),
+        MontFp!("4638144661708592747026214570394653618741348169520846615537958570570478926642"),
+        MontFp!("14161574596481971299443338101939032775184122775644932228990090697003591542529"),
+        MontFp!("8347184524978696917103428134030602422953969299144482352589164719698955992623"),
+        MontFp!("15813323300222999983430155647025925955486314051966513470055962106024932062990"),
+    ],
+    [
+        MontFp!("15881789235120065904292616653574390818033995432226550640643889297228035707625"),
+        MontFp!("7140692095240485283381028349925581289679980854833888018152159900503732423858"),
+        MontFp!("19010917339674038277064587622527941116247281757743905062456743932291367865210"),
+        MontFp!("14626089726879708665184866329935404127909714172968102824599946119716141024551"),
+        MontFp!("7794590602415713436531570478653055404401179384350609462543106242181653823825"),
+        MontFp!("8762400452539506415642761970628528841241237486958209742944443338295503154358"),
+        MontFp!("8591771034219068368320608087757251879675617969654197927442982939795060041474"),
+        MontFp!("16722135923767200283514351262528880285637400101562656889998995533577538049598"),
+        MontFp!("89464124861540540773346410834052352014308242060847315373495379383140228595"),
+        MontFp!("527363371365063907312062782674442937761539145521639367736772902232593392996"),
+        MontFp!("2507841963726490485511335482108618130879932510673214677761709746737826243610"),
+        MontFp!("11600559113928855816564236457966654301430060930571013803772049573600205529037"),
+    ],
+    [
+        MontFp!("4588632959705258135254026492226920833318427708514752831324568512946022675194"),
+        MontFp!("10614112216523109461118034753484116653637318222297437542653638803709000476069"),
+        MontFp!("9172525851971416839816439437260881435627775489982516326698553647792223169716"),
+        MontFp!("5792656644651436247861358501481981837063028644438334753333821264192324104118"),
+        MontFp!("12896881102412475846285309217744373816727588379892851016206737393174951411645"),
+        MontFp!("11468716910877986153680082696909131615615571055661580004775449060276352989554"),
+        MontFp!("16433370238306953119452573705529989067794419309147323079675422756276020408035"),
+        MontFp!("1273883504219371718563834088540759123243117439006904940363157254976913033897"),
+        MontFp!("12035946135822991823593467599431819071656367730183582372112230833767543617935"),
+        MontFp!("1584468415480349659495095652095228645182004993793756033988320959253925346826"),
+        MontFp!("21386287619427343417104905630296265178906342440158736945069239788114458023785"),
+        MontFp!("8353771053561995945612416527531464945642582086497086814556341372197660908215"),
+    ],
+    [
+        MontFp!("21451387710023590786286131498774138351594763095518652036485484549943942198584"),
+        MontFp!("4310607037909191923700937557843586755810945477870372794480216273434853743125"),
+        MontFp!("14760194187040766562518106717503624683554510559335502353786749509033100485706"),
+        MontFp!("1989722256243159180961728174407914946336713030784288455577084756567680416269"),
+        MontFp!("6686508185527220254925691658356528598024140978521686081374828691583660936030"),
+        MontFp!("10702736220547282218129581824426150350875608542279410979052446031034395032616"),
+        MontFp!("21518832429688884430739158604898893220375510075322761868526168806092202700739"),
+        MontFp!("18894734518217824473490018310535147336620281698236505678307171602214420149474"),
+        MontFp!("13651080497325379255207286313267854150042736096310351308026009382931418587349"),
+        MontFp!("13005423795701931110183481387065874846108794433756894029607220148510576563022"),
+        MontFp!("1791508640954698127256430696076954377556671221508186631495000025000560314469"),
+        MontFp!("17328036510202475587667192203250772802218778208191122529787186719212302087427"),
+    ],
+    [
+        MontFp!("10657963758634548274685968417226435837089760791121929723402922305566484150027"),
+        MontFp!("1373906129582260565717689440357805911187492936689152530374097510483815842229"),
+        MontFp!("9959127778329368795108284764279846598110110516049484774588901738715030294501"),
+        MontFp!("7658768549089819420025961609972525260083323240153758777076357709438863924071"),
+        MontFp!("8556349016311563259984530239150734383111823917689615136867121162886430935013"),
+        MontFp!("5584784611907644257624218172047384219563403421449130896517357173082649535408"),
+        MontFp!("12250428484720929053394094480089037225116943346244386549438429776642627577081"),
+        MontFp!("5894587027805929865241808030013307622768224687737099108946198448930484926182"),
+        MontFp!("4640000227377695114780725548825120725107463526354871525420448370293105443156"),
+        MontFp!("14209260856334030476503172714481690115491571124367768551485878619193949022436"),
+        MontFp!("12847259932859893012278918973069680996469229489435970735462165583338364177587"),
+        MontFp!("14707765228045361126133465815432967736466274565508518646993164305646449611538"),
+    ],
+    [
+        MontFp!("4776938221827642494595066454007024055266353658953900973539209110395754289252"),
+        MontFp!("17345634507638246813894504405651157443245008745673515073520154967327741083655"),
+        MontFp!("14030342049076255140305545268617582878430218358427444316024303212302433090888"),
+        MontFp!("3081608613732784391792246085705209806228642369232320349344206761814264266822"),
+        MontFp!("6925544598968002128460287121334907968171640408686960604018975554503413545297"),
+        MontFp!("6436833790683001261207201071187060041301666725391276179040664384301147754533"),
+        MontFp!("15822971282077757697408895884235910303980603368062126288859779292088533186609"),
+        MontFp!("11405645516905544384081084440311050207481018746793339445931815864373601814301"),
+        MontFp!("8057851956466271394801907096296448157702512819801554261185443997708792484686"),
+        MontFp!("13400701029177845728937105456192536667124279186738602373189177143094323643048"),
+        MontFp!("11966218129646969536507242899486947493941404691279766045602150839476320695158"),
+        MontFp!("15426703084090770508053125497176086377457196612492405232875849008237608103107"),
+    ],
+    [
+        MontFp!("6284964429736232174040445851307654679317453722810844133660225254133335862235"),
+        MontFp!("13541475439682330895175095901646773164410091796597408681295206023344696136380"),
+        MontFp!("746760486363018018017386520506969904420399625886684141218702464564311095236"),
+        MontFp!("17524104887381990279039312517306518062132617046713819525042298049738730617133"),
+        MontFp!("879502104476554315787832344079612181105511927448955207253764010183827781938"),
+        MontFp!("3322243116889956217287455917347322211093649776443788544812848110324752380511"),
+        MontFp!("20993304704177227988946248236539217504266305909163005794546179470620336655896"),
+        MontFp!("15297086470600095823350435092749336605284701364464412627268330863173424318784"),
+        MontFp!("7926831990131610326614433341787624222916431629609577848917665290693634271603"),
+        MontFp!("11271157869866761687706729926953398656059815012440629723619426315368733378302"),
+        MontFp!("18299023753364987381538199358983670915973737470621191699559419975990005285294"),
+        MontFp!("1896523269277706381575521315908503178186583134996425770080392278759498041496"),
+    ],
+    [
+        MontFp!("12890346501512276297101448381578516979954988837373044432656264176509301317397"),
+        MontFp!("50683999778726139626379750772467319774169558910797110829251191014869406584"),
+        MontFp!("2535823474764747710030290051647541950275171691698903262059516064562837569538"),
+        MontFp!("21244438525273542723000364561332886617309030482024380299212725538173747919525"),
+        MontFp!("3566180332124074953578668480009215478053830658040437750684892555496819182968"),
+        MontFp!("6934760873335280553519652185111971021084408206212600990256329557702647231767"),
+        MontFp!("10716990663016621229729175818785901525044168020825517922453745625674725718755"),
+        MontFp!("16032906457960033408967366361214250817346739568272864358714497113973437241307"),
+        MontFp!("3843556041176221664914001975628278428195138883854078534543734996192939783081"),
+        MontFp!("20159201956050700213417267403145919082229292739510798606395981692137742005549"),
+        MontFp!("3946206250066677142194595031646387588831888396304269420593204783498093935566"),
+        MontFp!("15041638172488186356886629449548027800133537204416329580391498341162500701165"),
+    ],
+    [
+        MontFp!("13278796648404195845011313500265526847654531008699937318834233691653233844557"),
+        MontFp!("9401997027308433525669054020953469816624994577441846092561461184007344721828"),
+        MontFp!("1983197599992175511555068756140439953159901969155075046465287261273690782516"),
+        MontFp!("14404434816783701517710626513536590424905462755924351795276920360393132910619"),
+        MontFp!("2265153992995968786485646516094566778663253373238301922539677847420824091645"),
+        MontFp!("7566790081073938501935204486802755123563765476651717380300659543531475311797"),
+        MontFp!("847895502208273199247564103189534214890423186204714047221696858073193176165"),
+        MontFp!("11963011786171849258296957535441472428283852585041548938428326288746833247421"),
+        MontFp!("17377294274187023009074658259347356248383537938503682836124988411002248431249"),
+        MontFp!("1025753579901801011233785198665030697958075722777337374303401815648687864152"),
+        MontFp!("19284019504590857558072823196945292661333776228606049953803221551974077538854"),
+        MontFp!("314204118013777455817026750462293710777754680076389729833422020056750157783"),
+    ],
+    [
+        MontFp!("16480262479960754021966845575947830901794064422051876558808623512389290214607"),
+        MontFp!("1118467174052506904085279606913979573425427098904935496893365844271017524128"),
+        MontFp!("19632743231294128529717484081406317899334378150630214776880617163102648968680"),
+        MontFp!("3911642624469919820429547144355740104724495930390962678347147942124333228884"),
+        MontFp!("2066832981312040153658964981292230050110396574171753029116180129049784719869"),
+        MontFp!("5311750082320962006044746418239249402583149931726835813032955695199748985964"),
+        MontFp!("1668605805291951132468779303736499626803321838797652736089353191224211818512"),
+        MontFp!("8117527083300107176240321998574781132203045054266787492339353711594335529709"),
+        MontFp!("21295747743511951700871264160352432803897824342737595930859857743511472313518"),
+        MontFp!("17388383598447322131131146498858701344049179581377651662282122447439867066027"),
+        MontFp!("1285164961547283134680842635704675169007050470102043475645519044978941760131"),
+        MontFp!("1875066966449664224570600546742926101175136253576702799078476168727336032193"),
+    ],
+    [
+        MontFp!("376316574400845651365366460471492295014146006765806307181242245707469972823"),
+        MontFp!("17926797393698382320151787394969682377223530524132422415508830165925306730854"),
+        MontFp!("12888413344274698384267384086384474622307363694087246755565655858542934044367"),
+        MontFp!("2287154516202749380541196057272317720805230156010471100500251268302509398358"),
+        MontFp!("12091269896021030725381711946583875149737687962883250402694805482865888072651"),
+        MontFp!("2866048333839936509265230844807967492604533527765757660355779329791008604324"),
+        MontFp!("11835071699154512268334373994146252361488263767766913426957050581680705629365"),
+        MontFp!("9769574961167512396467538163460983111263497516872198932597594846643533678886"),
+        MontFp!("6652717430998307400653769850757364796566010885765190753303419969578998970914"),
+        MontFp!("11706514210298927940240806568375915039663039872151275540599047371226868127844"),
+        MontFp!("14503173259289644850389467422758670421081762433800521230764199189580041755978"),
+        MontFp!("14819563269715549434612532555018280611140077716865566327825448288943759458075"),
+    ],
+    [
+        MontFp!("10680481968851597237106707337987338182186447633463836689709242917734021556532"),
+        MontFp!("8842568676211857695295830062491586931539601201165478151045194686251206282625"),
+        MontFp!("6530738090493870852939715092085577161577833016969257865187421674974986108366"),
+        MontFp!("9122348442271830670856465114634608231502719005088669868106730029655164877153"),
+        MontFp!("8276880496499930549062062890222235677345093568048001523633692507246467514288"),
+        MontFp!("9159103567267771525632277936018480854652194329966882107551346987942837974086"),
+        MontFp!("16469319105211987147868896414457254521940280033150431057117354850784171354412"),
+        MontFp!("10187807714948737954868581824072203418416253898435396377923197796615888421106"),
+        MontFp!("12487902950231724077418777903531512931204649041424531773792238730349062186046"),
+        MontFp!("7128847500406995361903929050204217572789701023482188856789540128660376992648"),
+        MontFp!("5624482939513897521205456066520453513748417048856589163079298385117683428424"),
+        MontFp!("16435310041815433976313715464222273283215148709695464992270777828213079073600"),
+    ],
+    [
+        MontFp!("6053110243546717824144224394047966869215089006720239359415188827006018953287"),
+        MontFp!("12155283178966050547567835509058470201630806787546132608678364857190533906654"),
+        MontFp!("4516299433052637321210501303761417706907638446979735686067715866937355560218"),
+        MontFp!("12667848710309960989666753249292981950689410172688589690222261363826904222200"),
+        MontFp!("20798966719534261926649504156624517814254561351583702314533431478871413942185"),
+        MontFp!("17967549743799112209110259394081841952094330269294500558315036929658897218889"),
+        MontFp!("17847714150935110939206476281446907230585782511584357788270839909827513843593"),
+        MontFp!("3706525735092193828297046649401002937097518714707649753675691741717374375329"),
+        MontFp!("7951431498272893618049118070591089643051946670270891223821638082032777246372"),
+        MontFp!("18050610739452223409884731462410705437134574403721907246909018802102148878228"),
+        MontFp!("7681233452521913704953218921671929208806506209990716853575264423980840711485"),
+        MontFp!("13423126358538883907022095116894303361057277664724853411599423630968340874618"),
+    ],
+    [
+        MontFp!("16385186307753207860551232886321416660506143479955163989130433021552655688751"),
+        MontFp!("19863338799254785908021006319182666048802363286601429063342266271260238637645"),
+        MontFp!("18613864443384979831007688916611349052439959929170133474546815634162799183324"),
+        MontFp!("16332951432721718166552572222119667287478083212745997662304261644978474988848"),
+        MontFp!("15183520838042840868796366239500461619005160337097541115133209726620419190272"),
+        MontFp!("21819632461581352219365791577295628387565956152415171439862191463144627141459"),
+        MontFp!("14048533271709057229957508911818231901369646548751751133058494379648714713155"),
+        MontFp!("5460165509823151384714546779805746551465260311624889230527298343216031522426"),
+        MontFp!("7812320479950772901428223193019468171803323319794359571259026602925413892220"),
+        MontFp!("6861047298949697363524436975701264276161947671796912946484193048676968364570"),
+        MontFp!("15350000814702190667768044439440847568690389883802239839819456575900015807805"),
+        MontFp!("13617689242559757166326885110516025083265117478902533337635065578646035372109"),
+    ],
+    [
+        MontFp!("7476843340092386505769477537028758806323421556263090744578424892783190404262"),
+        MontFp!("14826737006932111183364733677544376863485041173382717893595766527131815613201"),
+        MontFp!("20482222533304950354756700764919164516648372491559583778492129756055561547902"),
+        MontFp!("20761958166613639518991371846609568602964716408676516216766187562459779452764"),
+        MontFp!("383428479364004198529582849470440881390404766297544454256885030893768964772"),
+        MontFp!("9096063532469724072461386558496798312147772678120608720218640484115240695555"),
+        MontFp!("2933312980053872879818024429044461919397766967639471518371949595510364515880"),
+        MontFp!("9299316327986512295931957414766538459714522358165090755549540858707887390215"),
+        MontFp!("13488092390166418062347006407529577052576958127764741552594028975338262414671"),
+        MontFp!("9829077383468426688376344724031102291718825262406301642537010429132676001524"),
+        MontFp!("17306252922949843274941351200908184305715966839608017699204279919732909328171"),
+        MontFp!("17087445274779483487397521683084452362124400111143021068068404568736590545834"),
+    ],
+    [
+        MontFp!("12957176730411938817539171430465916443660534484629425102659921020718336935547"),
+        MontFp!("13051137677735706323786222675600013609665436923061132301167672971485926193799"),
+        MontFp!("16896405620483797393236904159048703206424672638297382666760526590725313874095"),
+        MontFp!("12982786219003483259026043754729673677479461289605569386777999422861331902406"),
+        MontFp!("2025152781178874327274493576789397778291707054468278801142821085671743579422"),
+        MontFp!("4335304794121497318666085856101737871981186359433585080678838732369068576376"),
+        MontFp!("12639022425423716913754007933194900468141465623346480681305462334218095484056"),
+        MontFp!("8623058240535559764777469232729047628411050024996927445391922152785990079007"),
+        MontFp!("20506335897603554545414833361572976187621941529253485759521266317325651663673"),
+        MontFp!("21253532477517846630911098846446871983813752932337294199438073014278982612265"),
+        MontFp!("7690372912700906813961714562821752274841425123411432854046237798188463759714"),
+        MontFp!("14774079281706150157728885377093724231164512116594840467791057304501073046227"),
+    ],
+    [
+        MontFp!("2057509476014278951878531863305791034496952436217061173216277825372715694262"),
+        MontFp!("11541539364361827516114507593054831030438985642093836719960774810015976254752"),
+        MontFp!("14751271529818502394785288765886130704541730104426205431367858892918636267768"),
+        MontFp!("20653824984831671829709191706456216192511770845470322444515326861038843106056"),
+        MontFp!("21794617070379980226628233360507465028625654800811478227502718633923944312665"),
+        MontFp!("12337675699660313500286885345145964244870355316351529815248466118884457457726"),
+        MontFp!("9527268832683847687152279087478212995292048665053951255936397859316414854388"),
+        MontFp!("9676848840550815858798680133955006115514366150476209999729932493584446713554"),
+        MontFp!("13718457801302749437654397628557435190188493872576865462727587887532950355038"),
+        MontFp!("3867324761996955514268635801392015956546058763053808698121442586705196957126"),
+        MontFp!("3945177530905850250597694151686052963152292050981415927393741981018273529029"),
+        MontFp!("9473309264240097344589730948390594137445381539328377904021534848676986947654"),
+    ],
+    [
+        MontFp!("9996385364578703060792470002743943020203968343080025005448261317627854389642"),
+        MontFp!("7671875350824730767796781844941080563527207835942241721783016221076158519589"),
+        MontFp!("21342545963270813418079341151020475012992255268798567166496112378940512106806"),
+        MontFp!("8273987785742417777035293384288140933989540189974521462819168552306151314447"),
+        MontFp!("16162516432221515902182136237589464594279404001688534734264317844425059661177"),
+        MontFp!("13403805360231306331901242144909690435939614369536490494604700225511328976179"),
+        MontFp!("21640586272648741017978258285249302214133507217427529405059807093199643534960"),
+        MontFp!("13103298902142682492657114828016226525668797790480257241873358498200705227711"),
+        MontFp!("3251016042668618943397347852790038985788901898321438389540422820491143534512"),
+        MontFp!("19418047092207786422594959190763286166909227118034848590779428639908706128141"),
+        MontFp!("16018739283901993654877226905623763753431466873170287728134253742237701259452"),
+        MontFp!("17444946939314191179654576411859454283526068144165928707555552999432295774923"),
+    ],
+    [
+        MontFp!("15128585121813842824984220733706899232093493608422847549831681212432769497258"),
+        MontFp!("14256042951767483060181875165067417249801798477941578288282791927486641413650"),
+        MontFp!("9120441102041865153472829977920901798404277796873323854759847010789551358565"),
+        MontFp!("16824614118285494743762017938800453590083305489658290570438237922163546634147"),
+        MontFp!("14066296536773839458459021526010711790088172526749593960873828093107983908643"),
+        MontFp!("5017173601281838065126812757441844223954524936921765951283764327160567691674"),
+        MontFp!("6254306109691187702602819001106221686689064495376117914248886700342291435542"),
+        MontFp!("8093845266500847743481398069264511597137596242374064050252920004891985410254"),
+        MontFp!("3578536483838573103029984288079029549393340013685219142070711310306738904812"),
+        MontFp!("4046636520929111297180341957015914921052723918845140326762981717454102872515"),
+        MontFp!("7446649718318072281388998859356555024817100796345520325890349758135889404482"),
+        MontFp!("12366828085438268177090003236704251656102820345668679675414643578247762288715"),
+    ],
+    [
+        MontFp!("10235336658636336826692093860668421345161445948384101191460476320469211290719"),
+        MontFp!("21557699385719674753050624572282725191612933706864216121110471118396873804204"),
+        MontFp!("21676195031234643974566056999169564975565168161106308063972653296222128822558"),
+        MontFp!("6363029841115916180506080615867336124807912331702908898766344258887335068638"),
+        MontFp!("6363363442473598630315975560083028488315295047134858188348763022659100540644"),
+        MontFp!("7124701104304155953181957655778532711129611550814127577450223681003954918096"),
+        MontFp!("7205717562822389401795306034882449236003182581929449060008168502546885140420"),
+        MontFp!("9228704321577313703028591075278114440418729713968708821922150367010520752433"),
+        MontFp!("17614180549151511016628006398127365390131305608457060951973438257438000114647"),
+        MontFp!("6418032200333926158639440620980173724897942384057810379437154014285875236793"),
+        MontFp!("1563831431103246736895130173427149853201118983353993792302858411456255839836"),
+        MontFp!("2319462743404521965297213055024756354349733992588917297296444679195296432311"),
+    ],
+    [
+        MontFp!("11927767606932195981021511932625975307755048477176557643420944859867328059951"),
+        MontFp!("11544836785666122785129617591037772824479186137515525949187438698705551844116"),
+        MontFp!("1594554937757396474339277849284988438779469013126014780880063013190163371374"),
+        MontFp!("3090898925598297656723114627648156566177591433413115455417582215942083585019"),
+        MontFp!("7446365483911748580722671015415226813450613606623816089630076567220802142731"),
+        MontFp!("7277804562221458254100595374565579800145724793164291138831756170012315181874"),
+        MontFp!("6742114513180059066582114839865181272182587124666919323239785847603969045455"),
+        MontFp!("17639336822454434815461281360929380680585323524008927548062809039584978130493"),
+        MontFp!("3577053914395256679157728351997326335158820293078866565158939674077090010647"),
+        MontFp!("8416461669288393104752370629542582785060946946477447413022986748926853429341"),
+        MontFp!("6094400991411029253990297530870616276561743402531435382960598226029406844554"),
+        MontFp!("17249210115827920255165876773266699947215322191164909559927557290769226533383"),
+    ],
+    [
+        MontFp!("16910579881016588643870584852395328514859173042128957721312044020554328803878"),
+        MontFp!("18548800605828674358041671058553674062230830447893195908258772241250469878618"),
+        MontFp!("19911311709285615075760278233929935358167599770457743440215721586291252049005"),
+        MontFp!("14163239340563250030474254441824385465013759375574504142110478434407678615583"),
+        MontFp!("11636512441693145656185679602762396225011813780067642639202962721478584613773"),
+        MontFp!("11627821975451263242823912745820712501746551642630006051017450144021728526893"),
+        MontFp!("8744830043459757570443313311577350804214757169018319227421153827794333111861"),
+        MontFp!("15502529014760684785001315229904504412899268461167235130234930235025949266727"),
+        MontFp!("6349631474781311552829280885666605465500111601027794362415725063684145078154"),
+        MontFp!("3750521632595755853919734934063554601358504982902984744970328457148877834110"),
+        MontFp!("9151149071511054371049219579896119997746835784236581257692741291133486546793"),
+        MontFp!("19272083712610290647942715418077844472245445971738771938657138000646231164194"),
+    ],
+    [
+        MontFp!("4825691749471936225587218745193692386291702673577201485716323799193545312215"),
+        MontFp!("10104595857457718843210330892798628518507052980455210862700838136878095844947"),
+        MontFp!("583353789674136864282067863249996585561603923757420881823548159264798915805"),
+        MontFp!("9605601189839871334439279312434848410060547509366999421152290140420275791851"),
+        MontFp!("9086947882810130237770476626970553738390827239675874433366363671998428155733"),
+        MontFp!("939087130792625935525433768373221635016459152936104283115879791761930303865"),
+        MontFp!("13281505022430256926767514868008519612680654933479762886734630854898956588159"),
+        MontFp!("8791789091182070262897704714134448615861594471173394864103714557845783501440"),
+        MontFp!("21362425094620759937169033441239641099917726266745789893288044988022041089124"),
+        MontFp!("21264794594588373773536904588090499011630449220310853635095227724956212914745"),
+        MontFp!("2201440000983888343103152416697093803247262395826184450099145084240341640621"),
+        MontFp!("3661470436482399872770855708635287053510507688269721514323020960038319367739"),
+    ],
+    [
+        MontFp!("7839180633039164608733079650881737341586893235460147432870429877862925246133"),
+        MontFp!("1364292691308802542355455613317294540368840799230835218243676354771769346816"),
+        MontFp!("8772467089802087196594680904948784186594877728663145042664601336273257169460"),
+        MontFp!("3528294282612889297228918585517392070857581849271784732463382671241778115171"),
+        MontFp!("12283613789492092234060914364373118974353698674396863245366831728069561564466"),
+        MontFp!("12820604492590938203463369747918352644874868843295488782427665387357902190399"),
+        MontFp!("13019897240405241197543377542947534699610589548844172163916774096781016951470"),
+        MontFp!("8978439745720039208900766235834036199032761881062736449080983584518864263026"),
+        MontFp!("15222275515593239598230012498885809744566950744378381699594010392927659453864"),
+        MontFp!("6158846143100676033784762660398310184837175038752344358512599894306514737286"),
+        MontFp!("3419255917977463022603617504775890366430819682320515293245827217078337799273"),
+        MontFp!("2797549320616484645720845216321636133654549511963540737715630452964584943931"),
+    ],
+    [
+        MontFp!("17033753202496389008484483700445909344644255293271694371709019653557282454651"),
+        MontFp!("1896525136564670388001069429586407694373044886658459111231294687089652643180"),
+        MontFp!("15410846104953506982390647924353051056630524372570695181992786623509940504512"),
+        MontFp!("19568185152800187670153137856814273221696851955003399523563214963248234462015"),
+        MontFp!("20371827999905064832491002071821973934784290275145413746617942892257828998608"),
+        MontFp!("18823477734276348993184895526705908983521278925070561827518170646437136680627"),
+        MontFp!("5656115226652970780235535706189807810471572029095698537591078819723656151288"),
+        MontFp!("6421434687984511183440563950015895899135677057679953590513947822215985604920"),
+        MontFp!("12040384754225464077155439050798210419160821091649797103441177013046869869449"),
+        MontFp!("9772259825341200622705640733478002328903138705997041039987261797557503104066"),
+        MontFp!("16498280031288829288227785307302736352936199535492502196962915104633784174443"),
+        MontFp!("2797879572297199909334218711222177900854374704477138347081058961121300793309"),
+    ],
+    [
+        MontFp!("15644078107885262244699428484967291897698559437568086329631330880220396182101"),
+        MontFp!("1929505350484341295824323699861178332578728141996333839839142429658318709684"),
+        MontFp!("19829107623275996909068882503976730773632786622439634235897400991781002387336"),
+        MontFp!("2349581104996259830058120951667460730076264470159293978617685139254844279099"),
+        MontFp!("9982268711644224005105745225708318829304834159499232155579405472268549361372"),
+        MontFp!("9283048909915207444384413611285315401509676836116090349272606584736947471535"),
+        MontFp!("7228184586853275989616881380719765868918305938029647325846170897121335348738"),
+        MontFp!("9356148801854782728189493667017091537583121935960987886599900693915567464857"),
+        MontFp!("505609318575290577159607039016980344147219758564644283699477849237669899416"),
+        MontFp!("14366218774993226370408163695258733389122646477999733966996434517776361579764"),
+        MontFp!("2089359639878444205205504625611416181841134778729917955490118311877471215733"),
+        MontFp!("698820948911402576486200349924367867353277614409335325450207885100392818369"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=12.
+pub static POSEIDON1_BN254_T12_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 12-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 12]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 12]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 12]) -> [ark_bn254::Fr; 12] {
+    POSEIDON1_BN254_T12_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 12-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 12]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 12]) {
+    POSEIDON1_BN254_T12_PARAMS.permutation_in_place(state);
+}
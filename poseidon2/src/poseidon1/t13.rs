@@ -0,0 +1,1345 @@
+//! Circomlib-compatible Poseidon implementation for `t=13`, i.e., 12 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 13 8 65 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 13;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 65;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("5891205978627836991071144083270417159015157070199928807771268303875194037650"),
+        MontFp!("19534191765629085451497649051014772157774065629075791332793195826681584551273"),
+        MontFp!("5154833515272483128294702820663628026710043323095920240638701304804298499578"),
+        MontFp!("7917593571945709638335150893778153193741477651398934233734658265336884279055"),
+        MontFp!("5133163238095742835090645087711007173805146496653007212988348307349716673728"),
+        MontFp!("19458003745533910239158707983408152209004063097952693956218574434126899070042"),
+        MontFp!("18880819400751577287416293176849355951596193714265500681157024197361640709188"),
+        MontFp!("11433257809059443065528679883569438998689217744131300496692049205047550090935"),
+        MontFp!("12190874701550908088290603272755607342096152398135156662834905887211629834704"),
+        MontFp!("3673886960353993252497154566539843726250940370616316569888448647738018022083"),
+        MontFp!("15676037835112699420746702265028664494892600184195941373794207326709270851677"),
+        MontFp!("5045635616511022726309482514512221209262777381751689684810362174166837266849"),
+        MontFp!("2127981970274354891783037834911068612842150500572698772082540184222710046966"),
+    ],
+    [
+        MontFp!("20602209860969247631763456039704661822909928028055826436834118113792574371849"),
+        MontFp!("4493454930923344041221912772221535937546211498548091351164191172571413962846"),
+        MontFp!("9203273896007845628978022785284502260659411177801743914849998536940830966257"),
+        MontFp!("8789969715987458351416076625905723956294447007026950650495844175328857015476"),
+        MontFp!("11215930522605102963220331022508304708093835095809374254680583403572809751875"),
+        MontFp!("15586225257380986275249577321891268850474446160605827963612996219805386502932"),
+        MontFp!("10011841080310254678847831138830289501574615297642717372149423820287945856079"),
+        MontFp!("18753960408421275836614693391945489354883623026056573684871883182548779915774"),
+        MontFp!("19761678450406536764850099419131825403291221931719095841429266089353917202891"),
+        MontFp!("2072721799733232450097521331054430993573774079728747665078766019206508544636"),
+        MontFp!("10615297085623730419003352440151421233872764050371800287995783143303761635742"),
+        MontFp!("11342862179098306415193268089882628716478112844171129584590093902612740081938"),
+        MontFp!("19276661508999391381559540866976056457016683157876039262437370976462342153704"),
+    ],
+    [
+        MontFp!("3487982371390545412669202630006964510064537478038100559383689697998101381695"),
+        MontFp!("18019611455528497754832260490613820837001396745087990928355304172772344827985"),
+        MontFp!("18731969803319425707048526160946688629598037600738751037795347491343115736279"),
+        MontFp!("18895516979789867152215547520753345684534505439453679476362827176682322481937"),
+        MontFp!("8262582236770254192527817333585360308520228688394271238254948357217070957179"),
+        MontFp!("20124670759706967221386035867404978156598790198778905768160002435038055915086"),
+        MontFp!("10113512603622787997151768792636528607526786828914243169997168136386113705095"),
+        MontFp!("915897670578586705347124681284501818659263249883815809455861196950322359631"),
+        MontFp!("4168315355477923626825760085789663510629998017921421169386695143739645254818"),
+        MontFp!("13671994112691093230470350971338683534216964112891455368255908216954091232088"),
+        MontFp!("16886224211742114996348237388698196253930997227482938831037908300450123060344"),
+        MontFp!("3345133367703042017339663005080189359441174937067366586009093723866269451347"),
+        MontFp!("21528583089657067992968569213666076092311468898762774519530397406988724032331"),
+    ],
+    [
+        MontFp!("15876161034145690426475777675897218203065468785806228994483284137836054650127"),
+        MontFp!("11419482087592638487692501143058453465931464811952523437138882421550619359191"),
+        MontFp!("4688593371456663565609532492788107789533208004746508669973851893459273665535"),
+        MontFp!("1201806670097794327812047975382630669548999745861216990648173033237996826404"),
+        MontFp!("4317641195125807665177432835324854194367911827066332519796115671103402289320"),
+        MontFp!("7278153623621857829571838333149240184056003767208572498343141321166882833584"),
+        MontFp!("5645725384264681461759518050072125001915558414870126637288820197391715227313"),
+        MontFp!("7876944044995178879031614460771670730631140542631541219377182212657483769883"),
+        MontFp!("1817650660807237476840344988506407016951597837358142384730920692904089879519"),
+        MontFp!("5477456541807551375261337022497006230056471139241891672239301675658950367705"),
+        MontFp!("8048211508931499636316723219242009338536169470228918968914286373316199192147"),
+        MontFp!("11051780522682663717015921863167554166331060525740053284975151807269395431450"),
+        MontFp!("13621239165564266256293257623306520070257833884735472109300551735647149439281"),
+    ],
+    [
+        MontFp!("2212937635665982737914958126511628913962157442295931340442990688391698941226"),
+        MontFp!("15995366165560744217392074544914614299200056620022955679432568011246760194348"),
+        MontFp!("8186603384193770310414376291688089375415922904100458138117461272757184852177"),
+        MontFp!("19591014640167727612037871145675698427320771791339346286884839214170680861630"),
+        MontFp!("6878084246380728147562027775456286883121281123291292075367753371891198993189"),
+        MontFp!("2177050211387664317673794964274713735596159455191994291603735988793477650579"),
+        MontFp!("19810792753868883549077084303872022596455081266982011682803771833184330522738"),
+        MontFp!("7185072414158632003497987061744951789947697798790757573674746012007540132625"),
+        MontFp!("12527008463897431318214816404214269326472255194708737027196205809865368523993"),
+        MontFp!("6934500447964393691613594947677420114877610140521721836488732706620555412923"),
+        MontFp!("9978011727059765171039296158502240318826874847845043732001483806732259491882"),
+        MontFp!("21367223873262404675887107131444254925505042466133465670356500592380419754092"),
+        MontFp!("12370989539828127569760369184182336850673006315469364895069603190681475159813"),
+    ],
+    [
+        MontFp!("4771734208255151020750966033073146490824522462970752283771863328392876062708"),
+        MontFp!("13551343845317029011162863399460125746613189002552375164523344552618567494698"),
+        MontFp!("8714428409330855425634336943651573814895603648572558273360471650145732556280"),
+        MontFp!("1770920281347553432035718101936298919487500537138994976517066719980578590089"),
+        MontFp!("3110069281490803391353365800012007306367848815614936878141004366920256162421"),
+        MontFp!("587101336788172489216190547515347729725635829809300681040400739386253763168"),
+        MontFp!("2745547964008447408503376832407161412155228373377123760513770235899201269964"),
+        MontFp!("15688219884606649780982718944113231917978875761031663915052600865004707442286"),
+        MontFp!("18825709614401251798160680403375685428394659815656068061728877494732182115807"),
+        MontFp!("7344398268236623675422623600003139537460576229211381042555723883054380022043"),
+        MontFp!("14666515770263042245313469306170077834894759906373286169967918153150186862642"),
+        MontFp!("6262353441640473491135912890626291592970997790093308164286742582769628052614"),
+        MontFp!("16647307543328963728423591228360400112670150511841037950484862728187168155597"),
+    ],
+    [
+        MontFp!("11187123547390829437191210933751439038277808812390863028310714957203862953416"),
+        MontFp!("13431586020033401007013925927716006954532655767977222332198563123215088393612"),
+        MontFp!("4290575536028694423523505804878297212249395907285796384174966179335089734293"),
+        MontFp!("9525030500997851642842588010076299538258273880797610368114449809143832950303"),
+        MontFp!("8240494019366037169932737683997756281590058122972608854062263901069681117554"),
+        MontFp!("20634410655079842888667296641045124414486057143740858179591482529433244800210"),
+        MontFp!("2021226937398532158458585055746155459624344885692396128118875161667614679890"),
+        MontFp!("13363628208058779432402710458326211021009444288989875416757721068391318188214"),
+        MontFp!("14662421589311461388753832631349921077594459767269924751258576584313288868105"),
+        MontFp!("18478701421001679788418312436274921897007545359172305786472370930338255515306"),
+        MontFp!("779644354087716689348274274240595541489283221242495213448957276905050464536"),
+        MontFp!("16071592196394048404777963063722475415819376772419538934537115615105548954438"),
+        MontFp!("5095096770582161819227893847981354649325178848130636101047350986634230116037"),
+    ],
+    [
+        MontFp!("1243295118881144894548654933667320243992122811397983231810580344448403973343"),
+        MontFp!("11884934205846782297010667633102865650294795721122133935339824653150509106639"),
+        MontFp!("7458968983251027899406062962031140351528726088472453510482489928822496580100"),
+        MontFp!("19572605475586099575374380909719328911692508931262625802140140705257944509766"),
+        MontFp!("14705309817743162695613012501115465410697971407093611587529338557210155341093"),
+        MontFp!("21814585268359946040619047839768523980706543116273413618895661291550785045639"),
+        MontFp!("18720305501197276565912107809183977276090965285535057360911917408689742145019"),
+        MontFp!("2134301697439195186325742384937390317718398738774895777564128344393744278579"),
+        MontFp!("16999326242022117650983709520661797031983791094852258286603416430772587131676"),
+        MontFp!("17897483181215416614794986081059087805317610826416633427262022077916365348849"),
+        MontFp!("19707797946013555426424189263942163273279448488563211841018471715309464788783"),
+        MontFp!("14555678829341308540860562709255991938855501651550888461483653488337939676588"),
+        MontFp!("17257409408848021559108687223120061819076248102607600439065783833668882002860"),
+    ],
+    [
+        MontFp!("3083159817330696927114122348973911210253613266522114299928693807761894470034"),
+        MontFp!("5736074496230638296274343498461296106748247754169694274901381380232637436330"),
+        MontFp!("8207744709591183622611260068351833593643143431375276434360211505091128037806"),
+        MontFp!("16073710603427960567922233549405442518423088068367439127980767364626490766482"),
+        MontFp!("16125801016656798988611163501719363451449395969542389751490084517803061425074"),
+        MontFp!("16681204974924630782971582682795720527615927905982945319130944791490607417696"),
+        MontFp!("4072675318306311800303326010748274698511258916524447342766005819081244518392"),
+        MontFp!("4639558473350853876171991553789446979158416238030006798108198266387155784407"),
+        MontFp!("21611752344375994669307116989730257280581712049771305171467376136735830835317"),
+        MontFp!("14260028812889714557229612460335412900866192266288236233734870104907234066106"),
+        MontFp!("10936007367915129326030460455513265303372033050181411601450223671258879981020"),
+        MontFp!("2935032369592212871409648743766195391225915910970902425024269583756879977136"),
+        MontFp!("6634946569637959135045435256486295750841113686207268069245788404426148269439"),
+    ],
+    [
+        MontFp!("11165382706437522214793349607928919108508947068233467479625942161240196013032"),
+        MontFp!("11449774151698349588558943383567398137746718964787475388291636245211033594857"),
+        MontFp!("13380655477865684658511486065341626238240224687038830357479380314844874141318"),
+        MontFp!("1556090800260299290436338214947407050034615159120446561828975680392439133850"),
+        MontFp!("15775229412830292677008903643751483031582598935755329498528261914480871637362"),
+        MontFp!("8325948986690458545596228454116700887740572176003019243020371356605705227449"),
+        MontFp!("1530006957398320461897940887072398082651602436763497487522949690691142033613"),
+        MontFp!("12096799031117418724262752691656478204625211872574986459351576236141568686903"),
+        MontFp!("9795222686269696766812901618791046177266354705263547407732303299461050133927"),
+        MontFp!("6370027108686216641431817942352503637286925173249339052075608610090399016749"),
+        MontFp!("16881432515653361341795686702127944966732199129855247935726797972793170639701"),
+        MontFp!("15351738821101585856536273249878864428731819041333447251288973888111661451683"),
+        MontFp!("14781212701946742438784746658712056984412254191444809612525158953148214912100"),
+    ],
+    [
+        MontFp!("8091550554023025707193058566806958042583606199181127012808071174695106343115"),
+        MontFp!("20490495862854672187041438553984493686275844004543178578410057111061213880755"),
+        MontFp!("17273768908086623408127314492263145283983205996943328025810362733169697859553"),
+        MontFp!("19294495315219029609698328900049261980545541811825479502505060031138576089112"),
+        MontFp!("21659964890056395567978937545379633715401240283937936880886676845913261767053"),
+        MontFp!("17982445074266408124204445317785538167802060185652418682509052997213396963809"),
+        MontFp!("5844393214733022541634389892381973171593154485646342141850588851005973351855"),
+        MontFp!("18594392739980641449638044568401634303115397702236811642652121212412266849233"),
+        MontFp!("8182160648431978634742268417253967926524505311200391645921674911687111696608"),
+        MontFp!("11615953679796573972512524871850400010935503185676276201708450621253942540441"),
+        MontFp!("21529769273680682324960067458105490598252059794192747768640910191459525561125"),
+        MontFp!("20132110404365493609540330952369583903023878161175287878881490874880122068662"),
+        MontFp!("3315629685104800403477925330007368560121731964009894641262206418774875779654"),
+    ],
+    [
+        MontFp!("20760167657218552641600617661638902204729190730886821404831449731791027856388"),
+        MontFp!("1071944235595105837840075721866496337725631499557288435858051131598364359180"),
+        MontFp!("4555500238184379140256400675916267083899817767012366155141824954325999920862"),
+        MontFp!("12550383095401941417929336389002623533497339330808819244458405679309028814897"),
+        MontFp!("16242206659396631583090203067480642438705316999988728264056381863300603790054"),
+        MontFp!("9739751068073084582742270086389269366580429568378316378361121134559060472609"),
+        MontFp!("16116599372526867528428771037225046273420084760475362036998100512782645537424"),
+        MontFp!("1491702476187736766465155485454380021314952117101776564498935819251125640550"),
+        MontFp!("15487639992401023512588653485898591177260483949656489128772103961337143916568"),
+        MontFp!("271607781293753174262565976623664759968566553298322241892863651425822643900"),
+        MontFp!("19977950168772761551778240748644447848771422612925087742515420134526515662483"),
+        MontFp!("1983058998587842721058540590280805932874010898105761473162166721490888500174"),
+        MontFp!("16107724245058262116195892590046753877901405295502298275519986777803068771465"),
+    ],
+    [
+        MontFp!("15598969808860428995774387003121151871293432204528482957206560135604646381090"),
+        MontFp!("10303850219152079194793643117738367623863138704730923818757626414538626924093"),
+        MontFp!("18042134015692222011976424010811982750427479608456170876100049843530175591980"),
+        MontFp!("11698490610713203406365860893495577403678312400711273827266244766764136895745"),
+        MontFp!("15094763799279956189651458728729592947034549021140257103479085265792492443257"),
+        MontFp!("430096731859098382312819496464652103427606481282347941948051379574546904741"),
+        MontFp!("13960717870097098006695192936395126361902142536383841733120824678277505383661"),
+        MontFp!("4016844697779547662080765040347308557842613782172342555085194018348439875647"),
+        MontFp!("21390612758814913695838461279472506230937295081984131634720827665924667792338"),
+        MontFp!("15210153052017283712229693210655704356560541803226219169450332030313730637768"),
+        MontFp!("1405020343631287949667260064722407285384539868534544303707265307694982887517"),
+        MontFp!("923081188208761071201163943024810005498690637139303388717205039798310044759"),
+        MontFp!("11733793144318360060340673323677375331041661345320756410073354731608712531433"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("8798508051216852101945770298446195263426534955832706109793971883081428107277"),
+        MontFp!("18986509933027080134736570007084643366993581436980149915398754460582000677690"),
+        MontFp!("12888632176077676513526604615863975427335143923557716212154194444095746278696"),
+        MontFp!("15218363940991075171925464923999816479227567707705088260324253815128900522726"),
+        MontFp!("5123856245344699442960347063644568841985144075867098178729206123344695866632"),
+        MontFp!("937497109650566529304426914695525196119182564119552807475810542139513078711"),
+        MontFp!("824338985948318627675352935105995425917124279032809525354560876904860400681"),
+        MontFp!("13942203133811236390194976449250445722833065335401645546668049734024419803027"),
+        MontFp!("21193497547768556525501812324063541319364865851059424372272833186043647143451"),
+        MontFp!("8250588221665279725951753271334754148372733354841465281007604695074534688729"),
+        MontFp!("19810263445128123914159130235504848033923366950322026683325764129239721093372"),
+        MontFp!("14320576994914109414332501339118128158228930675887122896979269439627163060693"),
+        MontFp!("18889264041663920880871940494605753485014632588652805059026615325416030193719"),
+    ],
+    [
+        MontFp!("10698608730721598729809918763148392473410663742558667693804099019738511427166"),
+        MontFp!("5471531298285601691838160103499608876767872950664004448938449413930813358696"),
+        MontFp!("11457601248269622750970644898105156673192281665198367584690238105651871243849"),
+        MontFp!("16992951642443963917535033612483493956841253647152989134657936764458589935104"),
+        MontFp!("10681874111209657199770620844475073359855652721773538207614365548304774934508"),
+        MontFp!("21875342192304323235337862160226992419479798345905340775869689347883470787957"),
+        MontFp!("18783598503862573040343207522848147282351537545213737188311758281500392127423"),
+        MontFp!("1221270316640374234025430293653174054626512367600614548099335037611485538579"),
+        MontFp!("3803127350594514565431185919859613836470412459545124433755604579955078714169"),
+        MontFp!("4692704173297526575662787527414813729052368393667564364621548376076502682045"),
+        MontFp!("14877344233698668087821753402566363996983618742746012792982725192235615274582"),
+        MontFp!("1054560579819817802724755404438987869385528807680314510667741074193700268397"),
+        MontFp!("8052216208184446695555642950254618937388238814298100142455645263386380520432"),
+    ],
+    [
+        MontFp!("21528486310986712871728288619461268100617012373791428855787397091733571140849"),
+        MontFp!("9607239927604256097021258716815750648977424271874860033430637795556811442682"),
+        MontFp!("2196312812082021938532541315765264710074289763312450521728804241715253452502"),
+        MontFp!("6605540160838083938636248853297421723407665107595785929587574408377531474397"),
+        MontFp!("6525948153406525772960169117380395975817023461160134354401655738067058124631"),
+        MontFp!("1003556227519998429556476568497227440137917634405973503866888411893650518666"),
+        MontFp!("13677584968475474622689223355399150668790874783709262452797580070344541448836"),
+        MontFp!("4272686670447940608576804106249107479440735969672981187306042096242258850880"),
+        MontFp!("7723948150633857131095670967217469617626942590283045283879017721699420758313"),
+        MontFp!("9563761958679910727099148022742805150896356950128100201760652748527446140573"),
+        MontFp!("2850052690445041465388681891454371728435601558220304706587137488583335087257"),
+        MontFp!("8526775100524058290507772716277462650712450825299014127801740914862834880076"),
+        MontFp!("3867865860920444582361195032750323847757212456942796852256870663055781206979"),
+    ],
+    [
+        MontFp!("9531131437985240947539412881613144640386246585015365913532093261866651903759"),
+        MontFp!("7876176264763636659141490167245054739857019306622806711043600468363729864093"),
+        MontFp!("19195073274099628437757763039411307254419435216466391625117296911023053159082"),
+        MontFp!("17201596318516267959242478674525331020962456681235714852429128354797936423512"),
+        MontFp!("16429987001981270427843548313178399452962937303668954688438246688799503164984"),
+        MontFp!("2808058398896238113910841463946229560728543614115874664163904810633100174930"),
+        MontFp!("14219106904819518684382581834179422938681605045169559176622588393807041033595"),
+        MontFp!("10530037691301443797595179975755234137332257307745168342967468344289118325757"),
+        MontFp!("1780683171090951191330938815635375996828930714110381590102230999415067289139"),
+        MontFp!("20631152509228411005601100836698224543066495368069041690921190101674287477942"),
+        MontFp!("808499207021654020396580110067573161997188131641925849629551231827774305643"),
+        MontFp!("16396331554632517617174441423319546296247483740125085437207362257874071846324"),
+        MontFp!("2856734177282633594896606301173852733142847026457749184584827572766646232552"),
+    ],
+    // Second full
+    [
+        MontFp!("1314461175505921794571050454800604608649221187723852105188152934159567388084"),
+        MontFp!("5074821468464653205102869421665358795805465509804176663850666382476895922210"),
+        MontFp!("587081217806702661903500809062925367993834792468594461022841500167739579474"),
+        MontFp!("5264606314717935678144297576598053656417231843504500779254542039497862460981"),
+        MontFp!("1236480134248818593225404950925034920588295884010411301784531216110810413323"),
+        MontFp!("13089489778236095768305861425107967360517148121020346060932975978601131790499"),
+        MontFp!("17291786821946523371971061686693758035121148041300868933078085040228288669056"),
+        MontFp!("18124176342257558638062889896829524322479803409217207498687844328613679520644"),
+        MontFp!("9905839015283285144616603221521153361618378103985458187286841556656656429741"),
+        MontFp!("11809535677454857598311615002421831170743476213662971184893106000202355262469"),
+        MontFp!("4105258996829645758179809917480600473288889890938687299484408634005613929027"),
+        MontFp!("7249994723657380623014328438319457303659016324967581867420756005957067258456"),
+        MontFp!("8317023308476962783876206275099298475926201657427120954368652120829272222159"),
+    ],
+    [
+        MontFp!("2011843005377857984465704450777581623363347938947142463422784260490016214095"),
+        MontFp!("6478487501062645568595261797056495837204909062128557523543545066559983302854"),
+        MontFp!("11332891765024246297067848612322823673574497640669814572890785705324624377152"),
+        MontFp!("11310396674827683007586339726668223579107272179969446205517750064367092175748"),
+        MontFp!("11099104200525710724155767594487158698174345291601467682384866473391802992487"),
+        MontFp!("9363361473795780293736671173763300702046469200956940247194666073284271734887"),
+        MontFp!("15151887186592767507545683753579108698447339021705506097120199743211891524973"),
+        MontFp!("19881265730819081878858384565138117903272592569639633426872725304622695029688"),
+        MontFp!("3011117599368717267651471428189462533336275598139751114483084233022831296797"),
+        MontFp!("17520576180525374756578417994075832591962992432186234756248866458711765589626"),
+        MontFp!("6155276852486738773749976144225301808688416984253808800735523464178476071704"),
+        MontFp!("8631372484444450448190767419844298467817852912545286035361602469665228507964"),
+        MontFp!("12205507917633391792796810613135648610406161967483463808645931187075958213927"),
+    ],
+    [
+        MontFp!("18222453239521787308656394705559544583576244681888667325435264246411012699229"),
+        MontFp!("9033412577366915161372037999993563962295812571996632039862860993305238914757"),
+        MontFp!("19454897503553988644403623432258520403053811767663131188695520064462224816016"),
+        MontFp!("19817199370883464197796219716426529872878349643708961034403389904486407134637"),
+        MontFp!("21290720498988312226623568214384902017696056682729297309424737295532449054922"),
+        MontFp!("5006872489582777310304924776769064242968147807589802114955067857036564413999"),
+        MontFp!("16420397542367588392786684585727906891797023280517827697299749514608736021404"),
+        MontFp!("1300345640934139074405647472243803688216465663235066735522675025627248393466"),
+        MontFp!("15474333493788166084607018352361187159147026284793436327548632778313351493399"),
+        MontFp!("5141280817333171025837036045163967613491344199994520722703718097693724060240"),
+        MontFp!("1674845241197502896336080529694883486542569529162942594482168089081833468174"),
+        MontFp!("20749587232360151357799983517636847191215950040853109011833662933435613635469"),
+        MontFp!("21065338667581575565163875505871743203827212418043845343534420193848874866739"),
+    ],
+    [
+        MontFp!("7051564661861006096495144384640210161011647325580302475305699820188085915761"),
+        MontFp!("1121065721208670659965039607998179107433568481023836429952089959263844401629"),
+        MontFp!("2114003185799450303041425285829091463889299614202766352631616657722513595953"),
+        MontFp!("3334382524507526203206024024777692800878664806770692329489928846721311223692"),
+        MontFp!("5349561335050661854855918228516028812586933810356904077294751460675531266141"),
+        MontFp!("5703041280392778021418588942391165300974701557007394123618062308100870196445"),
+        MontFp!("13673663050707034371520553032876769213055293448602317144467901970892454938354"),
+        MontFp!("5483873888673148302254721546416819014852587382013956934541598037319090821435"),
+        MontFp!("19318734450985860065269203041456593881321456849029001758521815811241052123018"),
+        MontFp!("12851330354839827372437628165464795441114428848058858241484775351172304561256"),
+        MontFp!("10041542640844175943995712334828870579034783871076347036534323366252583734600"),
+        MontFp!("3589445341963638025665117050103495399260947312205835568650192524332423143081"),
+        MontFp!("6240962307810325966231999724200767949498419326824465195783823947519631917688"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("10322006004350928347565262203595059371944943723449117022023179276501731876345"),
+        MontFp!("2683049508597401695548071522846046195699870016352617057472448981851954812059"),
+        MontFp!("16247545910658018105089170383534659574306111291405960552997108542031333797235"),
+        MontFp!("18622054704352443244328095600847746501599243235049513145116519278941866455272"),
+        MontFp!("8702183185743408572844482710294629749653378107594727106707156268893318308842"),
+        MontFp!("14401120539669001367195063486710670290450638416936122540739824928865783084126"),
+        MontFp!("1008932491940705147356558882115440120351684984366727805937095853000783157110"),
+        MontFp!("3335415470820584035672268458205991064638866247863327497697585974180788121327"),
+        MontFp!("675348271572506248948949205284720732296364868854507020989147277054109697307"),
+        MontFp!("11425495584918585085965114263893597096866227224211705734884180990929134229634"),
+        MontFp!("3139501819004172358132939651256683854267531704642601593201346365873114559764"),
+        MontFp!("5783084359807455756426365693604321435940832494660634548089377362001475784943"),
+        MontFp!("12095094864547001070709219304829411865589880746021553931719922191437710414601"),
+    ],
+    [
+        MontFp!("16318907049539057156624360879403805284297767691522365017367114160528018468654"),
+        MontFp!("11395679267999229912686944592292906403252965050428182621076890662342583575672"),
+        MontFp!("20304144520028193526915712162937805381528012861129116050582705669151951550558"),
+        MontFp!("9782367134866186358061353221055410602027546275400836771195060684844149613708"),
+        MontFp!("5028029607389470804476265609647401798747012470860555590083631854016382301797"),
+        MontFp!("9007627713847325367341162188681367076476205669132463414398773746849014673910"),
+        MontFp!("14471089911822534831111187815987523323244485794708545360218598163622346282781"),
+        MontFp!("8019180100611273479652907195647951337613619920735853449223048779220663542855"),
+        MontFp!("9047131412613791119405208650892730556330287685760678509615107996866748231916"),
+        MontFp!("7335839265467743759482091707230237155828204454352010285192894893585057443442"),
+        MontFp!("14787708665825803366954624175778735065630831061644057898061768895034788739773"),
+        MontFp!("13527353131634051756987040962797036674382517083826397597671775590484363794989"),
+        MontFp!("14976182354818370036095296869648510633049821903988384882639150664375161429327"),
+    ],
+    [
+        MontFp!("1560099560219605560187324067315095140041097722845226997637592687804174779694"),
+        MontFp!("571671177230396783003670900588907847431348620829409362554250135027808522430"),
+        MontFp!("3595401819266532411007886403698316446251107730632935527816690692561072394769"),
+        MontFp!("5582619801223860759886119158956613012355932421337001876528290847176002785551"),
+        MontFp!("12913958465964733555039885816821695149723050623118481194340346951472209298707"),
+        MontFp!("10229385924925018504492473674441213223237935733123654864337438778499472034702"),
+        MontFp!("21343876046925805512584981384457719270702482935633311830189677174062117124219"),
+        MontFp!("6420204451041321645976980917151646516083600244123365393849746698818033299571"),
+        MontFp!("19251614427061141498566572096097506725955838624383486128732046911906166384020"),
+        MontFp!("3637855341628865891325364423447146770285076487178203925892266081818756628695"),
+        MontFp!("6291095057555542758591776864498618016939082736178179913313186141723218066258"),
+        MontFp!("15680673126007380386463552533962308522676179674235002556809204723523731273305"),
+        MontFp!("14161910492383069106925371061927425194676913565753916851706224000847740857190"),
+    ],
+    [
+        MontFp!("8408124204639665996401588312942126836254203358943885876199057731364731921137"),
+        MontFp!("1799578340300310412035304211591366593871976523584701800579066126447600019902"),
+        MontFp!("3622312705549913151631696033404711371424834689912295490477655370864706883384"),
+        MontFp!("13273792131373119117124844835198348538764494606049066494384531598904652565002"),
+        MontFp!("14270260908863510838028225519201826231421616414346395482851862560529215543207"),
+        MontFp!("1633715375341396582749269086363023314520014551113162582725277839347569228786"),
+        MontFp!("3306641014838747329624593360740422499925312234577578401365800985267347686108"),
+        MontFp!("14198045292969051397674853108255956449504264447127690313478218267366467132592"),
+        MontFp!("13392638089393029927126006202520304645547980480174653070691229667854016527907"),
+        MontFp!("3343885948493512216041748082192584170148231918609106413514016808164860398278"),
+        MontFp!("3168199878854830552224332084447744849594328418779455928665560385861883941432"),
+        MontFp!("4414580242526682670231711868212166617171432599055299074136917305689841663873"),
+        MontFp!("14102753845056293060191802617344737652392043747319090694003879675100249630273"),
+    ],
+    [
+        MontFp!("5573924339361534820906966230551252047468623512376332682801232194633308271653"),
+        MontFp!("21326161716444219551593862645873877150197335577674154159730974795774063715301"),
+        MontFp!("19432958264517639868400987651848393946587739437911153002798497064389336859842"),
+        MontFp!("11213890749343283806487445619338898013466071000086191642490563684623068539710"),
+        MontFp!("9198922780305686612592947154769134303077110139729181291466776593119643796878"),
+        MontFp!("21136183246673473125620223552691909911094171966517655547561646692807715461191"),
+        MontFp!("18418245895226184084223713341727403858051315526021187444511237652982687814864"),
+        MontFp!("10761950212602122725874752124635327020166823895511275020048089638533400314021"),
+        MontFp!("16715137911831586771799460729625653907150059087921720918100590730689273071536"),
+        MontFp!("17773351327415330719066508685011039352345666357995827941409702750841801825772"),
+        MontFp!("20231347041576378765333233596636479502524461869780149044300194060543432934519"),
+        MontFp!("18064563634590190884918686050639690971635922850408462075670660991277444012424"),
+        MontFp!("19311806793959939929201104461555154688516767798907269687984909834335608414545"),
+    ],
+    [
+        MontFp!("18574604846458382436863152415585348247597412723601050266815439019897542533749"),
+        MontFp!("6791419133329459189167506884324613003818238896866734783035998091675242101498"),
+        MontFp!("16020026112470750192482231650248429937269643957555877460849721131766422051297"),
+        MontFp!("20044512180053459577124670295358045421070313597307221323295226934357969810703"),
+        MontFp!("5766192039785553259974801912149788259566859976619902528741727877731329189756"),
+        MontFp!("13160650100556144411626475539530712035286115503483655434606927361569861225444"),
+        MontFp!("9256036311849117781394603817644061414251138927392105895934210116699839233747"),
+        MontFp!("16660304826343536533933296919943639532544710122612011090406376521894383168856"),
+        MontFp!("5613106871918486982198177086366936520094704188389198685078718794779139126942"),
+        MontFp!("13070598684216243718425316519583985756848433332087820965201044383296972346897"),
+        MontFp!("20442537178749694119236982549472062379434941056035907413284316764767638577672"),
+        MontFp!("7934380525369255820244941077363404413659578389361986061643146925957595190009"),
+        MontFp!("5098727584136148023264970031064585917675686576590855834683443649082028079965"),
+    ],
+    [
+        MontFp!("20311250985440103383589841574737981968195820548330595923330987127317084128947"),
+        MontFp!("3768224286214159313341476585351274256214081884717440852909749729584659454712"),
+        MontFp!("9851045187544813632080431293737874643968516290829960553238754661188963821885"),
+        MontFp!("19015242154372799267961042586541854827581444440949327264389997835941454171864"),
+        MontFp!("6579883332160451787803375748174162900180016921849643588581107525271529605849"),
+        MontFp!("10040045688615204420748720492172111994988850765670088109590569954563466338788"),
+        MontFp!("16200830095429004437461800470410149972585288873073724430176128378316485348605"),
+        MontFp!("9682955393234868764418991570718636989945366768432593131049879027562290804128"),
+        MontFp!("12304247432606946020923001398973669839540045070461170795482951827192644001957"),
+        MontFp!("18191542513141047176662091981681722742011120542435182371090664849144982674881"),
+        MontFp!("18914155310347961884616925389217412880576183372588965807237661684331120845382"),
+        MontFp!("13251661686687775186900604006618374427674252707179991219741046747323389941232"),
+        MontFp!("10249194086135063113459170280632696947988284060709952681203426883826751049011"),
+    ],
+    [
+        MontFp!("18372233147890747575375422462203968154682646712463764047424428288714397527619"),
+        MontFp!("4638790386282413677458315728120268440302827185471341027696814407017634332517"),
+        MontFp!("16623961311962310335126734990913554770362581939583424145026358913546809927380"),
+        MontFp!("17860493200690068167535851968852986582339867469465298666834410487621188113531"),
+        MontFp!("4608305735853858774877121807982873005510358307902418229099062852681969399389"),
+        MontFp!("14245817054597951763417586258404021745844024655622942045401677661648532977993"),
+        MontFp!("3809843434538253198074703856325693344260532607506586917570357443895551048805"),
+        MontFp!("17962207143381407459791220803798424686492359980872541932770670422523978330935"),
+        MontFp!("328196272784453082510222579442282995329426913454628381427614217629272010096"),
+        MontFp!("12374354866281475555079168690683958365929973901084398491477273797832999248789"),
+        MontFp!("20853802547446175043916531515983184048009878116903616041233975407782898112824"),
+        MontFp!("21496110961478435785328603969876614135441859248001892605282920516413194792470"),
+        MontFp!("174344306580108774551764089189043165870298462272157912012996952342994211137"),
+    ],
+    [
+        MontFp!("20687536797191141044727224925607387673330130224876076220260820047611716395831"),
+        MontFp!("13067331728561732140381851790951434625007152955086246816336518249186949048391"),
+        MontFp!("3797862935019829095677036741096081889651388634128316641681020782687008662828"),
+        MontFp!("19718547173056417343715166978970272483152981364164960220358919266690668222316"),
+        MontFp!("4038867288048886379019053981446145278427073511478003014108759946240227146792"),
+        MontFp!("18486919751583655613568544119169758176904901075587096135301780773087898879007"),
+        MontFp!("9808503221335286135961820380426080374193561918865479543141400875074697503967"),
+        MontFp!("11673653729232546119337900700609058880494248359777926756321171038104786463073"),
+        MontFp!("2206576012107962093523647385331312771747616647783007209109500521090968991064"),
+        MontFp!("9800772269959998996051474306860888030671933506518907174943285018516216530078"),
+        MontFp!("704115937219213713489981656324169840669771632134921090864597729550810437103"),
+        MontFp!("10450936455424589171986477572900171091143445830786569605988162504754224051029"),
+        MontFp!("21102186642636060890935221995324710172830713161820423345683707273376266063905"),
+    ],
+    [
+        MontFp!("9163879187976750746407679763920281609583516506018439539723656773309855786163"),
+        MontFp!("3472157821801145643950882966615731480318387993084591537527672102896556538908"),
+        MontFp!("2411890225861629547000508300507629335095483162292985158748687911181232049540"),
+        MontFp!("4985409929001444812993021607654361988376208034496482520498705351994820408485"),
+        MontFp!("11553789937761176964063895958945223002657928340200203811287735666719305693830"),
+        MontFp!("16847788791386791842258653027481339013349340413294800029138566625971691859716"),
+        MontFp!("6760556724223230789549789607828387138865070021837886343220335025271850431529"),
+        MontFp!("20596400847159894247975659733452116044170283916549483164302854789226390584120"),
+        MontFp!("11672317719239435686809215238371249803784157167042393847476181444969645566102"),
+        MontFp!("2540677976918908199177572390470632080272842549139729131369041579508211107531"),
+        MontFp!("19442658692908544356400723297250992586408893464353224580638700020103731410947"),
+        MontFp!("3126219492402213962620193562481318950501969435505738692018385930122987610651"),
+        MontFp!("13815812651475874136168661258439176421344570120756240838952907916838907109528"),
+    ],
+    [
+        MontFp!("21443943680756083274641262528830913057368026549878765925197792130996882062057"),
+        MontFp!("4605307168758134905354332635309004486671872776946937049460665298426311628483"),
+        MontFp!("7482893834680785986692353891953868933021236845332400077427554963067657007377"),
+        MontFp!("5511796194740668281350270549653399801893104530256753635073935873703864854017"),
+        MontFp!("1743641818114051150153510266165400817966589635697948934570080374390326493276"),
+        MontFp!("479348932723256576178997677888058436058269610751399398192779646687149113652"),
+        MontFp!("2911895727864385123299618342188487304466499987531177084686132082340794352256"),
+        MontFp!("20721945554748282229818069151690746976633856763939126384250406858494706697945"),
+        MontFp!("12250582445106986808691543043877193096050336861542243284061992098674816799798"),
+        MontFp!("1203513568724767846410309911297210537295690041996158208540636591896498697618"),
+        MontFp!("6015583439542775167098811213988699848659623500530189193065070507681443523926"),
+        MontFp!("13090526755326389175310761634465981673176179379933796187486632857997253599596"),
+        MontFp!("5204691423542830100481358117753845481374238423707185894207602712512441617128"),
+    ],
+    [
+        MontFp!("13451357406880550276974168929055560555273701366887507611491312211347374906677"),
+        MontFp!("10327523031922710495308957226255708193968398139079389167803483310323608563088"),
+        MontFp!("20154372727310541088852354530646786495003018189623626400646862775892232254942"),
+        MontFp!("14022574434398251358742711545518515409780772767696241502841372748111287365393"),
+        MontFp!("5800935164580219886597276061890276866350374777469717717325353972942679889986"),
+        MontFp!("4950159996548574497193804912590478284182513481419060390699450490223901282265"),
+        MontFp!("16651189959199821925513089100199135327590269582473005780223712025001916349248"),
+        MontFp!("21313574815160763657406207917526347065428582824748947465014119238740065134827"),
+        MontFp!("16527537328830884258876707902602749401809299353104838028680907493128406403510"),
+        MontFp!("15328203752181180061659863584748718494669846163715366299567183721088123843121"),
+        MontFp!("12748724793783078481516963303490468110393403014501561235873124284244901317221"),
+        MontFp!("18960625031156828010763136389916953653374660277691326636541170359289051172470"),
+        MontFp!("3539001622988573864365704884620979405364663891195701665878843924015974631139"),
+    ],
+    [
+        MontFp!("19440492503123228668247641573804742990539424862427319298139890001019307139951"),
+        MontFp!("20663734806319981969735713814960326046259899097186434280597673603867647826563"),
+        MontFp!("5638550801924521462873676353658824547611149604620661155399127974706744652941"),
+        MontFp!("1371302213304057480201653073728712066696195910046203705054614806040591336203"),
+        MontFp!("14916746318031473743643312292935459967335724866678950897137588157969538011797"),
+        MontFp!("21398978398848411648231431125400371920346497858046605440294787662089399525957"),
+        MontFp!("17030308547789363149594169656019066171879481623126106541463663868095060483032"),
+        MontFp!("2440921987343876776494963936103708492610319137255329185550543355589457647052"),
+        MontFp!("16578274606037635679209783094809437790602872464993592428997022911117833097149"),
+        MontFp!("584917957795662537423624049408930838570931949382380283787668885015699442922"),
+        MontFp!("16787159358764578324620871006477408253726184555269443751955596568136636185295"),
+        MontFp!("16078611438600534298861481344414944861682463507987217038328914463538066716267"),
+        MontFp!("8692607842423869778858860521189757647588004328790444839011233818764128397786"),
+    ],
+    [
+        MontFp!("17461551965098847063794186994739508584146822190403534677972847044656722703432"),
+        MontFp!("19452713388671907333636730760656181158378071792518077405629847148635099895953"),
+        MontFp!("12121230247359921951208053896624893871654915559955364031951409301929413070034"),
+        MontFp!("4704830575919764561517254527427722789804729564629801789022427396957683932024"),
+        MontFp!("10670972751746137966064978863033768730585193577203300636726301560193269271720"),
+        MontFp!("8049285218762936069773906017628569610476710004139648882735854791085139873871"),
+        MontFp!("5327956364112241273910968805653529609896670219677653687154759819173833984314"),
+        MontFp!("16209531049562245939203780811684066382629831507349840609244051677227948603148"),
+        MontFp!("2041493598600414785071850826176044493717208172684229753087779307523403234108"),
+        MontFp!("14983712813504746225033809615975227528832540720868897893292242774973221269350"),
+        MontFp!("12085999002734552992271490964670175347983721486216238101789957598273374406404"),
+        MontFp!("2010148192415347724117376912318888220341164166588569584654147279107677185092"),
+        MontFp!("14204865408877079652583508111640133693635228399739312350971609944706493769468"),
+    ],
+    [
+        MontFp!("13330601146337713429471908542961289712020817519764634531830442337284933922809"),
+        MontFp!("9363311761287060295640718461317428792455498134298183595944737677775311452049"),
+        MontFp!("326899109513011756995862753231193478723114246336082392308214181273112126881"),
+        MontFp!("21599711748797254989207108046977874436425859822146104148381410251216892766416"),
+        MontFp!("4801349866375335173289265243677780019445351052028447809305464229839535867723"),
+        MontFp!("14155733033291624452674783678844450106798515225633669031829757876654937709468"),
+        MontFp!("6249525168453913775215814309255487077884826362528424439922890888603955052499"),
+        MontFp!("14928369633463338040897134982481546746234350670771618196847518086862393490357"),
+        MontFp!("9125617238450534926549876296505645845116172794938106232962454586773357787390"),
+        MontFp!("17103886312678741706528259762965004480595318892735191450742885850881411200483"),
+        MontFp!("9561133527164819548073730465187187729026229121272078865756229410363525806403"),
+        MontFp!("15499339092812475393717971205752228601033972883632736148845868837711619810573"),
+        MontFp!("4039978125105304479934956398755842410107811106124175538862554473225969256475"),
+    ],
+    [
+        MontFp!("2947887467809254352800319275622693352512539235808226814612313636217910816213"),
+        MontFp!("10793550355337981917672616521499626144068251539193270238994640132315408061844"),
+        MontFp!("15282024117559604646251556024222379207458577842775127240432856222129525583388"),
+        MontFp!("4938487600404790095807201685235688647996095712925228362099985579653367819830"),
+        MontFp!("1580271313232748704254579894100134003454608977989565849293374431242287188203"),
+        MontFp!("17650488790850180925611249978112046323146336603695505846749648092705166179072"),
+        MontFp!("6318477650409317961441518371381185077182540555690253948041195660104038462403"),
+        MontFp!("18703841587534276582593528858657361438697750302064291203982535124189673569022"),
+        MontFp!("6570410039872146139634976314211122538651252189045191357367613723647837080131"),
+        MontFp!("4058984348953256905202616995215664252962770887032362374636631606975462596559"),
+        MontFp!("18730155671005907941866324013560951043352315199408210078231145351355402093830"),
+        MontFp!("9651860366009425828519962206873795166909640005109793700260660519169015873934"),
+        MontFp!("5838396248515026677336009419348618624164637668061855829375740686597759055073"),
+    ],
+    [
+        MontFp!("15333954343347884216807246400300286008899152705372200767215516907035196412750"),
+        MontFp!("1930765848863686695612705389177613542562429368163669004940380022708446841540"),
+        MontFp!("10766617427881894379670242247746203663519351900247145805786260703840625869300"),
+        MontFp!("5568751333625760749025074267385171935854368145626703295693077672484816860148"),
+        MontFp!("1572988267140272358245681202814159120843671145631424724491020423275325461878"),
+        MontFp!("19938390788588115516825752214441323822601991108943527563767657270216212171610"),
+        MontFp!("7571912702211887911260059806577378242621772456912160075153794388926572849145"),
+        MontFp!("6858979093205494695976875629144179789647553123886814202229119872013338208712"),
+        MontFp!("18762692183810081055439753242004382629435200839335768813270546391733006575124"),
+        MontFp!("15741259924712306779754554541398522471185852133913466875986860727321229948852"),
+        MontFp!("2627808396704584386154002253183040546217470699174068260518444240974395718442"),
+        MontFp!("13588659892026426409480708338634242111974891480475426278869635762077927444692"),
+        MontFp!("19520457627758833811699878500944809253730386938893290068023237193482219846683"),
+    ],
+    [
+        MontFp!("8924178241223608091232956607418048641173985923478293687667819226283386788940"),
+        MontFp!("5816806026738575389886097663948769844875803508574710698349454181988557080269"),
+        MontFp!("12475579687915690911104520027833630083645170013159764349224529153441972577879"),
+        MontFp!("21056333315327274333873056478119184274531121536034759580035646721078602460263"),
+        MontFp!("21444457911856423029054975409869505918084415765420838103204937942386934191806"),
+        MontFp!("9510785855264774762445687543927870120017463057252636764104600528992946593471"),
+        MontFp!("20924833468200564542464376523632289762564405387275694375761127017107713239560"),
+        MontFp!("10520571364539530696429244627292574117914912315372596265076914837029323332385"),
+        MontFp!("15676633242665585964368692080238475248556973102970746891067091503318954352189"),
+        MontFp!("7605218463440663468653884707661331637891252539306862971462289235362996643878"),
+        MontFp!("8655416175027898012639173297596079731611447754405890380300192905812736625807"),
+        MontFp!("6587158966606269464624627411770696301475420343587060761712532930272301692115"),
+        MontFp!("17855802086555955087208003626983743721607218545075932831175321330674668519051"),
+    ],
+    [
+        MontFp!("19637270878895908891611772385398729322367589125936403809351999767233932966110"),
+        MontFp!("13844120079506591315671522711954211929776840478156307979285069853392230439385"),
+        MontFp!("3896487627666829488959793133071741228529962458688538373863209568407375861949"),
+        MontFp!("8072922412250331052234441827200394762964253395032633720559799911487619573310"),
+        MontFp!("3446650687448682504274462997312106041057536787701884723175991150707212663201"),
+        MontFp!("6750980474265223706441237350209360446038939446132826129894534048737231433466"),
+        MontFp!("7932667749655660682554204871450377554998925079055538597319130615505233358592"),
+        MontFp!("4551937123821223485620220711817547511116227596903370212234130807711068597972"),
+        MontFp!("8450248183513826743946014093205824327880033986070570398677443659142729011179"),
+        MontFp!("20523087403588105156975104084680617581362696346833272310988747947340344564064"),
+        MontFp!("8021873326844086700417206574915335220587232800373998588094532336448721297757"),
+        MontFp!("21537680614486477925570839538957839593006098192503694567393749587557940631967"),
+        MontFp!("16568393458463797316294547173419555682646841229029462217197639056287054602566"),
+    ],
+    [
+        MontFp!("16910235738263410812877183305130636228751042299094737782744154576712268208807"),
+        MontFp!("5629150734321853465674510845327410940323442617216962413835214171701329559576"),
+        MontFp!("12765131184498380847448550948920304867469527623961001350816744753387093810315"),
+        MontFp!("9954645632746218155245325585480173993199044803581756137160936155060542951690"),
+        MontFp!("4353999640943599221118281386228426498583991413938542575297776232303344359940"),
+        MontFp!("4598711079993417664489400987736110506328223791894077517963275447468098340703"),
+        MontFp!("17317745377506102303500052440899062192892945727209124468437255966617200532771"),
+        MontFp!("12259326473392165107331141130100680225887121989921120777667514143912156322366"),
+        MontFp!("11811238683014115897858309619999599808008425203222607881921631505596453270838"),
+        MontFp!("16699081706307776306233318220897168744889069844294482997417168581249408879966"),
+        MontFp!("2568026780472621467236485312283731374848886964214142251868040165224695316461"),
+        MontFp!("14887991441574434999687627633546581501365901473545173785249018184685706196387"),
+        MontFp!("3569299155477721143892535842178855295807355615071744617918410766174933059043"),
+    ],
+    [
+        MontFp!("5498879700681283951662042539400107058426136148099967700430316029080607629654"),
+        MontFp!("11559660278391175834668360323261696294263059777402960440249595574565141656556"),
+        MontFp!("2245933817469402341452987887837905070237745132811239554297372991710914298182"),
+        MontFp!("16951354954717164970902473060335333600574402706601527686301478774700332367102"),
+        MontFp!("21515931335312240603168050753263038826093559960430063520103206567658032925825"),
+        MontFp!("1249411708644964091210440756030782120444589186855809089574669034358018078687"),
+        MontFp!("5918980335509160299664261285796224662727132279493574691223062837411060350013"),
+        MontFp!("17739377184579332650035245294019601185834503109963479023132769460626331642282"),
+        MontFp!("18469744224495747473457470133545294362819142862108342977835066995529981406258"),
+        MontFp!("5759336781416534390511113561800934668877971293662332789437681884454072500101"),
+        MontFp!("21332416097583521698710421619829192185814871724795352151990455290564854827164"),
+        MontFp!("14991738373834450996520664429440490206770501447625984036209559286781489862494"),
+        MontFp!("2292383959319987392245961291693765909376521855421393945872334290076611679644"),
+    ],
+    [
+        MontFp!("20271880109317422113691100242542434637543023179088091376781918851828907851136"),
+        MontFp!("16343203714510839250939280610780086672320399980775100100207359519193914733737"),
+        MontFp!("19668001496569040779289443981105684833630116796777138243208746501411413314400"),
+        MontFp!("14867270521039301446053304216390047301289545979306959364422905455339336587211"),
+        MontFp!("9929001722154986846461251622116577110986506194975595510541659282347340459331"),
+        MontFp!("2420696236635890839197290329114608036709859071844599545644710485458818798726"),
+        MontFp!("7023164250260724184662962208964026963022579890290101367655364067452762770181"),
+        MontFp!("20508512038053113727358039673385440054337319672353371627416928672143778575477"),
+        MontFp!("824002554707189532054522614675201805472052085843409216867120989167259938133"),
+        MontFp!("8081396930643908204119214488991799751072819763127464506132047127093449268743"),
+        MontFp!("6146669896192112367311424975035875525640347544666981144388229137148737996133"),
+        MontFp!("13917552221424458749711502489369248582964839439204214559672563587245770000377"),
+        MontFp!("8550914481815010035465313609214308578203171728075688232687655725801893823141"),
+    ],
+    [
+        MontFp!("17861710896808118014581828397113186358973121215643160735097781787683890541230"),
+        MontFp!("15595160929818874035046864313228963482705740989418337036962890306288699599497"),
+        MontFp!("19810339910854592376336564454416808551332919752503671392907063850461349060479"),
+        MontFp!("14234414635213293332305234819580453743707554476430770368421971402668862484872"),
+        MontFp!("19388187253394163585379147543203559718098061800153070411989058669962446974654"),
+        MontFp!("9996031022330018431773704931033232677865134703871368350999928908636869707277"),
+        MontFp!("4533799599412452199761684722747577946145532223581271248962115254510905734009"),
+        MontFp!("21793143524581931493773122340799146504825838409926910431529124937257399233338"),
+        MontFp!("19692292712401787423885461139555179726227035221831860547833110141089023723875"),
+        MontFp!("15361825934066824966062130821388513267446230286103312548685290096230989843672"),
+        MontFp!("5736434899097417592114701714539404395501559559582922276628268708302331297718"),
+        MontFp!("6034516841645751575815362526363553327142008775019507199148571280847313939846"),
+        MontFp!("3103941851061425456578524836013350351780973108926423482048781831843642166611"),
+    ],
+    [
+        MontFp!("5899337062497322191697133509006899741055733585263214548357752956989422491317"),
+        MontFp!("963781557317112122222004641921550021210279319264222273713987285679221730138"),
+        MontFp!("12218612067667826360750451807845242059538814081271178077944945859470451709133"),
+        MontFp!("19859087159769083790343803575983864397266266537354870798907672197319357964101"),
+        MontFp!("19984410602577911276019354715470427122599274838994470055478829965861962241047"),
+        MontFp!("1510088699001180548764185585999030426586790568209473093795384481408826277110"),
+        MontFp!("6626530934788897049551186952232009297818099583639020036000874279734503731588"),
+        MontFp!("11102385732315753875246033828526944990963999730005798544082045825035822583016"),
+        MontFp!("6109966799003077547022556221879889867437672084424732190493586606121191686895"),
+        MontFp!("11639465666095459063122039730377449150155515188121884615987948657973378713177"),
+        MontFp!("5087119226269603720517452201720024842953062937909041658414079638720935374157"),
+        MontFp!("14200979596305023031605438052846373683912540635435718213758112152603438068044"),
+        MontFp!("10504614528473632996392119937661213820553616805799536317962705212982659337252"),
+    ],
+    [
+        MontFp!("16896136248461578519387122861681641687612480673542507251813953270071486348436"),
+        MontFp!("19381803511891750827107737252264239897475475490344583112691529084617905175082"),
+        MontFp!("17541407330179013144706644068775388402691308134663811736351559302382670708984"),
+        MontFp!("15072973642203756087665519422382641458207399650896006837784917038035882610771"),
+        MontFp!("2308847250281515017414251485377760361489324048295236605725148595795546323946"),
+        MontFp!("12235376578863551481515043248404435233298457594750687771966106520323367004722"),
+        MontFp!("234919702386651406701334351738513931283772159856053034152613436560637774413"),
+        MontFp!("21637694258900941342752268794989465303386552780774074413553118735507317693719"),
+        MontFp!("5017784917192017182377763628730148212610872485218540677293490321169201123948"),
+        MontFp!("770314504054605850178900177028157853527192178178763005605945816624700149604"),
+        MontFp!("15876814891128030074112970202145286137873841691080700504603122203177789175573"),
+        MontFp!("21396093758862368540558980838454280107203592525118989209906020509719986520353"),
+        MontFp!("12395505038782995420136281017345429633379095201006284425785781752730355509942"),
+    ],
+    [
+        MontFp!("4615582568807690433357570528319145785065741006112040062899881545600179737110"),
+        MontFp!("8043772689074175830138340191606864001269151441770005593654367646112660385954"),
+        MontFp!("5087060495541107066427394344208368671085864351635929517190622637786924485857"),
+        MontFp!("16943670806889779731197043466545390303516518769717829999301840370920339407796"),
+        MontFp!("13534112488771797914884729261168139500575711545907958816183168317604824618336"),
+        MontFp!("16825197446319806977233945546803116869802796446301133858223973227155555550786"),
+        MontFp!("415637162126135168159032709770391274308945193836031409779140830879628784155"),
+        MontFp!("18387406758235678040900363524694503366739497074863830200933011303909731885356"),
+        MontFp!("11179137042211084753827842203552443809484306307318317082624519139125401844623"),
+        MontFp!("21558283039234113122805760097265638181763687038555764258857715920818106215523"),
+        MontFp!("984886258318300688046776785673289560458722425831829175079944141959971496951"),
+        MontFp!("15742677322520864401961943379313620949320295584851281667782848443614715444083"),
+        MontFp!("4674404119733957791400958869998857022224647524150794463860851329191601384751"),
+    ],
+    [
+        MontFp!("8650727403827795422786989886519743572356271096745868930429292580249174286422"),
+        MontFp!("13805664248894834838953946033892670782284990791974158822573815009987148935892"),
+        MontFp!("16425836039758852245186956621689031634149287919733928973102442742483370034636"),
+        MontFp!("12636984265967190523588079564146349074168180793368546716527682276529007569774"),
+        MontFp!("6817261026014072560057398041777830163991050944713581458081292224492038152943"),
+        MontFp!("3842591846766588833800550804121369117907947886204778461433916219752157723658"),
+        MontFp!("37271592202861207450496704231110333036724082963271323982767766566562691532"),
+        MontFp!("12329580473492550135339911865680237906360631219143722403158320079104416996484"),
+        MontFp!("15059957190605121115530324002634619045808465757564252411076061916764353063726"),
+        MontFp!("11049964099753534691591813062197785153610766318641253048434615460367998657091"),
+        MontFp!("9547664187984679827610628603629867733891345475510185082362431432235944303887"),
+        MontFp!("18137012162384412674879864487567137486356542869085283721914855801046173331814"),
+        MontFp!("3685098687651674532509991642879131752314328195366675630043049707942891170584"),
+    ],
+    [
+        MontFp!("4350994188192128309646975341689829464702598370274816402210557114155641708487"),
+        MontFp!("6257821888636845714306847817710237763041012260573089876542080703522920377274"),
+        MontFp!("13253018959425522927774577647502632317700972755867567550168937147898302735178"),
+        MontFp!("16128520328840705715994832968486859989808820923410546017533359121760378044520"),
+        MontFp!("20170678152633918405966535017048153387865751946705242558406886703664743352398"),
+        MontFp!("3126527212972403378701842254033672681067914451311377332339121677604578433846"),
+        MontFp!("6867262232134903047018643171960682024396916574079628095990181177262447593546"),
+        MontFp!("5158367668931323144529189475506903329651812153505452886267986731006143844385"),
+        MontFp!("12754662355373887343223103245500264953264637968818954284520990187567577818296"),
+        MontFp!("8184207275666143481387081482876989547621596168672026673494737134509515879012"),
+        MontFp!("18182047594483210481213043235431614943690478827150601132558952379296218497290"),
+        MontFp!("4111619186005075315994626077421177613466749589414703176717884282581714875444"),
+        MontFp!("12594743600504910896656839099899310633582995354714016930508253088263104106919"),
+    ],
+    [
+        MontFp!("5906863249972407611468323572776947701049556100204019241509444413033282677489"),
+        MontFp!("1980080210708070252092652836439362225685565242001269004929621280778029319218"),
+        MontFp!("5317272650834519781155258400716302959016815255066834148961703371077671280690"),
+        MontFp!("6755929511172704567967900822149236127789264783942356076752317952971695408598"),
+        MontFp!("756653592358533438118443361825151465623048518178327516109550024774438883797"),
+        MontFp!("13279387228067311574007940426672455425799739197632963751860641037826906383836"),
+        MontFp!("3438061374000905227745499451129047661964429059615559246017344409320261233525"),
+        MontFp!("7182178722730387940200728260680152279805701386434851756285719594136478079342"),
+        MontFp!("3232238101879637288089008409532896629862152604458430213480076264229233742462"),
+        MontFp!("18349073713456068979069801924492847615265826078935260207982474961526062084683"),
+        MontFp!("14669461622305417414928378093274931180941168738663334898025953986981156308555"),
+        MontFp!("21025953377099738424497295026970813014051167806216418684116056037559447904210"),
+        MontFp!("5255626714680656571286256248936450375168592101301691959500945926924371301668"),
+    ],
+    [
+        MontFp!("17282802977887473307860913184523745285865757781571388182716814513918008763083"),
+        MontFp!("7631811346302144382630250446520799917692672405169780636309223495193617534466"),
+        MontFp!("20646156492067995141068212601070346054539051558428492870963989969028947901818"),
+        MontFp!("11672162857509134152068238052735772663152403666766041363264045356497052787977"),
+        MontFp!("11887096806532260110007600390059506485935624620297229185953255478078857108029"),
+        MontFp!("21264877166054737412301473554803422700628127592263555002579170780541880711583"),
+        MontFp!("17943121804479032447858025131006451946994591698842739992603829945304217132668"),
+        MontFp!("21741868983463662771047103688885133076855201341036000119862339617136906339944"),
+        MontFp!("1382607011220387511006322153920014038613273342046755883486188937618436917353"),
+        MontFp!("15178143370568504520122235741397126257210602740650018201900729283546561696689"),
+        MontFp!("19508797361068986472532319429763120919434246449052564653746851758275251767862"),
+        MontFp!("15432472447501574952637182729640656917161000067956855273526749406835661136043"),
+        MontFp!("3646314932117173966129769917469131878046956851972292537742853525040261895364"),
+    ],
+    [
+        MontFp!("9954239280150263233476434487622548184763162539776975088510280448547072413987"),
+        MontFp!("2860206851873890237457963710655623849107446413141590367626845970849894602577"),
+        MontFp!("7624884329556925103103971646513913063945080531848229875878001163429377892391"),
+        MontFp!("13360943796731806773064110741074091445121635972973177327949717322801106153527"),
+        MontFp!("11667836424651042501953164038576384835169183507720986280095771344954386965055"),
+        MontFp!("11905856768069261907772799745883705412557691028383268959241049562161180720782"),
+        MontFp!("12186322648958524087383458213507970038354441682650936942717890758775537576715"),
+        MontFp!("6206377886698888214976033056004148608870783801657981445534961772175420895794"),
+        MontFp!("9985786843211942401460306610504559890644549482837388550821870421722527522129"),
+        MontFp!("7131871091468632512858311810874313071133138864883788963707243539301333044473"),
+        MontFp!("6863753977467122978046292703800221264317404999280683794261458513920582107611"),
+        MontFp!("21254007028585610870255249088819596192746568962023826736493046170403367390748"),
+        MontFp!("19316740566828302369845437096994220205932229807972164794086423967490376053120"),
+    ],
+    [
+        MontFp!("20911390901671963410235703071566058542087894960862283015326592608742412435513"),
+        MontFp!("10947283094344459246570251013404575174415295611564471102942451430110207789270"),
+        MontFp!("6629514866202977686466275098623310374411361176607669574816969946802287210730"),
+        MontFp!("4306637640746831336889157874035984930432037257905593217438853767758187627966"),
+        MontFp!("17842812026941818985973284751293083233650807309923063938909281923223690155081"),
+        MontFp!("20979787799837328650256681706033358558090809594843147876018416522952145532570"),
+        MontFp!("1084395234163143750754914936597532036526030294466367212427999310133490427432"),
+        MontFp!("16411000398229165199635990982959651490150157098065257264356189234661735382064"),
+        MontFp!("19529434083761568271396183578711196610141016284281861529889071952129275189455"),
+        MontFp!("16011589790708451889651677332030836112945970875991060135355201890489173794391"),
+        MontFp!("18538965510307116327958097310749618403759736800399477577603041239573656036369"),
+        MontFp!("575597941676167484590243546673541695594364334533047994047106268896055691965"),
+        MontFp!("10154888807896331879146339491538875786991737788606023861857914666322797287434"),
+    ],
+    [
+        MontFp!("5751458975032524671409758081174846089867075271579936974413090425599016414105"),
+        MontFp!("20537352507069134091732836326103935751238399876923560688100837864640768370820"),
+        MontFp!("388393267636951339744357828936678047569934289925965154807382118853267759467"),
+        MontFp!("7988929055663996471299857775851374803891818939607048669296465149104280329338"),
+        MontFp!("15854279006908524023292733986043025770694447645646628114187722686173514956837"),
+        MontFp!("4521251106518907970739816189092928174098228189505033700492425014567434676545"),
+        MontFp!("8157530011801905607623371429913769514135441595931542848447134013030997901186"),
+        MontFp!("17210220909122096186649472888037140871466614174061205406900504999283153448361"),
+        MontFp!("8320732794514386532143557744115700882386234810376915665505736773284988176468"),
+        MontFp!("18545875500168811775790253714678031231209184655369087385271022571392488988409"),
+        MontFp!("1254781372250935292066700779746601577560528849791669968836092329802026798977"),
+        MontFp!("15937651643466826506099692353730922248216886908526960483039790489122416783291"),
+        MontFp!("19652922102341829730840072437940487297445121681514373922080785529489772301904"),
+    ],
+    [
+        MontFp!("10503757973376634172224417598657275807288376059467419568957007645015644121173"),
+        MontFp!("21061128242218898797612547848348324784465891245956046422267179077655910293944"),
+        MontFp!("10184102990558905165359578703213338644155257878926023646496853865036973561949"),
+        MontFp!("8666994074867894594122739436332358585611317775525945925668413909058593367375"),
+        MontFp!("6295777974744736354234904948599345763309384248069989076851179466032501025536"),
+        MontFp!("20085880035576919555482594174603593248431976403050445801147514535176924689286"),
+        MontFp!("13336325268431575611445366940808804882241012554243123490613520755178670444338"),
+        MontFp!("2851570355537839451958385805591947257368741486463662868962800334058987150319"),
+        MontFp!("15338540229139367100976188303076834527818508966890772601613851240301217961568"),
+        MontFp!("10371461591548028790292642751110580057135152343433828303350260803216047518940"),
+        MontFp!("4721084162843564631441880187545375946421625038671651491284453281351611614059"),
+        MontFp!("3995968148066851603072141668595964652983129138292929197582737382184727007080"),
+        MontFp!("7098475965261931921667649758515107427024701354618287208328785191069896296466"),
+    ],
+    [
+        MontFp!("4781012573672976409656547624587641549313089733225902702392506095387529662756"),
+        MontFp!("20670752440000061451737406274881546918677944716345491651236710839944182745380"),
+        MontFp!("12418063571138285842036790076814486174694060062447109012153384823149675212111"),
+        MontFp!("7811816758498775303698763826175925041786332617584902402713486064657610670887"),
+        MontFp!("12183449593956360154917192849776155473122126272381798975678273623416489913308"),
+        MontFp!("16618893942934404572687811463808390124523121286530811532642072424179325845074"),
+        MontFp!("19406638405969722482660214331214972298000686566522726077456272338753715959695"),
+        MontFp!("10114896891602590148908922343663289384078633766105057337614271468528428689648"),
+        MontFp!("12530180531394196901972330325311958484582015641092775027610123114239686805374"),
+        MontFp!("11041683014697540482690315932778449428117122302594146671576370088141482310955"),
+        MontFp!("1320027017053531867758788220662625751339386035447221928720928792758652779021"),
+        MontFp!("13527787960362675982060947767740337401799422304150087254060335221227857899395"),
+        MontFp!("13729174130954201937892395283664312766703232538508820386345658917013576288760"),
+    ],
+    [
+        MontFp!("17930242209772558948222336463641867165312462566025913611385142785331060524930"),
+        MontFp!("271824668681816275326818116439977879809479607030794905583278884369947027319"),
+        MontFp!("21830554400118502077887728598392792929421142621825963621109394022313090676569"),
+        MontFp!("6549136256809735363529860847857612644334750418268339758975670928910634751983"),
+        MontFp!("7685854228210998769567354902455601859064733599194469796921674884534065104568"),
+        MontFp!("10199783250519025166562439623829544304874659364288345176426181874053651581987"),
+        MontFp!("5943875072296398061174787119165901172801385563744445220331957391085927545285"),
+        MontFp!("13636089974665970650510341064071559084615387545694695884611938887433039781695"),
+        MontFp!("15691183714733597776429523227229000727289026713809002490626629956876436864306"),
+        MontFp!("8559680887315082801378048339294171503207432568538176257628851229613536726966"),
+        MontFp!("18821447832687547445675145256777518497433117070692725937727249438327468662992"),
+        MontFp!("6902270693879104513901555672223877604634815519768564032457887408714154308149"),
+        MontFp!("12608804561676097764962511762420917859780577008987930524021637882085147011561"),
+    ],
+    [
+        MontFp!("7174750477556160125005376833627217199860110410544187422988321767638233689286"),
+        MontFp!("8781965602133662570225157578692327961234407570869748187793896100695809894931"),
+        MontFp!("2495257954047729673944010345252038964921244093254482708244520480856454194795"),
+        MontFp!("12653575930477027537985334989917636556832984698125835912664116078189272484883"),
+        MontFp!("19470590483773048893847572401709923514063082100887826438398963400593333077944"),
+        MontFp!("11678017778870321267772215799288011478356485408686518093768970488464471570815"),
+        MontFp!("9301784115496434107640350077213193580405719087632828834385703690626291479546"),
+        MontFp!("16099421215741601628246289398699744194733547679841291314995675269636375173366"),
+        MontFp!("4867605962743281143953362108297759970476738545503236589613425112307423983682"),
+        MontFp!("4859206625232481008184446590986037965722621081800881620559019195768715084241"),
+        MontFp!("16443855645256733949926481287606183123363410685496841842812427755007713532892"),
+        MontFp!("9353805991413463049534222956121131872491808501219007024034024595326412518624"),
+        MontFp!("14757310122557726344690376086282772240920466622515316103858910465445116386412"),
+    ],
+    [
+        MontFp!("16114284659120061956470765697027372797086020387576070056735297826889023849274"),
+        MontFp!("18127578981196629851526283391389653885301579188814495702848892722544020055239"),
+        MontFp!("19887508599212070022669423370158309019634146887428772891372609912344825709907"),
+        MontFp!("17077080577854056476313617926548386910187074435097123438936357029443634140037"),
+        MontFp!("13226301328255541197539849574625779242079524439809722670248005223174137129917"),
+        MontFp!("4960598616057280250789993806465640165902417481299684068727179165412382990160"),
+        MontFp!("1556369617946500605910052041338863524761596681612901076288655800401759143728"),
+        MontFp!("8895728155072279486251055328673751534060735340135019245331916382281698263288"),
+        MontFp!("2289316141803713796617375788451106287296998047295030310234564594074457471782"),
+        MontFp!("5743949466604344050156379807720583796608463915463812355649877415948657877907"),
+        MontFp!("4559156913695113135073666675680811432586708455776982064075705161369076248306"),
+        MontFp!("15885688417651643378493200422346595912547116850227590423936072108251702846077"),
+        MontFp!("21139883658951849592430718453383511736537035608453172046329191674046781461328"),
+    ],
+    [
+        MontFp!("13189533415954149093943176775514655876533454488327933935436293535694805743518"),
+        MontFp!("17844001788757965004299951292259913492254300676101367510089343929865197748634"),
+        MontFp!("14359892033284780014175740986504899257072848573639227540808851637993574775227"),
+        MontFp!("6889876198036781721490463051636227217181360455045088258864933221139868422058"),
+        MontFp!("4553935378945711471651589030479778870996380701114996688086418055402491525866"),
+        MontFp!("21810472237273957206786186837441232294905360772841197216403531161190353859134"),
+        MontFp!("9053152935239668672905478856994179206897589996100634932367989982680043213185"),
+        MontFp!("7903591118665286176079138425128951407213501136276774569998580497121699548452"),
+        MontFp!("3172076359801796733031209099819054888498110723937314354878294115265190182330"),
+        MontFp!("20407097360746745587552307442773353270033615342704398250066232913190814364080"),
+        MontFp!("7972160291863108260254701271534596191348694866685225653124288422099219831356"),
+        MontFp!("2512979638327596184144169725891221385374685508088897591186666227523755823449"),
+        MontFp!("12288114406943148383409449156486826621388266177895423682705260002640229785204"),
+    ],
+    [
+        MontFp!("2564780208026103504658010005943517057775957652807715289383367743075323562762"),
+        MontFp!("1819044074894043026843741029300477751989386054356309111356865205713103547767"),
+        MontFp!("15212165170979376853314159201892286112393022743512259361106527770911103031833"),
+        MontFp!("14546339564581115494538806968483958415473632518920533358183015375037245948160"),
+        MontFp!("16595063968058690402196826920205083730698910363019110917303832709685137640608"),
+        MontFp!("15141874678776355759677532828036793885858184118820760885539425739465598163481"),
+        MontFp!("18904695587436094037528024957965408339655627593105121455062940021234808627517"),
+        MontFp!("19691835186381553427610210595631576698057108693742599090126967830113381498354"),
+        MontFp!("8924094782966746617987215970302687574282302360164926405994916025579749820763"),
+        MontFp!("19703754039872652879247596753980834118500205932499483040527802680246732547089"),
+        MontFp!("1222369520366776525212393255592594259431640497623436777492426726914294313696"),
+        MontFp!("3172344047869282929514752164510333501174713324035401216632890990510873753170"),
+        MontFp!("20343327187307675920101255252384731549311614062918830229591450503470004905005"),
+    ],
+    [
+        MontFp!("21822953127524319865522817898285572788301109254914611303940997016471607290917"),
+        MontFp!("4119200650145180459943542547991468255860361677520024595215679941381226516262"),
+        MontFp!("884147971363709313208000703986053560492302821976778288962675801741886201394"),
+        MontFp!("14179192339259619376051698338290832703239802203346051877647263334899616811424"),
+        MontFp!("6257894125748014033621520751032796530871364895580315426488126143829369988797"),
+        MontFp!("17601756798422918594467038792255913560794153830062518652267418453154739852356"),
+        MontFp!("2915639491551461190644833247357672612074490352906156209159755564104341441334"),
+        MontFp!("3231102949049971084860869512445280179160209744399934744399854930563348405672"),
+        MontFp!("13021414206859086129077179016730508855909534243359809624497206694614763740073"),
+        MontFp!("19605385510680022189244399663099114503798708854480688481468264949015383978657"),
+        MontFp!("17021346162472827600023529413773560471771827295900312703532075252918830714763"),
+        MontFp!("797420759229039341104668808766016498233266139304135256309887013677831743527"),
+        MontFp!("2664903618363391534009727112222679446988960947334573306133164042707563086517"),
+    ],
+    [
+        MontFp!("3385549618192271440433411525547058485954511711526716925876581944420956285378"),
+        MontFp!("1363918352372003902477247159358100870270143261694504161661979865477181878601"),
+        MontFp!("17725129391688775312434400553484246750421349715293800612810825705415551685950"),
+        MontFp!("5565167518043825254697387383458520114240098359236753821784350172714709788630"),
+        MontFp!("12992626143189731743865280272360549263209967124441767673953882659571766635538"),
+        MontFp!("11778225061486552005541072536540594809710403539663018251279263069564781285228"),
+        MontFp!("14855802626429861561613008610208237167206379805668816510881008293269978954622"),
+        MontFp!("4625126357862179281586979391899300109486628847801168774082540966299465334987"),
+        MontFp!("6276441096446918387723860737716070503136385106381280295004924708881687527278"),
+        MontFp!("1795411699207968753627657631153296652726915050129212264930465577194539027180"),
+        MontFp!("3713335398598470745490569816543380122933539387356971485299483223125101783622"),
+        MontFp!("8168117481656071903083355331291793306622574934522412245021942135108094609781"),
+        MontFp!("2572110912790333345146174163319527254651486901580412685997510630243717870169"),
+    ],
+    [
+        MontFp!("7025592394229454054105021012166569277499787182283497117950324822835039311263"),
+        MontFp!("4277841334216388876550672363276475272389275617444687843049115977026804420380"),
+        MontFp!("13307361877516070836117482544809649307840244674059174145753388549462990581425"),
+        MontFp!("3541353704173386321602971434547681828071166901335602064587889348895191485268"),
+        MontFp!("7930538155879758019336102161941128485659185437630747910453735785539584502699"),
+        MontFp!("16450196550849346986145420593545138121140944184099182530791513972233505276989"),
+        MontFp!("20317647049317277971067996601837330680914292615460278222929651175763284855258"),
+        MontFp!("12350794170306113031197271161768360871903372891508108500893141893703359009096"),
+        MontFp!("3619108225491731892480788907918892224372271737626523316019551923666189881560"),
+        MontFp!("7777524276793695474644184635794903798228263960698425657257115554422141857963"),
+        MontFp!("13186962355302544705310483262369096768297692231774754772442065918802639434148"),
+        MontFp!("3194922763633786447595049582526939577788731446948460263029416154575517633713"),
+        MontFp!("21764951733983707370163229447076318279945301269175491715317016754083795809766"),
+    ],
+    [
+        MontFp!("16974123712053830964644120202072593533882217585039161631414357628477067270637"),
+        MontFp!("7898037946204257594215947941255236785530939710112474014338432350690918260891"),
+        MontFp!("13624131700578417700359325726622999876995826287578399131977467236788609909029"),
+        MontFp!("711696429159123395134741628515974061981015372797792603239801873522576934606"),
+        MontFp!("4454694656802154477610657384658122890655367951456133773797632953453271645583"),
+        MontFp!("11258512209244601689743240316900203988033840864814099786180897714348264963892"),
+        MontFp!("17104789493255338246571119977680210285205025930099647162805883642403493155704"),
+        MontFp!("2453935444829235217884190831569908545427042660161097975703593935054598308172"),
+        MontFp!("14865321440470518031331143407868649683664573698762400527582797926467679386433"),
+        MontFp!("2136979677653477082590579842429661891149048368379361009535231454875783700576"),
+        MontFp!("4168503404348819959713764969732410335826536398112246387116198899646877476352"),
+        MontFp!("14962916637001251079646555121263487095246900845890500033998976380566908488027"),
+        MontFp!("17784806976263484298477702017969482877962979780736193065620735094916915015057"),
+    ],
+    [
+        MontFp!("5811232683701857296108715612406368550641241305997590112572736833619623657335"),
+        MontFp!("1137391750177492513773516911880446733365510948924761786959001414998578780190"),
+        MontFp!("10377533661461362184186134231677823404558478220398261395966741229154302153880"),
+        MontFp!("18289640374707184240721457180013244890344288557498104400868875166742835064845"),
+        MontFp!("9860550380532355283228261241815983394260110595913388936133793178666677861361"),
+        MontFp!("4874241362571018447675014656968369345960496639223360836208429603461250621707"),
+        MontFp!("16865415500549523957415217905648921092860879005547743663493860402980518775170"),
+        MontFp!("18108918097362824701832555936428153547583210042204293837516231255476913995307"),
+        MontFp!("5814882735302537469013996470647050035634690283351899637246825700230993102653"),
+        MontFp!("5547108529079575186420571231950116618134926722630598104799502651040507258925"),
+        MontFp!("15206849009490203249915056590647669409740170384956300636367240071539934234123"),
+        MontFp!("1640644832819090575801198338202018591671247888384450305015978486631872758131"),
+        MontFp!("7653538136953954504326423293731449069773903668694073045541918594328126765475"),
+    ],
+    [
+        MontFp!("15742666466329712178554069131786945235650635594806638864788722339397338233904"),
+        MontFp!("11117991054142148551955400250530241420008368948211075786946321189997485480110"),
+        MontFp!("17233487593470788370048183367208371204821743424889641470669677410605695176177"),
+        MontFp!("1026146833779113517033720464553035587620506307190537032842523110750653300409"),
+        MontFp!("10367733177218458884804949015952210383318098385156812709591442491050289560766"),
+        MontFp!("19795952047370883108661392932328291359892331001341014799939338429402797554384"),
+        MontFp!("280679929395977649114582242142346138466205172264485418126097892277002431011"),
+        MontFp!("11242092785741474644852794535567939603118987148698147008638503870866024069513"),
+        MontFp!("5042585848515676287159684547630780418802525904631814404423696800729724702441"),
+        MontFp!("18076295265515176256373769387621576799326851284582393083567912446445089657195"),
+        MontFp!("7379637951216509909219158222610656534687563904425133806818053082443647546922"),
+        MontFp!("3558112975941340025714332950698945379582897448619066846171473836063116876320"),
+        MontFp!("9573479360636272045463430387538379484130343682411115582933078720270296582700"),
+    ],
+    [
+        MontFp!("15319039730033276973173680817870390182683166144884901717164880903880948070156"),
+        MontFp!("12614337451840522588925552004159363860236857822744920028925848657320885223697"),
+        MontFp!("11264872143423037953370674414336573063122101340016831919048747444897009600345"),
+        MontFp!("63551039484071672399844495086043200468124594386399597718753671627443264469"),
+        MontFp!("16482682562437246084957175194240509231216838776265462897558195414149416246757"),
+        MontFp!("13173166853778783824789219649923900522843999506901247221151336885046420684780"),
+        MontFp!("1129677032059045391430942335190974518204360360119509444502890845544005628775"),
+        MontFp!("20501934327919041700769005911882447290880962332107224642437572131655999551570"),
+        MontFp!("8568499318929533224876840771639984749494220751421895884374508523238084517848"),
+        MontFp!("21259500369117112858046793730986192593905655365067722159200063822227414197523"),
+        MontFp!("18511737784245858621248028192284641888665848600116211193811323135518967443130"),
+        MontFp!("18603594203971848110309624617834986072777858306337940642153570206399896731227"),
+        MontFp!("10817810567854821052648176407774626914880746517118466269332776843576340211709"),
+    ],
+    [
+        MontFp!("11799868341887426863553231699803195039814149532556595741528976577115279703638"),
+        MontFp!("3757945008672794110380903983621818494776340526769633536295923729698796873418"),
+        MontFp!("21147309772231799704776665377393022398575359474581992532654566514687692622381"),
+        MontFp!("10106161080097685505341186856462439849936096425798398803226308955853233801088"),
+        MontFp!("14262263816750661321610690116851316864336319623922717953366014185440110799541"),
+        MontFp!("15141933436196205178645622716666344645155868155385407285733185900575537844179"),
+        MontFp!("2528094772114073897461542081006497820213677180486542945691028610950494870091"),
+        MontFp!("5348148742466995347556247741747876445951253226718284728036059196493410775291"),
+        MontFp!("9450763449774399387985728640759709076413273047954552646207186077905960887466"),
+        MontFp!("4298191617478880606290362740003112985908172107645583722035275913358222519724"),
+        MontFp!("17030410720819419348097350980058866817562528502379090451415524440163140111041"),
+        MontFp!("20216724013881673832814480585345314476760112405406761404693215284600396014822"),
+        MontFp!("3030458477787500523997196317647453871345579139634715247165773460115609582330"),
+    ],
+    [
+        MontFp!("4498462169050723804654682081938858063245557243640516301148855502903911071559"),
+        MontFp!("21824371776439248570562107317467644300343374167209593758174011062336014366712"),
+        MontFp!("203060629973198497909844961226448148781283822023116446785885526153889141133"),
+        MontFp!("13138719392503582101372758211337939905779727766832919837180755391232674501632"),
+        MontFp!("20854865022095468190800800251894006373048202378274232848293926367900444699099"),
+        MontFp!("18588694216199791340977948229034824338778108765317256872571104367383806683819"),
+        MontFp!("2107723674718153039822163382990954569558924075626979445723435572951885590282"),
+        MontFp!("17271751087973139804430243764301653129405836972234862722312699991367221547648"),
+        MontFp!("6601353498913454241063626951167220829716071681800808284181512008975791453478"),
+        MontFp!("12008446685921630692928691914657961114819560167122558958661337441533504934249"),
+        MontFp!("2546793791363939452366278657165405700949349144309147725354170056455546924821"),
+        MontFp!("7509639228703028871971491982924543490428017835786144030628769546327315651842"),
+        MontFp!("7823420836979374535855612407590511724416995784427817183396908887631615569374"),
+    ],
+    [
+        MontFp!("14440328435891567217327191606342183091806515070572157964854472183381142306857"),
+        MontFp!("13108790694241576486381404587677845524627865147145072471473988738194261674445"),
+        MontFp!("19983383938241398968439231728950534655853676209193309061102635732967764422018"),
+        MontFp!("11284194381233971884183176274626185331731276627680969047259362251065411330490"),
+        MontFp!("1897088360814614813239622717000987185543144416227464749796311263447835372290"),
+        MontFp!("5857538086726776983838909617047765193294143035705320425608254315461849915283"),
+        MontFp!("4535962484854275896801499258204616949002112470323390523101255172552516276853"),
+        MontFp!("11469688959565470671946358524828946654780293319597759366277869041848383938506"),
+        MontFp!("20012499472065515722935879215306365340460493343044867272369823908813586319215"),
+        MontFp!("7237913775106599492643101364689422933623029044428357142837972864389129948204"),
+        MontFp!("4449045505867908852965142075471111990333999619831208332535528566178225482875"),
+        MontFp!("18979580728127588847832634342765381189070401746541099544183581604411319882119"),
+        MontFp!("19208895793797113211677254952138771238229741398313608749033216609457932026240"),
+    ],
+    [
+        MontFp!("5693339887541566707818870620263679026120058867908674300592248504295030161683"),
+        MontFp!("5766336473322649734565794453161873006745293312886382597938223884039108263098"),
+        MontFp!("10170500372500319676592379791726967782477062446940064561124188934689788907641"),
+        MontFp!("10715178415744481025577257316838846623444784908902638764026977772991007652177"),
+        MontFp!("9619234464729169347469055561718306819745671419829947117176160764784203967441"),
+        MontFp!("1725396226859654685724455004555068750501182150139117204170589152838896682582"),
+        MontFp!("12812488682187532465982035305360477706619015575668642434536603051988822585780"),
+        MontFp!("11634913038840687277143479351580381035995147077852960651195662158592298943782"),
+        MontFp!("590921624537670966145684975359593307845422616174883518035926979611039297697"),
+        MontFp!("6994357700776589034911446516222866144704328090940819982199063011389928815878"),
+        MontFp!("3578735612848960352693685408184960756564585180098599373883079061082850989193"),
+        MontFp!("21635405933646037549623279995086014983969914199701848464885184930399308788578"),
+        MontFp!("12427020649229929152974948764411072254994310665316587507041988661972823471907"),
+    ],
+    [
+        MontFp!("5659276116329803541059701770846868827421187991242679567541795719989019960482"),
+        MontFp!("14409569205409757172710837837486699395207656272569912916147975142886221411048"),
+        MontFp!("20174498258690846561506592709929532887311794985051466436061305284714705004116"),
+        MontFp!("16144749571419779339987554753326186342500618892721832987727063682036648217899"),
+        MontFp!("5806868382318732909919594488299463205875390466419224655308941273678069453955"),
+        MontFp!("10943981499006299439884803446608501609107021429051016441936415463311572940454"),
+        MontFp!("1924938839701254827227113595273674646757106690922839787536830436278569286096"),
+        MontFp!("13979276902289154914577571664895855335838520340557113744304695510562836235170"),
+        MontFp!("6432081587087260245919599999151100148827877257649626745290270317469081484560"),
+        MontFp!("5063153002404552050282559105432952932908381495673902142745313078991883601735"),
+        MontFp!("10740439837861405332126185761433228439796897508030224612484767505999462329793"),
+        MontFp!("12737167379130609027705740172196108812439282784347836729589408028420120550933"),
+        MontFp!("9849845512381592257963507051167559787679646439786540183276617129759373853388"),
+    ],
+    [
+        MontFp!("16005888832870175950940829700448047001850581165998249926419521156267014986701"),
+        MontFp!("14353647838770412975928759605442634240665383526900174470441397150591603642336"),
+        MontFp!("9246400563934815769990941385821129793696094365581285133233487333862515377594"),
+        MontFp!("15148965960125250145435338272790355289745702742252704183388935133186551786499"),
+        MontFp!("16453015856957375440870560801879892220374643048047546023962049108638908959772"),
+        MontFp!("19594591292469468407344408128396176069613863801953800169935974455228391704047"),
+        MontFp!("4786494574332708919915792733222077450535137127349578261762663721149074161698"),
+        MontFp!("11960730258074073655703739602687794653921577208052039888289111813676118677358"),
+        MontFp!("9848013027466807250590439150605527564079804116846510943688041755058752584380"),
+        MontFp!("12899034273953092771018315472337883592076632199623658827592500124277160375343"),
+        MontFp!("7444799755957099892747756300128576168091670485463138298855357993349282405924"),
+        MontFp!("12078602067619785815908569606330383217651816461769668980367782310218465279082"),
+        MontFp!("13207091244585343533434026539671489949734093160191356902826819065281696899279"),
+    ],
+    [
+        MontFp!("15052325250252549646358568907141836154046676521102037138442805194847473405285"),
+        MontFp!("2221581370746975227443137026410298234625504939466881398515488592571828181681"),
+        MontFp!("20243663867674839549278772384366563405687275170339456055149452630358919741102"),
+        MontFp!("9989690042997457961300135390803031791688603090074435089369924996763771804683"),
+        MontFp!("2394175383139107454721858241476296570939299158552117381451586030928104650195"),
+        MontFp!("14935488700038770608988697394320688627203362240444723637257364275046157727507"),
+        MontFp!("12264662514829291661352518974189445073203160920355111833291657311513159477694"),
+        MontFp!("3827639321249325764386581723986872585672536887010319153929792264348424272953"),
+        MontFp!("13487908627465024716967075008462681337662334712641104635432980395748705243220"),
+        MontFp!("2271300450003775887579228062020983806396606091928055215698274988642377017880"),
+        MontFp!("9536464905154879502929074042077088011040150301676217212058440880221802373843"),
+        MontFp!("11224187363394886568032176392622806663752975492652881881608898287407285044670"),
+        MontFp!("16348011463839183311989740909923102224397330137023072134870626410011917574109"),
+    ],
+    [
+        MontFp!("658746784212845073728972419644775580738462236799285816112006815020578863053"),
+        MontFp!("10913847351958014923157235635013414182776322060119409210360083568773647202922"),
+        MontFp!("5174660898410477334415120015192903322111803813558785977142572971088703464181"),
+        MontFp!("13435625677277740540867428279095038525759637068783477185816475988654675258528"),
+        MontFp!("16042236961096516425577672868221946711334425239903980281947225696871186016946"),
+        MontFp!("5966732498522491800452631630164751678119619125003570554878083612603498334850"),
+        MontFp!("19244421524004466175382168721538493986709062021717633087544245374056859990844"),
+        MontFp!("8953835349510092921386508744116096000868233729683707705475555286120350297772"),
+        MontFp!("19400107730527891751069598685513048198266948717144299472180340676627124394816"),
+        MontFp!("1788315965311520067508595535605409766732846405132796308898593684888230118195"),
+        MontFp!("4155306741635339863500551353079678816173576578036039224848730508326156410064"),
+        MontFp!("314333752297483020587573946457568012126281776266668278461087690408622762890"),
+        MontFp!("5814056308990522321998868808930979798909264311720790843253044589964499450995"),
+    ],
+    [
+        MontFp!("8139572664280730177398240130505806903517588262678422575082094265546418213115"),
+        MontFp!("5891945033023051894996849367995027817716659752927558196235762187145542665778"),
+        MontFp!("15816119508706283096011330636590931848201515685282376441014894443298875354927"),
+        MontFp!("19765585883129509218113831432898646175337020355613337867993568030797990428267"),
+        MontFp!("7571142440759701899321724284276618755088451471808734385481644938761653631281"),
+        MontFp!("21372154727662886563674173359368281518527052830463392775221052936073944196548"),
+        MontFp!("7081252182490962865977081179238366944226264513969472284114001831030270956045"),
+        MontFp!("5223967788855971504613125877205268444815856778995847146009869071113923642838"),
+        MontFp!("7616031404430100022715089975212311635761004286094499406826744277117238450802"),
+        MontFp!("2964922410519663416704982602737862878081135021433460716313695716253990013571"),
+        MontFp!("21452022130413843576926752262576843465698254258090353961686699256674273446998"),
+        MontFp!("5615506565720477707126907662373732333382367798591588442255590186949548315516"),
+        MontFp!("13758529852543878424689721858680184284389257236387722027067085472062441696813"),
+    ],
+    [
+        MontFp!("12270911407050929770266474860904788952698196744336477194920983584641315683723"),
+        MontFp!("2263765921105974400584982144019036516750696376273595921030243162454245412438"),
+        MontFp!("3726743207790008961875115608784686494039367610419333613620227882409630379219"),
+        MontFp!("12237143845448219572451550465877077742264329541226110387544727976984528388742"),
+        MontFp!("2409964261853228751087973651622025781333933822306168735290950339250464395333"),
+        MontFp!("10085433407143766997440158468408095154887290417940533218330090781308733416929"),
+        MontFp!("21228510717137782368419571997756416328932321564657530623869061270948157027291"),
+        MontFp!("12056471592273332515153939599576024781202771932636795778229482480420755693571"),
+        MontFp!("18213366556788088673353762707270651486693693110301565439057413547158475404035"),
+        MontFp!("16502444929714671396753943873426599559613041594477504659253173973027014569781"),
+        MontFp!("15099656057998038622782214961934296168421711158711759920714280998215172598701"),
+        MontFp!("20662687648270300297638887955756931826210498273982273406811895752232434295545"),
+        MontFp!("11319823310823640550774639562916849455005127799420683680222546170719181512525"),
+    ],
+    [
+        MontFp!("343967214752520057864245186069476394037815047607811439097329864410341714737"),
+        MontFp!("17503305564468603693734885462989691651193248463782636796950758256530838277067"),
+        MontFp!("12741605036457271400687978857670758064474033476970216981099556950125969853613"),
+        MontFp!("20739683061558313403494347679816765721522415874620086168947803143944678465016"),
+        MontFp!("7946584883804172227025447539224245968128890222556746649628342784345139347190"),
+        MontFp!("8360187202053456670989734732945943173170474770371603015342370151451116337951"),
+        MontFp!("20059356712109588518115933733238896878411826141623495434229710888297572857371"),
+        MontFp!("8908929255369499016159063161703140212756115979036668550222341666828086389886"),
+        MontFp!("17260316215848220480068274186000229981635360232751196604952216075987952224195"),
+        MontFp!("16051483806631795891066047812303438046492064609198644392699337542232076970683"),
+        MontFp!("13616797129917979734082711158569544985901822934952385632710806021914842733604"),
+        MontFp!("7121878885265063724099916056725320671644022545379534508159519935129387437912"),
+        MontFp!("5095784041111849236518098036393508072376638346067943869740820917637881991288"),
+    ],
+    [
+        MontFp!("15064740101319510049989912367011238454044423307266600930402588789041306100213"),
+        MontFp!("12407265211081220177754043474825255990635653794940031741483370307852381609406"),
+        MontFp!("20223426484470923566833281385864037127075416246760538371032215131656571722149"),
+        MontFp!("3668052915108225265616930005001596789890471435407321351170819088999432868806"),
+        MontFp!("11366073886283256473381607209905269811061951337531274662130606966429881340996"),
+        MontFp!("12582247379355857288799702955077607346019480970920005735329811307693949234935"),
+        MontFp!("20186938619235157734865577521404397515633405422805778974853873925656656294650"),
+        MontFp!("16122019959033821849412382360062366364664833933984337294691025029563985515365"),
+        MontFp!("254648902211450148074737989547563835748091091484232032343713819397130138530"),
+        MontFp!("12172833228273710344765296926585753189586602769025493553315361303084014018635"),
+        MontFp!("19945409419975886341220734130071717017479702410716315294907303154809664173135"),
+        MontFp!("20712004789058289094102947689666173023011192678139675809444116843756511650735"),
+        MontFp!("21105077263104009643989508074340105423304108979860049556255519287288188396634"),
+    ],
+    [
+        MontFp!("3538342015671194745411221642090452794460666099501316339752121561502241774127"),
+        MontFp!("16008085602703591565147288064897579335322875565262127586367715180903703479852"),
+        MontFp!("21422197449727241817827580893411034314307602976542729347805156844487333432241"),
+        MontFp!("18799547353759539084890273894754702756999055145524208124647385295339036997359"),
+        MontFp!("6376647829380850860365386439681035038162788714588766405462355210239582784337"),
+        MontFp!("15847703763893026451216792359491847622491103863033823028942752373064363683711"),
+        MontFp!("21753518314824757010443592444509359716445873322887601064788806456493419038035"),
+        MontFp!("8018616956493930393966420225569393035642655431019891986498475494315186954398"),
+        MontFp!("11747316959744110931059298678334515105505609504722662598929370282308351440074"),
+        MontFp!("17893257331206244838570586905501874457956496735362457086282197258342214145307"),
+        MontFp!("15961328802051806619901962582575367866289226289028087772591680471434203677570"),
+        MontFp!("3236169926687880505076444874746358996739587258202752103445199991661738621601"),
+        MontFp!("187795190351727293099714304116924667408668969503996281039583636956966649638"),
+    ],
+    [
+        MontFp!("239392318624502435385014551926381415338676314268675338353695671324466753801"),
+        MontFp!("18674319341781941734600473869568608233805776945980535084119278717005726916471"),
+        MontFp!("2771345443147472606857365655976656180452504182686204076714773790025740827296"),
+        MontFp!("19450913042313320002652706987083488807755986380163327474873550819278969177372"),
+        MontFp!("17088122609555961904069874590098938791545854926952758736324719542173158272235"),
+        MontFp!("4614324608780439388145100049836115973615823562529414178724078049067318464093"),
+        MontFp!("480346578559945500918475024651677711916332481019644166207062710283884336501"),
+        MontFp!("3976896258432286534710425248577923525874780501683135711905396303214796569813"),
+        MontFp!("19747713021879142821191045416534012039092734284665942138998895512844748934520"),
+        MontFp!("18114341948852128544591474427791645861453096798290403362024577582905309063343"),
+        MontFp!("16389740922636415377645046847550552757683160597807953014971200028725848855677"),
+        MontFp!("14347956677754622309732029695580580261895229653511993172444407254733241454730"),
+        MontFp!("7001790022045736128714608604018732713668075078108360908557371520612055210303"),
+    ],
+    [
+        MontFp!("19272993806385013542500911534969336370461053778032527920746797814743961064937"),
+        MontFp!("20666992739220376856615766377753030050985727426420972006419425641580077380709"),
+        MontFp!("18907720165776367637548948232402401955322000052328805042740522159109944714138"),
+        MontFp!("9414327783699575006459158047005978347088459439117502202158387157394567126641"),
+        MontFp!("1092342932834424845294743018087259301897007735031966382725140810717192908054"),
+        MontFp!("3953337326499755715834998572085254989073834902724154050737740470058759118498"),
+        MontFp!("11743581671122680453195477167432558941219274023697487318378655108983631999472"),
+        MontFp!("14292519320039108746758578387893135567048390166844266988895150640926606231575"),
+        MontFp!("3463895766707893138928153619402823425980920627031760477180165727285868840115"),
+        MontFp!("17329024693549080975670914737054013479227561926835421032215803061920086593805"),
+        MontFp!("17069029917639740020306921281456148921331852897276386771126788265397483239836"),
+        MontFp!("17757973687563632162101087414879272203562112761746273122681647748208587693311"),
+        MontFp!("4657037101807059847480820087972588696422739806756490960821623277361176723008"),
+    ],
+    [
+        MontFp!("10031688077390763868641078069309072895683953752120759377432369407780311740458"),
+        MontFp!("13372959422785941027234359900894463458148714364727687916236363488102034358213"),
+        MontFp!("21074689968655856473305620806477766182958744184789493409758269023421704332636"),
+        MontFp!("19863757339575916461434905195916835719746715041076078424686010055572742030617"),
+        MontFp!("20973060471220514134365115793881078818914475917880454352814198678201263612788"),
+        MontFp!("20786322802664930407040804724442246307149058272537433665345242730163006184858"),
+        MontFp!("13503324543441706240017837835235888103455201530371938269103987765298019164241"),
+        MontFp!("21516545371414182695629550949358554432241582789246093981854557932438611855051"),
+        MontFp!("4189468076924692882857105215398697213714749255613940842113064700527656187139"),
+        MontFp!("6356916718613787191898160559615069566270314091650855868585425220261360364619"),
+        MontFp!("5420879043826588690498755027108627480358930425000012254678542105050717051191"),
+        MontFp!("20559290756493460657385989162478253178529811977588548661414171937311774738356"),
+        MontFp!("7472237964286313816282734103247675024752524054147218881654824853569171740182"),
+    ],
+    [
+        MontFp!("5587656354563280475935968622322500135733558246449238814064790207486002634312"),
+        MontFp!("10477445403396666334274248654801801931117602962284736619479124362799824273546"),
+        MontFp!("6964581565574090633894249931196779138063582556737680018364077690424381812379"),
+        MontFp!("14818621060919997284977287223747030674994127751231587714382341838934955054844"),
+        MontFp!("1561884120897562502024552266455149126373072574485245753929487036177604271779"),
+        MontFp!("15924114719216774839217247278498830109576711552882853826759218581616445364187"),
+        MontFp!("4632899269214938328686734061038562662617634605056083586217293196683107383226"),
+        MontFp!("414275447940334199421265067114189311498845927325050140106659496782723706629"),
+        MontFp!("5919841635132642392689439793932385370460582843320887853171556097049171723480"),
+        MontFp!("18213825499960993231734509662843099396992046367870190109129172304011496568015"),
+        MontFp!("19071949566664135927732333412944575018103341781608323568493744120181505633727"),
+        MontFp!("8055738556659889630969832748361502052460816679489752687851823555653740856922"),
+        MontFp!("18552532582629910542384644188750599556440125083583835015670173118025699825513"),
+    ],
+    [
+        MontFp!("2484657787081400262201491424080522833403834349414361745080712757081157295905"),
+        MontFp!("16983418852456608800545535696543676988540233741050842886180695807863040864765"),
+        MontFp!("4238493546779457743228884918170815223308340385056120983013226844661472349292"),
+        MontFp!("3630132677030524902934720712211822367613329466140887879132884750998038269473"),
+        MontFp!("21054939309368014542663397737839771438999660373085660928983975055849174262524"),
+        MontFp!("7764728564558131555197022370274162477498111431299751237388301945333560774986"),
+        MontFp!("3593305607441900907267751550709584072658973999555174092879673804077111387503"),
+        MontFp!("932359123290353753331737736031582956463161520044366983625790109891741693526"),
+        MontFp!("4731952008769040870131753442142911445224559732127188801247458162044777478911"),
+        MontFp!("1306090977867005427161468095288389390649620350907397989639122170049060845154"),
+        MontFp!("7759081335204598570892984361955322222987825766180300022358547686895179745422"),
+        MontFp!("6447993445251075592811527795404536687284747599271434406060192746881971850885"),
+        MontFp!("8266912047004162765439491557705745929139857757706428454484671294796825044391"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=13.
+pub static POSEIDON1_BN254_T13_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 13-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 13]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 13]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 13]) -> [ark_bn254::Fr; 13] {
+    POSEIDON1_BN254_T13_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 13-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 13]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 13]) {
+    POSEIDON1_BN254_T13_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,1529 @@
+//! Circomlib-compatible Poseidon implementation for `t=14`, i.e., 13 inputs.
+//!
+//! These parameters are not cross-checked against circomlibjs yet, so [`poseidon`](super::poseidon) does not use them.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 14 8 70 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 14;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 70;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("6418344278839121997761558068555633277874924383297235060428040203550148460392"),
+        MontFp!("8184778893153361724502825398680471446632259194563037088246379746010351701224"),
+        MontFp!("16103007220917684483813510789286348056519805127857943417823167430289370248882"),
+        MontFp!("1664056765289606259902279533842090994664529145577166075019619213360544081038"),
+        MontFp!("8189267971733078428327714274800548471520787418839983750528172780925243373109"),
+        MontFp!("20200362011107872066803394413626937903139046091127740529060907959370256880701"),
+        MontFp!("20173744990845412211550008592188171997284132875646553363208340317991304601908"),
+        MontFp!("6100976759548353184263451545777211359935324012594548692714862113681123862281"),
+        MontFp!("18589557631793259794347972680714314322014920073994928130094285735070065431315"),
+        MontFp!("774191617468212021433032703138192471679212014828288788368078839883023639562"),
+        MontFp!("20846157077077136618808870082870348881758556999252332666235423866411633465885"),
+        MontFp!("179369838162302125553370844232732082673907343764950159722856079645852949296"),
+        MontFp!("11918621764929568738238867861947162353407822663185301208219638335882724852664"),
+        MontFp!("11892816132266551039449220442540498829071526084314780371963132054098089857199"),
+    ],
+    [
+        MontFp!("8372083054789567618141127024727893763407629214826505908070525202510871464376"),
+        MontFp!("16053969929668995455194287538126250235271805322133461918670713060143921845064"),
+        MontFp!("2376335354846722834191665301267088635977856417140218787958824096999457654386"),
+        MontFp!("8473768787638105406889032631028566536048259675966039382891971280359035809717"),
+        MontFp!("21341339254986951084972542739281440827262568377712659704364142966685544694259"),
+        MontFp!("17589661461505870684154172724042456165609752814624225501433267429299923279866"),
+        MontFp!("15569410058783548405562051853887675489795673100717406458705097884992918832740"),
+        MontFp!("17136771849960699223133648686528512780844440317239376836819594891344485260741"),
+        MontFp!("6846911134505306255484642794298233221271816466086606204236441947984501580805"),
+        MontFp!("6155197242495613968880604860842601809115711093712877692064968695514089066009"),
+        MontFp!("19128637117872288039529749360298403408432890998345732113683578733270478526860"),
+        MontFp!("13897081066927368111242039741190751095002227555149381235627883407347099966197"),
+        MontFp!("15568696369607733677931070776042128239961624292553408918258251667007167792827"),
+        MontFp!("4872469898347155985390501972210222637651779849978701189099596399070936407527"),
+    ],
+    [
+        MontFp!("15828528305683107589096901962734302130704783164486093165237194118210566499447"),
+        MontFp!("10871050448146531061933214597828428556087596885695624079458134367495768692641"),
+        MontFp!("12715634392841723603484337993449575047813504874083783048519693646075495401499"),
+        MontFp!("12461797447292058643518857326956961860724022201671935652194772338021756072767"),
+        MontFp!("7893478478385050482361413970069327892342442172936930424757486435631374257835"),
+        MontFp!("13837601157198138379089270567935062044589875195895421018230835162664685103799"),
+        MontFp!("2902437942374588332095798046833013227638771976745087670719351344275677524328"),
+        MontFp!("15243444720000907684100993103252257759861356192301299607302082943722781818072"),
+        MontFp!("13807772002950549127635782715429970069282430778664455286304012934232212216437"),
+        MontFp!("3330690659470353458591000516836956263198779643900957759109026769401030561064"),
+        MontFp!("9121369550752168091283493588432030055987339875340008013960004670271046125246"),
+        MontFp!("6735940425069065421825600687003545396352913420833444906054707032006918504438"),
+        MontFp!("10202597085447755780667010145999569476263425973948073114389503196598866401333"),
+        MontFp!("5502428069812047253902185475715178213199056100606031376530298129227524479345"),
+    ],
+    [
+        MontFp!("16644412428296109976288812904703533310452124314681543539244699753829624611041"),
+        MontFp!("1464363769917768267070780357984170193149530071521363359029977430003041058712"),
+        MontFp!("6585015011013232733155666746473112211355795487658434466373981554623709413097"),
+        MontFp!("4801399347914387190330170488585953929447785020623558112311440395749307391856"),
+        MontFp!("4112082032915966312546686950757741516554419896344654985273514512341960565972"),
+        MontFp!("21751105223203500709422523072334627517145925318798961657655356624798705469220"),
+        MontFp!("5444707496026644829581718915396971639630623832272466243662913303978147462160"),
+        MontFp!("5176202236175268355216491019205403607765794084697970681965844070084688885903"),
+        MontFp!("9306814982626014542830328909308268815941037317672165275254890008767120833129"),
+        MontFp!("18534005003473959617581160840952814838772020918476059581821124675494141739706"),
+        MontFp!("6315286473605163880327953940950267064586093973100720152185112301408202766527"),
+        MontFp!("1300066066523772310953083996541204931152213396378337235691979863222476540925"),
+        MontFp!("5067928636570884230858740896001720688497565237268231912398775294420405805509"),
+        MontFp!("2247332707031505717534336329466562637772796336116582429349574314234357562544"),
+    ],
+    [
+        MontFp!("20247738241445291688801934041857783193064556313940428743035963593744371184825"),
+        MontFp!("18242310613256758222060583109097399506427347785291803988073944852458874238653"),
+        MontFp!("5027570650503078047623512055050961603632044524914664539379503584245086748701"),
+        MontFp!("5171024028215683374631525538977404535998938725569475537905229563957776539718"),
+        MontFp!("14944506678316768982115249709678873125670683211913646815567280613875065136559"),
+        MontFp!("12712774808537003317921729077604843728755135888798177519726799585299850232729"),
+        MontFp!("9704917525949617705578174784620125192847872142914494686332094535178307346294"),
+        MontFp!("17607293541936391773356775760016259535976314844718033784671775110177131099056"),
+        MontFp!("2540563558441922228123722363766317044293335648393481767268774186670778379682"),
+        MontFp!("675334497725789143197575210295522345522852999942555409554666160997486976627"),
+        MontFp!("19406946394055111935833464724872576724279555593094398662505995447037826031689"),
+        MontFp!("8556081865419484437161415079267380957278647215963771014478927152338629734722"),
+        MontFp!("10894080579102201350420363592007910695129177124810422954037601558695648517625"),
+        MontFp!("11291505870061211613677608925985023369330908318817731122393740145823745286941"),
+    ],
+    [
+        MontFp!("8111345701049008354923033690718534410144029586027127161268652239467844080405"),
+        MontFp!("274549942856353845785392053622966579448945647367587770109876363273061435394"),
+        MontFp!("4517253471300397604524436610993375842851058850598308811197142784491633632173"),
+        MontFp!("752091920065645178354359484693693144698238715470242001046768604290011335506"),
+        MontFp!("11638201273705718037686872429810169270152128290712392950719778292205581621583"),
+        MontFp!("347106139963113855692776969661437659762102942024424980057845079543094637582"),
+        MontFp!("1650860247124995235720423411540903470864782697117302631679816892337391491913"),
+        MontFp!("8807792864573824348524944774842835780541376663413796103538010142332713129239"),
+        MontFp!("10125706840777901469452374931444076850109537279493387272544675392762656076263"),
+        MontFp!("5796395502360267028606772833560634747652475684290748049574810918512176530377"),
+        MontFp!("14560087906294745333868694378307270211143393355422503000240374502300675908843"),
+        MontFp!("5122547319055760404581514483238475236540700695938727023605315976547513018758"),
+        MontFp!("18167310745991466802649320845690518166441060923615799361858509593334862918793"),
+        MontFp!("10537655786889979411145772153623144374332103428122704649668615330715372491734"),
+    ],
+    [
+        MontFp!("11129217221221505592409814225825346415830118409870141057639470413354985898470"),
+        MontFp!("18539905463366886572265160849053355497514707079972544561033138019460289616722"),
+        MontFp!("2634262960559341073751666364573144239402802922297326052528609874036724778513"),
+        MontFp!("1017221748705774701515487698826243372900579204850532621904642815268966836932"),
+        MontFp!("17514607882958712820434977243901228312981191640605665595923587928949930664369"),
+        MontFp!("11518730158165740016295488007249832672982237183652963149460237152135828893220"),
+        MontFp!("5440554707898671641433725775650782970626686693839748603607869391440450924607"),
+        MontFp!("9147974268228093219515993293158817299424235911620662153289209094112083583914"),
+        MontFp!("2705991777416683673465834362340927783587369064825521543877658261521166181909"),
+        MontFp!("17763220243034576321349123132976335282008457858814906290437636386824597776861"),
+        MontFp!("8436811158855395670172615633911662578819734431001698203244903207934616540973"),
+        MontFp!("4138616319619099661442960332848564292509386403496259477312793995345740346110"),
+        MontFp!("9291305887217504987438822522544685995385674372729325355668719643387679293273"),
+        MontFp!("14247130176192495492949419984506575479387281081069847984799112525576996955413"),
+    ],
+    [
+        MontFp!("12065917784915207956255864287163607339403293417584561373088222422519855200010"),
+        MontFp!("19602746430676351974790620090862300757670420200072717172839368523905726952498"),
+        MontFp!("5634613092261261536249683912838127927279222476821160426063089671641025921660"),
+        MontFp!("5043354945289735676261322233679902891599697472766188245131942353534121350357"),
+        MontFp!("10504830582720502950783794220870443419986496189960249704278002268186024527222"),
+        MontFp!("14773755085011014730752864609252484899166588993023999143755304644745097411069"),
+        MontFp!("15143011250372146369484566133567610168314155803929775363148643430052742055690"),
+        MontFp!("9296771043817098875687880718048618354875037373605143322099708291917193542563"),
+        MontFp!("6699308928121904151061270394393246387724926649022798867634982215175491150673"),
+        MontFp!("8773562200655600334022608356584787571874596251190350038742467377669146368640"),
+        MontFp!("14351113364159322541281738731216101468935785403577478179790191188832046493458"),
+        MontFp!("4850132968055386067280912095292570467587181719320385350609813213372507065742"),
+        MontFp!("4016458733475657342057293585429845015911706822034026494547861057484844319736"),
+        MontFp!("894041043510502707790816962794342134661931379046385946357294445947889380857"),
+    ],
+    [
+        MontFp!("6102901509904208647404960172211548023168535043766794923435155807898706549347"),
+        MontFp!("10993930772305308408754972248679389846078570700140268311987303413959565163567"),
+        MontFp!("11304109937008720250639855591630423562437629896442798574433639679310105935745"),
+        MontFp!("2101572929952921976477335632619843501489349436900225186251308254022656908969"),
+        MontFp!("13379549674365217138865497711163371499213584223441782700932894243483907931587"),
+        MontFp!("14594340674649653462364863346385318403203482098904810919462071947226757935441"),
+        MontFp!("10647634642733631076053157841634424396589261258510712678605967658799137793311"),
+        MontFp!("16930068860033006574251855928288208559689461032565007963103701897524112820278"),
+        MontFp!("17170753006961827437085793102001841977757115057767702296590976802337127094191"),
+        MontFp!("1342928489123424058754093123906133488378103161461686346784407392402405815911"),
+        MontFp!("355084123756415397117817901422581736826549282147584490336790542339114994995"),
+        MontFp!("1823092998982212793902589678970070284970565355035702454684653415443223765731"),
+        MontFp!("8048898551230697881244098474076915607341905762324328681953519068200924158237"),
+        MontFp!("20856146965109368880184165603695312280799455722407374791306423939515039038196"),
+    ],
+    [
+        MontFp!("3432336669019104452964292940253917813738689308738628946720655243686051453920"),
+        MontFp!("7245438991832359030357502486353272155562833935372540938834365796871211337300"),
+        MontFp!("3373527222057951116939099747150655397463396105930227294848620975346900604136"),
+        MontFp!("12572707687046208161448564696692430877391470466184921096766838283998472321208"),
+        MontFp!("12384937031493229201662431219560608524659573369707802134091983819133706078063"),
+        MontFp!("20462122407152292556380602425948480298757076111535435284512383591498863731385"),
+        MontFp!("6622785585380387259638611864923325325049433660844748518649420260935388464763"),
+        MontFp!("15605049021670133989806310757216368923392900885847286376362426319164543334301"),
+        MontFp!("14810486805240500676478878806350063220126440203863567013665308737654824897393"),
+        MontFp!("15365995118119349988306703047684161019037838200991362610041019844939301169864"),
+        MontFp!("6214064907470154787016516294417895267553707205697453843662274325114029144555"),
+        MontFp!("21692877109550228305351038417910800050989918141485758185602644390604582268196"),
+        MontFp!("7958034635501608074408556743462969750498597833797740641656952760348926890967"),
+        MontFp!("3158783210920420629823137370399691077131051120551658723733852791768119909942"),
+    ],
+    [
+        MontFp!("18819671751132769006885776104610704012597692175792895108910904654781356258396"),
+        MontFp!("4790325452934584098003515312147561388538271922609950099121379835100064976005"),
+        MontFp!("20767398895337785696143628393192433940154143348446221959395714109953426025406"),
+        MontFp!("21100955173515864394919601160078443835098475047602620649008862351655083694950"),
+        MontFp!("8821416529246363042674973639302381036248538012124317741611227124444469290957"),
+        MontFp!("11914021995756592924439683950035443074155751236836696847913625157420325157778"),
+        MontFp!("12885168008148287764892654933214399102034605991806678959486007885552095995306"),
+        MontFp!("4425275709248360981167156075250953689813562430283330510520431316578062369047"),
+        MontFp!("18741351324856319300007832572892392651059227679283122747711533395263296376480"),
+        MontFp!("684786537545646459534722549414114801405982388707719975074226812265976115381"),
+        MontFp!("13474309605538568830758681457803561340228343878559353019653388997246494295147"),
+        MontFp!("3099805728977762711509412402176547022850118685798164610570416531465753528610"),
+        MontFp!("5600770441217208920894248156825173605916487562076226390167638135518086562704"),
+        MontFp!("20319458329312269677818220957673767032133429098791178878849925747919552800984"),
+    ],
+    [
+        MontFp!("14900034607445803864669093098274289660238359682591825230729114910199159461945"),
+        MontFp!("8290880045417869099036293157381173265982569186829040493570954991061128070937"),
+        MontFp!("8462436298566072387124764625279911648753174589013128335782578822029407672023"),
+        MontFp!("21859976115370951722195695482147975930683297060804395404861331672395447064815"),
+        MontFp!("14955925753372802734448760267566451635799685206346879293698701403481312089613"),
+        MontFp!("4065981601972608953318716423564125873552717375448854762316325928896715511302"),
+        MontFp!("16523330234791629267329601436136683980570251446067965291433875700044665982992"),
+        MontFp!("4829516041540259457263557932051130738530942234255982016822863700315113125159"),
+        MontFp!("2798840870433591285859944008589519120500790120158397573735407833376475300691"),
+        MontFp!("13606709616318037902590277227595176470474803011913892797662975188860939656033"),
+        MontFp!("18594031070203820621139505381680984797252179318940256457018473319622484612714"),
+        MontFp!("301595919185954926511829797241004376609778538475902874115890821742528188064"),
+        MontFp!("13821866899865257052128875138353714301663063129600329885914206051645789155403"),
+        MontFp!("17834817495579398016717972195069792832976969906967797534885124084571666971093"),
+    ],
+    [
+        MontFp!("4636638693344568063056245513561087534038777759740617868439601311740450428791"),
+        MontFp!("19461094558355290408553329139619939302588770167904059927666632659470708174566"),
+        MontFp!("21540578068459849172758651661944081878533817564350404794583097681041474789761"),
+        MontFp!("14656354515483866868842463518575118611017107704362419507056354562832024225447"),
+        MontFp!("343560765432454876700403976911389497782754407830340801930660700142029455668"),
+        MontFp!("11849359483183996767521484728570765305101196328390648813094255236259946055317"),
+        MontFp!("19924264286421201319107239943837407504286665549555105416180422159851955997381"),
+        MontFp!("17166797941774367858319514708612731492719223682833034509589752159955652705269"),
+        MontFp!("214236542688014950402766598120744795751883810511956979430464011049570919618"),
+        MontFp!("19396313302865541199110558245182888004201133198902041449761385765703954241755"),
+        MontFp!("12933296125492490585010683271492258153065303286717891025958189212827073719981"),
+        MontFp!("17844270568289843619005627429285364222068221173113885235581641198349558307966"),
+        MontFp!("10272636157097124940050637562161546985351212351240072791897429490752295849402"),
+        MontFp!("5635761628763643716582314870927054669889487541831762994265390120663404976213"),
+    ],
+    [
+        MontFp!("1986614715532243876888195532508093896345436225715262830320266933005235142097"),
+        MontFp!("1081989267417492031457620947123272024310181199896785177670601875483149154563"),
+        MontFp!("4271010751492446271792663900865386872716262596127435260947966701647960153580"),
+        MontFp!("9432817034096362918055925335622289410018247097406473925807967486887418646785"),
+        MontFp!("8420506466048434939907866067225441403679080978454172178355845135440301602325"),
+        MontFp!("7476612931957868870953074766426582066042735713430654491467754928300129405389"),
+        MontFp!("7004218213610744415525118134908394594325377744218316765510260162880307132371"),
+        MontFp!("7571768879245095309014348656876595924444469219593531275918644914891061799835"),
+        MontFp!("4094897072683886403820666572344492999293005026807228977160362626188210714131"),
+        MontFp!("16648450944544843693538650431603587114533063135176857105384828552675810607958"),
+        MontFp!("12887042974366913908128212909540148628955501312789089034196573637089727885967"),
+        MontFp!("7707639242815954375609957852547086529799646052590213478924819089112009057667"),
+        MontFp!("8823950326593148986044014943552213420762296615584865468926316064433145020153"),
+        MontFp!("3784973153813546220636380916429273484146041804997715013288159720673291711004"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("21845584790817078371458083471368949437776490472877850604640045078191512294989"),
+        MontFp!("19653529167194186573342031346879012675435131167180408423801998487681049609228"),
+        MontFp!("2161640783454164110262374377277313793192503897274785966059544028153063342839"),
+        MontFp!("3054385704838408049711788708109646820127990212588286684954516786776077717445"),
+        MontFp!("16635301713639076283966918721405743045341739008997361549904617279107633739991"),
+        MontFp!("16225725689449395070421553385264743934675104858051374243666267106604469686859"),
+        MontFp!("13459300344588917210133884568970491767131781128923804903594902942358152425193"),
+        MontFp!("18076808066595657160589765822638228194586496301618971676220813553508533309824"),
+        MontFp!("15694780501519741286439086116053845024521602672078716577385749258673935540935"),
+        MontFp!("16872540725652861460604107748085417998883467929955001131816309200004825208678"),
+        MontFp!("18265717256656085201140362928095712147159090935193139243454994794524622317396"),
+        MontFp!("19947609676398163035598483882491861002323351300468831661031717096486749401185"),
+        MontFp!("7065348755377637300800426777670517297442798705186719592553630280930183590981"),
+        MontFp!("5932961695686545421777788171001317503890223201383043783424823453778021729657"),
+    ],
+    [
+        MontFp!("2576876576710667081195577161905871971928185892200731156106299121736713352612"),
+        MontFp!("12555802030075275558510564984903031995058560722557285963258561359487710166944"),
+        MontFp!("6675427211912119210966904017515062933064977336124153512189140864737663166184"),
+        MontFp!("12222599141329943348111473622063042423140762381594560767061296463113725630598"),
+        MontFp!("10362975561623981137760844569177506781739504467939943515494131756563631401384"),
+        MontFp!("12107531632347826132438108083855485290921771214734424618157420540758495466114"),
+        MontFp!("3236413362865617508850894632024597913345632839215458268170009274560894755059"),
+        MontFp!("13269640842689962370480760671727240164372687082286848502921603339956981268889"),
+        MontFp!("8634391395282326489812410144806386699066840792624899435283359752045476380023"),
+        MontFp!("14222253145252965302168372301361324351891663598874510374220732501857922860417"),
+        MontFp!("8638897321585802770244834010345336579513350450350318876059595307827518698264"),
+        MontFp!("3865974821285567306249300995778666703191077355251395536574998983854299535502"),
+        MontFp!("7110975263857442529682465175399830853738223819847928136560110618272170902664"),
+        MontFp!("20747849667914457283564964533975989346418433637631755372433299771504842519646"),
+    ],
+    [
+        MontFp!("8525337218586373689263383636693181730826089466845186214607593322372086164902"),
+        MontFp!("15284698092379128147447314807096025894174546974949762301780223877793581148749"),
+        MontFp!("7697267363768037767923915437621109821518467158489394234232803683407232938777"),
+        MontFp!("18552464418403713636184903318257896949762751716606049583572368219578636617127"),
+        MontFp!("8197992130246671682417102475937137101180310399693221498504232752936385287840"),
+        MontFp!("6267215711691005252092821645736755217101102985763928035625443919819600833817"),
+        MontFp!("17433952956528062441211440388577119958754746388457390005635829394680376947181"),
+        MontFp!("4412646415700345908595327060988016573431087490581498127967913815901104779675"),
+        MontFp!("15833191543444816612091389727640172975373200401937212078659643034429446372420"),
+        MontFp!("2496738022051529758808536908421531340554055955151436808600608028881270179985"),
+        MontFp!("18865821309368781007158439319264225124435320863391412688953283839208730853556"),
+        MontFp!("17356853444859379852104523062654303386446700475095103588258687890090805160214"),
+        MontFp!("13809314487709569674040696128771324530575306163394456992422515709961741509179"),
+        MontFp!("16441057642509466688507318755613056920894191930223829210993196564779122458374"),
+    ],
+    [
+        MontFp!("13126203992288988176378067141418363395032818736472538500506274256104409609416"),
+        MontFp!("13787967697762688988712839398243982920760030565931489906532854488857802399817"),
+        MontFp!("19656786571615920765259702874045834039087950126970888730192726308970074475638"),
+        MontFp!("9496155930249199891836650339371004045437843955429376625371983436016490755144"),
+        MontFp!("6133650980214690439646563353939486183333374330790371938934927663849630407389"),
+        MontFp!("9878750392407472855744714788091164958447986838940220983459727015586370325700"),
+        MontFp!("17172636845539329981401043696407061375416986816086343858560846660524543673690"),
+        MontFp!("18266068074968659293646594793202575012585251834459743250538629833034512472364"),
+        MontFp!("88170009072279647351671114053985696295758726185186407584170034409102883821"),
+        MontFp!("7161623553009701438320582200845261728475099875569530553253147102879319777702"),
+        MontFp!("19053465421328592764143131116469165876378776722378567877265112443326967288533"),
+        MontFp!("15787821054928042708896412640384007326676625326396861263135967977164294783653"),
+        MontFp!("9615173323505112477217305395762776033870692647990635021315350642536385774258"),
+        MontFp!("10748585844981412663149047472971001536312431184916168605042111548221680655573"),
+    ],
+    // Second full
+    [
+        MontFp!("10796498904992920132771232449170737749742455370471689413932089087977711623836"),
+        MontFp!("8377594495858973573128154823903039902710916666748285670520960751078778241055"),
+        MontFp!("3984683583649079079845275538974168947709880082512288559917193166572776389589"),
+        MontFp!("3342097912826432514400613460588054784328673911636756618761620185294261653370"),
+        MontFp!("16454683529696451672086473280821594992928717172228276559555553609303793416523"),
+        MontFp!("18807899744562842658522302889072060930222870278511178161869216361165074271071"),
+        MontFp!("15733467702619867688911165419464811721151813607792971238595067966297846247623"),
+        MontFp!("18142314765654743253383559257284245535624730148238939832089390410939642651454"),
+        MontFp!("11195224772751371350570884976842181779831961285896963666553011681145523971069"),
+        MontFp!("439244367736881667144534860370666344402105076566699968755250684892603338082"),
+        MontFp!("10704735720989025726743300184412677969903347430627186939154207946469729017738"),
+        MontFp!("18578876165452990292495920166540986834727225658030437088905164910953626259691"),
+        MontFp!("8557967377050572410782106944750290348752651270453684627569664713996263383548"),
+        MontFp!("8761045577121588487744533431678957572685193486431511842082771483510204390408"),
+    ],
+    [
+        MontFp!("21449005117895748002614414579158497856671036353929407470614229969798846654314"),
+        MontFp!("8742763699897193075490808534988551410985191733407596422193467672281063172120"),
+        MontFp!("21640560187468057824325948244870722744182743184907610616373323268039154063822"),
+        MontFp!("20504827051323136179653341596769205909304116951166709098994304340550034816775"),
+        MontFp!("16172525553351370894318080522373536864524825253487998830365706514360525527841"),
+        MontFp!("3591685457927038924948528662301423116917211093679974227116322357454330018554"),
+        MontFp!("6023337410706762262272160833625601604408129024083079173527188974815598816732"),
+        MontFp!("18314718271070348604361238020618683829686900661152269809595508349472847942062"),
+        MontFp!("7836153641990744469550400020392942083885339413553995305082889896550143678297"),
+        MontFp!("17661458352757891434962464787581216134089171454741034624471153568868635718017"),
+        MontFp!("4122036760441574620764812242429576792598439550172741100479310420694208068906"),
+        MontFp!("20862235339890611718235387895311549206105487705288574136561783190763219344570"),
+        MontFp!("2850551701806552645458448511649317843914427882049290827414738885733429831896"),
+        MontFp!("11247005361958789340696615426808536553465247486357927152826112838641725799209"),
+    ],
+    [
+        MontFp!("595377141566787974548956333113514797724094819448399289547808025689225222483"),
+        MontFp!("11650535249856393960142913145219442364845834591244773725262296147168383099202"),
+        MontFp!("20317269079309969728497469029861008772266846936951222269809633626856816105008"),
+        MontFp!("18373353028723033673212698815557995243796350917201491012576826029280066160567"),
+        MontFp!("7414281571887634589036890397094381209738607746338326059409587963803891852201"),
+        MontFp!("18038763738650749116346354096722212724532913209902202281075842225944189538168"),
+        MontFp!("1770786615956839194004148114013826935077292967352199469327501774219607260953"),
+        MontFp!("7488031997643483244135856650059587771856081877857609929648968340004445042854"),
+        MontFp!("8828420155416920637244885570572746512651260806059857940333819295380848667606"),
+        MontFp!("10713758486859145329962611447140189455100648474003892294431344231535672892839"),
+        MontFp!("19425964738010714594065721309197326935753724219070408633462182732050226443166"),
+        MontFp!("2074939531697630696938698501714595125993887544292881866150005180791766942998"),
+        MontFp!("12047170609336587447894029543834769407220306562179617939497216596360229783300"),
+        MontFp!("1264730939976742978058162525644425945405879597356760675420608373469615068174"),
+    ],
+    [
+        MontFp!("16770820387947475117163540876143499701759349964098540413422664664028819497801"),
+        MontFp!("16280068293625117002805552395286584356701604835446863786447466051605766622630"),
+        MontFp!("5829399622833188992426267462479081456743106260845929463922917463933701539334"),
+        MontFp!("10637584899633814574790036401685386209425675304700886428980902763634911791837"),
+        MontFp!("9291782595793252587833322197337655894886134207930378095811922971339860144588"),
+        MontFp!("5951026341599354622946777675133435736866407426534732764305509667517377130324"),
+        MontFp!("4403580174404926097288523091529640188805282235669930524399146786861723458014"),
+        MontFp!("5003639966922553172252470768563093188889050306376650665702227782913343083764"),
+        MontFp!("6558197410800499965347594156873334155413890035105551024236266846011615264982"),
+        MontFp!("2475377503098365612622742300317283313185534384082772531168342518799898155594"),
+        MontFp!("13480086440294256226712366993542304396509909765502683907895525126405850150623"),
+        MontFp!("6847184584651376043889746562956674365958636593745423518578010394007380039425"),
+        MontFp!("834503921046130599339994952233856873876410737494970024102211605603428829597"),
+        MontFp!("3623466614124962325494185109684730979089103423795285554788413052641061197022"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("17193496554920138070905215774356694517155445370515924892481894322048517125340"),
+        MontFp!("20981027561373479728773182418686452188471940655764396684028110979140369006827"),
+        MontFp!("21262972686125563821794309741130556914165641373895499794098515843451753652777"),
+        MontFp!("17107658081493676403630365275304097003600155220274082934263833389505162130032"),
+        MontFp!("12511894931761241561787052333055670047472612011302194769826693935710455626001"),
+        MontFp!("17806912269440585736864672280987816575618315620498245554921738316327046304192"),
+        MontFp!("19545292022072695979212345737390709562681030627834519269858193519314537426533"),
+        MontFp!("7543800958185166098093959246645025070235714806654195385000743554473922936618"),
+        MontFp!("4625475082534149232161405731224600013881237737740742465433231001161153655617"),
+        MontFp!("12763413547514960206069967971684559703789391875445961922711624474020658766608"),
+        MontFp!("16881857439631059221437594346198587121133192812269921863116602807689099485795"),
+        MontFp!("3231850141984038920375478271531925025564375094627983241106994584163478821676"),
+        MontFp!("12025284448956248150398233143375971306100458475218939512492496426861431663478"),
+        MontFp!("4525425011555084029555870990647344303529266577387030581756537915893006804174"),
+    ],
+    [
+        MontFp!("3795501990414375556011654275060518399449630146003978757203023617760680325709"),
+        MontFp!("4509128642188781738213513757207125341975604363824265606323571895455672910764"),
+        MontFp!("12203047758734592203615462366516348191118610129553686354997732038970187121007"),
+        MontFp!("13638687018405787761639420673973367506339916347486774445588648879776248875396"),
+        MontFp!("1480604348496719441038523415355764543336234934845679094037807499653490493865"),
+        MontFp!("10692361717579001228187212442602435054487474561573499974105521910665132275515"),
+        MontFp!("5007773285184019093586454921139763432640822738583548849737728483623941935472"),
+        MontFp!("14779176361271780821074929551122175132746249520990325894754727749064980218318"),
+        MontFp!("8810869743928256631039877155210471039332035226261305285283609913565228857800"),
+        MontFp!("18154173501970140404154140565370264376529820426626464552253141823874364956900"),
+        MontFp!("11656303509442573507998921894257944851336988233800378817636452696829680890350"),
+        MontFp!("17658875741559155615021995850723408238216088494443352771306394733637591342379"),
+        MontFp!("18902160612986281748775835015982844003483334222891067179969353562662106908838"),
+        MontFp!("21595310045201809412620701470963174488544609749637202383626402540041734306075"),
+    ],
+    [
+        MontFp!("19479000691333638193560008307308022198954460138615126342769742031420251871629"),
+        MontFp!("2972632723219534905306782557604159867920608086253824361540409985645334537267"),
+        MontFp!("3847529854347283694984727631990010016411937091308711649053729266492176961919"),
+        MontFp!("2403423343103741792664345548178046472942690163193710191494177026621927723840"),
+        MontFp!("4715026270007766325068951713831542483223564562870257986931331836552507312912"),
+        MontFp!("10050946066792704139911540686518561899625455529845988837175998569612056291645"),
+        MontFp!("8462612443675205626907647847435181314299724037672159352834910195653278990554"),
+        MontFp!("21536131862603746518459731430650379927614037990254564979711500405913555717860"),
+        MontFp!("7888269140704212528047592848167567446827917489655456773861487833453406017069"),
+        MontFp!("1170377169572693438349285053961454675716678619265656860173330864613871015536"),
+        MontFp!("19621259602533287127007734174330694952187330430513568066156633937834027643682"),
+        MontFp!("17165866032840109673438683878903959007172818091757219456373859826178202458170"),
+        MontFp!("14933333727688891927411002006378277183547380244506194877231532901665762194033"),
+        MontFp!("9343867619319423527260818064767777717418825720619434299636538864535443951928"),
+    ],
+    [
+        MontFp!("20583768764298760141290708372295636534035063275069820261065362331153347542719"),
+        MontFp!("2577125135040205550039517386386446603255535410845714513971957864613256569227"),
+        MontFp!("17000415305135911202592785659979381300200707282766529883690542305214226820231"),
+        MontFp!("12511346220893686853445962138128902612419766727072608028194026193211924059518"),
+        MontFp!("4149764657828794758957335209849218835702975200988138041342768571619211624011"),
+        MontFp!("12689460200478357229673533402655824055278729189265502524126586766684281271735"),
+        MontFp!("13400421547966647260602415862775725767930791927979420926402318903131836007467"),
+        MontFp!("2276424802663141757392692926366037451925020546827329479674513290273559843227"),
+        MontFp!("13207120510498253902527141512742067250437249796266587317279641215901655830896"),
+        MontFp!("11305234940211349140158920206630802129159533495623653407204084399910372957174"),
+        MontFp!("5497774303468738593952657049388763226915569613134333653497976925327612433620"),
+        MontFp!("11780989050042171707300156320134237383624870460000896361633114513125178671303"),
+        MontFp!("11197132624111031475729914540831613691695800675640925983729890209473799714664"),
+        MontFp!("16140443794881450163624886684327741887016098106725788249967592676372285999349"),
+    ],
+    [
+        MontFp!("11051595327889397185166361039119529941213436445931880650633610124485963060123"),
+        MontFp!("4836646605031570157308209197698105249544981108702254887271797808619270080413"),
+        MontFp!("8209009069949005083422618473679185090070432230901987251869909206753231145136"),
+        MontFp!("16474909562767173375575972470108350369290532718799369728226238753027802729614"),
+        MontFp!("6086055204212098936385823679317209054285543683998660974885049732319143968166"),
+        MontFp!("15845663692001601094665565765687773803894441337616861842355695214969875896066"),
+        MontFp!("9098420601018427903226168857535773321796962623728010568581252061114961655465"),
+        MontFp!("14398020486027915166775907825392652502550084557499300430668649177321914768934"),
+        MontFp!("2529766650486869899750453189656713618464482405989854109458777379339220142554"),
+        MontFp!("10340604539765718682878802989600110179317791699890691229388614321680034249914"),
+        MontFp!("9878876288226000720310995235539201725902546228232202248134844052052896121876"),
+        MontFp!("15598631334601739991411156353640870142866768421111507060679644747315412727160"),
+        MontFp!("12476036027414150699365888926628033468852849122092016651897900659667567319806"),
+        MontFp!("20803031063635652861008057675598133287087428947752503544588637173888166669892"),
+    ],
+    [
+        MontFp!("11219667600899420706469792543049361323110081262796605270588885844619718041942"),
+        MontFp!("9239500483618882938511561535687911499489200513580468310964041871852656042420"),
+        MontFp!("2295137860333409753273134561401486593645236761709753752653034489900637997862"),
+        MontFp!("513840239484316074257070195372896343613221099656421936676995152626965728304"),
+        MontFp!("1240423160571920455132480263739570027604064696559298170495781456216075158172"),
+        MontFp!("1000335770452092111597579881696386094005166181014081250146819592415429840281"),
+        MontFp!("17732477784640275643871498532120858657597895452427536145089468999578047484919"),
+        MontFp!("17170981021801009209909418054936322243367033572422755593175460758882664580508"),
+        MontFp!("10474002915893131225108722048498342055539813455822038310171605686332057287523"),
+        MontFp!("9603348396520941909404735719689357385027896452682306755794683717883589365434"),
+        MontFp!("13997990766251226080324180880591113480591220558468819674906322487254600879864"),
+        MontFp!("19165322589470665586312376362321121865643126447966933590462565164041476345514"),
+        MontFp!("15889877351963901365648141054999234691357421706029208812358207119735767519856"),
+        MontFp!("8668047067682657740038874191537478915165931784511538119786089297082841482372"),
+    ],
+    [
+        MontFp!("17366185630903586492716760756481097015828466883331247688174482412238176387717"),
+        MontFp!("21876355395221655909209577718688727663428003322801196411166438189091863032706"),
+        MontFp!("8342495812890419318878301820827026193204690451839354912098079931173365213850"),
+        MontFp!("15887225707174335160711915570149252929623026695369026542224074924735308390993"),
+        MontFp!("6378295267974387238182583304654614405910401210077434367173120406371155400542"),
+        MontFp!("8599288341856208016511446109829802753279910611437057243892303163654271528710"),
+        MontFp!("14384569526991087242726852106274677884712683003324629101047489219988189168985"),
+        MontFp!("12554858483255821944447983588851327925753928086067377749529759729841684476442"),
+        MontFp!("1244202115364291386279329790002693224358694897412189477392486275482043163127"),
+        MontFp!("12995340746860271768506969399635216407185990298275348495956441603189500195767"),
+        MontFp!("183027008509741755078232756367286076025735908110463758323021771971495606238"),
+        MontFp!("3573731114015522988150416514182944755210001235963017910935079689853918043877"),
+        MontFp!("18230391763740278339288441222957881377176531304894832542129957275399074045651"),
+        MontFp!("5937391935832132177003018978899438941930145516233683431615177125757010459652"),
+    ],
+    [
+        MontFp!("11430996404641585372503695535042106445550893936697962891622779166741655729488"),
+        MontFp!("9708207568505790825432073778606679121879367766370067169784170835562004694648"),
+        MontFp!("13398805456168802594406839007653458669253514721606724093311019328402863953525"),
+        MontFp!("9509172607193998955864433140854270981598209332315830561489225794322679022459"),
+        MontFp!("15502257451151630587420754221500874210427552449899109970701467342791400645333"),
+        MontFp!("11123898636843146818131986172543146864729611142704598768652657993190010179131"),
+        MontFp!("14756654073235850387277128827870550186598788932163678015197111219905127222330"),
+        MontFp!("2775596743802877046549055877116659535653734622828894792515801349479916697007"),
+        MontFp!("19393614735747455293764838818808226619563895886462373669050764399041257455003"),
+        MontFp!("1249320178045456927170274664240781660345447736835400684416240818258732622074"),
+        MontFp!("12796406917637014666646024992328239080230785967810042823382614274279812889313"),
+        MontFp!("8731150020101451449969760761271583792743252060005019647239509560664328018351"),
+        MontFp!("9933789898048906634037638211430912333233899173340437080629891912049447301727"),
+        MontFp!("7466451925704968193666093494894304477671721132981190905063657961063858961172"),
+    ],
+    [
+        MontFp!("18149794038935586919422942907608353967008376245075544434132167909292547581560"),
+        MontFp!("2645338086907790474420268058095037943265764190369965623902938950146047558570"),
+        MontFp!("3805226260777608297922168382052882356808083423152211373837998107098065055539"),
+        MontFp!("4989302370880302660583716899846990066057960576662320219898456979014043515025"),
+        MontFp!("1070415851697739963236557454136444292508409566097266743684211383600964969141"),
+        MontFp!("12944245506579810779814853939016758644439439209107032516878032396119045414701"),
+        MontFp!("11769349237256342071683275569967616595926207710668472449278925821680308524365"),
+        MontFp!("7515880964846644263997052091312985911975830135506678004303761824155773875060"),
+        MontFp!("12464357909913670578721898201968110981078170975300784494985401212355213230335"),
+        MontFp!("12531063240134560397069746994123636136169642575111678588073390651101761449521"),
+        MontFp!("6147041690306761331612194490775813268707261569782473045839636103273693189609"),
+        MontFp!("20058953556654404269207947758850631052629168082562724690664696238258819343333"),
+        MontFp!("10671449076032051862717464872993019906670729457438083279869852502398951441572"),
+        MontFp!("9263778898906682528709315203073828182663073896201474787696895934306219116039"),
+    ],
+    [
+        MontFp!("4280018790033654530253273135477384827165889895493311470077828964783579313988"),
+        MontFp!("830995672310831856642518343627405480722960393571135174568745694822266602592"),
+        MontFp!("12314576340884680561277987296413286687270775984122709242612931854296075085360"),
+        MontFp!("17095784374018350334250759671947431499861842886424783004809423482855754115377"),
+        MontFp!("1870276341686143971262732849057691846357227120913088159474346497974038463590"),
+        MontFp!("13105414947588023527779026549450388391878972150198998416931943855651155797800"),
+        MontFp!("18101033009026370452679604491367700242633788731529564744613454397646795133212"),
+        MontFp!("19518878118392884244142849835491751668891129028130325012169949275103156284312"),
+        MontFp!("10048097099868399020473369161831917580618232227587941412911792567651718843249"),
+        MontFp!("13720988503976110065971074438615366180970893755318530444813194189426744566454"),
+        MontFp!("10206920761042395073417518236713913313307032364045319534823111708069826397746"),
+        MontFp!("18034670825630502903409981952109964720282628088881306395156166316305807778803"),
+        MontFp!("17265902307488235822005808880948915593987424296023174321440226533612013329935"),
+        MontFp!("9429365481099850064016032865663594803605999180077296169535937496741805848481"),
+    ],
+    [
+        MontFp!("13186685927810765291293578499657740344232117785406437983769041709812502541933"),
+        MontFp!("8033401834359804746763731113146503141828920003868357417085510169143014612168"),
+        MontFp!("14829873372988191912376824090708426813704721449621613864198115299064092181833"),
+        MontFp!("18455257181054398358097306328798734574318231150508108527392947186444436439740"),
+        MontFp!("8119199559569330746396633117116309013908934582593556748077373019932279318500"),
+        MontFp!("1284108314616459045915004998457007728060914233392140668856369685125999999332"),
+        MontFp!("21852625948025981910963631951406283302114607467722317806583142816175293668265"),
+        MontFp!("14294796340840469579766310516350659401625921390409030932793069064155351769891"),
+        MontFp!("1783113620816999591947201004404230259494066685648885624939940386561651976668"),
+        MontFp!("20631226309571056417714477834545747594141696200863258945898766619654143617824"),
+        MontFp!("6903938062054591003542771080232078653298889568458433305746325082721608484955"),
+        MontFp!("8256881116864939888001716449324731552833717991541507741291129726788047573071"),
+        MontFp!("13687405621147199886679388919812090637070670174751017085656416653029285459311"),
+        MontFp!("3996906960213710686419471713152378445913261990655942335471489495797584984773"),
+    ],
+    [
+        MontFp!("18609485914058202995712970621084391939086418713703752883448366703199989106805"),
+        MontFp!("4737790485362149715518348725962346663474900881645708435962411601476490671749"),
+        MontFp!("2822552925802124863166593828171280409095683790486058849977885272116463701928"),
+        MontFp!("10894109234909999803847798036461479359595641981247612273970502633226141682614"),
+        MontFp!("7246294570777241116302579516225659482637719855779307083700855528941826872713"),
+        MontFp!("9013198024264523556163293154624511496427558116561774530941327330691750043628"),
+        MontFp!("15151960056770776873319338589377108700463507419234056013408936730718825997019"),
+        MontFp!("13896307757522598026854039348754398062744662686124720657491498342048635150631"),
+        MontFp!("936098917227958663269717535799277732372546413929626514061262842640907378836"),
+        MontFp!("5762347306349503820330412402395950955095460299470544346332901816556543923381"),
+        MontFp!("9011672463961728692091882997147124758454879881989902775354620290305441817434"),
+        MontFp!("8298508790377315029478690089877957774356278876814712068800423484382883105746"),
+        MontFp!("18441738918640842202469737660313781809854248733890462980130113721522272925935"),
+        MontFp!("11674356714078179732189659380227960024612632400322071192164520398757529418670"),
+    ],
+    [
+        MontFp!("21741531861742185590228910897294372846694047325677774428196013372184305103642"),
+        MontFp!("10726909710822683151420187237143914110905744739798587383554354069582246136039"),
+        MontFp!("14605794533892313551961292244462849563801666394421863533658628423573613615452"),
+        MontFp!("18252321089326369077368015604893296999452816437445326292408407276685442204248"),
+        MontFp!("16489511737082127400599277690755867907347415222776413612685047279770378853014"),
+        MontFp!("12335271216576477339942530570019671580774683415225712107993157387028894905764"),
+        MontFp!("4626127015007086021549442631728304324061443778286284381465764558652325702889"),
+        MontFp!("20204422163930758830873934092037602825061261159909688022442254278786267559979"),
+        MontFp!("5908008139766198991903649413759936674892226062609391695798412118889649830013"),
+        MontFp!("18949218162516421406624855541340518738013110700902674485216824630035967277648"),
+        MontFp!("7857158354961323527996104954036841386964344142685428573605787682122921808752"),
+        MontFp!("18862506387045373228977985979058776771998946696427211846227485792071994347569"),
+        MontFp!("21130307920872379374988363214010414716464915978170248808030666261671221223543"),
+        MontFp!("4313228149429751211745340097430041420420928800665815874510443032063502322102"),
+    ],
+    [
+        MontFp!("14718887479978402714517595897896626933964355973039392802299204272000024809808"),
+        MontFp!("6557683321264859885031038170253446373377122179252187766556430383002360480784"),
+        MontFp!("17122476831292693338874176268140351589680349774087615644242938775777697017452"),
+        MontFp!("1742427015172593656128486448061422456944242303531295475988475807736737942042"),
+        MontFp!("2027235762831193006217486738647428392424417413253628143597055373587736173882"),
+        MontFp!("18820467606179165158872620834655197843967670286762404094202648563691406576741"),
+        MontFp!("10125524296683464483537740904779830909043887085914486254166411012113004643542"),
+        MontFp!("449309305485238804235855079592488366640797157061432847068620096879828248038"),
+        MontFp!("18174298857888178621780055946769726501913338858930582293820343672195281946232"),
+        MontFp!("6524685458133323533884653748284163312203275737781993034758692071803006590911"),
+        MontFp!("5779446108310889204396522202292098396120321905868746440329327547028173740638"),
+        MontFp!("12117601207346032264472777912492513317230650895345305236204051403325746076935"),
+        MontFp!("17386891799362994472985052445984141822367941805998642899269245566076967495748"),
+        MontFp!("9179366996073356039318771265789433069240332535719540885945532359919512410546"),
+    ],
+    [
+        MontFp!("17951430600208154118507690771734790328820535928178248368783575740656844500941"),
+        MontFp!("14192271756367781523935569988212364655718978403224403387301537958961554173399"),
+        MontFp!("8221174165933793163901377098818684602634389528051583218569555199418881843300"),
+        MontFp!("619524678975534727395248661178123212036053322038080027161284774894885656968"),
+        MontFp!("6811391538547034359716390364062047260775039349151599816482953672829058524341"),
+        MontFp!("10339322550348888691122766885623110168053574406341544663827975995639398303045"),
+        MontFp!("834547622517043358034390288436661677632262995429052976763390684889069201500"),
+        MontFp!("4521430246419400790694482286290497928487157243703675820424059696776489816307"),
+        MontFp!("2856429439451475378239016597030439586695051951861405539943990631405466607827"),
+        MontFp!("4111906549409128541352401705521383327041723912730932911918947847640475304547"),
+        MontFp!("21238392279368838153073998702772069142756942255081903718049634748881681359750"),
+        MontFp!("8024413919212120225608654701439440497293912481641002166919294547045993866155"),
+        MontFp!("19638917754035941400580825054915831416618623037514650566052024405861982044782"),
+        MontFp!("6363298127640737755043533904928034878703559551572444680730376201407773738099"),
+    ],
+    [
+        MontFp!("5951378579926778764731263132308899303911912211097125509228135617058018449106"),
+        MontFp!("4299191983632622048727922852687899517860127872286225781380066638217198421795"),
+        MontFp!("13147530014153459600370443470203975351218206668086180214214011567772265521187"),
+        MontFp!("8932462500427500118784677946351185252169486521981702322459979960456999825085"),
+        MontFp!("12898236202423618599592389067167172325929052486002381527830813712396261626227"),
+        MontFp!("11097514049007489440587570256998525432340163981998515135891661207289091581098"),
+        MontFp!("16939217257331433664860194883658557949066598882598231827843396489027582239493"),
+        MontFp!("13558314929316884214321653422030897165706985165664325794343497050086242614930"),
+        MontFp!("19068785933927002693740188802713137005358154768592984493993019480794959714772"),
+        MontFp!("16219047234078325455733500620647043268680743772219676241027699492382953851822"),
+        MontFp!("11566125486619880337971321232314420701391596877779136632592874470668332390604"),
+        MontFp!("20247803830618639726625840441081484205981495541890152476410337155981008644211"),
+        MontFp!("10982561928418184138823352638412764796110898816926174525705905870653768875109"),
+        MontFp!("13865455597687551061085338688013943905732507438297014744008229213490729171094"),
+    ],
+    [
+        MontFp!("4623686187146061526075222395870304599606839445854872221666055672567414423759"),
+        MontFp!("1297323969860517810025547983969849541225259454470688019030850877270479215579"),
+        MontFp!("14722215188887952104786572716523684038024407798912988920497316253897978502603"),
+        MontFp!("6270780574886929570568937279666637132374218041131318249843841984676427785096"),
+        MontFp!("19252799885324088942740034016032302070594496616562387546044018090542659494091"),
+        MontFp!("18789886326443791197255838067795128702043473536108855579944353350374719120963"),
+        MontFp!("4004428942869607083699086726311786949460607919907288923307322356602295441411"),
+        MontFp!("2877506877897472797549134443444956093653579146599656174810841010891875571228"),
+        MontFp!("17100820986561348145097462952844272816935030845535472396118435485954197036467"),
+        MontFp!("1347748684215721199150368750223253727950390142032926135229664962610534785585"),
+        MontFp!("4154005697105794859947043472401749393077233408100643568245878899626874345951"),
+        MontFp!("20933456016305992700551258430315019036248529816811141758791937748005878705139"),
+        MontFp!("13670472427221544431486503737474464655536253995920989690199387492221273597238"),
+        MontFp!("4887586768965099882782231950154450807371608927535862225573402423957578253712"),
+    ],
+    [
+        MontFp!("20873520765554306935202238707923195971241799663989834594965009759362455236319"),
+        MontFp!("835408880898587261190035308896108471998627156220026699783039947254106997118"),
+        MontFp!("1168560772528590466033559747490971813513190425644816736321133758228053063362"),
+        MontFp!("11336620716299236865855310989061343228509583886619209006261263862998075759368"),
+        MontFp!("12980812954661261543443724949390081395650693161075132555819949729859873154552"),
+        MontFp!("10084252406967656001546402499122907140953995723236718543311202294822930374906"),
+        MontFp!("1644532730355076490850165587814950390528959259229216666472798276024619697665"),
+        MontFp!("9132885938811460827145905853169583367514769405887568204387077749084430489477"),
+        MontFp!("2725900975136309543580364211869546121021875237274249313229081305044972484552"),
+        MontFp!("16649132526365476017376435851279563056385888284228253635122650167497125722498"),
+        MontFp!("12731917506454877589272897239554773638029036473865906141458475592969608419975"),
+        MontFp!("13594653727945200481903485136384279897657772670755085103508581365171774040719"),
+        MontFp!("113624989360858461940510511528457240403880397732971520793552365714387680115"),
+        MontFp!("7789436153009366426158912863312519977840172895511546817589361831513864987768"),
+    ],
+    [
+        MontFp!("16143950714560148477045714466178426422461079160482754992311521791041688103078"),
+        MontFp!("1313437798365489562563299651704767522651128839986029835665434483203980839176"),
+        MontFp!("11252213995691856633725287186457035931029344732702134439407400361778220541650"),
+        MontFp!("17736157138183214282785525992210357080431726331346127883418866302441793685861"),
+        MontFp!("7180555369115773227093283745784562042441101566557650242391581159039035173198"),
+        MontFp!("12210449409908576791549312007483796989239766997007433768188337654528776461829"),
+        MontFp!("21645736522624670721457131423612680680560891552619001261475935027688037968464"),
+        MontFp!("5181231859976513481992166491356437447372643837921293039933901630631480168455"),
+        MontFp!("1822647984377309820943781894578040991064004363022862403681677456868041924061"),
+        MontFp!("18871375493748399832446064841374255973869421566131394074253151764055941485971"),
+        MontFp!("17104418810613244184376069439022653413672092602368197196666088607272531673120"),
+        MontFp!("21352793177578730013875622823471064860889253282644816974785905687738185801553"),
+        MontFp!("9232438288408252188083609458343687653050330220889343945216702072572457878290"),
+        MontFp!("10304159048240945355136802455474936548590756836618851032143486546904634881690"),
+    ],
+    [
+        MontFp!("20884395191144673183897559585241012685606665510456998263218110825947409467914"),
+        MontFp!("2819516448715556874313717913942313640504691377816634987702668983716292161632"),
+        MontFp!("7032608084905515886212514778954491987158299018684745883187663518324250619807"),
+        MontFp!("7559036428305013706113865872782652760220550823937161831466421604635377603666"),
+        MontFp!("6166475941379369323404906159647871547076193215494151224037609592313271326716"),
+        MontFp!("16084803679651600781285997577643662084204923480676236051120275962952234677286"),
+        MontFp!("6658815199061181195201720843092877884603511322340036305618980989430727251871"),
+        MontFp!("11532335040368550811455379206918215244397070466594500713220916970398105300183"),
+        MontFp!("223243445248411375033016341241378810903532891138952541245228108341484828711"),
+        MontFp!("3833279990355432746436294779672706393587026552206068860301259597964955244359"),
+        MontFp!("20021860409670140006280261770552932742716715786145482153861376747693162890815"),
+        MontFp!("3960940475385499730462877864983553000107482077213680397288242993303568324972"),
+        MontFp!("15345391775733994458108231909936015494312151716517317512597014646307002937890"),
+        MontFp!("18277195779471824870812914706668217603589292191686932445872231693455894425796"),
+    ],
+    [
+        MontFp!("12683998657212817923655738438553698764065257310760896868569072930043411914592"),
+        MontFp!("2381616850898069714423289625050293940364468416728632275660762167225224591308"),
+        MontFp!("17069254001556064617313013226218540738464402615134272312196301018778835133248"),
+        MontFp!("18389457738365086195717208767239120166429330517506018082718782715649157736034"),
+        MontFp!("5051258247116083013584227503078370155132486498229823799712010421955209135014"),
+        MontFp!("7997986980169935581373258514853533781209293632678320920663489466999553296266"),
+        MontFp!("9084231611273069170288134842362168520424117219722297893241580415854843050029"),
+        MontFp!("9612017102514059830687104062100896777017998502712927457146148255553769995330"),
+        MontFp!("9213917869163068338076473790268314749980273391311658654819685979854193078147"),
+        MontFp!("3968414534544698110694188177667553264094402034823447016934487565301236025995"),
+        MontFp!("13588240166210590726886944410340082403890007139576685347416857458181479170063"),
+        MontFp!("8425770643762906660570072207190993414304346471106950840911695090319487814275"),
+        MontFp!("1652806391920335323990555627698612547647520442841733871570123598479115999627"),
+        MontFp!("18130776043151518739068856550215769502964647168112043888178493846726550417660"),
+    ],
+    [
+        MontFp!("19135684176515473484108881204420762325009779099777199113513850601885029049659"),
+        MontFp!("18523926306415003163998391622948236629256503699057039444767412461271077678701"),
+        MontFp!("14918281748682691271882204320979112338671695483566718123583225923120856316295"),
+        MontFp!("8780852767944315665594165432479024772720494001116664689088446074574987494813"),
+        MontFp!("12668508386943347180515022360355291044387148272127255261058463414454846963242"),
+        MontFp!("4925095646155669413280350202274427260281996843455607837164490899877054944846"),
+        MontFp!("2595801235413574965831438899756848851934934001108314616806171013774086568322"),
+        MontFp!("16016207465062828688275342866097975914741628027802243675066228993942777558633"),
+        MontFp!("15024416797683381415563755573423733835281624847950439607885361353822729812986"),
+        MontFp!("13470695612540254635863734898909669181168896043111281127113964919743317383261"),
+        MontFp!("16896649112088097145823038767205812267673142507502911459577638240816340032813"),
+        MontFp!("5645009976160313260833270091943535119457478455011072475922688828021639421410"),
+        MontFp!("602871159134715892643983949134724748180211299390344137568222174867411670803"),
+        MontFp!("16865933848430240952665445021880291168087114172384742393439371745277930120747"),
+    ],
+    [
+        MontFp!("10638616947682848797128584610899807071800790542581123346366834113610995491100"),
+        MontFp!("15823119882352004895965266205059246906297215947121418230976223923682971872516"),
+        MontFp!("6335662959372125109139397987508104273775067107064186843021683240893850916670"),
+        MontFp!("9015302195224176847832179354591500534302983921972567063156768309852422127379"),
+        MontFp!("2916208839246568972516202976435804092919863447720049244313355052730101892832"),
+        MontFp!("12462097677706957512887764105458513125712391543206668564860335255817545411462"),
+        MontFp!("12645432056886268747459912808478608940565989560801243474226148714998374680219"),
+        MontFp!("3180920336675161838114354449885404230480750260525222632193238650778186030194"),
+        MontFp!("2765038069658052652090069059291472962961753315554068900506936144102952443412"),
+        MontFp!("5456153930594691794518876425254629839890061712982880399455001410106283524009"),
+        MontFp!("475588857464770407073340860667255085025964024669275489020055454066130988320"),
+        MontFp!("11604422213771358649337761367286746573670774367844043829963235653833716835011"),
+        MontFp!("21672531595983328080827235954964353440581013778240609331813180834517858151965"),
+        MontFp!("4158282767336475980124978163165153479336138216933325437750412278000398054990"),
+    ],
+    [
+        MontFp!("6032343652765967766200639763117076693275022773598878447445516751911914770612"),
+        MontFp!("1408994971542022766817088410277637989937031937486427645416181643240579694602"),
+        MontFp!("5675369397167644827707774427966283386689036453227097867563588663607826158481"),
+        MontFp!("16553836408556819911384314376537283029998074714786222283151724244687848945589"),
+        MontFp!("11305817545614916257748042736988000193121142053044277161651567885557438654074"),
+        MontFp!("830935697282120891923075627410843569046784310505888414612692179620161520408"),
+        MontFp!("7111327705324010951262975966073430392141084274376586856008212260205185475707"),
+        MontFp!("6041014280799995292240499899142298082182090482839684923821947404991902712715"),
+        MontFp!("6861532079489296739625909197765090537384733717891476126939216899495278356435"),
+        MontFp!("19512767336722762932902171703888917915267449700217444230295060094846152793374"),
+        MontFp!("11362087145491820831916999481856548088963000118526088967383804499026380829346"),
+        MontFp!("5345785713105818128869449775136443413167434948951799284901802489769456439786"),
+        MontFp!("17034353050546517551433657764343123751855407033689772581554238132654775360523"),
+        MontFp!("20948587704598096549680941899688868509854524167514075706787714294359604474171"),
+    ],
+    [
+        MontFp!("4994911941995400553330254818898448719600721576253446166237206475949959408151"),
+        MontFp!("9688152172628114951713346942216519356583583295750612146456768853572234580639"),
+        MontFp!("1343745797764784294443121474436370243518251011725658365765145682550385976553"),
+        MontFp!("14125032499992691719199426991191077310874247595141214711018898761632184485555"),
+        MontFp!("7693434862282992683488101124262547552730705787109948156284399632991566403920"),
+        MontFp!("358969526543359888045815665915036908949414956460937733680920722686744813444"),
+        MontFp!("10388303602140838746049100800433273910870825475060431768126678575590491651119"),
+        MontFp!("12003019672927505670536349874007900046436859031431459357691867861903891807068"),
+        MontFp!("100475008966885612365204906854412018822648141035087921833385587863034960276"),
+        MontFp!("4380976634270985228665957813264807511324931231158992383372104805973515660993"),
+        MontFp!("6796890817241810905532067210297629305992402701618394901609217064231507595098"),
+        MontFp!("7688772779168713577464553940881893522916379475003769246647902913600123216803"),
+        MontFp!("3719641128095581959416223705616297841752773711170097329183166123897200928969"),
+        MontFp!("5811339040244843745049016703193592081836868671814750017107506365287109556605"),
+    ],
+    [
+        MontFp!("10595470132919677811136446867941153163237789139416696690218064678877242827885"),
+        MontFp!("13030722908918648575860840375066614839148197698661025042088986261543919080554"),
+        MontFp!("14270313844057453712442736904892342369114041625795108985761645871570953543978"),
+        MontFp!("20755955200977796735614094726104970512145122846293966132203844248531587786651"),
+        MontFp!("9582579815411229200777590264696860160165980649555432144848137253789895405534"),
+        MontFp!("4350662320089661964064338788392477540031840065706369837322029959874552780000"),
+        MontFp!("11294656075067466938178599864610454390092206885966775647004601601555888026051"),
+        MontFp!("18946972646213006149468901853070557711933198234858050136328420076856760929327"),
+        MontFp!("6183788518442229735210652665497651321434896307527846329693517674734369795163"),
+        MontFp!("4357012315121006561105620778754114894493832281520086713987988030701496525309"),
+        MontFp!("4220425144490786998849301484441980495606132822919030749888073325247349169938"),
+        MontFp!("14847262709636261703845309954203315918152776098063861666522433996841734492983"),
+        MontFp!("2207604061881398535333278399637137320820424466179707349350037157354962490429"),
+        MontFp!("10374520449793714488752224568044645973292278262497536584844249765683309087900"),
+    ],
+    [
+        MontFp!("10046752763001031505421631811000499551831682948464981948135908582555522139635"),
+        MontFp!("10191830759680421095919984376117799343794470779618993576052513294297834150862"),
+        MontFp!("15076222662591944075232357720469485509899269012588377631779056491168825321779"),
+        MontFp!("10030346249770809877262359992756272056670319300684833869196768465826414079904"),
+        MontFp!("4720507975531560789365265597577186025064384475474978173798769571327267599028"),
+        MontFp!("17191621856092434134985786932980111138837305571579695662259528942970338620884"),
+        MontFp!("20148399372131987633026319910847242205929076170415419364579318442517450329463"),
+        MontFp!("18536741510173922435024204045457609239829288683624474544615917164289358647693"),
+        MontFp!("13631750264342742049519923217689863850040711017202360015936348405918551837067"),
+        MontFp!("15610854494548438731317902472235239790464071411103741562289565846725908998069"),
+        MontFp!("18387668285066173974072893371855420071639764562065930624890442086525580978084"),
+        MontFp!("14766673420520500288514312076124802867192062521660406237863986898959550205282"),
+        MontFp!("3732185864446238105520935485353063605521423641525603230547743875428671598696"),
+        MontFp!("6726861976047081116380559824755952195618930495454092484512720173680677582718"),
+    ],
+    [
+        MontFp!("14230102015194545129058653592673168907799074203765469344837164009883877641547"),
+        MontFp!("923698051441061755360657704655014798009775419003226945193621553062258046271"),
+        MontFp!("9689434574353223411824004165283477169808920117596652474458537007050145695959"),
+        MontFp!("6653548670473374668472111797166160911158340899061222339537692495508721251614"),
+        MontFp!("3795538039750942962828904073106200577850807348658267681985654384336620251059"),
+        MontFp!("16992880538370345570685975897913090022139738512471371596260168908478239467806"),
+        MontFp!("2201111019550353407862043052634082471477202486461462021878536956251970423986"),
+        MontFp!("10288260758300652435946350416864664136108249309736639494191503136475372654927"),
+        MontFp!("15709483617520776431567199768118570957603227217270596124343406544848127051229"),
+        MontFp!("15072326085023560939831375504206736581668538451241556883193631319058350825383"),
+        MontFp!("12561979699882391671048614617301093740097194439650447103774288376923816448076"),
+        MontFp!("11578577676104716528266269909528660737784973170422409028333858070522660907599"),
+        MontFp!("4774024940257712349371248845501840009544530180895035663519273410524858053238"),
+        MontFp!("6492684254039247711120616647406430763803059236762636509335603649550553435616"),
+    ],
+    [
+        MontFp!("20771468823570726361366818215861608649059576818091620623157713556101748134372"),
+        MontFp!("11770234809483645852438195876591109251904813195909801267796013610714277347278"),
+        MontFp!("10322220982623441869990996687272667519839773221084466704263092761447901512117"),
+        MontFp!("3873750796549479014998049182061589742243147534079044128452565125163635863792"),
+        MontFp!("16822068107147748367260830510353037256008422122115738671496285709779217814945"),
+        MontFp!("9894626898191884524351933214321095257519558205219922050393534754827614996074"),
+        MontFp!("14601192892171927905999982540260020693712306290426245380657926166256138073598"),
+        MontFp!("9507456803001734658737641235710642159379435011566715643921196640676158132603"),
+        MontFp!("17204767985326745166438450937743889339302038750836189001173941240117259581627"),
+        MontFp!("11493735704785585407432943012768068237407868899310349385901453142043105992145"),
+        MontFp!("20264601365705944529765978188292560353562052194559042151183990689436087045415"),
+        MontFp!("6396166643103201291451232462310280514789516631380296812005611744416636341945"),
+        MontFp!("6058445133237719937803819124490281802427048168421128127070586670707895911082"),
+        MontFp!("17832742609350217271111001369998668554917153176201832135065834598001176606174"),
+    ],
+    [
+        MontFp!("8301910681660121964370976371283430908357731729231997961142961954342095329645"),
+        MontFp!("21200700180783276860226125147359033530111487784446376587831447494082008760372"),
+        MontFp!("1364486141742658225769231963526281625713690163326935801558529383187840699159"),
+        MontFp!("20160669267302086336804098028492246933121636960809421351182280337233197662342"),
+        MontFp!("3635162620064198915033858334726014494627531534027138539712906873727339940152"),
+        MontFp!("20185829971410353373403938384712968683319708682735072330111840928607967762630"),
+        MontFp!("201523690073703194002962504757428126542763652704293724447838128821998746350"),
+        MontFp!("9709386469732131146744455888595822333747765095732359649526137736669486544832"),
+        MontFp!("6214797208590500165345122859363723824928963832472111818012074756679462353515"),
+        MontFp!("9545179136572857559578932159310632305901168870225039498707457861463081350889"),
+        MontFp!("7958214991370581593237224161079745627463630298171564909746677637838740804837"),
+        MontFp!("10143975143960280812282386246733660505472918014880729933237691446362785576312"),
+        MontFp!("19887571710409013601609612720342170573208042935750400481652798519320490462147"),
+        MontFp!("4385901431531790517574018221866017216283585679251714390188554453215701245125"),
+    ],
+    [
+        MontFp!("1664624586830909279573419685895566313699996183243836877427547144869129575305"),
+        MontFp!("15793134038802395204513915871355486670799044444942914383804421551073411566881"),
+        MontFp!("20470704207635104884647349860077097804311221255557839038451274311052027499926"),
+        MontFp!("6142526726838860239230170135812194898945683787933736938595250804972260353985"),
+        MontFp!("13855192186192101570267811921410903075943095341965742420598451678343592386178"),
+        MontFp!("21061758735696246985536203700046768078820850320825204373370639318620908053680"),
+        MontFp!("5662817483820503797666808429029051672943117302178352551614788456216271483237"),
+        MontFp!("10333519634297417737172450445440333857302089078097476871967805971949123871529"),
+        MontFp!("8218569982501149814702324407474792474686368395700170296571212557818282461359"),
+        MontFp!("20233400137285640555195780437569385053497789288081872387339896466294226099158"),
+        MontFp!("10072372834150094335098617649200237176564672558260325152023334458902878530776"),
+        MontFp!("9266800412877476308026297272156162694485705323044163059710847355278349659379"),
+        MontFp!("5276337117659173276438760052296857124207008171563166875174912047683077401125"),
+        MontFp!("2006436301001583347899849869571017812498189722949517889639315185888688453745"),
+    ],
+    [
+        MontFp!("21001242218121797019239763758294108685115914209711313091446808028690842184156"),
+        MontFp!("15200074016374430224281445993551091543367930754541238817021042732526880610544"),
+        MontFp!("19710826528876999823124699885521051879572010811534557471867248186884736123929"),
+        MontFp!("21404362987802009741223312847298906544768900296657310719715972255986007016585"),
+        MontFp!("7600530036229891939971232581797836974827849142680304648314541550980439417795"),
+        MontFp!("21632454463616985503979878539576013805183337895553881618120668190150301665261"),
+        MontFp!("6334758595723856085036854188165101506598081801208405745986881361560891199193"),
+        MontFp!("8057222251861033664666134852961391247267479839150238989503146436301385103200"),
+        MontFp!("3219721852432286392418844864881031568045047266727931843416507933961433025545"),
+        MontFp!("15429997317679838542619650717630685653076454689042566593207301547187192181410"),
+        MontFp!("329174031237742757022307048272693803097641581155976690924186132266202912753"),
+        MontFp!("21732746139483704151357074738923965870601454140619898689390165872304758524581"),
+        MontFp!("12807082000874685484816248695921308340943213652025179667821051958845707861874"),
+        MontFp!("16064026146032729000070008033632737632876654216683451632905581465015365908257"),
+    ],
+    [
+        MontFp!("4212217153278443796283906098959531995688364954974810176542537190766858609818"),
+        MontFp!("21143229289970553639173591837823586981543332391397328546878889800049585920625"),
+        MontFp!("16608191574158019592113901126750079565651977188938492362618874182536574927398"),
+        MontFp!("3624421885419003645925287958934125046517213705659854889615838255824651468218"),
+        MontFp!("19316501828231774431815118527212477451661840620435061360743197812130426231605"),
+        MontFp!("7662891999266634580053960517366506438335835205004858023405036760628949585740"),
+        MontFp!("16083409929948531376998108923041427957606534473459279085237388567102877584234"),
+        MontFp!("13084239861882397209044914415955882241492175271640738785828432132564815850793"),
+        MontFp!("18210156525345343590423422394059177051429214768712959878778772626339164549874"),
+        MontFp!("12315464041324428320313158873511378501430493994363943547122434404767709664853"),
+        MontFp!("8954605304811330146055184939317681861245665333018873540562258152334326318605"),
+        MontFp!("17293224339343743174562117843833611404589728024045173960633854068798646969854"),
+        MontFp!("12077428955835955218060944701333382577662880272982277398053344529073555194830"),
+        MontFp!("7126046228531607392073289758329125358329379287853920330519927955077178144953"),
+    ],
+    [
+        MontFp!("18588044234272060286987286081803360761513524944191766041826466047510554640918"),
+        MontFp!("20890448514466943340126201065304171306189507157689256667534257974304306634091"),
+        MontFp!("6801003349507087476371788107799511762780253146305342273491215439215969413940"),
+        MontFp!("3780619510354109448305575837112860323918556596060836509041441555683227828678"),
+        MontFp!("8767334354313558924867811651574408725653000725545478833889044301516199439630"),
+        MontFp!("21066572358677074507410523123848261256731407535120503516033231013762098795269"),
+        MontFp!("18362039042064137510549303809539961459419339855353944845069477034687076489851"),
+        MontFp!("8913523920256755688178316396983509800293437764919338700779034854771483564854"),
+        MontFp!("11723401432613346160205532396432709544892024270048469362382924649893252868073"),
+        MontFp!("13215697753311268652932970590975269015978051881025604765698398517094385751294"),
+        MontFp!("10096875802507022627888049762027565166660383010058121573194339315127497644823"),
+        MontFp!("20585340774134656767573027722172296226739825364200221949739798500696627689159"),
+        MontFp!("10445154974790139558211972190334915738383718443406895551366524953208899973439"),
+        MontFp!("21673116720904254079937605482525822519756033862602805679958950491098633276310"),
+    ],
+    [
+        MontFp!("8625969092860470813380520419082444422545844964279894335272441710185243798934"),
+        MontFp!("4025288875907887520103326512414690750293134922246866469493778161136380894049"),
+        MontFp!("14323499257030290650454481578106573336673758956871892054979745929234879010775"),
+        MontFp!("4477626471847050821212591107628903165841822822191648560679737237549532746098"),
+        MontFp!("6035678895480961290473069135353807694173152744259585744720200702817852056059"),
+        MontFp!("13087537929005319145430419950489154179166101298384003566794942619862071759110"),
+        MontFp!("15034538492744821867836124336381708186593112293438604485661643830336837149153"),
+        MontFp!("8805139564894882006885168301537536158944953788019925854273090624783308357258"),
+        MontFp!("19958581387726270611337524069699729934757503443234146496516617463929755531372"),
+        MontFp!("18594586877352581063978277344172832910008313781358399221576815775414535759101"),
+        MontFp!("20126420557250610569978010728469817957691099256920991551072367148797364737827"),
+        MontFp!("12608220343122929299383466116607513455913710378844501818574872277345002049133"),
+        MontFp!("7472029840435729707246144983643315764441672074448478483499047549570431985698"),
+        MontFp!("9145534859649655987690711448922451001707059796425112511085875363972462746054"),
+    ],
+    [
+        MontFp!("14833791973699407961392823324214593517418186311389016446279089810253909982772"),
+        MontFp!("5890565022258491346280610728283677536409667465411457717498759331136098626193"),
+        MontFp!("6036941096143250281821883413338948223454138251490770618188682101941802166158"),
+        MontFp!("21282043678033183106352077009071810571452663281954166213888935238324321673748"),
+        MontFp!("20333804372144772829649652869275200527604963503726633269029046116798625871599"),
+        MontFp!("588131164750999180590569033490334261814498911261696634224331209454245047082"),
+        MontFp!("6825251444721740074952317618082720927334521807162055931163012547596328197449"),
+        MontFp!("917697824543986394290655655205290161739195186499427989467675782006019774097"),
+        MontFp!("15041830455925245428849291972925354368991606611607025955853683450026323915411"),
+        MontFp!("18474239974098280549955893651580150846097530768719853315252027744284466692200"),
+        MontFp!("3611784158748153035104121136455977264222066374809674800505557964617331443457"),
+        MontFp!("20604659450393945231663570065906647078596881678092078709858521111095167311757"),
+        MontFp!("12029452301550033145724326108003255082656089254479215038003174967331098163936"),
+        MontFp!("15356676148621236051968257733981998213914051180019702757440018429006515803587"),
+    ],
+    [
+        MontFp!("9423362269266211501814985604120446961293044110825100766698681717935266136618"),
+        MontFp!("15899728330782424719479421593102373102438201721567285551304437858855478298992"),
+        MontFp!("19106020154064303782722594063821106692950347246115176981502414167189733529368"),
+        MontFp!("18908465093637028691441981127932774442154173676388632144231302290143899401470"),
+        MontFp!("14007866203893293106212925571168282959988090650409909185874688109550330526342"),
+        MontFp!("5342742216767912627176491444663860490435717482395442556665323768598286007506"),
+        MontFp!("3597106827456013501397704575480537495681742041904755766922490829038770978602"),
+        MontFp!("19345954862705630389044258042054882168728404897041130532396992253851595567599"),
+        MontFp!("13651761202893618441816264801125692678934609890284167560926428043317180108703"),
+        MontFp!("16448172308101926626190311626973854215995348130429072972101942893358814042334"),
+        MontFp!("13856649049195496934480457183055513754814884703875171340785294070612325977764"),
+        MontFp!("2593658379576055879381789234288074898757591156526450196693707683508079496462"),
+        MontFp!("12697568363447920404231218962893478531573733618972364303216707079428387821529"),
+        MontFp!("10578645587798138343532931014102095372709438567484299904261443385326645475306"),
+    ],
+    [
+        MontFp!("9492492460728662705158180596118275120458955222134204696298182307017420905508"),
+        MontFp!("18064023256112871749609279367679203109309609541901431681842430343172099602354"),
+        MontFp!("1989944422169611668560419735240024090899518972063746001231995750158992709746"),
+        MontFp!("19903230879435744155012664241474498197586597172440005182506995303093565641777"),
+        MontFp!("1728511243862152479724441453437511013527085523030423049007411340644871969509"),
+        MontFp!("1750200860549110325737825369011951368084185262869489413254703181129282667828"),
+        MontFp!("8859957468047988361338455823958226995655820770262073750796277641227321341998"),
+        MontFp!("19127783638542055071733839851815229696558338746338070032734486927449049698521"),
+        MontFp!("7078877955496438548183194474726026189811269821062629263278508622456589680639"),
+        MontFp!("3091324818623570240229136118759236075549426086908228304466341498471627762399"),
+        MontFp!("14195346783292343393569267444162421134560117165562018311694222203790784872224"),
+        MontFp!("7366930408724527902650425878366319531313526362168392830194685421619273052822"),
+        MontFp!("2217598702761121052299380093973147900304006951260118283287015270774119856107"),
+        MontFp!("16027808241528674736324615318588531063352235936452195394658431080548186455840"),
+    ],
+    [
+        MontFp!("9737799828204615060867766665537725835381601825573025977613959705161843611093"),
+        MontFp!("8064501393540602388259218046920616332801811052858376733153856073004571475196"),
+        MontFp!("16842422805430821078995618361206787780503480464556310995070028960079907202166"),
+        MontFp!("11112062472802799468234830097050518793604563281810316763737206541428998286081"),
+        MontFp!("3996143098194824093843968965659132847407504849137530967999278126450907191255"),
+        MontFp!("6307499143062236655628471047117923013144505466650278288900215043429516667827"),
+        MontFp!("13925586587992763438435497293527949989397459231522640308463502932206077961500"),
+        MontFp!("13087452945182453051277972085750711303320428980713041081211383304385877763691"),
+        MontFp!("18093207370226721487749634577199906992299418255969574006908138736154443387826"),
+        MontFp!("14272048411789712231464337625432600879032064592923947963806042930999866142049"),
+        MontFp!("5112973508688401012277318169584325529641020499005098236869086381676918059371"),
+        MontFp!("15127813438826152618698293752642344087924814601034550461410899877754994198299"),
+        MontFp!("6969695649962440087033894050377257471647257275605050327543911409064993144574"),
+        MontFp!("6755033946723836072389613036793975461823169166252828563832361834388266522342"),
+    ],
+    [
+        MontFp!("6630309846049050707531890910192358661458908514573775713066783149977348075866"),
+        MontFp!("19770918646745678079472470109308561511682083548143260940369403377403283034557"),
+        MontFp!("21433670531093667302664653834628387344084043216006808509497422477800438816334"),
+        MontFp!("20400712430451351419962326556923150417233487338363092310732550512855900177990"),
+        MontFp!("9914702749825526925254257687000920413318621895298404563035360292451257098124"),
+        MontFp!("18640006733688503648022700222053193091432820833842731658823766601630552120045"),
+        MontFp!("15001143407594668782401370718438100322710708950874705625981291998887962398836"),
+        MontFp!("6239670443845313966789296359213657096108058977356600364820415786936148776478"),
+        MontFp!("6433425223024186602967246363501689243341949458306873394092764835636608100956"),
+        MontFp!("4675918067976678709774913862522295500806856576135412363070396552169178386308"),
+        MontFp!("1601751749870408670839362316137379218159388455643443723425257645986433854434"),
+        MontFp!("13458041123361531838745263080315252882353509187267791623642428828275334206638"),
+        MontFp!("14471699013899259577738777309533960354293333303365529990782321200494073324178"),
+        MontFp!("20280430398960027135010448240285550337237252858424335652593355698129656795752"),
+    ],
+    [
+        MontFp!("15301671314764260006262782923235826735016067425075232061024349010859210176138"),
+        MontFp!("8527420415370243636986575303113397012724035052720519440118106033441076019119"),
+        MontFp!("3475125085974928986675306749785419160190213748540322213576708845513558192234"),
+        MontFp!("9006980194944928107206620529900294492273459999862240882500316250867368209534"),
+        MontFp!("18341113199274904243447557429147408702772610985345439625451614852727142466679"),
+        MontFp!("641303854841744162878167226844505402377828908784963225549380412498130669293"),
+        MontFp!("12717068536550964634894133707393353175533641253913906785707227145457684209533"),
+        MontFp!("4743152264042462778433711542894756531279965736412696362852105777475691147133"),
+        MontFp!("12538011691198347241319721961633309700824171196141123297965193643240835576405"),
+        MontFp!("20609816220088637782952524444771775704801169004700347625707989982325840582078"),
+        MontFp!("5706720205331708334089980420369782946367580993809969730038974418250776704373"),
+        MontFp!("13670952207260779576827977242585356546757059123105172869720468020067882252702"),
+        MontFp!("14767430839071230390516499921530329576551075490453331584132978354378461913602"),
+        MontFp!("13855121278897572089789093806573448194721330823581341784260637700156610112500"),
+    ],
+    [
+        MontFp!("9029464346382645337824607466955710618425900404147532427117006888589723750841"),
+        MontFp!("1522014999882036268928376276435422913665212355063956230967744832498304066925"),
+        MontFp!("8291362624704304963260873050727236420693946064959013721544766852407674345583"),
+        MontFp!("13589063181500587026605394799486087482543530017803239994475549538732438667645"),
+        MontFp!("13329949252386676079710645426263377053183751810973538969788279784055444085091"),
+        MontFp!("12386680681590613307772862225908657100001403625719832791304530216719729923003"),
+        MontFp!("7313059443635334631417408104276385788845981785748211548782869561833456731323"),
+        MontFp!("20041885136971069417608157269779303040508951822068411540615824115716811017904"),
+        MontFp!("6497376869042504752008054895287149835408180730990917088183191265348284326357"),
+        MontFp!("11125724561218240201117500197186012921479012541837342663628667709300377769139"),
+        MontFp!("19153359808996709649541642396803885814514927865816858283729145733607372609049"),
+        MontFp!("20152572433538554395328147373104228881714506304708579989936661727209606561409"),
+        MontFp!("20016439558639474267285355593250644884337484988031314831332037478976434317958"),
+        MontFp!("7527238505813249238885718653381005755459117986453125478349742303052560976989"),
+    ],
+    [
+        MontFp!("18157976047185465548629298047288220777638494790360987385974875362640599836871"),
+        MontFp!("12215475598068312355286440209098941716275384959997573662132780937926913300161"),
+        MontFp!("19661498481048830903134453807971238540013636804700867840177613880955309016673"),
+        MontFp!("16552339415724980921932277403793066165528259484794112971750640492538556458696"),
+        MontFp!("18694143444126088750994041983752049518157493949000498621268553485531912882317"),
+        MontFp!("3713967579039675465490867111467075244841382142654006118952820645091137343179"),
+        MontFp!("18003791572957152311824638498878311682370695010264579557670846595111585019742"),
+        MontFp!("12786965212577099174577804313633357821910340940882518052929431837084369088642"),
+        MontFp!("19196303109560878004701565248872597636081713248916827960361832879728708100252"),
+        MontFp!("13503920737086542355495290621258674491187655992864087131383678759555676293479"),
+        MontFp!("11236070378728091455898747203990505205014449224981497050563648348504784872625"),
+        MontFp!("9626632821060958086537183012642203130268418644318548991569123722985663518084"),
+        MontFp!("13034637881184844942276438495251249937482750510182566475651230823087848537016"),
+        MontFp!("1332579251268852603948763724532278782861309157968811258504214239247723497919"),
+    ],
+    [
+        MontFp!("4440487054089857105584789445239911365602192945489433191329897791642671641022"),
+        MontFp!("6061652828396333618822938589797622370923339089332057787863230635686741988906"),
+        MontFp!("20443625886285957158375952392918402164453450517610142032850159380049279019890"),
+        MontFp!("4971727988681610479349722318571442785499756930724120997617079739694258077982"),
+        MontFp!("18834174333023112536455562602096071615163100988222060601738058925300216483554"),
+        MontFp!("1418358707619290124642186511810240322979679442148132663647788839527968980321"),
+        MontFp!("9146563693745266063334532013679253366507504975079644933043489415067653903203"),
+        MontFp!("10198203189192787713857436708411760529883983417357336104059303759507359385362"),
+        MontFp!("13457105298809247477659188594503481428440666731107167805254859937557834208828"),
+        MontFp!("17541364687047908905500996158296679129819772711555398152345796866338919060112"),
+        MontFp!("20692063470613585148684100931411467587781915529223705941251811918651910411051"),
+        MontFp!("255059205539662129885859108675295842232143503986252525395037544865412535350"),
+        MontFp!("12717171726844166947240066436059787676718930218202178144871226850052045252628"),
+        MontFp!("11844159733516000729567466032644347431569093736490287481873540624836993897027"),
+    ],
+    [
+        MontFp!("11002794037358979127166193862990494036532493412598623614028528989937231393875"),
+        MontFp!("74143635505641635571137692594773070124419429312664778822553090280375104361"),
+        MontFp!("7056329304173643667817989532223449288690091032171349984912618184745639839146"),
+        MontFp!("18641921263165235968711057500989010664480104039807002554029677125998810936539"),
+        MontFp!("19559632820551845243777081617723524038257569278710484296878486836843940297771"),
+        MontFp!("20335178684657665532201643783226288715290471620876336715869115375508863124376"),
+        MontFp!("1400810679146237783184309652518065868576549410755675655514372408922313217181"),
+        MontFp!("4901107318137271229016627403293875571324832946427895930672961592355892796524"),
+        MontFp!("5432919409806696890084817096600220639163358481346859637851564223110298016918"),
+        MontFp!("7553857716186244958951458648858814979749280269320630962042771757992327513590"),
+        MontFp!("10889701053782808013047326782283414392744894366555627960860238324337962809504"),
+        MontFp!("10225055313049541990073049693496605844127702734102449597528239569584063842673"),
+        MontFp!("3178201789995579607967020188792297279206559603024986232709441132816492873170"),
+        MontFp!("6847656471793158091195364320441056206778429470543211654532315995128613054310"),
+    ],
+    [
+        MontFp!("12528583474708786099486823217911492959125739967840882229222854187982855300696"),
+        MontFp!("18467266119817551899238434875437425808022051394845724810256998883759435014686"),
+        MontFp!("17038264911128891077018547486438046001433165723713308233430847345626729200927"),
+        MontFp!("17955218916742955831569749120296633004126823963586826720707041154002710061824"),
+        MontFp!("7211241300282304354221867200910811631523632613327223837945134059614958044309"),
+        MontFp!("3903314564827641199204912578257298318982395101598969445634590319788688787892"),
+        MontFp!("5807166989004370646148664081075213577472290526426314941919217927313093625705"),
+        MontFp!("7286215868775919967580841436085988310805057373157718787975640918960675793864"),
+        MontFp!("1250176112708058341117534856693109443612022505613546634776485361294747168622"),
+        MontFp!("16240306074762036443870399577875028338427695244117010393369806321281281115890"),
+        MontFp!("15136410716022250493730454911117704953380153308032786787731835907574927894490"),
+        MontFp!("12835091061815802274496704101037812141879397401714459083078081237987781157602"),
+        MontFp!("9740091907879287748866588073726349058132835900960520906530105112062664428632"),
+        MontFp!("15914692134543071914048151066306389736053927516189539093172532429297748396169"),
+    ],
+    [
+        MontFp!("981367411385768085119476192577825808403597700860420619441932576179905250934"),
+        MontFp!("8146729957224132449602418119478334118295844780357577983658167000941734233326"),
+        MontFp!("590349410629703152398401018217258970865749864711821880028353966569788201764"),
+        MontFp!("1825395473256187220041087977011184605194426952605089376835233620262931194993"),
+        MontFp!("7123515738169356759148725471278134181610254795201025187792320024480691382570"),
+        MontFp!("17726785796913877143757425784904469412799726913909454284181133920507167220504"),
+        MontFp!("11651205611193223879329620496932918885123654427491270580240367578782038527170"),
+        MontFp!("7363492115511936228845261039013774575631247230570693094937015313770023603570"),
+        MontFp!("12933273009260754826631125313953911983930122727552157672205237418903525642215"),
+        MontFp!("4587914992471995247205971137457571771256946895956563575187244174461900917940"),
+        MontFp!("10792746122808647351065474080470836119851772052088452562229191086431179404978"),
+        MontFp!("20854485826587742461285159604615271614646978291370447131740817759213177014870"),
+        MontFp!("7837325030066353684135714390699866228186086140255598480265046489057880448954"),
+        MontFp!("6126605304840233971990826423459339578353618021565536707601222973624714560789"),
+    ],
+    [
+        MontFp!("9552503931384803960142614388346661043921110897228435044165354206374121160883"),
+        MontFp!("21548322368276531554717543098918449676251611754505041151177909568762524391378"),
+        MontFp!("18180071753020516412865055548290459200005267908156340472690024449103604081545"),
+        MontFp!("19794881883035632088168728687250887611858393833912902865301935760311201163703"),
+        MontFp!("12697056234954492550471667283339416001528754790033934829512770385731960713685"),
+        MontFp!("18997188462081688420104936253788181208943789361069483606382840563484861418437"),
+        MontFp!("13583525090048988441168973253797491422796978202781056749054729098883924623495"),
+        MontFp!("19314654753949754551488116156913608959581776089474885903850748881648064831000"),
+        MontFp!("15181859580303507964634708303997342165949140724201743603210372837522352052588"),
+        MontFp!("1716462370658876925282526879979978257325560447381441363811430134855582914078"),
+        MontFp!("8712634103130722740728459133380535370865755065924597276965974996780521350960"),
+        MontFp!("9765286726648838325814219419835139491993130621932376855733750920135287306908"),
+        MontFp!("19656947946994771960907781244127562602401277628142459052853670194718812445557"),
+        MontFp!("19486837510931007596539998551154014974137134735704825357816939421451262185625"),
+    ],
+    [
+        MontFp!("21628438018144169767837932394788626973405404712219671990762156320628259145217"),
+        MontFp!("3756950750264795710121609861402232041406397600142239597456522141772347776528"),
+        MontFp!("17539873562638205978340064500452614593864489286304276949397837992418594034919"),
+        MontFp!("31501567535731664761828271772320938243617735864979347171792246363428922702"),
+        MontFp!("20352331732176385362929127748957145775981304217223745596870640808444167485233"),
+        MontFp!("11226695452056490225554147366326688917799653401898260586559601210633144658136"),
+        MontFp!("11279669284613566548129429232553360604072423151872075897074160621145515173607"),
+        MontFp!("18292000567925132069216498991188575797602397842406377969706321176845302551280"),
+        MontFp!("17394537715319668724138949078062966852069669620345676386188677668125466782838"),
+        MontFp!("1758531732991926253426465239898570102024009352558916489602014827893071642025"),
+        MontFp!("10665213752467842898072683993424618781459586563812993734884914172018996230127"),
+        MontFp!("8324794334508227576619756931916312729743681044831168054976090310260143855664"),
+        MontFp!("6775442988166121274859488523805155937302608291295720635827623408019663108145"),
+        MontFp!("18295585939863028658565671525942083548149809167848494003118620027469243247255"),
+    ],
+    [
+        MontFp!("12098041580596728911973293152187391806099106269046962124832995425376587592750"),
+        MontFp!("4179756701368801644640767039230959196272720402682759516084041748778250510583"),
+        MontFp!("16793512481702225401381949294778305375341554886405623120613660547710034551886"),
+        MontFp!("4048682207452656355407948755890074205160404299490847582459375283758637453365"),
+        MontFp!("1909268909139397440485048370655601811806256012192941212480683118923705439669"),
+        MontFp!("3957160465110711873678443805553768764193925226448354732024034759529771356073"),
+        MontFp!("19248605214573594176390251265821595013690814611654024253657271349217548577373"),
+        MontFp!("13868854211319335141393401333112190211632180475309484073606978199113030773403"),
+        MontFp!("18603198177495186944736198329095228547417364118472010561530819889899131849965"),
+        MontFp!("14961608796326843943228062129500114917120869339277918579779470648489101516583"),
+        MontFp!("1868951368661473960034964936590962790214375788389753455318330338723533775732"),
+        MontFp!("10905586386180037341368454391941851073795645267274288454955833628477189437397"),
+        MontFp!("14954127679800529819463733123869358237687206843820983026443645382142721727317"),
+        MontFp!("100097327222806530673180018653982439008319453432490738486899052539918122124"),
+    ],
+    [
+        MontFp!("12805546034879808469495394895280673159114129337420886322224562831326122327785"),
+        MontFp!("14806980879080756440413940765209361196073245400503251970584575890653655885707"),
+        MontFp!("11244087337738788153622356037614703414595488262581651455100475566242231927775"),
+        MontFp!("18570222240753995928776550172488765352849447095675359684346868515783858763055"),
+        MontFp!("8341186767195379582987673341587963265518616797937499770780867790468554256976"),
+        MontFp!("16417432689254526830515766295056137071443518160358174458760640472715479776587"),
+        MontFp!("16841713253514960386433216886097940412703740575768017997853214356595383383598"),
+        MontFp!("14194887769957144867963380965988802472719946120213216012427494993012946567028"),
+        MontFp!("11529543378722012166333054004374317420377342192547947476057235546295927556027"),
+        MontFp!("6228288822288755015903485852969606389475962987032899088796179982251184963398"),
+        MontFp!("7936701363023944576389877378554772776727492414245244625252565794409904100031"),
+        MontFp!("15001498940712434912521276724772034272928752268882108797824422534795904619598"),
+        MontFp!("8473716056033059546731002019964120133827043541589774095605930332153697155158"),
+        MontFp!("13622250811616358253729853546023741257702106070523773564774458974873057917325"),
+    ],
+    [
+        MontFp!("365025468139420802765331768400644334625920629446545823780682161731304140845"),
+        MontFp!("15188301566744221387195339021364877229016833910188161414607699674936046087814"),
+        MontFp!("15485783106434451702559510093123052549288846753530302451778527951586674987922"),
+        MontFp!("16738591797575541545215307991580378005049794467812864864257667302719418546088"),
+        MontFp!("20925739023580534811148616300343740428924012857265956692833219002712597286911"),
+        MontFp!("14889383841375932440118953385212403650477118619024418302909728838111026909639"),
+        MontFp!("16907126208352189929083708812165010150781216847422378013750129578871272492354"),
+        MontFp!("14806337329187045580963626107721698864963698880842972656340333617747451702084"),
+        MontFp!("18421538430347165869364634373319132341572867349320823968976066555816366184444"),
+        MontFp!("2434665826886017086044423893890240566257398976888986717506547657107156268130"),
+        MontFp!("4107084864210868857776853415457685723648054900688044144581857773829680966286"),
+        MontFp!("9975696437638488842544898875508262421180359453753428380941136617917797565651"),
+        MontFp!("4271657746853939228219168189957401961257189278944783942462379137544482211389"),
+        MontFp!("6197095881644784733476508213210037292053663537272178396466755799876037787454"),
+    ],
+    [
+        MontFp!("17213367299854554180584426411972899951945067477706586927352113350447914118348"),
+        MontFp!("459213126097844409580498104618008133837044548373643874721876534610892189686"),
+        MontFp!("11543212578280368772068695265607222577889093375221336525779600349460584250291"),
+        MontFp!("6403298899310300874478654413460079479003477532791552465502800475489780616607"),
+        MontFp!("9679879153779101961935393514698788441597495345714510688032433497970373876200"),
+        MontFp!("13406313792037016685976311954559553063553089970578743589839464948184425020319"),
+        MontFp!("18447061183141116334003130470774130153225311547501637266696790170481921881877"),
+        MontFp!("8380490825353548292894683756629385493171585978110314915970595197861650821468"),
+        MontFp!("17748778220767931278266669246252235981422317813726374848817938354712043425250"),
+        MontFp!("6989443852838917282085440504075239616539844445576232297589557657622451430824"),
+        MontFp!("7831657729066776489302369169030303662771324770284845700052063314128105065459"),
+        MontFp!("3925294941359174220766529276974428294409483857727579665373968233976340302182"),
+        MontFp!("11937917292063962829330707841648838486196775641195800632090436249316243167473"),
+        MontFp!("5653419633240940595644007985875227652581749358539968324306529520430709397246"),
+    ],
+    [
+        MontFp!("4710671638294627998538116427765044043262338916893456876128991289569569324348"),
+        MontFp!("71394869385825610742630406624465024289788832054180269745498390791493509963"),
+        MontFp!("14738615985593994376179875742690333041302941042023229128451420345640432224466"),
+        MontFp!("19352351801479363044593690690009745314348194116715421753626607893015433391861"),
+        MontFp!("7828134540699329284695760683671979636145431779072416908240193138816504514982"),
+        MontFp!("21767328407325385326066972839469738803639297445302836419167176746643393964398"),
+        MontFp!("4363185783182535592354777041764733165968243966270266866378133278599205816107"),
+        MontFp!("12861900092547198665663242905083918119082751367916515401033164113207531942792"),
+        MontFp!("26199715195773805806030726371679979491296157888969354080702618784507347327"),
+        MontFp!("11422842882875515086151723214834881230789142279246505109137919711737652087050"),
+        MontFp!("19051762302341786952522177542948673906514656868374502102797859778503069987555"),
+        MontFp!("15304419135175746829806105353267450572438302765436283477928256874309497427510"),
+        MontFp!("15459263896362250252603436487314027681146892842462223021544635764438917687246"),
+        MontFp!("8022722450368098005612162808615173120915189731064159685566153653273958491689"),
+    ],
+    [
+        MontFp!("9933514811147109956084664025371824141534792260321581906034445537159964992688"),
+        MontFp!("13658822996647839872436185744737341749492205026854185105317636224609004876375"),
+        MontFp!("14379624233209822191260559290771588358188218543089774948098229868966603288924"),
+        MontFp!("14958992313261503520770373701642009455846990471215730792533739030356906680013"),
+        MontFp!("3008264124109355458910031641850139041159853342729069847393839537381972950880"),
+        MontFp!("19920038730043462715603813734444323019432755215636179923851277692610637123174"),
+        MontFp!("17230906594400326225968287949357928337332875589057677202822764136401667097576"),
+        MontFp!("3295851897757873055813703602696670024935942454512523388333117755329662151138"),
+        MontFp!("18768678356443319065659286382395610390856779982946155969362775884999359596659"),
+        MontFp!("12460586127165955570640740026112067337902936125125513451212701690418329829958"),
+        MontFp!("11058259665604247832957658651506896706861484197660414347806317812760432481190"),
+        MontFp!("4480738127714472007170345890289828985073762836671874831610164101698497828459"),
+        MontFp!("20356672216023774813369271811683185179666462848912908217024611254147608738543"),
+        MontFp!("14909200607508800723484308159953932832836734953149954532962622450689109093081"),
+    ],
+    [
+        MontFp!("9061041843468229886389838095513813426084716371809563990186852970500432288083"),
+        MontFp!("13877210447577198749973239837374936528445414390167357430100753821987322195433"),
+        MontFp!("2839635391670662085450211408709683689698694308308101424896263880864913513894"),
+        MontFp!("446790459594546823193048947973948688374393610819268956164912891701746906791"),
+        MontFp!("15513394297485200475424313247005293190116598898090270540649465364671255026149"),
+        MontFp!("6430544300458423909335482367339522552006494240640365398487065900428380674587"),
+        MontFp!("167620735842959596421416387102879695919480795342009064535989276983300514975"),
+        MontFp!("7851629689216713462310956055612887013872895546158054432042649792702892211944"),
+        MontFp!("6972603886636382162328373670318167574093559853635999968924151410796861460213"),
+        MontFp!("19413944499150555955013296242054320685906330193520693124525832782341915610104"),
+        MontFp!("12263527706889748735493918123122221596983902069097405802497958581677957096038"),
+        MontFp!("12724987466750360537318099905062339029588038359925927031456282032606443592484"),
+        MontFp!("9708897601683631082524518294366690107466919479336384437075715215088985624241"),
+        MontFp!("5574496517333770677326807465934080003942242224011251056548046091202080747952"),
+    ],
+    [
+        MontFp!("4426233947499799157064891373345188337862104712594055992042177154581455374210"),
+        MontFp!("3323616387049914826284182817146044206680302487334156840960948301224727078983"),
+        MontFp!("13004254604656434940963009501076991894817629784556574456240824509876544606284"),
+        MontFp!("7301157702835275823143922645275544869236677259284614641634323459896004519527"),
+        MontFp!("18085948818621221598774302236513376768745135801526414290158313790799455691975"),
+        MontFp!("15187850571325688566534378765892972581891242830408971491252049503437712864152"),
+        MontFp!("1246313402425855943486273052355580345666640430821848725745627960892819218740"),
+        MontFp!("8647394846748193532697036206738902082890058434940110730560542269988727795912"),
+        MontFp!("10064655809228901737773717896104022245084200167358447082271876992404069509689"),
+        MontFp!("11849936299337845059630533008021049942767799074160683892846518986534992566533"),
+        MontFp!("17222503330227123082665055850287821041983218187459530901865330120583332963245"),
+        MontFp!("13182290359799776528759669559625168410983932493170206108456280901111876076502"),
+        MontFp!("16922308846046171347608544335458702314243928203269931669473214292617484140503"),
+        MontFp!("7558805163644194610710740916145749972491144028571940176012011742150030513578"),
+    ],
+    [
+        MontFp!("19569775351485108268007779638227959360722563519500120339858146797114197471682"),
+        MontFp!("11059423706111256170971735544260170713544645580527143674901672982061741530101"),
+        MontFp!("14932750633257527497920398771292456029356930315768552640257901217840963910603"),
+        MontFp!("3437896142924792262479946438539259423504563537021031548773393821349707014872"),
+        MontFp!("14782805101152549038431586973278748014002447400847124060642259960939628565044"),
+        MontFp!("19682001999640336579725059393603666122104885151774112167088565361216155864092"),
+        MontFp!("4233558995266469322360257198354042357488781625226686110373130567271844168385"),
+        MontFp!("4047511689246902987779521780711143086427724115291088073014684434358317932158"),
+        MontFp!("13856430748589477860039479213633534029749590309123560510004500567115861827367"),
+        MontFp!("5679747763871928801801426015291106236767446876847749477902392536633742028903"),
+        MontFp!("2480123750122829773963967496725275864472046134363280592972001159541781284867"),
+        MontFp!("3193376214066985989527250356837102042223952356102254301113166141443841024511"),
+        MontFp!("8062756153672663466999843325461563174537500405375937887043564985230465866772"),
+        MontFp!("20934273497132536703003386629581299436206618333301538059074736379428757199398"),
+    ],
+    [
+        MontFp!("3964142000339408965627671968349646710437601305543271274636785761452376263382"),
+        MontFp!("17659047756049778260030257809618419752271933594271518827815853540916989127582"),
+        MontFp!("3930343349615664221386794926953117177573248229077177174315534154624751347076"),
+        MontFp!("2895341264817391965072938270159088942930942041694844772295944189135688863196"),
+        MontFp!("20818043348799096204572140288297522915577162783223193328931006646915252532953"),
+        MontFp!("2323581855503968054101786162741712103392782241966098143187644164748998373979"),
+        MontFp!("21506950445835751487883729097676565482258136522526260378635240810473639421131"),
+        MontFp!("15652663761688777314836638604018575636259220826467294056546736904699739444415"),
+        MontFp!("7055942973426557227578535789272026034619140718369524980726263182702440802766"),
+        MontFp!("4949575918974841751734514119081067078445927926837555007268000255590820633720"),
+        MontFp!("1398399621038450203083960309132739969264364773648996881599178794373813964423"),
+        MontFp!("15732506799197618296258053128654220838812930643001991164136863850944185860296"),
+        MontFp!("7794835596508268056707656540537636605902268233816181487738953353424162592877"),
+        MontFp!("19334780957038992630028385332334147629399375592719839985802296503565556738324"),
+    ],
+    [
+        MontFp!("21635034093671076768880953665214862813027411650977124864184274112296716574632"),
+        MontFp!("10688534301651495143056852052149424865722511689194196231759927113549978532269"),
+        MontFp!("976280778878149721615236526300424224199708100054560296745736876703945737879"),
+        MontFp!("1661236337347680899528966953581439111854379099437324147973193626090712244045"),
+        MontFp!("376684649999303144466214973033408609742031466036082108742020038605665915381"),
+        MontFp!("4455377013377822379614998251281676782235647079572422444530523356359416320717"),
+        MontFp!("19050227778954804843017265242388770579299436899390433258601701002663268793950"),
+        MontFp!("20176738847900667540339144241305449384029623393968373234965844794399275414945"),
+        MontFp!("6563336600400724498009526486444642892816244088883489205001024055715188696046"),
+        MontFp!("1774742053125969896553337470644410955775448576794083561882186570750312466523"),
+        MontFp!("4720344938456070076793371724100707050168913253766155392188169516296487837219"),
+        MontFp!("12386484928350266729292976320659720907387619673269670145583838638028478942370"),
+        MontFp!("16511156064543308562970376025636430728255148453048880933972883964686852788569"),
+        MontFp!("20161060548546289109867983393573712303980348945862267966490524939511877278515"),
+    ],
+    [
+        MontFp!("15745678416495620522776570951840736496696200297848371457414809276602866061937"),
+        MontFp!("4371269787269411959190919691547618186773560770535442840395153562222650962400"),
+        MontFp!("9183286542027774648383851710226269089477011745654659545146528858483988667073"),
+        MontFp!("6831030131135399650979313302695351819580359371445002328037898607795789751628"),
+        MontFp!("13808316624446561665670975675845412570522644424965154154503596711464812129094"),
+        MontFp!("16573850887526809906244420164574513771138034770054149931125695105829690192839"),
+        MontFp!("6536774143914522904090235450413350153861560318210355846585220439397199352760"),
+        MontFp!("7433401028165144185448409996814186253264882020047922839648723974949988749262"),
+        MontFp!("12023679188187515805015064799648457297092902074185758947196154639017034370941"),
+        MontFp!("11526239531876823273618354625960431640864995247037473959040869772406787371745"),
+        MontFp!("9965606134640358953439667616539308986324141554480312185321082780157059913160"),
+        MontFp!("3745993460581557715489390110730324720335274000085036229679909083358071037494"),
+        MontFp!("9383224303353982312273937680493563948228572509816601479265308820357851069284"),
+        MontFp!("1487827268845002359294235769079867448539095616058866371843794273898817464325"),
+    ],
+    [
+        MontFp!("12752425410737476015270360130920506733622111261208379938979967899453342006543"),
+        MontFp!("8485480321269023262044616550897625372838041041299338618725089063696829309383"),
+        MontFp!("12576415359894007789769349543504370901641305511107563487651205088993508989632"),
+        MontFp!("21051289172896982928450490300579871201387152257916628019163266526462496896736"),
+        MontFp!("15867382033370376377605746630898769390447074387330276410404020451043720395429"),
+        MontFp!("19175636645506837972748760370147539503389959528500852183571848133179892449157"),
+        MontFp!("18562815464677471696203191443459870050160752363485379734113024945295929018010"),
+        MontFp!("10670914301723557472895334516073215554660627986142935452136687134270343499283"),
+        MontFp!("15711527071984806338686904487656233279759984782956970430068615997429454331060"),
+        MontFp!("8708497335606838864375996732302168404094513852034309829472484268061328157580"),
+        MontFp!("4188819471869628756655847772489988754984005896205326092058226221066145721122"),
+        MontFp!("6512127543122355053584585699891817324589817660150620823656474181624359941201"),
+        MontFp!("485283184789889690494624068272585257719678055879291431306025716514618916201"),
+        MontFp!("12469998319178098719636931437231251055837496888416524814745527432538031712785"),
+    ],
+    [
+        MontFp!("1296020106178851400649855820617531046666668928599018437234450345456859444266"),
+        MontFp!("10011446468770184926016968173857732818096269728811257796162165899021103326404"),
+        MontFp!("21669153150071610384945525175896288044525929994331131233225247540220566590451"),
+        MontFp!("20224127309865911841696813826362911446819981503618978937382553360215894705096"),
+        MontFp!("11995994988481623386912855165099904216709109375061333173068533008232786187218"),
+        MontFp!("9410915142880940758336239494539529470909530692135279575732428340046078892946"),
+        MontFp!("540343796196110456420764600417592095932817965626261174892651237721778500283"),
+        MontFp!("13684306082150121243464309606855998092353075421168995445316517423697818489078"),
+        MontFp!("20162825761581699251737493230139692067729837621407892263730870777826880180708"),
+        MontFp!("5352686262532923758786328475456237662420216644156835779261605606151509572690"),
+        MontFp!("14736042040902670309572407869335525984156459843045648740121852458089457172710"),
+        MontFp!("15139196852186360494919007897486360521240977399849539996614669434222882428974"),
+        MontFp!("15545525411887550591483485994597179301368938856348279539852576272926020931130"),
+        MontFp!("11742635236612776978185392287408975566560283293946133146948657681925156152641"),
+    ],
+    [
+        MontFp!("15933911044011218543142885674144914931062698638612248403425430247045038884929"),
+        MontFp!("11224528638969252327238392070525091272297579382723799722332333037066879094771"),
+        MontFp!("10619901685741876140226157641208788563882472537632692937793306998778377039845"),
+        MontFp!("735284898318336599638540292516453331642892791568249331436388578312138627149"),
+        MontFp!("12167882615164420400306427908818494367178873976878804005748498778042330871045"),
+        MontFp!("963476496798286039732969224933550470500612316061831761002103352026702296408"),
+        MontFp!("10589214229258115348033093903975219788029199245856576715398082842145004049938"),
+        MontFp!("21557152519616203688970824263287584036198859167308821872261472006356046725775"),
+        MontFp!("573869759526051245236872936984426432239686428333700586079723439581218874054"),
+        MontFp!("9487380087795664760223154980070289042545005448274508346993861027000032902237"),
+        MontFp!("15336219129184315874932697305233742095099896250996970622669888209373427981768"),
+        MontFp!("18404646237880969912286263998117686518695361809473583072241508209656556652987"),
+        MontFp!("11799938531163685220849832019747758716514224719376128562353011229084757783628"),
+        MontFp!("16344281677414470708413218213072185241641469290932113189992078158350700334823"),
+    ],
+    [
+        MontFp!("13411067297619308137174262243170594092291209705703155674514006442224279302094"),
+        MontFp!("347096376483719141995221552155578565668868956459941108952060114777596679497"),
+        MontFp!("21711034316629345478518202673372708169627878437940325626238942704003376069559"),
+        MontFp!("18882050099612599842940192045274010067786778900079004241849918566254608017530"),
+        MontFp!("5764257238170917062706360501552002326820365975520365982521494644422134554879"),
+        MontFp!("15701229555594307124251301047127236083745198814231032790649389796695363681317"),
+        MontFp!("19380342180431843316217793484208165905833096992510614135124089140124740832734"),
+        MontFp!("5309407894979098314097225178362235237148427064335413702368413584568729847810"),
+        MontFp!("19819307748431076945425543888401840319829139172119377623119035071741874422686"),
+        MontFp!("20514761050842041997240240118048816505140382250318444479066954659053422537618"),
+        MontFp!("1344402086478653057873064952452423683387555289301580402150577440089615404706"),
+        MontFp!("16697255135945674012509919105064086681485448643989533271078867487484115301326"),
+        MontFp!("4621832835239804428359762201398927678345509964776233571546153039277467113103"),
+        MontFp!("3806403786653298341904285490244574263470519864399252904304315511448068846106"),
+    ],
+    [
+        MontFp!("19829858960657679259830225387994355131769725454664502533894857362311545270016"),
+        MontFp!("14331370957097049770140547885384829422939088321033246508644491038102571773100"),
+        MontFp!("17602213870111183187455849162801944225604239824059691632957781727843794239813"),
+        MontFp!("13431951289066965496411295925849182623784324208488725900281691974104907065599"),
+        MontFp!("16717461480909222952301202387848079129664321734166452456973177865963036285419"),
+        MontFp!("5335304795187086185403562863303763030150108130150704291401586263249269328606"),
+        MontFp!("12084224077193103457678419187386990486069302392148214780322095375978824542075"),
+        MontFp!("16562074867094659170642419054272949287052074902139533680078784684856883275478"),
+        MontFp!("5593185214695460463728593774938986800789091570195563391333689785627694694435"),
+        MontFp!("20794081973744988874029976307272029218401016626938878812907621515041534590160"),
+        MontFp!("15290613354786940528909115982493729676786892068577902693057827852601851859953"),
+        MontFp!("12549551509495378488198674258192692896591847320322918116198744545244343099813"),
+        MontFp!("16172760618549958023639071988927160267123900859890153325976796899698706870529"),
+        MontFp!("21355970775695686114097146789571025790146162225961276367865670606869296144640"),
+    ],
+    [
+        MontFp!("6758388446832091660422761046124378827605916325205674642673860154638138778023"),
+        MontFp!("14106404069959859167123546389809591376301394503370836987998148793984105405167"),
+        MontFp!("9246221316655556670219438263600949328330249756179668376432941216174575243170"),
+        MontFp!("17439153852461693097676073072037755599321185173017314957734642307684740219597"),
+        MontFp!("8081777382362242695298005068719699951526876053236775057946263871745968444089"),
+        MontFp!("5955062815471620144467538738332476050500085920379711183343040972831100802372"),
+        MontFp!("7135549743533376618652729621156592228493633259664287822661943632128030927327"),
+        MontFp!("6448789306286809743113676641229641365524354356867853498363388856986312333060"),
+        MontFp!("3836133206442752775823759632522089911744536837043185745585363415112795166600"),
+        MontFp!("8306967267195429229901513132397768469414281874455321140328179401973148164445"),
+        MontFp!("14279081291461540920232629360665447603436685365844514491245275283801357455033"),
+        MontFp!("18801535274021869273717064373621101183756544015062966551566128881569524508060"),
+        MontFp!("1290309525505162730469471418279301124342110934722264762505649909318202025429"),
+        MontFp!("3684482296351796270499952631629720483486993312250657634474107248508746062899"),
+    ],
+    [
+        MontFp!("13174517099719760880623841117042663715711430443825712743356469456554740491052"),
+        MontFp!("12367323038661460523248093127080567074978912019587769246568174104058982359108"),
+        MontFp!("5328098381011440170293483375144450438184897394389341853376147620924621832373"),
+        MontFp!("12027556664769952184858265393953265399418219730267455116938121684665358512140"),
+        MontFp!("5729732888348277625941886883620484887709258694699060380279441089816970092500"),
+        MontFp!("4021085134780294168590418396176917282024838594185209785357282466818023080991"),
+        MontFp!("17212964584402772629081731052343755101208077509065176631705574779383125014657"),
+        MontFp!("20917432005010641713392920477403385909781374146458276502002720501978360592707"),
+        MontFp!("15295563898035514774486916635776497660635050791955975586488426545424778559212"),
+        MontFp!("1359821178683766445655942489230783986383452848368510940932965034076334706033"),
+        MontFp!("8110406013405532283463947127567634123149268608919736912581466809002624991926"),
+        MontFp!("827196241038734359771319428856617658913932049877272548875698342240161957065"),
+        MontFp!("3791462512735380195191512407306530846222290938359064542531485381855595890578"),
+        MontFp!("11406017533405938491480863229977030648871769070115386849200353035840595881771"),
+    ],
+    [
+        MontFp!("520670302347263967262738466276891414776779776742458165836379179429124641366"),
+        MontFp!("2448606803226047006259184225386977543879941109635656602752172963011710994490"),
+        MontFp!("7191227529515483786577983572625758056228282372415415309332838080598142611412"),
+        MontFp!("3307446417204024568218448979093609311776086880103323372856728466529459088211"),
+        MontFp!("3813554883592508538818724202670630447381185344079357801330890767369937578758"),
+        MontFp!("5250709431012867357277423372041580897716232143432581463056922412233378167706"),
+        MontFp!("1091692079685509315891403580607856616782786079784896146193867325719999481531"),
+        MontFp!("4469622327160639333299363654867070539113198566013450831894562184457403911597"),
+        MontFp!("17323310137022557438196735285237348172608407585356043526322906169573029382454"),
+        MontFp!("16687301461250715912904355201855034952586622338354151885963080312175524252322"),
+        MontFp!("4918535113766539148632870060160447192025270821762409737556656897638575667317"),
+        MontFp!("11072593994523457387389747805751581908149754361901108358077132406445033545004"),
+        MontFp!("3195233728052289389067022539306995714028010328554486938319749354377057516714"),
+        MontFp!("20817686894891259203267112342163112292566908705837357461445233267117792429649"),
+    ],
+    [
+        MontFp!("17690940439879640319699865226478735545090209049084370958835445823603146902951"),
+        MontFp!("21690628807014351230241926358993998569388931315165663025074689739408667261702"),
+        MontFp!("20459556268654252373755265495950372631044421643418312123931432417755203158692"),
+        MontFp!("2615012003869030558909061586486157199297754407476408867239954327608829725401"),
+        MontFp!("10416424720480840919038397124331362407722217483977166150169359209212504823727"),
+        MontFp!("21194909967788113390574471899389319930586662664636393856442773352403088570772"),
+        MontFp!("21522774899908415003330605964114671001565200771087582504208866633533540017562"),
+        MontFp!("5506611081865651016465481008237511873335242056816344474949867549242155650988"),
+        MontFp!("9546865422597752417260521115459137295056544614907437375816616393702205361356"),
+        MontFp!("21830596616035544007289279751950703469521180388685936491933709578982542912017"),
+        MontFp!("749611610802485176586202226352686769865522163389912514564999424571463284657"),
+        MontFp!("6755555316754154503813947597597343604907450943684878399336695170747270471709"),
+        MontFp!("12170672505455181293754012516713072944675179516356563083524964881029055628483"),
+        MontFp!("6707337549725648754250613641063009246814657413626673293152655855698987287596"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=14.
+pub static POSEIDON1_BN254_T14_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 14-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 14]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 14]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 14]) -> [ark_bn254::Fr; 14] {
+    POSEIDON1_BN254_T14_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 14-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 14]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 14]) {
+    POSEIDON1_BN254_T14_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,1468 @@
+//! Circomlib-compatible Poseidon implementation for `t=15`, i.e., 14 inputs.
+//!
+//! These parameters are not cross-checked against circomlibjs yet, so [`poseidon`](super::poseidon) does not use them.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 15 8 60 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 15;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 60;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("1954546571818731885139861264947334230782822161673023234242993080695489129982"),
+        MontFp!("11606713580838194823093847718802359011098299538034455148401855555744041817997"),
+        MontFp!("21778217939341959600865514937973379081571132553754734185669152755967486158807"),
+        MontFp!("11867012199835162777599593543744285374463489953452947402200134749407575327780"),
+        MontFp!("20668200962959535110219664454556867828577715202494491079283962871771719016091"),
+        MontFp!("6796938349934826085352626361055311106987991567096993611616805270698773290279"),
+        MontFp!("15108030096316731537404525399718062561401239877230837132486990179727134215091"),
+        MontFp!("18618583058942935584876943765894457772128324732451762769633954204661267055617"),
+        MontFp!("7446958258820445329937058505234183740111199633995331064868799738609505041620"),
+        MontFp!("11019126795578266009911151080068316016022306110283709712693862589215772062237"),
+        MontFp!("17644961526468872013219663511656737898249108220341985100127433673616476030536"),
+        MontFp!("4959721361611533340499147366149623398780635086479466110353420780375692399477"),
+        MontFp!("18273613083607267201259595982191169247452947601227995394305633285537292365096"),
+        MontFp!("17816466502776842735116945485728134149282831962573761460376746436502757322332"),
+        MontFp!("7013781340485780306773324480395548266877763825891494701746512901494705653158"),
+    ],
+    [
+        MontFp!("7793291197073594006386853421213450159077336220644997691715731402410704643042"),
+        MontFp!("3426005025972529257284910903433598760993095858232060658495826014698591260944"),
+        MontFp!("18530832095369567225742997294004908129637537286260217494648426754251364141983"),
+        MontFp!("16489909564793485793960504581924093693287639849995832883358263301067754354184"),
+        MontFp!("15126602449686250365534081130480301443166072957206187316416707568927051456663"),
+        MontFp!("13994979972845996867477162556668014834350043400046615820474382058282820089898"),
+        MontFp!("17892071176071030024436108339592708638442586845389229765280031864359446154887"),
+        MontFp!("7094246519433675669226318744483462997736245331529142696208838903133391196837"),
+        MontFp!("19739600791550679646703071148379836779124671330492407813260108679076122705926"),
+        MontFp!("16577895124793812992345966235533166228538388644746952252700985971901794098608"),
+        MontFp!("2190990407832635064016354900528055762572032133913345251583721394536626731922"),
+        MontFp!("10838969594099257399038118686024400001327577210604256394537002295046250661365"),
+        MontFp!("13742554186879139633322968994905507641568437399912823098239782636831322642395"),
+        MontFp!("10281113667801091149613944447670705624056560574926411753502305328318932013688"),
+        MontFp!("7661208680673970050246952651218127022141152720979640414729369551173790735959"),
+    ],
+    [
+        MontFp!("14369836811580283035547554195559038793886958236571577920508487931208924192768"),
+        MontFp!("20603628573396476191496332378884772502350107937108583985752646932901407759112"),
+        MontFp!("19296916296835469264085474516279583782033370007674993417080564950885860980156"),
+        MontFp!("10983867448590555143664432588641225682254935452824608025544914671100236945380"),
+        MontFp!("5670198946055747149234813634846142209283829958947146164536023332201358566553"),
+        MontFp!("4904816432035963931263837796941455228547544800276020247096183162764093041386"),
+        MontFp!("928528370618860212551901809222389226336726628142306562102221490519648216649"),
+        MontFp!("8727385187994811157471310113729025912812882704232858255495018737569420129281"),
+        MontFp!("19909768217191699902186248006262494556099457367519802119733085801884256380544"),
+        MontFp!("10635786582281955931244778086998962127059196955758207056871772748744817883737"),
+        MontFp!("7140512340052162441606422433836236465795273624186668144911701254961330905493"),
+        MontFp!("16598081311443832517669265039250197623929992506944409626575335140315057620768"),
+        MontFp!("2339664320384903939910962081546057089170206846484766939921698239663651706239"),
+        MontFp!("425509623704802982425483674266195224640999670982140442030650575449074971057"),
+        MontFp!("7922384239142329258156226873732902413897900318612725000714450267548570680404"),
+    ],
+    [
+        MontFp!("8178140403014386057685967488315772252114289881535707170540858306748328725322"),
+        MontFp!("12689293740944871195190670877158259851710828253354810002997981152414697198513"),
+        MontFp!("13670630626216376948528966598720909229691593992164633421606526176324419533442"),
+        MontFp!("20189490101967313329851160663874367593390331759675962821030507426149184002493"),
+        MontFp!("18209972608416650990264895614325602746017028678399567737887116829945804399280"),
+        MontFp!("20353660437114078502000672122042327871511027701339587880609263231648053792209"),
+        MontFp!("367135858451744056025051491593060073950844607000402056456474235270560576836"),
+        MontFp!("13355850760886700974133527239382497141869096511168824351814359808886023658462"),
+        MontFp!("7206193356029734986150290058613471641978817208643432940709861990432648635433"),
+        MontFp!("7885684183122679587266799938650213096329650494585142531776846669540995068168"),
+        MontFp!("19085115218990181267812208821832153255121894513890241319104580206329327134131"),
+        MontFp!("16305675470941528258170184941405206862153955794946952667798249341324791515500"),
+        MontFp!("15443626257895746936982356461453477742473783071787883800166168668037169561924"),
+        MontFp!("101832047855527584987088220264952346019960111874606050409415217845556024488"),
+        MontFp!("10576438072746903138917852030571732352003417543540340689583487864994727144138"),
+    ],
+    [
+        MontFp!("5996861730264922256270512962050361669936822432104376503237345294243995854540"),
+        MontFp!("14877973900502178557219336745361213333854789301797456671201806787562463919326"),
+        MontFp!("14807962843542542498914061591358875654692300327506360640837865566998761281322"),
+        MontFp!("3133673265931719924452668737189159279894652423873815799856403146721022028744"),
+        MontFp!("2314426743898183021393131908284299082806555710249089245305873178073379019830"),
+        MontFp!("15353836455896084897563929713128028858175788390437902641700134508986437653318"),
+        MontFp!("5529981971838869469294842442312128910934708838384001405870007618574232226406"),
+        MontFp!("21863108219378799978996648633069571801923287451100447450849597846874069699478"),
+        MontFp!("6773528450923012634292634195479655092490402578779439394568805920957004744133"),
+        MontFp!("12150245180431051120309675366247495517352377611113958096501103925281912163211"),
+        MontFp!("11142442323884902255425165263749428309435092933107089893872191462936441527962"),
+        MontFp!("7030165611221942623542847326918501014233687676615371955108018311000952911338"),
+        MontFp!("14168907664945894221515023422776939138433274836712427741726190314020662482321"),
+        MontFp!("17973846874050037633502661848899122581090847479984048542694367819419188584711"),
+        MontFp!("6612448947387099268202244798863603173886774177350810153571814261011002084641"),
+    ],
+    [
+        MontFp!("20765273984039816245168454930434370234220284726385931011063596091927027011108"),
+        MontFp!("12525833775624943075128880966259784896817535866921245846013552547690890352574"),
+        MontFp!("18082141488353658073882233625595393585704703572017716887747923068948432979709"),
+        MontFp!("12080205172928213829055364897249628749790838461826174687455161036760925324146"),
+        MontFp!("10426041417079669712788047630796875947997831039494087898318197116078426849054"),
+        MontFp!("18333367395418670733687742418586004501344157225223371831515062599898496336393"),
+        MontFp!("10666844346085567030848134043176991777319226647942683934134661343455999941894"),
+        MontFp!("20287202184725945519955164847740850432153598475156573942705745729215691030796"),
+        MontFp!("17942851314410450183054332374663618442963349517519641485232231950262698445043"),
+        MontFp!("17672277011389568686180232934337352157780343745417630591806280730146798908966"),
+        MontFp!("17416106918062278234521335281965623696779795380548750331807903068461573518054"),
+        MontFp!("15034390385078628923681181367678507353936042776187855843799428131823528700439"),
+        MontFp!("15016371809918204032764565101078018512566551812562861502095795302834732872947"),
+        MontFp!("8176229788878503959342848267153225222150151339952249831413185552792554528595"),
+        MontFp!("14202549166569309182319866775579092322766621157492056208423752359103429675445"),
+    ],
+    [
+        MontFp!("6628758202046565882882491271332141326521031973243028104017889062740759748530"),
+        MontFp!("20267845326067450413789379016153439637066264448919236391605812427944953078755"),
+        MontFp!("2438946774028723892708023594952994993532105735189593503088246493623252811398"),
+        MontFp!("5327774123437518227973303235331602588839413198088244869937007412210139714640"),
+        MontFp!("16416517260868931624699960600760845305546648577328049221217547593071007584547"),
+        MontFp!("21691457642736313179352706050711464825492028639914839210493298427277168769684"),
+        MontFp!("17369736170805089474636304643282290719726533149056710536120639858255935606857"),
+        MontFp!("19460761623902421883797374762298555710671254062576987287084819867262496770119"),
+        MontFp!("19770570144034267396127078712986043464609355920552337517533085194608634179666"),
+        MontFp!("20904722049832148410244764905538463264520496768863784169293376826852051688706"),
+        MontFp!("6086931305514615639236334006857789100145606465861722355610937306339550862"),
+        MontFp!("5885641636117295888159072068000551173102681944020015073964039109891861226751"),
+        MontFp!("2197549059218467728366947205357858398035068309306368786367182781869500529089"),
+        MontFp!("18571065033075196607590252486530861399537536653506142751073877421696969841444"),
+        MontFp!("8754088881400442345643534933850221698544089985357770542959718766123813634810"),
+    ],
+    [
+        MontFp!("13673463077059539437815915980077307152850526782227553623693374041649724049605"),
+        MontFp!("827897346385894242944663854685785871137033256170575635435612086616249561082"),
+        MontFp!("3256718342276213157296321691616951565542440296040693556287210483669841487973"),
+        MontFp!("18851058760089844863118102177423730353882359970674430675542303019981882750705"),
+        MontFp!("21009037983427297652279654800570889577926790955118786173230430780907667982896"),
+        MontFp!("19370673591737489444054265538393258291592098201577177240415593550679982651270"),
+        MontFp!("9712794467513451079466753095103777002587307366322848872714822737566534970868"),
+        MontFp!("2452976395290300719873209484043914405675637974162415011707015440506646332236"),
+        MontFp!("13967023770779438454858978860214792451127045212657472381516830319420403024355"),
+        MontFp!("18974770134907327058718913691556562240688992993972407935785848010954975834526"),
+        MontFp!("18005343276101020422248769804338953747590444642920980587346957205121649916277"),
+        MontFp!("5364199751574723768938730543610903684616886041855996090009230701935892264768"),
+        MontFp!("16915141432748433783158989990154900013143930156431056052460984382677436665679"),
+        MontFp!("9810457740455050658326943855759399108575402539560713791636000764640385927272"),
+        MontFp!("15711844859260073612371012979328688796642677336582424352155989490394966892994"),
+    ],
+    [
+        MontFp!("2523486975208775388230636032695576725855997180931786065064150527465407276212"),
+        MontFp!("12939257203114853364537111886847673104871159136302860798643783368852456402126"),
+        MontFp!("18563343508729873190283517746040347988882591986176993103658794343898711153424"),
+        MontFp!("9139767925154848725661711816797304370425590863714334419686930754659416933343"),
+        MontFp!("15630326979358561783620795846763021145439701823262337294600523076394775855291"),
+        MontFp!("16709031855693747432049197217266634836607267701623669179857743766059308291076"),
+        MontFp!("11081746589259753124653594380015131650915622160245647873370327872758282529429"),
+        MontFp!("10263829532434991046602117509549967441368717347217075372460446855507340910410"),
+        MontFp!("923380097607272775621985864770232207712801803675470117823528453022294342573"),
+        MontFp!("7060362012752050086965449046391069479205357779670943566418766734533864254186"),
+        MontFp!("4238871118210220589598309748597547342336859622832314796736348778105851398663"),
+        MontFp!("17520061366255155846404852213753339526277619564174678951991892080505590972066"),
+        MontFp!("10513625869281904114245087023227471195681135249236672625281292889978751612829"),
+        MontFp!("8435396899666453466602702234562279601174578748121165208762270334814881381944"),
+        MontFp!("8088078454252433245088686773582075061475116946251984942060002979516553775360"),
+    ],
+    [
+        MontFp!("2719987334353537600366656327639544587227927648913835976421439609622334518069"),
+        MontFp!("1024019320641379568207362641296952564452568447918936948059464814762366331275"),
+        MontFp!("13889412498086825909291896540147715217051215696137185869382874120375855480535"),
+        MontFp!("16901630530096437974516169843541514517956751790577876352535912471650304576719"),
+        MontFp!("10977539500432168331426791033212854950231005312634634920276038346698892818211"),
+        MontFp!("3226460659346175003896135924099734866664778523605375090881255390738403665617"),
+        MontFp!("9695421696482260394078309163365413177132015345359377667904366174083251929056"),
+        MontFp!("11269053203885423427900382169641426379373759430258387104391167619152696936070"),
+        MontFp!("5178750298399029508026924620615685679610392082191932524033828050686124044833"),
+        MontFp!("12845878982355860044505488053971997443594073436267126888909625977253125523142"),
+        MontFp!("7530981388399357124357431126610695946065175975095472306777796169245607001944"),
+        MontFp!("14058213441446348117716607452759269076404820355922112411973297063005877380478"),
+        MontFp!("825603012201903073682942337154197674883919500964784037574585377691889312422"),
+        MontFp!("10236993586198323123785803013039327931978354285685191819502917433935835639701"),
+        MontFp!("9438970111160688934509448828104684236155844660381402912193342504181825365420"),
+    ],
+    [
+        MontFp!("11536612122721678319037657954738688943272460041908847457419482683492719528721"),
+        MontFp!("4048128893355211133472225346691072554088570917135573376272009214966234274059"),
+        MontFp!("17007960555125781716346334106074020327440615260084049810189285619997415816473"),
+        MontFp!("2935744638594795881476272405224480626548498961519135317809162650064622710267"),
+        MontFp!("6384493312721061401062408865799313573644091862395937725107886310975229942194"),
+        MontFp!("10507848115923740198082149097677194763453026968238422206438345199258995348681"),
+        MontFp!("18755782391252715265425541321566381935042481942506333926799033963914433188574"),
+        MontFp!("1622030934879728521636669415221999170954870898240640125007128754133416241951"),
+        MontFp!("19178897048453000979590659690957596324038669245300140765620928017217201486492"),
+        MontFp!("7668471074291870526245483897884601792626426080083647233981123797699500787980"),
+        MontFp!("17022938204221917796509718984925895198444138607270396412440297468084153383727"),
+        MontFp!("10938747411001421463106680010228586254730710894241856448408311145676137003709"),
+        MontFp!("1892143994611253681927160695719312882099525827316460372080933907151205825399"),
+        MontFp!("2626413664304179483436880400231214693597358131317388676910101259191110264005"),
+        MontFp!("10976814250018194880310517382191223839713741375476951941358522267556104616194"),
+    ],
+    [
+        MontFp!("3267603976137604608815546917515683877598008503122930381370588099122094818035"),
+        MontFp!("18223585230504941070194267966378685287221743128395324427323638965512681791787"),
+        MontFp!("4055897021092860484143383650117982675609656498724344612791022670810747280835"),
+        MontFp!("18652001434191198724037217430343155151673545332667032591923572773249520166995"),
+        MontFp!("1179210983342192637294098069949454912191992256395734070923896011947222260627"),
+        MontFp!("4403412539347069757548448548289536146089860634393235869990028179479631393017"),
+        MontFp!("18208249577016536190404023195559477353692681610041814639755282640930299265764"),
+        MontFp!("5253459060178003600605009461295776576191151024266914967218417406063967602725"),
+        MontFp!("2110599375707753504956307604156004992055199034205281989577749327575131764193"),
+        MontFp!("16838175205667561737978735977781331049290662487428014461885404122880770700959"),
+        MontFp!("6473428079010461623647807107937762759737150259352991014918746820779470984847"),
+        MontFp!("8337370031139132243770630686523670334344396638842630818638144451253681713442"),
+        MontFp!("5852133535345551978469538570221409138541345120679970583582105205614182914641"),
+        MontFp!("7693908046708935218171096369565374697059710647347392990894755587360287791527"),
+        MontFp!("6754690467826351700887172852005234976131445583530162984365436173814346372178"),
+    ],
+    [
+        MontFp!("4362899351088205531982963806583486557201252717995038448293398829823910923472"),
+        MontFp!("11518397041006514564038599401506526387562942749501723393674197214904315107893"),
+        MontFp!("20606341697536003623317613291213380804130123512962185582210369767659416485838"),
+        MontFp!("16897394754877405156789353426985842311670174197348619627467370676352261158652"),
+        MontFp!("11049995264887964858828368499123384474282091734658191426291499678845498016770"),
+        MontFp!("18903841016151023909305743424460730902070062204415137089939274033985227379247"),
+        MontFp!("14501632343069777665672565757138143573066425682965558756989443143462299059377"),
+        MontFp!("11936194426294671569251421865691095594275214629276606276242590758676139955663"),
+        MontFp!("8684782852463301178275527204056308121145836348455196441596832143888384190591"),
+        MontFp!("13840275015334112173632265864573045139112521216777064496416258170300441524371"),
+        MontFp!("8976112149735004651499648151657522459186187854485087924493254571044062238478"),
+        MontFp!("2557541446593153253492913007582627823644717754910327615163106890559828872362"),
+        MontFp!("17214289010670114093415697072867115184169717632618753776383803280726887828982"),
+        MontFp!("9277044732799923560347274951803854995664839245943597020159605756847120319168"),
+        MontFp!("8665104485244718969383349524127237156930430459852710098382428996861193438718"),
+    ],
+    [
+        MontFp!("2966017993337369327831105148290320997881600321998609267125699685969931023637"),
+        MontFp!("20703140915572601301330743722461592884427012015286406537986295678495182028439"),
+        MontFp!("12415056396133226456247587270673507158810318408454307171336801889305959276558"),
+        MontFp!("14096884501745579659192341381525893498221351476730589189812251926483574089240"),
+        MontFp!("11380799045102603249740262962085086862746282867943901718244205293076495402152"),
+        MontFp!("11397463999860523006350413477163951990037313029979805067679629038011006323456"),
+        MontFp!("3989254560279764593104713297200294887781503399600736805260233256187721580128"),
+        MontFp!("12670526207690537332382598355517632246478654103765566238996179271593311461311"),
+        MontFp!("3183711571356392622250181639411196710255078687860623143026450897732409025180"),
+        MontFp!("5610846600257417510307213084599977483933519996901968341741797171439650039141"),
+        MontFp!("3280606490416179974005759319341626407586508917823138878220428529454629673364"),
+        MontFp!("1201982324417186063031536229293952219287079604094432487446300310977814955299"),
+        MontFp!("5320694228353869326806779260357179675523748849691821041819016623807887766991"),
+        MontFp!("1117900147109997141002482710095589298468009897854242933473562031850755107739"),
+        MontFp!("3423874914270570048663861326353594445564054387733265522218157141041404209456"),
+    ],
+    [
+        MontFp!("15544724812507000325032356684034497915485954044805225704411638706645864153677"),
+        MontFp!("5773122431233952373926318394008190376724448498619094117536291976195311877322"),
+        MontFp!("6101823265492636176451963193766486622777300610881276372298697176417958756135"),
+        MontFp!("15795396300010870823125638802470599845845464744307444307899702370966825169519"),
+        MontFp!("1323789030194931509684647858838729962688902410898850967128356366290242773839"),
+        MontFp!("5751046064881673173633677922158261597917572500845688735304279201507132509829"),
+        MontFp!("1621252171583823353515150633750260236914561977816101468910968069661001399932"),
+        MontFp!("12193773521417435700759146251386454694422997324768376620870510312596267301181"),
+        MontFp!("6582582178277044206368630428791785430498389945338461249283089656681050213384"),
+        MontFp!("14215781677876725356925186332463972498447213260700771466349787162195918816425"),
+        MontFp!("5782842445406193701766362226063474843566378485612132749823957396025995938674"),
+        MontFp!("5452153017648783662501027666999013472879951066118424395570738985848450672673"),
+        MontFp!("54899108049022846277426184613878330780751769989719315816516065174629128493"),
+        MontFp!("8847320923102214377720246218239804718366581789144394009065472718259977212062"),
+        MontFp!("7818599458828105010909362503034203380908251186730393621503231459166558068065"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("9296474750911444465025945061626611450573261102544117494435956219223872045013"),
+        MontFp!("5146180402642819236271333772846779841277266622562021651959982317806096554632"),
+        MontFp!("20454733758478774733902661862471466785458678414673417084919753466149262477949"),
+        MontFp!("16939720779646471039361700224425387363963401457766730187304254365590145685643"),
+        MontFp!("16508379613071589632225608856524646606497744838305343752126125339777944056893"),
+        MontFp!("20867355466989514329400908378893992018169546886162092188544040547127653338003"),
+        MontFp!("9562028189723224670918372808954372258890790835333923497839294908971703629044"),
+        MontFp!("9227509288281899178360094205033853851646604997619773877005168383222677033411"),
+        MontFp!("8509781355418257783679010460137433931646066572707591432255985024195888527301"),
+        MontFp!("16352397893201944000231474199631274335610857147594743875684031732171609392779"),
+        MontFp!("7168331327842901194795012403324475179802342440545189729818235304752196473490"),
+        MontFp!("6805437858781519809721294519096505646809998863249026857458359222740400480093"),
+        MontFp!("23666102285914319661214067066621928648019777016465806627627356483470384578"),
+        MontFp!("4937167589168346771422573562192746031838523475116047879790134471501476035621"),
+        MontFp!("1346691375815699628539641638232212515770545677194558930872887124097351576509"),
+    ],
+    [
+        MontFp!("40983635009581820916284370715993074471281691288657861297464948812277259151"),
+        MontFp!("9682480346612414028382424944197316598030638601235308149341398931369789543950"),
+        MontFp!("20156146228109066705562831124708208402478981918458654302206305397911512087980"),
+        MontFp!("20244794515708180670563002449620617650597057411850557162891558214158122324521"),
+        MontFp!("5973431503164625202944413714022034366590666955289560873103517106326649624937"),
+        MontFp!("19003031440781649161524015602474761488168160816940423515211864494962159501828"),
+        MontFp!("20712605099727052887180337155696823289955789009114447705180663042338119444677"),
+        MontFp!("15751263674652110912724722781530878809312788917232502379399572977178318108528"),
+        MontFp!("9759440007076373606849230657528653877996372541705166313887529308971932175875"),
+        MontFp!("15753864581145946158736383142711883662973744817981456049085179548487345401534"),
+        MontFp!("18516736976984654236711834595195133682620758902795528251669085852778367276210"),
+        MontFp!("12505762035583653640932505661921159087471940695093437071026827168265805916842"),
+        MontFp!("17973475260212324625956540069233265735155253700466680406635575121618318752819"),
+        MontFp!("16893238145591341977099515648275086133943062918579997543738633084157810726656"),
+        MontFp!("9669071880586595648023284909288486812190820353718106940661900367937844527179"),
+    ],
+    [
+        MontFp!("9349088318894477564190440283477178116641834248883927901328258581942583077569"),
+        MontFp!("12186865849936919100247208155624431297953689379595363409626510899600302936538"),
+        MontFp!("14191429059221279746000430832297028082244506830783856999765158986408198377932"),
+        MontFp!("180235525898780969912044806879591313131227292826139844018949863600387171682"),
+        MontFp!("10889092107911649889040150092427122550710450427668385404292705974881857473203"),
+        MontFp!("20693842198933957663269842304864226322478103663024796211607837700737760050747"),
+        MontFp!("19741631810531886646761927581354810838182866370317964412790883870153217103250"),
+        MontFp!("20514964832794580053019916730141871673725635044918093569735954402314586611172"),
+        MontFp!("1563081830952767432323468775999289294801679977848773475307184358241727189864"),
+        MontFp!("6541733180459084604825913391805383670644609126706479690307729028795470985900"),
+        MontFp!("14338950551652503213249338133071776308134322059752479422120853035176120166434"),
+        MontFp!("9445962403225070324948180958168278025259517118659455884585920056598191781467"),
+        MontFp!("17673503748974627902616087621827013509215108587044719385816919777868083778907"),
+        MontFp!("6529067669277048252246949093210874205656745103861744893697681349157159395382"),
+        MontFp!("18670351688014831561846153455584274335550086431574922181801742319576494275746"),
+    ],
+    [
+        MontFp!("16087198812208315804697003049026004024678636407919712012630915190666161641979"),
+        MontFp!("5409244980912243970485734262038475969739470891565229610070736907935180643717"),
+        MontFp!("68998709875023468479905615300134369445692715997447916337587330860748485243"),
+        MontFp!("20797106660136416987264043187693969873379408007710762405000375236313842719688"),
+        MontFp!("5277839105793767851333321356647781091931078040812937205152067116052352239761"),
+        MontFp!("19813316014421099365974197500618281012606383357022846408656988057396247542705"),
+        MontFp!("14651854807736789020692970167659260203349703129364255901280500438993569147591"),
+        MontFp!("1953950121645869390891017290629473806985703435547819703015178261443018199466"),
+        MontFp!("16966986407661837074189113052135209905137771226341312735335483526986039302676"),
+        MontFp!("12196911666087491240035929989808617508758866763772938948559650992149525851273"),
+        MontFp!("6748262588722620715981149705241221174138004527637005241186672382866432164292"),
+        MontFp!("12351853389756326378242661122644604232955500481743248231924728979085806709527"),
+        MontFp!("2565243335980149108987604508873860765952308183381198225102084152967125103373"),
+        MontFp!("20241307878322245036836862464964995816180104231642798486998859049422772672116"),
+        MontFp!("6173912854438429518808824631217531662651777757438489861961683800252069642271"),
+    ],
+    // Second full
+    [
+        MontFp!("392719645589691148855449884443988672799862378880367658864925364810308158541"),
+        MontFp!("3685551641667332817505970777889874263257498648047179005679069858878239338060"),
+        MontFp!("21636266924388678043670779518381645484139129597308274177295703055588816929542"),
+        MontFp!("7321567536895702720774368439707249290671186165402244403568878177007380798611"),
+        MontFp!("7172839196695686167303608986523055739039297465612557351529643226329584487820"),
+        MontFp!("4229778574282653726990227569322645489471840372440828936529243607112035640074"),
+        MontFp!("18782302953492985194605877986375464862209789031757664619583780308051670003641"),
+        MontFp!("11974809482173143458078984920442199204778548823433238304966088658895546634996"),
+        MontFp!("16243115558222486864600123441049281534776025041710213515085053285162975382052"),
+        MontFp!("20774412501254836125508172987964523733402936236671082985700605339729723682856"),
+        MontFp!("1483065919525318072073144044234229344901017930590205131905224416619628808251"),
+        MontFp!("13859608645215082985012618178187462696738961393768893322969190913266642570277"),
+        MontFp!("21405376459105682082984005097612235541120033931778677874212817450507078023807"),
+        MontFp!("11689238963312530686999514700594656275688886785400717773550428094425955677451"),
+        MontFp!("13770805346049970815658755939543097628223982878203541747699103196190562329538"),
+    ],
+    [
+        MontFp!("13464128750778834946158208517080192694313764854534278881787962107045396572111"),
+        MontFp!("21407849640224904790247752730042846775377573219483807581870525715728151019912"),
+        MontFp!("17742435015936027530371893290930447550842976399042348699434701387859868436168"),
+        MontFp!("21607472280994630036571403820711131618277491312399185275464826427174795496015"),
+        MontFp!("7688464458545967396781600592854525549649369419708847302997939388491317131965"),
+        MontFp!("4387376978957625118504779199367262576455322502411234315349687321331690532595"),
+        MontFp!("18931911591990162605728772083755015441650830148575725789586998382906047952930"),
+        MontFp!("14640568984073443762298448827081280142493018130989596534711086636304928257387"),
+        MontFp!("13306842042380077440486698326492089142513016713060879132515526658677539077869"),
+        MontFp!("16831808713587057769987931635351931409992316524094756901264732606403438287007"),
+        MontFp!("11322415449116068033081427912158622347193126836157199640557951056169543153666"),
+        MontFp!("6851604032522439802523441319645962614365864202922586227207366934548805743971"),
+        MontFp!("7865033453112245503939716349145002950003125074081779764874079104952011815860"),
+        MontFp!("7859039670086457402270651853161690366745490673814388503748678622683637617076"),
+        MontFp!("12915205729177842184089675224634811499690953326168778547490092950902372657871"),
+    ],
+    [
+        MontFp!("20937773388247481346344394224147944853257697507105144044681818248765914256516"),
+        MontFp!("13287060897761790924722969858390853273555324655965292728828260759103061260482"),
+        MontFp!("13306896346810583848301553492224587956204219709116827141735317644044595737290"),
+        MontFp!("18219814232296473414944129594782177430971511097498319923644038792152844524438"),
+        MontFp!("5428298390273304248312869576101694361963883992477060046151268450358678474516"),
+        MontFp!("15201216935560530575994837331432805679558316705524948725894686934721622162910"),
+        MontFp!("9185979018761703837993382762881735970994413889163208925197647077666234876915"),
+        MontFp!("19181785293325954761013200262201782189994371972312628530578184844339518606203"),
+        MontFp!("16637687071791710254394571978230957592057586799407792249481371375691006025241"),
+        MontFp!("7540695380854477789623159801158526233798259867148336812702557598270412992232"),
+        MontFp!("21033749924623312349444484016552328468392604073844480133610758629121621931608"),
+        MontFp!("9900738449713019488539657454744856913719757496206137183076543026413680378482"),
+        MontFp!("20521005306019974539700555774138941983710853964949502636832851583991666071090"),
+        MontFp!("9148625417956423696904186924742393962087070575705980963607554918018064302410"),
+        MontFp!("21162567363216498523188025354796572574708404245885103426215436531517848841283"),
+    ],
+    [
+        MontFp!("516078530341213545969683556809078278712251743628535410360059529914040404316"),
+        MontFp!("16846240853341179628895683480146570801780199580812523539736983428470139888117"),
+        MontFp!("3725063466997152760713869763783030250548722164672702170123853642540240150907"),
+        MontFp!("15613553480222544404649584760559934580548305353382956847869893563076098726977"),
+        MontFp!("20351109682633578989341194704546173168875553988784930480177729322649037322556"),
+        MontFp!("11996883326331293630396983616262966081550223706386102455796773922602029237495"),
+        MontFp!("7467763103586466278784854382537524995001493410600869109792768087664508057830"),
+        MontFp!("2017508073008218056990628353468704346479637577205333604607959225275229259220"),
+        MontFp!("1314798328573144515884513072855778523099366140621231925682355151505185465849"),
+        MontFp!("8723657373526005784062072282873485835867170661363188128978957400998918352651"),
+        MontFp!("17008566456391889354408264184115222893038736754092176958021304795161250142258"),
+        MontFp!("1277201481182954066723110290486484624236238076832533331201564897838632505678"),
+        MontFp!("2883326282329572573598657327001707359250291449257261775578634065330832194673"),
+        MontFp!("4435817386812736743435792142021329255563452933485604716406076651024269234049"),
+        MontFp!("12698961373404268899870565736817085990987024732021219796534551825706597317720"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("20373949892834246498138105881327221987245360841655169052122063615887220321147"),
+        MontFp!("13269719501466581754706990055797732063851220874637620573076979035861249715318"),
+        MontFp!("1578162066274870756439515512829351279665654675138074205960935197210851459902"),
+        MontFp!("15699466465478499203883101572116714566818501168342041974225130392829754730992"),
+        MontFp!("11504917391580472762574218883359632564098492996052059988389474392975779673931"),
+        MontFp!("2471534962918953214626354556158558932844702538697900698209059019370494276871"),
+        MontFp!("4461270721380837522838335428169036184773145514356202591842435276161887233701"),
+        MontFp!("17625308215993897091838888595388246859096597033404108848402595233466786865507"),
+        MontFp!("18815388073919190191562579690732647812407368396854637581401819367329270645091"),
+        MontFp!("18268550843300936597164339843045347137811481798420250960800057984676328312298"),
+        MontFp!("7226998309855583456700105258532413039112576746166962016437941756495852856370"),
+        MontFp!("8072980663193342911861442510877605529321700341477138509973424538392015970628"),
+        MontFp!("15269265747158306563417251524571764365372430081923021726955952481038826679058"),
+        MontFp!("19910037920320033458941731683391812599895690039363377502140416867160565960964"),
+        MontFp!("15486045363368901308969563040163325835584354680260407824152890548047474173382"),
+    ],
+    [
+        MontFp!("16416166568440710996186902352749667794644450153896415669656858302452107263361"),
+        MontFp!("16719876352169334284151333613847626557780385036803676145001883066946186048592"),
+        MontFp!("21250242709366561537300491285945444276614074599553674495821079421382627519383"),
+        MontFp!("18996863118748910427791883574992010505069161233259708625680518449974859990560"),
+        MontFp!("17413984989888387572882804568722637770904232773299911698227583956354366272051"),
+        MontFp!("21597529235679499224130811468867862981087206894945208547265972184954721846149"),
+        MontFp!("17271950603399986802591192304636434671723720851969778588610595038101316019146"),
+        MontFp!("6798909530322911571363098835602980477334813891267210720952461019098407212259"),
+        MontFp!("15221754151466299785203667902143040752208351220550667002070154445706666401776"),
+        MontFp!("9969565572595685753856723561942014758757583006296150732797865871416014191612"),
+        MontFp!("213937728610177048236193694412457038661914578783162813372436128728852762482"),
+        MontFp!("15615646173777239619093550417838782753019556951599702936217300723011405507797"),
+        MontFp!("7718045694050546136660011535056217361530495049313202420611728185159583583910"),
+        MontFp!("6681562236645975908164269312121030538373320810986198141132181047805166475529"),
+        MontFp!("15406190007218505433124772738477886033924738791369244099805663445061334497251"),
+    ],
+    [
+        MontFp!("12748925302440648174656014197639220978675653093966691038521528280766659388490"),
+        MontFp!("18792405480956246176786158899722364426273579869771820318487342148987615503627"),
+        MontFp!("21034833205180901867479986245412384531166379419487221053266758595893395608147"),
+        MontFp!("14993530305792055254503672111497655292586320227932876629369830030730566504041"),
+        MontFp!("9717186869050569007497148725820161576480205291215534893842038294586234260310"),
+        MontFp!("8846581556095542298416062056868302371695747263219967935160771313384250848220"),
+        MontFp!("5719380810982615524673837631402222949910527678512468701059039624789965209167"),
+        MontFp!("5973398204091658428913223841081738015584068771535532858878785900515770169284"),
+        MontFp!("390665721029373984714826019392824960041229454845430836402699997231854884676"),
+        MontFp!("16884099579377843869279635395084651641533249525144181522512029929860550566335"),
+        MontFp!("16716976662979247989662403288430439465273241484103184167374388250697744962395"),
+        MontFp!("1289338592939287230201589454150014946433268062090374613616251000166621302313"),
+        MontFp!("9317927644121595578754625372882467923006787730797841509740916536436754202554"),
+        MontFp!("9792346806272266839932159619375452292987210695578600955864989901266954094960"),
+        MontFp!("9296083977157792888817756474494818556822681079104304334773148774182988756476"),
+    ],
+    [
+        MontFp!("10323608200914404656342379674401080164220099943588034731349775904877409752904"),
+        MontFp!("5665882510779783554356611639475035143817214951779938939820232711292028607543"),
+        MontFp!("312967658452369621468725894369965307895839161467077935727514890821777198625"),
+        MontFp!("20664282841391311519597819314930060269576136022608505709992590417893434920589"),
+        MontFp!("5323350315725158992622594066490821193659726275127554898433661812401256218478"),
+        MontFp!("16039351301369040724720782620886565139379163954982512295794534252600896786349"),
+        MontFp!("13820693026747418768344065399393852077420709727979822490883114289330249839237"),
+        MontFp!("13797176144228246839971555725332102191019905588045129521304130064480669479604"),
+        MontFp!("20861818286081952786814445160300163171950644955642439845329412070095760317081"),
+        MontFp!("6607366884537402504576829976217056754321721309944806800456553075345742381854"),
+        MontFp!("16676075047056109258708765855713030298752565264610707737757882684833916800885"),
+        MontFp!("2596872657894796835537858907147863451585796495210087845405329734139833282637"),
+        MontFp!("1129905770296794876904745792895109768657958745069004656310552795892948378584"),
+        MontFp!("3524768344101236852745306230236516385903870540974561871381448404874187920054"),
+        MontFp!("990706121628678949689364737717640674078492689689565457695471004362978678643"),
+    ],
+    [
+        MontFp!("12808278123975447545284898374625872643451056414444411676912801184092083815084"),
+        MontFp!("6358161990036632946294642058872786712712883111056083194592676110308231876108"),
+        MontFp!("2793873016535929696420652383221830923887713214117784093571041985641508474546"),
+        MontFp!("17305928010614439499134847438771424617226147912488714899621343734884653872709"),
+        MontFp!("16284502064199759145361743401051151176267685561605421586130298595070638086967"),
+        MontFp!("16669378724978577155412758169912049188272082311653969294949527064226374258950"),
+        MontFp!("11861408925173541719238679231659983919027526756130925399544116580983984008351"),
+        MontFp!("20319862771317637561778652893808792643716731292949307597222212602977661607952"),
+        MontFp!("9965217723779805596104842963458526859730058546126612755023280528059812781678"),
+        MontFp!("2231229143040861060383699098560329742848948229177358362095200642518040448582"),
+        MontFp!("13286704465412914480712873676020914803973411257385854321890647189069199504571"),
+        MontFp!("10047635595882320884409798135378777636448641999630707194378323292740008960417"),
+        MontFp!("17087336000400458291414525340830964856800142468906625956701637877370905283650"),
+        MontFp!("13535594575282729223473509709844990145664370669908108705760692736046179136608"),
+        MontFp!("18619202345418036145538258744454149503028380077256559127312056952610301046510"),
+    ],
+    [
+        MontFp!("17607804586369163727645967551963527279187781470886431384954240254070602283137"),
+        MontFp!("2633042556450301220705608140363996319867584411611510879359782399294296770849"),
+        MontFp!("21731768638998447189300033743436435314153248129574741734083353285598244080117"),
+        MontFp!("17469131737454037935821691482173830563221091395109883834897114256467877636669"),
+        MontFp!("7750519651616125729362402583651846123740462681349952291880562002377909054050"),
+        MontFp!("9447081032000164239615272903502339392629921187510175841323135270498903409976"),
+        MontFp!("21517411732531464267453023393592251500842566572268409532461770072252092958721"),
+        MontFp!("7876501141846244962862794902521772277174190799788868978106856756141641727971"),
+        MontFp!("112943796512817751182977688559740204117088697116147815915631198815244286561"),
+        MontFp!("21045605911504121416853713285261007893735116973950420994950288232723845218749"),
+        MontFp!("1753558144636632850096274509984476357404441186238175375676398948118672743832"),
+        MontFp!("15932527070583999576056453444162361416070384829011926782656187054064561647022"),
+        MontFp!("10670345925352776542283780467580832901477001176626927666485810080031890388634"),
+        MontFp!("7579612604695836258451031149594628860115515363868399672164640908835805063913"),
+        MontFp!("16678286807861813265032290142015890821080993558199809221760573633471452514219"),
+    ],
+    [
+        MontFp!("15414078726286200869397977002633905619706559151321201886825291326757189172060"),
+        MontFp!("3948850363929702397926233515670425547474212215810847662989169477642088807390"),
+        MontFp!("16676527723244281555583733536047809454744760551541688199219131455695588765207"),
+        MontFp!("14087355065973222250918209290719451048172913300838805685888196087459492418503"),
+        MontFp!("8104074033209440421654249234822181338065658024406724406758136674393332631833"),
+        MontFp!("5187207728881418438225188748028865692950088102865770071392557429006545519499"),
+        MontFp!("10194509841193628413711183989804813746356025348148221750035788743075560349210"),
+        MontFp!("10011332996303549537072329954668024063758462809797094286213034989313201039795"),
+        MontFp!("16531650268844669358279021502930777186663937554425443224519161588787088219270"),
+        MontFp!("4198803426144178314296362723890875454161257236407666607766866723687553739691"),
+        MontFp!("4796265297638646381399181426481493643799772847333437062194249373663993441511"),
+        MontFp!("5547609802231005143723955844984958632609857658378828698100141356246667308579"),
+        MontFp!("9238958429509576319892727665041354560043563806656332888234436819654598656915"),
+        MontFp!("12517630133819614217261779870726755029108748195491643235440705119352058557308"),
+        MontFp!("10368014234449326131235994433740216752312468149182650354075154587397704894585"),
+    ],
+    [
+        MontFp!("487465315883418182347663117280302929984519597673016955398438666336416216955"),
+        MontFp!("11185034244837678424376295011043645561088905841730131726788873681031338174455"),
+        MontFp!("7421225151165727634904994585688522807152162423870550118585658136113463984936"),
+        MontFp!("364806019968570676099566454292016788351689833960119434834370974516536828706"),
+        MontFp!("19587762041779243275917908822520467733740516572707471226884058208850887204874"),
+        MontFp!("17699580368555821903526305272862177569486615328343491839828472034082952960813"),
+        MontFp!("15739138573269259325517597876326746482937619260543240058947651429318524320399"),
+        MontFp!("17749815771695585990262895269645295514834869871993754550237121995617786628911"),
+        MontFp!("6001281843453332722738415535953496883454214144943230146026585272790933236244"),
+        MontFp!("12226248842279453247400486197034488006724059955941344491855600110495696624034"),
+        MontFp!("6471818050024319863197241387509891745688912297463370650154695351698691437030"),
+        MontFp!("17643944916136214612858153024594861226464689685432825152621939791565281052364"),
+        MontFp!("21110507073644152221308742247603861828087429176260377204494317652281698346306"),
+        MontFp!("5767681379026755049166284789191192306599058359643996830685583640018326835809"),
+        MontFp!("20268516002780303771030074402103352053711651542638490897887552979541377898692"),
+    ],
+    [
+        MontFp!("12422024375924323315232423143442023050941283872688585715847426230444960244284"),
+        MontFp!("20694378016149655833849108581703458975143197158248183642690852771732423212333"),
+        MontFp!("3589864715711417186674798636588069952164879749159939425060725590030374075726"),
+        MontFp!("12536189236026623553502052145542282538682809966098904986394409008189339523570"),
+        MontFp!("2807680329090948893979159370041090163078264208853025987363628558807228757758"),
+        MontFp!("11134364423913486029994525311335222090667061028008118303028273833566959977738"),
+        MontFp!("17354950677485256631166408496835881692979370134492552889236570818914175381226"),
+        MontFp!("9148772489395197941519683034415731069796332771510468178434252005940900987586"),
+        MontFp!("11051248410353114396778661421385387052304377861539041695630551535039137025728"),
+        MontFp!("18136540266852326106685174083549032333515525540247442551401150755180487725743"),
+        MontFp!("3985310545130175559232554478662499499843818415947765870117105273186277940709"),
+        MontFp!("4596525943567680471287409788454878169502290977680316722474714256040545023213"),
+        MontFp!("21372340401058762695712005390221995267277180470546612925471076506446695116009"),
+        MontFp!("14075257312640062020412482491643121652025454294874360644194100265009640244905"),
+        MontFp!("16944503245314408069496787236107780704688926045771652862339257733069860638621"),
+    ],
+    [
+        MontFp!("6654085263135548167634225627185378533095825854533316762555824971264586187651"),
+        MontFp!("14273015308464032668640083373269579252065688901233915918278293118708191497972"),
+        MontFp!("11764478817260526126925152191588101018315006712336605965222952598280968869260"),
+        MontFp!("18155813367404719470487615677791656111973118264369674303621800593085173024524"),
+        MontFp!("10850039649283761393985741586943649861203783935137056310000609328911733104269"),
+        MontFp!("14974871370837446170027589325430324426484756076474120128444445200816418916077"),
+        MontFp!("11027268992558661543645635853060250199670617750348905948352231500166030736735"),
+        MontFp!("7737561462151958609897925089803246494900406501278116203687665387696545068888"),
+        MontFp!("8536397585289585667363111427598616854125268561779225310999422087929379230845"),
+        MontFp!("20520653588911738970276129298340919743898256960050723343166349159335019506767"),
+        MontFp!("15573689478722985997422248150576705853901581704550704092699815959211941071233"),
+        MontFp!("14024637413191434115623282477558112970843649259330326803453616105375507215588"),
+        MontFp!("21353180477994165001466351039795610246389619571796579650797005446278678838787"),
+        MontFp!("10956420585038523642301267761059211443146357574323727078003385100699325814188"),
+        MontFp!("17634836995596798350536418601573649005517540866687768902898663209263471034753"),
+    ],
+    [
+        MontFp!("9073831830223507407321206741538426673038288500939999264368323867070102600399"),
+        MontFp!("8100061233881358546546217363648928017848251671613674228091904606518338839516"),
+        MontFp!("14292884686436880002999028765738013651173249817687065086024117836878136072418"),
+        MontFp!("17843666610704256421074257091600372846189412242040711501888312601205476877610"),
+        MontFp!("7418496999733433074316279404200997784331208365418356982886646484553701153757"),
+        MontFp!("21292338796777812243292835631771215001169911232666037289555901315952974221584"),
+        MontFp!("21287440813442297789932443224883752220885368198719782335487617798315717278443"),
+        MontFp!("12316285862536720021079405901921917335504024672055930573734336005894401174699"),
+        MontFp!("3187192338760815459509556016254324490807541427929108750144803145054500486833"),
+        MontFp!("6482850190067117044880439000213252508905126459059393355671593783930481002065"),
+        MontFp!("8071947666795842917503687890154152458243669781913953337362618950188906690910"),
+        MontFp!("21450848111897538423936810899713904909897296879883982984541189476327391575319"),
+        MontFp!("10587193221429670115264727936502940314613396351335733485096445152378181351510"),
+        MontFp!("3385684482890050363671196710999686076700864071377274740326856556910758812375"),
+        MontFp!("15982506074704654515559890904595916307715088473006256644287900474899011899187"),
+    ],
+    [
+        MontFp!("11051707205631537861946095917392475702553421314274237258592689190929368154151"),
+        MontFp!("9251268179161501251470629870144065342881530535654006637177099334228872748754"),
+        MontFp!("15384401424299601275458808814042231311961518546060835375991090955541715971658"),
+        MontFp!("15147611899621647169760029554894128666862161642594655274320073446753024250644"),
+        MontFp!("10615109639224170479859701634647953864795396211020891058926612120687182210257"),
+        MontFp!("14404478161927699835476596901422616937403414811611272602635749875651680921447"),
+        MontFp!("8066178271733489868262162784101440625233465103401108911752234818690246453789"),
+        MontFp!("20248276648316419325977215090702991722051292305169422329958075780094418699373"),
+        MontFp!("2029002457916171156303194520244672392499138634810303216935386602108547985314"),
+        MontFp!("21075731183134253416827200425853801562619807215475840771703429923133977728069"),
+        MontFp!("14328824218343127380036695796575765857116907766196272954596277912518546721093"),
+        MontFp!("19160580257616407097132379087560824295215411994049928358528355359904099586504"),
+        MontFp!("8132787339090119872084216324271073977022107784441826475259174173324397592845"),
+        MontFp!("13163912407679060956610455137901723175820157380216990622789851897632818074059"),
+        MontFp!("8592347632837509846508131492057017267103172209796052768156176559923311880083"),
+    ],
+    [
+        MontFp!("7893378189384253642782385691967744125589477790209699483381677762080196748261"),
+        MontFp!("12191210041395050728454216483522186705806341251170673836903099334267999015601"),
+        MontFp!("2618630263994051322846776438484808085311498796047898764787894432257296575984"),
+        MontFp!("13304976328362334711292646669717074062694791647749224129724116074506061700957"),
+        MontFp!("624484185190294110172011007497433523891084548746061325634035014633317325140"),
+        MontFp!("15930533828823189720011923265223664488732840218774700941123034073028585645207"),
+        MontFp!("8694885217655511794794497743599831016837108207363203834593510913033381737488"),
+        MontFp!("17331704109224667609742217259848258607875799984699711250101367091564505664522"),
+        MontFp!("1143531962761362035993655799963451435028684354850849659611727546724143302608"),
+        MontFp!("5414384052493950126841753058585655621007207302558963578017856767058437816718"),
+        MontFp!("8343436020075567035126766344602793013516161248129384290738181959425998466132"),
+        MontFp!("4832334637017830367140676637457267590768883455902783371477346052893423021577"),
+        MontFp!("15960489327340978673041589758216206637417346222123069896632550487782527402630"),
+        MontFp!("4557633726568362237945959401789921266559833502098724828463753976542737136513"),
+        MontFp!("21292745345591415194683498267204047392092318996401128975083535124377610645118"),
+    ],
+    [
+        MontFp!("9328286636803072400168705383927986130049573701928015781654594560657277940196"),
+        MontFp!("4380226305886799411237881027947999265683595948968607979382183326525427173817"),
+        MontFp!("9767464780410766464122903880954110687963200055038905098288212464855640106788"),
+        MontFp!("2341077008076389666838372736815646466482189988625828995627083487505977087946"),
+        MontFp!("20374148380136094638123722089889049817432074134077941767483383760377289749825"),
+        MontFp!("15787533442296590591714142770598935669897792586470392297628568666971956576563"),
+        MontFp!("7633568663576517885027652011215374332444473983000466204175445586239040766264"),
+        MontFp!("9580455424171239687884724329420941872151195359863514187490663550878551019919"),
+        MontFp!("19911295277809179559313541842789165346180998073715101124443882860854846201169"),
+        MontFp!("4696263765841909122685466774945153879000521574484303419094957852664345027123"),
+        MontFp!("6118063668634440692631158765198200090955204724365488531804099825202234531440"),
+        MontFp!("19475295610121478762637598544231725416556110773059271107863119783250115177307"),
+        MontFp!("20179584065699439977644558242108004135948300904465438207718184556293527131765"),
+        MontFp!("2676625519221951651755843575492662910087680586300055636532952929295968711540"),
+        MontFp!("13080027857034076796318609243033548218531614593023186061246772929628249264284"),
+    ],
+    [
+        MontFp!("14263363493033688982783626695159053510832721506041125050282934376406154340703"),
+        MontFp!("2706925569052822828207630841519123914917406668054471879280093987418440726365"),
+        MontFp!("4296262245978655924075197707705805754837595446469608588294115404804858164512"),
+        MontFp!("18695068331082119185822574502658840301072706119995753204333012565779354028154"),
+        MontFp!("3337114200744822151016415017664070914837554273478224716426789950128003958134"),
+        MontFp!("6975962532267211837956893753783559781855104297873769130878094791004904433267"),
+        MontFp!("12231027575978562434100945363599501995871801149646690231633189894762835916126"),
+        MontFp!("3888650643207658707541674459822797638642380807202524073478543941467788883170"),
+        MontFp!("7676102615198465271172138409531332193754467889637067928466752449336805364120"),
+        MontFp!("9811623913757464238022803188199125269665959963552692281954397128646993550529"),
+        MontFp!("156972936087215783948417984577273879197745728180593700417440323304914519034"),
+        MontFp!("16588845615557743733989563376260327418375621586848888397570715079613783522187"),
+        MontFp!("12011819284668339582414804793555969540908484357893010282713633444914261107919"),
+        MontFp!("9658635959610321892309182692375199095125363721825304507540533137281497868500"),
+        MontFp!("12870566947850007704602286722292111587887779596594529150836676091488336717955"),
+    ],
+    [
+        MontFp!("17017505534531720639069672825866463960388881234646040969078396172375695830933"),
+        MontFp!("12828756987874340466657656310127299330830014385503837959876241551509225608029"),
+        MontFp!("18359630725137047619220866091928132852289131876343072040435717392635728807670"),
+        MontFp!("17863140285101027979634946030001422306163257009614315954049905801373677640874"),
+        MontFp!("3360582341611046517265570944669154454984314353967466888538655993528259823694"),
+        MontFp!("17502670755424560054495200659249586316206934247721147538443383457515937914644"),
+        MontFp!("13927649797430421409818392324255621487749881718200898095569535249421816857631"),
+        MontFp!("7898469048097586242538236499785851298058905177690518852139435937277393454608"),
+        MontFp!("5393613856505501947177492775058221051864341377769606207857027894289988034452"),
+        MontFp!("3985540196927201732264077382523049411262602519666474163140081018404802939018"),
+        MontFp!("9933548682032172763206440650514545057762401680471287764841975167579530669418"),
+        MontFp!("4792769546077532311825527426101752293398958175332337087633556291257147047450"),
+        MontFp!("5156370681902535309417699137971512021677704826447998658592162481410036210325"),
+        MontFp!("4763178997526214364264420973622397594953864683634906055109407846288865926145"),
+        MontFp!("16580450694184328601228411647550657642835283837702883837525078295714435399937"),
+    ],
+    [
+        MontFp!("18999059441252116518985471589622428967202991051386298987587330050320210739909"),
+        MontFp!("10924663926297817693040648165612355343542542868891379772060513577838006191064"),
+        MontFp!("21154439824594463741340124378173590149781737214921140238108168355994559155084"),
+        MontFp!("19866718036982650474956880892863081255204850747846301678102177541466640536845"),
+        MontFp!("5726341152794890743559900317356587749078768834368131432915420083753465739652"),
+        MontFp!("9721930072810562138700262351588760483759343649062887160202262013979665248772"),
+        MontFp!("8692713109320385702126858931428174471319167530336355188864897071149715822466"),
+        MontFp!("17412352102001079694372298819013833514973940827985696439109156982412245617083"),
+        MontFp!("12672821032755011921233434696998986247906307111868736700673800862103166163534"),
+        MontFp!("10172970004738576173270726006846527765711642326938611469845242801391711907951"),
+        MontFp!("9780618262465502284058891490165578338456400832996467543839344602761711940182"),
+        MontFp!("18851700024286130309776897830342991020297865786362943013731692845791157935866"),
+        MontFp!("1702470413617318930175864989944938294024268866839160834076264568335720361305"),
+        MontFp!("4126610465068493007303692923323412316073023198781919888394734000856090363586"),
+        MontFp!("18923463588257096056764527998950010226471775437259486940005784970583303111186"),
+    ],
+    [
+        MontFp!("21839276475997615446044485384539051285181302015654907519320251876081314334359"),
+        MontFp!("8307810470154672938299109640101353533274474986026012646848648110658880568963"),
+        MontFp!("21681710504784331775159894287594944141623698915845714535863219742158877134446"),
+        MontFp!("13673546849110664891321911520052734162092211680871085198884137808051757614727"),
+        MontFp!("2216218889963359247084339208809391515747106874740680794343547186367655541588"),
+        MontFp!("19115757323666287625233094721549891816100455942516447718127879700062782999180"),
+        MontFp!("9695759088071708435257280035083149593819491025470776577904130348886983773393"),
+        MontFp!("14990538824992915759805383740971251128446161760292769615173978580109946105509"),
+        MontFp!("13500547160059033639084125020765777005399100253116035721012986936873938870871"),
+        MontFp!("11690620517343570003741441631732985155983668292416671376092598551135607742688"),
+        MontFp!("14092995167301878045390831717670181419253620142006537525508736966592448309458"),
+        MontFp!("3134074644668983555014847848317126775181820544161601574897784886291988720093"),
+        MontFp!("21356667096328346522478877399307107750543924879742810010071567516379232573327"),
+        MontFp!("19328894389922014017090671733282649341431509867352021154566219841026028128702"),
+        MontFp!("16777200051435195914695940482277275703598221309253917444691069340963118078548"),
+    ],
+    [
+        MontFp!("12283150914371601398948839801032083610952821665268602559004246563482653048467"),
+        MontFp!("8031025780211541530328680242940535108474848337234033825435617391087509668168"),
+        MontFp!("11393488534594129202264782038673712630583567081414606145740976603672179543089"),
+        MontFp!("9489545274708587303435157546929129885054870357881034831999260754593859728111"),
+        MontFp!("6440297901700932642712459656137166095980435930130325599953289987347675780242"),
+        MontFp!("6894515864899267480052927589331916028154612433273964297048946097044117290367"),
+        MontFp!("16589536620737203892631819952021004148548871602361830198268389856564419047101"),
+        MontFp!("4583341011172551024562134025995013510576215116397573125685762514159294995879"),
+        MontFp!("5241434939346739917434576806032039711895865193494390109560604956906855912755"),
+        MontFp!("17826720085025179303670747265498956093868413968174402515352860124554701720680"),
+        MontFp!("14064177224800231170886754123172947484113558685092392153296947056267698509591"),
+        MontFp!("20746239761736290080585462212523994318855974409743295600262800987044075760592"),
+        MontFp!("9275091732796562047971474774056076192970912855497078395575768670699769126364"),
+        MontFp!("19100348980922843718890560618583044384034028895423544873772560921345167893474"),
+        MontFp!("8069571373226602742410885815839523915870737256484068502291195820379222033494"),
+    ],
+    [
+        MontFp!("1467293981248465102656318727619114500592839521238995988554329217082570049573"),
+        MontFp!("6381288201275521270245376318098534380164014423572839901345374016424121324717"),
+        MontFp!("15155207472765568640645705040738739058676005958167290460723767903432317983809"),
+        MontFp!("11827307205908787524136950608324322915050519697651400085361964512106546953617"),
+        MontFp!("21374803923432823385981703420361272374739614104998515368780896432531799636069"),
+        MontFp!("1620198401197709963530631727016287708030541032398661971728351531077981225770"),
+        MontFp!("4800625217713746765755573372019630732124860961049092382553729057755156854314"),
+        MontFp!("16885045113746325978276736698359862381890909478720549186885416774857519433844"),
+        MontFp!("18940059701033384450370562570502818829119108154708321114250461189220553261490"),
+        MontFp!("17028163572961360646345264113167539623561504425952365941615928454393252072957"),
+        MontFp!("1611295667261368131094571297996490796385397691978293531693197200319130547745"),
+        MontFp!("12046456112369511110295802643603369841325461255354393474919590204243253663937"),
+        MontFp!("7754599975225661234551263660451457408477000193002987626530262268904609507135"),
+        MontFp!("2313209556333942391083901663566216617901599085931440197682535151404828238681"),
+        MontFp!("11201045085233852526555327068198185072847585214550933934267941732063511616826"),
+    ],
+    [
+        MontFp!("4467724481011544137869960218540384367398804813150942792543111067523429032279"),
+        MontFp!("20757297420378962887523157926616026028995120570567878138398997747263606554197"),
+        MontFp!("17461435854008784993561933203163122288937104371480871856334630516397129102735"),
+        MontFp!("11233240529972088933066139644647717712375997842172826165553170491212908480221"),
+        MontFp!("13986517480527430903609790838030615892444640383875866009325809535938190376249"),
+        MontFp!("4301929714047907689515030408708811722641058401192285565408232097014641811166"),
+        MontFp!("17696794946098216231804098416293622660221918490072683908415671549432039016947"),
+        MontFp!("7439888160491058128389941744272690247182736763968209695016076283096704865863"),
+        MontFp!("18423890552826544443227868172047086037747632248290044990903922658938527547680"),
+        MontFp!("2138999949837278709489832079964510183830385901897653052233908704951853637094"),
+        MontFp!("8615970277754580973564280361114435711269905378483503993334701750963152744511"),
+        MontFp!("1245284434872653312507712347938909167594598148561528981150147522912318212857"),
+        MontFp!("16245768201990863935411700653063134435976966358871420800168843470345424885412"),
+        MontFp!("12879861318323580145511978223564804504159396722688932396568245995032194740594"),
+        MontFp!("2552284675231253239834976615340003107373525651900622595931449498227491766869"),
+    ],
+    [
+        MontFp!("980488019139379194961142515231528163966929536082343598209628148732322981564"),
+        MontFp!("7264750621517252424008399274736151499285393886075664598878039391991523050881"),
+        MontFp!("2234522067987218377738399151771319350093309605278907663987720519875860528325"),
+        MontFp!("3115352127411243786318531509959426596747177553864136971519102170874744602976"),
+        MontFp!("3667866402502198123848250630765454744061857715778956702490214141676773902196"),
+        MontFp!("3070805472938376665214561241692318374677535766486562510422267606125416243825"),
+        MontFp!("7823349275852374866499481666710330223055954666864547234510846404777769619933"),
+        MontFp!("15260540996982618345416395328474127890176746670866447824367770359510137832585"),
+        MontFp!("16879450644311650077796590833984905697151358442225768059780947025899660370589"),
+        MontFp!("15320151525992649849875202875042133283365355535963553893454329179458534031485"),
+        MontFp!("14632268633016875179098944659717422356155019599115523094512078700042675723400"),
+        MontFp!("2897293850461343844445387032809649396366859437152990471719742534237008502809"),
+        MontFp!("19666002760225463497556206571085469154515092232825812732816997876615778032614"),
+        MontFp!("20118956923652349866364311940594053676005949829436315819799225245413961818420"),
+        MontFp!("11744985056755246878773405286931074634975698324846162530423833591918403445926"),
+    ],
+    [
+        MontFp!("2015290434884472026423323399096281629968890595973845384920383969402450506434"),
+        MontFp!("18249544278720359760062826368546772272198884406461513288445757927068468076813"),
+        MontFp!("10696154943059697598572810332978032400281533074175542497867727183214332201316"),
+        MontFp!("3971341798054170372777083512695270701169903687966221904151853325481552612966"),
+        MontFp!("9959663659512617071119055590004415036736845473755952682208877124936249722622"),
+        MontFp!("2181868782404294694165345801562667214869325590275262047722759281472694906368"),
+        MontFp!("16543662218198449396015118334228119894116585978443535797616874635898860943302"),
+        MontFp!("56444853332991525451220607324577660725674378827134771417770990653696578708"),
+        MontFp!("18053264525785010356112278932118420278769989354317119094784720416230380388033"),
+        MontFp!("963511433856494437037779480692599956325036862396765985497581773445785537789"),
+        MontFp!("1640548195682821030419870544261428767973895477020340173398587128599119014650"),
+        MontFp!("15577232677357883687312806010171709394572550002708034453277797023283497421495"),
+        MontFp!("11090954381098620504472251563901051846520004004959525348540953956152044818938"),
+        MontFp!("17126684395012300883745178409829259734795520186865031009709416122834152378303"),
+        MontFp!("8422844186088908124243365558762687987341862957152073183459572023886741121702"),
+    ],
+    [
+        MontFp!("14662184649744589649763530324217861015549859438835398957469773613387857845572"),
+        MontFp!("21031377022606130696364565229626602575982595290181000993536506695259062820250"),
+        MontFp!("12689389963301532909415546365757541009330035137990488344057816579888146178666"),
+        MontFp!("5214588312023400585366048036841380932988375130807938893042700215898846591858"),
+        MontFp!("15041893128824524382738883860992957588596383766546394405410998279035324015705"),
+        MontFp!("782192460855406809992878758154419200401389375934686194113667662665836876265"),
+        MontFp!("5661239444927590688224044135973292706791664398184824354673135332710484834774"),
+        MontFp!("3817944105257087067488946967989598283409314186886285679195995872866829761906"),
+        MontFp!("9462770371773357009169408492776891016388486549772557694482752113820123322232"),
+        MontFp!("15769763308905916572724877727092407815390795595917044619305377061254245212113"),
+        MontFp!("13771914451667791497863967930538877162560051760275863288476375824988661915333"),
+        MontFp!("706561967078473376761233253052150494577539018705821668938338502999740910843"),
+        MontFp!("12390235197745683286169097117092390888206044377209705969844343148646378449506"),
+        MontFp!("11145596728577531179888406424537808841248238586984730649700720979694309693930"),
+        MontFp!("15681641822349110677199695953283484509740812777386467296516920337242539284544"),
+    ],
+    [
+        MontFp!("17574569459993920657262688380744386356573422289886331853177778938058648912158"),
+        MontFp!("5624326259006122381461763176192388804186780246412847832931645688163746289220"),
+        MontFp!("16496277716358307095107833912170230220700599576045238114071305799889738129888"),
+        MontFp!("13435082168481898917836725813624706153275101528359222531631402061384065427333"),
+        MontFp!("14145359265331851785705850315135126451460028716020850520657056103361268407457"),
+        MontFp!("7994845231841309191598369070742738315856499258678582728014902950258896103570"),
+        MontFp!("15231733338108258465034981184038200743349384534550555337079556783807466295081"),
+        MontFp!("7713361220902077511242971379188205561979775701167781811837114504665826686675"),
+        MontFp!("11242747039712276301478013037107867308448907318991409117791290960665848463140"),
+        MontFp!("578482125379746689637837359294794469152617864738083364508568444154322295070"),
+        MontFp!("10411717957746838375775334848320973308009407649021455865417509560443692302903"),
+        MontFp!("20227006425680272844667600313609218155469920109215388465037566152370252232679"),
+        MontFp!("4086233333215814731082706125141659248745981704236593522409792368006446650781"),
+        MontFp!("6808584534953180557531972017829075410964510413317063595166364533912498333198"),
+        MontFp!("14933356623681974917513408843614143743808832277364712291262158931793965369957"),
+    ],
+    [
+        MontFp!("8361829936147528340154802497787058714748584629340813748839621641190595526650"),
+        MontFp!("5538387899076347863195504194335672966840748823754653039217054113809390157835"),
+        MontFp!("6615705984888160955628612086123920128735091346352058978084487503753552928718"),
+        MontFp!("4247091362085081761865598677360777173802067597672781739780149117241511260340"),
+        MontFp!("4974625254311074298909209418614844254599548567616598303359921500987075775722"),
+        MontFp!("9907284404283923304330882823688663208972219438730081750092058981162203620514"),
+        MontFp!("14238658152145851054995618234965786835935641311230999365757330392464542182773"),
+        MontFp!("5602036166343678358319941150088594172266643715315669628685676756102877898852"),
+        MontFp!("10315461382975117983285315435824656113503983463182219407091635205239799886780"),
+        MontFp!("18907680493658247637744313142676664986314600274326418373879671192300682190898"),
+        MontFp!("8553303723584937031207437681614026720261370015480962759264727133956998736136"),
+        MontFp!("6891145490942460969785177369488530687011218911690606251233112277697487049464"),
+        MontFp!("19170245246106596353570021916624717497160367167030242670159454715827568730304"),
+        MontFp!("12963872577905157066479007532642021260793201956955966146918319119859824768103"),
+        MontFp!("19825143672793576100800645640010743262329532693712002233459160547290155991184"),
+    ],
+    [
+        MontFp!("122363237387596939334930000068508772338726942066663977131612051418977350004"),
+        MontFp!("14083010722716557573128541885186700095072789367734739290283132018427397490175"),
+        MontFp!("3438988943822218606847460625826294857914025511956848084069535393649532189446"),
+        MontFp!("10824463893276561180588773956898041497815337719354064658470380006885795713398"),
+        MontFp!("16647020853733827256761129360155419271528077519934494581063879760642045999108"),
+        MontFp!("14779736608400098952280783906738683105991809429340153405681057429405486938750"),
+        MontFp!("13142746404873014842415191205878004285381311757484122253939053617110681448928"),
+        MontFp!("1386333773150414920705543260472370744952980454893569383627231135209073588228"),
+        MontFp!("9697679119976816079822213749536923969219789272727871054723095487806154942217"),
+        MontFp!("3460404774244857416356250946884568630025797822298777944076755223444927083223"),
+        MontFp!("1247968706400267099989617117009880060043946421405967563604685657862139452642"),
+        MontFp!("4507768933023927635709805634829365165299253230159842263645571663212665854586"),
+        MontFp!("3426290614024232855381062903753364259178390841306198547519682507770097215868"),
+        MontFp!("14446229460062832266543880366376059431654246279317515826580297094759435628761"),
+        MontFp!("8067953446832743759173288639514778564060677449303509306198517269476636669111"),
+    ],
+    [
+        MontFp!("21177512050925009490126830107933732327927737774182499659239090599165030659885"),
+        MontFp!("17012508948587701524178526490941665696519922093511307343255635334215277408110"),
+        MontFp!("13790566627669249053646494281027520203709518820977624221038904711079372230598"),
+        MontFp!("16958540834863372886175563802155893167203339755555003665356919890498736433754"),
+        MontFp!("13423323660143533006628485455163236538714121124914329148003611317540843114521"),
+        MontFp!("18732130441148222818743122719599795831305992743627249485606912380686722650328"),
+        MontFp!("21744582565522883116221789698750442373155722956313850154199652336110648496029"),
+        MontFp!("7144354002506963954801968243991190427536676772300009377345054067229788469802"),
+        MontFp!("546773555852952995236535448961524872583912665647152978722419200006698017228"),
+        MontFp!("4115449488659159484679292884434524602598250771770554945188450323754895822199"),
+        MontFp!("8595752591492411960378746550184866534422481915151040676780917721787338199480"),
+        MontFp!("10165757572338172851680092102888140824090130405358311565741520039277283108819"),
+        MontFp!("13881202481966783370628597357006999877307398877082297393247431527668438855111"),
+        MontFp!("164079380527758195225075822377759030452558671627014461867366308987961032431"),
+        MontFp!("16597854725561663920154725753244355279782548142174519652163898109297275062756"),
+    ],
+    [
+        MontFp!("18782804026635015341133877400860675283426234307512052057983648925359584161851"),
+        MontFp!("11803457827110915157916889714293161979623605698803927824089144848270204757888"),
+        MontFp!("3790889121366006080676961526219702764983373811240106724685417109395959845261"),
+        MontFp!("547387322589169298813085629174571402766705427171260176930124272482922852550"),
+        MontFp!("2250336107342267953278191463235028062291289397936525878162948482155007182474"),
+        MontFp!("18878522248575655887331882357174227152771300904536167927922454904180276940063"),
+        MontFp!("5479817494658339363150524278522534672218076031607309533533517453567105458144"),
+        MontFp!("14471434214677769494999424343272645790653559928767779107202935041996962964627"),
+        MontFp!("15118605967190623209837537416237887934162874725496448474965882020979641133025"),
+        MontFp!("3039022034764509339398468046956573675942916248030956764556346100611315129267"),
+        MontFp!("11040960503243486479242227243433964824049773842453715686240136222246114618507"),
+        MontFp!("14522024554516641986547166200505804498300840654246791399797761270595590503741"),
+        MontFp!("6835224295711983166071915743041027338226822865794125690776093851440377194137"),
+        MontFp!("13366807550844486078755421216059218883003148232530961590657899207604510596943"),
+        MontFp!("13252007130485429909186723432146549270562877209239528962258894291232641470721"),
+    ],
+    [
+        MontFp!("2492164358128252077612941728095518049849413853275094452844245158937134153493"),
+        MontFp!("17908308295682961911088181370570531617402259473536245650574431533231050717409"),
+        MontFp!("19598815799287079436414455405296166262841941910574843546435474727483962158191"),
+        MontFp!("12296683988787359527811189035096536832943622321600079901820428531954001883453"),
+        MontFp!("3034216468239708296411368036093482845790159680213461245275097466511477145032"),
+        MontFp!("21365662921308444671672644376862924257055009191312934218262773003828812482536"),
+        MontFp!("21643121605272677508602911313799693744649969093302740901187983246336863173757"),
+        MontFp!("17106474623853535335864151625226941722837229436558906235895607080504648969673"),
+        MontFp!("5170493063367583327862860118856811567748934103888623432970286709351028834594"),
+        MontFp!("16157297782580756629356992582192341437261969693717401640006420404649789675283"),
+        MontFp!("12726902817862860900369295542623896245980247290073629666117912397630392192361"),
+        MontFp!("3081548921497703421223514856379500960238907095191066190890406228882786538855"),
+        MontFp!("13383733165408518558908753036431726341720113406574723548828112868926423953299"),
+        MontFp!("17114711170811861625673949085444809223764181246137324935055609379301828432643"),
+        MontFp!("490084821539048617783395755779188347718107877537460289908857747116706971191"),
+    ],
+    [
+        MontFp!("4649874458515161549974867873095987666703831126237318082002364917533053056940"),
+        MontFp!("18309757296672459101064597681764458207032523283974093139605613289699615669226"),
+        MontFp!("7819183041486966141608141659813055168335432042159899601871834502038946555307"),
+        MontFp!("21341796073041881721040729550176895226179046215340279859887156449076052673189"),
+        MontFp!("8964280440056181910087237574225882257648057952084687707991537684955336080915"),
+        MontFp!("17214415387533445163014526685896715822410886705201252936359885537015585673030"),
+        MontFp!("1103535976918892886566275197010540828604931300309949743647411661976344710321"),
+        MontFp!("18989472017473838846112035962614068658032619400049135145786394166114140582702"),
+        MontFp!("3261305106935504628452223922938937365963307399148885220969836911471709180672"),
+        MontFp!("8250352610228962368545929233808273614693022774196635878184474970985288333646"),
+        MontFp!("21336821559645974200167373849850832540612240140661136750527391104897925746090"),
+        MontFp!("4804442184264650022914507746660662354579855239722797010340948536245079163068"),
+        MontFp!("2980351069317208287298989743520302917458165906618872700838358045014403829821"),
+        MontFp!("20789178264137518066753395738491570400211130020259232167860189045958431732223"),
+        MontFp!("7043197173168479199982037485481873468680373055205806152875339535752299138160"),
+    ],
+    [
+        MontFp!("8803416368756444354155108122527515347994446936702934474369040166131196644971"),
+        MontFp!("2327814593413519022021694520242495352706688146726587158472403609475455845031"),
+        MontFp!("21198136442842149822575509914524766455809483419897291889042050133491478146813"),
+        MontFp!("5588816308782619790412052393435292678862962083038610001710149574381906419148"),
+        MontFp!("18457707371647662280814248200286355752809748069618488571669726734664943098742"),
+        MontFp!("1234389524319480772210519595012254096434562205385972935754568629194134383384"),
+        MontFp!("8590188161561261795686801611365857510200794054668578839168287669047410512276"),
+        MontFp!("14470989978325106127256991312163042779416463931400090968073878504946069484989"),
+        MontFp!("2305522070968512786738458204005001050714630746016184992510479809638463479749"),
+        MontFp!("7477355783744938536562522733347415235458953622739577251175967178186753572312"),
+        MontFp!("567290291622042941074701939710650764088062581452752653943609540087956251460"),
+        MontFp!("20440460641466928749670992266608077799893623138336747959135603955102479614007"),
+        MontFp!("3054829023148086310176268593952124817160009906490401089445995945337990758835"),
+        MontFp!("1115018683950178281685995685874075286310060333362422341896901389981346771770"),
+        MontFp!("7809307874649635295815564328714298840313171873184814252455880392757245438416"),
+    ],
+    [
+        MontFp!("16435322760021026161995603943756578712245360106977256514516858926570158830261"),
+        MontFp!("15538054347747268689904146479533221617920707373984233068744782064536586402847"),
+        MontFp!("2145106610221843472170037420099674539983626911224263405264737836509031499186"),
+        MontFp!("10469489855508967606640238771767546316388755621910047798817316247787439887327"),
+        MontFp!("19269976274347330455450094587958438145203987980029244301223712026832424496030"),
+        MontFp!("9442647357194564700611387347788116150552532746010343621293769302924097756012"),
+        MontFp!("3958820742734683830021295040986048520026707752457985546687457778678735761219"),
+        MontFp!("11348329600459394107897118896385152488024741286593365288318995787678872184581"),
+        MontFp!("7994061748163511046803584801099079098965771882190345699910054751589174660915"),
+        MontFp!("17839452077827445141859404251925903822746019560954563061323642101356666431414"),
+        MontFp!("13232228834563301156710808377438125900543901439977087615805079133929315161755"),
+        MontFp!("21820445178921591403441486524597609508081666751352389500190809234730066099269"),
+        MontFp!("16335255581063466649418326314739202718270800342509998411094897137707475468574"),
+        MontFp!("7815038267931398026175910302031438514210779836695664391140597883647619726660"),
+        MontFp!("6108907775060665614604453427927916450888778767561273091208488690292223394553"),
+    ],
+    [
+        MontFp!("18731401306874205485033288380603219954710608893214981010860304692443381676445"),
+        MontFp!("9207310842136026530277150017022352541117999932757777916196896007342718304978"),
+        MontFp!("4275597059348294910195928059046438407683127030809544814087898068951365707578"),
+        MontFp!("8551726143850685533914504884506052366269066663000473253249997846823470852089"),
+        MontFp!("17722307737167433007678801800007589919417323324870850217087785924251047414732"),
+        MontFp!("9659885913999670803985648240505723928144853579243576606650908903914665975845"),
+        MontFp!("4127785260298795455679482228123141201810011802311941172182140342819621401490"),
+        MontFp!("7995480315864311476694461046721076190059098585527103653430045699541205743090"),
+        MontFp!("2710887853573837837653751249029686223333938865050270571041154550753948687821"),
+        MontFp!("10929954836518904033304553904442567770321295457957205208591304372724905918438"),
+        MontFp!("14001950425766109615209637768509276336684867788731325954527529665290504740308"),
+        MontFp!("13093798171633818149701610236482430519636222108426384974269506295925166461929"),
+        MontFp!("341112534319506600537441322030652722098845095671561271148940215817004993097"),
+        MontFp!("6110906587902719841743444555887325466851146812889558891609843725163499179004"),
+        MontFp!("4611067237252812652698946201052371234192768537459773069641551009583406296376"),
+    ],
+    [
+        MontFp!("8813661389433335471770742946393259278020908342551057181562786643750538648664"),
+        MontFp!("4082028201221928661179738646263379819458232996637786410627338568216272942842"),
+        MontFp!("4387150015663192481675842540873436578480016784260152864928968972841893485475"),
+        MontFp!("5798957359608013690581519427700262504660783802132038228115867574470007647157"),
+        MontFp!("19722104754281068600204006779633412991974614044671884281702255273995426048636"),
+        MontFp!("13237763289640918787304198678609438981723281080085860502804175876747302592942"),
+        MontFp!("10350397375768871942356366001436797697061286173475393119069375716517495164748"),
+        MontFp!("9716754658986776864790426519095473979615270133917036668138857377100462313944"),
+        MontFp!("12559455792963077442707649745319068600669837985026401838048238001273610719876"),
+        MontFp!("15691474792343664499590886820783843178573436351948842211318848415073250926238"),
+        MontFp!("2315152971409777702150694033027032394728963326020928261979764797562368859984"),
+        MontFp!("10145932773746660365948684949555749309408495540108698054181994778319619682499"),
+        MontFp!("21031755674184251231918386922772885560228556184297650283520145773416227622020"),
+        MontFp!("9656774539408247026483766906298154647226870877463506520381115258572281833777"),
+        MontFp!("3703366867036538119931629591865173960052419475390100010069369247148720449735"),
+    ],
+    [
+        MontFp!("1155849517605989105668895794996696646805146181003961935923384046079365333956"),
+        MontFp!("9462523438649504157500459081869061592673258626587962773726459717365228540892"),
+        MontFp!("14558942402093706312345858485376818615887511613771478694966109228129293547963"),
+        MontFp!("2812048253376149161843484904554248272181353897695109556171864471465530141300"),
+        MontFp!("14581264237688674105789045795632866727938915214983870169917226308316644895751"),
+        MontFp!("11806517668379902968824386871470726201210807458248501270142903146174354419162"),
+        MontFp!("4072157532402496076820064418241903939352389786658517709744366379045220383162"),
+        MontFp!("21813972253527564887362886309776329116642260196272043100875592141144150622785"),
+        MontFp!("10464853353887659405357329005361733216328104382848677769669050984745487501147"),
+        MontFp!("14772015460668233451710325522019255336227111235340236488781640750731630746977"),
+        MontFp!("21527357780259989210243319716428302865642384867695769975035672624351025254966"),
+        MontFp!("10891794486798293092627071927690327494090905691269827842959180201330098409394"),
+        MontFp!("13485855904336418644657500553650073084861569474830727752625752553358760580285"),
+        MontFp!("11448242815253167183767068865945471000243774132210813929164325622036544013989"),
+        MontFp!("19410155802742110833632454245227392555968304439001009443806761608484053045554"),
+    ],
+    [
+        MontFp!("12167659128474886745250741538637209499739823457162062821474270919941304684088"),
+        MontFp!("16680628007927224771777485144832808896272748488695838488671868152342742322186"),
+        MontFp!("21298757047923195531118553955836039383942072424271519371368119907422148509278"),
+        MontFp!("11697718923718927022148851233178802389523673168156063462791132046990360699116"),
+        MontFp!("14786267704709019791824267590492332247783919098705756794174245591027365669638"),
+        MontFp!("4029431706248165284072638415134780930697313346853706588481402470852714029894"),
+        MontFp!("6637339357364931518321345115064554926275905427738668939829923099637974821930"),
+        MontFp!("11721089735875799137502005413616822380279134029969641544449026524015093316862"),
+        MontFp!("11258510956066696357188198767462611223354755428568008829233602610737417570759"),
+        MontFp!("17382216798452684990847332797566093767764854060615116426813871855967998298795"),
+        MontFp!("4497813725838018324398390685637602656336499793658559967801659260213189483769"),
+        MontFp!("9309905740467289615895980718346712749846725019185323382202916800724200146653"),
+        MontFp!("4554312255934480823406477691403023661425250519363902231000470326223736059080"),
+        MontFp!("15877126774677139396238817617140527789790929268045936819000883506734809330678"),
+        MontFp!("102909811737053415440204213495606488273727867104784554516589930311835745189"),
+    ],
+    [
+        MontFp!("18412616692627375517300170963764173086100899052204830988805458281556342306704"),
+        MontFp!("12497849254798953635808071778933029738229621492383212989583296055326390275927"),
+        MontFp!("13190130371739872388747884175792586030658495147350847435106581404654633998344"),
+        MontFp!("6569505978975360946878354767391913708682729333577071120385232557177773967150"),
+        MontFp!("5486573030999616702165020120135398562758060949059349810688840632469582165448"),
+        MontFp!("10402649247177704342444720212899468852439313211834653468379224661013914061054"),
+        MontFp!("14870393688688048179476829039887867662050219694351765548412705799226912185902"),
+        MontFp!("11112866336990864101289111553915677602536611363742637349719791431991672470238"),
+        MontFp!("786378775702052024749700197105312354581130448431768994347811552323325137023"),
+        MontFp!("12804314666740390639363966758718513558509859637020806917779321368800352250093"),
+        MontFp!("16383777838797313001717025761299072170493145903921192845224827276088079299324"),
+        MontFp!("18390795790658866542478563024059495583921996400061610405032233720404354578517"),
+        MontFp!("19071180375782447479569450482330188708754447860024817674265020413221587566028"),
+        MontFp!("7064459298524756483115092315588548572944660746058573142372140457074172044435"),
+        MontFp!("1201661337668100759364810191918525473246105501963149603200893010746984240574"),
+    ],
+    [
+        MontFp!("14673821426225418930674597108599685172091690120026694257549676255194170791868"),
+        MontFp!("8007695637892306475030605880993450528464588921978475145792244443873714795074"),
+        MontFp!("11523933430814198704411556653252358930965476928606503642553530463689321317157"),
+        MontFp!("4720685254208822524727003349181010163122046826828742211508555904133729568685"),
+        MontFp!("2704641256375089357490975523544121554540568505171014265704664461404637160537"),
+        MontFp!("17873735287572402838142305036318020439254672746974064930437541429239252592118"),
+        MontFp!("15591377870113101853150486245422804312550411754131695747717894157544182932395"),
+        MontFp!("18680866424621452161410252746147706712971801673312117262770403991597018010533"),
+        MontFp!("351092121232997110902065296627489736041463573095030601146795248738327912247"),
+        MontFp!("21410776897919290439576321311168524383878404907590335964810760611175870803022"),
+        MontFp!("17236852113726644484641871544732171570702656662638441976539579805457648241354"),
+        MontFp!("5141902767347064892821277441749408218256386923351945667768202586451461154007"),
+        MontFp!("9061206488410388460545711706154817066096529615910172728152676288649487389209"),
+        MontFp!("21501744386281091461333207234319069661329384520296829009605344252699881602491"),
+        MontFp!("20523123786355828261901087089746676928050070859811577818342254696196216070331"),
+    ],
+    [
+        MontFp!("16340183952833650334331728750144828293293213803155672739442287164371494962264"),
+        MontFp!("11997165266626958619141537683193751516111129514708339075256579474097934990898"),
+        MontFp!("11727998419969202419125826890516046584560800158309390774599325003825426052070"),
+        MontFp!("13931457202894182485995482691171342703522733597260457019434686547274942752168"),
+        MontFp!("8889288417892697124945054364222718721845070552969370183001562339292498026982"),
+        MontFp!("10607636906736209971474676586549274600168449611294688931553643349980364319662"),
+        MontFp!("13373379279879419696628639312899105244563309886546920506727138704477895635504"),
+        MontFp!("7516778707927565776230701116353902556324173125006482617300170811322359880287"),
+        MontFp!("284142816263687923446544408203711951081978657997111022490562722442340431026"),
+        MontFp!("1663629018772009800050577108421747444431944008366135531327269967377988698730"),
+        MontFp!("15976560170935104892839671978479711290979780560451333565745657902528940986567"),
+        MontFp!("2821894717706045749560970435026664943807315523436547166205412842765540126279"),
+        MontFp!("16411046577927712325274395387891881155718376469810123282180259561336053360599"),
+        MontFp!("14830500017171075678309056171812981524309710840633235720674153550980163992149"),
+        MontFp!("18516862946535092410308127384088576733043708186179828736204273213102857123131"),
+    ],
+    [
+        MontFp!("2803122828384011132142130003641446021361083629354222582787564441368260233535"),
+        MontFp!("8655048519095208179618171351501333519098495288987579151859877773818991560313"),
+        MontFp!("17628029087309631764673769418575149062296127129914718449980855676793923700717"),
+        MontFp!("12213182221565572417730092495989703548687597794044608925789870740387528958423"),
+        MontFp!("1942254992896992114200957837548346988692238147738540593160350563013662461747"),
+        MontFp!("18572481120445885888145197091838633506611989952111678544191205873056195137330"),
+        MontFp!("18140911659901689098303870986888083953843757583911874535981587625576530534701"),
+        MontFp!("12576912117641358754786901787127955567685279522839676706223096592944048067439"),
+        MontFp!("9853587546273100154686512094544697531675085181100931274409412440600306885014"),
+        MontFp!("11051022304629047704897656757884328402546597879242426643246383517726104682373"),
+        MontFp!("21747178377575326127773834076417831630856635794535286757990250257220185876652"),
+        MontFp!("14802894612148412188667842094308733856318572638297904757997514236587913861527"),
+        MontFp!("20978447371728675031404263158195920974895558458780873769079890388209356430130"),
+        MontFp!("1037699465155917084450585874080268028561047537346921243992399961945112783290"),
+        MontFp!("4796870784617170576045350520159043022811420003073563826183620782718648038813"),
+    ],
+    [
+        MontFp!("9853767424749529094226213721882427217388134769321854800041359789143412725618"),
+        MontFp!("16909978741728448404835572592895383783360498936047937910269991412415349672326"),
+        MontFp!("1843438491269241815751499591631260690521017848626075859779071605413804154431"),
+        MontFp!("14341379518602865584962107623594010671519157088756134035711400970326861508492"),
+        MontFp!("11148607079771922951721552848779822101435560243853139254657574581631328105418"),
+        MontFp!("7236118376476006500577604441648338380683405215283131664289729852364085763863"),
+        MontFp!("9771089868728917573774866709686459919517030662395798431632764214674312680212"),
+        MontFp!("9110797416046523350769719273137039416172041255824880845510758575776777500007"),
+        MontFp!("11171998782921300624880315291878283252073139909993544537205045086249176031551"),
+        MontFp!("6662646470172779612845827572259867913830316914285246080650610642251248472832"),
+        MontFp!("7840073244312824502623215399534211251667887906747521505446844849673459134791"),
+        MontFp!("15746172297311038934003440308705797271829102199583540526113736537482577060229"),
+        MontFp!("6886137335014489748489631206590121074634704468637741127875111216297167474235"),
+        MontFp!("420232306382434242751186173486850411467398307214919587427625546445400284482"),
+        MontFp!("6101917029417345450869344197624899835171925047771043819872045680173320199082"),
+    ],
+    [
+        MontFp!("20902767593677591005062278004159629853556955206212143592781424915847484793984"),
+        MontFp!("5806682782059166443158221425741828463380368530625835045262822570023351074794"),
+        MontFp!("8937322067898730475133772449174500828509823386740428196387523130412301822446"),
+        MontFp!("10698423376034838319080023911266285836831986186258448454885030311257173631094"),
+        MontFp!("3804139705083664873950903601295520892698549383396840546300663481183626370753"),
+        MontFp!("11666167980086510761582909817125709232688171970969608229444060852628732414641"),
+        MontFp!("5195343484230074513500630640199601466733948553952258905990513870281939709899"),
+        MontFp!("14624340714306078897305540751958284706578556105002276099158477226484579202503"),
+        MontFp!("10083361130687068583126496521090418135813124339806220406205408201612392141304"),
+        MontFp!("18641596433875997766126569678124500204213295416321224520910179376654463020142"),
+        MontFp!("2202590858941069122312246576796422760143586439968023643572584733807707875591"),
+        MontFp!("5606778593839139538330184356292709713321311150529135965207704575468867770974"),
+        MontFp!("11958475164655758519149540613838246197985233378525766327961909142873639847913"),
+        MontFp!("9229670488942629146704143920529444712626724835075235979345002293458105678408"),
+        MontFp!("16754443145923358894066339463409997111280948017357151500387305969742481068301"),
+    ],
+    [
+        MontFp!("2409255870410101963458063337813762581436752424874011147061097403491760442109"),
+        MontFp!("2214559337316973031881614971177447231501212859777889339656681156270831720504"),
+        MontFp!("11421110362488121690592793129931919017457696830596130693048382951666066696299"),
+        MontFp!("3820500999703948691966833683611482557851819710346178343790665741738753374219"),
+        MontFp!("8756218158739871072278901807217627680176358846778830959237385752221505342071"),
+        MontFp!("8032449730250468861487267430306584116120245106718506069817646852031479757007"),
+        MontFp!("10243677923364935391432160448413375676421376200851541463326287529268288890040"),
+        MontFp!("1128776597481264636069298358213640667665677523022374620075432951501260575292"),
+        MontFp!("5644093261057489381910165706940112111858706948653266534656144218172488885132"),
+        MontFp!("10992382177616530304259484530083164550434687278050566643026419033635199094809"),
+        MontFp!("14561508845841116001383562169823893923516132174944052932403904968433057227748"),
+        MontFp!("21863964650894297432572505174186286520067123468863642727441839125002713095277"),
+        MontFp!("20755119936203820147240015392257516486599536773168698212709082762309828651450"),
+        MontFp!("16959073226608628398387152299601201172109169744065562346871992262472923761717"),
+        MontFp!("1422360831209405989940170583665509252269399522601915800432845527860741426172"),
+    ],
+    [
+        MontFp!("17389326678043835536716857792606285368419240597293609647618693076258681847452"),
+        MontFp!("12197527885708217940863852882450902665816552534167940448166754267717839462635"),
+        MontFp!("21611570070424770005490607477701523898795002037965850794497143400504853790221"),
+        MontFp!("11892102040110397810197867975938039639353004723546075702397206087388005784781"),
+        MontFp!("13516538003340091563836438322533954338394185445401173280530387106662595790317"),
+        MontFp!("12048467357966566407092445451263285920234083860460644072449020638723740218202"),
+        MontFp!("1735010081751908190348908571270511873519447755491670766716880879623393561967"),
+        MontFp!("1975466263323070433866371621575002630840361531361829342799564009137157551290"),
+        MontFp!("14332158763257273099367529977375112575029271371581919959093592722516585473205"),
+        MontFp!("18308662877208906527685104306067008785578317983243580533749074382175572194649"),
+        MontFp!("16147771244577038318738675557955640363954546932826136022515936117397152662906"),
+        MontFp!("12310104474053287506280911462776789511537301762185944649254713583392295220302"),
+        MontFp!("20727834941236374148190412755081722896905078541838912474132444343774737989102"),
+        MontFp!("4772017368444066833412562265277526529104319024388995968178984274657250179860"),
+        MontFp!("21049615822069603562632099674853063708639208121642147875791882100914627258965"),
+    ],
+    [
+        MontFp!("17646394174446098039184464463379581654623553955171175600685924254263103033801"),
+        MontFp!("20405917523260600357692281918105892289710334792700216851356175227972968907413"),
+        MontFp!("1288067623955526969302061242482178260544140652671589048188723078863398511616"),
+        MontFp!("10835537730223027316050378066698794099751744792059501849218931290020119106372"),
+        MontFp!("20990157274288749983727934109087848093794137769592917583326461140632177148526"),
+        MontFp!("16941875865072358419938961334635058647868180482918222959682613586029647175353"),
+        MontFp!("9843778843793690986119532637057920745140536160016368781021998854678847780413"),
+        MontFp!("5125414792982533413337556654533863770640044560799521974799727390524971858377"),
+        MontFp!("10864462495296760172304401051361158670010031482061392973919136670275578530070"),
+        MontFp!("18638188379508145413237672170551928898223721010749214505292285199256581752296"),
+        MontFp!("128198311352192300389214509562427846527025583978725552204209143056794270869"),
+        MontFp!("8639617857907165397429617535993768106671328412853872156360019116822694247239"),
+        MontFp!("4844530255382539675437452182943895677920405337347260379412255490567182702620"),
+        MontFp!("1804675521808388615789572941859899805904819249465788091888167545049898256219"),
+        MontFp!("13789062391664555041422570123657413036572356127853659660401072142070681096201"),
+    ],
+    [
+        MontFp!("2984776938673077398183780839702246034953572367406345432704517332443032892459"),
+        MontFp!("2992847031807357662780885550148860874749067596604232667894089534007015158636"),
+        MontFp!("8223481330716908812358677489080309112433589978061949842348101802357147464280"),
+        MontFp!("15829423985005178435297810317464595131483457001231013788201382741845473393508"),
+        MontFp!("7862158982782368203916129371016962734683904219117743973675651114828548094092"),
+        MontFp!("19398173801438973209233005992391884365223581634877055780725266290648936529417"),
+        MontFp!("19275863963890569296158744143112092383404704340976692571347641477470540348192"),
+        MontFp!("21372102182460094800056358406250397703306525384763761377034855645794765423371"),
+        MontFp!("9476173056510831081934271835896053957562178504127106649888600367232767217909"),
+        MontFp!("8945631126280639679924915282277004291625109402912694487894772245410534789081"),
+        MontFp!("7926253519938744933656970287399447078338275573508348380139787090085253922642"),
+        MontFp!("16018716520675458445608001155996902645263605619338294478505090713237417816972"),
+        MontFp!("14921856253793536148329746401088158240205455851010569917169181161371242017185"),
+        MontFp!("2079875574141510421000322245042248069972426430967618609676296915817312672160"),
+        MontFp!("15980599742048633141868186018745285129739756803812956666022183903444182047036"),
+    ],
+    [
+        MontFp!("12114893033109318668956482804227784987572764113979211644064098174620745412136"),
+        MontFp!("2810098426330816525728669143855005497849959012191227510766066867001705905451"),
+        MontFp!("9142041039259596869436365631928849454803241725030479248178020744616036193428"),
+        MontFp!("16789255887716308252460957605463144609726855240455194269099382954389067176728"),
+        MontFp!("17736208027338616948877595063010911429975090558603409155923157365536120955065"),
+        MontFp!("21150410646727639080649942919157548722144769190804322567561458114897439673110"),
+        MontFp!("8728923715723554965990413741235509264041126517176940864777942728557354521757"),
+        MontFp!("18877975508697261368601618378333048287851497514619873601021983336084282573042"),
+        MontFp!("5857216433172498779920090709564798696432088771454963449283671260189720263346"),
+        MontFp!("3416739349758310085403544797458080484340996684307665591910516201053743952382"),
+        MontFp!("10014661157797313244292934148350131996657869525790562708237180274791925878615"),
+        MontFp!("5349492492153439871560702687440086188179846024931646961141799958661743380684"),
+        MontFp!("8090802326500729500530853058367718173141097881651767619458686010109346379147"),
+        MontFp!("3243876699925510348842975998823895571182658963945930929780026879935610333017"),
+        MontFp!("2417624030466093278001094443901143647464035143208058634414228940495675339286"),
+    ],
+    [
+        MontFp!("14707208083442648370683763592077614356382972898797034092783713393639907619177"),
+        MontFp!("17545631002841426592730923010822053419499562660641460363423698482744533869073"),
+        MontFp!("4421380624161160840991891701912641613710893511150975452841445166075745805974"),
+        MontFp!("1077414817249103950943875081715965937853608110732127257676522759590623460183"),
+        MontFp!("3507723951294024188735232556852951953031271591466929388169161213367649583178"),
+        MontFp!("20744267413157655820488615250021371239675627876287191610069053435816486296093"),
+        MontFp!("10030806611757571510773710000844469375387967668887810533790132098599140714696"),
+        MontFp!("4098608919187988291739821341638774871917086915024686655297087774191912756808"),
+        MontFp!("13453755776490865531134267856063521315028666076529776333450382526888445047611"),
+        MontFp!("11760020934758140446049369723964305600159936563536726075225660675360331528465"),
+        MontFp!("6406037247037697473727755924858735296546535018055390677906442691851004797953"),
+        MontFp!("11557358458236767995494355298696159281191676229524038284238319440638237580594"),
+        MontFp!("6743825309135636487078170799646071187521226570701598888088674184356446519660"),
+        MontFp!("1363740300261508449387087660891142217813794297057216573859174333622397381039"),
+        MontFp!("17353086088993821287882776856791148032337098217763977250246938079060857079815"),
+    ],
+    [
+        MontFp!("6718938715458951426350417166499489427024335443629489544367851434423278337209"),
+        MontFp!("18987489742261748676564017446964953975327326154943753293417429206546308211028"),
+        MontFp!("15965659332352377592570514074534026676650570619443318860049459797446297666148"),
+        MontFp!("20815651494112396184410166336624878644932077115689590904142342335838514032225"),
+        MontFp!("13289193671002988855733226768851953818541013880219936597636372756289364866355"),
+        MontFp!("1567979574094562858336626709833690822884595003609499722973068728155414218656"),
+        MontFp!("1406894286237293229723971624858421702390230552459261102967091748375073005174"),
+        MontFp!("127524353461789027170460839609791116742773867221485647945877046573898816494"),
+        MontFp!("14964917196913210912898635537202273294898388149888129789323384554911752584731"),
+        MontFp!("17573975090037837489354042024755208350546900760352759486497415097145199425437"),
+        MontFp!("2810800768573675011848063715305835214048305201677436448513685242208560656239"),
+        MontFp!("3182831596404920556846849906787256417421227529749874859977287400581732004348"),
+        MontFp!("9293221288281284622603029762317683176118521399756060307438914930508616154161"),
+        MontFp!("16673727443492180846335195490607282587311771952172084861120865668023189607968"),
+        MontFp!("14861970206393540357193848383493197595010751639867769363872113104941368881368"),
+    ],
+    [
+        MontFp!("1947459730453714728047065931026988640356317164613412029204943819757651786269"),
+        MontFp!("19307336986855330171250472129691969885469727663168817962206758878226019668925"),
+        MontFp!("16272723820736869927531258455696258295545428585773733795358621236250129636598"),
+        MontFp!("14067203273048816019998838866277520003916774532401938198162316693809632760951"),
+        MontFp!("21551507147108044169777874975479534770240991315165807000112720115356653530622"),
+        MontFp!("17717640130827927258645965421731152649890280820567693269781156985346197796607"),
+        MontFp!("8341245955737785666220035421009659068228682392298228242003714027037157516189"),
+        MontFp!("12339617302318828762816142250616108419519769797732290739230041883942137188888"),
+        MontFp!("10123566056273217296763559440410478584111931737336670797432668142672222468278"),
+        MontFp!("100330409846079023411270961296323123363585075447874304748480232510662674144"),
+        MontFp!("1571586856190083814591643193451371280998279878174710717174319810741950319455"),
+        MontFp!("17626078717574459956178559042223066518589738318146553446930321898524763562145"),
+        MontFp!("12597474837573442277568310997313425097295312228965354157636545560783225415194"),
+        MontFp!("9282629691296360927786998265863490172450342562246145699272852196309154676715"),
+        MontFp!("11475383910279033220892799115018315595968658540404734297367799982184306742405"),
+    ],
+    [
+        MontFp!("21207578382911046461925166339581302823631975619749313031737447832273859578217"),
+        MontFp!("9867737209512713742156966231095908977298144995685201344042106615506717185970"),
+        MontFp!("5419250263664842635521293741086549956576183663718321466133029062700646217820"),
+        MontFp!("10680301618473120824196964202055452643700003562180874183620820959381512387087"),
+        MontFp!("11734118782551011491731695279474438578347930162244424307495336916011158044578"),
+        MontFp!("15017496989515336959243556328206095618035482039354824183597442359483266217611"),
+        MontFp!("6511621188986288868363472995938291798169517601112511039955221592826262879815"),
+        MontFp!("1057116630043531259822644333647831844583791045276219751332477616072560117378"),
+        MontFp!("19059685203310882657000346272623187407133972415131525465827340360192915270217"),
+        MontFp!("658459778905143381055609790895794611242407110015004801647654573954369450993"),
+        MontFp!("7603036257465538034144194526422841628761243007297872008772468055256213693455"),
+        MontFp!("9990634061622229590998343828576831787715708492431159246049355339866257946620"),
+        MontFp!("8811452869339089802509557934124104823648284438116748217266488878838078556311"),
+        MontFp!("377338624779519383654026004940027827544698613282452894117899417005371283013"),
+        MontFp!("20372432760811032237009833207612531537192479064270437032142982696970398716892"),
+    ],
+    [
+        MontFp!("15755624168218886509358985223476988622172061232118179707766784427029774962558"),
+        MontFp!("813530157019364022188179508608879306640863329215682533796638454834580544088"),
+        MontFp!("15366777101659035236493629475299055460750095122762878608882821037262385854171"),
+        MontFp!("9412569363133467481819948072214644683803929543842857451635373713673797937906"),
+        MontFp!("7663229432381596357590875498997196481050744300865119408024986926994876708428"),
+        MontFp!("2802277205489381760400127515311144092806348374683690687945106801827615715524"),
+        MontFp!("10398607437116928120233501860786387490329760704107357815670770315190035281298"),
+        MontFp!("7713454417232694103114115606745926964297583014900008700978733395172058088330"),
+        MontFp!("13765632281005573885638376819890884715413389187071206345092080625293363780493"),
+        MontFp!("8481595129822383153686510359174155430289248263706955785322350737378030164791"),
+        MontFp!("19662013748902527903033807785062252860528102610990787703983459073558867314734"),
+        MontFp!("9308675917577065974819002737905225232234719968566022906777214056034139366532"),
+        MontFp!("4460305046275724592205653945612957908431797179457871442468403547092050276501"),
+        MontFp!("15300682822970503133550740797435695997154332692415846288727432330759835456273"),
+        MontFp!("12237959719178772655779639362705631668446778143915144512652426042298606318280"),
+    ],
+    [
+        MontFp!("9074337685905972785113091085216241647906400276520026931456332814507320753649"),
+        MontFp!("5631451392609103370493346239448329080866515797549576094657011047227839672541"),
+        MontFp!("2121343968626452536232091590081231792044261748288461026059952209827486505534"),
+        MontFp!("13428337287072393077290174792209446111049614088462784414150825648173933048712"),
+        MontFp!("7637923455564736402006004688931010233604652911202725935144306827988683195798"),
+        MontFp!("14145144407093909228652602933032447693975357132368479264731012546112843610158"),
+        MontFp!("12996083206893173697199261663271430611160188106624571072102983887472126762111"),
+        MontFp!("1731425456500536573020772566007405873501250425959888611809221924317821607399"),
+        MontFp!("7235256019731839713165664289644294928953374624035695945735560280325779220974"),
+        MontFp!("21068041929715170328271489788020516757451181357080748023363808822374772951544"),
+        MontFp!("2613269578304019608168678260820703130619030694547890166073793773931717299431"),
+        MontFp!("2895580764674798551230549071717806800560472156999393716124982187189744328131"),
+        MontFp!("18080627279556321451131957341924578248984242962783250736655220087867816491510"),
+        MontFp!("8541787229490752355605354050088489559660583814306921152210039891158051673167"),
+        MontFp!("4773003619955976464416147076099271361363813479523212833233541104584120420092"),
+    ],
+    [
+        MontFp!("3902657294907605469046100206491238707374466860318674919042657295835038516976"),
+        MontFp!("3278680356861257827773728996810819308957995332730291009303523122858553038927"),
+        MontFp!("15799387696541061708257049935405397874115977721965461912710489016029411671536"),
+        MontFp!("772127417921361022785917422978769290675101599783616547948775836973315286693"),
+        MontFp!("21172675246800560416431972802840350117631380023487771635657330436402785697771"),
+        MontFp!("18058055216124783386618232874069776997923635894599900180049394490069349321671"),
+        MontFp!("6486774126469831729812548493701084245613732338682698890925717889323571075377"),
+        MontFp!("15523424894706974280109344562353257665535312014690869100282355992032362528753"),
+        MontFp!("1155165761749577539253357611425533199963683430668786603835581376097524220748"),
+        MontFp!("12293270303409235416382105881403663503297523019464344830582632347870203818941"),
+        MontFp!("3805029697086536368150132668072389131584837710357108954351676850002928826418"),
+        MontFp!("10383737378445903520223858424352769490012472500810730319338019642336472424460"),
+        MontFp!("19139905240360031959084644666653677709382597275601060683474709422388012451876"),
+        MontFp!("20662040367334915413974593999171397527205617639639561319218396170856657836909"),
+        MontFp!("20693045501044506792047932972050932132614012706885392044610670760209701329210"),
+    ],
+    [
+        MontFp!("7646436833143149025181986338139180317049527836176583246163126009131421429218"),
+        MontFp!("18468267029773286403821867293367277456399346317458918979624725373649687804739"),
+        MontFp!("3368837325920673645463134045719391515049969019513957645401010913338888270435"),
+        MontFp!("11009184090818836540140614853735105475441340244881441725231380311734117124613"),
+        MontFp!("16438249098211375676141540117467319174575385934122099955146653152842543524308"),
+        MontFp!("1978426318655034633621003481179913733408584009919893346861360079137199026039"),
+        MontFp!("10835870076129494328875329193612835430557328436367520958854318265497818180707"),
+        MontFp!("9238585128656275231406470048000584932169168279280345637986485301554767537943"),
+        MontFp!("9860408784513424998732752702463731273076404557148376009384338221275998841902"),
+        MontFp!("6200534253629734756957567067039457925613934220422888818001227120423772581272"),
+        MontFp!("17839095320044387599555680620747734440255270476109368494374200849742690805825"),
+        MontFp!("15852210333828297391427169949742706715097259872699655846272917384634556986629"),
+        MontFp!("13333830495117301018203298033441593118871576320662915411592512406905971557482"),
+        MontFp!("20141868252044007123768212581882815770873248742867175012988635705853879924105"),
+        MontFp!("19536412434762756561256835516560484981006774488160067264157868466806483409550"),
+    ],
+    [
+        MontFp!("13376474825075395326396381198361278687546895119831914117080307993251668968861"),
+        MontFp!("5426793384935877497416931155195289044246814775306615779818726668069304816143"),
+        MontFp!("7377826504693724659923090551901604172560455391003832805477022207542742841455"),
+        MontFp!("15304023433512090811722731808458537014494291201252876199903945357536893662723"),
+        MontFp!("21332460757344630529450409218029849205820804076582364819572498755186176235236"),
+        MontFp!("10123770219692666599208924625777509883271312561577690589112346574716432563379"),
+        MontFp!("19563297971101084260099820862783813929179580711197283960855071977068075400435"),
+        MontFp!("4447207254069026895839688542596519844298864167018271339727580848327164733569"),
+        MontFp!("10805247891957676953966507705079087855877568284903068495618773980630496028625"),
+        MontFp!("15247455859749071900237435930523136345408272002648820316650110622790580875976"),
+        MontFp!("1523513713815340967949299977561986680730112913862622151113511170812079722460"),
+        MontFp!("2250092345142769832690429177076582432571233932766644284160212961505086786198"),
+        MontFp!("5502205194489637815685308301009607192191278776537698439488907603617260091996"),
+        MontFp!("12376110094727246736838305034851207905481280418572145688559541407100489476563"),
+        MontFp!("10967543165217223191795950135818653456591208931427429972754586323384063916713"),
+    ],
+    [
+        MontFp!("3007127915385490247377746617881910295130312971235615226220093403044166435816"),
+        MontFp!("15039810425747682043693978030807314591772830040893968588297240388685740832440"),
+        MontFp!("21282434502237090849892927101680045504469095017581606642683944529102929897154"),
+        MontFp!("17820554077495649709432871866725766383400924076526622095222498536082233385393"),
+        MontFp!("3238921346058569633798159055732162989904755716515207404910193961449538091755"),
+        MontFp!("6963065779232897395043653410311694992252845745677523471496668662716409139923"),
+        MontFp!("12741150889158293058202855298380167280366511627706735086836055155215291856874"),
+        MontFp!("8505231440570036863755136868680092759740858472921139738784962359970555463386"),
+        MontFp!("16263867309272856871859017360735124501630350380225439505739588230939592341511"),
+        MontFp!("11994652030543012675378700484156120788799454178056174639173766735729691200407"),
+        MontFp!("7531001745062176388176369578077819605743320864253593427909699809569190244306"),
+        MontFp!("11827863847264026226445842677102655786795007713756694062525124054010199531855"),
+        MontFp!("16129429107591815909860445595042156146087129962390514833959473605760944146527"),
+        MontFp!("7516787161495467824155321560594800913116855117833255279107222188682607365695"),
+        MontFp!("10052162714097814491117037623656731155747350728656529965818461926126557689689"),
+    ],
+    [
+        MontFp!("15866126693591846474201863966897712396582450897559972801925967203045783070614"),
+        MontFp!("1769345590280238074854062499047542732649300047644326060581629862863392048630"),
+        MontFp!("6419881722518831944067972549598430355215508824227908646199374367237336336567"),
+        MontFp!("8427762909619307952658968866443552584787988597438819510120993141703552873807"),
+        MontFp!("7893311334904009120458996652611823451343891633360441850908461624396886564456"),
+        MontFp!("19760139770663625588230394835227131962944058142185140281511805786245566094372"),
+        MontFp!("3986819882418057034478817109485859706183862449050678809809350743539886112292"),
+        MontFp!("19462740190201175689471975116541495793256811137828607475310915853573746549315"),
+        MontFp!("4950984269438746319161544135494005780273536398471035822969257463380210776783"),
+        MontFp!("5114126436371649572151778297196722433397981560175541938915777654399517384867"),
+        MontFp!("6500502433233986724406965295441070598123907669991330544723786166137230830713"),
+        MontFp!("16317287950659298477752416750967064455139776512193467637272228586615836234506"),
+        MontFp!("14644311768591599882243015624322844079095287900058309581805861693953854143586"),
+        MontFp!("5280400910054229241086158902425292691428494311972092134978890627089874392610"),
+        MontFp!("15127218399002910266114392708998449241113801821878209170134753716497261629315"),
+    ],
+    [
+        MontFp!("2711401246560501813405727886361637939228317090729002412066290100904319360960"),
+        MontFp!("1913027325164607464956755298437679573731669261937548387089448543475389709293"),
+        MontFp!("19048913840687946111515885036078245399510860136071611921573959765837451269059"),
+        MontFp!("21295287720552550910280526966903203926240851680963634134437049744624969307301"),
+        MontFp!("3797109693086078196558789257647252246485873371501397666934942661912968057995"),
+        MontFp!("17220092189283987679009565165189257352830222471797888201205665583060198133070"),
+        MontFp!("15728859045813610297052814756440978974533041326711446421367132340721185641530"),
+        MontFp!("7915246674314292718027357038683169057328569313936589479968179748005116348962"),
+        MontFp!("3168535062320575750000050980968432832479836571078965478747377498891053785750"),
+        MontFp!("13803986818738311305292789973880566948405947108749390476554600214529398575121"),
+        MontFp!("2887021549045614886550633658248390500136948561362661868354602793719521054017"),
+        MontFp!("9835777853770722919097370759265493857673816349027354889969484370501195735082"),
+        MontFp!("9162920843634529872148328448437467777954760966007230180656824492469148201817"),
+        MontFp!("1074954035830520777949184022573483523978030205622574039670230048399504082838"),
+        MontFp!("14573949395382273725648387764764104587706358354270169802238874779976052312087"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=15.
+pub static POSEIDON1_BN254_T15_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 15-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 15]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 15]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 15]) -> [ark_bn254::Fr; 15] {
+    POSEIDON1_BN254_T15_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 15-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 15]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 15]) {
+    POSEIDON1_BN254_T15_PARAMS.permutation_in_place(state);
+}
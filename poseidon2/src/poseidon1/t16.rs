@@ -0,0 +1,1641 @@
+//! Circomlib-compatible Poseidon implementation for `t=16`, i.e., 15 inputs.
+//!
+//! These parameters are not cross-checked against circomlibjs yet, so [`poseidon`](super::poseidon) does not use them.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 16 8 64 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 16;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 64;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("19647061463337916460942375553072101475191437675089764130648797272059706835097"),
+        MontFp!("2987900412319695329324667493933426290750629320482434345012869808788189293747"),
+        MontFp!("14313117549814523542459271158255968194819696107203500245376504355915249564569"),
+        MontFp!("635066671179149779961724809079155342626591882143599249747638714005480456001"),
+        MontFp!("14160366375280976850992425663667859199067402849136919009370279834492741756927"),
+        MontFp!("6973916440684075662378599037972982797550158082488606172483341283171694141353"),
+        MontFp!("407790128607292443078618781455551950270304278197678311107891073846005921099"),
+        MontFp!("1875793830194257638983834574124736838833728874912304344706772047211830871895"),
+        MontFp!("101555677977911034029979807139724697918613026657646487138174278033141465909"),
+        MontFp!("13298961474358064737775518932222238976786587146906206646633234612439936576772"),
+        MontFp!("6675018665213382228528485041578965344759847379196981998842754547093440230230"),
+        MontFp!("5085649234634970209690321129917296688853246686378177913913323311616242468355"),
+        MontFp!("10058141944442728296289308385948277117189357184119821310668675797744136293133"),
+        MontFp!("20711981720256091912789603700019290285604375596717389895155646132584571552203"),
+        MontFp!("20115432152302860531854002084546199214679745925822431241410388037137709465378"),
+        MontFp!("19426738311039094155622173280735935805207149231732138766959497422037163547769"),
+    ],
+    [
+        MontFp!("7740589787985988848427674257205602851899971532434369842038308874897481875095"),
+        MontFp!("11072265639503386933704945672016505140436978537584329931993329650203494086219"),
+        MontFp!("6167282302581750408390138662907316184354012779517813053982109604767767995057"),
+        MontFp!("14593714320140781629003483490890381863557111469157054599498274206519671343499"),
+        MontFp!("17959188687624917851017921366866983692604241271917787434145985166811823698158"),
+        MontFp!("10852786592684215415216400376119268936907433212885674472022333115957039052793"),
+        MontFp!("15899441678259173360040901233792251513972059637300348276334545233380063193689"),
+        MontFp!("3640175378514868793712597306483649195648235320181954901691448087453970656158"),
+        MontFp!("19498930515578230344335483600141550927765501643188753803487668144320311818295"),
+        MontFp!("4153883544158745158953668931089517690854504894896391299015592025101035411270"),
+        MontFp!("19024468701496237603291237797335586206588375930028220273546773163298357041151"),
+        MontFp!("7469727364011292433851252680653746774195189525727608179319902706399363717756"),
+        MontFp!("2372143841469285674441303263292066347817168610069150223765733476276718069613"),
+        MontFp!("2516526351266496289030890575774410993157441063594813081137075222758309555822"),
+        MontFp!("20958751338961200084885567700868871946051162714262967700193597995642229058459"),
+        MontFp!("9198209373895042225521605474867845062450002141670817279014351290187429107128"),
+    ],
+    [
+        MontFp!("543785608759854122795367682791595958842618445464321379849398930724000250504"),
+        MontFp!("10214529630060513503750965897811894289300014475522844219670830726679857175601"),
+        MontFp!("11576753654045835303746511804171201194442330501175712221979130082457712862265"),
+        MontFp!("6214928611453392028562534794962748192402530967301618657847917468183855957477"),
+        MontFp!("6248903930557664471829331572570457764958370320737816568669654972084840708363"),
+        MontFp!("3521559114442643806761280511561190556015853803605505266866910604261521098953"),
+        MontFp!("14207749404758918058098136067805881181486166837455095244160881284733449919110"),
+        MontFp!("9959485107346230833915817969343930335833003289106263613217998567268111531500"),
+        MontFp!("17002458248120505483758089120825692383088865286608827557586088545674133219848"),
+        MontFp!("9310286746554253001882911152696415122865977191166769045081952245779941262056"),
+        MontFp!("833245639626789987010046903814146615257437312131003591772116076699143834195"),
+        MontFp!("8257332153195419962290907487481324519003765405123021230564312430389478396079"),
+        MontFp!("15127724347963527967475442670935452967842333763417615675896327776913208692165"),
+        MontFp!("15791631600664089304301903868070551535052107017766205491164731100213785544191"),
+        MontFp!("3248589614829341629004884091016822219853816257771914825780122055933452087513"),
+        MontFp!("17215199223989028745431952733663229031216291778213241728328297124270973463797"),
+    ],
+    [
+        MontFp!("3857684745108028860654397149812523817069881299315264066597992653650257401551"),
+        MontFp!("4707785116452305555993924679316564589154347100943642537399862884483438576343"),
+        MontFp!("19430682328356065477111453488344441289467658065205729792227680437122893422861"),
+        MontFp!("8005988640968242998051528980068908390083328633663970547195021707967989536508"),
+        MontFp!("1972474227742829959658839187518313253567182690341134307491795498427960575880"),
+        MontFp!("6504813065413498635983080741406156525863657160083764580567056987831449046042"),
+        MontFp!("5823311218891803691266204716746992257279538141703406410574718561307174926795"),
+        MontFp!("9892303067707797586148875186586047934481214044907972144908705198351662761557"),
+        MontFp!("20467423831764780786043971286447965746242601887189594828393353559483921550575"),
+        MontFp!("5337137105639218811346004301122986797373254603744281473362301032791465429184"),
+        MontFp!("2653918865001450389595199059314513619487087198676481143857196098234024054997"),
+        MontFp!("20026090683375374670866007502511215153733777854247692013299401340222837331064"),
+        MontFp!("16088029123818655662676092939046004587731443682967462740467056646463545748825"),
+        MontFp!("9880178757459464201483861677712096813007025248923714154921858424834034903165"),
+        MontFp!("1227858189983101698453184059397045112686910656353893224019532173573557918655"),
+        MontFp!("6965709790321124552058584230424761849742693958580766537537673695015364525547"),
+    ],
+    [
+        MontFp!("5275724511243540616354496187333612866929959836267482390875038898914899476257"),
+        MontFp!("697708336385781014957549769788950342363636191998726381071876409126144042559"),
+        MontFp!("7274584324261857876506709208086520820725839679509101845928052585127373751594"),
+        MontFp!("1101072498472320542658663987709974387416478403320298285132888772486638626384"),
+        MontFp!("17063249509595154712877503960715103016753273139274556931196815282616091591377"),
+        MontFp!("20468232842910222775240425801279694589286852891430236774476461428028768660386"),
+        MontFp!("10839957331597622631657614186340514237771754591887181416690281526344756522470"),
+        MontFp!("1833441125433983427564061829081424752522350755265858559398836992598910515884"),
+        MontFp!("18955730579934733484387457001397648556717991843841809299503396866826874046919"),
+        MontFp!("8193171082824386660318148864436464606096456472585333569988889002087311061541"),
+        MontFp!("6800731829409783994258949782115883803874917294598056504156236185152975271613"),
+        MontFp!("4551221506539437319374784319536342657448457365716669137274070321896962382201"),
+        MontFp!("3888700085587860510427705376785182344099574784427861867496328978292244934753"),
+        MontFp!("8086322087822351497126170321910559010882234382816099821864406027930561491554"),
+        MontFp!("13275797274085199955841117698566970822958536692349164078040808025934114965830"),
+        MontFp!("11798506987450083560046523556681776539473600393190500985018551824337777992733"),
+    ],
+    [
+        MontFp!("2379081429050928317988088394722736405728459402480510127050576787799908525809"),
+        MontFp!("2158947553437093664557813698796314628878318098916390925037304154608297340081"),
+        MontFp!("11904049624504424229914369023060185670359894203980447724969113153014864088654"),
+        MontFp!("21129595246904679929428089867320350013514202309069019924095527072919847726344"),
+        MontFp!("10893562472341509760161513998095439702562664638408764329166649578524495942254"),
+        MontFp!("14633782125268548143403043594739012390811363821154748677494041549086652426818"),
+        MontFp!("18155420130909256009162482779733306385315875131491307204196352931575522168643"),
+        MontFp!("12073522950076264054413053294532869251854443128423131910399999522064467473027"),
+        MontFp!("1433592116103756425832298952472313408701354429203600638317025112329710147915"),
+        MontFp!("2210939565463298865317782595691956567659826882335372151952428383797077275627"),
+        MontFp!("17035360868359161456401993589512915729326589319922635525934508061308509305732"),
+        MontFp!("21403800287219776827894322644981677663016408317172756418765747341745060868637"),
+        MontFp!("13463317002652268594305080031749651114168039804631789430404782211764311412845"),
+        MontFp!("19738499492349409431828527491123847227085394983018723982858408988105307624104"),
+        MontFp!("2012548380220619299131832783872761872147153098580334235039922730491934764706"),
+        MontFp!("3325274441705326523449614352431988173829782789776117744919906973769657338996"),
+    ],
+    [
+        MontFp!("5261611144921901341966147913919865209616390993972727644394713260572315512744"),
+        MontFp!("18987697050242894331980397947115962487019662790026980590641254086717180862945"),
+        MontFp!("8658141027857622941054124779019043605220504649377920644749538450450805414621"),
+        MontFp!("11298428708044619749095290390778425959792777464903586113463716315584533582828"),
+        MontFp!("6730200291399992595132121834599191803078178940321882359439272645986988925939"),
+        MontFp!("16058286461189478903573915480209402516073069688039571574175048313793344696582"),
+        MontFp!("9740895146643188739739241045620497326490653096157416163918867637699590812365"),
+        MontFp!("17328668678982472669285290349933801381460489699965770954259262923597437466085"),
+        MontFp!("21089229510079204828717685354260991995629733636903215847138008238449607565274"),
+        MontFp!("20640971546156771190021485453412235742638585574517108137718546522103899393969"),
+        MontFp!("14758279983387100491873648446401986574422791750180622274744397880182747812100"),
+        MontFp!("1331898546985028774480334813742156878861378216830516346949642945416964272379"),
+        MontFp!("6432287430987511826080726363315893796139259314225964668680871966245781390173"),
+        MontFp!("16771287021606049252082476128446106722127174299597407353702759915141825150750"),
+        MontFp!("8558856604643032676967156921137773032066151674912302830855999926475047747086"),
+        MontFp!("3441849687388033123111488396776112259878496892302987380166582753348946609870"),
+    ],
+    [
+        MontFp!("20817116194964519717309108464421257788806753886196720998666047916921548668924"),
+        MontFp!("19363239836951813038374327912605477961457473367759250309818663552575087804364"),
+        MontFp!("8719722538679135055399244869855972116946451760806505569767286592823561841553"),
+        MontFp!("18664054074328463099250618543796241821469021451703648566147509976488389212302"),
+        MontFp!("14668897608285076749626150823646322752663015099871458303607991619920343960884"),
+        MontFp!("8824985320268620533295858061606775496359110158594681923758227994736311199135"),
+        MontFp!("10765520116421824752776648993191019870707037690612646148788741126433863060128"),
+        MontFp!("20754227554163810768271776561488490692278680037121708279136293739447289576147"),
+        MontFp!("8507072847563043340105426835824153184629689984787563844408253684598778757305"),
+        MontFp!("6766982373679017786884251724806484438649942596522690604198707242527640673411"),
+        MontFp!("3038766798814116247860373387571799940341461487105503437312437210868806237693"),
+        MontFp!("16132175023628563044043762398003871532172614031006064729051923614189729264142"),
+        MontFp!("15583173149116838843387513514855791665649616393679968646432984027900294981739"),
+        MontFp!("19200443718712964237956082975258333421930476944060656325774330146577168149713"),
+        MontFp!("14490821043935432280588585568226041328772039440696419883978899443298638245193"),
+        MontFp!("1261830229525183456874822855513761625054204680497477037321364189175040481068"),
+    ],
+    [
+        MontFp!("6528746667003363057717101918351735481714469206031070610241614606650021871543"),
+        MontFp!("16147698956945808666133328464174436996026072559773234518262594815923002983587"),
+        MontFp!("9020387669972688980419006674825908656426016085797207362353154226605692909040"),
+        MontFp!("6727316761823910734900206867002954254557029243225097482815337322560175181198"),
+        MontFp!("10066421681146255853671223544720366622786875122426340101570461526567311479729"),
+        MontFp!("18114193263469715956238812322551819970497722041025850638963351240642707536449"),
+        MontFp!("13327552382593937204593701292574430198134175441510741573417228229955049364251"),
+        MontFp!("2372604211171385703747757710474646305749482500024237878826421281702483230858"),
+        MontFp!("13257727745849193909326785093877285673934675536283265665870765530981203548766"),
+        MontFp!("6028193081122651452411463574343231811776375151328081689399842891316362242212"),
+        MontFp!("8508301356193721985012355411615100178521599009635936162890863637274261948848"),
+        MontFp!("19464559199695905284994131173285166577427724356611906328878634139911049316349"),
+        MontFp!("11574946347736941315258330071986157639717219704847732435648573723449097294965"),
+        MontFp!("14316018291870434740761571976364226850140038868497601980741769481529398163257"),
+        MontFp!("10918196690875147279977362872452345319770767457845834002916792583407449275430"),
+        MontFp!("8977373069224380198540140180493576791843577554452269707469880849832228035023"),
+    ],
+    [
+        MontFp!("748498829648879147053737200607377785638767247375633990031472844537260809404"),
+        MontFp!("7873158704115081877804196477528352958470140833786962209738121862287852609943"),
+        MontFp!("8630532424574483719830065132415752445222218233997041715460638881404278125797"),
+        MontFp!("8690582614704926771670051368117061261335922283383440650770249469863376973533"),
+        MontFp!("11095347717221488007795836937657301037546485308926406743891578760518489637433"),
+        MontFp!("14135401679286508502504277387212121656373093920904597158275723422439876100612"),
+        MontFp!("14738090907871182095556666808390406734966899260337679732930591106508814238308"),
+        MontFp!("69949271807030541733792162811562320986072778465870031251424993196153906266"),
+        MontFp!("1261108319753649612663311207745706802298135850234573661502446278242936235395"),
+        MontFp!("16817683438765699400477322528948826720336276287491287100775393652707943792575"),
+        MontFp!("18841362612982270174762542916999427955157117780377439797570032391179795654286"),
+        MontFp!("21870116979686159000730008975387147484906370787640570497473602061164852395071"),
+        MontFp!("2765949947644452455039725847864010340741814376903283748968022076584286340602"),
+        MontFp!("17243705140322781483942034937718263695017457618400778609034996357553437986248"),
+        MontFp!("13677914966377093417490296499705767815775553283213762175449591670735007344873"),
+        MontFp!("13297897273167025228957171745153893110275891317806768533464851402665750442708"),
+    ],
+    [
+        MontFp!("13343269561671098171091946421541340634645677702710756455899883309946421878045"),
+        MontFp!("14317554923995329326292532110843156058636017277221221405605647959782965284991"),
+        MontFp!("2449835610256525707119222686954432076774548565002604197859382557987062142872"),
+        MontFp!("17311927259294224200654531686487034697399582221230204382526629700762752029323"),
+        MontFp!("15886029754147081563564215095016637219622964863827251334461319377673888336370"),
+        MontFp!("12975391569205596000382467418571211360327385366404855968892273321920864753986"),
+        MontFp!("5192224731376769981697271181929966876988577937843948018413420047649317448463"),
+        MontFp!("10676192139479409715075805869252336543157972214291179434959380291895052573000"),
+        MontFp!("11177450837775344504988539319102121281143354970746599512770721409890402968920"),
+        MontFp!("4593200667847399069176143966880767249193687931869738276411303724780636851859"),
+        MontFp!("11038090380551563944847929106606106685586830480239388947878234434263502089528"),
+        MontFp!("3953526418885419728011595573117200571065709475826662733812952860173033412620"),
+        MontFp!("11423581837569206292763368836201420979900393158634684009052097987935130296343"),
+        MontFp!("20821758092880168608657749212670937227806187953778513378055795779476865339010"),
+        MontFp!("18497750301637542715216545677959957759969933594321504330433834545748130561538"),
+        MontFp!("12908315310864070359072899712184126229744818024807969170422172983759986468742"),
+    ],
+    [
+        MontFp!("18124554128224712379655197019948407579501104121202515283344405665022477997811"),
+        MontFp!("20982975342803604005070898815103511622812678185245827078739170834137855132820"),
+        MontFp!("269825514811016046965635325890713556615518696022373524499024558861784638050"),
+        MontFp!("3147172016143608266119085281262979524079358702373693860744797997889998689295"),
+        MontFp!("14386832245166477008833710911810567249931220515383598373556096298357174022469"),
+        MontFp!("4556487278328022691163443795787718624849832853076824895328263286768388362379"),
+        MontFp!("12261472135716169178595791281788338424856082203277018628926152780653238868197"),
+        MontFp!("3899423277681311798156637809536718065846612626667684730473026778811334914007"),
+        MontFp!("19506309861341587023369919042973949592579256277585657370274971571040135953685"),
+        MontFp!("1364959409282923580897524375843789492029158451437094417717346158650761726050"),
+        MontFp!("16825446178437335546349323854223244861262257417842514939476542139147191650927"),
+        MontFp!("8507209116997169365742612629060440573797814488088100151461758543065101868641"),
+        MontFp!("15267004752470933248572062004321128218304784520473623806984809921883550707694"),
+        MontFp!("676031704648473427598859615894926588607941948575683685792835248653139785855"),
+        MontFp!("5619669402121492986528563034254744932241765329516105050276049374453441613893"),
+        MontFp!("14704798323824402102639327448519792804756861685698966598005087155126928897024"),
+    ],
+    [
+        MontFp!("16320067378138810368504584396122999292945236808095532790918287639367557973453"),
+        MontFp!("8146733655224190459272793912328710535983522769849572460217349885461291275505"),
+        MontFp!("1038180418056776651442944028459510265058633383281360520702142043667403503844"),
+        MontFp!("19104250152149288692194160087229108962380481983770051876357439473931889382526"),
+        MontFp!("7003760916474780870091321276888809099928758016210575511830123521067523691017"),
+        MontFp!("10460150809039904156668983747345347198316841366879181914914418086579576664491"),
+        MontFp!("15677112907432790716289265075133862681087874169637399306212310599581157175963"),
+        MontFp!("15326287388823547786897864243344800490244989953594543352512215080754525987008"),
+        MontFp!("3009920542142872962638960374061083879344081833005888204328736035225746795718"),
+        MontFp!("1804978488347291728619316877980070589260679228487014904460247370976082660690"),
+        MontFp!("1328483773995482788116589592947585572244503408960547493086897090179230375909"),
+        MontFp!("5730439196427856076422854580234519707227885379777920110477133774925338997125"),
+        MontFp!("12981431367443547352573507131765244291012615436617972351790438163822109185806"),
+        MontFp!("4832711978673748239567077367987729540684018769513731999388791063624971084279"),
+        MontFp!("19167638139894327951096186708600927728591679782746822664161578344690189946483"),
+        MontFp!("9333793061773227893961520586484148770892826436173136355616167263506645189532"),
+    ],
+    [
+        MontFp!("21458443518750111068075382716496819469049134888053123021475459386077573760694"),
+        MontFp!("10205061553685164402371459751106832224694007401400200656551443744478399832956"),
+        MontFp!("9830442925198991171494436686328858756494894392913894165312258843542937207416"),
+        MontFp!("13609869649628867442619044498926584416410429910199812031508542862339177409898"),
+        MontFp!("842857359216662427573900948838829890161571314532391785590044951074431433210"),
+        MontFp!("6704851129269714864143856350805682503777715960622547687053167421313207852468"),
+        MontFp!("20114446898395957281817578351485444375476540075666338352767091837280210668931"),
+        MontFp!("6130491715603374999851365684496448519606340852139128448851532580625582546602"),
+        MontFp!("15813600594451539718733724931622603275717510629305297903420212550967482486778"),
+        MontFp!("21327142130781371825633810115678136219928778056926678460292750153897861437357"),
+        MontFp!("4961568602907543961625596532526706517274095072299784820035412496941108876522"),
+        MontFp!("9960714813540172203971946479714057278358565379915043327324100653488017320531"),
+        MontFp!("19766028424299726292403979387148081559608033800073407130824876437622345769610"),
+        MontFp!("19128679427621049663909949398415698465159247423858348746959133844645715231748"),
+        MontFp!("5570166864868188450021144960131468276106515498742461735810689530781856406802"),
+        MontFp!("11574972995586621052272541749980259251569951388173301707052886832340902170154"),
+    ],
+    [
+        MontFp!("20877646438494519923058752260065237612204466401313282232221152388173388627982"),
+        MontFp!("5249150519585813956946898091205522450918428396100844955321690157312140444303"),
+        MontFp!("2017741632554727420098342601911590665808744692822556685407780092354922864904"),
+        MontFp!("1195760854074467363227832424961613965990883686618742557387108941759791735821"),
+        MontFp!("11466582138640916980683611003811079018804741425452823176665968956853901549307"),
+        MontFp!("10985903304141344987201754580174851046824447026961915755527591886735857840658"),
+        MontFp!("2130569969210610976943124127703960718576010294778156297713757734434872381369"),
+        MontFp!("10738808247531379378397673739325665568136689079862172683794674460448121540040"),
+        MontFp!("20614646033198180892625991863201166456931543657809805208583071176938057085966"),
+        MontFp!("565997125213498936861304726982380864240733960104507001725677609359585569840"),
+        MontFp!("10632097546602816816944445466416073366486654512740953706603375905768461201631"),
+        MontFp!("12929362833112356946255271584627031609627907912912902502403907291451582319157"),
+        MontFp!("1034235212357952436868793031480652544314617407845212103021627643626485031876"),
+        MontFp!("19390762319422155950976700977771604452581304443563816543281343170335005291057"),
+        MontFp!("10630153844633439282958810722979033212891017579520387012386923832074337305798"),
+        MontFp!("14190876500956732147461925775340768352695586121304109940717530850819588911999"),
+    ],
+    [
+        MontFp!("18722506055380266423054060346625995391170752586033471909467419883841861306716"),
+        MontFp!("3056819986793075694786756176651004538794778835114033048299678024064951583754"),
+        MontFp!("4672570536584218848208255703572454924953635491594309524306431682544106754221"),
+        MontFp!("17351556719883029551473146382008643929874047263147825865359162665615894766393"),
+        MontFp!("15361589507494181649833267508353254535596250253216356073840778948791727807159"),
+        MontFp!("13693471199005207103868448237123737586403755421081623632730820742927025187060"),
+        MontFp!("17755277847125531485682000612777686738631414648862078678723432159826928724703"),
+        MontFp!("5078737090654746516628738054730387217943533822956354885634428155918832329055"),
+        MontFp!("12147601749747781924069337935019031145159705806951967042421913575214356549816"),
+        MontFp!("14365231440612787726412058658929032228572314258997026523542183583106877612565"),
+        MontFp!("7278303658563994843919131396912585917500535615791945995381401546353032136647"),
+        MontFp!("16203755920169126984249498560164803107868240707492521482933949021054510520315"),
+        MontFp!("6791925607504018751125155518211487306271141824074365658905258365090537532910"),
+        MontFp!("13823494237593720607868138054959291887740146822262268248432322209124930846096"),
+        MontFp!("11009501160902109690977091445438703229756969339078969536565574715162502634351"),
+        MontFp!("14720462490975063947234490477382491041961626472580003583159938559677559185952"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("8089493102530595468824649860529717181797071865148765611726566631095271469313"),
+        MontFp!("14191702863884040950201894968554909828474263688190658890761244249722339089253"),
+        MontFp!("7127251756910107506817428481560230656411897313679770455026102671367474097624"),
+        MontFp!("4637045655841785226199626823170615348821917492997807409130873179711115857270"),
+        MontFp!("20694397780982417522377524687614391225351058648522092200738043379276991211973"),
+        MontFp!("7370528537006777008458800981771544164650999525791518443407240929647301337224"),
+        MontFp!("8357863226135085648491488089966537213596680483394798064376015085804610201240"),
+        MontFp!("20367087512494301090653054692863377975501664994817680661442168333644299005261"),
+        MontFp!("1950307616347822794878104597377932622958317394806719432040871827549672571148"),
+        MontFp!("19534568412595886801081532478416580190048212177563706092280027206347120417736"),
+        MontFp!("3526428150493332211163778868665379218281231008068537115657136020226387337771"),
+        MontFp!("8661888879209475483716403816709663500534119170711151152467249807886559994385"),
+        MontFp!("2374871949454649266019269203973683966955509927334059254254180194157352943840"),
+        MontFp!("2602346264611026079459352146265308073912201263350931622963181149514761751618"),
+        MontFp!("16750875216633927741061710170647391823629779494283192027015930006045661350833"),
+        MontFp!("17325348607596842041611786882495470980592459865615213806942169413086586278610"),
+    ],
+    [
+        MontFp!("19257833407854296241609506861086921463964253377452883026082737974007723285939"),
+        MontFp!("16875536222414380047765946704936299627494679557426090013440678117262080229388"),
+        MontFp!("2035577529925145134060996791483051399276402053645795728801937468290487364296"),
+        MontFp!("14222296831200170749164428995066771764920247405996747821740895709606052517407"),
+        MontFp!("19708208883712347371628256596476366883171584832295648266624355950215220127904"),
+        MontFp!("9765600454835189412776212142789582666145342479104764891694616693066517922502"),
+        MontFp!("13365055082376018935548592209736650793571432301565333849832704476237136996118"),
+        MontFp!("12420692692663472732723794387493491467146971343430969855362995088112286983728"),
+        MontFp!("17844493444787722109223680249951335211927705850925719012603471955916755628715"),
+        MontFp!("11924944537382281343613401541176014853361783437608625866798343540969055542140"),
+        MontFp!("11538333989403053525558050588509973706031711021450280471202165095037142068477"),
+        MontFp!("18764881783775503232423409005005138632447539466481045749407270569758537393398"),
+        MontFp!("883162740610443285913150132085694648635519837269866659825930623017971541006"),
+        MontFp!("10171610390436513861069093903522467940290613868370765425375996220687957422386"),
+        MontFp!("3782996878040749700177878799895260702330488343333700969514291046490113056911"),
+        MontFp!("2239298968343190621001798397134314102896115970272480212453695048745962826317"),
+    ],
+    [
+        MontFp!("8391302051404015178833807081994898536482484463022532956140929200199667853077"),
+        MontFp!("18096164030411129129794470313655227930799185448547884977984818798794318221345"),
+        MontFp!("14613424916575212836238688482051323957035101724778773648452919665593660234946"),
+        MontFp!("21828842930708391060823304507355770719075227415675853536112390374449711667078"),
+        MontFp!("16155169369395425892836659730715232159396910561858841286007416017756724971516"),
+        MontFp!("6878543234699575736262375462702866780772252779651204219697567141737826275015"),
+        MontFp!("6515580357485419559928692633356133128461299871887195169242750902403019378404"),
+        MontFp!("3124270150531482035695013416051086970123461406854560487654035672981879561715"),
+        MontFp!("10574698281815682641771693421115734396172155905702803455639975437772362072107"),
+        MontFp!("16383053320907906491543185419013182425229207444535577699313377451249772804551"),
+        MontFp!("14128602818455692582904557734609240140547347183347545360942329621911511704432"),
+        MontFp!("5010441108136365108046592406551529565385874487432431586303802326844966531017"),
+        MontFp!("20105162467673383983751690623903949493335895059047083944279780871549558030082"),
+        MontFp!("4243524359837792598965046978953117623831146922720880550516255054678204683627"),
+        MontFp!("13992943040313469850370025986460260524555943628212884466234808367632688288666"),
+        MontFp!("1356223459509978352432345061666791804860823828908318470584528377749655994659"),
+    ],
+    [
+        MontFp!("18723939192823222870283999271398340959482157197116460943968917507878394278385"),
+        MontFp!("20636603031793247786862933945150382157149390912061952007323929980504212222032"),
+        MontFp!("2128636310217902240014588202116301452804441854659460379597999718252532068242"),
+        MontFp!("8892458127594737495267008183431736534386964959413452688074230438040504386190"),
+        MontFp!("11063574691797903196312547816101464620325790434381460261930676459857164737407"),
+        MontFp!("6204567085759054728130072959301910137692879386674785283047171198023995380593"),
+        MontFp!("801138962072186678791979363551434811728526970692377261272697294596118718403"),
+        MontFp!("12941641588837981062781447688458784558606932072335320068724403816548547438675"),
+        MontFp!("1341967686547656677059175660053506857201298028878352128737987225407679621418"),
+        MontFp!("11183207447266717668611438117401741351804062326702937903525908319309155043524"),
+        MontFp!("18507917680426998547390822933408085624123062783706353845158832700169805897057"),
+        MontFp!("17600956758590554476691669608345000941959084040124470813766683278150335958657"),
+        MontFp!("483394656899715242498310209793599026850663551066988094136788591643990355221"),
+        MontFp!("10090827432001200203094010206359607863114405903353400294360808239534902160627"),
+        MontFp!("10039403020393871677252553778177538625214464462052976366251977746523421324197"),
+        MontFp!("1924712848258707645798108847678093621275398467857399987976700805356929290674"),
+    ],
+    // Second full
+    [
+        MontFp!("2848475116255698304240592397534148356674367429794174368229897070816851620326"),
+        MontFp!("12588734179864636951823212209185103123565413699799377322920024420368718083095"),
+        MontFp!("2232868200526350302935711180734463487873472319399673090914563494365441045552"),
+        MontFp!("21377055866142483679502571618984666212079397034684178104676086894866487789275"),
+        MontFp!("4635961124592421709613730717934803195170398911827834468836156717082833802089"),
+        MontFp!("4795610144270291375198683497266358163517248476702360287932565598454128564576"),
+        MontFp!("19878139464835077446055142241229890663517120197298970825225586650258704320634"),
+        MontFp!("9323502139000335770056400844753650839571196769328639117682123746249546059680"),
+        MontFp!("21112416038547244178847876677595343035216538411439215223028113590182490455841"),
+        MontFp!("12697374728966756322005607409702182672422756890620656219302331428706184232671"),
+        MontFp!("9995295835719608174333254715476035051015528223152237761898234348962139965339"),
+        MontFp!("19838681448410021391386343877373049070883481927354420971134124213644615857262"),
+        MontFp!("13348946731323604604321164467837298053616765723820806627202376009331748334141"),
+        MontFp!("14459243825598700354634855807582241585214330632597159841271530816381999251613"),
+        MontFp!("16398077966528830249699687699527475156225434078425955606570595942126461545256"),
+        MontFp!("7643106356234289086355359290357087474077139338168525601611444369470624871398"),
+    ],
+    [
+        MontFp!("20959675452873599571224578970521717955557909237727928302612036851445583012450"),
+        MontFp!("4540025033646420810215611052662284909609074316113304153198697292213556037365"),
+        MontFp!("12027414908456226247222654846878068961735365399441671468269489072556154853636"),
+        MontFp!("1619579475293093356383780863588610629947769633032102520272612883476001600909"),
+        MontFp!("8181934427490631780501305508522146234029250148182651874753791841738732508561"),
+        MontFp!("15274797459753339681175428319112732573480605162611546476487133996977708752899"),
+        MontFp!("5881626303176748491435260832560365957494745248996859086310569735454431253109"),
+        MontFp!("3420994635070209394832291125346590627475632172750870826118213841463585026009"),
+        MontFp!("3253514739572615120245273590897551489662089732892213994698961442123844511104"),
+        MontFp!("20203053841592538933587679570153207252397020205630948336602455897993666135475"),
+        MontFp!("11921266577552501086897102705390311185747062744461377484820893183533643304437"),
+        MontFp!("13241907169609567850145071795854997578682645559619300976538988140768348089882"),
+        MontFp!("6151712987809690919701305472528460675037031217153195032569932714294427052374"),
+        MontFp!("12205705969228027397734509241211850240154931572324813086389133244191540704608"),
+        MontFp!("12945812038464520921512101569088203002779738300982254987224395885526111963420"),
+        MontFp!("11566504805689497712142963920462066842262053081516919575350242192944407040992"),
+    ],
+    [
+        MontFp!("15723543550582708278977667347083710027075971342576647975064035406870923269719"),
+        MontFp!("7860258127934035634020846943939055662221372673596335233171159964609391837625"),
+        MontFp!("15528468237941422862373023290041270186925402725739650209357342065347651170444"),
+        MontFp!("11352800656251355849609307793804420136337526597168059373534052608892527331301"),
+        MontFp!("17897134085314321992998873273654112498897006369781950634488633467466113054350"),
+        MontFp!("17437455229098036757167276638155356442563492876806914963325540308515770959304"),
+        MontFp!("14997752382200324825750631040991638912810425809039371028733815341246302297900"),
+        MontFp!("14919207184372260640968465889336548904504385718633186760383678178843526009885"),
+        MontFp!("11844428225775333775773424762116332026943738109456526441415077427972318646084"),
+        MontFp!("15239467296659614730514306837963031027890655569818408146133916080687077630265"),
+        MontFp!("20117441496592956911595459290983302272515832294843762087476380541606326158806"),
+        MontFp!("11211331365407064571488620323378276965399956667340240900293979889578003659573"),
+        MontFp!("8791311092499361250396136430755948222248236194668427353488891370543849807047"),
+        MontFp!("1774021261549926243219732938834617869058292002083739109596586460663663531688"),
+        MontFp!("2787995130097988538722853640105055460288688373288517482894772682647739193296"),
+        MontFp!("14036419256752421574134832204020173449399727322774622383219518631065684283606"),
+    ],
+    [
+        MontFp!("2503905040784637175494196915035325275253506707537902487189138102884611289404"),
+        MontFp!("21664450508754049721193573452150382260579289185348178882539067755355712577906"),
+        MontFp!("18957082022313129842427206117755534129197834418112547991189485282634468396440"),
+        MontFp!("16781909482475992329419834248503013105141202009836651280677498803323770600224"),
+        MontFp!("6643893904478082560922317503708920766922698054352006836803634579280954593309"),
+        MontFp!("20411224098851507032152776776680744497103387047587118749494718067214956146818"),
+        MontFp!("6932200046628136855984161612336282559188694975968887215970260451999517971798"),
+        MontFp!("13683745075914427134220228573435856137115339570721809234203113630305711234299"),
+        MontFp!("12049119081343437729871267418004147930387014906392381996118616615174663353079"),
+        MontFp!("2441975952742754201500338273078694079713769380080349023008653075072257968553"),
+        MontFp!("1011172201777323348203437837012280331103466286486119939185319004696667574496"),
+        MontFp!("4513858889726009970880526008944305706495472698013415303299122950266699858614"),
+        MontFp!("1829602352761774082971266699128478520319034089172917557099196106572588528973"),
+        MontFp!("20888913280392789424820640494940209099778028785108373950708163556643786717499"),
+        MontFp!("11546628016884748339883959858649314450404290142635813671623548341391668641333"),
+        MontFp!("21503514976315590685255962605196280426006590386762670744592186136529638021924"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("2167923809975069342404582706442301809782773089534518796665536897555762931998"),
+        MontFp!("17151337466618795643803903749019481111634005237742283518609358703199930418386"),
+        MontFp!("4693404918498880046514671012411195415185134287680728307646808116832211396470"),
+        MontFp!("4839381186041991439202094011010050618021148651243083039175474529769659591768"),
+        MontFp!("6368336559448535344096548979708211013055912139451892726345028976601375052582"),
+        MontFp!("1969826462127986113899121152613324197819709993940588880171966228829834184618"),
+        MontFp!("10949071784553227115382687140318153702912633728202525040498248461299029092720"),
+        MontFp!("14575837988956266060370685891330921140979730144767405243152428616625585430364"),
+        MontFp!("19025750374860322288379311751788635906611708349625974065695544669443137256255"),
+        MontFp!("8764838805814072278932908632105809429351124473407135322177352737762640822858"),
+        MontFp!("9508966145895699172941574119083482387827406291145211775810415653335356563307"),
+        MontFp!("1499853062814132179278896768948580432185360181707926223121987796423000161587"),
+        MontFp!("6748895423937754323870965728722563272366114856617067613970193226138372983547"),
+        MontFp!("9814536604142267489967579335173323116472525708739068285104384748406479290252"),
+        MontFp!("18898551978119284539473648820403307265793266184829523824179430658011490726286"),
+        MontFp!("13332891294455702171134399009213907075208764727059236543259960129130320074982"),
+    ],
+    [
+        MontFp!("5806223588823614408610900761814632517803554600662169829453323047489266087405"),
+        MontFp!("8600992771060584427141790133616651912076186875693672268259185811464115092188"),
+        MontFp!("2345229032656719135417406942532115442859563039531904661440827899574284256939"),
+        MontFp!("3389225209359511603097670682835778418062727662626674404140526378611375444627"),
+        MontFp!("4001034682434006987902788179906586121095610380401857294704332109514659937637"),
+        MontFp!("13067063794999809131589470979742146742674772176859338210659998590141023560073"),
+        MontFp!("6021149641876662294720012642501582990883570287510418620500730995186581678990"),
+        MontFp!("19744062936660555217694711756418639275110313106982456779211457821487189038259"),
+        MontFp!("4280146833782122185864346406516267200729946534205141990397627632307276252746"),
+        MontFp!("461380210352497258096433274264553487092816458993207056498455795007735775326"),
+        MontFp!("9185904966475190600688426658264723956468287249470549941528026771035049026029"),
+        MontFp!("14362213733702712122544208087232387435453213772974406963783416068708716342186"),
+        MontFp!("3376133122839395926880247692021311631985267298172444834121553500975764354590"),
+        MontFp!("15430636928872496244042298024957854104479533547499980110816885404855993587926"),
+        MontFp!("10066678820787654667318707571747478964216410231002799394324814978870300697084"),
+        MontFp!("13679726663800139966289976616317518761749708307387522908283736372782010246091"),
+    ],
+    [
+        MontFp!("14452498713182028708607687209189129972318024710956800636750706657647839477841"),
+        MontFp!("4665977443169087186016514814881141858109411469723890078365751966690257156734"),
+        MontFp!("20673947333628935106924449469424554002781432367358546714606202194020160454893"),
+        MontFp!("8371937803667063739943392730412639954872585103736021824547232324141910768530"),
+        MontFp!("4915642891224254203187535229956007243890165147322145197624420790022741880987"),
+        MontFp!("17737894427474715513464524059598818432164911920893615068415915800842574129123"),
+        MontFp!("21257751286440468433003251296883236318376753833102432675015194790356307947025"),
+        MontFp!("11831044910484530710733100158936923549935832556815349526083442021617595246222"),
+        MontFp!("6362553671302097483758288321671356788227750730995074141338726755028234269753"),
+        MontFp!("14935971406087488699083625022278942628959200513244202282294495763807242526556"),
+        MontFp!("15337281352851760799121170871115742187805714822038335053341937333897883894609"),
+        MontFp!("11913020597445264316110942608419581486071629740611054234626537590474050938474"),
+        MontFp!("1838104841451540707239841220222372684744879960533787766800248740162155353463"),
+        MontFp!("12200586110950172266226854658183951607066336178441918240404611233042731023944"),
+        MontFp!("19022187774400544497696484163376638844589760125070799280660843859892595613745"),
+        MontFp!("20574219232807646025576258258887918895888511977194786423008507430088227970769"),
+    ],
+    [
+        MontFp!("16786939539299167614623107235117810634408742454195124075763115939760135086779"),
+        MontFp!("17293110551721019748567259052064171353712124247596873005371071440209356535788"),
+        MontFp!("3515336831584582569659665150343000056186787768627351931657274076732504326799"),
+        MontFp!("281425181255898711371985357453012506419817930424882315501470435479210893648"),
+        MontFp!("12703632225346671660598644961986122293501524448004046573092229475506828991982"),
+        MontFp!("5589259102510829346047060861494427710966525350274659545392525201677772096380"),
+        MontFp!("16136155264962057601674035851031539132207654054954939479993843931937787809008"),
+        MontFp!("6577810342827559587628942717478127045802616172145914985109615391025970118111"),
+        MontFp!("21163359977438641650165237544426961690819476598192610702649950529921750524674"),
+        MontFp!("20111680006656824813833529237249851680178501017371275377838319189183351852836"),
+        MontFp!("5575615948519348213741695691025005079345478514823636124237182535409145360452"),
+        MontFp!("5523844323060926872300411712160500242292621601876678751811292002367512597282"),
+        MontFp!("2341781247790586645587607723874752210647545627417738353111026163429313125329"),
+        MontFp!("6287726121450538785847268284295299220404766172163996260292255850387839422364"),
+        MontFp!("7510648235102286772333427364079209113816404370140168905255311267436378725194"),
+        MontFp!("5010598474035699846534727472507206499428871255705467310762876793528248455251"),
+    ],
+    [
+        MontFp!("5700900579731011250748267725185425351062102227041063523439252105791254824347"),
+        MontFp!("260864497479086430816275699586473497971665832148788274905912958670548982986"),
+        MontFp!("789172560806161139006535038197594038879692565830598745604163919464033838458"),
+        MontFp!("8100205286609553283468855217617464988341806863414698413875768149192804914582"),
+        MontFp!("8832923720458983936387697888604823914542155000760959167380458346468815941677"),
+        MontFp!("16408716740290439442219096309603613006830415688534254451854294128560591562449"),
+        MontFp!("1113287770802944863484309232492725673552460311886837538428140710794437752025"),
+        MontFp!("6321319205351235108330320300049882062942831936257890309503365991814791003082"),
+        MontFp!("18560922321672258596191688202021423732168869299915307979899958586924038420673"),
+        MontFp!("5189180998718572459539043290302679421565915950138990634526371489122263353689"),
+        MontFp!("21059088895950142731136323564720383671175925416797251043903234558355128937274"),
+        MontFp!("1758538453070760465213341124871446098960577889968251388551632606564361922354"),
+        MontFp!("8880106936008308891697048583874212287093823544613405494775675377028797786924"),
+        MontFp!("478113991762053058066048308336221551443374933771727184756201865704929054005"),
+        MontFp!("5364027562586142278013801938849728210749918309705526248720112356632858758221"),
+        MontFp!("16060418035757996187280816105829974246762721308664137780198420107462005497738"),
+    ],
+    [
+        MontFp!("5211176330971465153824216149376981285606706263050338982115099875409328929990"),
+        MontFp!("10368007546243825628853187421535635929255384415227635572356066208437184727920"),
+        MontFp!("11427684168459596442778160390157786957054817508127224050241732612892526145073"),
+        MontFp!("18949700777601180659579150208396846544227381511915556752893455904600581402034"),
+        MontFp!("7164876529024763696100437397763470424696261670104386888341279210050743231742"),
+        MontFp!("11672893202716354350665939605724566655297144836947407230492353459173601370992"),
+        MontFp!("3909710158111676258105703555994764863745260060728195069107924779522169147903"),
+        MontFp!("11345007057187618276350650167405783488453459256756441881422153715183450077456"),
+        MontFp!("16177820285119478894470005926103825065477859050875380004945625842748711696023"),
+        MontFp!("14605787997931757094644238801452595674783310674883843033832086542610678460710"),
+        MontFp!("8394271758834499906719701243007064560791869846306715272114566194508664069904"),
+        MontFp!("21873434283102736245937985212570921154785039540401524103800440576512828034687"),
+        MontFp!("5288693737755970825903341313388382553570352630888453317445067182115774973600"),
+        MontFp!("21527468641133089700262079461319550104586512157305923587305104390806741900810"),
+        MontFp!("20906707665998407830558869509347677772600060055286961495556134628147066213175"),
+        MontFp!("12592377229521260472205372896527756013482774884841906928184642093380092134349"),
+    ],
+    [
+        MontFp!("12910817347876296654185371831758099341950752668436921762585644650086702363745"),
+        MontFp!("19707023277054651202681442144426045379962145417802880101371794305270012080065"),
+        MontFp!("5370501568233094600905283140114160955385907390235753857797923590879732764547"),
+        MontFp!("4893525410028747500809250162311718717246192757157339358355279414251440821369"),
+        MontFp!("16933412769230696712087772684638356779344465843185712014276708361284654443016"),
+        MontFp!("14752252971762751211424871408209457845535321896357466404820315342528474475265"),
+        MontFp!("4381439374316418237204510331759065345075613480172639747277311241150560517970"),
+        MontFp!("18983302468109324889867853797358234303151484569217755559414917991855202421044"),
+        MontFp!("1720853637877748574561923069656227402993841601703014320322493127808042278354"),
+        MontFp!("18055674086054269831422192610284879350534759472987936156359168085840018213729"),
+        MontFp!("10022985435599924530950214242280707656465340100733875531414649379326713485700"),
+        MontFp!("19489289237398317489155396973411927208825921597467958674111652983018260376467"),
+        MontFp!("2483969152386321683533021854216836603663579724438750369420802837936459253247"),
+        MontFp!("7096495647820661985493920714559517131838710818830485847136203068929085317377"),
+        MontFp!("11239734991193991562092142618442979132467959527040707676033760692044595660477"),
+        MontFp!("13964689425465688308583323493745924709191744803740806305195114536185949582431"),
+    ],
+    [
+        MontFp!("16765583167493792488003846249322412563932361325466895848165782639635509252698"),
+        MontFp!("9258699025821383775657130802030622351199780460158138938453769223645462201380"),
+        MontFp!("7261743362549790776698831974802523106349906457536556119083744106556343806039"),
+        MontFp!("12174595715224479314914773177369125156875875973781826642881907250708051936707"),
+        MontFp!("5012109434360646519756574765661145101042498088812514145017131935992653466441"),
+        MontFp!("4700224901573028234520633038757562359543812027452948633531739331172070339917"),
+        MontFp!("5524276305760203869547781419982228286567487292869238897177777816144717638402"),
+        MontFp!("17095081617164642751160867282346616888601375485714887290060713493809720024377"),
+        MontFp!("13598351826191942256399407492736185075239065075291047608049023984461266141081"),
+        MontFp!("17308136644455825036504551600136601372687794695239587014243640872267542984788"),
+        MontFp!("21730115551932424945388178787861491256026727823117641122401508483473017057216"),
+        MontFp!("5949879858200957394974414439063213931632579243992797429658708423363937192395"),
+        MontFp!("12803563373135008849577150591968062878999687541475931973931310610530915756774"),
+        MontFp!("6197313147432278847012935526443261694774265540021863115632496255704682355001"),
+        MontFp!("7028974783929794816060380650452873559926928309476428464943277081506765913122"),
+        MontFp!("4456589108827474318002287720505015907920793224049488679883557016295128273089"),
+    ],
+    [
+        MontFp!("6100631380538490803600079371988603006023173019183128962118450543769518343550"),
+        MontFp!("20177888580254527519697427670797609876974132471313961205784103868094735572841"),
+        MontFp!("12073238358529645427878918495905605836088922482621503239707831646933770345610"),
+        MontFp!("21856100081180867115422179532992210187999850914786148325024530634866939637660"),
+        MontFp!("12466984235357303385071417632152615871825808908890287534009467031177378240877"),
+        MontFp!("21079839629488810269856967110699842674750899640941851601070908540874579869846"),
+        MontFp!("17874776547285892135748821889881943162098691701279199858985484844123833691703"),
+        MontFp!("1051310306073108765618056817409777616413695911463346454738615844650030082984"),
+        MontFp!("13201928900083471438265807470373218252839081683324776881761206414378175945385"),
+        MontFp!("10190119257010511211543154766347424644241552289850760573785222226686792626364"),
+        MontFp!("4688333485355359912766058497543573340825697092661609637162867722200016624599"),
+        MontFp!("17123765906835174464437643379916457993692257252235548439872663365295598699138"),
+        MontFp!("17134395901615480964750415429455088485278477136528521479550104193427850309670"),
+        MontFp!("17940167838269540610857156370913968688439335297994033024607831012271846654207"),
+        MontFp!("17211561414938780959438190369003172382648707317417933395371238331823656001285"),
+        MontFp!("16098871745352156970746489901084198750790375003919623885201193800062775011577"),
+    ],
+    [
+        MontFp!("8110023901773238127710250492301336333009382423651067999842110155158638763822"),
+        MontFp!("2422126936887420595943345532312996428472865746519279257114791700143799243285"),
+        MontFp!("10404075183720133448708607844137643895914849868988851944394714021532771347787"),
+        MontFp!("15058460651933569411128043696753704901683095219148372416995175617698935925786"),
+        MontFp!("12723555146577954839266714779606964953587444931552430067661560518513484316738"),
+        MontFp!("5711657581065990142612711893585463369566607394626940266394184697079173482347"),
+        MontFp!("536257458004844094090200378771107142207864145916201798362001449936167413480"),
+        MontFp!("16904097338561724274235483260490381087656469057354151613747001642006805270380"),
+        MontFp!("5885951988825802528717928331501311151617037953699202264796404860914064325522"),
+        MontFp!("1638374036867203230684496314040773233229976276864977417441853704948079389600"),
+        MontFp!("12231982678049991867660964493898657015614690001085229646831798098083877705620"),
+        MontFp!("15427058040900308626278462505422407030810836290013997730209614367042825008816"),
+        MontFp!("8287021582226423932765127558352821849475385690410389396789209560743363809623"),
+        MontFp!("1082920048469231434960727235146664410555571531264546962637788898495946680112"),
+        MontFp!("12010985569823806398477426440616395624594456642130882649075614403521532359981"),
+        MontFp!("1296330560506042268524168200578272313263311415565810607634491211599523487141"),
+    ],
+    [
+        MontFp!("13952994457760775342452243315330031022436205430079469228726234965837656704462"),
+        MontFp!("16985743548885988875181345488907967382109958231690477723834234297604193256890"),
+        MontFp!("18108965571078287240054765173491615799731924163046353815744437352944165909003"),
+        MontFp!("767530387704511846542067425779936904920481384673999726390798572580115466105"),
+        MontFp!("13028307645272598127894992332463015335938472044187733546491605798735426870634"),
+        MontFp!("7618140648066747223455845067208730203353985191563779819124185938438241329003"),
+        MontFp!("9331096408013670718143367691921833484100954507332698681105640867440874379095"),
+        MontFp!("18801244145798024592964955007270988394935375648640247134868164095028295260248"),
+        MontFp!("3795459656522732094044639001176736675357207553263371458375866920248726997086"),
+        MontFp!("837603424793724140470838171288512417218344963365692836088493443407197621528"),
+        MontFp!("5831962429370013799978251611601242499647911352287066127245255213245836916243"),
+        MontFp!("15608629818832866779793800587027419050315344573115273720682841552596629193016"),
+        MontFp!("1032806196152635164955947370516112750756471391006183599859124038948506281039"),
+        MontFp!("15696208732714216759620197747787990886188845785832889089576996959760026394301"),
+        MontFp!("7234583861372635173862436546128104054595325252312930586077823639855585372879"),
+        MontFp!("9351673222157837029929340587547626334173727344379333167413124993232252858910"),
+    ],
+    [
+        MontFp!("9944025695384518607461067522154717153892207999526158631473359210163311199905"),
+        MontFp!("2388456762928315498284690717786606118379553818175612967295585468860359388241"),
+        MontFp!("8762894023280585818322989976434413098781364467068028456427186735232945062041"),
+        MontFp!("3982990592887978322236356963193527056120817698005386105106663667178275102339"),
+        MontFp!("15305580827228104504176089928458181616132990558748682555478070517971443343847"),
+        MontFp!("19329984412710376451608868772829323267202139442902035837598118892290482040500"),
+        MontFp!("16858720166203416564086563387111926247316950607110418622245689767918477559853"),
+        MontFp!("18241191751089003023961171787624834962373436019307905123520202159673396473314"),
+        MontFp!("4235718345809909430818933555490446039661741163040234575769526351800272747420"),
+        MontFp!("19336790216312697816897624746946849815032853517670504993859895524588720552146"),
+        MontFp!("5833407345693372936993877824926303626341316298366978224289402960052763586750"),
+        MontFp!("1246029211601050773151495439169819001283925818386531745942667315981536442422"),
+        MontFp!("15220086581176169769190817430254624676596374261069714496878816359393753960898"),
+        MontFp!("2365861900029834138601812494207710261363070950725532631535675557556177296586"),
+        MontFp!("11098939996500166020441603457997996414027684936264124308565274744828953147961"),
+        MontFp!("10188381420146390141161084654671015083379584925005132911919536024814384766817"),
+    ],
+    [
+        MontFp!("4842017639583023386245773507388835267437650777401251470903076835565728725044"),
+        MontFp!("2473142702350888708640145449504461869356502285425231033191892981515210840933"),
+        MontFp!("3420689338060147901034254227618353913575873772273264928595432321920193621195"),
+        MontFp!("17283074258384994096612354354366039962078336488852681769982384814157770475001"),
+        MontFp!("21006196209784588036771700052971738964442512933084691689593009338899209077510"),
+        MontFp!("18493924441589584713280363204662484496255740376612784432044649586411869984552"),
+        MontFp!("18050342602178255577111253065667811034294368946566845729063425104069824407598"),
+        MontFp!("3678841436690736561960339537207788725774745841190361109044779271967419319179"),
+        MontFp!("7229032436003943468306271354423246592397907094971085338195794532946516086746"),
+        MontFp!("6074543407778673702938004547803408348112315194664133731027958879041743082105"),
+        MontFp!("20066840392762178519205386382693042859978931394221116082945207576871299356172"),
+        MontFp!("7392805501563984757154630589230967797392843859128971344647830779113836888313"),
+        MontFp!("12821992933916071670969878929371658252743368422895791718047075564423909142719"),
+        MontFp!("17668945380988406358182777882021562738916627352451654449781528301527115094220"),
+        MontFp!("11046977583853814151360987998476416244370762366051844251247672138778958746421"),
+        MontFp!("14002539550635338734484852844372585460419306414704948112807545053902011590061"),
+    ],
+    [
+        MontFp!("6606166606298514036368338857611676551502463523513184670555953533985389963443"),
+        MontFp!("5446061818493011809543734117506203755719984305445968514525154959975352640311"),
+        MontFp!("1111668700238284277732413088411620500087954609586256178652323895010927817926"),
+        MontFp!("14149444834268176201304046807022441432518948094788616136522350328948362984806"),
+        MontFp!("11145589672768136991149928307436511325152474561287303838823870178226307903252"),
+        MontFp!("10735913608001838681702607049464645919999926391398426712273901727922068271016"),
+        MontFp!("21604506935173200936527132172076877500255612688310066661071635553990139487164"),
+        MontFp!("15839861321575777225084554553361389979179161218821903561853968278717519007256"),
+        MontFp!("4119757287481158134674144154651639875410119871143992982693366998676854632059"),
+        MontFp!("21215853876753845584435912754954004476252409742434691973232278809063267703513"),
+        MontFp!("6474112081529376586123095858950370103982143188298415134572081362206457634840"),
+        MontFp!("9652012486829416433331453480518336590399776148133427672040049807230079809918"),
+        MontFp!("15197188648271522366095502604900923401004235531330211517976306351199244466155"),
+        MontFp!("20653133196783868133525869637730316323485671920172550159454294692576016995406"),
+        MontFp!("5372382735415117884334788700701478755716540017619541377211078296573383620529"),
+        MontFp!("13207488856826369213621681118482759531513035177166234417284384049787214456366"),
+    ],
+    [
+        MontFp!("9796874023172008376751307392327706745295558991768659529793122654595100420492"),
+        MontFp!("9923897870015737084413253850361127061692788482930375413255676958440886663490"),
+        MontFp!("20345695890485642585927456012849850866425595188379347054737827588286474285546"),
+        MontFp!("5970363931303429796131779726714445177344307738485323531135738898520018230730"),
+        MontFp!("11099832493605970375935052305980301651220466371839450598182845139430995541342"),
+        MontFp!("3984084259485258180085200952180050171170098877962423607492480207306084313759"),
+        MontFp!("14795772399510400766677766636479617776597353783628606580152075755214440944984"),
+        MontFp!("12411649494499018184602448007465543838785542392797557004957418343044512599298"),
+        MontFp!("16626394877225947777126043865023393002546807148362691512796749460115071596438"),
+        MontFp!("19056586788697785123807358806982924302021705802715675015657752728604200366151"),
+        MontFp!("4434453689868848221439283056960830923558217744100875597646474729523600908085"),
+        MontFp!("1558497076513757145941997689136111603376893591128645477322800957597462648163"),
+        MontFp!("19855312204158524284458047353907697917092904349885231039917644721609392348549"),
+        MontFp!("14296610840588714282305996857377052531476403374009704740496611471123591762688"),
+        MontFp!("4255702919775232787836264045412364184259731261431115977947396605020618214051"),
+        MontFp!("11933778709913818332041068411271656949772987367132405709674023277986614336789"),
+    ],
+    [
+        MontFp!("17039721891026771364303633162265090865698196106396237296534502784700270974342"),
+        MontFp!("17677496806049413057414389850646660368467945835594987026827101037177591654389"),
+        MontFp!("870853693457310346529819971157542197768087609061398183617507613118675541833"),
+        MontFp!("18050709179661944189572532609164386192084249162773126990656248077533050260547"),
+        MontFp!("12509892200475964347152748879404468874956023169504780638942472032501158691943"),
+        MontFp!("21290469710465436947459252705329759527038443945558987181251205171276803887919"),
+        MontFp!("11697948989002333965655987240828667368730889495575710652080571936823918605037"),
+        MontFp!("8736925166569662118997397920658489847012314542439353474077461070832299486573"),
+        MontFp!("15840706598368315931966990863663232282633707371020640698077565307266653295218"),
+        MontFp!("2336556619780315190172318504866216965057876522664932909981631800248874766723"),
+        MontFp!("3401386693708589750154291055903211427275255738949699968058464589080271508279"),
+        MontFp!("2985234144487193053560662812519604867522985647272968908108472491424983944847"),
+        MontFp!("4708870044417079288579972819522537183137753616055169595344370650267781985451"),
+        MontFp!("14922125937386550210569417108464732470846637538443952204254988396764974305556"),
+        MontFp!("21120247382250540544939703044577215089934389719849213503558017786742393072448"),
+        MontFp!("2316928219829771701890891437885406935408196453098584469128414556890718887397"),
+    ],
+    [
+        MontFp!("6617231706636202699892427347447707304283374531129890683057423079735568483257"),
+        MontFp!("8090371166463272188656489380541987815472988984318384965972491395697937697839"),
+        MontFp!("15553944362864127106455863696894507934518908899701997904436263710171474812555"),
+        MontFp!("13969363969212770297664533823398202808376213044430873505018872984372178723135"),
+        MontFp!("12323663899898371993768411530695190245261583246438004058508426675150745422627"),
+        MontFp!("16566875439359417854141203050455423662432117146587478868034846190942245403701"),
+        MontFp!("2085197407488705803446596939115235993629808393250087488250514287409223581616"),
+        MontFp!("3353163139852271259915937989527822937141797275129253304946488349312180510876"),
+        MontFp!("12046433326685614994434242499247496702084910418498830651483913063626259071609"),
+        MontFp!("14532850787151304296314608701306827699237853461438425922459530673831764439726"),
+        MontFp!("1248026383149472348014634990743396120794718445584945878307644249641199424325"),
+        MontFp!("8057577408830637105543973735712037717868264099646638037329061041095961932706"),
+        MontFp!("483318248530006664922961695058460281946793595997210254862751288308569479187"),
+        MontFp!("7597115662868588435202039449596982590007976221501668730777779068799655592123"),
+        MontFp!("20576588045443131888269350588198092656950342341724106762422448997215666028156"),
+        MontFp!("1519447772833782137513870714676314005111021965782273965574223992106447841589"),
+    ],
+    [
+        MontFp!("4564008647036378562957325007261730909909040333020122431007246472496053026801"),
+        MontFp!("20058604285923748821870861850977236730661592466138938301165874489677433369725"),
+        MontFp!("7444135345158592582936132362592519965410456021574373937334236087846829245911"),
+        MontFp!("2619748000882337075816263795535027848943303678661676628903586805250445194179"),
+        MontFp!("16416961968137667392446268131224700809614764673462182053193218154672694280838"),
+        MontFp!("11133666903754924307114887742321725054257211678992195923482419349863465176820"),
+        MontFp!("18185607668620497969429683073093259678523061297809671850245875139394070019601"),
+        MontFp!("21100277417778348195596070047101971906596351310455360764416675735903158404363"),
+        MontFp!("19242696527172349562445561603976204919773321047775511674132373901693664836452"),
+        MontFp!("17641599298698108341739444819055281386405281781490793437098263180756526877861"),
+        MontFp!("3867854105746657505538112984381791148258872175005817045777653312620913799165"),
+        MontFp!("13673929044343556285939637616797217757029992003977067790150536754605693560092"),
+        MontFp!("1413616068844936743999269099411394796998593026467324669628149625629672193659"),
+        MontFp!("17182247321635044788965517464433108044764735758956097220834338897775232927292"),
+        MontFp!("20369403412607032840251975012855741679474191692713337906759271231600952698270"),
+        MontFp!("3368021137971787382116663997287877185273428758197831072626886100000321343009"),
+    ],
+    [
+        MontFp!("4058882993633327642712241699549337354407965426094339469485649412411970892252"),
+        MontFp!("16745738472558294852806571713586420783108275141805815749526145532281107769806"),
+        MontFp!("15581910313143599067008775890965264886765882549739040775187928885624726340064"),
+        MontFp!("18274240790635707268508029122006654280996256674828118427269328392146795445159"),
+        MontFp!("20233611060321484677112349983942832171860822463387331585688221516502467246411"),
+        MontFp!("9578794697709092641086862842633192064390680557390699768090648655701242260988"),
+        MontFp!("2852999879330450264744202807076238853213578558186322493583347517686662265691"),
+        MontFp!("10741465223456896033565854764716115079295016404042872162238148987621341827594"),
+        MontFp!("1287682441013790555075762279132093640356858755666376578858076731440259199594"),
+        MontFp!("2754348824392215081276565702993043505186786457070961060283939332925679390910"),
+        MontFp!("11228376276796390660691597656113154929593350154734497204665175991737933493193"),
+        MontFp!("3311568804745845654494413785473574358224291288129470978720234105131364795989"),
+        MontFp!("13909323234259677397453192122165186938471237491769110758142097116703508140857"),
+        MontFp!("16483726454860443352311561107200491233365512138292033674515348800113826861405"),
+        MontFp!("9812016698744294015002456058504358834959473085649393839832264042031387688048"),
+        MontFp!("15177982625372891489410897122382147270421146795964397363826406495667593673282"),
+    ],
+    [
+        MontFp!("7519222254582187388553575935727848524972644886624855076224718541180666103816"),
+        MontFp!("8301393036521557606902463799168007719863879180990998631543603658427343679902"),
+        MontFp!("12294426767414989378173056202553334768408199886443392931845979330633289559578"),
+        MontFp!("6739869506728400044391551988623920236526787529007157619233939477743084008138"),
+        MontFp!("20069013437903411974438964103206724858007754851513756279456345172264534005155"),
+        MontFp!("2018834934981362435424318060070780595968114685660751661802811774334240763265"),
+        MontFp!("9403754976005906248280231838299490163038233707814936394634595857779528197369"),
+        MontFp!("10417979303761863711608825801177810340012849177958033395021985945544086842565"),
+        MontFp!("19604977682052360618680964990952175014659495782934855476845092758883928933035"),
+        MontFp!("2688418347826037364876739676610309530415711729219616027453603183128414252617"),
+        MontFp!("19429655781202385023906019671461539686080096105536288714975255299583443223945"),
+        MontFp!("21850552082723421744247018249567438591533518178485575560955887428084379635362"),
+        MontFp!("5617579950805240697000723938792084314286755653766678432489519653418680110671"),
+        MontFp!("9555670299297105865403034351288164766308791892391888668326102655956067266166"),
+        MontFp!("11495978801695516791606532228534536491719831441531647897999875880662918322774"),
+        MontFp!("13446240540987488159401062110915298027617715146895881354765875946092155869706"),
+    ],
+    [
+        MontFp!("7830137135198409572038952404379496414181899227574329472045729197166487746395"),
+        MontFp!("9556039398803760338966955211123892534270670688829455928022562515996727400498"),
+        MontFp!("16536848921900120010270774925810022257466132951671306792158048247185847693295"),
+        MontFp!("14984883995702583492812943326719347992462648669814085270913355351041523830317"),
+        MontFp!("4449876409800178139287642958372279285293173800225271693328528758341654973266"),
+        MontFp!("7254404965814435397385340781449208987509307430022649779312503908065673947649"),
+        MontFp!("13482540945483896073831058930261724641277618328981766008722675404404347005009"),
+        MontFp!("8516464109813705017846673381161730340458008814885800650922822516855928737104"),
+        MontFp!("1123466447164703934328741246642448511199513706388932930170273400845584259512"),
+        MontFp!("3549658554379334242628536166107543119171319090515117171924404150785175614651"),
+        MontFp!("20050236656917680705173407104625075641049186986084540346810909246977321174260"),
+        MontFp!("4328179367990709150244102496965604541058014472966567986200766436334838205729"),
+        MontFp!("6523960484836646945629638483047198947455910731228954877131634689551005111678"),
+        MontFp!("840118507410487418552201986292140077062095264830249777356756239948365951505"),
+        MontFp!("9443485141542020716795178817254625558092596484849541439794346219916656780924"),
+        MontFp!("17517352113160950398700094980635300524550125065948318365273967900505416951637"),
+    ],
+    [
+        MontFp!("3558475678500267728084169977682358432036940763329037251571517902892359350060"),
+        MontFp!("21782716253294940222641143651977099452381765047328512527623130468565364299843"),
+        MontFp!("5143600969269719315523014195013433247337519583796238248783742413790148311283"),
+        MontFp!("6937766932051718809641611511685721559694403467477888704545484121202675152929"),
+        MontFp!("10248511959409536383597760784458127936162061804351541810962726000050652155800"),
+        MontFp!("15584432188633520588792022977848834701354218910295071102828776215462904575092"),
+        MontFp!("16507813940325077551530806573175229924530686412372461197571308720671287449933"),
+        MontFp!("11975780799589036547299759597975704141323969091612997515060812893050562154894"),
+        MontFp!("2743143635303404960353780118079778350398309245035425997562746911685628540405"),
+        MontFp!("7772317651868959663361913464992926253491072635393117289337616002993612374782"),
+        MontFp!("2337519377771760840047685336751334655527732210909930380948789294151305059244"),
+        MontFp!("3616132500009609097754462360087761508553832847286785396472146776175233605533"),
+        MontFp!("16840763079152094997371697832566620838881567041180683145570810083272689914599"),
+        MontFp!("7660270111053834825889064071065415593147131275236956990950685283090480450184"),
+        MontFp!("20762611535627684584459315491847035257226740939101231132494468375819869523509"),
+        MontFp!("11042134071344142565200223013849882651956544247603198913312251335786248573941"),
+    ],
+    [
+        MontFp!("1940414570916441943786503544322754456611678071701943345167834901237861815275"),
+        MontFp!("6726537219661359488596406444614225094882184675245355532878420949418407567309"),
+        MontFp!("13510096963519324808490741235975735288120771162631019837713476375721562862601"),
+        MontFp!("8622373819062603720895891234592129397052930901106318032071934400492518897707"),
+        MontFp!("17024729268332782126190150627072810510918979523772816301096433100167961982933"),
+        MontFp!("5587788522776741050743071285424100117576582212221222382824387609679897172811"),
+        MontFp!("12388474196602817840033287553175313493977358457560707192943603355479290048023"),
+        MontFp!("17628897462697931613283808657115398230150168968257584770836553363771557372104"),
+        MontFp!("14065018459890202233327759187460200256630019200670511082974394473420421694399"),
+        MontFp!("6104554150616525884927725557698817344144889522156367737688769378902924558634"),
+        MontFp!("10992664178398974993431947901729345793474931192615930318284939994040095999242"),
+        MontFp!("10969415364246753478074755753965479915045305330246521543663858633748866143603"),
+        MontFp!("1272956806332000540144317124032834499397723122931270496707464205344164793800"),
+        MontFp!("20231387145101186513751955054188659753720260135170026275339142463242692015712"),
+        MontFp!("13619345053559735757194629501334040876412094886252831860426351060385674007066"),
+        MontFp!("15232357465863047438256672278087920688704670526832495687820763524016520668185"),
+    ],
+    [
+        MontFp!("398623536003273726220923501915643032345957170167431833298597360746684401150"),
+        MontFp!("7028250324917735970960317543369047790628992348836498053408852879988541935724"),
+        MontFp!("9906086796055827169135574765108677877733074061825927799032011921038506005373"),
+        MontFp!("12788056119136131846722253277450922603309904756259034915496788214657808474919"),
+        MontFp!("7651468821535297879836173382809170069017662802420683445433781610638854359553"),
+        MontFp!("783979512383179545035255083828374733462990002107251717097983319441159249905"),
+        MontFp!("8836457559777657917879541925866800908082149812458375523311279635635800064090"),
+        MontFp!("20144830911959238621340309337187081519944136818938895654338696747143855879203"),
+        MontFp!("6287181334311526693967500402732058645490470512478517679915786729496945390849"),
+        MontFp!("6578356433253304325301622313818326592018737710185433748824597250242226401957"),
+        MontFp!("7102838428799453179757741468552077315750505008228901893660362447987283707998"),
+        MontFp!("497820320926977947430162841325159818293818758853362781494155076005984229339"),
+        MontFp!("18160985608878840530944322708383120179552980243808985184923313895763861919637"),
+        MontFp!("13151612592624659285402557051397602661028781656351284997191435582230535568502"),
+        MontFp!("15780018535650817819214066689149640940245062275718550628416824107970066217991"),
+        MontFp!("20500218068755784102392504532520873969817687889455201735073738515957629646567"),
+    ],
+    [
+        MontFp!("20888440956803193084788282136946318522770638138510716565517310266646640263708"),
+        MontFp!("15971618258529607882347998799169510722902529521092131094419433339204085498306"),
+        MontFp!("5293253007777884257810026986010586149314566166761521493811000336947093325852"),
+        MontFp!("11581497334099661755434309930999056492294889088823870215073663090290932016658"),
+        MontFp!("741799820113052677363038461398206547514107871854645629573511776865766479899"),
+        MontFp!("10132388802463968927185535273034278687094388163001210029432141519239006715163"),
+        MontFp!("9843127774781963908073388692741045845788815542021009420639678295559452765837"),
+        MontFp!("3807159091255176851037359514650776155078897682364934585802230436085278410633"),
+        MontFp!("6904224411392552889784909729922790171388456358295280077234569059475136886573"),
+        MontFp!("13434461271014860123956911179522881298853645897065359788401689426904150525428"),
+        MontFp!("12249944872255100022137726834287337969948262832177273992983186014370317983705"),
+        MontFp!("1080721436007830510323005297307130991703868335217850548555329006531295547187"),
+        MontFp!("20651452101643029862567543402727347872461377965899451538964773059299839803923"),
+        MontFp!("8902465483627533643127758766065785114055357770410218037441501029224645377694"),
+        MontFp!("17049203022284218301986830675304595858941437294636126244507847280421482250090"),
+        MontFp!("20389632733862984292955678575857173748595770609738193230360968914580393387194"),
+    ],
+    [
+        MontFp!("2628553647311400613862969480533814764766703257834731382409245421165389763845"),
+        MontFp!("7113709286888238485244587172405174762331565517345483851543315956528264191390"),
+        MontFp!("21099219272139712501072896978936944264265953306877942445724732180674664269749"),
+        MontFp!("6196322429352603190460567397253889100476004214002852031233332452859780069595"),
+        MontFp!("8042719870001485176157425681827371545772290235839006373715151688719449512739"),
+        MontFp!("9109639854367609940132679558323551490721386060080938849664245212200893608513"),
+        MontFp!("20820097690315610819796383305043844702226083970185755192990081897048903995104"),
+        MontFp!("12474580713368057036057537615251118300654026141156267907684197425413737128906"),
+        MontFp!("4935701571378317615941939703137616736056472417588666676180454398865436202845"),
+        MontFp!("11284674041151323919882446027842742890041719161934493530658741778909469887490"),
+        MontFp!("10784274648620717180750449830334448050402020683452763192417352842268830091773"),
+        MontFp!("7221763500960709043106304906102942899855402476011030187016839441411503790134"),
+        MontFp!("21488573117797078819915291564603086854092528370673732237937703606783710747214"),
+        MontFp!("10368071846917543663295415889847910771342838450725996382692227125006143332582"),
+        MontFp!("7189971404170280285101021558651838178138415948558212834138150417852815291774"),
+        MontFp!("3622212247952623996680563730554546964978269732772060499110124228651174919309"),
+    ],
+    [
+        MontFp!("4030261128316895271453652515658449745835613736325708089700420629607904984695"),
+        MontFp!("17917906020983172293093657775725231153822157764639429745264576611859524349990"),
+        MontFp!("7911943088433991265213669750138013059877768768674246542956611065207230165349"),
+        MontFp!("1237568201834766592374215633649772138875843120827112365624718270590862573855"),
+        MontFp!("3421335729151995408099494011827853754192179969298834320585486465552812589072"),
+        MontFp!("3983646153441614758977318714985842223356917025653336289250912061318144657309"),
+        MontFp!("11832501153279122995870584183546009144756312133876131903016034834496067810803"),
+        MontFp!("9642188977879773189558548598547584337663289943484049782260608599281971392829"),
+        MontFp!("17650631071515565052542834438104124084088724345481542312112093611903334892790"),
+        MontFp!("11727835841766435406263157822186528758001431234638936084294421464770985535272"),
+        MontFp!("4170985922866379778894675975982515174956777531610306941710536931798155932713"),
+        MontFp!("1904373901352853963433078121273558128713374926256562753718137883724136086536"),
+        MontFp!("20310106358538316988108567646970248789216107374650929380709349367764652307148"),
+        MontFp!("10755444294914746017826019531774644079659674520334374055753829758342372728308"),
+        MontFp!("21544150011748410796331015950886967191957797301800349750025078804307553228922"),
+        MontFp!("4791178272915634809747385261951447492552726497734131770971381971399880792451"),
+    ],
+    [
+        MontFp!("21569911489317541997628510847711750299218484102264583013015022430206018097370"),
+        MontFp!("469129421958624587885163292532024178451414238938436764179429972880411747423"),
+        MontFp!("17178941568791193515971789991206572943108926053917999875254005411851993177716"),
+        MontFp!("10061493654971891913017572469769124056153763544567322059270144080123216592759"),
+        MontFp!("20809728888234709563099636671010468945654173909532666727272823672496239666493"),
+        MontFp!("13977154471274089663104412732631175732414148541501676926172952880637640768097"),
+        MontFp!("17159586220373273602393948660742324700378108883620196368910126875831365156676"),
+        MontFp!("4073500643742253412297370658761396932893392648826435730598979045413705716932"),
+        MontFp!("11493572320701213886088466735117685416116051720844612287863434228749232164029"),
+        MontFp!("20892362924238047571943713393658663337479326222564740924788211586758905169271"),
+        MontFp!("11301788649359616808987952401363629750830090004562424782094282760076674679757"),
+        MontFp!("5346827191385082048034578496490044229132766705726383391388695220005635473547"),
+        MontFp!("2201675309086116923924279562154152714268152257293936509978158351509848697027"),
+        MontFp!("17917826577478806664624583743441880347892085028222113450954395295616044284297"),
+        MontFp!("16998236939959525187887324821682683276592762127156629849525502305788028877451"),
+        MontFp!("4214391906402965555572230230924694640705243512659675242003943183722900957472"),
+    ],
+    [
+        MontFp!("2247036556360539125313249919915213027481437766291950011195105972974928736865"),
+        MontFp!("9520057428546863347057860284023378728609842187323632161028631359500291204227"),
+        MontFp!("1828736612590741052604266070553266072363175828159047049110641125134408628099"),
+        MontFp!("18248580838532636162005188449054546706775958322552749296747411333541139803294"),
+        MontFp!("4834174634635006984667037697276171446677972706028785635301769650621697899268"),
+        MontFp!("489734793278913821067993383657373029590536750171301785048262915525334613888"),
+        MontFp!("20786356799510136039282916235428434827695977220581899018592633397684279594261"),
+        MontFp!("13145806261364716646031477788186247592609087183869974953048604633861380872823"),
+        MontFp!("3841420600164259772367211721293848988818647274121301867288590071853319905062"),
+        MontFp!("6882597113948243507685423253840830929482565588666222909972451758645869849656"),
+        MontFp!("18980943240416553858613600850285792319483905785062366023956546569017873987439"),
+        MontFp!("29028675420319059649770139934414847448931804950673695214332234580922091102"),
+        MontFp!("21775882073646833834016763184296217506960385126491507726769771477412306185790"),
+        MontFp!("21300410381086030882514625793664995316371660662503398686828472860934288345599"),
+        MontFp!("2472267536757789817746114566761390885333410401690592727164834695142144448445"),
+        MontFp!("9723880451630142367622302246353057781908243406397604481250639516011253801606"),
+    ],
+    [
+        MontFp!("15313110769360181254868962440810453125247644871877064008660684649744823174300"),
+        MontFp!("20564192458187565149788272882595180521577855436987831222746293278781628416968"),
+        MontFp!("14590472970343049442795311527863765295112518097070620865372037933484660359574"),
+        MontFp!("17062841617700893799013325463153088719125540677385032193714935878731444635251"),
+        MontFp!("20200256413643364477818739581045874029732018697196252518589192345293202187361"),
+        MontFp!("4016942840923529049014357120480338974430876995693135820048221911362323816443"),
+        MontFp!("6537662900592412058939229458410964809681215707806173558553209350444735100542"),
+        MontFp!("18297886978176179234700005359672839811634650525194200352748308964228483504767"),
+        MontFp!("19662609243585750899960608765774063353658909677643282385185291970742157305960"),
+        MontFp!("10221731387204953046960523786878755517637874808703460048675288088446619398011"),
+        MontFp!("6359039963669314770815970957437041104980907769543152198211817790059980604175"),
+        MontFp!("12215821248736109157702052060566873526833550744650237478543432061962195037956"),
+        MontFp!("5935095671961849761918916493742868190941272209470285856382557274883130995501"),
+        MontFp!("4892952774759542491697203073862387895082272084344610213076129801679488176656"),
+        MontFp!("21229502683877666320987735203037721130704543292725579308736529207275811435612"),
+        MontFp!("17138707315635877064706472595294259087755028865414511496916808945331553578413"),
+    ],
+    [
+        MontFp!("3064016632497858706665392042657089766401657752838410773325693629079632182414"),
+        MontFp!("10697546089228176531454125219656376659283555269092429548580998097316823780605"),
+        MontFp!("6383154332658396326745467904696030415502846256075951666613157084114882351628"),
+        MontFp!("15399057406934719483674477103950525850935787593222687850952111073065008222381"),
+        MontFp!("14222385230562109850723354194041721683341002968759594278414075630295191868997"),
+        MontFp!("4196055868873306164684774878036056827685013979028843775890547079994909550345"),
+        MontFp!("16495894724764636941708778916623546529990869478284583127943604032121307478877"),
+        MontFp!("16662872698072302151631604897467020278898785941962087730337008570271651661242"),
+        MontFp!("11474333637424192324648146148268767361869344542358721131204304533867910403346"),
+        MontFp!("12663733223747936066515430496850730759882234287115648507227477484368789774494"),
+        MontFp!("3278587449214209390499027872507714198695688159471276082957385919697290643338"),
+        MontFp!("19852281910336140665278848076045642033038867843956224684297984081107396007913"),
+        MontFp!("18119827693896204780441288678080897397841525584683046254519435612134656396725"),
+        MontFp!("3004914531848687259880590983112983929408762744461212501319599913078181196369"),
+        MontFp!("2744805266681634195002030539778050424843533661368265264531107280758966836574"),
+        MontFp!("12003425394929552979405575446174684244823087440054609540505332749485291543559"),
+    ],
+    [
+        MontFp!("20899617298205776433505248799032591564097087832964850292073852661486959326400"),
+        MontFp!("18977355049070793830198782732221581680757588074059967640900502934882545605494"),
+        MontFp!("7774492678097446887356009465076901979542998304721485056654902284935411555748"),
+        MontFp!("6257979871454846095389581530427518297635929206624687597975380788548536091169"),
+        MontFp!("4200362970271656646831473686822497724241729322168501270846014047124987169639"),
+        MontFp!("9626555465339955137152373316361342798468143010897667050837558740100066479490"),
+        MontFp!("20599104767749131194316802432182162807569082153791773541255180345102031538229"),
+        MontFp!("263120164166236772856966508954885356163375871249262527569221312470198863328"),
+        MontFp!("14408635566922092915650756800314844499933046455992065014832556500390343919586"),
+        MontFp!("5183079049039149593555827045385122348972389627856098826226304101776790536649"),
+        MontFp!("9079663711634286747144802677527389482368428094888913737002695282424348199029"),
+        MontFp!("1730279784246934965982679327290069428428752739692892522465325889599043152257"),
+        MontFp!("19417355737429211343570897838176489391485942830255040228180844510907794134979"),
+        MontFp!("4688867852231614618639054238240140493597889866886829462162366294959927124611"),
+        MontFp!("9750344066229503722317800976404611879233170071789439067052923419204148303262"),
+        MontFp!("3998708829439769920633774213209304480109842139515896658207686741134489049438"),
+    ],
+    [
+        MontFp!("21119879197425450902002580651023270363969638681856902851675391960298938692105"),
+        MontFp!("21081985423839377197813891376664505429739140831959287968556239547809632016084"),
+        MontFp!("2194830658993798006279037291585161216930179214066933009151541946587699367176"),
+        MontFp!("10072059773309529038163761158232080490764157196414798621723969165341603556957"),
+        MontFp!("12893922413945288356816723514764026149994443040839144958812668568890693761688"),
+        MontFp!("6289043885924508250801248626630354568142964747989700154772682955247795752635"),
+        MontFp!("7117847768519054206399668810694592559179837439189798231517777441723405148708"),
+        MontFp!("12250493458894268361669347775761796626700321541112455611623342396141245275173"),
+        MontFp!("7536889841233679602786965527644616779647633163370923759512908958092882343888"),
+        MontFp!("13743804807205248364239225685151790362682679734025326476864976250180416573459"),
+        MontFp!("15326756679387284645144696476114707028416084124671428061976908306742987298792"),
+        MontFp!("14138908940825881278249529554167499601250081601885817719517135886992016259170"),
+        MontFp!("18191604052705446018115707722428677617894390137039905732841218263472908863798"),
+        MontFp!("5747208788515494994202809520653945260017053266507158973974399964661798663161"),
+        MontFp!("16272405226087543462175411026937377018988783482980328154498443115982159189635"),
+        MontFp!("14959941590764331840350995267307957753388831891977493365286637018137237442649"),
+    ],
+    [
+        MontFp!("17614662493206460042788564726025968337855721129414467376860164849857118120897"),
+        MontFp!("20540842142176422103053529661013282478418611089363263097515168740633056607687"),
+        MontFp!("3290501693077219671357645003459548779173001871326499393140572778475444534834"),
+        MontFp!("6447714071113870576284977914086213514251182728059734289291667058468678194763"),
+        MontFp!("10016099876948482641338204267737928588893338645093292975971465694121948536197"),
+        MontFp!("14390773887169232315205787249570222449199544947506512428178148864937891297988"),
+        MontFp!("14620241431433245619096116183667070956408821753296293274413828291589840475809"),
+        MontFp!("8494548643236049061832844399476487754775580533076308093638832054930352314493"),
+        MontFp!("13769276515160804060029880354652109198182914994347382081537158871751993099394"),
+        MontFp!("12260040442111865637116150785319893997124694704607680451569915655443058209529"),
+        MontFp!("18536763786390498498949547812325024712812297365266616445712766917775521176399"),
+        MontFp!("17646030323097974380890038898728697538005884509434748880882937861292172824089"),
+        MontFp!("20995211004588227271383296369921345208592928705280945008379701434887289620654"),
+        MontFp!("14012493030585128283500948776342859322003214361666347021120271800114480256613"),
+        MontFp!("13455652757462524273399933538052579123871086976307529744949349716824204294368"),
+        MontFp!("13176577891048443118691856747592745460758840755040242121263455031909886205386"),
+    ],
+    [
+        MontFp!("10958007047406016650282891454669527247751542376915102729040783130554758505696"),
+        MontFp!("2403556896710211170900277969533691258363807287618829314859189083914528732150"),
+        MontFp!("21557822641920534343252006226462178185422073111762808253392360322675121740589"),
+        MontFp!("21301363144372514699996437466711759383396133417588035206347144265914876911832"),
+        MontFp!("21504297452761836117267872934293380498824936327520529664563583098264179167892"),
+        MontFp!("19008511347839951812168011659083529710470044770506456216838511009924048532294"),
+        MontFp!("20729557973386382250734811255901369475271445538588448251951105355722497057092"),
+        MontFp!("1596029835560146984478838415844959546370291207350745477127036146832708172428"),
+        MontFp!("18779043981021734310054761701946195503647990042042517453246401248591799960006"),
+        MontFp!("18389072889502391007861938373344479996746585336278814994866091596798460590651"),
+        MontFp!("11450018471721248431523978933304902015421867719856001119788032399031643640503"),
+        MontFp!("4695792303828705800181239521907223047109864872167868532243675764473118182293"),
+        MontFp!("7504424077032992561542388084424474665707766373549642837850671808747703476058"),
+        MontFp!("16201871852003230376980125294909568327837373609180822015372468013683148626476"),
+        MontFp!("9820880186139182810240320436271674897142093850866141441610372427566550592116"),
+        MontFp!("4474392152763162487463052318741100507628836016813487468299920576199966718180"),
+    ],
+    [
+        MontFp!("6535313881166679884076402867701793095581869448631792643683900609540448349145"),
+        MontFp!("10375499075088306448884735241746141379659872937880333028048697275256343984198"),
+        MontFp!("3166175670215426596895103003450519990743126408554698027893417949729089445829"),
+        MontFp!("11825805020940873618060190367149910908253947573120883350031415196553790678909"),
+        MontFp!("15868728010683147011542066925511350470588043768845023267900379312401595633901"),
+        MontFp!("439641324715765398013368707995400811722128832670521930633684822154134655293"),
+        MontFp!("21389215620992100898484688007707226862245610434491939970737280409708735004882"),
+        MontFp!("13567457078921654178980424734811793190031829390199528822285370655562819201102"),
+        MontFp!("2491418221701715606532424215068729097730197896717397364135214058133617329024"),
+        MontFp!("2700199371443700220147927924846092239465972046042951758397024540313204379477"),
+        MontFp!("11101155530024798073181197117436499601132355735889219480985438157046671552075"),
+        MontFp!("7696323106369972761130328211405739772776004396041557644397285018363028133006"),
+        MontFp!("11874928928175576980576459276992229552831994515252269310033260164062026645644"),
+        MontFp!("240537705962136501448280118114493602675578232672944677099273394877211057455"),
+        MontFp!("2336277667844723086566433195865965121270861932965343039992167442067222380338"),
+        MontFp!("7139477698789264606031986148297940201890262913499702250627665080791275501502"),
+    ],
+    [
+        MontFp!("5394022087396393768813205254315732819042275688988698297882925332496064046016"),
+        MontFp!("19793961339963600744392705441271116638543292070344579052218623579091649097618"),
+        MontFp!("15692138411890707768379631022903362931511371639119812810678572491600193150934"),
+        MontFp!("17870790093336984580535630746458834581867280473989239593391879854453602020534"),
+        MontFp!("8527221939827993709561548898140031570873635681631994323216682386420609693119"),
+        MontFp!("20583146985132338324328431878826670751588212915037908674977314222878838415115"),
+        MontFp!("18286401926708183610455794878977380145855167386311409319243563628128439897199"),
+        MontFp!("14883280595953108786791995875596640384464375470758397510182264364463199426941"),
+        MontFp!("15622372954112461900770387091989505548249197707826335911691325586886540655839"),
+        MontFp!("15015231518950399896087228957162542423583966819051318376374846686122344072726"),
+        MontFp!("4110275540712809298667287978052760900153083659165519222137609468305797552622"),
+        MontFp!("12194886690835058962976784720918154631659193272765999855977381550575603733993"),
+        MontFp!("15244557029932516875079143618219344257744782264536288508363832394149847400408"),
+        MontFp!("10234512862511661102613686672198745648772485307900923622631623670310818907316"),
+        MontFp!("11002446381083289255418443918954072583200871532274996806630154250111947858951"),
+        MontFp!("8253167315431388215871891033564848144784018068378784924459677347009078928353"),
+    ],
+    [
+        MontFp!("12052362697319720881876615803891693831545089601220691511658506577522506606138"),
+        MontFp!("4222271910532283822428121153000170791277438320812055898378713943538215316712"),
+        MontFp!("5653799018158152396708984453701593562269544320974176828284977837083044089432"),
+        MontFp!("7882074107401771620456811688079120160013843021919844939348749959599330360070"),
+        MontFp!("16985024708206545567858733579435707605892308141313442111649869690900835499686"),
+        MontFp!("15657638292217617224447239244820122003415140104485558954475025681733455080516"),
+        MontFp!("5031435167133277726502623124488830505559857356049078624241295172338778604459"),
+        MontFp!("18251569826624635398451261785711619709466995337593606694180128347754947431611"),
+        MontFp!("13347210735949290753346060121453479592654490759925951960015474767685667320469"),
+        MontFp!("15097256163521186466089214691748103981791931847659339134237546471578704117651"),
+        MontFp!("18618539001972002803104792202843684224999903989910895364254642809781793542910"),
+        MontFp!("3535158924054577294148391849345768098132565619653646126038220164469413178376"),
+        MontFp!("5247111262643738028940705230194040190316748748116050300897903801701767549935"),
+        MontFp!("21069968641157288335927634486731267978865294883466175079455493856177243522705"),
+        MontFp!("15182016439888624540835624880056168611406902880254574867923247894452951108191"),
+        MontFp!("2598270152918575231168351721309733272482825217008498067779287914396446525010"),
+    ],
+    [
+        MontFp!("5113774671804063464274403166444533180735260894306271253672198506848528131887"),
+        MontFp!("10007988864825946871589264454040608387449058007032979960058775715911078006740"),
+        MontFp!("6905160188004006948460048193173293195325586959399957154757620552653693466890"),
+        MontFp!("11995759573081381716106152146833656576924747390008060686555489446745383610128"),
+        MontFp!("3012863557306381401755965576258357584550751416148424411503270543269480674925"),
+        MontFp!("2913988303571063477501511529375194010078206270482236339308347176586704669764"),
+        MontFp!("5006524049092266462685266534385319119401827059140718482624293936686566174021"),
+        MontFp!("17563183357860154367150870160675168713982368687948283812578969167511639823963"),
+        MontFp!("17837755859790999437375156561325979429838685660428780384415700354505074279205"),
+        MontFp!("16080522246080742261793784524730583623229678427635608200910206239897175583035"),
+        MontFp!("2848708361765680705684254800195178864363439998055587286174501633177530616249"),
+        MontFp!("1557519954300916467422364113319270366979873515036760226798787393508368098341"),
+        MontFp!("11733665678441137100700293713589760125678167060800184611962879142675741739407"),
+        MontFp!("16521409005131685666434785453319000098069672876632224692360259274386316622759"),
+        MontFp!("18967967647390654785643258265157841586213408055603172849772769208320302959605"),
+        MontFp!("17571504476351312669488808692966809815779784230239825235217863108338926113527"),
+    ],
+    [
+        MontFp!("1033277958139146991725614151292577955914352533347610456481561709576150765927"),
+        MontFp!("14781931979382308373625511201561097203707487976646566051328122813686985362864"),
+        MontFp!("4650875909473177450881505980066567106490273485086660264292847695230525365004"),
+        MontFp!("2914409799137239617504079923731151032417003482030433682771204258557926592318"),
+        MontFp!("1569377349856381614777306232175508373904011237210172681516586308749045834576"),
+        MontFp!("6819560317780627274403497421141083285449699366697573487561555149873327611188"),
+        MontFp!("16783037329427380826125478934695948949747971672927005004484739677562779828896"),
+        MontFp!("7015004192682971326660995855922915203604605225985220083215349611396572003775"),
+        MontFp!("4630816304408133216263172876471813378546980993666459834637527842126471866675"),
+        MontFp!("1048974869579541183196305195192354335945589066090693848404581740062134729282"),
+        MontFp!("18688715639285068183434963296396143793423259729912639999344372787920282768249"),
+        MontFp!("10934347243740746938446533383348729597078488045109074394137337392516949956248"),
+        MontFp!("6293725896350451546716316494602770048720703017891567394397586512386125658192"),
+        MontFp!("9703640866977553131835948007053717693480506246962404280815291341798629276781"),
+        MontFp!("7906707563931146558411066527524578790489630019846849753651156405406896838827"),
+        MontFp!("9702629288736663637774512920196886201521548938988112829178357919548773380071"),
+    ],
+    [
+        MontFp!("11496918342981223624025423866080407628491859149911166113194854209867880853781"),
+        MontFp!("5310797284993667039758118840812749372556974855379167283523727209282978379416"),
+        MontFp!("4023078794142040206433592504682005395486039156676105856122688431286266944198"),
+        MontFp!("16871217006715623479558921653467262427043486550696934636603562460293483965032"),
+        MontFp!("11838323559167998464544738415089027103939009770214130070107400686110312526340"),
+        MontFp!("4980088349530490327691411848559646523716982917225546360214229685978505879808"),
+        MontFp!("9559603819596004088164413773616676054644268005451072511817132718244048934414"),
+        MontFp!("16706530320004737946238925506482163802145108867695620161891008305110107710231"),
+        MontFp!("17097818114876900788313405299390647115420321455669519337216918484593668351660"),
+        MontFp!("5849414403812965413267782406018277549067364089813096482998057955032613458546"),
+        MontFp!("15554882868930267918822925407791760045541474181510040631764755534058258253399"),
+        MontFp!("21580870201101672999738147681605702976639626554191473007622680366607482413326"),
+        MontFp!("6529597247071863795966038055163209598757897174702699285551712764342047434265"),
+        MontFp!("1034354038040835970585668649926410980243448725331655567931872752141342573332"),
+        MontFp!("6310767810288764607994771877877727315289627303796831154815613377794983946591"),
+        MontFp!("996939452759173758835198197713244238928300388619776170462989538225909183250"),
+    ],
+    [
+        MontFp!("17830180799634516986244195887614846195714707335284590299432596686170229130983"),
+        MontFp!("19874559606908304758854510017033500134646556009065042866233430268619254302205"),
+        MontFp!("14009373839533951923879302153319163529100920268844053529575065501607218593395"),
+        MontFp!("14946211767155341356149843492542554767905660369447051530372801216288515919491"),
+        MontFp!("20201956319173972615406549564337979402376802300388863728690331165140287483581"),
+        MontFp!("10535506466358617363674252301599248223977286129433846317382254624662176433824"),
+        MontFp!("11029001794792043521930346330104687908171223556219538577009137251048931299365"),
+        MontFp!("8200934006947443074514571335915268415570710146769685554653690866413931711817"),
+        MontFp!("3756001813899409241012385101881052052933218317388650253688371184478299762463"),
+        MontFp!("18636887900618256435910622299263466888026971253061227337296887637280237818897"),
+        MontFp!("12062360063539460494176603336727957703003697981349882838776879968409682697224"),
+        MontFp!("11001722900962847730169711618381123657713397243841075174948041047383605406084"),
+        MontFp!("1100165499084337642558411348439001505815886538759724357694738775770474410180"),
+        MontFp!("17434145857282894864497601363005649089714522250566258141982190265575138829458"),
+        MontFp!("7528570688175530782083445771420581373161584322581902670613058236780353068011"),
+        MontFp!("19971690728399713343613282829324748110469462189895719072018362834001408037396"),
+    ],
+    [
+        MontFp!("2720175852023799717892872520519566779572526301065275372226989199715421627391"),
+        MontFp!("14943825869290446747104357486851686014418770156528443888330443464181536442315"),
+        MontFp!("463946552619089332690398123288326517388219753658554785664250177816814153004"),
+        MontFp!("17828919314250532241077886046465359044175524922026640483099339343132288207680"),
+        MontFp!("17254663393643639301497528160788050107293708210389962463123967310041334977099"),
+        MontFp!("10548323428945380231342461328951126333180031707800802905121494905097297919778"),
+        MontFp!("15741600332199859123106892774234668638862408966232099592590755994226268019056"),
+        MontFp!("1447099980864195663747093395834509033779875532198378629216846242387465017398"),
+        MontFp!("5634271615965881595424665300014723762842669209816714518648909648355913896267"),
+        MontFp!("8004046094319510531556517659927356739393971820240935140962728964910152787977"),
+        MontFp!("20150098853172404856553856601505464455083744410516166414862502865711843962616"),
+        MontFp!("6051577504932496971373107947431966202921763803799244564057047344716078611429"),
+        MontFp!("11153752935200730615392163240542716924397740666674490478903681768299619893440"),
+        MontFp!("574571428841330767683871500343913695389489991910772848795148223306401258112"),
+        MontFp!("6644002840867033629815195671865575216742798076650013015371202918478434067981"),
+        MontFp!("10643887442409207306340562760946079189746204276290341516975302787467861819914"),
+    ],
+    [
+        MontFp!("3310054989122793204488875713765468888781168322357480727671652094037000591815"),
+        MontFp!("14260109332138908810733885305730437910719800845345795697882239548319688811219"),
+        MontFp!("5210296637174697538908079595920299077484266890923778247313974722933789990659"),
+        MontFp!("12099525392201549319291916254076192583959301537918452056524775934199172994864"),
+        MontFp!("7553233508370030852973625898622143795815488816458055012305692281375143855601"),
+        MontFp!("338064484385145371035100049761904487152067475286477053744625247222287881704"),
+        MontFp!("21656711030424471443690125986754350631269722127039355499852011488517394872248"),
+        MontFp!("21372195548620232488593781254533859082570347959319967220919951808088029020212"),
+        MontFp!("6382011083971938503792804272844009960813722135917547320595901431805157825428"),
+        MontFp!("10487620314920598491955634712479265679552728563160570833716083687459951803271"),
+        MontFp!("12540875281457292616015016392478978686561298989814479837942710114246256185916"),
+        MontFp!("18617705893970874028060182711496372099359212095272496239846702650061090540602"),
+        MontFp!("18861927157583050522649066858336628481061253205783797036256319572504841854227"),
+        MontFp!("697040343546965749510304464460790931385381610135802696124987191773265224046"),
+        MontFp!("19957864832901242629222592391639674066674531543245225621817890196632976954948"),
+        MontFp!("18958366438463449522535881612753516577920408884030961917206372848340856779454"),
+    ],
+    [
+        MontFp!("19038355323356709854907634385093529445778219247035572752290295596750121939785"),
+        MontFp!("1288546653652611126768551729241141142059005744057588910355805731786584613343"),
+        MontFp!("4046804685697550720337256987447438366138088525717758741922811410001913306096"),
+        MontFp!("1660762585827970516291367008365082875857712512262252287267067642443918638514"),
+        MontFp!("12490758580574114271593656499165833022285695011717771020275535753216014465968"),
+        MontFp!("16141486882419673588223773309048616384177430148674560859754337286781225436609"),
+        MontFp!("17480842754700647574286106752058337138867662945102069039800534716529718409113"),
+        MontFp!("15604353321749597155917847886619012355001132908396771273202434684314342259150"),
+        MontFp!("5617279871415371338336939332259796482400498338204339205308393954394249833452"),
+        MontFp!("15174696383871305992552314836076160250704499338484600692522408887766438439385"),
+        MontFp!("7427777880578061171924488290292477545896473049748950597878341286830693333925"),
+        MontFp!("14092813309756880555589883560674882809640120093304366339387019683168400944554"),
+        MontFp!("14371461943490927493590660203698012938705485668960202862586478499151043526062"),
+        MontFp!("15747628341829994869775701388730275581025993393892718204096081193836534027616"),
+        MontFp!("13069485962326240936812384345060376352182714448869348207061583108709995536141"),
+        MontFp!("12897613411832214271030930629934621444235734267985429117525716946550585934838"),
+    ],
+    [
+        MontFp!("21009316561423875807053156778128985534726230082102006144117574738389765473006"),
+        MontFp!("1961079851137768143186691785724923358396590015729408078834852546188718166268"),
+        MontFp!("7449716401041984694438903080808992521397429308993548549788139074686598693961"),
+        MontFp!("541840132011603695915673487977337384196812668637554519620724199330714724824"),
+        MontFp!("8732237210312418481429872365007927003824155222103189850471564031658954501153"),
+        MontFp!("6523310024353124781137808005011914742907908537623530782692226224442022388987"),
+        MontFp!("21509127266455930277492767884139578463956043561699345583365079657386805307554"),
+        MontFp!("13989299184155011575724091657019750720502004610297338129834280607581254559697"),
+        MontFp!("13646882669283186383881254452527864262332184212165662411849024353857046450116"),
+        MontFp!("14437939637607347068422046603975325432039018873549816106341419990853530813605"),
+        MontFp!("12400256882351762977557053352624357859435580188305833994340851255855777961583"),
+        MontFp!("5318260629482404358266028277859623051836786599425915900288734688050650647740"),
+        MontFp!("1629695214770269012001751163254712973932233705878496747522731454404864982213"),
+        MontFp!("13712071936286520679632088394106925703538964875601961825262229735589116706613"),
+        MontFp!("16370398845138146853603171685418735096308178156853614168785529502102183447562"),
+        MontFp!("531316798023999736377588166858362632977098508125317800095882267717108741597"),
+    ],
+    [
+        MontFp!("9318921203266238488822738977554047526587525733728386955697050423076330149397"),
+        MontFp!("270377449844572577022377874031006263208440602734811115345017690504339807539"),
+        MontFp!("9815014161182964374794581271513785279039355629218722105019385525592211310401"),
+        MontFp!("9645578731676628215511306357368922184828636697072609821617709363109131724528"),
+        MontFp!("8278125422456187544426337239756968442258418675393889722193805619331692372756"),
+        MontFp!("3793686014229600852890515574697744650225321239380634655110095131274614241795"),
+        MontFp!("11274706713173058310395599257524413726315092599580477329306442323879171602089"),
+        MontFp!("15700516289342200878818538157175110895267852686641088808116754074662814875793"),
+        MontFp!("14231470645515485158149500150154330504733538042275634884479799789708016096981"),
+        MontFp!("17009943350256143028237187628790018788978713833977098031809639804676372324573"),
+        MontFp!("11807744076512700514106955514318371416449053759940168158181473579522112561617"),
+        MontFp!("8941191247819458429052997300931331828693768320666304381154122720332624423159"),
+        MontFp!("20810756908118815132070882638097329621005628857845667418135716334205275433963"),
+        MontFp!("17633865705062992379853990460318358612898966723353031319355975229233791359833"),
+        MontFp!("20574714650596437874739546946725432737055645662464099228865520688481435554952"),
+        MontFp!("20753133214966494228304506683244546691191781338293295716113306511488052350935"),
+    ],
+    [
+        MontFp!("20891917718961601440155839168193313007436009622881050810291426124657399756167"),
+        MontFp!("15254490709565398763344025189458873521593894682543200304507470388535826802423"),
+        MontFp!("1579686464441495454543935122179009252394402667945704420064352314351485765393"),
+        MontFp!("2776164910982632895181744444931014666522951510059312025217047160140701867676"),
+        MontFp!("18493452977704296940835401396385377366306134685072368153755232646737492155884"),
+        MontFp!("7466441893052199319529201615576158956429673118460829755667264030485444838721"),
+        MontFp!("4862779186474359727417788114204862127925883646628388801420770199523844825968"),
+        MontFp!("4332442906725686065471004321063349183397729160637280571390764069298441846158"),
+        MontFp!("6235595676847271156438843637266606946005787585155569320931268977334224113102"),
+        MontFp!("3283545972614629083851761609721595874215964445590540647737064482671122002155"),
+        MontFp!("20190238096841101806664255153033971438119127586536510897732203034569691981419"),
+        MontFp!("16218082928311959370464862694396129351602418127064911331498297863184968070057"),
+        MontFp!("18169106670538118432467974548733501686430842104311041161699612921427327878488"),
+        MontFp!("13082519725061669934177494520541983774227971959053999627907293338903728791646"),
+        MontFp!("17917738025521205167709067376626145668664963656058875474248539992479417821761"),
+        MontFp!("10201169720940960483107286532267011054369536208475708600788396458879352247717"),
+    ],
+    [
+        MontFp!("10086018032281190838410153727159750412677882106214361975307419597102233105666"),
+        MontFp!("12682273581371325166244148831560984283003922721179649899812794401686084266959"),
+        MontFp!("21151848537444166847396763358911125604594195283585946282585966889014856464069"),
+        MontFp!("5870563280212708258714113395803290954804582727013499575940699343839714979459"),
+        MontFp!("15818574025355019546843656101475484151535156797083091329572001913076927497145"),
+        MontFp!("9172129818981348435701876676880219576808443896350696165457788920197063328446"),
+        MontFp!("10390962755465039363458687913467754632739357930439039623432597903165701965254"),
+        MontFp!("6161501669511652251380864592245214064026880951608726788784852344316145896868"),
+        MontFp!("5405539522259494292389572744962975583699928794990075330665720986459457361914"),
+        MontFp!("15953141686281883540154911071743713625324924225902448030996737734044456286469"),
+        MontFp!("21879157639189744211597108651045875700807085936267782759316146460177277605878"),
+        MontFp!("11434924404918060113007413962226874803102772070441693086168193464929290844877"),
+        MontFp!("20757082623598026102923542323531106955538082419998942072958829717667465557131"),
+        MontFp!("8919981096169474486495376362947226335482024821650926316923260800584787410595"),
+        MontFp!("4288509578096166644556335935101899777779885105511348520519380469688243082096"),
+        MontFp!("16046529059566280251436022453196743987059059184968007751447655603059461800273"),
+    ],
+    [
+        MontFp!("11219038614950179129482943179862383597500934389713177845014944329948593838918"),
+        MontFp!("10043039951240572103842000677370211126438633625308559058041812947576435228523"),
+        MontFp!("13424594313385554045395046911081130962489696002268038176721598695954847052511"),
+        MontFp!("1211793291463193275930858215285948372240170625699602215748915241438580144093"),
+        MontFp!("13956919764638999174327326329058963982898576605238294264300483944344569472991"),
+        MontFp!("21763763972390992315030985535287866258485311664824948828240050483482084352503"),
+        MontFp!("10420983865433228040050251893025618324702726171912049836799135351770260802977"),
+        MontFp!("18753701818722627673883564699398410722354636353042388382508975546895568464372"),
+        MontFp!("3775364142286651074799331885877684616693199427012762917550076623916732710822"),
+        MontFp!("8292671711306889259828642403052383798091670542487370026886155070540857817510"),
+        MontFp!("12071789317828192902441324443833143078458416120447222512665882093850970086582"),
+        MontFp!("18221914904286916930821501101975619932383182494940702022220624561466550429056"),
+        MontFp!("1015894946220130503079847588005345557311861372219799708101258594371020452677"),
+        MontFp!("7497079366974677538814211796849030622236102734688468791057012961185250977217"),
+        MontFp!("10412524008150259624425778663311437889796006875864864836590446801100002463061"),
+        MontFp!("242804608586097049060214639927231617495599500538071474816862835383660136678"),
+    ],
+    [
+        MontFp!("9327321863177171873474299501143270493456472132426279976071195256972553555122"),
+        MontFp!("20841963613720468677785103552893139903309688713322442693081971171914472255790"),
+        MontFp!("13630784939841089364059908940844732601072559390491244936534430865345773326547"),
+        MontFp!("20060990491599613171081634276027504616735657335613608029945430961773413795782"),
+        MontFp!("20369723769740268530808565104818084023349274297831884550854878783888535628782"),
+        MontFp!("15076862507709493588855118027827375721947856558107489434980647124128748591027"),
+        MontFp!("12996451838772518667606758612923003843581729895975567888341928977998771990237"),
+        MontFp!("4052235171895745508958823720790274122684550078188920081778152850440806616718"),
+        MontFp!("12335377703331511956642047444506386381049513479297082723690490432465187475758"),
+        MontFp!("19412573446052112260556809146936739541890274154067075795508039506436684757726"),
+        MontFp!("17815752693042999749244044082547565016456928313463444720511782669652296613554"),
+        MontFp!("15784264337580227630693723391508440881780776717973842946134335715743491257089"),
+        MontFp!("10667492170364941836778050228790234453197448653226319355770388144152747476935"),
+        MontFp!("211997855288770996034164566467801948520054169907247985981041869092209280055"),
+        MontFp!("4928982790134068590739975426823777812194464923471161503361037698280174981760"),
+        MontFp!("18608672135933853582317718913701178979379432491853388463545185599228146691207"),
+    ],
+    [
+        MontFp!("2315480928424106999355320576364787114368100799067708928765166041722251581141"),
+        MontFp!("7132821772386644248930179957111951717051009389997169728630437453984113307524"),
+        MontFp!("11744909558588287567829975231377896922260739746243206347301263424478589809196"),
+        MontFp!("21821616737585515642213483301817094657757210129023396850436714925413476278715"),
+        MontFp!("11972412756334108055648790188313869962577071423570734765254546184728606861831"),
+        MontFp!("9898102092275580917130558353359107455579856499298488646821411779664420246304"),
+        MontFp!("9958879822047499292094876401302022972082946727826778952858218178060652972948"),
+        MontFp!("19871205375909775929744751330720905649873502681808501037344623586575605895174"),
+        MontFp!("16601345490108570384179778033128731955939874176242885190845545230306090545377"),
+        MontFp!("3600818319871189164836691793538369796689767756596935154198009735998848369707"),
+        MontFp!("20314694248383769870802453966233555256007670655653781360971318867730452026627"),
+        MontFp!("13418958906290175260633447769067612135267907574578622587604011162488451092514"),
+        MontFp!("14459952906281539694149485094281623760953141057796841403669604773900469687889"),
+        MontFp!("19448408956408494949564099234438127422205398424291589853947309097810118078185"),
+        MontFp!("7825443276535418239837092010081563810404777554695462770138005656816269166303"),
+        MontFp!("8463248112790565810949249352339633764856217758597371912588298951088604363676"),
+    ],
+    [
+        MontFp!("9397106702637851943166369067733828452382029530464971824017078972309585633364"),
+        MontFp!("15452595095505449307828854722016355425252678164966267614739918073395150429984"),
+        MontFp!("10382687268437366227597120935808669117993505555024110738918156422833458968254"),
+        MontFp!("8525129123003317464420034009755418250374332671627293582800681623438541074422"),
+        MontFp!("2209915653341740331756848895690532824379055156318218590971248250386542567791"),
+        MontFp!("15183382625497370680223757887016738067273663916736248647060220264288252640054"),
+        MontFp!("5914882314939376011130904692287520473675802288813732481565058295269249787489"),
+        MontFp!("10737923811176739642308957871008944847331638141618843900584295941869359201136"),
+        MontFp!("1019349115878726003171044582547108836234898959299710235354611802499665438533"),
+        MontFp!("17364570285151724843778637821645803441762402239038594475632207111794332738860"),
+        MontFp!("1251558044831543718478805412133062543617572066062707552512150093969983011815"),
+        MontFp!("7568361578986094203490921683991770751640726490619033852251843934473195496119"),
+        MontFp!("15439738350303496845805351814211602783597897988159829928947802101119983398961"),
+        MontFp!("20009975853963282344191402380547695759759731502702993682892413834957351648692"),
+        MontFp!("2363424283223098643833999734510060612126929924822351279904900380971757668501"),
+        MontFp!("17136028473467909987260660923882066072229277631411461261810169503965426571985"),
+    ],
+    [
+        MontFp!("21423452231832054119549543703585288464249234898851841557254723364015587266501"),
+        MontFp!("6300803647873111234660196693510491620063330061194984556803532776163457642510"),
+        MontFp!("2749674757601823156522337416483425720076662114029481936093706226193678544468"),
+        MontFp!("16123152544885237760040277581510833657110306240025009956733742940484347179299"),
+        MontFp!("1449335556475943566914615807167633374850907490355989852917815325720800540092"),
+        MontFp!("18225835808858885369557291110939124591374272404427656191824524491194015802997"),
+        MontFp!("10406157408365973197865488683230737937923502029339842530701446718978570586144"),
+        MontFp!("17998633938868470446795829948946241208954439598754402047898587965138352946507"),
+        MontFp!("17256506181869579387781206921456178119308959078547323103229058358574594315926"),
+        MontFp!("13094455817799190250176997937777121524540082751332502303810836528817463694222"),
+        MontFp!("773415265947842731444676861730775210907581945844672294668103420848288364110"),
+        MontFp!("19038336321288882240456993528476377501756672900320404015643432684581055112943"),
+        MontFp!("14760627809381104024315295117982862934682028410719221222838112174132134785643"),
+        MontFp!("2299176916578536045959722453832883315456156391111537255429863337814706171473"),
+        MontFp!("3669114713225196659381574151653298340387887549947282054635076450967251116153"),
+        MontFp!("18251972251689708305163349578105700140002711019536040700377516042131395885101"),
+    ],
+    [
+        MontFp!("2208114007582253724814286240939815507122998650551224166265438176279104064289"),
+        MontFp!("5311319465851004438404228350963430011209528099234009157573275300632566893387"),
+        MontFp!("17704997085718018561575909495530243398580104458533121202802306468962737491818"),
+        MontFp!("1933762715500501610210207583856164878867609341004969983518458273086102828809"),
+        MontFp!("6386814998566871009542498039659511536698234159072885405476628256992102020495"),
+        MontFp!("18831772844239784973934592955595859276748324649808851586824036127112826604451"),
+        MontFp!("13462121128312374635146040270944207295588795209645130037644415451121036635451"),
+        MontFp!("19731805313375129918425060059406339849106971447864245585064948352741000760923"),
+        MontFp!("21643500371506858849424889544041572150599889621082729284012490052076449459481"),
+        MontFp!("138548710091390954908010216339657754049773930079521223071410504190290814535"),
+        MontFp!("6947102089761831011730597399319184690211329034898095828464106850135090631740"),
+        MontFp!("12831053427863630744244436108113801672008361468343188269856032816742671083134"),
+        MontFp!("4223577351003454708551493531516184634014386163092922955496011474432781253241"),
+        MontFp!("2714897164693927923639587351413494956248140707486375397510165870974510685388"),
+        MontFp!("20023038420823827383850598062203899779139064378489163672445486337197649825217"),
+        MontFp!("1767883277856872395041944981599210930967554162796692711247113543638244036456"),
+    ],
+    [
+        MontFp!("11374943081906439317741616342767018365183355349301791743927619930324701508426"),
+        MontFp!("11803403776542021481872407152864894420411711895295228328072919482156044458427"),
+        MontFp!("10466014303238336135169608931533160584648879880459007777160264124745826740750"),
+        MontFp!("20569672017414729176046551969057922112780177375685900568749563089152585958033"),
+        MontFp!("17872595105302125548629012813042403567513885359499964690907253660570968601519"),
+        MontFp!("10903006438854654900289559455490687959429486945748385817337220540948365309048"),
+        MontFp!("18275515870643256842855966776500808668842291767076613550498342673701067299432"),
+        MontFp!("630933085628668840611776843471079145620019967645658920379584401715066362709"),
+        MontFp!("6825394902701793105668667323441283311921241346208501522910007054667207868452"),
+        MontFp!("6858750193485140252798450603380083764362623733369268015553477107387074944232"),
+        MontFp!("1478873465931112194691102753288035258894560238829532399713218315372158028033"),
+        MontFp!("20647986868364738274961741160207964168903320676158610371768611791255451415798"),
+        MontFp!("4340383424522509172928608655219336498337132315044301147894720301443278858359"),
+        MontFp!("15694054630954602584443160345828698433451692126398102396637062194669728150097"),
+        MontFp!("16587614091042345417694939817299154667821001841573634480871399767976428325370"),
+        MontFp!("6780948432583055051074963887359666213579607675322530873343441117551279310337"),
+    ],
+    [
+        MontFp!("881888803549941181636901791599886290841557130000792317917044641982716656024"),
+        MontFp!("8375540908772391314073560695793592104950470672337969483097880419747173630602"),
+        MontFp!("10968989437172024632405943909737990264150382688586767944077705368389009715036"),
+        MontFp!("18851349698000339475263334762001556036662188445499369323276947028195614775701"),
+        MontFp!("17424154833179596191247217224032955577230090491464139783174467885567507995873"),
+        MontFp!("3084264395291045886600299855227253813661906050535885634611163481700929635296"),
+        MontFp!("9780424450121954867052166726264263906169948113675811543750657338499901472300"),
+        MontFp!("14927658638057710246989480138441311677749528914441518171301964877558977872737"),
+        MontFp!("9409069466927713011440733713350127030613235827582038407437204556786573082426"),
+        MontFp!("21532551006723685558182869738272976023905972040234419867623013049768952103538"),
+        MontFp!("5167833995989484123952079378988963389989994865716472465476503880641449880786"),
+        MontFp!("14740174761652743774118447006447618386992142680472177663315668117221175944698"),
+        MontFp!("10520805511348878943408706501985230943081177040800761729569633389778724896451"),
+        MontFp!("8700454790689589285537042584692084777365997167238651880195034227220137266589"),
+        MontFp!("2733945906594382609383183532639154621092632308934603150907320167864486314840"),
+        MontFp!("10291836929398020145429078280830166142519086643388813863997726059451399134380"),
+    ],
+    [
+        MontFp!("1964652348248051415524467307507959105507630568171154193714989224874728277054"),
+        MontFp!("2594310934406347332341406086415178782930670052092905736460388995932291385927"),
+        MontFp!("14842121917468246033091527680370186171895171052114757236254597861059412933851"),
+        MontFp!("10375528532253513340592396399825408524430850232835146379883683152448199357827"),
+        MontFp!("12477762696438196028925893395417443678494787519078936487705085579706421333630"),
+        MontFp!("18021944163681185812898833787149788656465569272261580921039580457574363675422"),
+        MontFp!("14971348947199715425743490776577354350250241776207063239247262831298628671824"),
+        MontFp!("18527079575564336560853661036281436413609153640671180843997315618816477020062"),
+        MontFp!("10112969341623453459297605360048147871962444397321717541108011492952113746933"),
+        MontFp!("9306811905619197449152929816385830205919355838468334319731910917748600490999"),
+        MontFp!("3201856562784023690028197460260731000729676130502544364740684445045551547485"),
+        MontFp!("17677019524999624366971265277439539545709248668323859450511125252478150097491"),
+        MontFp!("13203760042423946863820551582066224159089369605713946028771025792976332967017"),
+        MontFp!("8225744798383097681411492868795146058284602972120901801159373206820642535790"),
+        MontFp!("1133207841388716114249120311196903646181388009397557241156806164775023041371"),
+        MontFp!("15623890151225925383841140893652872349082273871391477651993101123938000801867"),
+    ],
+    [
+        MontFp!("21454879142557364834852736051259149378158105754952971451715419987896242909650"),
+        MontFp!("8531552648559366596105198828222595210930697829968414405502802286874489507133"),
+        MontFp!("9401758966490949657386555283103887925392012048184105735334446423864991206786"),
+        MontFp!("14182985100305261645378993603110572106752819147111838091043200594637885017186"),
+        MontFp!("3351125971378624693919014331249933776013465831551789721255791740304274394936"),
+        MontFp!("19684429419661111328145464896944065690801463972795522382446603218587092452167"),
+        MontFp!("10925858543362322055288327267322188904474484027644138316216578205314218206492"),
+        MontFp!("638388255012974167128675944413974318871770367985490338348509002020449680093"),
+        MontFp!("14959175299535994556786536841655834078592404933909341983568127492171858384391"),
+        MontFp!("4565657688930940961208733539150773567906556683309024875952853834684480507269"),
+        MontFp!("18262745015163208046754959923695809473174547423960055443621926543956602209348"),
+        MontFp!("20270410959376379064306739018970053272238947330997122046760265674383899359657"),
+        MontFp!("15290563044070434983378131412705775525253143318870138946525234035666015420725"),
+        MontFp!("584590168302279667271049654248756511695470228213252786062709717390671267095"),
+        MontFp!("4966088591187905712289854426727671159168968384675952400787560742388879645568"),
+        MontFp!("3319669368740731092651343449167365623382886973437878280133897523197651190312"),
+    ],
+    [
+        MontFp!("9912174158284239213664392208740702249961859675813287499135373479592639861287"),
+        MontFp!("8516379350240514281945243250529128948244709428452350892659587420308554264613"),
+        MontFp!("2460558416671744640916958595519740745483455366236568201673238589517657340142"),
+        MontFp!("15249503582713331075672114681569135202331428122201669911203912573537116149514"),
+        MontFp!("5355701614078955989983290080356684040651137096346994307217952640790301753843"),
+        MontFp!("7986859541259474766804707773691600248831464381736783455743764933501866864285"),
+        MontFp!("20896597282789039779549640920767060676459830701472255011776479759676387046352"),
+        MontFp!("21156365340242937197411344791352838273101399835862399143303393482855843470763"),
+        MontFp!("8810320994466343375413166155559061722877903255339567630911647558329186883195"),
+        MontFp!("13305939854322882862383989397499916187143744662574072474260034250574401506438"),
+        MontFp!("14276510595076120373993498057833260625756300056428067994615344507361982835166"),
+        MontFp!("20071507901284477038407301846126798169150113895071357904039436046848822333309"),
+        MontFp!("8183811379732619835362931863732297053435925338898156873268534636771405892075"),
+        MontFp!("13219046823251102005239988390708288792968718105445890139009709283489860275155"),
+        MontFp!("9185773756453985715582986632685488321665392756676197265712130071752773976631"),
+        MontFp!("20421943269702844355893550089604182682047061852707721138511917444304707579860"),
+    ],
+    [
+        MontFp!("12300280142885224975710767798713824153348765684179955650717019029210821361254"),
+        MontFp!("12007970010282235734724332615090869243055192449773846575955902036212263482679"),
+        MontFp!("6158352771477574748962005695592225162907260568470401385862937675095774244006"),
+        MontFp!("9907046449530603675289866399335586386847026415848055552734816021036571412645"),
+        MontFp!("20848733914096475328182569300389604774285326414256566622667108340302595094373"),
+        MontFp!("8365137332145458646854804122757179721555979085847877907343540983873210953635"),
+        MontFp!("5631007860876051233682685206319236038053360910127434722172168921859697775602"),
+        MontFp!("7724822014604490732390628840430579796440157358556500058829335597037618014759"),
+        MontFp!("369071834493409594945180455653375424679250473615867182702388739879310444614"),
+        MontFp!("16769246200562822457100153851476834038704115916651426939703296994708244449575"),
+        MontFp!("5761928395342052380070450997738075595651684639539927184355295101970822313162"),
+        MontFp!("5566206280191314323446398313438814776510866532854163164867370641136219947308"),
+        MontFp!("3342359366346342054674985507754083252076489241172458810297886110396010701777"),
+        MontFp!("20961070283557581225918432539907379409525514793918155863543775859703676727621"),
+        MontFp!("20556180232726542574747935614764094533414324377887922356987347326584809231233"),
+        MontFp!("15941149549798383978046697670090370142285790712496734802869937995562571324293"),
+    ],
+    [
+        MontFp!("14600396724469636128403441949598983100001916970624980610808650856082650218424"),
+        MontFp!("8853454033412621833577484167285435511890615294361962242058729914818351640066"),
+        MontFp!("7796912448927288083851300561583091259511345752250157116939318925851741489961"),
+        MontFp!("3163019852372632897541834965385695185434873283151315355155397996458747882742"),
+        MontFp!("14952700133737822394693717249052150819987738575989807253015506109171868391432"),
+        MontFp!("18525964798774229541041472797217632579102334608286281048443267822115517194616"),
+        MontFp!("7102118124262431444884005767970969670970778150875473949502505015348122457394"),
+        MontFp!("14204638357887780388176917062053732214797428898508168337150295424689096270757"),
+        MontFp!("3277315675050465719399614805088484534242274431436504827247668714966713345731"),
+        MontFp!("8238377613232984055051747513217839877952472190522754887201501162730400770486"),
+        MontFp!("10224258402104106587652987351104010753816241645110952300303846844538654914570"),
+        MontFp!("15487805061491340966964538995368847025698196465175525562567846982560655044359"),
+        MontFp!("19219815971577427671261496058630992677146093954055100897228392314454716131964"),
+        MontFp!("20451783587558695494081025729566916635107391693056303514267562508131052495026"),
+        MontFp!("10604908883794901754591456082090038158328418399338332217230293938008589705164"),
+        MontFp!("12629655676673767414687101606277783347408094199429831144029123923860076780652"),
+    ],
+    [
+        MontFp!("13709603387456116061265164612378749494975357559995793114409724336723134851988"),
+        MontFp!("12280342248736515373215500158719410978133041790040105888782814256839967443115"),
+        MontFp!("7016009627584978047738165231314996484413117067952510110423917440240605830479"),
+        MontFp!("1524894728322552104763656617099911162075578893415210522528939966083567287381"),
+        MontFp!("16110348090073067974052480411460325713852194746966461188536450049907379558864"),
+        MontFp!("15849314408692105099989833438461121938666945258532089314493985018340786241149"),
+        MontFp!("14526266680576776847324515988959661063035583100946967808594725417429124130409"),
+        MontFp!("12662059588246809623132277946460950856148161126345911747740066547195150214304"),
+        MontFp!("21784234245035153912497219470830935288918016717870099869682681847939205742526"),
+        MontFp!("9781991278293660355311618579950123622358901576863101735826839255817708999571"),
+        MontFp!("9734447579561113294373127302234593360683756540510409616709038385517649565283"),
+        MontFp!("15783703381085552443769424496547949342637297192593569766965137630433735196499"),
+        MontFp!("16818302710679242439066482280541233367708597536159643998239357880909455304"),
+        MontFp!("17208557330797764065799659085570688998141169399652780006230425322841317471944"),
+        MontFp!("68706297573456724111634550544331343558310761429949721000334906561427561646"),
+        MontFp!("5166796490382346553866700788131214421083284131608596259217301814881739273429"),
+    ],
+    [
+        MontFp!("20336383765590527388425300334919391504612546166109544795528892908246473855987"),
+        MontFp!("3743358254907302851720282727384172290999978135762491123193685721554867920482"),
+        MontFp!("84015356866358900057683156333277329434974724445531256660202285630028495424"),
+        MontFp!("13076432415761967873214874574212608989827734997835707946240987502102677974919"),
+        MontFp!("674004304490160746369333425685153534516797008922146231179438460150958899661"),
+        MontFp!("18008401489523347421324064960257465802035658427821867761642737239891301877084"),
+        MontFp!("9230814159278735889507853746171683425354655931537527400639327580576056029011"),
+        MontFp!("2338224170787780983513724541155899891770286010849997183556172649088034621522"),
+        MontFp!("13774730216408957127941425099141817093498762972537953648152397183615811260761"),
+        MontFp!("2008226192472961561861571910859568903805945585734651871281309234451941696550"),
+        MontFp!("18191659524918356873059208424871952262892246019889299985115112751645849510652"),
+        MontFp!("9977051090514243658919274826700877766433577306278237326432737577914469740819"),
+        MontFp!("1382241145032463690710185532538145321402596788581319285825708537098818318411"),
+        MontFp!("19630880072807438067933226715597469053645950344003618599545076855182567392314"),
+        MontFp!("13804532717560570932337083609013921209558901545207945868049344755900793666387"),
+        MontFp!("19508550180350246129831873366824988420065599516069494466305637280501827380920"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=16.
+pub static POSEIDON1_BN254_T16_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 16-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 16]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 16]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 16]) -> [ark_bn254::Fr; 16] {
+    POSEIDON1_BN254_T16_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 16-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 16]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 16]) {
+    POSEIDON1_BN254_T16_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,1824 @@
+//! Circomlib-compatible Poseidon implementation for `t=17`, i.e., 16 inputs.
+//!
+//! These parameters are not cross-checked against circomlibjs yet, so [`poseidon`](super::poseidon) does not use them.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 17 8 68 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 17;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 68;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("11497693837059016825308731789443585196852778517742143582474723527597064448312"),
+        MontFp!("9160854578263429171202421862962594026987177464192712717562131193605088890171"),
+        MontFp!("16140003334191084124451468943070902129052879491017160345910048022420147165440"),
+        MontFp!("13954253824852759534031493316905992731351625718124698909948022659536770029356"),
+        MontFp!("21692459647877833789326815072729212414846887919903018341690717828718320112005"),
+        MontFp!("9941936267230985844518782624440910125063679135232844826673261884947459743883"),
+        MontFp!("13764916706054812213341909699290503443927147550756936312875016380348026052252"),
+        MontFp!("19102599208524798070012402067365820884265931087114568811319734727534891174260"),
+        MontFp!("2317229314815846955024814528087757341110607641360330608288042339421595574836"),
+        MontFp!("18416483069534816725178879766589878658686265350707575814092642317380006218736"),
+        MontFp!("1008780931278741447191637805167409477999443010661365677836100477728938308997"),
+        MontFp!("2545090804346934163783014162536858416885322260022963915511642447380970940906"),
+        MontFp!("20063061892576784746234714844937263854658165123147516223299128773175198821424"),
+        MontFp!("10515454963476061878165006305843100325941655508909556343534889232612007413255"),
+        MontFp!("14666046876279128708964624720946075308028756066224010008571298456055047416803"),
+        MontFp!("1019066804447509488848959767263827580870207313924599851872882869076383737080"),
+        MontFp!("12069305948801710705684828347680772060569105557945593846885134778987150642368"),
+    ],
+    [
+        MontFp!("8621808318996908879998215444507382011199442894883948814246574848625262495021"),
+        MontFp!("2880093917191730817222149621749122107421670637456732204857760380124742164752"),
+        MontFp!("6542220672426887509675431131054437027921301676718161645952700116082427886835"),
+        MontFp!("5244163576308284656156828799646671881031097312780189786429450879353224489935"),
+        MontFp!("6473177356818363685990488940547034234726012773074061935352613261396549623686"),
+        MontFp!("21820960109882302233412313690073759484098924860992104203363318963429862834269"),
+        MontFp!("776485012941102583811996326817291151707768276005464663608504425462324969189"),
+        MontFp!("16008040671461692654565857317129301282959438973184407738728929267807682783872"),
+        MontFp!("11432621469217532329151110587073289606303873577842245393046608426744660231625"),
+        MontFp!("16529428848681190297024382986484471912959470051547049854507168832330777582917"),
+        MontFp!("21022141832384945339899318910964518260971220396886777286264903115370862218195"),
+        MontFp!("14654032557013894559632377088090851151746560959506805696507500157500224672350"),
+        MontFp!("21354688910115519251326230418165921377662159943573060390104844506964336391116"),
+        MontFp!("7752619774088278019865202458496784783724532278543560344508935599686049023521"),
+        MontFp!("9970654674664205783578937362441466301698784253920856829108173973533602978434"),
+        MontFp!("8766781005151378907464491478911534398597711022218593484510275842885145890118"),
+        MontFp!("427418044823104424459481289119034112585817787122063948281766019090147258009"),
+    ],
+    [
+        MontFp!("20178400825368362428846659280983736786989246199446877730902913555280765010239"),
+        MontFp!("20090950791842994139452610863206653079874108855048809192426038290381481405709"),
+        MontFp!("20312256534186716461922298801781816516042265527822903054862895650988285562148"),
+        MontFp!("4909922693172591670749898596465752034935631291352031623980892388245203503556"),
+        MontFp!("14711873682305388680005649678171045910409841883308457847530167875811246495050"),
+        MontFp!("15958770417311022337941451962731041577578914593218560089308342731268531712920"),
+        MontFp!("14344571866504726216497643454572836820480317972842515429281824926793932325980"),
+        MontFp!("1769397319845168475613819781273574132774109508454697099778007579313922258588"),
+        MontFp!("258587389395146525547707547116022862705614858555176588203787762094788370646"),
+        MontFp!("7022463667655232121918607020344762877802128330181623732816227821885112788637"),
+        MontFp!("19845060031908398041047500648731073426448577406797522741285587091948148114644"),
+        MontFp!("8929070527835967284189505459276001504282186650781989399671939877306459603924"),
+        MontFp!("3547034362934554702033015610070536051926221082690581599667998618549114761320"),
+        MontFp!("4305372853651357563855521637099663044320680997112932657745973067738472275743"),
+        MontFp!("12214176886158725776791687535468106829515395407992835497927312162638766770078"),
+        MontFp!("11706938131011539306398383828922578377803816015869195385220014980091795489607"),
+        MontFp!("11651169608106469169652973769680487604343355235752770879131657116772958352610"),
+    ],
+    [
+        MontFp!("13534129170664288430416028489502009030456779206590118389136628926201783734674"),
+        MontFp!("17992046595622622161177230469344714863802535922717313714552838880957499215981"),
+        MontFp!("10357743233228120740298650287569640748031608153491097653220067758242532130547"),
+        MontFp!("14517928487614900276301089165901434951535050275402697067248151083596639118376"),
+        MontFp!("8209182069608252268840455827520737704804441580266130568152978886513947166844"),
+        MontFp!("7640073974915695432060688995568585354936052110685953897202450273254409304465"),
+        MontFp!("6665784939677502535233010310165049782941185917893655988878440622136707103599"),
+        MontFp!("5556533518127592657586282330044128807869230494205809390140992474026365014562"),
+        MontFp!("408852126690043032125614061393097734033642541042497038832349288207856020928"),
+        MontFp!("12457107183372565990532088400464843754189254530767241274690121422398153743230"),
+        MontFp!("13376606774696045471509234199808902800235186345177397461276986609303176540711"),
+        MontFp!("5984750790245793264714000637029537436625478804219132653560918517073545131213"),
+        MontFp!("17088785934598552415567637681327922806463774512289686863420148678865116179843"),
+        MontFp!("20571346584413672249720180719864302959756306192295084730012069713189235458689"),
+        MontFp!("10563908856437897624492808163035753573292724651291788140893440219493498317425"),
+        MontFp!("8196914932493081300314276778567572383087801740102758457948867897900124032622"),
+        MontFp!("15351358433647454670571026954149706648025357095082471550411625465102412004635"),
+    ],
+    [
+        MontFp!("4691549575923073479244290910433477874429633596189924034857922132851997509067"),
+        MontFp!("3824402350445187488499181297883462500055483166807595223590583390670577007868"),
+        MontFp!("3079709149206454455742256725666572935951085219797765997598190006202700553625"),
+        MontFp!("2933435916857570611285367519434342227981452027299670163614250683347221879812"),
+        MontFp!("11563256620627039928684054332499213986056102448749960304814905960272568400553"),
+        MontFp!("823982800920919472728462051408065816783743200056445901787723684868401502814"),
+        MontFp!("1426387027752743868839120378047531362360888408160839303820390910661721602405"),
+        MontFp!("5731997056751920185589748689260608921390513807500493745181552863591316000292"),
+        MontFp!("7720993839832885597994588400736035173902383878486875722645777844101744411689"),
+        MontFp!("9002464001048295091481045293483875664362475441902824699977741340020558338559"),
+        MontFp!("14092406920820325227424145554147519809410886190773484884502907926085878880530"),
+        MontFp!("17941121573263204671416572834368313711647830547916231506552438869447449377380"),
+        MontFp!("14129082317750218835304685520412323779932243077309313102580200435673568399019"),
+        MontFp!("12243264707350346605364305720479795822428336866265087370053925276897423448873"),
+        MontFp!("7359586883838006703688461248312304702229090925413466255532354254039350309505"),
+        MontFp!("3965462104895971860844762333128974051506845133345812196538391893357098668635"),
+        MontFp!("20413404046776512481224629835753522701244606906272947012890416028042446470875"),
+    ],
+    [
+        MontFp!("14776939713848231582251698583594764736648030077584017202377553055729297756934"),
+        MontFp!("11733430377692682072369036122380634083002891720790435745639760545087660184574"),
+        MontFp!("11292503903510690808481905838273174776860675950299588217800551517281190922743"),
+        MontFp!("11420684871520848382159461165975179232780830769432469479502922603701278643559"),
+        MontFp!("508745459215920279034800716428719752357838311731203417677851933253804443780"),
+        MontFp!("13406614833603475717176189537057020401687589054028958458013676908546295198427"),
+        MontFp!("139498778461763532268768837474164379463641791532876816780917343085192436149"),
+        MontFp!("21267978943610728192699568453293742013765519272282009809779840168468272374983"),
+        MontFp!("13427075011225899058346282386775414644140701782157119231274761492276104178003"),
+        MontFp!("19408556542747941554117161987423225644412569826994655839704848575188694207985"),
+        MontFp!("4344748623903456736892311801593408666061687792073497811421388122550378864729"),
+        MontFp!("5249164170024197498338412206444867159273343488553715674803882832943638673489"),
+        MontFp!("2429321160057297680775462145791389342017255648014188548442207641044604477112"),
+        MontFp!("17633034583356484958669009250049760097861376611937917426871239574846566954163"),
+        MontFp!("8589358080626653532622087734866363152181033731195407703922685134228472217961"),
+        MontFp!("169706674245923892154983046053808641575955705892301306744269839968148939837"),
+        MontFp!("6930563539197568226045059034503291611681517204525149696500944995384070682620"),
+    ],
+    [
+        MontFp!("7715180204730071272045176332163669182843407084675929323110162515552729145466"),
+        MontFp!("18820951479360410886797033460215573843065563410033088307349452904607006418510"),
+        MontFp!("13004571734426490172877737218587476803131320809388224624418159739666018276230"),
+        MontFp!("4221983194911935567520445796137770165826124860682996668925096525627918503987"),
+        MontFp!("4179458328609322852016864400883248432169808675570811233121213062838243996150"),
+        MontFp!("1974895668668582620251979849937803690939422905200315076249511583941627363923"),
+        MontFp!("6163089641798041487723906004413611225955533949531028903999813030048150618255"),
+        MontFp!("20798314848405704563578428763355242237484138146926032039199072579614133708498"),
+        MontFp!("7390763848541838674951447729994978703104555741288179669331719960925737939679"),
+        MontFp!("17595709281213749734228944927123024058411855966868873405155429088809356857614"),
+        MontFp!("2441295378821425129112278178696474169691086102217838124236465522719015824771"),
+        MontFp!("17849999656709233176331949642982487670074039937621948427273232063967495006615"),
+        MontFp!("5474039350730649299741439140946492457291098791158740216537321189511814004320"),
+        MontFp!("18047496680368319496003204418900793303422432417949101604087210129655489944730"),
+        MontFp!("13429638037186326961998474643589136448656547552567706898682870898125573018456"),
+        MontFp!("4946359485751570678621380009145072972898871622865400112232372288432448523287"),
+        MontFp!("3957822358540559545601980592836183988567881320017369675759228588453032530432"),
+    ],
+    [
+        MontFp!("2400564715392273231728553914090327718988368277691346375047322875638060719294"),
+        MontFp!("11894303179337833272080120828007835602120013500741388580434416759507002392538"),
+        MontFp!("855198138664096487124576201178969627556714572486300747020353650684651888948"),
+        MontFp!("16178438790960495615141009256088177242852822581784859562011828222911238142141"),
+        MontFp!("10137977256085689928230181030370096331013301569857962720687404778057694535741"),
+        MontFp!("20515298296047282701750471139118794292164841708209011112551973440387123890479"),
+        MontFp!("7578927989884657210580284649728673292510875058413984297956940891614841867974"),
+        MontFp!("15739538064110791981043195085211350004676897766248503311173839899207608217532"),
+        MontFp!("8509314140684268376822128791119356380924264099968476717867240237437611156406"),
+        MontFp!("17940958334079989608306082569922896755033794405963583994940471979845228834401"),
+        MontFp!("6805144082112931458671099527607595264417843202533041916539891329055761729898"),
+        MontFp!("17799985834198911964923035884156388240555414131807336706586014102549806733421"),
+        MontFp!("1321093977038377443723007121319910985126631474786194145179087170534580192225"),
+        MontFp!("7212420993868874204016591911998823569295893131341249414736874789036080080431"),
+        MontFp!("16508732240056273863067547854850721821696261984346034975271352570374366672941"),
+        MontFp!("19365952558655609049762470933563228578883041309045062714419724629518231309330"),
+        MontFp!("7130642306151081144305696424018041594798737863203511582301667374235931034428"),
+    ],
+    [
+        MontFp!("15515575256858646879456258778282520667217724217234033369846512585375869363745"),
+        MontFp!("9059306962111196078284859952597377729932287486280210246946890053176853282710"),
+        MontFp!("4280792737479805409403664514498090636723785279466801897971750554236293020515"),
+        MontFp!("597858655089478477234738420519139785711327216887739704481840585427214898574"),
+        MontFp!("12083197860096972935386715826619665719725493525449003092099143765304129114681"),
+        MontFp!("12386885105651539176174534724546204378869340659228252756723599802198672140462"),
+        MontFp!("16104345036536728728283892631581380860380214418375736872156768647788333934514"),
+        MontFp!("1354879242449295308208627398119082438254972230604954870747255418847391619159"),
+        MontFp!("4337243085646703769896498772355566596731636687256987520433742534413482763248"),
+        MontFp!("21848361732679483806572599977716282165817086425830260890883893707113846583427"),
+        MontFp!("19219603360817893268138318807528436572901663409914252265449565639651815377666"),
+        MontFp!("8493170221045553556330983879086435859112709446579471993956965243640551891229"),
+        MontFp!("14527043796091130553415865581526659472541220106744153300268446542314624889828"),
+        MontFp!("7937111786465239058321924657743649234315319708955946415168847089269341907969"),
+        MontFp!("6983241618969891267833664988834562719925952220932975709556427480787427509845"),
+        MontFp!("18465592203176453842524453315376851907098534348906659566313290009668304127477"),
+        MontFp!("12577134888425026368283611608059363450080978991251364658919457233283032077618"),
+    ],
+    [
+        MontFp!("6518118228984271075704215109336988269949556606855830580887066004852197959585"),
+        MontFp!("10874313757780612756454383662805810655295466490423990025119534270332368723291"),
+        MontFp!("20050459982999023948350579488832955163413000705527333267976511300912322921717"),
+        MontFp!("21437528840108163281775491180120464654045652656938712316426352947376535924261"),
+        MontFp!("15993399358508475124653838111269121783855992741631266590611036066175654211556"),
+        MontFp!("10666318855988978766724392469406816805194254878125837815249460550109965279109"),
+        MontFp!("3728246313656508848829733684629154197051824118611903835393981369401120614690"),
+        MontFp!("15672779156766891637063280303644284349935441934769848438404666835306555334745"),
+        MontFp!("20385059098581842810391414884461531594818480948222590493458925664185735049630"),
+        MontFp!("17350905999329080753256083745369335770368978719306867594625495391694963226620"),
+        MontFp!("6853271030225637900654747228083647747830481256853322607732231926920221636638"),
+        MontFp!("16945013335725553221697952514726788415224349981949301561114619373330552300437"),
+        MontFp!("13958698773337419850196158271441769489630950623502500296722238460406723080346"),
+        MontFp!("10720560039907520317176701532328409797933913846226344831945799921818292744504"),
+        MontFp!("21881661898012375513399335499754096939362343783241122010305516740752509759219"),
+        MontFp!("8000150875389840177411621828177856485533123076967778453164076865957788973133"),
+        MontFp!("12006864256015504014085835403867962462497397391751885107034795661085483190829"),
+    ],
+    [
+        MontFp!("11134943591559521791276826061536197135026619606969540608969613264694780202214"),
+        MontFp!("10460719249321273180939167776515335033037028258913526408809327052344611741158"),
+        MontFp!("15622824325483665989612723680117847052463187830077568039968213833454442303285"),
+        MontFp!("15410746446975895232352042328675463711076427815985485297730903996168105249910"),
+        MontFp!("2891625203408623652388062275887825901052275992286085168525244670232353662516"),
+        MontFp!("13945346776936592111645435552497641082181197055210791224507452108313960355724"),
+        MontFp!("9948029837520576958543862437428843878940881432043095435233721163595331057646"),
+        MontFp!("19462884070334019417166507501239463855294968830363882698967905077860898932836"),
+        MontFp!("5520136928844106525731832126160315598282989291025512087174203871698688956372"),
+        MontFp!("2261360495098633227748653797124151186175710067614537829940284371045336769476"),
+        MontFp!("18416255543912854662465760974639117371049579168655995592136361451572699752296"),
+        MontFp!("11463536517819692876416279640167390882172379596277754722588804736317693209031"),
+        MontFp!("14766312573746115666714530391277822821010415216258152152785909105008723593246"),
+        MontFp!("15974413313983607427146441647805917978765835122483385258268287590594890695726"),
+        MontFp!("7395768998784322986017026062700445312131764953109410144512127957624588026520"),
+        MontFp!("4984362060666297962621166548113407854009922708767986933509108968930963325593"),
+        MontFp!("7856069925664789206382562869453172139307214363990979700110954142509543954720"),
+    ],
+    [
+        MontFp!("13392340056392075670742743235567931160245313927053798436913893965097578743589"),
+        MontFp!("14474853581934896987119860016624045909083123926480713073931600741515836999440"),
+        MontFp!("10281094117655562522718238098582121237113987849950975777935895552307296780258"),
+        MontFp!("15651874975250045926713763993802349605521485743295256479167713866101959393837"),
+        MontFp!("18837584337473843738351569365992574544592556787094567312567026738414350732486"),
+        MontFp!("5422285873429437751528536004788420694267218180077508101272917323525729776286"),
+        MontFp!("14483434861134394018133707852914799521209887335043004813883706597879855694501"),
+        MontFp!("20781651897373919207655051244184479648667009194959049646213659882342077548670"),
+        MontFp!("13257874746816536319517386553976680827294792028033921278407244416529098958298"),
+        MontFp!("11687595443717328453023567060513955787814475781716013805208754435947875015986"),
+        MontFp!("18166734702075655056906373297658555632769934998914813942424547912757194661408"),
+        MontFp!("21844245024899402789239043296889438934444349492098488274411135961103932824086"),
+        MontFp!("11576523423366505825808301659798885009429028155669986846400153307591026628871"),
+        MontFp!("1148929907457849288972409801053032039492913919668345502756020163532587226569"),
+        MontFp!("9833563661199700341560575887921549871699630430379479441644037429312353663854"),
+        MontFp!("3530072023449326955819177530541286351102246058096342120743109127661619847487"),
+        MontFp!("9578572618820421025088920868463234434017082077820664789170518853528041438058"),
+    ],
+    [
+        MontFp!("7903274455513569732248555355877155817202990193424390098928646605922794954894"),
+        MontFp!("15657165496965632442135478212817895834512397576120080820666617370995124184076"),
+        MontFp!("20874620177603797416855214546759257732117862468392283423272496519554421621175"),
+        MontFp!("15225141515575386127960844403303707527145779098407107474807381929305493685913"),
+        MontFp!("18969726739742369826201131743053235807095357084892793088520738053078283905792"),
+        MontFp!("2273322388759602026766688787958421446004694547879317377854849512027808056744"),
+        MontFp!("5432511453110863684214390264939773007164867954188379597097897639266721931777"),
+        MontFp!("16573736113133010502307046980851924227669732687124639246608245168226674694938"),
+        MontFp!("14442377229880210285366376088368281053151209045775399428755468734230536622166"),
+        MontFp!("11507669956883827819118311484641373285663729813488745641363568933392081679659"),
+        MontFp!("17839532429606263422276008800408286253970022114288361307186094837217135904945"),
+        MontFp!("4014265539378515041529068347957642340321904838486974789543291691351911347680"),
+        MontFp!("13003411109936526663340169024379119873659186776343250211166084846025445488231"),
+        MontFp!("15306462549196010658398578543921194922332897913051564489815921371400376655492"),
+        MontFp!("15374920278441335059913374044144421033417626851032526544458034333565246831914"),
+        MontFp!("2323707387573936061377018731706151895551673463355017998838984100857163359959"),
+        MontFp!("8763825614293136074070194100490720958859071057754803255698169463529260604989"),
+    ],
+    [
+        MontFp!("6460078619951263360498950096593765796985616066844041272594234887640757495947"),
+        MontFp!("7787749571541900537576366919010287855513576825542236245962970793208463720908"),
+        MontFp!("14155132064389382746286911268123763500605059883946776474093143918456417640061"),
+        MontFp!("5273412399684398435707041325417233001212071630727434066521150499193222197699"),
+        MontFp!("21782771343726562685905794523874393783448988772056027768682759741108535654588"),
+        MontFp!("2051311409953010480673263657665054154914165583084681916263406447692393737285"),
+        MontFp!("11875661765858469891704167021983258181748059479218144774825851054326067416765"),
+        MontFp!("21068403881106541076710977164706790160208140346094887767675202443752560686192"),
+        MontFp!("15758340092420689120259589661569467106735378390624556577895360824207644286190"),
+        MontFp!("6348044865909997285104441438862139025250301814988835887095459638724790173542"),
+        MontFp!("17932524652786058307278475190078972097828770914834668973535335630530452917847"),
+        MontFp!("3228816608788245618072625224844244257443056161113290604232896419878090903926"),
+        MontFp!("9551656383427589703749567396517141384933708835078892692797520811547619069163"),
+        MontFp!("9020946637763713728445703977273148745690000656147983844173946393849341364502"),
+        MontFp!("2943971707938849936044214925657955353752398302351355306608002652427585494465"),
+        MontFp!("13685248050363412502458409958081650237171619758299111626717716567774759779438"),
+        MontFp!("2868302441860272746035818268943730589208611466934920188905195434962368539498"),
+    ],
+    [
+        MontFp!("12942754961762450702258615422302669970585456811961698112554744196254280397924"),
+        MontFp!("7774153016753223166594232439290374237307352882928845881491063153885238423849"),
+        MontFp!("6049199094489456460150873785624904803705527267070980629871787636829670320696"),
+        MontFp!("20845774618508072345571200270048422316984213457783733272821856328966613453129"),
+        MontFp!("893781271752145245996199566953390937322128434854461459523685354415462146991"),
+        MontFp!("18253521160421279022112163574719081086152283091051794425756555232523003389714"),
+        MontFp!("5830356839546054218884630677325272050228211705054095093735137780865196640272"),
+        MontFp!("465724850238100756077164119125411924005501292693566847128509233889037994810"),
+        MontFp!("16128424014911176575708362980592727342883849823936273728991741993116151256846"),
+        MontFp!("16294469866182032082852110477156803007474009685855833013024070339538767961172"),
+        MontFp!("16065302076211391028679793764673039756452069543133653373534107626161947643947"),
+        MontFp!("16884841668405833752583472630774457863811531316950980947099417366816649918715"),
+        MontFp!("6491186733352076588617073683591626846805330965399318749556699217006305107353"),
+        MontFp!("2905363039904017404089840194168764066155916074074586587258377722869451022895"),
+        MontFp!("12278525316798802696433043227895855967349302508482435733539545800309845622462"),
+        MontFp!("17314019937917464307399357201462393625411723762603710024519926241513301595993"),
+        MontFp!("17575148837153403621130441476206668397295797814863081080474688530411862819003"),
+    ],
+    [
+        MontFp!("15471095648400918007606825575051718247465842587885842548618709676930106301461"),
+        MontFp!("12384318072593682092503177281279533170809948746241154089695346932340260672911"),
+        MontFp!("485123300957579906724434519828279717066415303115967428444773045074341666269"),
+        MontFp!("20783388705359037084238123327815399486599342641041862648140815340928809400500"),
+        MontFp!("782158089782171747510228803754257660300288627956397010472288943774913698434"),
+        MontFp!("3478639244366860518133129066736643188173227661380443503605881541719237123785"),
+        MontFp!("8117881660412001220282982503758841397343065193097821242261528946890929063115"),
+        MontFp!("35058721234476129650326366428341402515138917944396335645144933468879076532"),
+        MontFp!("16397938679668108498255642177027044558510343548921376375827648132906803583443"),
+        MontFp!("2433453154751221667718635947799318194191827076977739122814782387249355591958"),
+        MontFp!("10029257080729083671923349509739320383181145104464214096029001591053185820564"),
+        MontFp!("10656149073844062963681322014553075060763965763326275398946747079560564035850"),
+        MontFp!("16593959744982924702318121631183966060100893770061806448540495161947421822987"),
+        MontFp!("2068414540408163070577553011598011878961687148891918803076043034635892035594"),
+        MontFp!("7626934390068570344424013149524663454707020514194937543157326823804903603467"),
+        MontFp!("4554638618925430597188146675680715151198309496396245054063619103455584547039"),
+        MontFp!("13496396626587678250552586498039471308586222626132206600246125554024514007655"),
+    ],
+    [
+        MontFp!("9386575758165199373292193827891788760863572720909068403298778509513288095168"),
+        MontFp!("7915179166050735066974287284338956896350001911949145779842510041370266325973"),
+        MontFp!("8167168351770465939827858686491835211463722244546741051891842055610292036496"),
+        MontFp!("2984855641991264563125921669666111398496570671860076360618117725463255935294"),
+        MontFp!("13345515131307851631101763161069239153529744919878592460694942948582652858318"),
+        MontFp!("1683899377816547722982571372970082226593844001913781056647398610840773630210"),
+        MontFp!("18082738493041031740109434905429676829872619916356658605260447196047710914008"),
+        MontFp!("3093627219579176143734483600677215286836843343902256951787659223143740127833"),
+        MontFp!("13711569243726999863512778161983125470458400981069655361767578939766971032706"),
+        MontFp!("2942999073656444791256166118886574877976523928599259988730412230442182398832"),
+        MontFp!("13741805436257583212406513632778404280140012772934699700695904335718144229392"),
+        MontFp!("2966496260001027437433299921197242880503083867421600405476235949236365134313"),
+        MontFp!("12214449406116917832159291589682410934022154504967698746834536840379875421993"),
+        MontFp!("14724028677081535225097028894192068729072210977241429998194582705094787450320"),
+        MontFp!("18532472576071611894578331306492326674151870705350144561012248059523768664658"),
+        MontFp!("12590949238667856614747230064159254583777806754508472794550424398198576585120"),
+        MontFp!("13228220894074693515947418568115512670466893414535562052872530653586084906533"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("21579410516734741630578831791708254656585702717204712919233299001262271512412"),
+        MontFp!("8554993601136913148229849281645942416873068991157116548355045570766869071269"),
+        MontFp!("8349770263904395404819051886764727880530744217762197718931556224723090619132"),
+        MontFp!("3123463970516625956994178947134086868722089624251980030957656091366977385793"),
+        MontFp!("21442360932957798040744480141231788172382126494033577704060991460078536626315"),
+        MontFp!("10231325350034913697901001930461380417506010080725776869094346614943052057882"),
+        MontFp!("6920436402694617694727322082450000548200664649231576891284834027764418393590"),
+        MontFp!("12792717999817516574604019538349201413861750406724026925198874802923611904714"),
+        MontFp!("7319083527910098850218832163004092895955809799710817531274971443221833500573"),
+        MontFp!("13757426179233640966146754686419290630140910517321420779897314617147307309749"),
+        MontFp!("4049033549996591060740078431987567671358359797940903000648212935570542836589"),
+        MontFp!("18201423118137949240970920992151778204900119273029679711616513196892916845798"),
+        MontFp!("20625824460928171809204757749985517429359815439093046150315733891121610507133"),
+        MontFp!("10457729085307334834523167401466014435492132985358294006123747181337070073721"),
+        MontFp!("21561527744019186913993064335391813055903937050713577176254373319368609289121"),
+        MontFp!("5599728995155490107164072595052340911357670532131511292391179640158683770855"),
+        MontFp!("13966745298956307615009517188536529139238646569224392383446375189982202020807"),
+    ],
+    [
+        MontFp!("17756603569040095098346793596909383204174838953876788800894937537311312048006"),
+        MontFp!("21742079076354402484587060728532755692106347543073105531119578054037775042874"),
+        MontFp!("11100784872920528132266123983509067070706469425630493971770997902694662926998"),
+        MontFp!("20400085312205960400585536190272432205634747302273829805331461533195763963464"),
+        MontFp!("20028967251238446138082148432746545470729859763361092299497853989733022321309"),
+        MontFp!("21646094126368547381762879012999402861347883442032865497835121981839683154574"),
+        MontFp!("277256790316883617863153728392861425598900309956876809085316502674092638050"),
+        MontFp!("829273940377701999291777589563653090200708284690056650568100074655963961702"),
+        MontFp!("4606908934947031763433560217361121304957410936748694859993455652227072492205"),
+        MontFp!("10769441872728289230396615620861141176949118733537017427393172917499470840245"),
+        MontFp!("19521824504454300285368889620047541794275889938757845035419559810899465345698"),
+        MontFp!("17161053048471962353174720811774420740284389196847515292313979813334039268748"),
+        MontFp!("7908822737820790247231631548479205241063360318010733129560952138908448461427"),
+        MontFp!("4877162162397125215823403409232508291458423909077159953565289381413423118030"),
+        MontFp!("8487393998302601588798118543133789294087935184558260165377494640490662085979"),
+        MontFp!("7454433584826937164880257351721993831542783218228578962943846432869272993591"),
+        MontFp!("12600486335574416961082984651671003440366178113351945406282261259087640562075"),
+    ],
+    [
+        MontFp!("229943091042136639964977508364517877844589816262259724739584329059854831474"),
+        MontFp!("5964363464498190105797451630207382654570897906930358361814931706994649645813"),
+        MontFp!("15027885081212300130366181566116370954163923966760653842199145107431036749190"),
+        MontFp!("6389712846176883524184535452348872799769012323597964483555439005016828865357"),
+        MontFp!("13050625522428562689464418495099691361897297012535198348448952952830181214686"),
+        MontFp!("1457960163867278804802442802649716001232992897386781587117754753421449788143"),
+        MontFp!("19121642548533119996481133068671203033851078573250942970641264441950592334007"),
+        MontFp!("3319626593342830359906793887689227542493167081286725783452961782138075389498"),
+        MontFp!("10182025658554317340763807114890885589336807302908478511429803960136159439487"),
+        MontFp!("5258867553475471512860996445670851629850555214065072419972647507253648925387"),
+        MontFp!("17105844700483111456515253413030059462544811526461544189616915804056937372339"),
+        MontFp!("15389507448590279891790879860335127747331525679865083633840630125275096453854"),
+        MontFp!("8628144040598587326275852302297295030455205882673458629883629373821226515849"),
+        MontFp!("7225764039772127797033872800338173049227188735693607855118081892986058306767"),
+        MontFp!("20070937673840272071045130712690506696769042932336737261842298381743641619092"),
+        MontFp!("12139783026483217581244209544149124607538399031092603229603855979370010147969"),
+        MontFp!("17581810038009568123079980574064070648109195109589787948955203592875730952957"),
+    ],
+    [
+        MontFp!("12141791671600953962785570868053442402210784762014326945761482080946083167280"),
+        MontFp!("10216251141439191088257104654134450392253712707713481968388586155680061818083"),
+        MontFp!("1414175852848441331935246181908753253333655328715371554777242869802352097003"),
+        MontFp!("12411223399258687363418284739063179467323133097416451119653175668156302546282"),
+        MontFp!("14163252864986479721057694562184281568622251449154036885135516034438034547025"),
+        MontFp!("18935280158362457804125825095786762216868621594409914695877661895590787449138"),
+        MontFp!("19612073572528301850608997760721508284827614275438129248352825723957284031526"),
+        MontFp!("19819295714156197944855748114967530774512172286810045467127912108030396642179"),
+        MontFp!("11371593776080642722788656520803479745110058361122399788254568568846349693622"),
+        MontFp!("4027805955664709942434150181718117928301673452282014923837288829996079949500"),
+        MontFp!("12539691854417510068939338882045915380674719248923282579976372900935687263702"),
+        MontFp!("21456335515466708235982252733061551106892811579323562996977622319059624115114"),
+        MontFp!("1466175641997386496752167837552521008018514071345728218669064866303097231258"),
+        MontFp!("16954396739281784813954958963214415095472216566673098897333193147120371509076"),
+        MontFp!("12708223137926559496125521072416503266378368566414170219615449248989766379947"),
+        MontFp!("8788739220646322755486256871812144068464402944468818647293944655221095435821"),
+        MontFp!("13058732292597055849703973806172477675203122319912563406670103404654094386664"),
+    ],
+    // Second full
+    [
+        MontFp!("6511309643923082597828923519243281993300211181014429981003702021394673484309"),
+        MontFp!("3158509665257424804238893020268668038794684527943417360064521274731483365497"),
+        MontFp!("10106711021741632147292275661510721445094446856823798214773373487329113893162"),
+        MontFp!("19539597394622940270506324036474158048415508212083560312665281444636039358507"),
+        MontFp!("9075752841443178653052367723489194637305474480630925657941805297419704103107"),
+        MontFp!("10984322959292625191378844945459317193701661510910417025411449026843907121641"),
+        MontFp!("17927313671844804578171839390174936993766288170716470877087434657482712787254"),
+        MontFp!("18872143162724264635018175304456023676931184143071101308504884978404622981510"),
+        MontFp!("20515902079806118899516548385287144085761546545950360604606371922174253859996"),
+        MontFp!("909520465300399063286844098900959690198495601569624327409635184475032912958"),
+        MontFp!("20206446745684213293565932220432184473350758436589542174203682990821527627997"),
+        MontFp!("18202239467972118142408259480727588505962487821013411792329301914239859726481"),
+        MontFp!("7983919062477498484336722979137049758716865253938030483468589967847498836327"),
+        MontFp!("8325614337212122905531318376855865321332189692321777599447159536409656516919"),
+        MontFp!("8597163804726192574292212854626326634508890118939606311848139014738137868745"),
+        MontFp!("18500944130179290196885189330659070775829081695220678669482033354888226989090"),
+        MontFp!("7688562031300833340110739922365866810835026837265955101002957176624104705373"),
+    ],
+    [
+        MontFp!("19947182975808967149585827952170169153114833389807259043148589247054925861923"),
+        MontFp!("21517453591126354047928769118216728006629501856431780724965733122370735013602"),
+        MontFp!("19724728051616532722905708549906983762154956430000563385110162693578183573835"),
+        MontFp!("12901041676897371025108379309395257314967524835524863357724200518477312324601"),
+        MontFp!("3552976975075178791886580855175573474759766023206417049453393296909032090255"),
+        MontFp!("19723727023156957738383690313848921568395238875690539785344406327140649751346"),
+        MontFp!("8448482122526118108012095036728031952881057181233719818597059954973230302598"),
+        MontFp!("19506723656402269183886569439436103718740054288352928129647596321989076586438"),
+        MontFp!("14170198227468034198358292107569967739463050831964193487443803219358410981401"),
+        MontFp!("2398884471809169728404698398912564280110318964918284340553363751754870608551"),
+        MontFp!("20316276154960569208855163430299959763482642890911503054443013025286320052982"),
+        MontFp!("16819346170274370820168489515108636849055213099894018965040750518138293949687"),
+        MontFp!("14032485862625099598391073907784424901699264655891124043486362510876557553033"),
+        MontFp!("3741512032866709198636578814340035900693212341083373891378285003365821212725"),
+        MontFp!("12320998007117681710156579111620440763582440787871319835484669927670822914066"),
+        MontFp!("18497785580008161471127317447391820533755559610828409772165480020067085485597"),
+        MontFp!("4405750113669275276872999085838121306688869906871946649397890860602053182664"),
+    ],
+    [
+        MontFp!("9830919490764773329556696442544208406733862221424439787824742578837854955405"),
+        MontFp!("11656214840454358128291973754562337687978444406755233034850997851692380129929"),
+        MontFp!("16862314752433119612255838474361565358197526603416756657147704910780503202458"),
+        MontFp!("13994730783691699927566696508271362101008053907735972055086535421903219406757"),
+        MontFp!("1854398114607336170485701571991055833572643201188015258021784624866312103211"),
+        MontFp!("20069390379729391520654648717585246938403361904664791681209786618785720069963"),
+        MontFp!("19778444821535136400266636630030874919578160245360989612631440824622767847607"),
+        MontFp!("21076683052368294199461719450518198858527944040936974409849054794891242446768"),
+        MontFp!("20411961758852468040955165630870750226459296665790953169982230333559560296737"),
+        MontFp!("7502887992944485334895259919103098713153732704425367381733235650810817022601"),
+        MontFp!("8197671201190749689041918593081864258260291777801687985230863071067546597951"),
+        MontFp!("570474345973383626887611250305231463005632817815912456645561438458026430574"),
+        MontFp!("16187881277000098252637261715701961539204559080071010626416256497925074197451"),
+        MontFp!("11845760454453819227975628605163841650771493308562299599588237256809780809041"),
+        MontFp!("12496905116588812906791602937225630677262600876144492834414412499317258049345"),
+        MontFp!("4202178453449927253051431595142836159883278283876420191896732301089503127871"),
+        MontFp!("15280239602029688372093391224297422856227971384132785248484775299588777602819"),
+    ],
+    [
+        MontFp!("7675624637796659741242134119256859966414372807758392029058215748145801821134"),
+        MontFp!("1558712828795805480069595730092542425736929845400776453853052297930907571419"),
+        MontFp!("8635050664405255712941235442916789795311088451605341529181390886210645594786"),
+        MontFp!("20012080883240845774134523050094772793782396855825781678689671532280266223297"),
+        MontFp!("5332665745663855702669515489737590813722712486482340264533181716182162216492"),
+        MontFp!("4485713577378561256572703202440374134284884194291407499970429041545518915674"),
+        MontFp!("11594166683437400908552833183389222213106302657616725421515671119008232951402"),
+        MontFp!("12334131761060442183744872783660449330900007808735685674075245978195977188916"),
+        MontFp!("6469473140282499287719023824549928080797880736382216700350093546124693991876"),
+        MontFp!("9636916035199580181413428196442500662713954920605937945590797926723030993514"),
+        MontFp!("16259404193045476674338459391599179316200568243819582010383092764410171324132"),
+        MontFp!("20780929014533211529148194875929589500835169316324843103846798105106103748435"),
+        MontFp!("19665958036420604957261288306620614439114405875604211377014353932489316681936"),
+        MontFp!("15722491897648578237100473323139351762887860215830601049261437931157637153597"),
+        MontFp!("20474814639824406443111360000192533367769367587690127965461453022108167666929"),
+        MontFp!("15306300298273142257702357120212730128497075786589008381550108606914393296015"),
+        MontFp!("19116371381269652319147699604019975103087973589614811479290794650138683901396"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("8931344638882118593791237662384261193166536469680242356398517062367452395384"),
+        MontFp!("15456845400516927354313637168726345061971892967841823745636300923188629474327"),
+        MontFp!("5751588038559337581650296498368532807067353107651773867820816744681643949204"),
+        MontFp!("11549544825319477431118343561134281237789591414423676704396089395115754641434"),
+        MontFp!("19234147263577254818888926168924920479297919454657521855750553715796101778809"),
+        MontFp!("3648349134208466654728357812767145066715472797730454946149007751312314206222"),
+        MontFp!("7718151953117918461425809889893754434608769559584222279828239292761893621712"),
+        MontFp!("8845522739821256867897474373924647700071798803600128774472020272335057310062"),
+        MontFp!("12793577303328701474174653130332291657457728764837263636620978444987214166803"),
+        MontFp!("12567791764609503071525053111715537148465248715927771041171097136254310005533"),
+        MontFp!("14173284996087186652368776168561110401474338255050963923163796413857580470909"),
+        MontFp!("18034666979500281081740131331708674377786999775618310647791265825609322054725"),
+        MontFp!("21422354834639531449049641141105504766268284938752536446702823580190877745329"),
+        MontFp!("10861911722118463296713372205424749768917665229584553370962914232866310912045"),
+        MontFp!("1426840929949909140164228257293070123281693940796332643637029311310121856472"),
+        MontFp!("14301944441994042783232016477141675248310618781100688243801831318561916576546"),
+        MontFp!("8689261616262362847656173161257424730101884874532916838450695695508844076137"),
+    ],
+    [
+        MontFp!("15123977840288488307479771803223205244132730982232338102604391529168092315901"),
+        MontFp!("14782587644869453236501780556963556761570896168324364501980524203741590116061"),
+        MontFp!("20171126664277707857959263654502050384578410237255325322075457593732181023858"),
+        MontFp!("5586442008782671473934242848395070351077466917106669778054075503048330770950"),
+        MontFp!("14893034316669944289540729978541666240683450933307479859464390524607307041597"),
+        MontFp!("16358386602267214062406516556279496593235072850353941542010428321612942609886"),
+        MontFp!("18848866854232312978702044457572917667782740587353338084332267136131275700603"),
+        MontFp!("1579215194993191651478809349088803658155969078580739214414910140585581589538"),
+        MontFp!("17033458213089087701892498495271710586197475793707993846597834001983636294290"),
+        MontFp!("12940326624292849673877504632305122955030021835426715254781235159065401203407"),
+        MontFp!("2093340797218797584680567638361507396244460243439847174481303012347581894177"),
+        MontFp!("5964973748129501579884254138099588668727348462189690734364404017042795728252"),
+        MontFp!("1674681106235348685135834192630054282175690835155947917214719741317698144031"),
+        MontFp!("6021317549494232079997036595203156655990507346855425821696978600367848015237"),
+        MontFp!("6518804338080390019586997346732962982860290823548982950371646893604360711024"),
+        MontFp!("15170463834922876947772409926040699970156014460508617331830728121385518919006"),
+        MontFp!("15398930479669448663557196733417026149527004779216987588229439497346738958046"),
+    ],
+    [
+        MontFp!("2669700622596766237628533802450875322874330587389952384417956610466102910333"),
+        MontFp!("3127548363874797616403801375102757494200866522631068411037184158214286131549"),
+        MontFp!("6584403272373574724590091428656742867168271029343425772452216864199113551892"),
+        MontFp!("18683280795877134163038651063011198948877602385157504892093537654399764426518"),
+        MontFp!("12422086496748175124620724672115957665892586761203533990582978803368996339430"),
+        MontFp!("9745099390463439278844126903162955736019504616033755299630228960871294951628"),
+        MontFp!("6064302059807957253392579676216068692721552882853761726090911957467256977688"),
+        MontFp!("860534097291826421956520903118828583111860517568849548148819591113129410233"),
+        MontFp!("9809207437695386100460912579554305365027175459186390700555141956544839955242"),
+        MontFp!("6576375143489291749779792018893403099848704832143183847385791291045583986902"),
+        MontFp!("2551573667498115865454648920084921687986702958687913960418526064766186248697"),
+        MontFp!("13043550024569409591105305093191112805611412364703194793685224224072149855745"),
+        MontFp!("5369051621601119248797945023525768932797813569336410551989722329535217332717"),
+        MontFp!("10399989003670197520503648853627144005300436598931266893609225004624861627954"),
+        MontFp!("6159561484143246751423457452493034991227592994791307133044136210702400602726"),
+        MontFp!("19651431183851896182934111830326153107040303776630454129626690653306388341484"),
+        MontFp!("14970612719926241839940820046954288242553272322468930717244806728631485407526"),
+    ],
+    [
+        MontFp!("20461999502486452961875044483247881758853878278954851693532423676388213697528"),
+        MontFp!("5016750536904085805050275769221233811927007383797241751325050175740220466319"),
+        MontFp!("17316427284462136919522043989265881044949832745678035885743571937214912552561"),
+        MontFp!("14932533665158850512241105212984927846164589888111067103835286341225240509742"),
+        MontFp!("16012484446855626574765806641361955141820105388650596409595164514899481874274"),
+        MontFp!("4863651915422513654068087402811690721104417928537042800794511645180712743925"),
+        MontFp!("9478941069339421252769300213729433894403874553023597073962166402867140590783"),
+        MontFp!("17529734771936454727002429801459948360484278991049231778922771896004721758963"),
+        MontFp!("12672015814840095133854330679674924244657276110030612294537194913437310163995"),
+        MontFp!("13442667219867515606432268873704321985951188504382080502019480975401891351960"),
+        MontFp!("9346556839116407181813364316149756946394621057020562307256030525707411763792"),
+        MontFp!("11720199480542613604905913885140886560194773593236018605042270421391171700142"),
+        MontFp!("17713550818981273796962302212731756472046580671829192490772244177376146261137"),
+        MontFp!("10658520565101402948486320613747540160084586467440232263820881394770094857487"),
+        MontFp!("19120553688581692745126354026518291549778059267410591682456431863134002720631"),
+        MontFp!("5837704130879353469974552270945063041776968090189866547252204737075618880582"),
+        MontFp!("10952573317837731274507039100853076769322123807364252392559268333440123751056"),
+    ],
+    [
+        MontFp!("16175443191562457274813386127054957574917457114692631929042817125665307085782"),
+        MontFp!("15651399869272720599280980856510555798668301135962902383119739133368631494409"),
+        MontFp!("17982602271750585864051043003255537160144994845232276906099438195600610259340"),
+        MontFp!("15564417296959768207318803300620712620729991326343744282367518107640962243181"),
+        MontFp!("20488793123009381941807231432363887878967153580282107905075818200161452173728"),
+        MontFp!("20845615892337349138315927113904389806784179140299978993512007141180651572609"),
+        MontFp!("13259443846669565093311907999318849863250202720247601058242623542433998488480"),
+        MontFp!("19583450200980335366984605451914375573108173291577305129314361755545713207859"),
+        MontFp!("838397221216450052117952481963960818729311704107008458344946293696441980221"),
+        MontFp!("5011508169974056046810100610889953041631883487165044189687841384459345302746"),
+        MontFp!("11361142794005243853743618662384069287777450724025651283005645149554307240000"),
+        MontFp!("633456298666951303063125949977139107258301407861096110769739192555903857431"),
+        MontFp!("21529743295731761646584336858048296237991767869832124757149704140550994020630"),
+        MontFp!("658601336565148638150196750528474813469384561163113116358457661687263150766"),
+        MontFp!("7199686023207207605469992040453620774208316115852720699379826196410259893822"),
+        MontFp!("6694724393708237460096397340665472114749949954099504252044742926933353832323"),
+        MontFp!("16157341357004248687598290467887486980266976396840580002635620741183071441576"),
+    ],
+    [
+        MontFp!("18735931046113570691341052792512856145472697573166865032702507242384749856515"),
+        MontFp!("6329726929169898271848965669873324332951781357803010027329026944351232247476"),
+        MontFp!("7061209522680426874403579559245042585450674594552752972115778072823155787614"),
+        MontFp!("8946282535333125111854282749852344921885511854306802144459174056051518348720"),
+        MontFp!("3988279129283542026399878866455129212655792613180690875708855315104446444211"),
+        MontFp!("19788373916759119069273555853960393438264058803290744373422102428664987314058"),
+        MontFp!("14781179276955000841116554151929609467906998220188278865550170013658249984549"),
+        MontFp!("14439714507608577761834821622197467839862763277392515730094487176490304118865"),
+        MontFp!("7462417821368326183154895363497223167855553631230374112323789393782682375855"),
+        MontFp!("14594890619141141626245598750050255728680683081531253962411791337998057959565"),
+        MontFp!("10297822643679438597162031819677962851770740929826506853838015287832878785074"),
+        MontFp!("4231562753232550403815225933022904310027433526532932589628179163940950572874"),
+        MontFp!("10646529230382575523755302352793337142051175018996981972379640368359926883275"),
+        MontFp!("12164083461947216214412634084378451605511487715584004827824972078192861740778"),
+        MontFp!("14686738730377226817976749475359230017849316512373192440082122302915229733394"),
+        MontFp!("1972065207953025646946682878392649299678417507238551179215236414492110157365"),
+        MontFp!("2596220810659736571653162812588827790612138851645327014072494893329877375848"),
+    ],
+    [
+        MontFp!("19742890478753895876191378843357325113803569368242719397467948082842949716134"),
+        MontFp!("3057722811279760312017893583084211485332195649925192440822130571411021625062"),
+        MontFp!("5078380046721228959752000757775271056076152925648793679038634805597314364689"),
+        MontFp!("13994065550182407605627049394529818937411332791332501915425485445970019749196"),
+        MontFp!("8718903390300613451595895490522223543548941022555756021584328963874682051659"),
+        MontFp!("2830037047734434537263368457077478915382396457133799070168011318577509852518"),
+        MontFp!("6351328336589112831842317252922662854527642572646149728733839010100363641064"),
+        MontFp!("2326811025141337415486606567159001979990362270551911801543813258783748664928"),
+        MontFp!("20631048108966815289784074608382072458460940973738551134226168800345554951214"),
+        MontFp!("14721204648069833595280990040775556291988674304257774357769088964732410863991"),
+        MontFp!("4551815408348203166379129282299441425423743023821112768447969406023780214645"),
+        MontFp!("16626589364834731158131207695359287961392723773324953999720551575045565560017"),
+        MontFp!("2119119902895954746578148914775899257881351646176141505001582342610666449702"),
+        MontFp!("21198001320003994532825962721847645202213745138854311312804244693213734456020"),
+        MontFp!("16575653563112300802890760228610449333758690368857078853765813869752023855520"),
+        MontFp!("19662199829424784148957376290310955285339135428848477356788149061587904074636"),
+        MontFp!("14365866723679934269369638167309959664282416562097092385771370076826961535871"),
+    ],
+    [
+        MontFp!("10462405794880410718710911820471224752218613463413073336296570316774568383223"),
+        MontFp!("2875852545895825315521599376020869827316570932962420930916494831426174036683"),
+        MontFp!("9365014190378730240070787324401488407601702337948470069729769089736757502613"),
+        MontFp!("10207664772554042762314615033378156185686480986853035052764356129216234339601"),
+        MontFp!("14593204638464636358074700677706356615788934459664487787695216859702024204802"),
+        MontFp!("16870253735699395936222032450057462746120274245691549241725232755997497777650"),
+        MontFp!("10403141045354931831897350467824057442824486714796947030202528195269765938299"),
+        MontFp!("13491630075210993306306680088269974619065901790274179689314972497025507972072"),
+        MontFp!("11737690900303251277784941365088697342741256105580987740031964012547987099246"),
+        MontFp!("6479411522140791199878732386964631711912096436445632282256505752750116503021"),
+        MontFp!("1083921069605939352705123162314489784481429719496507658938926140311574639372"),
+        MontFp!("17653617267480348306435879910355154811805166995916787026122631473598046826333"),
+        MontFp!("14432164328022071373295386637326904766549408251892516429892690428586807444784"),
+        MontFp!("7793671760657336901389781721891199068620856343884708391197481940646184551315"),
+        MontFp!("1959765449995198342923438542063212054673245344496347360855620191194506656856"),
+        MontFp!("13782621216902843666964879695291399503179812919290969771526164415604545126461"),
+        MontFp!("19078359557987218636232226316587656499460623053969287998311225512418446587303"),
+    ],
+    [
+        MontFp!("15876205697805498189610174935234268016677320029636416200517433249256912037787"),
+        MontFp!("7722805406045443730324325528663888753917114626390901464015754094863680930900"),
+        MontFp!("19209717507699122245389693034814796437142853968464799877186934474171699195095"),
+        MontFp!("6585127313235216502111419023607202115168882276771320178943546160044534358599"),
+        MontFp!("20805733952846662054565520828206551321957145521190409450891077526078523041277"),
+        MontFp!("1584895259816785676773529464055176663163421450657396866598813628883854756221"),
+        MontFp!("20856003384184708896097189495372900159489733605505425206950257455550934589790"),
+        MontFp!("14069406225378546242129093232844103602751521581800986668621079503726397000027"),
+        MontFp!("1028001874294327945398022002342466649656159450229921471622913285892988310568"),
+        MontFp!("17966371882429795190944428324003030336630819228004846253694564940042988400820"),
+        MontFp!("12876427863944186509000750451848678588143362805691657085788824358176114653258"),
+        MontFp!("8715273966427022806434959651283926754916348745411538421378504642158804932403"),
+        MontFp!("4267431569502908019256597205760133122178817596876838981125845973819244098360"),
+        MontFp!("19262568227942313166139131845806786518421402326465887345846202580592913547380"),
+        MontFp!("4587852954486808043358798482712674466376898718708260090686361850306798447997"),
+        MontFp!("14015025676058610927837367157870446606501041323636097276737547710444897937611"),
+        MontFp!("1144137690247115846969300874102656726503285917182531654472086138598330367043"),
+    ],
+    [
+        MontFp!("9454537148137071892124156670991734830383436460928166061331273469365959775909"),
+        MontFp!("13012777452486288707879402995033258068339583475193898659333632638157561913335"),
+        MontFp!("14224623168753818289919819482009713832024545924040193705962378465158371078837"),
+        MontFp!("9505762419233185123340587169990814384174703626434894051560218285882560747356"),
+        MontFp!("7019256137023476309554512440166531052657689673592456244254319500937647800006"),
+        MontFp!("17246363017424260106221670693985197925080671704985089438076796011281669775795"),
+        MontFp!("14408593968797438981684807585445491387554770474935530894938751288467685293766"),
+        MontFp!("11991202914737654500568163346559814604911312199759237827422720619371707311619"),
+        MontFp!("17485055588733729741263685618351826350652951158142561188142663313955733134315"),
+        MontFp!("12950471790443580303905847354051728755537901376411054363054627208333559704631"),
+        MontFp!("1323445558625272814455691764419102369727943978699511295257501714917998936833"),
+        MontFp!("8841255445674311239873770890653537908142822789956793926192649058263474173411"),
+        MontFp!("9401395313777449751102417028930156506880556083443899378760756340424905478877"),
+        MontFp!("21060472724780336112168263494706975132712741930000971588397970288258676527061"),
+        MontFp!("3812019230904757099892361572360806117962259446525402823615305574055615634484"),
+        MontFp!("3514498070156020200040973833201476402797883738073136493951314455793989266387"),
+        MontFp!("7030071313560321306345374157122385026218445152391153750375781343870025689321"),
+    ],
+    [
+        MontFp!("5268144785716401601955218888231720448573921710587461252164696334890979118029"),
+        MontFp!("8300685363844078100354914067414753644670881295812529449763437785519065857313"),
+        MontFp!("18450162872081547013081002634376155990252513846297828484205311669445865332085"),
+        MontFp!("13292716648615315298838871484647252965100904015865352978197979561906010036270"),
+        MontFp!("21013686439245380148735850740480550290335132408628769676980938610161935118557"),
+        MontFp!("3221231898146718165495762474085749772444251029483710808533124131909521295435"),
+        MontFp!("21706362586702075336538820540433124172473413960581336734430968480142138077992"),
+        MontFp!("13486895089928308553329688303040674812803987395822848864346610815697956322679"),
+        MontFp!("17668589109420826004140047157134934003621500937594400640720194981334871115223"),
+        MontFp!("15183620307048155399117286900834263744557758459406645835780045985078670266986"),
+        MontFp!("3170561135789021599641212581208901692806457808161683895582450799937372092628"),
+        MontFp!("6919102281737620426489877909256066802544737782816026767549277126151765906518"),
+        MontFp!("4002850049662127756373199253004358198702226377235167238172929008366286605110"),
+        MontFp!("1107642403321371666617924914652619792767807515753221818405051786669061003368"),
+        MontFp!("11885648350895482809772026695774528865889321558031441531978106387676087338277"),
+        MontFp!("15378937381250173939485112486205353769964903768096281219667852296031194688940"),
+        MontFp!("16437477998115322080973717158007622249171727785394053923880768422172683727081"),
+    ],
+    [
+        MontFp!("7621121366936849931681051136134866470312478335111295204625916349299382392728"),
+        MontFp!("21215912298727134138155623335764864420030241120133029649046764814328222738790"),
+        MontFp!("3170821186412794476815678730429053912378444748965057631248759273529827834502"),
+        MontFp!("16084650047636623354069916778426687798588694909600147172336375822549887134716"),
+        MontFp!("5100048383300431022278915674482647701788618482574130930551328380700470664477"),
+        MontFp!("6089313816847452477548960449234592795575689699536263376449482717087314881254"),
+        MontFp!("3168424144644268762342999941888553529072271091445061821545187264931478820334"),
+        MontFp!("10404448021076504176124328273829362151757942013323044839630548232003974036828"),
+        MontFp!("4252171300134965718003785947840070216898476595813167252439064205515819918152"),
+        MontFp!("6217524790069168495104329195931800727381161902791016429835385350530530741236"),
+        MontFp!("10144395323727769803680924125110392290775229805005263966394467735634758369184"),
+        MontFp!("16773588435110330580333921944382990185799235928388619755745278707444537536196"),
+        MontFp!("7377711139925591251943689121143870901330717424145420584097007639933333168762"),
+        MontFp!("21066973161927891686455166855433069549513160220938455527728499561969343776185"),
+        MontFp!("19501795117214672544349409001236794757366887214419994956397195381058548371627"),
+        MontFp!("2696597170314397939863800656320896858584168884088153464800095340021127302558"),
+        MontFp!("21168940252375267860138608985225319663694276566348965377356649424639627399939"),
+    ],
+    [
+        MontFp!("8729578229953090469373121531954476034394661891580932672218135172268595941310"),
+        MontFp!("2843049930012752804094477290180353526348132895187059237802643422297864799516"),
+        MontFp!("11477013052507658297840246977078282722343354831202397750212752940967096033484"),
+        MontFp!("15309765532985207981165438737385487871403890172460978621511228072457639715462"),
+        MontFp!("5276795386880565031975868524294990841634011123906486763299115154863864095167"),
+        MontFp!("16891192769456289619320007723900774808412675144364121907391915236424354884923"),
+        MontFp!("16679134204463371830386366769161735700621394109825781706069839015605424451750"),
+        MontFp!("3726779668847121591477372195002524410424328772388206362047548845558525545594"),
+        MontFp!("309779746952337123192541883987405477861316061278735939472746602872146210577"),
+        MontFp!("6116943109304762420893019486121829026477809860415070409858407396285217706031"),
+        MontFp!("15461534026148460547793521829520247652146949380168641953893161519404620248399"),
+        MontFp!("17490188391720816485403388897026469756907238275881766262188942134609165349946"),
+        MontFp!("695497190921838164093269283587166536603898439348751059907333515445935240850"),
+        MontFp!("5740644431998005711645731796487090897088650307567273144862464339327188211037"),
+        MontFp!("2924287064221347495709747210800576855931727720487579410745268421394700507499"),
+        MontFp!("14573731858717233227103947986267826915706941233771169473071405909223613015343"),
+        MontFp!("7774646633424887132991406013236011457803238614262239840323638862678503960297"),
+    ],
+    [
+        MontFp!("17118778781828713462818509847054627300883191299007086864997977171169360843500"),
+        MontFp!("1050282722198137586289457046966751947431030691327586256768002473254440863847"),
+        MontFp!("13445581272162801515105119436838273760359267729139949483205892441735924907588"),
+        MontFp!("18349340856764570254875357271690106769333653675997142444108297087440025433976"),
+        MontFp!("4652091796588614730462648254434910913694389428280196479944835477366269369125"),
+        MontFp!("131910217723821243570751713964244292677069024546597890404591990585681690631"),
+        MontFp!("21456499410534986907068306761035738313492308692998775099405282472250364294940"),
+        MontFp!("16716064024995806321979269360595144737028204641252901859053814928024784155019"),
+        MontFp!("20095956414904309942694457361888203543242910622115746869354406017074786974736"),
+        MontFp!("3757071258857910927294547413264306476593820051587638010209583502745249383710"),
+        MontFp!("10923680137512329262337058371790257353876483628009769758330988714085083694256"),
+        MontFp!("9956537089548622902535815248396999405593511084482117014020012399528161074693"),
+        MontFp!("20271486778333058297667394153786073312398256884133810048721290976749349792007"),
+        MontFp!("14016317906581503528126751971528140956503207448983448742275011042750601060387"),
+        MontFp!("8746591469817011275278863986926266284079779997046001490419468813462270883629"),
+        MontFp!("8984522535046539605932734670718817033319009310710809111062522425202730307709"),
+        MontFp!("2799492130108020790397631531338556641981343238572990117018166640120082230253"),
+    ],
+    [
+        MontFp!("20327087348772938506861277218558621387616751140016505866350214286146319911488"),
+        MontFp!("16319897245400426733698423830406514849234661290508433522979200712395774573821"),
+        MontFp!("1519870284239742680329151132587900987246869312347047206488363104123425891937"),
+        MontFp!("16461017950418215698742181372949724919904570515241953684108936425007551365381"),
+        MontFp!("18543391801737989528567594217602323229839954429370908362237267818290566814608"),
+        MontFp!("8685602143546136106524472764079001485875727469672211204721272795643296788175"),
+        MontFp!("12069346074371335540240816613547917114412632205795651309433084773115960400274"),
+        MontFp!("11988670992502988905175891565316960155103243235237609851490650832292602385997"),
+        MontFp!("17403966780192169176887636449182981656483203656331951842717004987726351707328"),
+        MontFp!("11324032593816374026994927363702518223149146507370165210922763265919399924610"),
+        MontFp!("20234844998585751045105028410680389242420574978256032494303069254030260948453"),
+        MontFp!("6845765195018211653702320958640753289098798259762906597713727891164248375213"),
+        MontFp!("3889696987737574856084707743921432094490126858489993810406205011091688718490"),
+        MontFp!("18124922133262643422852054628088483763756251512543531233603218549062255836441"),
+        MontFp!("12631624196128994950514018157378748957581711189609992774676240830354853053921"),
+        MontFp!("16425963978111788432975031188568839119447517807863396285900192209489855884936"),
+        MontFp!("8953995026906183527591662774513923704717356851383827135468523305945599975444"),
+    ],
+    [
+        MontFp!("19118152579552440466145418131293777218789512388294771840750929877489494176003"),
+        MontFp!("1815764556579131181044414049066036485535218062641438722387702702907845125991"),
+        MontFp!("879360492005214940756250098489982523077085264774860932709313739626061471725"),
+        MontFp!("6498879898701797309689806511256248775170834501358785166173840360873803298370"),
+        MontFp!("6056271365753495672454589488039784798700896703812263056079587620516360143438"),
+        MontFp!("4967400286944517048147291964513981345417368809842939145340090658371340301574"),
+        MontFp!("4566978800722351857193060210384689061165216230883853755194411631523409885319"),
+        MontFp!("1472717723310974984615849145251472221350601734805367368974791217038995119259"),
+        MontFp!("13776027174095858271746500087697263062688345981919975914318610607049564847265"),
+        MontFp!("8855093701782146194984545464090688949611436896016570549019701846352658653421"),
+        MontFp!("18811023873792469394695147052306776708450724591075344787347859872893669250403"),
+        MontFp!("7691358114878791244762289651247071530769998349760928484062499608910793916658"),
+        MontFp!("7225113154421014531794800873967433806904006446284346811024995482075131277430"),
+        MontFp!("4255179040498688460969978231175134372259939403539293652133247789357971307025"),
+        MontFp!("20794639690572618510879417372961642037150870482839234645523330418091682849183"),
+        MontFp!("8794944058569076073698664070073889624033307879863301731569984292406059326253"),
+        MontFp!("2823363224083068189197562122730561866372340074232470613435703914053034396662"),
+    ],
+    [
+        MontFp!("2975786158778622849913792385919164957459418638496064741275725856836606581091"),
+        MontFp!("16980083616151411398660130004732794369729853408844917920819260677159874549034"),
+        MontFp!("10213122016673910048073131434217437693976054853300442236815045010440415530751"),
+        MontFp!("15622844733073950747464963822187537454489639825890383970141531456136711221220"),
+        MontFp!("18921846985235911245949818418983694297719932450371747402698247786766269226032"),
+        MontFp!("3046395690298904837024144793490039860680756832120703445515317729068809524596"),
+        MontFp!("2583060914190138727980083409436742233507882746342184052904895573386721270220"),
+        MontFp!("15629635543342892581496645526353345602210475132913669274084203765913418607483"),
+        MontFp!("12153931408209967834920681475366877980388294821029465748773772434628588757707"),
+        MontFp!("7196162362609954988171353261930756212243825869655325258155368337285953898704"),
+        MontFp!("10832344161051287447174100117756887153534049211780756324764320129746636797806"),
+        MontFp!("6499757948467889740913713634114407800462786362067580649690672122741467699071"),
+        MontFp!("7899568931631241171429470880810625833615306002278541085808972359443075433638"),
+        MontFp!("12736525266255297750005081634236953960899087644399368181751506194282329326346"),
+        MontFp!("3885354407428541945392073706505699854042913519198595640992547091062316682031"),
+        MontFp!("3816703467689192492336832188741146914005957253921702912680936907951936401119"),
+        MontFp!("20505128951308490293180094071215431973523299690572491020108425696662802268726"),
+    ],
+    [
+        MontFp!("12432320732362421179661978984313902638822551634634152631489842715192622778201"),
+        MontFp!("5512525665270220248672390077644266875095263411830016769341860810913920051168"),
+        MontFp!("10270457412178462574290846807202840698511884913948623978547229481110915603115"),
+        MontFp!("17881266377985198177178319262577725460740518218103427901653715311091974197536"),
+        MontFp!("8510066829287194091329244743814121446186389458433991751573612039633575413794"),
+        MontFp!("9055280011331581456260576278219343217137015829507887846588161405525910242827"),
+        MontFp!("8463744395607465452751391868824103994906876103054045629692996934328842962296"),
+        MontFp!("13446532739903962714217041803396024505862416007402976354796893648172348836934"),
+        MontFp!("20461627446006946445871657481203161690018232261049944659088120713658680145761"),
+        MontFp!("13772437540389795965930455187657216773904696937414401755764626097076342530873"),
+        MontFp!("1466290965505206997895149562488255231958570328271915401528301574657737237351"),
+        MontFp!("458020438009477539046680120243558779131470015073376206673948106465783075724"),
+        MontFp!("1421211327829956939723172049232055433330521137466148179271043779757165996383"),
+        MontFp!("10974718981737058413203531348845889514701700275404265370241257111393906385203"),
+        MontFp!("16573626183656890049533788707333278060585059246627869841186891657544526527154"),
+        MontFp!("9919036431890005168544961022934926297421650894480789723772572215746478261369"),
+        MontFp!("16576030844958446931425212176715038836356393446209694437953952981269181564296"),
+    ],
+    [
+        MontFp!("14994178560671337822305816896620048994236927709898591331458613992689693774863"),
+        MontFp!("14008584724223915404998855561309674162219693188120257826434623735246475372519"),
+        MontFp!("1069086166685137961564210887004146480746150199033946836704942515923278172866"),
+        MontFp!("4441018674700315637078726026786902627166868710369033622977939038672598925463"),
+        MontFp!("7640939046542807984747271754089880008702702616120100267707112408768750045699"),
+        MontFp!("8165588904763498143729494321532511422369875008004817175656306909794360343847"),
+        MontFp!("7474879615118088486232080639206966699381035482748779385536890548622822072536"),
+        MontFp!("9155173050525839883630603125759778497949178563635870467694197797566249638786"),
+        MontFp!("1792459779962272311746727109790955426562388793812453852666007819191763428793"),
+        MontFp!("20263099395174426853367767733535578420938395109027150658334981283108594115829"),
+        MontFp!("10303451485708370514931418998595531573075964003678715177508051557531947678705"),
+        MontFp!("20321308667229656744129683025590573929765537488045735596831342276888215355579"),
+        MontFp!("3624395650764060579161285692706624195562301326135878674105170566731046375182"),
+        MontFp!("13334418042222364794805341409419804498243524763983861307681793920401725352392"),
+        MontFp!("6303209034428307796195867505386756492498981454350686160404486917810670093803"),
+        MontFp!("13336041503866400337979868539402126587964669853045701517757148479437143265311"),
+        MontFp!("18767353303859794045974955054051240567712613481262860811042439117400294729243"),
+    ],
+    [
+        MontFp!("17648775923900705033106796825075257671428721760143056038927896765118752472038"),
+        MontFp!("21205320334769131061393260976939399463360336322318482845795119858952799244444"),
+        MontFp!("9155233455417262996613263565710157090618649015115334735214563627053911364986"),
+        MontFp!("4147290460198895367036286052342502315935666580918696244195287512520682701639"),
+        MontFp!("20798839743961894523110712994056132446749297094165852697406325453288254761759"),
+        MontFp!("10967475255107274575063249329253351284200208982490877243231819333935797701403"),
+        MontFp!("11107733429142106765947338633972307528508245023783319424287268815241476152303"),
+        MontFp!("1684156923230048092780410733570635056170405885844154942542264956876312136441"),
+        MontFp!("6785232819930341498287206635949744381666087373588334711760162570781902812411"),
+        MontFp!("3481752771358613640419060374040695972756380958880755418404724590727014534662"),
+        MontFp!("6255654521236333133437711371709951475374611142503566953029069344699939884443"),
+        MontFp!("9785296768537417566104801168235697242332552358062255540417443962942437906303"),
+        MontFp!("932526381863697246577606288678833611354323840639105626154693740791708621269"),
+        MontFp!("9020085732775576234837702925603013416533345825301814972240093864457581450960"),
+        MontFp!("9671990085159642158315772309293609967281764349933520964615615069622307368396"),
+        MontFp!("19046031964146698646372035717767563096934497588033260517438574042056341692955"),
+        MontFp!("4697021545834663993102099542459528774847962616007459562129439303924850728783"),
+    ],
+    [
+        MontFp!("834623291329335140949595387679815012162663877433757180177587164505466461752"),
+        MontFp!("20037517567993747767719709123741990248999315495577556924410214834773675677458"),
+        MontFp!("17745162106287481150568322325139792027305354960331855484108228857447789511536"),
+        MontFp!("15954503136290327680020013900783441236098225469294487261574644367048211928766"),
+        MontFp!("9447580075462399824000861225221012934395732074158377365412928734066137521046"),
+        MontFp!("17638109886139425399034325504572336884601102137123870982594354109676619973628"),
+        MontFp!("4210479489522190727435015049116453108500323090316045735249662201929219316543"),
+        MontFp!("774908475070259367041407977077109033779248383557848908386935310773126844186"),
+        MontFp!("18071521129238587956292632288468255723364769404447125651660575676226691230621"),
+        MontFp!("13782927851189257932376007280798546946814788900735661108027866834341111483921"),
+        MontFp!("9061904191092017185731693336948023564331336704118962360596629665269886564501"),
+        MontFp!("4646677737877430404232975108246717822854320420060743321121290725024507501530"),
+        MontFp!("248089340757097041959106969939801712104654938085655092524027449699183655736"),
+        MontFp!("1925061776519306799931873233357921480445138537754738082308417409427350959191"),
+        MontFp!("15090381222590604653751344079459745088601676165498775998808367167579158825796"),
+        MontFp!("1507946310710275058017347456880204243614713444138569740896198937197257762391"),
+        MontFp!("9047126832872244897061755443779135724439127934417426920025891305321906533199"),
+    ],
+    [
+        MontFp!("13210467659674398263347498463769321394392811495810195742972914861526145024497"),
+        MontFp!("14930790949584540337435206846889685669928690545385782178172828133028677953780"),
+        MontFp!("16709614617820458913659268544942181680363927832511699782451755998512279824691"),
+        MontFp!("21741729565131743651162167923641181853909251404044798652854243082519505872026"),
+        MontFp!("485748276371306614511019734420369335387299906747036897161996666725324275885"),
+        MontFp!("113729363155019628451995418785166919308643202089193856958029953269497359518"),
+        MontFp!("17315235749721747007692514702872348378105966945192310104719562066051041365785"),
+        MontFp!("18164826565342651411070681557540176534582615273713551079309038721773735887418"),
+        MontFp!("619154088023795178917549465782795919604596935887112076630902791174323663727"),
+        MontFp!("6356343581175745844660675205642675571886993827862033572086056964311839166798"),
+        MontFp!("9925861911435596968816749828945917008180837850750226489783169376408872855250"),
+        MontFp!("20485137494818996958862809145264015810010636121731140334074571507470008401090"),
+        MontFp!("19656735537075233905125360479201796779994483024563540752004528779684256455735"),
+        MontFp!("15774243068028338132223253794279808721177740456197787086161475053638867039504"),
+        MontFp!("10497591647442850110427658608196767909160517521573199077258279280018098065029"),
+        MontFp!("210438711366620938094565498037172584426960275594120406278610962685482768128"),
+        MontFp!("14574298645311491089278146576548733364636851707576602363349001846285614576119"),
+    ],
+    [
+        MontFp!("12543161131818467611229160618197160281328232851440846538778664249828945509460"),
+        MontFp!("5869885827840429160723685065960013290766384774919021040094074809404023704797"),
+        MontFp!("14800413279643792835835488664661954863340552091411990276270728475507101877386"),
+        MontFp!("8706244411264045072904148746698442560958145712983473320099851020229801141593"),
+        MontFp!("13670524729264788581176214636184652482954266319986131183163702474027069017797"),
+        MontFp!("6632699635952855355682293206130135801472538218093800086693644923578553649842"),
+        MontFp!("14292924690435752338909900819628889644248019746008463256978678218121438364347"),
+        MontFp!("17727807116656957204562694366530472131199058877798797418410969267673712196106"),
+        MontFp!("11223858699504492688371995874202967948461173433281557745471419331000190821973"),
+        MontFp!("15274518373659950909975149452889634174127158243424169830798483206460674313743"),
+        MontFp!("7047593141729299688704504157654237466438485917193869775780994636019451364555"),
+        MontFp!("3378912259124002728608820025874776626345774864595321854977438303831475863909"),
+        MontFp!("8837103908146248796259735965370584845236705198353666155001962958984824353698"),
+        MontFp!("11869977356793268256679742068396296621649101901864556682314737356997442919617"),
+        MontFp!("16469201342422734819465744813830056682872807194264521097865986969265941221776"),
+        MontFp!("12990791567002738838300315843749937126679083554067128201599773654099601117791"),
+        MontFp!("20519376488199491586810872596388233936561051835647732260479226405592285629272"),
+    ],
+    [
+        MontFp!("13549175857035221960740890286176570298280920124622104651989532035707272553373"),
+        MontFp!("15234870385685844193215578943145814211167163612264546132211506983633757513134"),
+        MontFp!("18704889004070339618907065844757112006583041625995006884582590095948666509530"),
+        MontFp!("2234955996987396568560381823250866120960007674191266942270485492312904973151"),
+        MontFp!("5564977611394684319243720881013051542355462582947649518330549023416464959605"),
+        MontFp!("1112602741380454855327102347931753030221494100363029829630354546334828994329"),
+        MontFp!("9128209482091812367673670394219617423875730198454831896130112252113491505252"),
+        MontFp!("4376101007428143823621574836425643116618104664163736787280388345260726375856"),
+        MontFp!("17331301745157506574267466700454501468193891305005371065208065202603272248798"),
+        MontFp!("21846545094192300969525954249087973697025055955155275797969191218036594965229"),
+        MontFp!("4498927631211901890366201669629694821005085404418517058539528045609417501903"),
+        MontFp!("21785888954457119007377380145673667061898185873161711282742721281162112687992"),
+        MontFp!("10412875728643419025694818649503070798945684772972009054835016566542500457165"),
+        MontFp!("10759299704717838172704330861378107927462309935609553476471308013349659622009"),
+        MontFp!("20590357061487044454315237834494568787816431390448334652405889873515373283815"),
+        MontFp!("15673836157910318771949663697819073723405442238797105611030274826598578081102"),
+        MontFp!("21475838557209838850221160141181337897147127057878049055974104365046170025610"),
+    ],
+    [
+        MontFp!("12207716311215040854533276425309298962214124105108870363296256769166507046674"),
+        MontFp!("11559647355273809434894669847822031984160367996613651160672893927347403624957"),
+        MontFp!("591243049503910187533030984285442752553334033673422383798814281778102029479"),
+        MontFp!("16149820605774446835537612705920133098873928503868848274855310057025837143826"),
+        MontFp!("17994206225397988293963936097869072606400130753097128779836685484696065715846"),
+        MontFp!("14255641384045583862403050464134422000560975124837558223693835399889249787677"),
+        MontFp!("17504545541397972120376845150557483982076571058727369435414053758625774050033"),
+        MontFp!("20262964605263856854671661755299361371513888427580858149435552591882491905348"),
+        MontFp!("12438123120680430355474353651690582027160630756728481836282192739044572633306"),
+        MontFp!("10986893609190710977332579571229244255566870443973548516403950419251545530801"),
+        MontFp!("16087745879258651861681909407049342984746351306967389910641236570806978994348"),
+        MontFp!("3161560484131968841565856280480697486595651138902139808256180482675228360792"),
+        MontFp!("13935262764632300233548760451251751405084774897832419843437366109926436448024"),
+        MontFp!("3163708356211087269300587340868486145250960085863061247671736451529273060199"),
+        MontFp!("2135858951319828747123196912541413689531634489616050528404576322173340406195"),
+        MontFp!("19499372732572987294622585729244698705235805453794292187854255591946466724248"),
+        MontFp!("17894089552733756400990786239277662075181623012822588150546768816557117698135"),
+    ],
+    [
+        MontFp!("10627476289794518149581909173158184680927686574280550541626159986648732476852"),
+        MontFp!("5936760260909841348473330607703047527686233710292277373302498592584078811475"),
+        MontFp!("1671632573007285452120449241726645612097503185776127730816609206067570503177"),
+        MontFp!("3240964061357526102193296883771275932317464053465161060634703562592821382373"),
+        MontFp!("1379991033533041123683674223861213511881534224092884787544607760074673933288"),
+        MontFp!("16612309984678362724113323611405699676953754495433780645121123868554119586714"),
+        MontFp!("8990362216544353251183644843626621823440172244041771554179888258848070990408"),
+        MontFp!("7792126163077137553546721412284336099138469839853005420022151183726709890605"),
+        MontFp!("3411642164274869168109174406711447272755835383047387248320765695217678712087"),
+        MontFp!("20744964603175609148844959505361532818311810796853446841217756183414690636777"),
+        MontFp!("15406348836110379416850862929702053477202461331674247559909297027963274533036"),
+        MontFp!("6655070454278595774182716706613320667767771244472415371150701320921454970910"),
+        MontFp!("5387596765852619562692588945067153848362301378074547750716364254552830548618"),
+        MontFp!("11374645815567953400996409579523063654937913407852397185357281079289695498202"),
+        MontFp!("4637259464430266322534397972465849540585061569384580106712860758129812888983"),
+        MontFp!("11664424120893756026105365780051433965998344366144921839621845056675798876861"),
+        MontFp!("12683173829762047157652990138425355617601406763777942452461639149511855161467"),
+    ],
+    [
+        MontFp!("13231456998217432692837253637279353598668867747754119281167569613817285339906"),
+        MontFp!("18515103011277984777089818507163076049947207220694092563937098383351447664230"),
+        MontFp!("12292599779221056615130747040145001893088895814628302035476053980079114062115"),
+        MontFp!("6616224505832681391261988520162269850555430131210843369761179293711760268991"),
+        MontFp!("9345928984249349577886491000914566117062182722884874880308419980252230631490"),
+        MontFp!("18529045286534814640266328796625676801335117782021569247528965805503339014374"),
+        MontFp!("9213994428894984896264727606602499546795637673535140817494171390234365346197"),
+        MontFp!("16046191804660661030536723745809610250792224521907784347921533311133924729200"),
+        MontFp!("20387305341543872348027149959300247251122586296441271309757521233850527145501"),
+        MontFp!("20660888328716295053746457587105952359847177180169600462778173036912653930309"),
+        MontFp!("20868619915262427287234582212448052350184310305114553346457821129256743588185"),
+        MontFp!("17663781345821195819131504897468846255222386958594579879767055754372925042002"),
+        MontFp!("18948627844717262137578097615546021495287533410031083974644721619152121684198"),
+        MontFp!("13155646636245265066188794529431453696019006721019099462642586385520674193264"),
+        MontFp!("11607002707400435612558884657345015071868104333677950253781252883531031962578"),
+        MontFp!("7814494031495579790510272464332992505237344345380518199394208941034262720849"),
+        MontFp!("1133679025909186037940532130396250706184367022550359228793807250960294591300"),
+    ],
+    [
+        MontFp!("17684405674061959945309711765066024811535672808158415749778270434181980067051"),
+        MontFp!("12226477960064982944626820350420332900927073584585614331096659786290796181633"),
+        MontFp!("19811925349932047710573270972607617621205677733103349721011497772488325290731"),
+        MontFp!("8648978019377605720004678083600719374811142735403518791266825142192290428262"),
+        MontFp!("13576053523641184415871793911230224707049224969614290468646557972152185575110"),
+        MontFp!("19246597006093201923867388663361027928531131674594730473459152193253618076466"),
+        MontFp!("658606426123772934076319360192555383963003130831902899767201848799898493860"),
+        MontFp!("17290137813713243852250776166370982880777712118107986170022122607636402519519"),
+        MontFp!("5468484217427333109722188824068218191654858622412608074953511216209386470685"),
+        MontFp!("21574260030527757195923820887880669668169386788465310192452085714817280961577"),
+        MontFp!("16977301410295947721817774534383483730974509825154556215531376468755351549427"),
+        MontFp!("15733754299741726976786941703016488589821960074385924955118036049474131193882"),
+        MontFp!("673051665852885808394122319132734442090810198084101653348055105938368910407"),
+        MontFp!("13398071607416834058601862099131647257306816396251547336997555114713122083633"),
+        MontFp!("12550653745423625869263455159672489463891823157792786197798074997479171696837"),
+        MontFp!("9401077435832768325771683033269047231487705804464911158715103022642914069963"),
+        MontFp!("12470382814922435426465904555965877134678525017061716145587676176477156930917"),
+    ],
+    [
+        MontFp!("4934832010550666003820836613445526232712748207490447572633148755378222021432"),
+        MontFp!("10147431980198881931337046775655000300780675339813128987464503437796530727819"),
+        MontFp!("568707585724749374908018432889236271799675619271430223486579915440515081761"),
+        MontFp!("7940313483819289305875198195901580599194224791864182697672727755313391315497"),
+        MontFp!("18743990138882019355059105644701125600589318429506940478381096888059645669064"),
+        MontFp!("21390103921503672356366200239425679587351838964225860732507169466542370471389"),
+        MontFp!("1162301008434626626179696713526875746106593765970497860274277780972785074172"),
+        MontFp!("20866648005214775989252883040721823770262915606193206494879341544251201195074"),
+        MontFp!("7345491533727875108206436501195735628498565230546589655556895625258093485858"),
+        MontFp!("7625514906338569732343456767807169396379912415318224530910677456500209942160"),
+        MontFp!("7862757427463847382674463970373593668082249748547169027835585420099101631793"),
+        MontFp!("10678296207725321824311910857643347417220081751912275071080081222106552722672"),
+        MontFp!("16952109495603736214033791235849594583684019147749856522239399814261672810874"),
+        MontFp!("7275820797336428291178396248364001448190236523398157638561627689896470220284"),
+        MontFp!("9969981466756203881447261853491208039919719371961809442805550510895150629471"),
+        MontFp!("2820683912350770819480104529177445624170795283688064543451455124015297123761"),
+        MontFp!("1768227783012842108298280205661549942041342510905211540896799241998425991810"),
+    ],
+    [
+        MontFp!("12106377471628405436369258554037168031059303858931832122655790690193581259368"),
+        MontFp!("11133684149892568979034305171877692043755100938665306193308979862319584720202"),
+        MontFp!("17034299967559262110637856726685484180103963204901371145115468175393665761738"),
+        MontFp!("7846191388095544813700988786315012990125413238605101219369138067522789941990"),
+        MontFp!("18501907765236851484806470528611625980973567313044760445349490286993727971054"),
+        MontFp!("1249778578878632628279160684089277562912849600486367726058714364522238473984"),
+        MontFp!("12800296338215947544269523539435530627816725976238738793426232070819346748361"),
+        MontFp!("21158276531398412025848449677754681262986399643402199704435277598413275051402"),
+        MontFp!("3445704611858969368326297112584817848554583085326694642313172161045347990986"),
+        MontFp!("1096936495127696656709715702297708886712481387809916555366077657639639717937"),
+        MontFp!("2595620917275871104056107465860277106075411236679259253450463497473275008081"),
+        MontFp!("17133880510415980077665570077127522127719512648188691671645676431813033706096"),
+        MontFp!("5074434632642876807560017812171878490042102579785821263047865225182212462287"),
+        MontFp!("12326838696626858713891707574688276984801973627108664533453869827609207218879"),
+        MontFp!("12304708188533179803341734069046800825101691162690698708386871234713091258421"),
+        MontFp!("17039317314373515840113235553933676165005713953680758739139458602566292834442"),
+        MontFp!("17138392904367957911746841143250516587142345504139102606501472531272348644184"),
+    ],
+    [
+        MontFp!("1667176916358419631869035157338470222219117704099667397276080580945958414759"),
+        MontFp!("15905657308104601519308683078473840880633544254678278144766436492794698882065"),
+        MontFp!("5818790161586099604498469370871887484543304527772047827828829241332373438952"),
+        MontFp!("12442306790659206506330372960358262675060765197848150572966156688729178055833"),
+        MontFp!("8830924073341698588202301965823165752475570241349344118304602842583809330430"),
+        MontFp!("1875325218295968167735653385555759668710537053646457950513379995102733934773"),
+        MontFp!("17881009017973237557824378556848686261064176014957336169110006388300690909574"),
+        MontFp!("6685909350747636031504999384308339032316756393531361646826148497177153343308"),
+        MontFp!("4784523754559868056092042886072700730690109555243230003862073871950864412709"),
+        MontFp!("3579228774281988929604104014107948733265891035249209872698544379599035221295"),
+        MontFp!("4279085918474431382642565261103862932684902464862097608738233691705350921017"),
+        MontFp!("1137759862158982763491809087349360013612105180026300214422560224275715429456"),
+        MontFp!("19720837769260646491270657488868445833170057293472427873975392955686128702328"),
+        MontFp!("1718964391395646738355239526986888311027176887298320241700031309916500343965"),
+        MontFp!("19881985451679395586972172156300827725375888727627109004411614649624414408826"),
+        MontFp!("12172287315747539949563617756773178635584674160408496477097950831147264075213"),
+        MontFp!("17818348202724817301996509561425729131915819248800408750587580377834731903089"),
+    ],
+    [
+        MontFp!("9526094675919376961704973755217702642807170426879590367299173409422751261551"),
+        MontFp!("3828510101756167773639196204933352052436416699730184799283196091112205339599"),
+        MontFp!("15234684891802383017182920097366274346299756278272648689423306851279308385365"),
+        MontFp!("5259340948631483055118623587523845455777103002480150827336361752510452569472"),
+        MontFp!("7522204711834833179288206454714805063533565847207420606996829544233502085239"),
+        MontFp!("4461024416231372224773966846126492961855795819183621636555920813846035684924"),
+        MontFp!("5024379057555899833767820321243484415757741022843259764504133153673503667684"),
+        MontFp!("1944348388731771850836911827979470257687115271361319233348322098245989069259"),
+        MontFp!("15065306781743278475238637320889039722240861069687500843669623160807616263519"),
+        MontFp!("10185565710140214717926900268233420932083773824278146477403635012592608678058"),
+        MontFp!("633282989982188437228855410820528038963373266773393154835968536206352769131"),
+        MontFp!("15070609296388507673139787908428420573100959040320412658731328587817279331759"),
+        MontFp!("21412207865606943642342520185052032190311633739645180935143927525277916223829"),
+        MontFp!("7878150466166009547688919693656375135045159152200952621922018706527536944814"),
+        MontFp!("21647238538593110963647188691796027858369673773306895927659346354059471769993"),
+        MontFp!("499258196791436830394876102694379667868532135110557694409916330175138906869"),
+        MontFp!("12884932140229984175244344672594504633237231438569204938753218905028898420879"),
+    ],
+    [
+        MontFp!("13897088195397438949124517043146998392030835253375096249186075371152838123957"),
+        MontFp!("17292635307267344947001814307009165392877577631472640481991264040854674668918"),
+        MontFp!("3402459730835246576414179745580422332982133349475000364518392993042878622965"),
+        MontFp!("16196872248846772839551997397431726113069640679308998300903092440097004592538"),
+        MontFp!("18016286200009868237596690430345509415593965676022902141045234608328285884800"),
+        MontFp!("7857847074246536045428962801424396151797565515320554961976852417962849298458"),
+        MontFp!("3893665144753462156875073379241733664547431241880239458489799597153819411492"),
+        MontFp!("13248007323269384117634056450668957554780628822533632623855150478480951625390"),
+        MontFp!("18276875079677833610517929467816562761552054119550676368999897123627796943528"),
+        MontFp!("5189710327122023109841696471286837143455102090557692433286249568240177726287"),
+        MontFp!("7945689534444199447942235389147404638251522098536584100372965245456120158597"),
+        MontFp!("5423767002711680999240727030908088708424949959926318146878189397264151455693"),
+        MontFp!("5995072902526586585223000514086044396450113354893476089124870631086600489121"),
+        MontFp!("15275396095782853076485751600594247065910139735226081476243182046971110416839"),
+        MontFp!("7337085477458019807945256166142271568824298087615645600806448794391563720590"),
+        MontFp!("672727225441339286039441508031715798485173015033624765579287288711179551652"),
+        MontFp!("18686118639158601820164163991883068337953517102808111440802394108699280438488"),
+    ],
+    [
+        MontFp!("14028695600256037576881239065837481650658243799767033132853744776667874365505"),
+        MontFp!("58443191205451688703601063117164997697541381759347793811443271650169777861"),
+        MontFp!("21065693836733911343227308560913091721048742432913504195640605496691788221566"),
+        MontFp!("330731975523362608415899785414131718097488715216703281999950906065685546389"),
+        MontFp!("16761217887229483694018038191674783405871691887163059178481478503168342732832"),
+        MontFp!("3038029821773816605245101973977979223409779873144308602734440877027458504742"),
+        MontFp!("15321291577034359343843366677170820527712726038561116015047816977743924220386"),
+        MontFp!("13707411308735348097061010267850476217721836580681738128758120478182032058809"),
+        MontFp!("17436870236156178819421588659602200365388268058796067042132167176029472760360"),
+        MontFp!("6398050943215095842335686342334715469621392080870897191300696782676608765887"),
+        MontFp!("3437851705781701539278760214561262405409371984542409183492392158766597659391"),
+        MontFp!("7124479506211457523573057217353911508610466081033238660465711588737967142974"),
+        MontFp!("17234798882124088040334413616145917195326697085511927239835381731514314303940"),
+        MontFp!("7189678840883577982600011373449005624085144056595391210996212544957416984457"),
+        MontFp!("11759897219791977703855743039753523539327151517291958119140418872939095386327"),
+        MontFp!("20863399992661754827674386311991156323060622036945800022666816987897813665853"),
+        MontFp!("19303856056826570957071409328135004293560711503205766767776395391371667771674"),
+    ],
+    [
+        MontFp!("20145537319102686898024411390314804436660254524742986363357188082440068405993"),
+        MontFp!("10703774427752668974576334932100051927133058205544953271144849898179475571339"),
+        MontFp!("18572949224433194996605971200517412011627412770605201537755920772491000856992"),
+        MontFp!("17216568647787248412779004183002114283399247525457054305327730013550616639101"),
+        MontFp!("1149230406252660804924057240581611320485721839789360487426995484240018883527"),
+        MontFp!("16555038770640675528229594153638806495397639088923421322231578669556920716448"),
+        MontFp!("4570242682115771574920314226681534421057130734296724983202350760375592356599"),
+        MontFp!("17057311432878064305365984063315754288324488570316135529616408791432610322138"),
+        MontFp!("10528094520692277398437810246572829940408932590689365643375030258637643836256"),
+        MontFp!("18372709972473962897678516007961361205643396632377488479769034609756086158014"),
+        MontFp!("21838679058944462510227588871439974934573578287277780576996692447397949481484"),
+        MontFp!("1580935194501439869947304184720525369376830253681649968786933642354687903995"),
+        MontFp!("20467445367185359735288490209436765649401864859115524728703996670394981839178"),
+        MontFp!("1991219687938782033642326751499030593324853839359574632972633310438247709458"),
+        MontFp!("1439755133435743273361732699887115117694558270034210455263872898360142260289"),
+        MontFp!("16300147327505011303454000556020917254890545519131742846543658162285928042977"),
+        MontFp!("20520490665047234670156871323008670381517271529350180242819417364083994994438"),
+    ],
+    [
+        MontFp!("6542862810250661443960454367170533117465189617181697143331232138215407738369"),
+        MontFp!("1321343667823388542880641322895423041177577518845790583045290901719235409147"),
+        MontFp!("2442599361719607622459103501177302579953568947914600055748918886479950617614"),
+        MontFp!("5868547468478431065596263697373807107429411676393246849408309043501871198941"),
+        MontFp!("12236312006031079186182743334640941452854376343312535021115608217162266564748"),
+        MontFp!("10096437059927455034494932369876021874913717336999122002472353603141122745838"),
+        MontFp!("16855287129299048285700210681474924546365514190517193678115189183373112094664"),
+        MontFp!("11157210691115027557284750949314979990011151769293562411757900634452166569473"),
+        MontFp!("12647512288244490046752876853167515938997386483355090398954955982145642073003"),
+        MontFp!("14545106158760908532417185649648478400561191409850112377631214176889261943702"),
+        MontFp!("13380884028176250201501329354617948687842379239413597035170382200236519084210"),
+        MontFp!("1405533288610146305353614064658402594063683037599662723217460924638972391780"),
+        MontFp!("12520645345847038927033831419634817247041895425958103228961545058464030447700"),
+        MontFp!("5466316270148149873459535347452815877745317540692907701390798166221830387727"),
+        MontFp!("5692512917835452114275964699861010134159019192212203758647174343214228494998"),
+        MontFp!("20406430429993275527714460195190469680616300984772360022156779114506307005915"),
+        MontFp!("2464398587529067351284637471629204475741470377175139272463319164375422871700"),
+    ],
+    [
+        MontFp!("14514168707742420576792107362849792246555009352726620152895701096554575857830"),
+        MontFp!("13270866810108509432782712956020266539382253503195894711776480924657007904548"),
+        MontFp!("9134846497886036320932877977667565061646780167819704730891157458029677347762"),
+        MontFp!("6968315929580902986583144026090679082271496835598861747834541738159673277903"),
+        MontFp!("7271494204378938515839411341192945686765403888626619615653078042877068436796"),
+        MontFp!("18286507248612017881101512297381359645934672218434256889911439358758424747545"),
+        MontFp!("11110097749119198784554144689049124335810208369471373426127558546444086832925"),
+        MontFp!("8247301320031025187145317669710241636727060646792731418249820159438470005156"),
+        MontFp!("14294937643948997192288252800043984341826315689719836039426003941357321500134"),
+        MontFp!("7348709417616598268612977306891011618520420209855159927526151677200518293152"),
+        MontFp!("8241665213979757536094384437357490949853796937135356315008833005380426051194"),
+        MontFp!("14613486093064180194908604682667542845871833534699460114586605109597063839130"),
+        MontFp!("9113009852475701814533040575255279924173382591876828814441237692300104301457"),
+        MontFp!("13165038554179369948431676356672208670169443113631826535771459184335356285593"),
+        MontFp!("16086269628133108266869931705626389777530735876369884384351431845165268738278"),
+        MontFp!("6087260580031589238539099192443153008156470383537608551794229727048294996340"),
+        MontFp!("20512862830064709642749702619534843169107182362836772285105007708471619038567"),
+    ],
+    [
+        MontFp!("19533181397104762456673826441378799870077181112746023293729567449839481548356"),
+        MontFp!("14705683451830296925170674619865205995188883759493716060771015969186134607704"),
+        MontFp!("12605792817445608495860292975716815181045267213200470818664710066347176280047"),
+        MontFp!("3592996587655107072245474938139935892949344064921754274126873928977701118749"),
+        MontFp!("15294232235999070733030126460937359944041996136808334336982336855120910512334"),
+        MontFp!("1582309023481075306597300823293668340335902140969083485284345214470852671798"),
+        MontFp!("1088465469116422019323930771299807477223547422484605269302564104323452973509"),
+        MontFp!("8883047416031049757538663266985855517015256170002963846635310997205392683864"),
+        MontFp!("3176854208559033403336658900147847127925792493324423188639056424099958393072"),
+        MontFp!("13232382305984866276823393930594443402152224642505744090163285056748376747918"),
+        MontFp!("2245408257366834949733190872330311995971080118654860159924241594642495281059"),
+        MontFp!("2379830947280597194685159865267943843813010731722978284837340564719341924509"),
+        MontFp!("17826177593330561305441136255554046830158210141415356927118963102466292013052"),
+        MontFp!("8247294876117518524724460400399686295737179004092227538368916244669417581296"),
+        MontFp!("9784484010004736296495309204028701732510703011300894445280176432324640320289"),
+        MontFp!("5252332428538395620048044694806285484695976570037776918613895482295008833982"),
+        MontFp!("14121980677685920870338206469007772221567007585360563336746921691628879549127"),
+    ],
+    [
+        MontFp!("6873280017888024918917070095341125311616790755139638467124027157089622101088"),
+        MontFp!("11318363160576645628856748485098328456894971803038601852118504122294284627221"),
+        MontFp!("4121152361595345093207401693369225144531013563993157593094962034269624651820"),
+        MontFp!("20683333802753484511610127216996129924370396812165897253170891149253939360760"),
+        MontFp!("18647670031404843711327708343249425853609227031502475411522973225042672187860"),
+        MontFp!("2086657429367670063332013344180770222302417569271803215009583482264097874504"),
+        MontFp!("19709435480590614483363007409253356879041585154641286906159427713982563792526"),
+        MontFp!("4258866914660942354120395242129829740747833749509017072799985182732656616800"),
+        MontFp!("9040566395371463976833369354842616182669127821598268274888838547664814843928"),
+        MontFp!("4116084355554837400529216189356527577376881089426150781634827262283154963048"),
+        MontFp!("21605169971906188704396331617487188526616554159276096155817564565239435978416"),
+        MontFp!("17937211187232154582564924643486965895957558838414375726168318393094228123633"),
+        MontFp!("1992558685702604567028071709121593075020313087150274357559040691997376317338"),
+        MontFp!("3218330818271934662263059558699096796852863135305095433439395639068666897513"),
+        MontFp!("9251019796910681440348368762961435892628247766190360978557679196269677702320"),
+        MontFp!("15836693249656382763970061319175857247542072239256868703809948219144542014793"),
+        MontFp!("15037961154776922819548802422798270596812938540093547487192133997609110330498"),
+    ],
+    [
+        MontFp!("2832985667948889103643595801116542064299718867280064733138075131374461684249"),
+        MontFp!("17231308893316371049725716015653771498326104062757761825930828499226120649063"),
+        MontFp!("18440767123713815329857061224743112764715289422724359826699690594664366648275"),
+        MontFp!("19107308066370859685275861140608997220650596581264375407813390731366162034891"),
+        MontFp!("5404929079073337935336499214267301362161341750373615311934457105389796446473"),
+        MontFp!("20619481508117738469430272904911772560595466539395055218439600469328451867796"),
+        MontFp!("13952701312362872281963137538185820474584748980975982879243876933687953690941"),
+        MontFp!("249746714007340023576169446261897246896602417046451816160506088486861113993"),
+        MontFp!("2031064645897563637919423800257706696891919492795925079196751344512608989440"),
+        MontFp!("11565396752616415432819810596660051755078386394477458037668058318114060397110"),
+        MontFp!("986400357247356567494096182209394879745469890962659581282966363140769490357"),
+        MontFp!("4537710479751915891784323654735525989928281714716787626598475607721311119956"),
+        MontFp!("6473679789570545843051971615499250002895246840329033388322918048865214899581"),
+        MontFp!("9663160517547239942952324390296932496372339501369452594336052141919689254511"),
+        MontFp!("5816463053578002459222339447164819661176549623835281810359748779077848682107"),
+        MontFp!("15884891878740533639319804312855499125304552172182333174674400304788572220390"),
+        MontFp!("3033403576169643300191876532878569681892028128211422158671974606431741064057"),
+    ],
+    [
+        MontFp!("10358010399523377550281291332476224381854765291561168350961812145251103430188"),
+        MontFp!("5609119676745360689177377631509255444635743595725402489628064332010648311979"),
+        MontFp!("9629834817212476647292649072966526100818069994746003213667948679343174132340"),
+        MontFp!("9902671572518009781416980720459757101464549561371827438607346753254097417791"),
+        MontFp!("7861900468190840021147507239605680877304969442537895993247780719118539599806"),
+        MontFp!("17965713539282108900380748546130491121922627970953515439952089259237956451418"),
+        MontFp!("11875127160859896409551848475414052588524539216162668112975117611722753563101"),
+        MontFp!("12036060586969767703747823124372554748937989587973573397651022172958180951726"),
+        MontFp!("9687479700009208904244134411901361698716242095445472067017865326177763818896"),
+        MontFp!("9417881715546662771138833677126201059337478279387740105578558474324645551658"),
+        MontFp!("1453393784429512178361095160478955627301424316545464923297558140633602643591"),
+        MontFp!("3605661850144345838926820161654200172773665263094741288943401786929181563752"),
+        MontFp!("9633343945573595088617177094944240138296472849064303543706399640795820973418"),
+        MontFp!("12473139325003262775761026452954333162901791070446569863302424457084077761149"),
+        MontFp!("12050383798910643577317544771125034193641466979797280393384224161250051391008"),
+        MontFp!("10819280347517551331483926007074671789351833709996171469616831904311265033949"),
+        MontFp!("13165812145639084020184122275986846677464323294523670942848342958951658208097"),
+    ],
+    [
+        MontFp!("4349080070868874275277456883424617452669491031060808106496345174056753179120"),
+        MontFp!("10004465749978899491390807601888552616948704671794075232666191099858337042324"),
+        MontFp!("5250553500845345497570122559573167112673420661316457421274200097450990672373"),
+        MontFp!("12898588403041862218453963458103462644872308284833558427909835998108927951654"),
+        MontFp!("18081519381738296094081786403175972446703155704846706982096034153576188388497"),
+        MontFp!("17428269008435424731165207394539747495252057789950545989706433801022598432683"),
+        MontFp!("21363561515458498091299721959548254375350819182606817501876503845082368630248"),
+        MontFp!("7730068437198222255038306872157350083702057866362961468917603557490811496832"),
+        MontFp!("804393402354592729694369925125379986785009558185994901175413585942989446396"),
+        MontFp!("5734375191529745066389276413443500729878560514347145222515433157787509440061"),
+        MontFp!("12298550311942812907291709877922367696701304981384797706564000787234950045527"),
+        MontFp!("11599955784759432052811066625229516027776562566066732630526030964712154732575"),
+        MontFp!("19052852164576098666742966317699449063762547007283635113650074658720294958279"),
+        MontFp!("10025188726400272980943623464270412609902684461295237328964275116870569460545"),
+        MontFp!("6716809200457040320003933672999684025455855713745782618771058247491604111902"),
+        MontFp!("13265049252191301811995103374452481986951626617983097109447548540667223415855"),
+        MontFp!("3243273032455921996687884516398249181181318053856384035604616213401999420878"),
+    ],
+    [
+        MontFp!("17596345835810824358133183122550505795231799288513594802190425260560699653345"),
+        MontFp!("14128892069525759858946235743223382009747348980906404699362919849705747120829"),
+        MontFp!("13560912558482649403704338887070267536467549681310240991684055262800855969142"),
+        MontFp!("5146650134021051734120451478394931677954544403196703024645198760974236051136"),
+        MontFp!("3656624380537215154312224820978463973302627823287220718508509926228536255064"),
+        MontFp!("17192240779422792861455260231600955507004716950247796731699861536149977973585"),
+        MontFp!("14505892053752720060006987522044578502315422621292564446333307406645448977523"),
+        MontFp!("897766293354199284998764120885006378362273185992111179631631299334090746025"),
+        MontFp!("9385522526111341509707107108914634969549440746118599228963287393277576591675"),
+        MontFp!("16755551931355332730912571473383446100229118084434859591641172409884703288623"),
+        MontFp!("7998487350537565460407312493288045720319128967533762889043801066609578748620"),
+        MontFp!("7253071994474199371350196978282275858691584350754099653147319975858840509065"),
+        MontFp!("1574915908528094850594155669399292110958198100218448518346743116412237429711"),
+        MontFp!("4964033462714392747475438670479181630637512997723146160177292638427948047795"),
+        MontFp!("448448106178274062085484969210407935775566171618153143848162702130236340948"),
+        MontFp!("10632678501409460480908899293916037077432376165397750836428181066894132950422"),
+        MontFp!("6560185425582299240893314836102079088239101938875583740502225690151903320396"),
+    ],
+    [
+        MontFp!("1579188680045650467520863974543764324416915154559115370865175124462148613819"),
+        MontFp!("7512915739904886375944428372792517861202973027020854741625268080946313568103"),
+        MontFp!("11437215569201917203849322845056384272839597879584906569042314064918170663914"),
+        MontFp!("12242031850346916661228168797937142001106743366886274501414812437377823138597"),
+        MontFp!("20754147834828558255955521879849243373047193258439455093561468286224927742847"),
+        MontFp!("8071694345199003059727834592733443749021397716021823212294503054151589204390"),
+        MontFp!("20008195907609188950845942022048531038227320303695709133926612651814670959266"),
+        MontFp!("3407176450804539102225412244906714437508697739163709736953739213204054495282"),
+        MontFp!("7164733851792769372626784323196646242013517015794517414070184073240948375536"),
+        MontFp!("9905209321913745841790507726277960404981926942651589731149669818541827874214"),
+        MontFp!("6005284264922151317117707687482989323446281090296110762814732182741632133904"),
+        MontFp!("3429846891758468105169655789950721158959212737421209807144772069541192906919"),
+        MontFp!("8594924739933231799186811828034272621585362372014000894229076451226180774305"),
+        MontFp!("11871888849049325223331295756953912508046243621607006393548707001434995954238"),
+        MontFp!("5685905463286653736673250214945816154927032180553041236977187605699044205816"),
+        MontFp!("213743477607202689216486321834878291503247072102535716407202454507834205331"),
+        MontFp!("12562677380228777117621213265298559705423375836884092254836487933903100167894"),
+    ],
+    [
+        MontFp!("8185089847800873546122723865396073717154949803733075710451409261160174437901"),
+        MontFp!("6833793650798095878312843740119765700866687688998823144083128641706792979819"),
+        MontFp!("4953313804596399497426456604424338320630850621290040554640752190475708172557"),
+        MontFp!("2236942430605502498740725704270029328474562664606378240692747298661887827319"),
+        MontFp!("1513449164658866312019182303051862147479160906256039744485216368678117608853"),
+        MontFp!("20317977663255757433651259494490740600408545133225161089258307793180016743554"),
+        MontFp!("2805473554735433677734689688066817081805362311169954998301659824970436339447"),
+        MontFp!("15815182380045621180846286591593553907538664337286124104922652024183257765519"),
+        MontFp!("8995040978147557956715534525198883385024370476405764975921649687446895470970"),
+        MontFp!("8204191497925877512228406532110351579276808609895204619986274589454615200331"),
+        MontFp!("9804350573365946552974871840565664742492437387015600083520724662514547605734"),
+        MontFp!("2670072936416198613788578252423721270352613415834375050844481006480844299140"),
+        MontFp!("18981033918047008332984286244333212532097768099085053122971946780791175897494"),
+        MontFp!("12954085820551020895974547504930170442441222377530978192526624489157895091072"),
+        MontFp!("9097838829535778653934125096834099047122481765699682650259018742771271485024"),
+        MontFp!("7412500154264631610614836174268215158855286123479543445207793653417339063427"),
+        MontFp!("4570719057023209933442917712353770885061956176422382707340131424665352082829"),
+    ],
+    [
+        MontFp!("3764270938841013974016907794451240962927342721628880682930414663597308799660"),
+        MontFp!("3003259409352957401069823305356475477293574843963128589242837442372343327078"),
+        MontFp!("4364045643562272249326662669199826714126432097046293502341074915354885207241"),
+        MontFp!("7862497546139280137858115009924456872495405978857024048016201451567691552233"),
+        MontFp!("2320345330709275050815413684718297588549014329479521210951823856644277128291"),
+        MontFp!("3472052133118830373581093491758594658278444460214482406644025496494813391646"),
+        MontFp!("18719780395522132269892764976785744172160001291971824246311191396996341879998"),
+        MontFp!("2052034405003094013719516407189467871257070713148889132320853589752948812286"),
+        MontFp!("12668293034393175159240347623878811740389002104040561234210863028172486003814"),
+        MontFp!("3030226117016103704707119677479690730613220303512226016348578882444095217375"),
+        MontFp!("10328007796868265401669271360208572151985515933147061282836598786351719442924"),
+        MontFp!("2936234154384973501504058165728446155253408008794108135203900159696674018692"),
+        MontFp!("488196422923618399001126311361138933209773385094782255292621218871598725659"),
+        MontFp!("9986243106324062707533627601692123931503570667062976466271294044444369357821"),
+        MontFp!("16946417777163299571223971859384083494444080413698624920773661721819621003999"),
+        MontFp!("6290655646787769345150128237305014645896272983703662862175950979175390099996"),
+        MontFp!("21576354856650767064171440939276202866931509433119578951322323205119147402280"),
+    ],
+    [
+        MontFp!("12396222895516500136715256668686614381644469516081823538030375243855459590807"),
+        MontFp!("18323538829312240204982678233417444895412920429922269434090001873145951634449"),
+        MontFp!("5312977696789603985280968610327711512128871567270265803635161870118441769141"),
+        MontFp!("1256303934933114233929368123369287749699533819478400217199836088113619997588"),
+        MontFp!("1766722289253438664291189751442574085464717822269757100835532731400395828058"),
+        MontFp!("20358149792139162776765514570327167438522971057031059964363017830738523560815"),
+        MontFp!("18792275959974477341072288096653531684536236050557537981692565367846354045353"),
+        MontFp!("11262334644528432796511003492639130680887823443747110676752140224527884546026"),
+        MontFp!("20501847923202184923710061886495102946632889060597711856226126913397710337656"),
+        MontFp!("2407267120907499137598716355969720653158717479075795831021615986085480804669"),
+        MontFp!("17307860167097742136268530831561503531461792239855909861554172148017416960805"),
+        MontFp!("2162289869674344221546800054490037354766673860343307342595970088710829183154"),
+        MontFp!("7028199420603561050998184473139307688291589649001033510888359073812068249126"),
+        MontFp!("20994672525924208572413211399438489393060949253402639946739540459305617910138"),
+        MontFp!("20173431861606460093483867873896693022082731481398426039971589468498814864556"),
+        MontFp!("9008871249428555602906292660739315591043104408371562099899839775533895523948"),
+        MontFp!("10700805877647067083283505504782153003756758200729717834117016178491024406477"),
+    ],
+    [
+        MontFp!("21112465741764258732843126921665151348166174697732269978267747373163853108430"),
+        MontFp!("11434984188666323224207642407059759324684918143122828248101482207244887988138"),
+        MontFp!("19774670032646206729857931401475084640123741797887602999266543966674579660422"),
+        MontFp!("674655537444683637096570248236990032160746930820910298495859277351312061534"),
+        MontFp!("12399952541366504233794506479162333424641228812125959815028858786807335765308"),
+        MontFp!("10787188444435222346993386033929793480957373636649543232735691292731739491178"),
+        MontFp!("8444484958726309128422673453859105535823575309761203789528942642797742183861"),
+        MontFp!("6178443234860827296437957284319327879259579261423150811121652495400576061977"),
+        MontFp!("9312686778448920745396192252021125483491892057300805307510293202738266404216"),
+        MontFp!("21035346041382930907137468070148126300607151584874513632902588011146411628958"),
+        MontFp!("21595380874048139145422335028184290720358814546434544789176692636485866078352"),
+        MontFp!("18101046998013962303174610041325057852573934184601619434223015638490343790989"),
+        MontFp!("19667939085949665955991312921627801957163412888505151951175612729243786613781"),
+        MontFp!("6926779782333484660471017512018931263032231687078574887001518725808608895509"),
+        MontFp!("21080823663408874605869582733040113428551737099016002655485931862299065393278"),
+        MontFp!("3655825253274650356501864672127621389343134570773587805971056417535174536245"),
+        MontFp!("3388541056162402337902270805874665052329588330241925030058121260891155159271"),
+    ],
+    [
+        MontFp!("21883983703980930327495083710303031860831358299523823198355219501906879066592"),
+        MontFp!("6926045735792789706902681099796161456533517354066439495586980016226331896111"),
+        MontFp!("4122959111197116982155867189740721062669675590322734927703841118527609239459"),
+        MontFp!("13219980757163558837707442934888299444190968070207889541780648207183773341011"),
+        MontFp!("13812897721847878024718349018307386755424010495939938584588468146497630813114"),
+        MontFp!("12798580293550179822728288481477643428051047081525754174406779865733869649777"),
+        MontFp!("19217125674525037886170888487423869066614323854468572412518413433041373346147"),
+        MontFp!("375553361556668826677041847695421482571990138873344010326810282960129792132"),
+        MontFp!("10834658340690112017927173678735250754641576226700757836412274044036729618775"),
+        MontFp!("4000963705080416713341998395751411942820883040862140582570351568343523301185"),
+        MontFp!("11715758996898666999645486245570559500678491132871745818362150491793495427655"),
+        MontFp!("4263507095725155156517595569654198316584673531155392198157637482244808941446"),
+        MontFp!("328008196666170836733724513867841384647787752809351062335576481522598738696"),
+        MontFp!("16052279110256413457891846976681654137774297241350913171330588402386482933504"),
+        MontFp!("8560435921497695081829647680343824474942212198090196568605135998993570233835"),
+        MontFp!("18716362840678189592322462186141719951790452060116008532478803871048621361376"),
+        MontFp!("6373296854260461347237798330630093524006414867838125038061282895929391886849"),
+    ],
+    [
+        MontFp!("18914705717475623119982002205679946947799155688028604423250694242332863378659"),
+        MontFp!("3615848956195277523544974986464432491666056752600413462508649484050878259586"),
+        MontFp!("14319383609652049721947038362087013158818175249064317958973877909037224815373"),
+        MontFp!("16735322269478335929131611670089342135588205793924787907856943392431725411629"),
+        MontFp!("20487989084414161462431324456040544956671737924589348832484117290264531005303"),
+        MontFp!("991404779256099913569561460005735823176831315250109345694197087497778707447"),
+        MontFp!("12403152875035099961283368388852584749460932263482397452647736730447907363221"),
+        MontFp!("4086427505181709923565832636992346270489207870131792488930389136658523450334"),
+        MontFp!("6143243951533181757415269381412864770996360748004903845141664179532399602512"),
+        MontFp!("866706669878257575355607694505677029531447447184317717274975559227499614511"),
+        MontFp!("5892914572161311963309388543417806910338156401784367910536431408323821746054"),
+        MontFp!("17195629524346618476376551682847818577110341498528926796026110967915240955181"),
+        MontFp!("11821298882555096497181913149211502245650586243756443688232286290083613236253"),
+        MontFp!("9353931901221302781036617146700068445151424017933715326811471823048576997254"),
+        MontFp!("287161824097962028466164990607592105232453331778514498980389789122436840050"),
+        MontFp!("10649681231914156546306210447083574697745999931000675911245824305402757632471"),
+        MontFp!("234428462053245029707379734508145490630528735544251100489484083890623632434"),
+    ],
+    [
+        MontFp!("18386986463595680658295944092336172488193344109864388929828691162200829447922"),
+        MontFp!("12209205038092046905635371865560616384451716418834791040178049545643031921053"),
+        MontFp!("14186020280018069417447809370772570171911004194015095921511996859327548729773"),
+        MontFp!("18113938321789369780471778880758786581581161378582151974602552514969078582931"),
+        MontFp!("4811662992940356526245596671631791612493389462608103550402208802794345444140"),
+        MontFp!("20758900083194181903559645045132621401748979651195063688583538188953422299252"),
+        MontFp!("9153554145073302417837255133650907646404668180089275208960151746312542076673"),
+        MontFp!("9140083548410620433186361969381763393513996980985547052071730395844456832878"),
+        MontFp!("1995008884754637182207447536267941537231422109677541855619755654197330974956"),
+        MontFp!("20815985241080261061330547616924867409496454481048128927799047536950736307626"),
+        MontFp!("3230373257763661238115338374579765087994728137593244037768237534083906441753"),
+        MontFp!("15848607284344292541615961379571084541204910796797596203678379270219514113553"),
+        MontFp!("10428395164296448020881569101995272889447042680020183589472054992027224827786"),
+        MontFp!("13680122146242354024351667942887798315658431983426300974494489056276061013513"),
+        MontFp!("4339724378247845795006138666633339576672603219988412146454211158778911139049"),
+        MontFp!("16957453138566211779676251195537106997166365265767483860476580167187603818641"),
+        MontFp!("6903442910536492004022094150099887848831560470459811862902118892324229883825"),
+    ],
+    [
+        MontFp!("13517533794136732881120013247194703467846939834067648417739110803619549581956"),
+        MontFp!("8573259981507024845652388913624603863752296398651459450944134507080250987145"),
+        MontFp!("7184725885552680253794089893559736740786331382981862187745268057810849566021"),
+        MontFp!("7444911651670419666243138241908768267984541319117854062859415506699726860996"),
+        MontFp!("11938251205745649282918409002562222384447298563524098642560439931062370036028"),
+        MontFp!("18732676061737136378462265890132297759828778436681433094170064283098167411411"),
+        MontFp!("8846532794008556689131513011423108042165045871690949838768507118557843169852"),
+        MontFp!("18388218263536244281946085549244925864711296342518046885707040924859244937400"),
+        MontFp!("17560203902531872086160045342305935339585392467343746359739902020514592872929"),
+        MontFp!("13543101793457457855715055504451796018964110256417682942067062541530681224758"),
+        MontFp!("12729201216656193290459172785293440324005383463664790973551946761644249872265"),
+        MontFp!("20749632282565878390585307142178974866932241415051524225408429188255209021839"),
+        MontFp!("2939350463247023543160206627078350892997171197177918468376868549940855641486"),
+        MontFp!("479410521971742071578523736929989300479738063431591906751176641695551615616"),
+        MontFp!("1215960229051771956876212387168875875104433482328478370485649365955599472636"),
+        MontFp!("7395834689442865088318136274271999810879214824816761763663704696957588580019"),
+        MontFp!("3437039086902274758103248708959011309309143141844585728091733160570479897488"),
+    ],
+    [
+        MontFp!("17467540498235313026126845934481902834360708027699640261573503748997917041831"),
+        MontFp!("8422995023428881457749354920047230520807267797593122026229940569066728153312"),
+        MontFp!("11738236614524316047928272298201726059224571806912522830504677268234776263625"),
+        MontFp!("12990187369429149609670218646295887927962536468837638994349699374218402630706"),
+        MontFp!("21623781561819578622543358003640431769369503776252057790027825149203611468091"),
+        MontFp!("5669501143149367774701636329186878874504568114693026656335516415399035236206"),
+        MontFp!("4575509333922357841125659935390034260822150782714631158836599383911958256934"),
+        MontFp!("6033679984514005206127292322842556662338038222728990090780027736088500900555"),
+        MontFp!("20960588060390074988596726655693601209728221976889320157850211914560680389602"),
+        MontFp!("13413928900186225435324599444732846219399870995843202836070995363431243049194"),
+        MontFp!("9234295907731818534748337816708817747432963728163044189490249482655118848141"),
+        MontFp!("8555123146188721627588124037374319613226378704908045460846666824116325453744"),
+        MontFp!("15273216586585654487893582395632825165015207795457148545076967758704213266502"),
+        MontFp!("11125068324382272646583837096728304098536045737064996999867399544054690011336"),
+        MontFp!("14959670405320615939216519873622952189488168539376564485090492806106125094875"),
+        MontFp!("2609137341445825321095931245531863029306734693698914386115461720635228617677"),
+        MontFp!("18022833118482437226280994646426462585862398739142688151319660825030833751162"),
+    ],
+    [
+        MontFp!("10525185277608968809171389414591123336341871721390818817629448578893073864965"),
+        MontFp!("6744998464824276672348708743166802119889279164929133576466782672127996471038"),
+        MontFp!("9344118411856392638882345685982595534877291739896163576786468663773481537247"),
+        MontFp!("5834845743289993456669132331911158234826336724070643002101495844260807919180"),
+        MontFp!("640787484559876348086629339096900566749702582335998287167888457763777550412"),
+        MontFp!("3289778183958313592191132419001427890244410850048418852950969831934946260405"),
+        MontFp!("21884067909485911557708530514125998779898644250629017666973972309955096019929"),
+        MontFp!("12413233158576537989326032313779399359311922621054801366572548255508592334846"),
+        MontFp!("19326971287642001797155516864971431422568657490079056599164481456075851978783"),
+        MontFp!("9713216558784550226779073929454993288918740379425900055346553812422457613427"),
+        MontFp!("18939709206067618242082635502394826733708666259540151764313557087718564592235"),
+        MontFp!("16287201832008230557120811959387085081039691924582377369304872636500446900292"),
+        MontFp!("5278016450516276499641394140854299047658079220708153291463991663971826612725"),
+        MontFp!("8340962164398516487176161966485950102648589482540787401234013926762930809122"),
+        MontFp!("2189360335587469104898894044890322510567148239646828290042687811234738731248"),
+        MontFp!("3449789621053765306024986494635371148296184209757783340117745997173900013891"),
+        MontFp!("9143283285825049929933624722851126967075894848265558258882809634241528781890"),
+    ],
+    [
+        MontFp!("1762589836333836268173539283261821177424666871790048223025892425442664744623"),
+        MontFp!("14999716003380172870846520006929664713498492291151583767005426828389694408867"),
+        MontFp!("6657396937759865997077915983426704746176142403322137472681086594905173286297"),
+        MontFp!("15931240944401174032343453067874387364523175128749141502399457399279513999466"),
+        MontFp!("1093656103569127166341414994548826968375416499363412936873802336598768367672"),
+        MontFp!("15311996490740753142335607256171022029850671542830487572556180935394970969084"),
+        MontFp!("2114569000427676164059607854200438792707180857430926937778356703166016436297"),
+        MontFp!("20765151929972334364703309580474623940346949760797265180189525857343032900699"),
+        MontFp!("15086899750477019230037863517768898777026828694611906648102458179274814888575"),
+        MontFp!("11989598054307650119711820896035556261218721126425285775266572489599541225098"),
+        MontFp!("9091334194223602201291135947382348087276953920280768131063598550602333641673"),
+        MontFp!("662205156672119277592919023943339579805630811299947870362049797370818219037"),
+        MontFp!("1644010308269285824046398201622466330980474102922491226041283891094107089659"),
+        MontFp!("10802230809760799055453358968022894649048726749105153336410430294944584338077"),
+        MontFp!("10002812375469387387638524822236342839480428168589080127597945114460807065506"),
+        MontFp!("19156323136144190554785957482371465262949638224644638616894263767724257422262"),
+        MontFp!("17733486484176040486420248003268701811107138651833773383126692059485133299226"),
+    ],
+    [
+        MontFp!("11591816707957137858747879818244290349664729458236317322129399152472444934827"),
+        MontFp!("13438697403526660565724658068461816155647612966569784134628157461074851140504"),
+        MontFp!("13266011411738324637226437761946084145971285033245245813436082422574964132769"),
+        MontFp!("19496324867803620550902836425197207665281935127556215366411961309085032313784"),
+        MontFp!("17828723725738030742535695874826508863700363541225603578112437608777873288872"),
+        MontFp!("1354878734330830246945810749621035198088901780682128075198035721915088394677"),
+        MontFp!("14191386869116296861827703975696048379271311137936832987484810982707013486938"),
+        MontFp!("19738102036337682966068176606232821315534061860926048846327720022609950683611"),
+        MontFp!("10125898950023999329674512710082674945903794489998271114320266993547905724563"),
+        MontFp!("9735132529803303628278600229677357854336331158152745512812889827254017680646"),
+        MontFp!("11236813353178034372164750145200156935906967316633703292491531523808184114591"),
+        MontFp!("2838710343346183231321901296981007702601287106254090132583144723338172491341"),
+        MontFp!("2260966150439461282635555159610786965904582998424984888756770014321125334804"),
+        MontFp!("16205138689269860259863339330838066928731455712034665818609854024821608206800"),
+        MontFp!("14474351689504859217283250911440109599364797953678136799363008066531050767071"),
+        MontFp!("6800897037717406782446195419208951974623496109363697062923841346454038837657"),
+        MontFp!("12439223794583479313810611548866683769924970108158641553689074442900057207206"),
+    ],
+    [
+        MontFp!("13368632268650760591616092601174213792332738038582508245973036690611922918115"),
+        MontFp!("13045024155077800673372198196114071407850396125448182263497388710529843778012"),
+        MontFp!("15909614634449771616261546064207953846549526793661176217246033322484959782377"),
+        MontFp!("6286523689254946058242241932237494136885909209872547435875613686442878101066"),
+        MontFp!("1349982286621373054175291479185807897612755726394394337821103602455947583575"),
+        MontFp!("1790249021131563196559147247422502568900570496057134946620667686190353467913"),
+        MontFp!("6402193114585552816271289059052503078472437339555969654050634927290142355144"),
+        MontFp!("16657876151292774412640987426284773353540623435906797018927318161786869839630"),
+        MontFp!("9323926757990898215781025566456682055829536111985821344881754566754550287464"),
+        MontFp!("333557759600743416321063171376142987411973146341256173569676328325670137868"),
+        MontFp!("2979415176549537735678518951412460315394554685744871595392830851604961695296"),
+        MontFp!("11399925948142490745007741431204138659570087052831458877959853228053991616097"),
+        MontFp!("17621136800256980786736718847997675507300992679800923101555538476888606832414"),
+        MontFp!("19796186386112600298425768110232392434623500274218805673491160537841238382774"),
+        MontFp!("6150633696896276954724869550196553826388538976047912638783098883173888551921"),
+        MontFp!("8007744165470722314410652720217502653722691054745133622163922368886561184511"),
+        MontFp!("9311054107030040402449841855464250035701299963653145080544526434268446648883"),
+    ],
+    [
+        MontFp!("17483374709267556993216232945834980676370211318754373298278341962960548363667"),
+        MontFp!("10342541634151745634549333576310088998125069092773405335947539258498170319639"),
+        MontFp!("2491113909032842407846736301373377587232014330531326042787834409966784029431"),
+        MontFp!("2868273648562988738033854616949524236294200717479306399357880720258598168313"),
+        MontFp!("10742331131465347969746591645056052543770202432577403033214021867405353469410"),
+        MontFp!("11262784136416067912059431910504651513874071545823747299137490135956765416093"),
+        MontFp!("1202953198131460912225596542947265149450470358249744433218140619832526467659"),
+        MontFp!("1357188592392022798249752911997083133072840552839921198518049365021930318735"),
+        MontFp!("6860322969159072475760401518666168198209741930191210869338776131213742985903"),
+        MontFp!("17512830541972446751364043570597924188477065675882039340228337890464360413831"),
+        MontFp!("11068769874220100931580712510878176008480346856568608049014134908792656846759"),
+        MontFp!("174592415638141512586004221919684850728269107225502531102930877822615095893"),
+        MontFp!("4529660791853304207880649392489892958123653811385478713812028357153062637877"),
+        MontFp!("7261086657925858393339848092042570053113017792782297779091045392687309229931"),
+        MontFp!("15947797860109427499686809495296483548232719158897790339724183452147879327839"),
+        MontFp!("20792174987130011436800220305375988411204569143955960269071283382992617027310"),
+        MontFp!("16004359657196683674112642475201997234099915262640166308760622340887283269558"),
+    ],
+    [
+        MontFp!("3052232100533123772344258509339894427157169209304015026294337193048877370052"),
+        MontFp!("6932731371388662052904698619255543785138918159081404052613363170137706724722"),
+        MontFp!("18786344120940971245053939784556033915582618306965577644860589607674568931598"),
+        MontFp!("5543910602266336221969634219610367731229996706190750207556121552771043245699"),
+        MontFp!("21500331075127237626915881392062922706776178350223005349901601754581763355631"),
+        MontFp!("1647168321391235121087005294677186408057439085818209785557656918589059596681"),
+        MontFp!("18530920602212288118571329897791681729275409540471839847360270409798512800049"),
+        MontFp!("4854705606288041012191490612688235100018679131692705525031856778474580243436"),
+        MontFp!("14030500314224264714821884320124695887543721243139555484376859209010937666224"),
+        MontFp!("2929002685096227153223014766825090983318157894872369622190343561822695490151"),
+        MontFp!("19571082362460435625048830465870659122288859631947561605202388130763965600724"),
+        MontFp!("16262018111979731901324396497332329657354718141076944178560644525915310954979"),
+        MontFp!("16511368307044025445048172352133100276074403358941934299584092446434550554652"),
+        MontFp!("753252068911531248849891296487019536968643055711963719939888051839316478907"),
+        MontFp!("4925743538740679971454033971243400511986874154138434310761553437631037517931"),
+        MontFp!("6674573928014195188134308196945556256425786164998594495377003561132663149662"),
+        MontFp!("12252972387729677079741673273643386252988421977551152663330085764982570989423"),
+    ],
+    [
+        MontFp!("8132942533382570630447992711549276375854208836400139615573300103975003948893"),
+        MontFp!("1767955113430187982507552626731364143386403228830412651051530991601802225661"),
+        MontFp!("15911785581735751807295094148896874484829666426702209837871379520505512480322"),
+        MontFp!("8457495441274725464495320737396221038491121993793647195771335087939175598066"),
+        MontFp!("4450592062983808718489321963811761580153883581981811699755824744572598554379"),
+        MontFp!("17594374172823696288546149573800906143533062920680825959471396256808502015697"),
+        MontFp!("5396987646216038506498234734744679865090292329649749592797349235509038812800"),
+        MontFp!("21252439428941443609961710022512211297072223091751149596182317370399721579385"),
+        MontFp!("18205060096578922655953660066798909616473486532390705801648322522122587090830"),
+        MontFp!("18335844957174152986460129513698965339432209604680688295392195893700811299508"),
+        MontFp!("2642745947776207344581721189041440984169021288344823913353017838424039626428"),
+        MontFp!("12274412791221963394261975707650556785293993588861677826296117405653175236519"),
+        MontFp!("9027620387743934195363518529726678172699541005293960006772332651314467807053"),
+        MontFp!("447942484646789701542537528994314353531710489288574350580201677777950035282"),
+        MontFp!("8057692910194936390243001022339132476522458573171096947792809940102911406995"),
+        MontFp!("12466422220613642034159029765007116066052620609909566451840020754182208020143"),
+        MontFp!("1171876794208841082635913903308771131102729703148972430947313152931064171577"),
+    ],
+    [
+        MontFp!("2996001803829960955342682545650059577915377668754838044698518610136674338212"),
+        MontFp!("12306305078198172670943701974214620978597606399214937108559926306737496310941"),
+        MontFp!("1710451046983264379967941386456520655992388623198157754427123889803480356000"),
+        MontFp!("17294040628260706127805165314917539522165501484257724022309899345383198007240"),
+        MontFp!("14850723644587717617416204055806811021029607124589054329504158261611106696194"),
+        MontFp!("8626653016235921835569469801636971931942277275560421125462026339476626883966"),
+        MontFp!("16134665167778000757091607171765139146858141948472867080489695707419285323252"),
+        MontFp!("11129502792062576191937938132554397380912998297589916298721102071015359559556"),
+        MontFp!("15130530042530114554524440015929780266039077867502338045121803478767735700077"),
+        MontFp!("5922013411157909464044985892976718792018167710191222879749916950629215559119"),
+        MontFp!("15641877241146443425426298971354708474362723075989298367558100488623078084763"),
+        MontFp!("19807467678520679976886734556492012503982987205100734773481397135264223200444"),
+        MontFp!("7230620319188569738319728589376800438996515892335609872222230868954870926930"),
+        MontFp!("18168775389925542038768548563310374943094173375522704828602237046711660513509"),
+        MontFp!("15879478673208122828337915995586151196586860000621630078538789187695874266640"),
+        MontFp!("6026559623764003366786032824563693874782053604114144074018595121889075259493"),
+        MontFp!("6581629708588609217727738860658907189729022916858514019350636298557029783381"),
+    ],
+    [
+        MontFp!("4392179832866307866014828901921327571555384668846640428849164693147430204259"),
+        MontFp!("4039689077078382209094762982217352133778072152116592303105622250221872775182"),
+        MontFp!("9439756845261909878224812875899975078023923372686432160229876632562036997401"),
+        MontFp!("6300039613750170323909974701574854925454386098696929245502563775636617169329"),
+        MontFp!("10245787402060152709811414110506095522941754087902005711632016024561712242938"),
+        MontFp!("10770509446150307821311775909967816156602102352374955635502734154581919715219"),
+        MontFp!("4445806424060983011786548514585153091691541530320683355086806781784085761841"),
+        MontFp!("10891448460108394317032887254041302638257759566726100966329794645560947944086"),
+        MontFp!("9055121436695773704587898653339600198373789357537888127317084878411452883579"),
+        MontFp!("8149630563292186659644235715307853879418282762593116603286751824944805128957"),
+        MontFp!("10164520096412584664920364217937374178249615925628067827666302990156368241543"),
+        MontFp!("18787580258401627795053641123916277779446570183960070225650619813208411270515"),
+        MontFp!("5840281460603743032409236132633000232507414411373674275076564056089047616513"),
+        MontFp!("1156412247030909285380152844453068577291565904060426536020772600017567322066"),
+        MontFp!("13125262001118293895463954353229315406286474760130294037415093324929424537303"),
+        MontFp!("19341994820951858136925352932235917695982462478372266716377719180643995594202"),
+        MontFp!("14230192493251599368583155353256194379698225636722091587422432258409725791125"),
+    ],
+    [
+        MontFp!("18604655597949661495485553681980778009433340166469812699886221824297364988905"),
+        MontFp!("9484517765495232191823212744512718095318306206191891691619884709082351623103"),
+        MontFp!("20661548256453489016691122417433244991048818711049327729621550642733467464794"),
+        MontFp!("17946084952153038878596350204495193827996043096722462577062358769356218083033"),
+        MontFp!("9008616567490400741620365097652579204686050477332773331876377443733844010527"),
+        MontFp!("11222501299614818564801113661500553859475257082250633539503337648966645140931"),
+        MontFp!("17547881514301195137575407746474668605213387222566272868597007270413523256918"),
+        MontFp!("5795058045621081034219295632641782062332989904715400020698296776847639194943"),
+        MontFp!("10503000549984486673912349445691696940798628902104169170719393589667267164117"),
+        MontFp!("20627605141977035823281920467011832985049476688278934213570130229801586759490"),
+        MontFp!("1617802179061215386952772461423280627948056362689193604176834379576303877113"),
+        MontFp!("6791812933391289387114968397366223795809410677008110102514189342693442788856"),
+        MontFp!("21469327667867855439208525645169470185070216384607993883382520539336879692857"),
+        MontFp!("6209704596259199225388050030516880246912805490686902979517644524792187929012"),
+        MontFp!("15048235302521180373501889249113906037936587642496276368753413449431797081949"),
+        MontFp!("18778185090591194140890220889049934755279881763207537060835789721322305845157"),
+        MontFp!("4164373610491096709440557803327678927854304626112734913682240155408254130319"),
+    ],
+    [
+        MontFp!("6352957909818668217728163741226734988658723962520906766539822539894434836600"),
+        MontFp!("2081726858060934846688892186400729209835095311388667168398290314717411097500"),
+        MontFp!("18997085104017244551079822479039381301947270057658366559839643454880390097993"),
+        MontFp!("15381301300391534710642382148869618456973733474322247178000572705046719371489"),
+        MontFp!("4198258606721523455517040976251319538290332464337310822593109666080121166366"),
+        MontFp!("7557782631251063800473816565466881793101085796415715068491278248627266080710"),
+        MontFp!("18931480224318877255548108668163955495398356426693160573742850933557366286581"),
+        MontFp!("14006438920977933743471268674953904657230294434994593269165676508225740552815"),
+        MontFp!("10552613214353127913757671512246056403297131067811718291694487016254806821431"),
+        MontFp!("6942360360107439367031353171424554084301681329082198856946152988574077265518"),
+        MontFp!("4821893567676204037011511732637248253220700045205121424698817536976139720741"),
+        MontFp!("8570797921121743873772790445200803600261480452613730673514117912909339929304"),
+        MontFp!("10210191620579364785116101817818502747961980686320008627354511305745123505207"),
+        MontFp!("2219514545666614569412405614553938185242666345781569909039944438048257115280"),
+        MontFp!("9472494948236350938478699115530847822498160599193967472596054792264811505177"),
+        MontFp!("1966000492511706985369338139654568276174679894528461227404736596640963023833"),
+        MontFp!("20046698515808110863133254224236843582890061845803004799852246848232152965906"),
+    ],
+    [
+        MontFp!("844879645041461183539563036099295737132271525297913466134251805988318847548"),
+        MontFp!("4731835924119595590183070349774829521463227171971888362743688018910258502055"),
+        MontFp!("6665591397442095781758927683212527568272080607916992672135848576259567872049"),
+        MontFp!("12479020936095094085154050050726399793348028251321297846621658033596298393726"),
+        MontFp!("13547094394131143768607425506026982562580191167096570425566382504500825930959"),
+        MontFp!("14726791597032654225590630927699963688874228760066722543952181168729825328904"),
+        MontFp!("16458751398904781219324882164741165137205147400663608606201428995139003250837"),
+        MontFp!("1382702744652547575130131869708589984736039127219433507919721371542416805658"),
+        MontFp!("14166947289061517490924648855242003709961143010494793848406604325534604905674"),
+        MontFp!("17345393385322406098467895186375277399629828208438881931258894959842597584446"),
+        MontFp!("12612093274589904544460621781003810044552285394822234432357810635329000095532"),
+        MontFp!("2278721075910399058699498270814807833560549835812826924195171790394865401759"),
+        MontFp!("1421362911034706266530649492401610048326109063529762443419685870164992649589"),
+        MontFp!("20384150845787594899080953437004222732830782572958598551146508153682280572403"),
+        MontFp!("3495056095843097816017033529053032886550694720911641681721955773137850234745"),
+        MontFp!("14487246717687560703169821216097612442592434246076605506691782211475316706576"),
+        MontFp!("13114661974362015466065924612631881453272278776220284208159815065535423486323"),
+    ],
+    [
+        MontFp!("9628837478322926908639029641093928246719582225562091179037197080152788423316"),
+        MontFp!("16456984127855989467975376775709400558526389914848937751741445859284106536098"),
+        MontFp!("19656266549615253685970702218272816322599871270060998775752454696892588799707"),
+        MontFp!("16028536897482325114555541492282064621526855756620164892739323385311597784598"),
+        MontFp!("11146275353887579825261271116625263398084632057838305651534583557054398181584"),
+        MontFp!("1266798071261939757558459653520435752781168817699931045808536221140555755937"),
+        MontFp!("11242956579746912399423902926753108012060763899073974272480062541403588053076"),
+        MontFp!("14588608018127887593991920960165305144719061971763947578582110087376128309227"),
+        MontFp!("5393054051309402705329828859286427425185515439646193479342950065083063200985"),
+        MontFp!("18307950351265411595229078848795494559405008993794506472277342386767679169011"),
+        MontFp!("743926999062879465491123202608691059211244131659206478586545917792531513741"),
+        MontFp!("11450449580887323007201214040662031298427088361641716342102428346626191862079"),
+        MontFp!("8918057399138776974775397421552812822591030597414174436378830421562569121660"),
+        MontFp!("14007657148016840281780221954150751472316590653604019267269105557294795817454"),
+        MontFp!("8897431240575139049076571283304115052162507033543918057893215164198174868677"),
+        MontFp!("17409416377040437657574990618884772475255396933561856228823381810258648281132"),
+        MontFp!("16403607054571283311114350132750182990388190855449188101005849970173367695642"),
+    ],
+    [
+        MontFp!("1508877263063909922036861471040526805004287450733006660015588614987220564178"),
+        MontFp!("2405929790914208591765690668430163058227989428739435027301989746453297508008"),
+        MontFp!("18415257845847977290620817418058909465150962144437291827522053905306094819516"),
+        MontFp!("20783625745588167215135121578855692267545194811392233146323762172638833678083"),
+        MontFp!("12583735518159929222168953087285648797218797099708802919538903595273472024870"),
+        MontFp!("15030113532753402263141105846533650021837620483920708638977418255212037916690"),
+        MontFp!("2044646201923266135898414814098969242685760821863709223961924291978394037140"),
+        MontFp!("9562505824238493762747890608580676752653265912034931437600706699047478680911"),
+        MontFp!("8576622443481230638247834729157921300954369512398151853927361194153863723577"),
+        MontFp!("13502467573105057302451195240442043465889307076796874707692282952243564124313"),
+        MontFp!("8659508751425384756294968991737225118186991099981336883558347987313410663071"),
+        MontFp!("992836427155144083079734949008912409782777055146977880211513548288277680936"),
+        MontFp!("14478698156814197173486553402886358323315401752422792440407576984439553283243"),
+        MontFp!("20204662700070586254342470347097589341087597032672938910846995633724257876133"),
+        MontFp!("1812172830570486768023198219726218583158255123460220955281515074543933097836"),
+        MontFp!("17992769749806193849727573532001735714323064233429371707200828907106131204931"),
+        MontFp!("11275304845104653478990825724663443324387792098049434796341439810037846570595"),
+    ],
+    [
+        MontFp!("11105199526118828533245892738176913532701964584183761946009726630431598631704"),
+        MontFp!("11393903979182757061241357325556769026145343989318381225525091986823175945546"),
+        MontFp!("14383047179623652129287498422471395756755287245693820194038638652931729043624"),
+        MontFp!("20453515719997662355844823439261666280107449255435062001366805179715649416520"),
+        MontFp!("16342434030539357589118587629035849207895930777526269265833103191510088418049"),
+        MontFp!("18401618409669783606190734020469430253158741609682792710560527808266513994586"),
+        MontFp!("16076963378432439787360187815002671229026877629938245603309362816936414414606"),
+        MontFp!("11529521787481754741418171353792184686967806427253956770441456297160607170941"),
+        MontFp!("12602344479327416239042579290059415968931395895265775848606068378636646131638"),
+        MontFp!("7874996576706323538862472352643073872193797790899948188898578232811415974069"),
+        MontFp!("4845431324532575010258701269749667428252187973009639700566740844013627970107"),
+        MontFp!("14802018959157569593654049670448062053140035551074745737518084801052997376690"),
+        MontFp!("1438261891736096301511736944301645044858355530036359764344568183983865122024"),
+        MontFp!("9702483734495711444970258278431697259819553205295174290488141405714571120392"),
+        MontFp!("745629299152271704331537263378566091569916279264951187804590327433939503876"),
+        MontFp!("13647050656573274198980106604763314305257288237932552445752822394869548724758"),
+        MontFp!("16745795700152546519998532820179128558740146524138261377398336069302091938316"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=17.
+pub static POSEIDON1_BN254_T17_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 17-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 17]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 17]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 17]) -> [ark_bn254::Fr; 17] {
+    POSEIDON1_BN254_T17_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 17-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 17]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 17]) {
+    POSEIDON1_BN254_T17_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,319 @@
+//! Circomlib-compatible Poseidon implementation for `t=2`, i.e., 1 input.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 2 8 56 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 2;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 56;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("2910766817845651019878574839501801340070030115151021261302834310722729507541"),
+        MontFp!("19727366863391167538122140361473584127147630672623100827934084310230022599144"),
+    ],
+    [
+        MontFp!("5776684794125549462448597414050232243778680302179439492664047328281728356345"),
+        MontFp!("8348174920934122550483593999453880006756108121341067172388445916328941978568"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("4417881134626180770308697923359573201005643519861877412381846989312604493735"),
+        MontFp!("5433650512959517612316327474713065966758808864213826738576266661723522780033"),
+    ],
+    [
+        MontFp!("13641176377184356099764086973022553863760045607496549923679278773208775739952"),
+        MontFp!("17949713444224994136330421782109149544629237834775211751417461773584374506783"),
+    ],
+    [
+        MontFp!("13765628375339178273710281891027109699578766420463125835325926111705201856003"),
+        MontFp!("19179513468172002314585757290678967643352171735526887944518845346318719730387"),
+    ],
+    [
+        MontFp!("5157412437176756884543472904098424903141745259452875378101256928559722612176"),
+        MontFp!("535160875740282236955320458485730000677124519901643397458212725410971557409"),
+    ],
+    // Second full
+    [
+        MontFp!("12156296560457833712186127325312904760045212412680904475497938949653569234473"),
+        MontFp!("4271647814574748734312113971565139132510281260328947438246615707172526380757"),
+    ],
+    [
+        MontFp!("9061738206062369647211128232833114177054715885442782773131292534862178874950"),
+        MontFp!("10134551893627587797380445583959894183158393780166496661696555422178052339133"),
+    ],
+    [
+        MontFp!("8932270237664043612366044102088319242789325050842783721780970129656616386103"),
+        MontFp!("3339412934966886386194449782756711637636784424032779155216609410591712750636"),
+    ],
+    [
+        MontFp!("9704903972004596791086522314847373103670545861209569267884026709445485704400"),
+        MontFp!("17467570179597572575614276429760169990940929887711661192333523245667228809456"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("1050793453380762984940163090920066886770841063557081906093018330633089036729"),
+        MontFp!("10665495010329663932664894101216428400933984666065399374198502106997623173873"),
+    ],
+    [
+        MontFp!("19965634623406616956648724894636666805991993496469370618546874926025059150737"),
+        MontFp!("13007250030070838431593222885902415182312449212965120303174723305710127422213"),
+    ],
+    [
+        MontFp!("16877538715074991604507979123743768693428157847423939051086744213162455276374"),
+        MontFp!("18211747749504876135588847560312685184956239426147543810126553367063157141465"),
+    ],
+    [
+        MontFp!("18151553319826126919739798892854572062191241985315767086020821632812331245635"),
+        MontFp!("19957033149976712666746140949846950406660099037474791840946955175819555930825"),
+    ],
+    [
+        MontFp!("3469514863538261843186854830917934449567467100548474599735384052339577040841"),
+        MontFp!("989698510043911779243192466312362856042600749099921773896924315611668507708"),
+    ],
+    [
+        MontFp!("12568377015646290945235387813564567111330046038050864455358059568128000172201"),
+        MontFp!("20856104135605479600325529349246932565148587186338606236677138505306779314172"),
+    ],
+    [
+        MontFp!("8206918720503535523121349917159924938835810381723474192155637697065780938424"),
+        MontFp!("1309058477013932989380617265069188723120054926187607548493110334522527703566"),
+    ],
+    [
+        MontFp!("14076116939332667074621703729512195584105250395163383769419390236426287710606"),
+        MontFp!("10153498892749751942204288991871286290442690932856658983589258153608012428674"),
+    ],
+    [
+        MontFp!("18202499207234128286137597834010475797175973146805180988367589376893530181575"),
+        MontFp!("12739388830157083522877690211447248168864006284243907142044329113461613743052"),
+    ],
+    [
+        MontFp!("15123358710467780770838026754240340042441262572309759635224051333176022613949"),
+        MontFp!("19925004701844594370904593774447343836015483888496504201331110250494635362184"),
+    ],
+    [
+        MontFp!("10352416606816998476681131583320899030072315953910679608943150613208329645891"),
+        MontFp!("10567371822366244361703342347428230537114808440249611395507235283708966113221"),
+    ],
+    [
+        MontFp!("5635498582763880627392290206431559361272660937399944184533035305989295959602"),
+        MontFp!("11866432933224219174041051738704352719163271639958083608224676028593315904909"),
+    ],
+    [
+        MontFp!("5795020705294401441272215064554385591292330721703923167136157291459784140431"),
+        MontFp!("9482202378699252817564375087302794636287866584767523335624368774856230692758"),
+    ],
+    [
+        MontFp!("4245237636894546151746468406560945873445548423466753843402086544922216329298"),
+        MontFp!("12000500941313982757584712677991730019124834399479314697467598397927435905133"),
+    ],
+    [
+        MontFp!("7596790274058425558167520209857956363736666939016807569082239187494363541787"),
+        MontFp!("2484867918246116343205467273440098378820186751202461278013576281097918148877"),
+    ],
+    [
+        MontFp!("18312645949449997391810445935615409295369169383463185688973803378104013950190"),
+        MontFp!("15320686572748723004980855263301182130424010735782762814513954166519592552733"),
+    ],
+    [
+        MontFp!("12618438900597948888520621062416758747872180395546164387827245287017031303859"),
+        MontFp!("17438141672027706116733201008397064011774368832458707512367404736905021019585"),
+    ],
+    [
+        MontFp!("6374197807230665998865688675365359100400438034755781666913068586172586548950"),
+        MontFp!("2189398913433273865510950346186699930188746169476472274335177556702504595264"),
+    ],
+    [
+        MontFp!("6268495580028970231803791523870131137294646402347399003576649137450213034606"),
+        MontFp!("17896250365994900261202920044129628104272791547990619503076839618914047059275"),
+    ],
+    [
+        MontFp!("13692156312448722528008862371944543449350293305158722920787736248435893008873"),
+        MontFp!("15234446864368744483209945022439268713300180233589581910497691316744177619376"),
+    ],
+    [
+        MontFp!("1572426502623310766593681563281600503979671244997798691029595521622402217227"),
+        MontFp!("80103447810215150918585162168214870083573048458555897999822831203653996617"),
+    ],
+    [
+        MontFp!("8228820324013669567851850635126713973797711779951230446503353812192849106342"),
+        MontFp!("5375851433746509614045812476958526065449377558695752132494533666370449415873"),
+    ],
+    [
+        MontFp!("12115998939203497346386774317892338270561208357481805380546938146796257365018"),
+        MontFp!("9764067909645821279940531410531154041386008396840887338272986634350423466622"),
+    ],
+    [
+        MontFp!("8538708244538850542384936174629541085495830544298260335345008245230827876882"),
+        MontFp!("7140127896620013355910287215441004676619168261422440177712039790284719613114"),
+    ],
+    [
+        MontFp!("14297402962228458726038826185823085337698917275385741292940049024977027409762"),
+        MontFp!("6667115556431351074165934212337261254608231545257434281887966406956835140819"),
+    ],
+    [
+        MontFp!("20226761165244293291042617464655196752671169026542832236139342122602741090001"),
+        MontFp!("12038289506489256655759141386763477208196694421666339040483042079632134429119"),
+    ],
+    [
+        MontFp!("19027757334170818571203982241812412991528769934917288000224335655934473717551"),
+        MontFp!("16272152964456553579565580463468069884359929612321610357528838696790370074720"),
+    ],
+    [
+        MontFp!("2500392889689246014710135696485946334448570271481948765283016105301740284071"),
+        MontFp!("8595254970528530312401637448610398388203855633951264114100575485022581946023"),
+    ],
+    [
+        MontFp!("11635945688914011450976408058407206367914559009113158286982919675551688078198"),
+        MontFp!("614739068603482619581328040478536306925147663946742687395148680260956671871"),
+    ],
+    [
+        MontFp!("18692271780377861570175282183255720350972693125537599213951106550953176268753"),
+        MontFp!("4987059230784976306647166378298632695585915319042844495357753339378260807164"),
+    ],
+    [
+        MontFp!("21851403978498723616722415377430107676258664746210815234490134600998983955497"),
+        MontFp!("9830635451186415300891533983087800047564037813328875992115573428596207326204"),
+    ],
+    [
+        MontFp!("4842706106434537116860242620706030229206345167233200482994958847436425185478"),
+        MontFp!("6422235064906823218421386871122109085799298052314922856340127798647926126490"),
+    ],
+    [
+        MontFp!("4564364104986856861943331689105797031330091877115997069096365671501473357846"),
+        MontFp!("1944043894089780613038197112872830569538541856657037469098448708685350671343"),
+    ],
+    [
+        MontFp!("21179865974855950600518216085229498748425990426231530451599322283119880194955"),
+        MontFp!("14296697761894107574369608843560006996183955751502547883167824879840894933162"),
+    ],
+    [
+        MontFp!("12274619649702218570450581712439138337725246879938860735460378251639845671898"),
+        MontFp!("16371396450276899401411886674029075408418848209575273031725505038938314070356"),
+    ],
+    [
+        MontFp!("3702561221750983937578095019779188631407216522704543451228773892695044653565"),
+        MontFp!("19721616877735564664624984774636557499099875603996426215495516594530838681980"),
+    ],
+    [
+        MontFp!("6383350109027696789969911008057747025018308755462287526819231672217685282429"),
+        MontFp!("20860583956177367265984596617324237471765572961978977333122281041544719622905"),
+    ],
+    [
+        MontFp!("5766390934595026947545001478457407504285452477687752470140790011329357286275"),
+        MontFp!("4043175758319898049344746138515323336207420888499903387536875603879441092484"),
+    ],
+    [
+        MontFp!("15579382179133608217098622223834161692266188678101563820988612253342538956534"),
+        MontFp!("1864640783252634743892105383926602930909039567065240010338908865509831749824"),
+    ],
+    [
+        MontFp!("15943719865023133586707144161652035291705809358178262514871056013754142625673"),
+        MontFp!("2326415993032390211558498780803238091925402878871059708106213703504162832999"),
+    ],
+    [
+        MontFp!("19995326402773833553207196590622808505547443523750970375738981396588337910289"),
+        MontFp!("5143583711361588952673350526320181330406047695593201009385718506918735286622"),
+    ],
+    [
+        MontFp!("15436006486881920976813738625999473183944244531070780793506388892313517319583"),
+        MontFp!("16660446760173633166698660166238066533278664023818938868110282615200613695857"),
+    ],
+    [
+        MontFp!("4966065365695755376133119391352131079892396024584848298231004326013366253934"),
+        MontFp!("20683781957411705574951987677641476019618457561419278856689645563561076926702"),
+    ],
+    [
+        MontFp!("17280836839165902792086432296371645107551519324565649849400948918605456875699"),
+        MontFp!("17045635513701208892073056357048619435743564064921155892004135325530808465371"),
+    ],
+    [
+        MontFp!("17055032967194400710390142791334572297458033582458169295920670679093585707295"),
+        MontFp!("15727174639569115300068198908071514334002742825679221638729902577962862163505"),
+    ],
+    [
+        MontFp!("1001755657610446661315902885492677747789366510875120894840818704741370398633"),
+        MontFp!("18638547332826171619311285502376343504539399518545103511265465604926625041234"),
+    ],
+    [
+        MontFp!("6751954224763196429755298529194402870632445298969935050224267844020826420799"),
+        MontFp!("3526747115904224771452549517614107688674036840088422555827581348280834879405"),
+    ],
+    [
+        MontFp!("15705897908180497062880001271426561999724005008972544196300715293701537574122"),
+        MontFp!("574386695213920937259007343820417029802510752426579750428758189312416867750"),
+    ],
+    [
+        MontFp!("15973040855000600860816974646787367136127946402908768408978806375685439868553"),
+        MontFp!("20934130413948796333037139460875996342810005558806621330680156931816867321122"),
+    ],
+    [
+        MontFp!("6918585327145564636398173845411579411526758237572034236476079610890705810764"),
+        MontFp!("14158163500813182062258176233162498241310167509137716527054939926126453647182"),
+    ],
+    [
+        MontFp!("4164602626597695668474100217150111342272610479949122406544277384862187287433"),
+        MontFp!("12146526846507496913615390662823936206892812880963914267275606265272996025304"),
+    ],
+    [
+        MontFp!("10153527926900017763244212043512822363696541810586522108597162891799345289938"),
+        MontFp!("13564663485965299104296214940873270349072051793008946663855767889066202733588"),
+    ],
+    [
+        MontFp!("5612449256997576125867742696783020582952387615430650198777254717398552960096"),
+        MontFp!("12151885480032032868507892738683067544172874895736290365318623681886999930120"),
+    ],
+    [
+        MontFp!("380452237704664384810613424095477896605414037288009963200982915188629772177"),
+        MontFp!("9067557551252570188533509616805287919563636482030947363841198066124642069518"),
+    ],
+    [
+        MontFp!("21280306817619711661335268484199763923870315733198162896599997188206277056900"),
+        MontFp!("5567165819557297006750252582140767993422097822227408837378089569369734876257"),
+    ],
+    [
+        MontFp!("10411936321072105429908396649383171465939606386380071222095155850987201580137"),
+        MontFp!("21338390051413922944780864872652000187403217966653363270851298678606449622266"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=2.
+pub static POSEIDON1_BN254_T2_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 2-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 2]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 2]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 2]) -> [ark_bn254::Fr; 2] {
+    POSEIDON1_BN254_T2_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 2-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 2]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 2]) {
+    POSEIDON1_BN254_T2_PARAMS.permutation_in_place(state);
+}
@@ -0,0 +1,757 @@
+//! Circomlib-compatible Poseidon implementation for `t=7`, i.e., 6 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 7 8 63 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 7;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 63;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("19332164824128329382868318451458022991369413618825711961282217322674570624669"),
+        MontFp!("12346323761995603285640868741615937712088302657627126374070962894016296466118"),
+        MontFp!("3913895681115272361294397190916803190924061797587910478563401817340941991811"),
+        MontFp!("7048322889096718105055545382948709082135086733564574465991576956878202831861"),
+        MontFp!("10375086910057323893637057154182902576957472442368661576421122036461645295833"),
+        MontFp!("12765622911241487148932810040772504127756393086809438933166282251044289864727"),
+        MontFp!("266900212758702307861826326591090138389415348463003233900705815890364224151"),
+    ],
+    [
+        MontFp!("14435131616556129905356866638030823183270286404767286105643513738132789033353"),
+        MontFp!("5780976801287540146775934937953368730928109502001687434229528186520268917700"),
+        MontFp!("1618320442446662026869390273942730786145909339107736579759397243640902802126"),
+        MontFp!("3818399583522206096165108192531271582827953520684743806492664825009577810261"),
+        MontFp!("11764506724346386316602508039052965575734225646587104133777798242528580374987"),
+        MontFp!("2414215974836165993714858157462355581258152126063378817495129367240311967136"),
+        MontFp!("17609437036230923129211608175600293197801044251801590649435913902851695334081"),
+    ],
+    [
+        MontFp!("363438080029711424794236047863047716381155074181485245036621530063262917196"),
+        MontFp!("535766679023716739184211613469394818313893958493710642899297971974381051070"),
+        MontFp!("5305068908469731303772738758164870877638068032868328180355958394150421214337"),
+        MontFp!("10807632568240507366657354568432178961148417327580695024415275247652313539292"),
+        MontFp!("15964415873358391713354948903242729080763777490509563223190335273158191600135"),
+        MontFp!("20700362719972015883260687302741075186857660623182772413609788566925949033885"),
+        MontFp!("10135127975676256977820296631533839366076919827597067890970660746228807376456"),
+    ],
+    [
+        MontFp!("4251490167543116819728642817282216847143714366441358372252125244838181656331"),
+        MontFp!("7745587495915033527847242564710473705100826890903278244320948416581724663023"),
+        MontFp!("11741113129223221800185946819924457344647035336264986754437921049066977440806"),
+        MontFp!("11630296782890656599545188109639399768829653360050213193782325240600583381364"),
+        MontFp!("16861140446185941149398487176581839232380972247302922484807333229513905651035"),
+        MontFp!("365879246117123675211400356410703684399715291171114630107795112994207447819"),
+        MontFp!("21725607857580053522363567649763546934441685061337033780528788383243719579033"),
+    ],
+    [
+        MontFp!("9222866548596464928765000608129177609426964853736257576074550520759533736918"),
+        MontFp!("10261578281201197531384003420612639018011405529775212563256392340336951230146"),
+        MontFp!("15644037447921591571869862919382888810859308861783088910843592577202362807673"),
+        MontFp!("12752004188139535619565478547449108772137477456363099481095747591698702436636"),
+        MontFp!("4205805109630387448825516813913983509046636797101589615147198457314360427718"),
+        MontFp!("21047095155106717901091873146599497621258071512562421967648909471775919992713"),
+        MontFp!("15624165295872926124160584750951090817255240214488120310950503163805737026315"),
+    ],
+    [
+        MontFp!("15064589937731741958666763896598138037875460434244947486199623542160035749721"),
+        MontFp!("1801577872277160959016940766173040841160105238799805406938450020949902989173"),
+        MontFp!("2896766420608048344829901127120623317655260981420052771341833288256800199953"),
+        MontFp!("12828791469509204618898135640019714232831708508424682785876476343251730674999"),
+        MontFp!("21363471986981372923191391880511344708743312828234098289107697080824665183315"),
+        MontFp!("21372706354350795416381912271616633829725494570576895047490974943034914894898"),
+        MontFp!("16006531510217730955981102005088687858079561573088629102219485906666961331083"),
+    ],
+    [
+        MontFp!("2389357602244845938251345005183369360523566673990464798041306722747500447645"),
+        MontFp!("15275955107196234672088664710679934029171843237458844492987233368659104714648"),
+        MontFp!("8038797517535218686870517662905230585331773059774130312418943649247287196930"),
+        MontFp!("17923922393436914864421862212181654800719733137689602673604754147078808030201"),
+        MontFp!("12890519745320143484176500044628647247549456778462652469313611980363507314914"),
+        MontFp!("8058516556024397257577081553178859094042894928866720408652077334516681924252"),
+        MontFp!("768425396034382182896247252731538808045254601036758108993106260984310129743"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("15193892625865514930501893609026366493846449603945567488151250645948827690215"),
+        MontFp!("8655680243784803430516500496316192098841666200175185895457692057709359214457"),
+        MontFp!("11710807066713707084726423334946631888369490193496350458331067367713412617049"),
+        MontFp!("15442364818086019103203999366702499670382575019009657513015496640703659810202"),
+        MontFp!("1358747428976145481402682338881091555771254635226375581638965497131373838774"),
+        MontFp!("15658002471767984962034589730824699545808755102240624650914676102923421241582"),
+        MontFp!("6420480504329990097173256112095253518339231893829818344055438052479612135029"),
+    ],
+    [
+        MontFp!("15457172495394305353698644252424643614748461590123908880271021612601244389162"),
+        MontFp!("5745943350537490600340174787616110056830333091917248931684290284533019091654"),
+        MontFp!("3877253492903478989342845512796806320713689655633086736499730391667425329322"),
+        MontFp!("11257677301507982757739320943403112189613848490812422490591766717141506751601"),
+        MontFp!("16906586852467953445509312290627525856126394969718997799028223470195783329296"),
+        MontFp!("15263589725854108297280528692120758129000336125328939290924952731952242586386"),
+        MontFp!("21735940039489460025710098364749096267519151075908323637361429746399161905338"),
+    ],
+    [
+        MontFp!("20023056608360522105358681147781839024069418874082333862551226466128829664291"),
+        MontFp!("5677500725280079960679484373333947430817198394184436922575072427342643665917"),
+        MontFp!("3080516739494460477657748111767941482024045797587058388950619118994388252853"),
+        MontFp!("21486496065617100719537932626843898998311175055335457507845650282870586541596"),
+        MontFp!("5371049178920102602305531530023787518286335086323221270202212974241707302466"),
+        MontFp!("3074817222296007572297581554183445947239252698770067839721345984255386069425"),
+        MontFp!("19180807038569629573914331337874446591506172622522351734982093457681161813141"),
+    ],
+    [
+        MontFp!("16937785199372956273358037645552299688842385008757508130180245705952406225194"),
+        MontFp!("1688218397616770248184651775433764527272029131542529408516364801909017591719"),
+        MontFp!("16315958669815317541884966612581197291281164499674338063931623110684590850347"),
+        MontFp!("6218230753007070123505625054833158632732536069700963073464625252554943737669"),
+        MontFp!("17774528060285257656595928889288330429565059134928074258373583886985960212139"),
+        MontFp!("16197131592052727313460949906369199026477758140133103701908949020106767192893"),
+        MontFp!("13418604038232148873269488320329340508522225417123160144993642839875173062296"),
+    ],
+    // Second full
+    [
+        MontFp!("15505380865926802396097545843811910443367233632805651511272732002583232431557"),
+        MontFp!("17973744614207669251901495093091561913998272050499760575282030108740677066624"),
+        MontFp!("6137688223696761009295745609563284204827706564566466060484103844265403078408"),
+        MontFp!("14774243062532823236792831566222119634320864630838624098798648826842418775856"),
+        MontFp!("15864970393171078370207775103899428499600152663946379517190945807315353544891"),
+        MontFp!("19010063123357565300336230971672519561204810737546730911549311353159512986740"),
+        MontFp!("12607162829921425080830052984475623157169603642577010527391007035133383807243"),
+    ],
+    [
+        MontFp!("17803108634879437217723652777640120469990779759700458421844361066182881628345"),
+        MontFp!("10065874953507223318296028499872542865030107611981933577973812883589535269142"),
+        MontFp!("3276471432535144390388324850641020151392959100393035635141206272558418581928"),
+        MontFp!("7532054601401798035926415744768772852833516520318445183340725930886329458991"),
+        MontFp!("18893822928119227829016544343228228897166113682019317256005502643243867377334"),
+        MontFp!("15940597493253236451533839310728876441657428995464658827726295547815292644378"),
+        MontFp!("4268009387843764409267791203070919313017052533005657826253994943184768120896"),
+    ],
+    [
+        MontFp!("21611251949238422413354051947529388972078300717392131751061464498329326474580"),
+        MontFp!("12516447001729804412674006874184731098280474050775388553768469608793631490618"),
+        MontFp!("49838549447142926741568525697026885045023997277705726329780325103507790978"),
+        MontFp!("19763902910323896567698991616245963026306943100978479625077573937114135803058"),
+        MontFp!("12029297973430627253212633299020402005457460023136429653800185001711727387314"),
+        MontFp!("17676997725594777991384952086633589048516371093397126876621255518370680168503"),
+        MontFp!("10567543371894667303450346380722020266352683222046730266924342174164712049360"),
+    ],
+    [
+        MontFp!("14583364850544999818712646438016435003942847076919084667364987497592599663937"),
+        MontFp!("17348091487238815837308569582101875357715798351834275089190053280855958465528"),
+        MontFp!("8743083090296259283603789316855921930102444739264013461469099560398359267240"),
+        MontFp!("15114064505647935792598848256320570567717917317803629185764147361301698519005"),
+        MontFp!("18332675991829764561879941291908436508530604635608341316693114747813051532006"),
+        MontFp!("1757567731797951053080580099911774643896363235228742197150882457231133285549"),
+        MontFp!("6526388717947413328592956348507481629843816325885832861915399601868279124246"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("7265658443160253752317166706266927598319661172006072732797351716897681315157"),
+        MontFp!("17200150079219747370109251547638276280610591698078334228421747259741754887"),
+        MontFp!("8627121890622175767416692555014275717515106888840919734160364408960047296494"),
+        MontFp!("14546964505431549758350267964924534495477687922558528647552728692912697049247"),
+        MontFp!("17132720822762740343718421124251772119916072270451579802112353604446214831761"),
+        MontFp!("234333065870376500756753915306346778417056884715946003873280290982247600083"),
+        MontFp!("18375643491701271245209094287106352436174133929245169725584150600992143374298"),
+    ],
+    [
+        MontFp!("5158448692161567615645197008737390561357077078129599243188536485308363800282"),
+        MontFp!("614161645152783610732075198073600394068518413590650990586931263981193439341"),
+        MontFp!("12661793104597977909223565537293318966803153852970198322604479648383643541371"),
+        MontFp!("13041905650419760925682179803296711066088286278603171065755078690359168540579"),
+        MontFp!("15006023590144168506070897325649191051975999212058008674224953860265667513015"),
+        MontFp!("4983349941266961584317889823965291023669365981564144622292227613558024302012"),
+        MontFp!("482274340065333833495445682213681402212945945150526736364263233985449810602"),
+    ],
+    [
+        MontFp!("3966893131006556898236790392613869798057510088913626163333804949895810673044"),
+        MontFp!("20923301526284527685000591080290190641416245135554916208054502046381491809443"),
+        MontFp!("20838692384005825835959734210506718428443540957544929066941550833051093000166"),
+        MontFp!("8282357714606447781782716442854085217089572080066047419459610560432999443766"),
+        MontFp!("5410651444876169088887579490283094453001167796545260026969919887357676973543"),
+        MontFp!("15276966646285075387317940436655285872037988805762800567413073418506412856419"),
+        MontFp!("15066911464727337689573664613158712498015597773345106524271610486257089622849"),
+    ],
+    [
+        MontFp!("14583790985054968382519116885383608902981814292128186470697458065499359610203"),
+        MontFp!("12059090796146479535492139954279038037217093044815277624197659219529427760034"),
+        MontFp!("7273811886044732271171500579064359282424476926867187108258957006777685922641"),
+        MontFp!("1463086899665237074608503061872751147444637332808872866814340325832200880984"),
+        MontFp!("4403177494620214359779479537027014449448686844655371530169401219256448130398"),
+        MontFp!("10860968418848589590932601250051274256181778387706764281989724391784015147562"),
+        MontFp!("5268786978207139542368199165627108325282167169564314266747401266496556301775"),
+    ],
+    [
+        MontFp!("10683355823176907476704511935094343405052640940909677712096702771871787224727"),
+        MontFp!("12998090263935761477316698114799901126086030852595294916463464609721875730852"),
+        MontFp!("21401280461419124637791689956622923839426783908187419462727763377498739154778"),
+        MontFp!("9827224472048063173905906705579289843819400982583185823840008976971109664519"),
+        MontFp!("6215804144039763858354471461864183189301201862376216122255322421321775987311"),
+        MontFp!("15461308489200344015891625455653488930440613755785081602434124530381300882814"),
+        MontFp!("19336334695450889400681207491394600659946256404722006637851709906131899294790"),
+    ],
+    [
+        MontFp!("1712331165786355540802697725399423752392267480553199895882357858951999960061"),
+        MontFp!("18153038525983970702748717571053178456148003321236490384959117581005013333018"),
+        MontFp!("1080183517033034908031748897211289245459330899463186432840251241943892326023"),
+        MontFp!("8948022108193679628295152361559653763100984324221629445749311939820327674857"),
+        MontFp!("9553342289560502306921915013446606435600388298465288181461633559299564421155"),
+        MontFp!("12714965617376828547637017050548818007690047452402682720666099310241001848988"),
+        MontFp!("10945704657865102635748104464461970844653553427083981539165832149959193156197"),
+    ],
+    [
+        MontFp!("17511714411688352203059545713591160825310809755917403629838415797949261359373"),
+        MontFp!("9253691969419856285051096287845246422848295397226841130282244592511676512433"),
+        MontFp!("12218945350859454581754463621617733341764245716874083264842931063272433793037"),
+        MontFp!("15268139709971695434346690496076067658968455677120655340969837725391575270485"),
+        MontFp!("7948825129295102283421620705853168119104356217418364837218892682579042520651"),
+        MontFp!("6887299291348589691868712194070626390224806410428583073294593431810559288717"),
+        MontFp!("3610235157455454109573625364057240708256027358184031380521552355839155549623"),
+    ],
+    [
+        MontFp!("16532488069063334064099666525339953823111673083177894678898823509406678724969"),
+        MontFp!("19317517725107761280217103201908049748015068578935276576200982249386084367574"),
+        MontFp!("14980901224290526859762385599553818204548992110637275324411078408232697158492"),
+        MontFp!("7741797285700915051013289492475875831764653137095445146268474269974647962596"),
+        MontFp!("11964233864746181868467810392101989052496076326472717372132104394243614334823"),
+        MontFp!("12746657111181947224582102380049766839578185276220682311596480990298620200286"),
+        MontFp!("6408726946032901840418309506578019708113712492100046332894630652186614300568"),
+    ],
+    [
+        MontFp!("20959261828945984489015610988397031913577918654575078054490013338416801523934"),
+        MontFp!("3173674599420546165852740604987014294355430358334465189504551707066179193914"),
+        MontFp!("16110281513253204315524614633789708146700074483476149119440509845258215816735"),
+        MontFp!("17135377580103690088853370572199271964414896742342749305424508776150797285064"),
+        MontFp!("1405769920008485935711505753346340073052795087429311991287498566024570212365"),
+        MontFp!("19088073362945853867763169651582894739272002359692597239222895238839593467749"),
+        MontFp!("19897231284455588615416169252449008151349728648961637517447194842672488184146"),
+    ],
+    [
+        MontFp!("20476415629812014715153863754869742189693986277342067785614833846523246536739"),
+        MontFp!("11074321446706734150375041020583051611133090415774365192315805856051215270782"),
+        MontFp!("15231367549323128694183572409135806408519505225209496441892541205465727777072"),
+        MontFp!("10515952069292929457050921929301902464262874744159361114100398880194109971971"),
+        MontFp!("3216370118771824418364829250073852356774095079734089790620447714552849459645"),
+        MontFp!("1940445924652458480775282556203659335417827058983719042726494187979000691704"),
+        MontFp!("7899310668555694144370607061960060230071621529123669746309839400642332452086"),
+    ],
+    [
+        MontFp!("3125410912833939638823760577011271607678545358020637189655641109813198731542"),
+        MontFp!("2980079409624774815878860133121670095839651294537928173829312563570356348730"),
+        MontFp!("3766498515736372882285796238406751547889526137955288498682767455795237989580"),
+        MontFp!("21751217522789414135074956130080241003845828660310903627224390345319859795839"),
+        MontFp!("4947229586642010378772262640583556676497656670779800090478805824039760706318"),
+        MontFp!("2168676839236948809859825591626629233985269801981092020040909992251312517552"),
+        MontFp!("21172906642114648036685108008020762271569381607092920279879047961076646303327"),
+    ],
+    [
+        MontFp!("882675742500939602754673078407141697482716600335919344527751158504426951699"),
+        MontFp!("20942968937722199705624825492102184647835614761458159157410261242387423597787"),
+        MontFp!("21880640497503102067412608072166388563991106464538369680846671301780353850077"),
+        MontFp!("17593472026567804917122179982860735087124786197105685847979050530954084564297"),
+        MontFp!("4492875530722152383516030266828166766820778742874238188105265500984280376666"),
+        MontFp!("6799763500412433367637987497601148507907071065930142757525839585946238894092"),
+        MontFp!("7812331664758167657763399273963290017340604299019483750344476103319142702775"),
+    ],
+    [
+        MontFp!("2222332747647756867926707541092465789402467819000336747029352557749400316077"),
+        MontFp!("20438798382149666667185974604464532451975024544676922060351031604444896151494"),
+        MontFp!("16155157103796724378615022758633778903205872772589663310774455593497441785913"),
+        MontFp!("20281325298063880945091623185126257485818350714264176365501683813650871716911"),
+        MontFp!("4922178080989486450454493110764936742315495846015561426329316977670113220071"),
+        MontFp!("19579063976700768282784922967523980346960151903154507737857728349662090787824"),
+        MontFp!("2458828873355000645851832396764221987760639423132968569631493912353159373462"),
+    ],
+    [
+        MontFp!("21166618206785010755521994106737991950548963896649678270059527421944129497211"),
+        MontFp!("9131643699583013708059191290958290089892787165715294157378879201986981390031"),
+        MontFp!("1820371114511473946932363841206094088983972935646887524223011276305844153307"),
+        MontFp!("7264184404232663540867032945940974372967974872966180860960243405462016972362"),
+        MontFp!("11228656105550475045610757902396386402555430893045183008968975441800824215261"),
+        MontFp!("7151503559113638565935009743218857812859208253653498318591469659718664783964"),
+        MontFp!("16876040581364499037941813142092448836399042253618385783944016186340703846779"),
+    ],
+    [
+        MontFp!("10334125383426918152464737478646460879481305348617711177774418125714273980769"),
+        MontFp!("18900559046103390399749767994653107625464807708680067464279674225251110804100"),
+        MontFp!("18685667289312169245526749652972366835289568864080726348092618145885982989561"),
+        MontFp!("19970582871354083670567197978171723431124602481748785146813441774826500485907"),
+        MontFp!("15873472427137024971035326229485784626398898771525077832924901475242073457867"),
+        MontFp!("9090803292122260583635467396769157643561973206888822931647063181944243467413"),
+        MontFp!("10156295009710074552070572489422360071526675259143523597882131082376797944708"),
+    ],
+    [
+        MontFp!("18600630374968456966046654667577076758720435487386724419578803020365834014000"),
+        MontFp!("21292291483064245088298314957584631356250347533568992016547598449487977536460"),
+        MontFp!("2784266893057214755054197979675795184619614089277590464548240934105557638370"),
+        MontFp!("21206743389683892419024645604723431382001453245850423743581664552645211926469"),
+        MontFp!("7915761821775326316473924816837591351530533394717381318596295803119061411675"),
+        MontFp!("21881095237485064870468603451853549262304643738646051878343976465227744077912"),
+        MontFp!("2011784725603622472271597952122938645154942022107573948889667939904597454410"),
+    ],
+    [
+        MontFp!("21059869383015715705096974077910228193608826877524913363323189378554601804559"),
+        MontFp!("13660545486380051482020817701263881806531607595506890631732662177505270213284"),
+        MontFp!("10831091042775967380899180760062457635694790868286967266013231823406639854653"),
+        MontFp!("149288128407476550494800886735600251983375852319258454101603889073198917321"),
+        MontFp!("4032475033542195421623899365282946172767274020529645277615759958662043553317"),
+        MontFp!("17860535012887415629230166789742533149365132198763199254812432302158542514395"),
+        MontFp!("611194463774512114860065022851497908950074400927073001695280142990812150583"),
+    ],
+    [
+        MontFp!("5518364261187313845085346561539515049557757056751872639492957432879259341390"),
+        MontFp!("783263978868449790737487156609432867806742277074765259237378374864740012575"),
+        MontFp!("19059339826992310300213673274315612374137067865428300882729551175173242291657"),
+        MontFp!("3179709304184015397125565132235783368222831063701934511986753856772139349894"),
+        MontFp!("10954198701843076039176000728742415722273043852061382139560487789741501275316"),
+        MontFp!("16411266672500930935370066093245284646483148609897099268661795671514664627451"),
+        MontFp!("14614816948231085620934132277599546641612327229810158468490195811014141518325"),
+    ],
+    [
+        MontFp!("2458257206135880430320027516329707989817636936777744813891328347210486074414"),
+        MontFp!("13549483340434455515002570470395006683062583844603627042649952800864870013910"),
+        MontFp!("14465927800403373425828183741641078057513049263889255157342086762479739044711"),
+        MontFp!("4039391352709218793104596256671892882216573882631238721514928981154171136548"),
+        MontFp!("12750457082077152291009387792121930725761848879916565703854704756389714536037"),
+        MontFp!("20703941646953337308096638741387402857948436803334980867971163138332859477843"),
+        MontFp!("20148755487317949638981041809982361196106823990400472213765926589941031736503"),
+    ],
+    [
+        MontFp!("19035096428824471222963574043396024781574056587456391309795571372815435282399"),
+        MontFp!("13597108420431213178364236660710194375344287228654817880431599113069659963625"),
+        MontFp!("16737817219786305757887002253067607822378794077688837656791543060369162185533"),
+        MontFp!("5164935079689729145670846016031605160169301936105766707946436049006171651941"),
+        MontFp!("21653381930704765824477248798502813954284378782353810890869232482999795586793"),
+        MontFp!("2062605478140760101860087118379474541965619844748678233207247884294051836812"),
+        MontFp!("6841505950265078437298089354417829781031272459823272323626556598403583002674"),
+    ],
+    [
+        MontFp!("18723551101558427097952125661588457059960574026361073828482106612260297969553"),
+        MontFp!("7898804490983679270754258611113569895515918945891808074921872907759024464249"),
+        MontFp!("10882278698112390755842292529204069263813359338030917602809789513528936860051"),
+        MontFp!("19447560013395173052961224723195565400117958329259001072560983848146677205053"),
+        MontFp!("6251288025262210726686494480483550276704856797649458538460443509657307219922"),
+        MontFp!("13176666617050786358406074057104742181338809005466316548399895981897535342946"),
+        MontFp!("20703225796049910173111490454489910459787604528779911406172217267261190895618"),
+    ],
+    [
+        MontFp!("20336720518722954780604743873837334696992422089627753769439653667292899832714"),
+        MontFp!("21420427865372074512365684526694872695798980614525900481233709853915806389425"),
+        MontFp!("2498895690812694987926199054702295457557454143930759961192198950277119149872"),
+        MontFp!("18753512301709603592612141197073246313430368834576850495154922324845448997662"),
+        MontFp!("13229612292359498096055458608547157785066962647476451239567069089111704445000"),
+        MontFp!("2690879919643532184588441383789963956137193400890598777054187145581183393168"),
+        MontFp!("14142396602342548413722428497204107502988046500369932366351553161157672540408"),
+    ],
+    [
+        MontFp!("20448725195660080278132534867269279218381543910636641344871383714386318629041"),
+        MontFp!("2559459540570011016181396098001618067535109329950570139376049832813577592045"),
+        MontFp!("2209294835847631004298393339896770055851570184195462947318472391473531519454"),
+        MontFp!("14610669112573509857774678749257346364319969641690596877040685661582231189775"),
+        MontFp!("15281088465087253563674405311018738676067395725444151577815750152538449780965"),
+        MontFp!("8600553033773805414817363397077178137667131851961144771667772828459236208319"),
+        MontFp!("2748346039979601666392027583251905158817539034260921486084376270967628661657"),
+    ],
+    [
+        MontFp!("6854960712378511006304629447898292218014632388505703802374806527561178043857"),
+        MontFp!("20207552563190343462280438839438087615024485494479390954719687107061991587248"),
+        MontFp!("10281541252271366635718295778088948309847900730867531177275273130071062184625"),
+        MontFp!("18855605847424121529776135453072696981767402526737712879984848146282568841809"),
+        MontFp!("4160214035780913418097601322951078913381556877408879904436917334405689553255"),
+        MontFp!("2122867135885631508183413043949777333811557914428796322029495785048111325437"),
+        MontFp!("18793959580906171893053069386015945646795465354959679615181136313144978078417"),
+    ],
+    [
+        MontFp!("1043591673717355695648236328597936528752358227297053230241551190351813693314"),
+        MontFp!("15686469257015275311444450012704351019335987785561570672026138336552980987277"),
+        MontFp!("14048856209379833670666148034655599475317994357805584661156301746235313941815"),
+        MontFp!("1011563953969880478397969933799483261900428580241502003261587014788238280391"),
+        MontFp!("19240556623066672446907714818724971233422104071815927265423017590508305430997"),
+        MontFp!("2121904286573815063480388650799381683473766736407678915747169455786741101182"),
+        MontFp!("6724437969134367395210139771738563153857495313330774537559578422672993498270"),
+    ],
+    [
+        MontFp!("20206855573383441961836932177838081339503382415601366823182724056749038447809"),
+        MontFp!("3659051978213562322887447057085386386485486575515693147713900345497451171308"),
+        MontFp!("21246119528547168535908718411570119652856799993958321864163737649108920924448"),
+        MontFp!("10446114322905404392321651684574668727564081327779662579984472408056125404335"),
+        MontFp!("10052242287865403393859620372179811039720807230902452334457123873762222543944"),
+        MontFp!("6373462744579965543231173757071025010089494620309953425653057223643612177083"),
+        MontFp!("11716070974813426833631730493593924834405915845847679294742728105127112594434"),
+    ],
+    [
+        MontFp!("6451284530793440411577197006976867289209413848762574411101073727224316913966"),
+        MontFp!("20143217291446069633369261481904349401356557325260758866598205109039367201468"),
+        MontFp!("7741896897172494958877302103827661518814930985518070029789560123401964418102"),
+        MontFp!("7414486245715284930410091802521351113719159777210731898112598211035848096490"),
+        MontFp!("6480506916211642204624111742530825907262535747743645014149694168805302825019"),
+        MontFp!("18349725066341807634895742572304899830893334427067633858521634672944685466440"),
+        MontFp!("1838291082333887710851505844271184097051704051003105078056248035350245616867"),
+    ],
+    [
+        MontFp!("19201915197596065583046168024521824662441686729039260890206806469763190071269"),
+        MontFp!("11253788423541320580105520117231178489492440242200599071301755928628199128159"),
+        MontFp!("6048832714406694444296771635481934823208451249770515560893368035838759154821"),
+        MontFp!("6398008918881249487422929614611145638894557821587972164243877575640548705346"),
+        MontFp!("7013037564266297435879776776659289982125632651326438965546874242685502904730"),
+        MontFp!("5942504790082366811245813670914617310604940200824079289270465669331434165301"),
+        MontFp!("14344789199380317440464969138686896230070901882253997360605407637865754361287"),
+    ],
+    [
+        MontFp!("19920212380356573378521292048728904573841049083972983190424200459025557666792"),
+        MontFp!("8983390577894750782268266038315113359711163721228398686939390484499979421166"),
+        MontFp!("14953991148867572055684497824790735528852361750007063016470842397064705671772"),
+        MontFp!("5592033578501586280289038012647352732276003389059749788953239057845882297561"),
+        MontFp!("14076883072716069263619564306953450824526010844333044566762059693672378725675"),
+        MontFp!("11108270411921226463443318601950168860230077781212396032908932369105145901793"),
+        MontFp!("3681277588815101350213324449908372578846563884174807724121308021640034446476"),
+    ],
+    [
+        MontFp!("7194753190480156904207319938161903897566477363779122267985209483435838216959"),
+        MontFp!("21241255448366937244332942306324590869759761073985963892514045368815880517382"),
+        MontFp!("6203071960722514588958553813186803009742459823360660333787981951206442471249"),
+        MontFp!("19041823565851118046937769551785013706136778514067168239416647071096062639366"),
+        MontFp!("4928136619692555022185087228378238193895894009623071873887735418398682287593"),
+        MontFp!("16266329364886004534411977872528706660422476743809029518681886596981922182359"),
+        MontFp!("8814684891729998059175829142248330760704444206534875755023421115211106199303"),
+    ],
+    [
+        MontFp!("11072277000652722690981202459933101924925520292174200155471966778637063588914"),
+        MontFp!("15889576313969861857250394875354819627977602318110620311480656842740292435237"),
+        MontFp!("6934515229262494305594741689326968268143898236690173897991110238064230886755"),
+        MontFp!("16212991575388366798683594066983659236103186124339324856776288894513503543244"),
+        MontFp!("21100508914867482363389012032457112622475533432309937238082785660233880354422"),
+        MontFp!("10381104469089401657446748653199843213201270332853172509558263968565255702795"),
+        MontFp!("8849389605935865968361613766905708889092097013638425059146677490704442276611"),
+    ],
+    [
+        MontFp!("4826404934194100291623537890117339503344940312401101713754206109744511979962"),
+        MontFp!("9981819567268652304810465083896863711149056310505889216307212434682251812603"),
+        MontFp!("16218484218588441290424553684558267080330286201433140852298971691458926313766"),
+        MontFp!("21317661296916247018967238829275056855142711494630067664736600708605437812892"),
+        MontFp!("19523923008662567951910986132173659591346561824926093935331274289896011695634"),
+        MontFp!("21439241836891927940168832009944210084078628922824257988298290967895179737163"),
+        MontFp!("3818036890597976956138669961319975835941979944306305168232209375279960168960"),
+    ],
+    [
+        MontFp!("10212547715001519604442389033695156945619060410131175896383181616280631586732"),
+        MontFp!("956283172524544133830416114111944076629240232397666924807554743752464221045"),
+        MontFp!("8545109273807246425343308224167362024331960554428088718932211551700420545275"),
+        MontFp!("5647769597708100114837534314408246331518385631750569421373379085922684908872"),
+        MontFp!("21776221280695269311212391423788179027868152904973644113087833004348746215729"),
+        MontFp!("15989020831232836203074762591626149244364214836699154611339161287030952623233"),
+        MontFp!("9384665943619921791886218744024370375464874104981653298499433530463000935024"),
+    ],
+    [
+        MontFp!("15469006121097295841026542766455781293432005131673839148320165243166330403027"),
+        MontFp!("16103671377537767724271717097892044266704736999841135349844319906338275108222"),
+        MontFp!("842367229428650719054831004741080336526228967970570607897528985803108607790"),
+        MontFp!("8752325400224955775788313769797750158375262384121380328719514077259567119347"),
+        MontFp!("4803861091350023344885030428100876947830986453029412601567992550504530969575"),
+        MontFp!("7917553047944370948250445233027936387189889293110390303835890604428798853681"),
+        MontFp!("16378323148632546424902611135263436821435778030958161546757828745002247975096"),
+    ],
+    [
+        MontFp!("19873719885630097137106352132870659633926425645300622070145979694717581586592"),
+        MontFp!("20324790419158243246762098227260178678767896786893299456278167341205663612964"),
+        MontFp!("4358908354524026935988729716331497263147669784003421920394531784876541301801"),
+        MontFp!("14403952632095852077754539203207047943619815438482171213105824864831554185165"),
+        MontFp!("16410713482142323347391147127545553384558868490870150984280601225023662513809"),
+        MontFp!("7304216341846662695189617252648753140769311862815448449926830269690397729157"),
+        MontFp!("16792943782280077475956215580025612636120139194657275471595325031090407485768"),
+    ],
+    [
+        MontFp!("18494329391227402645175320826355306995912366111176422593669423022411884295357"),
+        MontFp!("3277597348237827068690736756050060740435013727549848360800059544123155276133"),
+        MontFp!("9396765756719511114743964794180256605700037182617127755220919249774110852382"),
+        MontFp!("5637053961584389263881381098869862042993858662768294676971865632259649027245"),
+        MontFp!("1752142832257643043564515360000718468888861086573246457619082905919623770956"),
+        MontFp!("14504506574384680785750882507533398260948836347427103366421836731538357314790"),
+        MontFp!("18947994518078004413210940685748534988014581551965984303066903086446389273117"),
+    ],
+    [
+        MontFp!("8931855168578615387850254663107425567403115805663142600825724478150698936342"),
+        MontFp!("10982092525200624040399870568387498905840578524691489797530932831401946309626"),
+        MontFp!("4738907023206802373255186532236849256768509848242049657234258536668430260775"),
+        MontFp!("10888145285628319545262252531874405309329869513560101920454793431198094714989"),
+        MontFp!("4767721624212785367044047554655794533816937807005608600525762243335180089923"),
+        MontFp!("4054394679973840378112083329204220302222586590732553688297938891619998137578"),
+        MontFp!("15390471663419625573793381445844013245022413344196724396864223784781333233143"),
+    ],
+    [
+        MontFp!("690498740448849288977645176879593806019080276382495160049117613302192708860"),
+        MontFp!("3326968907274045758110436838010900592335267522219473049427145975873344598768"),
+        MontFp!("19461545874830130561487975864151403334363998126023624462211037468138940028328"),
+        MontFp!("2255249425919459031033123095731665691066980364231819200773725596456576056043"),
+        MontFp!("17139538647342063569964264947811360956712827863014723985947727876623459280539"),
+        MontFp!("262834317961189780923232082352297808796511874872711860311746704570027370416"),
+        MontFp!("17784213646586812350819691264737755884800773322574478474130308351003659945289"),
+    ],
+    [
+        MontFp!("9206479615073686723914227166450906925650471865894639492301222855979337534393"),
+        MontFp!("5955379232184076713510750681781395826148323482009739159408415185190732125682"),
+        MontFp!("16345512244217240951729073298135981012471478596479891072149124888060645303490"),
+        MontFp!("20053701095030547796310908765544502773063879272854547881438596069907281565287"),
+        MontFp!("11519146559536679602608982593432194283609736022486509747046459824035493513614"),
+        MontFp!("10868663839942247532249591973192159672852196011910414460124452013501564199585"),
+        MontFp!("12668355291693420029179738224611760713369106517542315102687346083105601320689"),
+    ],
+    [
+        MontFp!("4091011252347209563858280520339886760216002486858313383741839652119084430270"),
+        MontFp!("11416347683590132388448480763970462739172261435271326798646502987745949753371"),
+        MontFp!("4462763980178675172541782335457125059884067698347130082276003539434128058577"),
+        MontFp!("21728891122467658477520865529973242372850367356840114983386033432316519759391"),
+        MontFp!("9556106604731806817435679463077765288658189491612307664294729425381901530224"),
+        MontFp!("5086982973132652080709554654284904229374030594786774699435814748257879554118"),
+        MontFp!("2278505454992311041650060186856758463754878439802195559533882189615578260695"),
+    ],
+    [
+        MontFp!("16123495070352975934848591912315341924608875638550779884194576881433498909405"),
+        MontFp!("13177225503435100563531015597038445430211235761527278782674200718068329833622"),
+        MontFp!("11626932451843299545922103072142674578946680165802341368625957942237790110177"),
+        MontFp!("8872973246419344365802198448930136062421718851114220299577394844231810068090"),
+        MontFp!("11920016786052130191738519934437207519332291620474831138559948859328822621221"),
+        MontFp!("2773753221970604083383541092979093729869734021029185810064937974430862835870"),
+        MontFp!("1194583082499114147792330367943150006952486615245506995832323057119894886077"),
+    ],
+    [
+        MontFp!("15293312601348482070373672684782686300692505365845870624263228679370968807837"),
+        MontFp!("2292156760291800990693425534213440357167359161992251338587906324724034592198"),
+        MontFp!("20920049766730284147153707151387304988393631464951398563908410768221002588086"),
+        MontFp!("3587899345078220957148828249287269521408604837648269936718299413697642586126"),
+        MontFp!("5857527906708110948691023855516662527925762284342493618496858248142623857037"),
+        MontFp!("18312267494676788897591109008609888960798722042916784593521762607767538629817"),
+        MontFp!("18354455618287562133438807735729369657256664914390381320892039403006410339493"),
+    ],
+    [
+        MontFp!("18594037435499535688023807489676900345345731643180370940972090155512943637000"),
+        MontFp!("6361231157299815359812386352981667048590510979947935475914610076041390336883"),
+        MontFp!("6503045850716008738909204934356093641022474278658078426701342798380459107813"),
+        MontFp!("15826908470360778431798326530563200301151807861414464213699967513881040969457"),
+        MontFp!("913167165738148713876672473302437265273760468892350716109373788573860454641"),
+        MontFp!("5163418960719047707254162004625467116036830361107107814320243058319914687515"),
+        MontFp!("1852750695670141634014249062360862036043602867770163972096325792863710036947"),
+    ],
+    [
+        MontFp!("16164029969996795952250343426848596535809001568622155377829217918121790073916"),
+        MontFp!("42291476149937488089591434144089904529405222471677684973768504172369443350"),
+        MontFp!("1329340386229357940610579826659090359930768580941108555938139535621252899508"),
+        MontFp!("14087936453397725507000489457270864434699508074557952952329368237400407748133"),
+        MontFp!("11454917885298514922755456675259734718428103879515668717779418480236210705323"),
+        MontFp!("17749966508430836878443008025013283275306943216523661550528505419303121693213"),
+        MontFp!("16617298839486771009961431205770630163409905047728421465641369616889696635464"),
+    ],
+    [
+        MontFp!("5622873871440608391107520706189063847917690892897751818294742462879871297589"),
+        MontFp!("13537715561706278379083684257583804567523085149672090320983273122424669242274"),
+        MontFp!("12609629910090871112615676094781247031353826207267723991911250780907380059468"),
+        MontFp!("11881347692420971451998583525696964339513193164613288356598017302547676912004"),
+        MontFp!("3620434358220496198439193226313617496907852030586214671337652678218740406153"),
+        MontFp!("16586456872124455799862826347901525401871594428044067424833235946565396779382"),
+        MontFp!("19602593015746956165116919928045364895525104709835703557292833702385934632182"),
+    ],
+    [
+        MontFp!("2465427491077301663150648330772125184470808854603184374760649420983178107738"),
+        MontFp!("12521323976712195518272978277895155774288446093713549157148428964880747896725"),
+        MontFp!("361951232333654306694462853852464888974834703718677826403016226307188397185"),
+        MontFp!("20048343816024297162848487251896481827914904696805156112188099141327595641104"),
+        MontFp!("997638030405613623344188782838773314122493364653596616029491564227193697621"),
+        MontFp!("10932007654988104622042938184134556963651043067553327861790671211490960094259"),
+        MontFp!("47171599193060570819891696279547021610376047998583333086685382152080932821"),
+    ],
+    [
+        MontFp!("14669115378939104862697280661831896914139331878760241858539421915983017116504"),
+        MontFp!("17868874372855679948405169936193924176514630305572838555185339642210810710203"),
+        MontFp!("10178296575837129106771098084407669500326673901243393867574658658064222502028"),
+        MontFp!("11497182727976130924559852428316615034304736115488257034951588831868596612725"),
+        MontFp!("18847036158089242140209840241495282890278502700082131513222116906134183113862"),
+        MontFp!("15514518995390761662346743876733004358408187550386554449789531199638765348953"),
+        MontFp!("11474102901522012346251529527050392650125347221410246734211005177721289856415"),
+    ],
+    [
+        MontFp!("6612195415835443084676700243243174090072629504450965229103970796390091290688"),
+        MontFp!("11572474094368358234669561324969692616275099241307798860733942350364532366113"),
+        MontFp!("3855324911963410548772360326122995145790506408472649961229511965629894550308"),
+        MontFp!("8802640003128749594245736338745752744580147773009816234644244502373660889677"),
+        MontFp!("15676839305513015047736600040932186843826469281853634239081282896349443894145"),
+        MontFp!("11124722103091011602185413968164672678635980457394627450785290630813993266691"),
+        MontFp!("15087674670944618980358596427703842917302233637812357643695687556421910213028"),
+    ],
+    [
+        MontFp!("457555060782651847600218200815104907046227486293278645126081160142069992497"),
+        MontFp!("5340353060455057701755599760342180989590806327490432497082435572367648024359"),
+        MontFp!("3289809733259936118731355294329652879189400852472418229718273887860572748363"),
+        MontFp!("1821386174933044868215348232606758690922944887434531299978498726875279584854"),
+        MontFp!("17399236630582894158137572250502674699298844870791766041927951699287421557453"),
+        MontFp!("16772722824042046255416248879357647708113647471330900665176012648038469814744"),
+        MontFp!("331374066696126093678097185404981758791664151917354547180452342655690460271"),
+    ],
+    [
+        MontFp!("5482079579065945934120471179616600325379965440378196448353560421120276746028"),
+        MontFp!("11861638874356162254375133266687016527365630872709665703116365332534843803431"),
+        MontFp!("19751278476934230895840638614095718373810690662562196455711240141902305648888"),
+        MontFp!("21017623330912840225230534280017695045717261514215145256795880310933667407841"),
+        MontFp!("9692530233397639077769939390011937602190121885296235066426091743618448584134"),
+        MontFp!("7914031992737639503490179289412369887137436318696390718781298556229610513180"),
+        MontFp!("5046304088054212585035723354298412694927209198400753780585596829596665931980"),
+    ],
+    [
+        MontFp!("12735457541003664856181534137486291132119134214862779086936585300598349629287"),
+        MontFp!("8144204472889944485922664106370529127382213990656088602566223875490414163362"),
+        MontFp!("5526161442679804982165840590640681348630369336752481706044759543203459722566"),
+        MontFp!("4665464612431440885211271075488840033628676516298384234452346107374012633528"),
+        MontFp!("8451965709652752887539585363308640999657377914501438391781526068371105983117"),
+        MontFp!("18990458193856163728406448194111866469438835810342179114684453609893347662421"),
+        MontFp!("14602960690767985987882800342208585041637986661619503513589079723840776294824"),
+    ],
+    [
+        MontFp!("294650277854196485752526848096008214721988745350555311479128101695333774927"),
+        MontFp!("9930361494944692931597991649915857642608730961125454734483697613693272941776"),
+        MontFp!("17972565769620820679641368732920396905240248490243886868922250461473059009007"),
+        MontFp!("11842743032528966560856860268344505094861546674985872961254820091273444880060"),
+        MontFp!("2260251491209762630871337015316066081541066308706934094017641769176593121838"),
+        MontFp!("21336986809148977544823484666876006147697590184356254785752148187171367963063"),
+        MontFp!("15637234083283356311249527335446193685599985235080555266374006156231977517227"),
+    ],
+    [
+        MontFp!("7637477891046186378249227336975234440873859617986704147458186423096226771577"),
+        MontFp!("10435340982947407847927678888878882924793449778165415690957335683641419176012"),
+        MontFp!("21071574044063633264442120715854514033847137356154103023224485568597330648075"),
+        MontFp!("20085745552872944745120547909310789275453780111307008151203836541147270866122"),
+        MontFp!("2369255222739182549768488367357061329939116877812397072967912842660453854658"),
+        MontFp!("3320710154094663715463854219978294133429318041799642537800174050047893035878"),
+        MontFp!("2437552820481788519744888712380245016748276158860265401041560980354471184914"),
+    ],
+    [
+        MontFp!("6687580113987208531705167517979176727449238324356562435678492283111952291541"),
+        MontFp!("13835828959457330678345759960614663723017667326485961761361157914420441377430"),
+        MontFp!("1823843951353887792473925888956554516299304358703549730900495356152013614424"),
+        MontFp!("18229384804985230011714562427207966412342158903455811854157839446374012856695"),
+        MontFp!("4983049472282717134994110428470567601005310848076496400503178535459679438524"),
+        MontFp!("2047051967230753763135778305592853785901616983565528680886843131244871631064"),
+        MontFp!("17059505494771925862841990046823342770591010831955480339095397897088168520686"),
+    ],
+    [
+        MontFp!("5845823714127413134610517798305104245114036685335948729450609519089263487144"),
+        MontFp!("19810252752845594230307894817800427820113926573704856490871938876757561680148"),
+        MontFp!("20741340243371419379519807725035036726040739024854919427690724405113594586449"),
+        MontFp!("17305746835229988220561638584011917989169628535378748397361130724475478785704"),
+        MontFp!("16273970657972145440112726408308019138099820274904080726219726815138597785735"),
+        MontFp!("4927605725478881247988642936459897069651251926499343645614635597380235002430"),
+        MontFp!("4076655226193629464789557616268492785057128805549395585385432329518368497686"),
+    ],
+    [
+        MontFp!("18134767316186963456589895259454813585756254459227058992203617493951135964914"),
+        MontFp!("20798436806114056077588608064161229365173163847083955162560624566238528904361"),
+        MontFp!("8811900287453512972593412116532745098600991077158875340182906101108258578231"),
+        MontFp!("1611466530857794066271650650204918615746591649578992581483080164777650137733"),
+        MontFp!("19520757346022691586967284723955378385034675472244175822936613026597514818901"),
+        MontFp!("8258287931139503595713718829279050060190693609290797346704848518381891359704"),
+        MontFp!("13807143439443425137076128013998009581746894329904809421858222329599144124143"),
+    ],
+    [
+        MontFp!("2034200548964915935625429760202284220693125881760822084201315022529206424506"),
+        MontFp!("20594375914400911567795140472107624446159181622166676420027082349633992663301"),
+        MontFp!("17773828019575037451999782968066986504577459910353828196403976545023426528432"),
+        MontFp!("10645884969014005687699860915213473815514464399964009808411811895545112650817"),
+        MontFp!("3135829883501342672772973577699379927756997243617424917654928164800203666496"),
+        MontFp!("21807676600134151299257078976418813484444183016737321278512745883771478511369"),
+        MontFp!("14168063038909284721702678019083222059818438340503980617872573468231611140141"),
+    ],
+    [
+        MontFp!("19022539506931505257153342575586362988716958060936788031721967221986624233067"),
+        MontFp!("919797128086310623571009200546035983274688764270933413427846490906074137487"),
+        MontFp!("10651353481391913627770814216074873532920753703051075188645774021198634943682"),
+        MontFp!("21601553598752750925049978818528421110707879819831249175157596816870100048288"),
+        MontFp!("9544964974935674319204796617933096476421551193682156030394816088243121582636"),
+        MontFp!("17113833205578964054057051521784698139661258340576694677296240312431808476286"),
+        MontFp!("9889647672195559279745677506312894570402108521106900082889976819798270827735"),
+    ],
+    [
+        MontFp!("16028191999932520938901585234936954312994452706490572504997534210876573833649"),
+        MontFp!("19224701772787524647172128751148104366752057774529591812815327738829591289117"),
+        MontFp!("8065294760892477625290114823800398061529770004833832691347498933238361039736"),
+        MontFp!("8385011404987806129246014860479833290406969218526611328586242951296814426438"),
+        MontFp!("17626526623257098006524211054563886193098683828265081734658432468695686509315"),
+        MontFp!("9760584950604786147191288118087660976225563461953070125437519145090832114537"),
+        MontFp!("3282956645059793949082172795607530130101621492305193365378997603911833418463"),
+    ],
+    [
+        MontFp!("3788543541342252822847978185963388795825378340921321139695221828685330606335"),
+        MontFp!("5728277403393912877393143174229934529937061751983246730506397742038949251701"),
+        MontFp!("20532577038632159357383817240596922896191478140446876998140515404169184846609"),
+        MontFp!("6138500779693128517529525961343097735306947649093633133232282430353593175172"),
+        MontFp!("16387038830089541476468870208162294639575042754761542956218362331966004300870"),
+        MontFp!("10184264376398708852688445921404363179240954227345322711923845040842165453208"),
+        MontFp!("12576299651793170522912156101640799825541149618303513174146382191633847258859"),
+    ],
+    [
+        MontFp!("1340015400080181141720946234858756484323564628916867888877667239334982793481"),
+        MontFp!("733959369856163480135680991009606990817015555938726628110611986599242143578"),
+        MontFp!("11467033813562140192244869512537566463715027496952375979909160849747976831918"),
+        MontFp!("4619667645046391146577435774790188488541561222783010406420406869960248783331"),
+        MontFp!("58552761198135931030902257754896948615688045302818928845814661296914920622"),
+        MontFp!("1199849881730507352706524556330002080538296688430736582840314007371442152147"),
+        MontFp!("7124502590511184113044595527748024819132713282667933641439666531514739645089"),
+    ],
+    [
+        MontFp!("8623660134669459112474551498616256867375253975034970808437732784494772311361"),
+        MontFp!("12655669439191191182341423414424342421477486764113555800095493091893820045534"),
+        MontFp!("18432703875775002490514477493898870315422995231506677048275960580528644904682"),
+        MontFp!("15467220287938881354678249472400749704814316816035426814619089032223454845193"),
+        MontFp!("2851120240492392321044027263769720216640877441121430445737594074121655318176"),
+        MontFp!("20519914249934881206828098454303256358482675671718589102535780334267934987941"),
+        MontFp!("17275124961392392047135728713829752470490098022504524438869454049765356211723"),
+    ],
+    [
+        MontFp!("3323710067527231515807603961736782048796606296990840839366613937968342331886"),
+        MontFp!("4468708240622802562056471128793253296493002925988003094771284205007772045098"),
+        MontFp!("9006494818135081033869830730030943407240565201693254355620348420258773924028"),
+        MontFp!("2624130417875598753127999576825019766166727976335690685433712946223008520912"),
+        MontFp!("164131399455376615654870570697119442360078693174350746600132391198500093412"),
+        MontFp!("14931668887432843139264972187415200544679230597820424081936926034478502874299"),
+        MontFp!("1638753880783574431267395352024193675000113296497173968722590753809640941864"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=7.
+pub static POSEIDON1_BN254_T7_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 7-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 7]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 7]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 7]) -> [ark_bn254::Fr; 7] {
+    POSEIDON1_BN254_T7_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 7-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 7]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 7]) {
+    POSEIDON1_BN254_T7_PARAMS.permutation_in_place(state);
+}
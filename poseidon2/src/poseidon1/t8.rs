@@ -0,0 +1,855 @@
+//! Circomlib-compatible Poseidon implementation for `t=8`, i.e., 7 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 8 8 64 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 8;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 64;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("12051363189633051999486642007657476767332174247874678146882148540363198906151"),
+        MontFp!("6387692555402871022209406699166470377527846400909826148301704257996818597444"),
+        MontFp!("5501161701967897191598344153113501150221327945211106479845703139297020305204"),
+        MontFp!("11704372055359680530622226011526065512090721245437046184430227296826364812961"),
+        MontFp!("1448611482943320179763394986273491989368427112997509352702795612841455555221"),
+        MontFp!("11429145481524962708631235759094055797723504985787912972575745356597208940857"),
+        MontFp!("18021858528471759023192195347788820214752298716891162685115069036283008604659"),
+        MontFp!("19817577944622399780828745167469547332167999743980557486183403063955748437619"),
+    ],
+    [
+        MontFp!("16868980302925985719076889965831700407328155411673408077166038059874616424216"),
+        MontFp!("14717432944340806781505761211058502775325970511884444497202848327581753493322"),
+        MontFp!("6273484270523289845253546319956998489830555038697388950038256377785540828355"),
+        MontFp!("7726043103954429233325852791166106732104332590864071922310309250010129731951"),
+        MontFp!("21052353119157611359715869265647287129868507410601603360127523286602350622783"),
+        MontFp!("14881796557136180514390287939887071460258251160875710427576954128871507002642"),
+        MontFp!("16341327439981153879863707938117355436152690262312411284193970279829974799334"),
+        MontFp!("10737675906107372302108775622264379258926415910493665638388971468924879578019"),
+    ],
+    [
+        MontFp!("17652699767629314433191915267767147860052614073432922215674211498672835339113"),
+        MontFp!("7457854400138129895665591719907473144796504905294990100367501377050420942800"),
+        MontFp!("2136850802972823585140870808569264373787409642804109426616292140046700710743"),
+        MontFp!("14029467347298896610468190615212519453678316548442709087191045978401072380889"),
+        MontFp!("17927699952921266007590534383984238136710494507499176330493504416180410161683"),
+        MontFp!("1404719213830610030709583332543456268094679432456284386108188509031502237811"),
+        MontFp!("15774757292079018355173698870903422490868220545526384876021336136892926326596"),
+        MontFp!("13992040374687149195439840459922227749294794072303579532004750946306028893274"),
+    ],
+    [
+        MontFp!("19895094843870397064274579657905921299619388074084417486420154568847155746891"),
+        MontFp!("943833985612967248618844364501030453998731991825395875139617731659343743483"),
+        MontFp!("18334641092245356682448009823797080853859186519922476229272838591594967878678"),
+        MontFp!("12440287044655505483131716236615633401781045711053210640202766768864619378050"),
+        MontFp!("19130942564098572936370308509908873069169152245172660555660369853346605570826"),
+        MontFp!("13687979327148217614616687417475244897906227789285703940171633508277844471062"),
+        MontFp!("16887921327479880141959363366262254722342925451159884082370074726344024008329"),
+        MontFp!("20378003125024698406589040864014894045124234695859352480989552885205935609512"),
+    ],
+    [
+        MontFp!("9961553412530901953022991497331082655746860319830309417179972582392489275965"),
+        MontFp!("17755268665220780466271147660314410613992814315871705414495724015443459797439"),
+        MontFp!("15394131279964876131165951719955566821453162041574233072088124095626652523043"),
+        MontFp!("12668230348320365182085867728169435383987570924921845106243310905832768752125"),
+        MontFp!("14046812111383844816383347755263287603387502282980410255379630204396960343368"),
+        MontFp!("11590093969266595252327261214735156204516524792938909229175092594303424141199"),
+        MontFp!("4623517074925959322927421514289132524032863498392441375476410779446526502799"),
+        MontFp!("11550389531965919926150256242174358326491059727918559332939872696684299343135"),
+    ],
+    [
+        MontFp!("408487396317981846281976563618407581852133413686169882346565860317912856432"),
+        MontFp!("10717757571561029382519744040791773994731123262749372629687813122941078154016"),
+        MontFp!("21323787615496251932181222397986048515693661833099659753170924658480548866921"),
+        MontFp!("20780799310067873093555276926357624414275975377319941015818682052081980020892"),
+        MontFp!("9948385944800296129032348634683354181546876394979291412116493575442898426065"),
+        MontFp!("4957033413111065858035065225611730571499258914257595411830870977545212164095"),
+        MontFp!("5227254936689728148737265263965107718869714128941995977191096572191110991079"),
+        MontFp!("3582814872786080867997255427740166393615552773099677831398251586195329933975"),
+    ],
+    [
+        MontFp!("2136737803483410555580163900871515004623198990079556379647848364282254542316"),
+        MontFp!("2965752098571712086281180512370022839542603960309127077035724860894697782076"),
+        MontFp!("1478525086510042909660572998242949118476342047444968703549274608283885678547"),
+        MontFp!("3563375996604290844805064443647611841824012587505923250907062088840679700555"),
+        MontFp!("15461452581843517997080348781604020486994675070532901120353124746087231692278"),
+        MontFp!("20472517020063295821544268171575414161230806406668271887185150097779785573889"),
+        MontFp!("21058001005918321995459971112208002381460494177332965873048074199074929946172"),
+        MontFp!("15805746645980285645504697043988763170971539673993759868487715403982423015009"),
+    ],
+    [
+        MontFp!("7141240965656437676130015766799708612940092856280620325870466265817008351948"),
+        MontFp!("21418010338098024788434337801477243267248314524079104488811186206038748626642"),
+        MontFp!("20272108634229595317682817969506273496034097230124371921628691470754475805838"),
+        MontFp!("16734095147399743907618148751687506877774623133599770145304816136018239803101"),
+        MontFp!("8439324632051181834455499457268557602816180314723268640869118054114888151316"),
+        MontFp!("4953900961796661020464968131122569974581908893169105485631905994366550928492"),
+        MontFp!("18071625983692455679240094911529791119099077429122520426399552756115503123111"),
+        MontFp!("19638917592063029281156873227053827678889868373299664608974791764751784473040"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("8243355230504186170667337521705529968548180153769821936979698914169521362326"),
+        MontFp!("21549235422807751640146583237936799392598740234259041629069949854834009192195"),
+        MontFp!("15309683586299089746803554818142261058154570215179112411063662706557055610156"),
+        MontFp!("12007539402495575255755232938576927941514879725482443887151392201585760698040"),
+        MontFp!("18793669376013417649313139054009540629720623019893420956495818743913188610515"),
+        MontFp!("6637074549079529416739232814950531409613090469922787253991308038219905474403"),
+        MontFp!("3042007484821627445120830225760006405192082634864137749621636257026891883326"),
+        MontFp!("5337388510268581167254715112479133594089770138749507073603490761032513368106"),
+    ],
+    [
+        MontFp!("12325446798142239188409242319577957593792614990556679862642230477712636037037"),
+        MontFp!("676789245562467194073706116744095779362669155912771165373940448756070927910"),
+        MontFp!("5854747984773506278911353281567883752585612596682487681686710970786834920041"),
+        MontFp!("11245406467967785626327694659468342056789182160059009120973665143197638081760"),
+        MontFp!("10395601815816075071544509552592627172226369015806880764151195346316980080894"),
+        MontFp!("6756096862783612163697577917108261850810460757753491809406999449771712474223"),
+        MontFp!("1708595072322964393019739105130946639405776432058599259998973103484499438306"),
+        MontFp!("2817817145890818701877539103826217929456570347854153048034669346981432211659"),
+    ],
+    [
+        MontFp!("20337270972708498869284875601749656006552838338471813066271573323209168221011"),
+        MontFp!("19192338172842323468707146045612196807750411464817516820711948717057036544820"),
+        MontFp!("17223253657227310295312621282100531845543865578630870272599545474783775759681"),
+        MontFp!("15004735209586276209064505708625280228119288986650187909395010184201059452346"),
+        MontFp!("3875652974956649356154345677088455126258183810851242537013757276075769588050"),
+        MontFp!("10514447960615206081458524578173743817818597124482828867666984705327684376752"),
+        MontFp!("2087647010835075851760610474040959236825470174942075295716631067964093542910"),
+        MontFp!("5927163251920754154392384551305623830535034440727310604898855074616515892551"),
+    ],
+    [
+        MontFp!("20585333621997037505291454298836355589763292536744926081563336065939121006537"),
+        MontFp!("19320876518201905459682928158170419256739531666800973485138890064423348282196"),
+        MontFp!("15942638804716709831210239594904570403189415026144938623559274984027906868220"),
+        MontFp!("11197022744936474661934096628367688581641778841814728682794507017845346201383"),
+        MontFp!("11034020922250561671038205476395109731446686553549026383358725302157324264144"),
+        MontFp!("7574933006942933995255906769787776608010920618615581322603847524789684181970"),
+        MontFp!("10061361506744906780155460423367413099657465765582917482575074226383566926764"),
+        MontFp!("18611343221859570540963418999548488653944851224739716224660835306206658947980"),
+    ],
+    // Second full
+    [
+        MontFp!("2500862781199005154907185089778932765489906994365960644306361544820582839768"),
+        MontFp!("21880931942133046355810983155922578513531850539420426025723154879488808270315"),
+        MontFp!("17850206894189265929807971665186479441938275634968267590809377452033564010382"),
+        MontFp!("18427883853363251276513100116480886898434829323430684895879968439179171503760"),
+        MontFp!("18758795974827407022563870795763356401215175366078230621502388363785425038612"),
+        MontFp!("15672649260544536516531393740985073476934112035694203841471047634286525005174"),
+        MontFp!("14497479780124030172334631091033639981498927489925809517218125709975200816290"),
+        MontFp!("11190855071574099336548308963044121660452976926988171712775481672446931541539"),
+    ],
+    [
+        MontFp!("8339442292395337481335048552147626044800877206694030770577319544121541364092"),
+        MontFp!("2461178629683239975488518502624530284391365519847067341739449204945212652770"),
+        MontFp!("3972313936510404965199308344697399140590038866586718833591813109326652018667"),
+        MontFp!("3224811019580618549699828950033477378112059204060062023677479068506440937528"),
+        MontFp!("18443657715765406615721041820828109800966587434816919981514222787674698772960"),
+        MontFp!("666201271764511484388505793135876064418452477237751508215203932379618265382"),
+        MontFp!("4434899717815685275523711262432486808621984251515429736982413712108987655422"),
+        MontFp!("14584918585762085382434085071460369807803840154636220934254933165793423091295"),
+    ],
+    [
+        MontFp!("15646480282455307022430957975574008173154630787861430193406352480280577045711"),
+        MontFp!("402840791633175231660910669665966910050981784044822648466848382615330599909"),
+        MontFp!("15437492296189220094817534101128968523410729375545135146260659057729649968314"),
+        MontFp!("13987760171743052442513877961667805977500573882586118554487715622045738218279"),
+        MontFp!("12589095501858681021442730872878907609617459069328956803139727387371467358051"),
+        MontFp!("17551064250089164193025672794811675406761638177060737129533175904585851772273"),
+        MontFp!("13500706213131978087516005477128059726177752268287240395927379509000435850498"),
+        MontFp!("7331629294073516250840302816971095420668983701195024195892939287001016568514"),
+    ],
+    [
+        MontFp!("12949377725980318589136021850295478499564248427839661600142796482665024587971"),
+        MontFp!("3988955063770305621858590171391799353484164878730082586815877210936858093890"),
+        MontFp!("20512156157023978986265779260320491356890557397261515752540394821171756173724"),
+        MontFp!("11624190532749034673782735319581023504009231230729490439584417709012081446066"),
+        MontFp!("12473562150323140802035699452896239306300376623759190078147999182702752528013"),
+        MontFp!("21504777935543484323252258287484534200045631968996932563017737909760083499017"),
+        MontFp!("16104745906544338230790783632377375683831341202924378150021598903321494336736"),
+        MontFp!("8312554144734150053969625169851557776466370096299754626528722906617398229171"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("17094203924957299390365889251598099482992645049968199405515681968938743421467"),
+        MontFp!("9407145832890449495071969940777105644547801064593141904558463573167881762713"),
+        MontFp!("10921438560879150587765515492087524756046482460218342400194862909363870270743"),
+        MontFp!("15101279960899220452674629307354995123411280418550386595937683027146194547144"),
+        MontFp!("1872357133681596467751878560069114718371273548294363719900935160833598069645"),
+        MontFp!("15505500304018853111989216259257978796595506623204851206292254759641600763191"),
+        MontFp!("2079667978353221447444850850900204451820443725835104896018664141845782871343"),
+        MontFp!("2852655320672908960411014862634757863509253400797831983637863741066632490909"),
+    ],
+    [
+        MontFp!("2702824031197306101989338159138451445088523866133498139857862801497066633794"),
+        MontFp!("14553308731276493692643101846551382187575566516925133957384350697980935154102"),
+        MontFp!("4314969815396483242407853639218064117498232660761075778657880116870422414637"),
+        MontFp!("20236724297078811959918602376319440958076910292454596856154100774072250182183"),
+        MontFp!("6360017115980704736383763605019264589498600998515606807745670287390050560160"),
+        MontFp!("20856970531105411628054833058646203890148287930330473527735908484791842390307"),
+        MontFp!("17691356258507144960616314395885779533907781694329041597441621553108536658757"),
+        MontFp!("4464167934150673174817562382299722091160711333547138388803048452674668158635"),
+    ],
+    [
+        MontFp!("11538922347277268848344412167140306567742076984016453903533772667841006045703"),
+        MontFp!("15558861252260038101730449864896864763293561339637017072015859069059083288561"),
+        MontFp!("931980552683520059135814229579184511049009637966018180567726214946979768011"),
+        MontFp!("12746506550979326220422215987591117730943427023997792332255149062957909690818"),
+        MontFp!("16416138987000536018990311324687201169959549714116951891693452597169869821726"),
+        MontFp!("7473835750915837381583185047008243788613524206396316652305987269933344653773"),
+        MontFp!("21223994082372071324452834147900730753626104062167370333103771844983134656961"),
+        MontFp!("11102363694946721470818933128034696027504133564649607436252022322296041603786"),
+    ],
+    [
+        MontFp!("2666835000155694643357391634256423691785613060199379949509682292216642706081"),
+        MontFp!("16883033667413528795407641102416904598130659502290474063092941543309042023190"),
+        MontFp!("13093053604456598783294628038129487761924241298889312497497820946915331319389"),
+        MontFp!("7426349812936697309541457521193139970366533826612714195359894150484429907425"),
+        MontFp!("5243217285990182677741567384304278362485372018078770234262925321063263504918"),
+        MontFp!("21185490040917275396474067542756068684704036418473170810170344320388557093876"),
+        MontFp!("16181135763579884029508432324330748636846464150219757303321560798898398598349"),
+        MontFp!("18088358880437096005757355821526785623101357556483672471222924931365890201571"),
+    ],
+    [
+        MontFp!("20418860027198053484245336569800730261127301261293595190270103940460998981236"),
+        MontFp!("2058948081811170389115771489993053947061173620273801887242248130631460165879"),
+        MontFp!("6353796008567532863300373986154930294334380098977007704532496889557690195858"),
+        MontFp!("15854609649070278722833415779491666201355987522519101725393408435189057056690"),
+        MontFp!("1355942327518086746604287131396672941922424788908995789539897301592998007690"),
+        MontFp!("10194046920666955610804398522181498854525794643476895032285888778350918459761"),
+        MontFp!("18342608728256650520630397534564293474806178807929639999068140223470256007117"),
+        MontFp!("16101948218093381908101491223075947943147313203969904451859930796280152622017"),
+    ],
+    [
+        MontFp!("9866645853452683082481412876547916795343134459981103407915522925093474319332"),
+        MontFp!("9309485422719740772955698359258466728180120624442685713365406080485336040166"),
+        MontFp!("5201701081505060757054562398073722930344229781365241858092054974705598137660"),
+        MontFp!("5279555243870694216927790669819597822350327573071817682265773244733785382064"),
+        MontFp!("10661662716572743893824841881707597899963881485303936548294117975770384660590"),
+        MontFp!("4306964326426793675768869124893413588264762573088622132302954501394542576141"),
+        MontFp!("19945975928045383298785833694292459276727208605892865429301546022994613804030"),
+        MontFp!("5037834331249812829239656466783521330249138768989720606017856991559732121456"),
+    ],
+    [
+        MontFp!("20693877087308232030611148201802513236570270737947270986743265610517665094074"),
+        MontFp!("17748932969923719316564673051784340920943155490113289807023660243301385585070"),
+        MontFp!("16950307665556055391386715682532553772527550247031548278958142572490582126842"),
+        MontFp!("15034211391483347494286112687349366897258989065045859280146461213731663274520"),
+        MontFp!("3455096385235320554100221104677124747996171720170690637998043454239897385610"),
+        MontFp!("11220329458242704347549150795173830262585759464331372299692251819012138352257"),
+        MontFp!("8230076319752658879891285909687940775399748755759819661970430769188439691274"),
+        MontFp!("4178690445391578185009939705412120505162313641744671740163024993195883735198"),
+    ],
+    [
+        MontFp!("18632680236376151061913536149173846032710756800956417249233907621575802688710"),
+        MontFp!("14168747730472612819827430620596085566004981811676505988180237018638188025380"),
+        MontFp!("16777617016129912124437138351698263064579177499617525409625791377061066895460"),
+        MontFp!("403267570119386144603206457308168792379980670187570608148634410971295877610"),
+        MontFp!("11045890302538505532103216886575539246473207034538532950483165910580782953337"),
+        MontFp!("2632893274667647784827087132221744991131294771819888858265016332574437797556"),
+        MontFp!("14022461303364013571172470728150898521630042996798160127819093871974124417229"),
+        MontFp!("18349129573612583311962846403448135938849737390546876598640066736462315682295"),
+    ],
+    [
+        MontFp!("8009723611300112743690923532773238474616291315457276539919568488041436720507"),
+        MontFp!("3287586297388209299132232426281031982329712892122181769502106059441842217623"),
+        MontFp!("19893256464101780566218598404932657965361824655069879954668551189408491121155"),
+        MontFp!("21779954643920608321663779655887581582907923850271820082121309309571440586162"),
+        MontFp!("13938145028737822338330333388496944993576078307754676998341398757402576278690"),
+        MontFp!("17280605833933949866452995551396279974325968699794264573823990818913515933775"),
+        MontFp!("11562775307500290654949270847967546133812416593099094805234457839659652146289"),
+        MontFp!("21556021192476590536800970202944195471695121915357500612310904064652863447972"),
+    ],
+    [
+        MontFp!("17407055226077297021071802288772735837293135175537846248261973015744713174949"),
+        MontFp!("21295838064085671525042198277220548723525913660103018392096215316189390548013"),
+        MontFp!("14589917958236435754986191512564058641868109230240077937707647376289105324812"),
+        MontFp!("4538073055458854134606640263494592220617270326115451287834630189270577020111"),
+        MontFp!("21247609438242282269742265796811514090579388884916478939008977411932487423659"),
+        MontFp!("19263560475610984724826226948356735903574936974192558145730920786586162783055"),
+        MontFp!("1898614508331499418660051276594019416852890004788354240344418815409520758722"),
+        MontFp!("13346547977920686435662774643991891597826323722140876186086635239306340843003"),
+    ],
+    [
+        MontFp!("12144969177194297999321084025481801838621405926243412487948189180755523714531"),
+        MontFp!("11624156909934489978766768065107924627236090741698411458481638802308500352917"),
+        MontFp!("8674349037900011131899280296161700067911742760618648557038290076406601619864"),
+        MontFp!("18627233188669469962636721109716646416813512041955577645627776298400086440228"),
+        MontFp!("1153719160094308748956884656041023320488424966635003188538565876464091909764"),
+        MontFp!("8000003066081501211900754070779689975656073731442793160620896624291841806771"),
+        MontFp!("12069801117560082050163959286673266840809976769131514316118288648293224324822"),
+        MontFp!("11694828863372498882861202648883355759680038037706633938668096525787115759720"),
+    ],
+    [
+        MontFp!("1181495201505177954430275085371953511604847831716865494220845031383860562941"),
+        MontFp!("18321980275956746302814628602546438645691886543647725888694024551609678639266"),
+        MontFp!("2785661975937033521551267460848061931764727388015171856456622007438303671899"),
+        MontFp!("15557886094116287182932984983441793820379366058597052543066101158081817575352"),
+        MontFp!("175179830261452669822497364983291141568331314582563701393865403724263011876"),
+        MontFp!("10455128373814266139918350629083299308526836847946708764631040462916637941146"),
+        MontFp!("12622681406523708498691044494295298210175441851465578469593208754136900020434"),
+        MontFp!("9624138424345877000077746656879336097173254842107184716328214933320809030543"),
+    ],
+    [
+        MontFp!("11726383465426411877912203592949370178096897707629953853811352568008881233112"),
+        MontFp!("17566146584557385507728086844334319515338136183689530813551207417981719751958"),
+        MontFp!("18423839150858891406289385710861955437811779173242111498197433255650436048047"),
+        MontFp!("17408376662161624435555256564084894291578222902661202310977717110546842356960"),
+        MontFp!("20995943422377609225953642092578140203148330329113983394181012996247925741957"),
+        MontFp!("10409490873284794620245703460832015892256721643100501421596423100640512505920"),
+        MontFp!("15047062105747285153444463303020356100177963702386173227676803770571846532695"),
+        MontFp!("4535940688608096040988822900684697329863791065464226849059470519882399535780"),
+    ],
+    [
+        MontFp!("18980357680792173392910397806033731294240363676914829395702138582894418363978"),
+        MontFp!("16468042735091009392571235146440392007609078458297170996132218787642722263238"),
+        MontFp!("1869769403621899262774247370472546961521039203681166934356431996537822108263"),
+        MontFp!("6151829532330885020831674048300360431343535966534922988242884341920915237665"),
+        MontFp!("14373964388615044752046531046884609884388869283450342961030080770253954449754"),
+        MontFp!("21429869771065858399481388829822721985084474326196139156050788103070270663923"),
+        MontFp!("11836916222341149344359827526882466618136359738495035945807998286429671739008"),
+        MontFp!("4542193081188277792793758113018430324598765345700596639963408884670534634317"),
+    ],
+    [
+        MontFp!("17262340128494663310404052919129368521415818617921877469042393034218456907650"),
+        MontFp!("11614110585474201606235056157412783071151951301104822431509283035322273244217"),
+        MontFp!("17241248261774133453753660970137875514052923171943595080766050681996607133130"),
+        MontFp!("2990875140768570679733810173464987023133165559726680992079139149034178002777"),
+        MontFp!("10032389096385585741539206260012253444831624820404318451026478423856181568200"),
+        MontFp!("8391217416130739565515338215591963109158836617019021044489286448654465296819"),
+        MontFp!("8553700889274799411012667201578367398970695661169430162294018618925895640041"),
+        MontFp!("13529692770771168133213371031275281478756443444824139121847596546264553079152"),
+    ],
+    [
+        MontFp!("14478949636372928879378459122088894160202116364833386541382488835123981766413"),
+        MontFp!("18528743543311452855194545818079449921167163839226390851954136986727320245809"),
+        MontFp!("11724222260540829258562889360923785293478512718704276634048783603461995522859"),
+        MontFp!("2652532822068043785753514309321715043229885635900630208154874285707479247265"),
+        MontFp!("16473666207635815797882774885364997250503755116232911726426811919269547851975"),
+        MontFp!("12436631741803099512327160776479880302093882812091908650798222524569929954222"),
+        MontFp!("13061081443094122428989571162147084312340276850316867585582410062467362267361"),
+        MontFp!("20909566607465067204267258789556187669343825005173558971220332255443231196363"),
+    ],
+    [
+        MontFp!("14278016202378252898173761523743422243750790190417896338147106476354187349947"),
+        MontFp!("7703701752136585609667768350038563449121231460368808945757767724712186009894"),
+        MontFp!("1622258312841010773225479468430896972269503924285598181547410615000034107894"),
+        MontFp!("4706114868510775588142857635375822293570353199661120256611528287780303504954"),
+        MontFp!("12723022498690150801900112713057006417552064300221766812928489357200260312668"),
+        MontFp!("7736508633931646965699972944684083339925061856252811104228904321699984469949"),
+        MontFp!("195095354858363944780141950724441876473553677166595890451203685104276178612"),
+        MontFp!("18877614091447727762374351623731936445361116363480970639310200637662433378180"),
+    ],
+    [
+        MontFp!("17239262588506530491210045452642505719938421789517734104955853192075731537629"),
+        MontFp!("3391556611912995522919492308422471958888145521362922265487749943660431330300"),
+        MontFp!("10164629656754294522862462407441648133619259920942013682702008716587122474446"),
+        MontFp!("11939828733425435518898229234599966533928666730047925120030711579782543312731"),
+        MontFp!("17335155958861138542643885799966192412363788951639890938680530110842555336617"),
+        MontFp!("21068414996957890621467676209673805582866493104159841584377567318112060433438"),
+        MontFp!("18041291613104743972430309067462668732698702146146761776321539150844598296986"),
+        MontFp!("6149130772490689572076747194977244577047643214871016443290724757756394340290"),
+    ],
+    [
+        MontFp!("12105848363324940274456322072887282559016226587661485273111872063034847034485"),
+        MontFp!("5683957548001811989600472365740829603387405501208071642225953069881259762607"),
+        MontFp!("16529542077365261070047716411124689196456625611983373158922227651721798753876"),
+        MontFp!("11961524596519782767188645738887896272947446382672325012202336646508449392990"),
+        MontFp!("9785728068011868312995387469680578201705397880590293454099364001157116688561"),
+        MontFp!("18127416268588083447440821307938591826251677223119815897950307944959875167560"),
+        MontFp!("19296461637807972438220899702591874518336722552660488565818484435311224286288"),
+        MontFp!("6801016831512114134395242293457679538495311188529990156831889204433183626116"),
+    ],
+    [
+        MontFp!("2964298470426582070507861407971247200639242211740381994158541687335361446525"),
+        MontFp!("13485975887078791259342768620261671076376983307468484850600890777864999230190"),
+        MontFp!("18842264035089067687391583729082424222425351385494040849910540441253540345719"),
+        MontFp!("14703642210510851071131854548671393020078600676544458548174965732036621712435"),
+        MontFp!("21220214849253889952179905879367949668848598115028365535238742829171770487419"),
+        MontFp!("11808561815315084933226034934054773302447242219261466208644893422841430468026"),
+        MontFp!("13540888692913543742580940929469376532537583430034252053023468103862294761259"),
+        MontFp!("7244161097354558003276348625436123965060461415149286453943040900234287411785"),
+    ],
+    [
+        MontFp!("14838699086047571226987010390426316539929576717533827724866261274778253262656"),
+        MontFp!("14556703155521968503536618488028548581329555701042498979115582733446728182407"),
+        MontFp!("7681623302896593715513288894378158777679657507901023568046253058158573848701"),
+        MontFp!("1088441387469941348668229287331864702951247349577784177659963097331109780661"),
+        MontFp!("7314603916265509104428110912296267885635061026393352039011815022900719549691"),
+        MontFp!("3986211915826218802854255636104488183733664187834078111248006041750140814882"),
+        MontFp!("7773946401984571616670752866609685859292708427659817737120107917606152933392"),
+        MontFp!("2842014599902358831415178364343115068084073955515903534808862171830738904933"),
+    ],
+    [
+        MontFp!("5310724334723991338015239276468023426385678184604207589409781216959654582406"),
+        MontFp!("5255222348968955358505450804240823699077014235887887249383824524518164498567"),
+        MontFp!("4683270496545943333741165516340250527555279356319043788098737100323469078711"),
+        MontFp!("1419863943011284607504318632953959861647793372073243840131919334395882404459"),
+        MontFp!("7983638904317557271319561780754076927110887040374328063199742162092282580125"),
+        MontFp!("5569432847705373609838086039153225563020182698189928344759413994203981320990"),
+        MontFp!("15459233133041758499623402905899885787129812358908703405750502906067055055230"),
+        MontFp!("13557004098047782158753673078158469379829777184696159361573537670440394932233"),
+    ],
+    [
+        MontFp!("15455882302725774286899673141535924396516348007554186719344822187820635072053"),
+        MontFp!("3420919058826876625284567898132572990967515410265578892047210512917031439632"),
+        MontFp!("20100418454140979684745740106982178755085746706837715848777042819378494283102"),
+        MontFp!("2569258507332519764813672456351707773863376375715947817185409500202699032309"),
+        MontFp!("11051426796304102496144764766958179671506736496976882366028801902480842422589"),
+        MontFp!("12740229748287653735988491742372785228070141556372656548689214318469788908817"),
+        MontFp!("21628842595664718258888324339774974922449098458375293925060310284267692457557"),
+        MontFp!("16339231976272978519029290439531768093693541721039081313180796119705575069472"),
+    ],
+    [
+        MontFp!("40124736742096746520902512885311967045111742860721554225254094895613700655"),
+        MontFp!("17732965892472841235257958105891466451086090480423956940377743815006013439"),
+        MontFp!("21822629194074446176794925064792912534191501981075390813302606875002422233533"),
+        MontFp!("9308214945046921143097017249780654286051601646816113552080893008307002107495"),
+        MontFp!("1407926751839535775233537792971129618756456590720440342541085713782189375466"),
+        MontFp!("5640645423977029900985251540406734874840031539109774937559862819450972865688"),
+        MontFp!("5033216407501194252797695593441325021622991729008118693554186469034086370061"),
+        MontFp!("8067057037475400447259522316648004416684453970851364075976857314405950145375"),
+    ],
+    [
+        MontFp!("3763719773038467529952189678629891209905984306908045328296798459182240539135"),
+        MontFp!("16939797418368521863388331657892541744299855742774206972703171911218723184714"),
+        MontFp!("4830944198856568835319759101429165879092462296316662230100861015921313890231"),
+        MontFp!("12704214658232136513943612645116991664417275945120192627735782298715562058820"),
+        MontFp!("9273823420095008025667777982828688153052061387261780450903573585273931011552"),
+        MontFp!("11055274871946976331353174512200687536982312509623944578515862663278819898965"),
+        MontFp!("6608499500253253446996042326570359354182967780655057286059057541317584758989"),
+        MontFp!("20888058022129906086941050692798413401844596394165346138911969309287247738108"),
+    ],
+    [
+        MontFp!("13297667979268130800823342819300433555314639138313483863899090834749801969571"),
+        MontFp!("18968104066692458124571065270953767119743779337036553042450471941512165236867"),
+        MontFp!("14932841303199490878640323744926137685749952622800747995690439854118498001885"),
+        MontFp!("6250599214474930878673138968631643032807502364864165001640712550360147900771"),
+        MontFp!("13872044280192246670253542029636668414586465840988190477111017540404431909403"),
+        MontFp!("81456119668307937036914780206985985650137679027930766352442712034886058018"),
+        MontFp!("8178364156193615628946078892680068624209694278864784660439209878556857933585"),
+        MontFp!("20847565685305938921688196081711559611104247746032524045765048360946563554616"),
+    ],
+    [
+        MontFp!("14790603163347071870110696142274029411377352843070075577069234486581346354229"),
+        MontFp!("18977464663780407707262531952390299277523056655145169930121579582916387871374"),
+        MontFp!("16780630803676794749613238124686604459373604071531057035207376612438682381040"),
+        MontFp!("20186476042367781999034353334494913683828163385175556939730585228743410724033"),
+        MontFp!("6782638209588187356802454014110236225878206067794807253486060610876934918759"),
+        MontFp!("8993456778572039939715813797180666624819850516232234360679317411311388323391"),
+        MontFp!("19966302498904269727099815984264954717659138861990152509516897188319443441697"),
+        MontFp!("20169703794592063233917650314404110898564218327366603108408586484609331826027"),
+    ],
+    [
+        MontFp!("5979829627203584558315118820578826847995466683728103070319484562170838879477"),
+        MontFp!("8237679343008214539352062545936737645555361114339038346011678993504862443129"),
+        MontFp!("12382432100828502258569798167004899872248210099869176340581848176730802349663"),
+        MontFp!("1568185664985590267262857882936657784210740515169196983171026814738347336756"),
+        MontFp!("21214766447038120613598232832812136678657988502205964335817205381807920739938"),
+        MontFp!("7692941991237742474520327457310452870153482370889548010226143053981890424652"),
+        MontFp!("13595129445265049664221406027681079958478209116108739005508499004805469917071"),
+        MontFp!("19188096071580221579092496028987371780642557049389322053081699235155567772173"),
+    ],
+    [
+        MontFp!("17975673380464001374676034638564230054429981676012676440863525293845130019904"),
+        MontFp!("20841685157342026757711329464299804445471940020955209397956987009823404283299"),
+        MontFp!("7510778644672212989684926383821874729073504800968951172295535413714975603558"),
+        MontFp!("5412964648109092367425127656145675316528154462488440576988541278054587052058"),
+        MontFp!("6998001450950528857399821530729656471745472711969582871968416561472553420135"),
+        MontFp!("10017795190513370580285083759517584035694996563220913850722002288744022757377"),
+        MontFp!("12113185651597474067026664715619946415749981707739597619454641751791169267554"),
+        MontFp!("20451540737363571466111039734160615184627155382583098695879349204357410296631"),
+    ],
+    [
+        MontFp!("729116950403569953818905038668361626861855541652418271170712441039707291924"),
+        MontFp!("6874571610670154627346562968411422088198077609945741147515101915358108207688"),
+        MontFp!("20307824547105117373454598908217917152093200208838326389260620574762152675045"),
+        MontFp!("8758875530447210792904496135011086289851932865540018278850670496425499052683"),
+        MontFp!("13224694410602002105805224454797207933944742532123981533211431845662395381395"),
+        MontFp!("6621493224766717216701548708726891168784911176896760330321592836065310482866"),
+        MontFp!("13937858022779991611039558948054774910543950212969141252259896915615778617893"),
+        MontFp!("4917806030251482092362529677296731621677399228082641707762616055246746126061"),
+    ],
+    [
+        MontFp!("16304922224312728276104330461175394847795848175925462853738047204383447573035"),
+        MontFp!("16678452722472429203861326329044632626530032631343862086351886162579978046420"),
+        MontFp!("9974691111613144697061424119079539196535411918411684404824080439336446439564"),
+        MontFp!("12391128852318795781829794456501239823062804741032268163807689059014957151322"),
+        MontFp!("16376931186038869228971542812469753097050036606517944132293138523631153279825"),
+        MontFp!("3057841358487505418761470758562979965285993261118087156094367416201750095404"),
+        MontFp!("15045409518037090814105826994439679855639635253710791541219370329682069820225"),
+        MontFp!("13442376736433669968016223589180307683361433436806777011753497283272674012644"),
+    ],
+    [
+        MontFp!("18917174176736242961299708438032963296686220808211170958894252981698475343631"),
+        MontFp!("11380920704380401611525239094209208940853859054744619020167150893676619275400"),
+        MontFp!("5399632748693319676480270098239871368958944610827825094400876104909425716392"),
+        MontFp!("3072779406768337118240884091792704214322792415195488652476136252175179362880"),
+        MontFp!("8351873470285292321562674159922105545256148886389216816367528787141186556758"),
+        MontFp!("19039526722628732399365091326361517675801947890934047817293511021151913744591"),
+        MontFp!("11316453563295765895775061205389385485172841919365628835333993250531664655988"),
+        MontFp!("7850755275953939062184858524678116551304016605992491147837939252676680785208"),
+    ],
+    [
+        MontFp!("189663666172994057560830062107872734380479327839628938168402275701561917176"),
+        MontFp!("8944554955574110171273295960753608410178793391130829960067372967633462961614"),
+        MontFp!("7116498249918759493875054905542634690892118438594298685578805598675410965669"),
+        MontFp!("2535963611074434631003149876163530430931993688129878286594756194015465278460"),
+        MontFp!("18022460558081751594574692271414706303627866472796139479944146908393139741182"),
+        MontFp!("15341193598946540230880135952221211503846552166425406354080863978843527894671"),
+        MontFp!("2942431717153385426545606490874257811230086292797817271859433296359160259239"),
+        MontFp!("3009774438756820489964746831334449123894740822794580986556997529296717581423"),
+    ],
+    [
+        MontFp!("9496138301121689616049759054935646143502980987880350156990306735995260671175"),
+        MontFp!("4076156724842725224174300000468119057699244699381290980710548119313376968129"),
+        MontFp!("20301500572584246879220468905731058339249778940966192891128325027181404226629"),
+        MontFp!("12240449395531309263037726882974869058539543342019721791945417590157321444565"),
+        MontFp!("2734576041547526732946886809654954568832411068107541730145912482251139322538"),
+        MontFp!("1913611111144137178181099357504813610426696502807761974432419767623037547574"),
+        MontFp!("8323981703091520786969788588517080546120036429535328021157459160571413370125"),
+        MontFp!("17608089795804665912003122420873117027406690592641558991713120617999818930151"),
+    ],
+    [
+        MontFp!("17954961401611739290579723858653246962839079599354059880628870682426849304674"),
+        MontFp!("7693642591048722104105715300765742636898670019493041402551952316778508785882"),
+        MontFp!("10925165536949195683545612102300879902373347522535838874708839717193999335745"),
+        MontFp!("16740598974035404805544189925980303793846400946043080633235004418045311113846"),
+        MontFp!("3028458114292500648266975052798389647613432243149006395166123161184170940972"),
+        MontFp!("2817600861932061603203157785548222970685465773360278995551965365313604217882"),
+        MontFp!("2811366666795973435332404603090484498270752802044239619104866535127344245139"),
+        MontFp!("6901007103297959557257110184636027233977945890205420866896244199105220459744"),
+    ],
+    [
+        MontFp!("6811040256124961160848956238308470640308462502755753004833080999365205628787"),
+        MontFp!("846642049586630199735666112786431409696508103735494916428842550432654381594"),
+        MontFp!("13061166881718302681365231291832588791959186056326831853549555763101859584396"),
+        MontFp!("1581547457654855644173875819143310956457964952802128135344084991507959176621"),
+        MontFp!("12591698412731075291488515328885878994038884715020576113812619060374399968487"),
+        MontFp!("7129047166046749599109058206849766841261983329246180789653876287940952140294"),
+        MontFp!("17780920041966559015242418384239510699940753783778307759603993814380170147815"),
+        MontFp!("11411967002648206460094819913767451172535988461576286592244752756526683869398"),
+    ],
+    [
+        MontFp!("6535147980143805768211908880661065989475773196469834562468932004056012068981"),
+        MontFp!("12872366293792794368642323198969017581196463071340612957009439105182673573396"),
+        MontFp!("3845096876544992085668616039795853840768469571100517631039776002796484609549"),
+        MontFp!("20386025860348257305841141103130861239832870083066852913792413739711579490278"),
+        MontFp!("5663975388273723452136125938377376330824298621841190787892884430812699456136"),
+        MontFp!("20880523335705106555101009571713688438858731841737802690910851430800496104934"),
+        MontFp!("8664815262171336902475127109386834836220742848950659183106085559300961747316"),
+        MontFp!("15212672296023611959246835252860546019670000046804751249547303425954183847429"),
+    ],
+    [
+        MontFp!("3786255974807528210793957400325837912933369979823637013145025357556219775102"),
+        MontFp!("19646410587152058982763388053845872310164493339475512721275474101828150077273"),
+        MontFp!("14407426259630290801648546162995549804322572985407158009259933675410180400077"),
+        MontFp!("1275955073103101917295562169849127375209112030395179332033340866715396722452"),
+        MontFp!("5487750760448101899937260261898752719887276580825994742322208269609306618405"),
+        MontFp!("12414079753210256499611439235670285717945909010061941159696368398137523291140"),
+        MontFp!("18058271753030912252347026705895506604519018890772902865355002646910918153759"),
+        MontFp!("13935235821735626611156505080089322797654275868806802361406549798199236177528"),
+    ],
+    [
+        MontFp!("17110498079878546324718511787669387410942622969712445909354000807236690314957"),
+        MontFp!("10687508266469903792000405420136150569946636272800228999781195239976105560612"),
+        MontFp!("1277956894120355360649091990517188151791867400124079104247693321263057601099"),
+        MontFp!("929982009519538400155920125117423265869657236620766216139182914925009802954"),
+        MontFp!("16559970949358997473575123467518158994842000800881347427572300986319432656507"),
+        MontFp!("863852544580033885106607226598354103099120172650200980695458006092725115354"),
+        MontFp!("436810575313416269983882563851323926836428928449351162094565391723605483516"),
+        MontFp!("6334913013691170767138698286357556285297887475783792365865857018173994149486"),
+    ],
+    [
+        MontFp!("17785859069146472999908840832788077051672090890508101583397157534162626183973"),
+        MontFp!("427206014337914391283601765560115825767253196347193816620589108299037926541"),
+        MontFp!("15115704735938262072587983952645382098893412471333885175144579020987265065203"),
+        MontFp!("12017969315449748476118643575203596675122272214009056004034938899095907760206"),
+        MontFp!("20642434407226804845623813766397536183962927868804716012482833199686414302852"),
+        MontFp!("18982318327848493301474677819747807686491978396022748137991684529478469330097"),
+        MontFp!("2306193794828709014215315860179466106408084703631347012188232489780230095671"),
+        MontFp!("7060813397820173935956757571314686808083877731722252822508055423697679476893"),
+    ],
+    [
+        MontFp!("9925864312610988474999359617458205534034473691089101964213562993662824159034"),
+        MontFp!("14036238569106986370932971272638702550236692459418895654245682921654874601312"),
+        MontFp!("9509048813859143088347263336607686057099400727479311504780670742158653486206"),
+        MontFp!("6842166521132564137619008158396211111980991013087076743268157882198576269675"),
+        MontFp!("10217353423046013950417213172971567565900229914457220187215408404202554351836"),
+        MontFp!("18220384419265532097596052952017594673237799959023133602933674050572298730193"),
+        MontFp!("17866822945198657177461453619458294532377313634196332518543246556611008452933"),
+        MontFp!("17694368679979949511817467967015330546905282492241200905890171992458134240678"),
+    ],
+    [
+        MontFp!("18971922685739566979638356009544944454629162680819328093994329160719843056737"),
+        MontFp!("18684937612086669383439812199377945074448160740155966772829350355651237261795"),
+        MontFp!("9235876281667970051504588287667786944160228843888838710239865727309603061015"),
+        MontFp!("6187574163551283282357553100017400574873868151705871779659681332774938473442"),
+        MontFp!("17196369096305464930639002419417036905613312721767481044644254878990952814786"),
+        MontFp!("18296927216321111202881056198300973553112302777685079899199090840516364581791"),
+        MontFp!("4983948188027170589078739023086929105628955321978589464920358286161528573448"),
+        MontFp!("2276814237931645487686771259585160667452008745791625290365802841496721618760"),
+    ],
+    [
+        MontFp!("4138273157833414032755498052453436990872835066620446328921138739885868998379"),
+        MontFp!("5835580830979414828575054128735121537583042482361311845838347096674448689116"),
+        MontFp!("20992630219061340843601881100837482710979119542034786928296223633950908472388"),
+        MontFp!("1118381353525339785976839119511758587763620520383755136959051018516094253090"),
+        MontFp!("10337002023922138844951367775712178432524190386722995225923120494344904079950"),
+        MontFp!("9765947418137225404722546740514250763898752374389411503005283184253024586058"),
+        MontFp!("15411836962046751164622748177831913963909013265942110958658714173394711125370"),
+        MontFp!("20722527012138131360820192152290968950993396481440050289358737370268218859591"),
+    ],
+    [
+        MontFp!("16585853587281811014582898583977502965045639444130273779047322749735299560207"),
+        MontFp!("21436098743421172924014781240823435281025352300035264733201366114473419058727"),
+        MontFp!("14178112462860881459540462916598447735177675761773338824394753907217898488960"),
+        MontFp!("2590560710846804342662010467713568407285290476715663333366063002353018991264"),
+        MontFp!("17949223181156469858379065899254284317305309247290121304422294912030586532673"),
+        MontFp!("6940063127036366626640075420306454154706369567406835284901717013872681276911"),
+        MontFp!("13212339415583029091219180722363760875223983190396769244985733901171214077679"),
+        MontFp!("11143838426689049623360248250302972103117784521940658207527698432687552942591"),
+    ],
+    [
+        MontFp!("4994693363062895106345077091869420711664571716019971952890352464184561249569"),
+        MontFp!("7785839099197795033948112451740381108555553042322704038905686323540025631473"),
+        MontFp!("15291655295654923849266753004503491258117644584862711291502217292211074445996"),
+        MontFp!("18223946690101945712849081159295298164630378278313069852577349403051751559726"),
+        MontFp!("13247893325056509281811135293440873471348664328435966021736203439379360560346"),
+        MontFp!("1838627965154116499570588511051176331708387980121591719463695143475045130831"),
+        MontFp!("21746931323535899361372833028120884537569529325326959379977185108159655128847"),
+        MontFp!("1569229799996373000993208676467175871896208509249271061977636872731081653113"),
+    ],
+    [
+        MontFp!("18668959729045139805375896352501526759923123936419773886979446262254907152787"),
+        MontFp!("12698285530824454564359053510831159718450594302921296519937334733529589738160"),
+        MontFp!("5743752602883180080321224936560739109224279187008023590149271256478879997507"),
+        MontFp!("17615461436426765950762679333452659818080751337498512367037395397687644820677"),
+        MontFp!("4379963027402443949761342437016192165148025657715626365315450970388283739261"),
+        MontFp!("12622442863880120105122485141053297017921305018805552070109568547893924027508"),
+        MontFp!("16493349884995741255319414030015325273883108492981717376626952633010860098410"),
+        MontFp!("11501183900713163689133184470477728399861217340901493951105967658399341986313"),
+    ],
+    [
+        MontFp!("13184464903575565740074003127437693743650101614906307232173855163739473476900"),
+        MontFp!("19056993236227362680720448341933549082689888775458266843506880469982452347227"),
+        MontFp!("1180947252747369471066257076205537751320494098262241412291924855089764608729"),
+        MontFp!("16229532924404554580195616835338949126663348103713418556119694233568376894947"),
+        MontFp!("8604714607572995451336310555882946070542334844212691610961393592348706930493"),
+        MontFp!("8362594100280133221998296898045505539071433915735634439526614339277300552370"),
+        MontFp!("16399159148365956463951582514857891684943332179297226423628752792536028483990"),
+        MontFp!("20791958918883897879651946680726738927333774947616022833294686415482396438838"),
+    ],
+    [
+        MontFp!("6976099533465307077876553477341301102578695004868981952387720840685240842560"),
+        MontFp!("17588607896443047770053818219711270035985826074286753981361920802895326076124"),
+        MontFp!("12865981806811655044812914486873432317316688987331760480657262748139002813688"),
+        MontFp!("19080259696546964979932036247707282742365340353585423017939782931928015046575"),
+        MontFp!("5475353703257038456872747308072401784844227202792527428899399083236860900298"),
+        MontFp!("699444932025038530835460727165156424336147795146205258896894678525124927461"),
+        MontFp!("15695622674480818777943366659102932349783785381339274197766151422625765388038"),
+        MontFp!("7644428489984569999599080644830401450294253782967784792584750934960812468382"),
+    ],
+    [
+        MontFp!("2484044190398385977417569061356693291812041338880061938702052957819048506706"),
+        MontFp!("8456986467797277421685766156179980502998860530369856189405630837033584471075"),
+        MontFp!("5054041625001826317568038929780665383894838531896986763764007995985738029810"),
+        MontFp!("5197336058480822437408118036219119090707158130910220019747427914262297331861"),
+        MontFp!("8896147437242770809876821567936215621570430903276974181159659855796295866923"),
+        MontFp!("20755757167342693300106178757642141909843395817794855978028122598254488316281"),
+        MontFp!("12495257799325917448205113238508489684392516282807104246531380538192500498286"),
+        MontFp!("17639970982424592615983334078785592256655637539816187733799215839326807071148"),
+    ],
+    [
+        MontFp!("8140016957188286078776165555436655378303814378750387793587919949009492167586"),
+        MontFp!("17209468066776420206923060639618147772644663380208004030591040036263548572020"),
+        MontFp!("2619409586309117922582791327977378099828554504012201484641253637770276078843"),
+        MontFp!("11172679254412598275301264634812740710430873755458899712228629497147611473029"),
+        MontFp!("16829502099778629987235691213955928527920624415791356237580609633148661633897"),
+        MontFp!("592799060717298365629187138482067858694007427100574367745567028165989185342"),
+        MontFp!("16864381084532235865281462338072964457337415344658720676113860956416999505572"),
+        MontFp!("1015589663070446561434523645329239389344944669662180065723984179503017360337"),
+    ],
+    [
+        MontFp!("9982212112174542265411457778485410853904388759147308861218634697975431894510"),
+        MontFp!("5412525702631618381358272227447367851318305617863423359948039591381065713581"),
+        MontFp!("9852930575259000100332996271562617389630146990442517175422889296173516799181"),
+        MontFp!("6036993105785310658467845672504384047591296265363803946714632979523201713762"),
+        MontFp!("1821500632172143873156399122734194851200445368324858351038486833883177057468"),
+        MontFp!("21556520116213603298246786137688925835788594639953568860110645708136881336676"),
+        MontFp!("658318860971707056155247027603536846915894897192791739866840963356575472681"),
+        MontFp!("602842622617647573132938965729563329852165494525296971607175031334298950242"),
+    ],
+    [
+        MontFp!("1151063223719891516862415316972915766442753873652837551132768558136109394634"),
+        MontFp!("20030054542089253165409106868864476953251573918915762537158006593968012247497"),
+        MontFp!("14455078111822464502989472874268580626098857184523941794725425258923962713053"),
+        MontFp!("1699191450188970110166570608380346465689006650580298122024202987580198200132"),
+        MontFp!("13971136504849280501801880342723497383580392506287195375689019810750613223527"),
+        MontFp!("11259011415071078991947983706483998982146186263873384729739331890304233635860"),
+        MontFp!("17741270384736018529047001790810396141344433078911295725171243367964019815741"),
+        MontFp!("3617456068852846022110280599700245470402025130645759911795429861830057016581"),
+    ],
+    [
+        MontFp!("18773989857774369564707484486703863617112883499664601804221477949481034222590"),
+        MontFp!("391101570414854801618801587626783162239406618115954162053108159404294160435"),
+        MontFp!("3752824438659815340558915518196975380567589032517034180452547083690665271869"),
+        MontFp!("13652227089592801810376789544861979384538590096633526007583054323554301421745"),
+        MontFp!("5753030785259259818058977992956569985665739253964735992489420513570911607"),
+        MontFp!("12794765444364718066463627091127875266371595037234762762560519184694440318642"),
+        MontFp!("1844165267423966444579133456200541636533189889959706801468771335509321515822"),
+        MontFp!("799352162562582415493264759184613437140226428304061991778193411771388762097"),
+    ],
+    [
+        MontFp!("15915114786946818157476898276501926276831197920612814619300062353559927906953"),
+        MontFp!("13041871949144831370743756131359537126101784549008553888408794912277392285626"),
+        MontFp!("1684702427149441531010110315726002248751792272226034774456204740385384491604"),
+        MontFp!("10195318610969070608511028432066597876456281143783329459466964443360549551082"),
+        MontFp!("13714193389971576085579160116206487363436474313560046541969781285568217247624"),
+        MontFp!("12202470771012770210445954644081270058473831351768121852596394422757629850892"),
+        MontFp!("7784616613742667796197638965440313242748565680231200921682296807888993222090"),
+        MontFp!("18581613859576442652033888735999982405110741068271804741467526764394720805037"),
+    ],
+    [
+        MontFp!("14828223806255884089537896775456938290494683211666564494946175120085694803958"),
+        MontFp!("6191868112332934762674478056112840408041237177775248347690069948259811627101"),
+        MontFp!("6055199518589075551800066499277675747934144570099354689629636497613775458486"),
+        MontFp!("20043219892592698889412649805669712950039510114250762278667968995416842502234"),
+        MontFp!("10591576812697540586115991527347511638405122244793393962099090930538459086772"),
+        MontFp!("8146910292072979142616688207315340017602882692938548874592904341871514175303"),
+        MontFp!("15451576003386544225828312996072681331940167554848966592330715947662789205180"),
+        MontFp!("21156998090948310800651324456525534600543417534335507361948830316109451323115"),
+    ],
+    [
+        MontFp!("21421497039083336739241851024868234958744697872115637345287618993148799764131"),
+        MontFp!("8835309990713613011240324096693076755485475658999871502819747407829989219746"),
+        MontFp!("13102158958973358955423565573049580406238531533936309830903999596178966162490"),
+        MontFp!("19927703189662863743499379923522860979653455328626544661291243971618992342837"),
+        MontFp!("18417771183154820005238210056528713167003520086953806649233005148247829186154"),
+        MontFp!("13242250186667974182640987653516460478853973058739850129463954545512907574522"),
+        MontFp!("10971901023853281329361069638276077765206234747340067637718378767976633645829"),
+        MontFp!("20436550472837870181409690438226695091760115955076127106091878852797639823191"),
+    ],
+    [
+        MontFp!("683842651763399941903331243661454687566310039977770092715404267515366625429"),
+        MontFp!("3304534668380354910105587611199035768704466410761708200478786163367382500984"),
+        MontFp!("14327892159763789670354328059011011973128878640806462164819794130243254129821"),
+        MontFp!("13712101990593648405837473744314130986494510088132644940425089514662460031793"),
+        MontFp!("1270386163717136732049662990020454155453019401464056820650142849751291739739"),
+        MontFp!("4559668312052315567004252521434018809625818725552950834596073025095274632653"),
+        MontFp!("10289456013947128246221059115194021747046925564818529566042034047888244657473"),
+        MontFp!("6981981682422059144716871555026845840161063380660424650450978975416029699739"),
+    ],
+    [
+        MontFp!("13275723002453843398308458799872954358948259042779675411059905047590837397361"),
+        MontFp!("18372074965684100000331046096891533070433189717560527825752357282553296305210"),
+        MontFp!("6007153627662867365254986874716350833679184737288669421698890656788831322929"),
+        MontFp!("11557682792813633323168221751485510314542594132819842305598531070629168100143"),
+        MontFp!("10536598621155464430657941977974614272794233321865085717974545329727298277125"),
+        MontFp!("20566123440884795144385782557360498238445700080133152934423121801124172346047"),
+        MontFp!("5484210585392274768700243869223282957415576141086566136019633416151230114084"),
+        MontFp!("4675266041161206862174450141632759296562489084453522360678052892725376421684"),
+    ],
+    [
+        MontFp!("14506966485061491552710372008504993235111668026216492386033611735228479487468"),
+        MontFp!("3682565950309631924420685101131217452257499881999322497664342243267291843503"),
+        MontFp!("16753306733039910894513530708776251948831720207834805689601646616427039909037"),
+        MontFp!("11892397629144764406188085785897237236955294380381710017192179450763501663923"),
+        MontFp!("17027229171478232498721421673139332166581061755210509139252013418924500461243"),
+        MontFp!("3560458480908782960366816146149753544371185355186140843210760460011482921556"),
+        MontFp!("2523290942811919827064721825289040221770310594770466909167316010377190569820"),
+        MontFp!("17586848354290518015476851435178627882600199642491204839902589087637701736514"),
+    ],
+    [
+        MontFp!("18771893348474501482962831973790983143756587183687952333177929270650139940171"),
+        MontFp!("6788202157749582404834375771398928959748074435244246320016871403739257327326"),
+        MontFp!("11025631863450004428764861086496374449453982180198151399523240056816657483248"),
+        MontFp!("3256907622263919521402687344729539839835290137654795380148237049547054026004"),
+        MontFp!("729757374802086603625382264910105909740146180896096383332210024077887641124"),
+        MontFp!("19863253866253150070643618896444516678169346690564661550005769233120838139485"),
+        MontFp!("12468569017378925985548033310919519222810416238732327538088208928920140959143"),
+        MontFp!("712344748962578398623451251358410865586764243720605242158768608887082462846"),
+    ],
+    [
+        MontFp!("8546087066371010720013920767653366050032317738437010080974697619001241722483"),
+        MontFp!("17144825509786899110344839698077839239721239583625175190269757913667929043953"),
+        MontFp!("10651563297701188942358589203989937961905153035428112097802788565849122022100"),
+        MontFp!("19602341346389413323180922571631527509531683866957468565049297030414658843948"),
+        MontFp!("9238186664745057178430953403953596421917515090260446457039212350976296818523"),
+        MontFp!("263640414028390180122517954487976369901122460517389747631764885875587715955"),
+        MontFp!("2311641918305077640172935641310996393584851078677397516017312506521775283636"),
+        MontFp!("12911852110192471656473443086611566556755106535388637084532737811151296554463"),
+    ],
+    [
+        MontFp!("10436700004928765835031725654432267178079115705246966695358470216435798181674"),
+        MontFp!("12755555289896266917759922247555708737024386059041699214870911784508162783525"),
+        MontFp!("17390583422165077903045260639521919716984664232208360646931078032292219709718"),
+        MontFp!("7412526952366864882775200227476857681850213243362827192310877977391550357930"),
+        MontFp!("5016060582872027330190350728607317487069057897723717249157495640519710863591"),
+        MontFp!("70447200134990075406173842139872041532268968648265338736409860251327029352"),
+        MontFp!("1545500244158153586647380894391367444874762740407966854865957002078767363820"),
+        MontFp!("2082567114283705201161441383508830647153064041365131752708347264051557391980"),
+    ],
+    [
+        MontFp!("7773933577113494097575644205473257493685202208592412633139277067190461074505"),
+        MontFp!("15907352821797623044340355088248954282080052141018731890243639338361458586983"),
+        MontFp!("2453390435048874114321626738320866552399505338711520013030652128583351121221"),
+        MontFp!("9182038581165182763924458518550360578443802241218652973210280653624820005202"),
+        MontFp!("13176557622325900598244222336641110473108400343854387783748570353220729582767"),
+        MontFp!("10599983241136666078578113335543683963633036808782400964809769571709020578918"),
+        MontFp!("1430816790456574892099931300141571059151141389317227589818258647628212654923"),
+        MontFp!("7207251746626434553568433426934231676780727971853793874008147862305418016123"),
+    ],
+    [
+        MontFp!("3847365229378532841231862621068765430417579646617713430532944299440264931969"),
+        MontFp!("922422158589085666348657924088867593873646110588554410818179794404300446471"),
+        MontFp!("4298485174770134050325487753075508760849575591910135387686931072102416450479"),
+        MontFp!("9475141350581193757416877790061277619494551108434152557051757495614692231364"),
+        MontFp!("7750163624390542388958191386016094472536166330496081849246099823270737686866"),
+        MontFp!("14363173695671306304956071467171940429435853698217676411185837490356013810171"),
+        MontFp!("3402134714494071567155197273072160417049647120230862441840621369782667867977"),
+        MontFp!("11378968132153772980874973211734670604659991740586197794619174704886870525408"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=8.
+pub static POSEIDON1_BN254_T8_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 8-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 8]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 8]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 8]) -> [ark_bn254::Fr; 8] {
+    POSEIDON1_BN254_T8_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 8-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 8]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 8]) {
+    POSEIDON1_BN254_T8_PARAMS.permutation_in_place(state);
+}
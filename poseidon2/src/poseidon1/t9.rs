@@ -0,0 +1,935 @@
+//! Circomlib-compatible Poseidon implementation for `t=9`, i.e., 8 inputs.
+//!
+//! Parameters are generated with the original Poseidon parameter generation script found at:
+//! [https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 9 8 63 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+use ark_ff::MontFp;
+
+use crate::poseidon1::Poseidon1Permutation;
+
+type Scalar = ark_bn254::Fr;
+
+const T: usize = 9;
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 63;
+
+const MDS: [[Scalar; T]; T] = [
+    [
+        MontFp!("708458300293891745856425423607721463509413916954480913172999113933455141974"),
+        MontFp!("14271228280974236486906321420750465147409060481575418066139408902283524749997"),
+        MontFp!("15852878306984329426654933335929774834335684656381336212668681628835945610740"),
+        MontFp!("14650063533814858868677752931082459040894187001723054833238582599403791885108"),
+        MontFp!("5582010871038992135003913294240928881356211983701117708338786934614118892655"),
+        MontFp!("17817167707934144056061336113828482446323869140602919022203233163412357573520"),
+        MontFp!("16618894908063983272770489218670262360190849213687934219652137459014587794085"),
+        MontFp!("10883405878649359800090160909097238327402403049670067541357916315880123123342"),
+        MontFp!("7439184039942350631846254109167666628442833987137988596039526179738154790587"),
+    ],
+    [
+        MontFp!("2727663760525187222746025175304386977552466570311228286110141668880678011929"),
+        MontFp!("16992375884417886634716738306539629570444547136030480542879886913528563834233"),
+        MontFp!("4178586893949624406750122665277033849762243490544460031634329370298105635905"),
+        MontFp!("2517914797385699886738929430037355069462619900197972886482360691236776726214"),
+        MontFp!("20164173810534657634631187494276970100735049909727379228976555863615716408280"),
+        MontFp!("19970958827248077001061220127605534603528515080207197493660642269195127427214"),
+        MontFp!("15606275977308968307194602612931727810866183872589808138812916593200446820753"),
+        MontFp!("12261436001550634140750381230737452634746867040398895669545077774504957433511"),
+        MontFp!("10405309809257831434323731445544896504541938387524726028487604098725193737428"),
+    ],
+    [
+        MontFp!("13408856444092113657034337770571899796129642125690066226794939383190876435468"),
+        MontFp!("19768080898957882918527124226120459667739640387901357739011662191034806046251"),
+        MontFp!("16749889646056241484852997428132695501278739424507088920371060969471495213919"),
+        MontFp!("12331609790192161246735870679870317366088443875784324655482358218146673901073"),
+        MontFp!("15769331739277556832196167201116801527901089923090632364403958141614820528626"),
+        MontFp!("5227172275505968397128736045169568430462701766148126842874241545343535393924"),
+        MontFp!("919073378344729780131814412541912290691661039815032069498359347682919854836"),
+        MontFp!("17858725475505870077023114050620337312678855554361132257763133392017321111169"),
+        MontFp!("21805188450184460363143840112266872832328782034569970452376470141743078343745"),
+    ],
+    [
+        MontFp!("15808413311863154368918155104905222670782553225279887458053980771135357021692"),
+        MontFp!("12828907214414139667587331812274388831051429093098655261887619166452245292431"),
+        MontFp!("19323880880917307340820066456419195877039970908109908221992925424585030574269"),
+        MontFp!("17591732412986269470826282099678922890996647592922237928486497997144096433314"),
+        MontFp!("5282593184575641056912422403901924986019740793240905758215569065763629999318"),
+        MontFp!("16013130707598525718519250412251656096494468043256226360413191733653074896117"),
+        MontFp!("928381583587170989315021718439506896903185927814675820160976165627097308915"),
+        MontFp!("13354336789663524324458402003354905134416094005220899335023797754517805691310"),
+        MontFp!("8780135673134081873589118311874067764073719549433574820315100541871522642766"),
+    ],
+    [
+        MontFp!("3334957744389892864165113989538814646945861179021194859030934481494560681812"),
+        MontFp!("10553413566358881045095498839713459314577909144176577153981801574128014927353"),
+        MontFp!("18894321506279909207228932263261226433242541255661384643559047811974513999438"),
+        MontFp!("20211894014628303327332299342564779073614790317614402383971270594430055013904"),
+        MontFp!("16723480621932556506775906903415088312771104391224076734252099577243237899106"),
+        MontFp!("1131872547334579236404174618548801749854242069301712398106619948805304881636"),
+        MontFp!("17386814048141719093058723520379257085987299288710382497237609774141718421404"),
+        MontFp!("13729980537487612221640320393867198844745491357830417754869369043292518007370"),
+        MontFp!("15860780436383591737179656321807464721751913977397035980422407138400867838633"),
+    ],
+    [
+        MontFp!("14708550460111247278740231297332510059116901767161326454481923990389610737973"),
+        MontFp!("3132820559166321299152015048428879769905404947939291493327190426785911502819"),
+        MontFp!("8658132367999084824971296219169212568783540935524918908332001856872807119287"),
+        MontFp!("21064783047501777742084787259676320053480170916619513986794406566953069418035"),
+        MontFp!("20731000104011695148048713576219525164619502119638555785381543866326561323"),
+        MontFp!("17189725817866212967650950297463469529475851286172280116066228706121595462088"),
+        MontFp!("3310440878606659516028312898499559492876015493892608849966645073367377278233"),
+        MontFp!("18463918215326370595980949760897480127622730018343709491036454088497976892863"),
+        MontFp!("10894192430593140913557164014343360386192963621862346779515699758352916852228"),
+    ],
+    [
+        MontFp!("5060610877870389107953459328006060153180283860738879092399406248484265273634"),
+        MontFp!("9068988823145592214189961315730261367007076042069390630024839612151270430414"),
+        MontFp!("13160707893890865447331361630522644819624543031829773191665491273833460019183"),
+        MontFp!("13920568292534026180186486064598876780779571940988254327823480971820885713801"),
+        MontFp!("3894011501178134026216736522445829906312115650019712122802932677318433032635"),
+        MontFp!("17895318821130376385979570244603067634449453259842805202694945793852667231847"),
+        MontFp!("9777993060458301797155055013115849176281006051494461044565335406558308324220"),
+        MontFp!("16521293541516305251718414192107787058980727971856888501176820100904791554730"),
+        MontFp!("7744063601405355255689420547832904761861257642931934580021876189691881462544"),
+    ],
+    [
+        MontFp!("5444730929053688962452159157646022068806222098484627080046464163159451208522"),
+        MontFp!("1524118152994294864739915388438939180298324297960159419600850033701763764640"),
+        MontFp!("1334622237342346242862023763160346671504959163544406543315614662442562816653"),
+        MontFp!("16126317914306849967682996412350336172782726693375105190424151365140854833923"),
+        MontFp!("6345975085253358297751050638846919833013142450462810543971050115910612860460"),
+        MontFp!("2703875280053263252177031410407166981522153304496807669518295313468095058674"),
+        MontFp!("20550626512184448884716175825490086259235894802178999642552696391947509065676"),
+        MontFp!("15013718986700828670892638677446258841869291160144196138236407826511808592486"),
+        MontFp!("4682264015512203762723381542642871160915706748420642731100634327658667608042"),
+    ],
+    [
+        MontFp!("12834108073603507925748862283503586970613250684810871463629807392488566121352"),
+        MontFp!("8422606792378744850363509404165092879785007388646473871019846954536829739979"),
+        MontFp!("9339209090550177650528715604504958143078492516052997365409534971861874881780"),
+        MontFp!("9141831918422847136631159987994781722269889810731887947045878986971886716767"),
+        MontFp!("18329180549061748373684938917948729366786279119056979983310618862430068636631"),
+        MontFp!("2009551904565170718789964252583363785971078331314490170341991643087565227885"),
+        MontFp!("3859729780601667888281187160881197567257456581829833310753128034179061564519"),
+        MontFp!("8535335342372994336873304745903510543599314397287086554558824692658347277251"),
+        MontFp!("14148514289641991520153975838000398174635263164584825009402034843810351225518"),
+    ],
+];
+const FULL_RC: [[Scalar; T]; ROUNDS_F] = [
+    // First full
+    [
+        MontFp!("14715728137766105031387583973733149375806784983272780095398485311648630967927"),
+        MontFp!("12450793357728630597819493697261391961392738728208603858426218806728799382497"),
+        MontFp!("4427733724068610336929510244982091587998132283636864368924406075658439074153"),
+        MontFp!("17863554236640577761956319447874252524561947852685470820159498661269344021716"),
+        MontFp!("10723868775598272126873918500257797117892409794706524915527428530195343520361"),
+        MontFp!("8041366806917098496431513544630989490693774700064656765914266570204855843526"),
+        MontFp!("13046986480231887538692223126751085950758763070227069247275787663666591811005"),
+        MontFp!("20228999562936372999611354929112125019466353738760451044697249912024766542482"),
+        MontFp!("14238976012080913074226552202264063302466135977295108038770514743089287570221"),
+    ],
+    [
+        MontFp!("19486717852389551661121716850619781027370627632295683938875312739716376501717"),
+        MontFp!("15733057748709959668511822511174594221965585899587926036013893958610587491491"),
+        MontFp!("12041333229715539748857491855115983195198694619439452683631630426350435252478"),
+        MontFp!("1829888811413627407640409778757789140470123549237476514374669162490680512211"),
+        MontFp!("10288898018349095056494632386514957183841700001184195479721999387950102580094"),
+        MontFp!("7360553146019695788111059047354435502690072975650576744373916804385350955674"),
+        MontFp!("17476063720528136669048514677420727796180556343667231122803521620226101935369"),
+        MontFp!("18384724266969916899691009636435516722111206340289089258767862754828208946542"),
+        MontFp!("11046121967047431151707881264774621308937270618998625466342467829704953599782"),
+    ],
+    [
+        MontFp!("20018232138773775379089542131722766973741687507582662224374276186775807685863"),
+        MontFp!("7926534193496947015875888176706209291021745851605316909116853588598743879034"),
+        MontFp!("8826996877877607049084007876351017199517432230182001641783930871320527792100"),
+        MontFp!("11760708819943554023765145606995747732169597984739408998714117029765838566505"),
+        MontFp!("19598000655770319703844060561747179253151181702222064644764822676806532882514"),
+        MontFp!("15036675263180992517064890091049355832990063162957265821390555448206776251789"),
+        MontFp!("1053420874580688637503969479036991299021138740018858993455108201424412879748"),
+        MontFp!("3723543690610038931361367959096800720510056325209292666118208798533818425035"),
+        MontFp!("4599370243050726453512484851927735252841106375733105184316191846221056036380"),
+    ],
+    [
+        MontFp!("18291400382386598447603657416871816375751118990979359745849342284893280004873"),
+        MontFp!("300341627009231088404894405580745838091318300821994947846008201887884150151"),
+        MontFp!("13332605655619720841053062902143052543375741442250678582318225211621890248982"),
+        MontFp!("13197729598850829723360679245789196039442968018972826673455394330035263151299"),
+        MontFp!("510788688496484172389408566109007465667555285205327059265048317979249570221"),
+        MontFp!("1685584118031999835794907889275254096486823415278284757369286336252006457602"),
+        MontFp!("15103945090904102223538479231258677032197950627619049222966748226967974852043"),
+        MontFp!("6653802896618953033344296077900828173967467309849915708475948018848254380036"),
+        MontFp!("9254803560511166426410537422101769642611302194250107918342410310963831784950"),
+    ],
+    // Second full
+    [
+        MontFp!("20661038342485310632612091028394348057035659683250957045340774030445861865592"),
+        MontFp!("9136910062528018177460276667688174167129493547069053533874280111057356360561"),
+        MontFp!("4362513385797089229061458501847196255783651860098500705320631416351847846956"),
+        MontFp!("2061137061600029258110405980965338431925491466724330216028866028449889153371"),
+        MontFp!("14607676885409772552908782897874144975643999944034675480739173900267789420534"),
+        MontFp!("215346512487318428553079809620502708407272005519315271404209452927497999118"),
+        MontFp!("18044026902282362371439577283764019415115969502361960218708274179281044595578"),
+        MontFp!("9652478245641134951513165220881528043195466248948069255527062590256621034842"),
+        MontFp!("20994154929281322813927859895894589885437941429166007529912073756113466975582"),
+    ],
+    [
+        MontFp!("20752721666010515144550782025078875036488075535083563976118804420187462745253"),
+        MontFp!("20857028711523544595627940704882176284224509745902984714255291431664146535922"),
+        MontFp!("9631521770540523913735742126933921923952197512938165111866628665235591582568"),
+        MontFp!("18950423265182779471595998716023482060645307106263127634953888715515988505533"),
+        MontFp!("1436791836740130330138273456892846001841969807914099860317370076565131805680"),
+        MontFp!("18145299176463660895047063984288790313564980703886502044680749544519011424826"),
+        MontFp!("7008134596456692891696131297028980612714475387065733972352529833092170154127"),
+        MontFp!("18054087496593103261596842546955317831262607456582498514349407492750291465651"),
+        MontFp!("2460661191051979147731673103829326449069370361298340160666765010767300969003"),
+    ],
+    [
+        MontFp!("1121019547339042268901204213478561141018690742635442229019134496736639790078"),
+        MontFp!("13486140142607002128358893931572108539446504181590991898872881746144618091798"),
+        MontFp!("14485083458755292442253176062192342099468601222388603924363708902524652589634"),
+        MontFp!("17684636079328478898730536417772675839399177918554869673260926729643471105206"),
+        MontFp!("12382939536995562937141167025903251534081453604974163882762565576243762872206"),
+        MontFp!("5191757256912351314880102858899907666377813090645991709894707944196053941770"),
+        MontFp!("18397247107649643640823283145149323187327745749077714626730537494597891967945"),
+        MontFp!("21508632378351416585385353654317189405917247727406155133342616741543833680788"),
+        MontFp!("19108354768686907995261340253443420621814860995097718380505789237761300853182"),
+    ],
+    [
+        MontFp!("3649609518051015699386442513879956346519312025847003339036530556474594795760"),
+        MontFp!("11893851425092314587513815253407979901615516208632062595457152391110352908805"),
+        MontFp!("13296593391067251947204447959241604616835056311051696511507435925462940176830"),
+        MontFp!("18493557674615580922923001229788184231889430766683327472934879670006059540367"),
+        MontFp!("7669746659590113244880799806073731587177781693253502772068846650012974230120"),
+        MontFp!("19370654200032786851343971085637480775724705092664059950989935645178139099864"),
+        MontFp!("1331955346226787928500793024038189892044219824334532771311923855914410290305"),
+        MontFp!("14488880297827410405382492933041130286687512096290491259710680579157544248910"),
+        MontFp!("6760882547908259908954677726421351194118695606292587659467769365205068189814"),
+    ],
+];
+static PARTIAL_RC: [[Scalar; T]; ROUNDS_P] = [
+    [
+        MontFp!("17006557344160230194691541621666219420787918477303225545533644141096551358258"),
+        MontFp!("773112329554511160545400721342977593377624843987783062638455005748446223137"),
+        MontFp!("6671483881284330250685026918783029584764740571210869197688044338476895092050"),
+        MontFp!("20812941492969561606721983530907505914064782270990490150214736286311482532652"),
+        MontFp!("1156984923268097592347582093730300227184163551449762803735684309575717323017"),
+        MontFp!("15303159756724065068145651405407765401796657934219121639364061501460295743948"),
+        MontFp!("18999785075801878445291021498876384414176522501978873700451842582224940767334"),
+        MontFp!("3782716983967799050957535371991538595453996691838733068933109780481907925378"),
+        MontFp!("810443910646366078824923626573819081371243815242873044781414798707744583851"),
+    ],
+    [
+        MontFp!("3940687718063184864573934886068875138239553970085689518511531571139105765743"),
+        MontFp!("1222092197964451545227395363538155091563596468425395922702697716100572937718"),
+        MontFp!("11901775018663948557424314950737290815973735008800495766054692238446226616230"),
+        MontFp!("21839369981774608005059280910009281502958794510307248992429390932011110951241"),
+        MontFp!("819873152679629471918450179717035855395702808145570990556719950289951175212"),
+        MontFp!("2918016794043041559376798791171848118057043459636680115122516324180788251680"),
+        MontFp!("10788401265856066217998495397128704450484607734353922353470809976686155443188"),
+        MontFp!("13599498756047543641157208425687419183141596017402196474108059160235795892976"),
+        MontFp!("4993390793677030007023804867617329393931635615810976661139461248253851471412"),
+    ],
+    [
+        MontFp!("973050533401342110180605419751137563184725082821038770229241448201970125921"),
+        MontFp!("14313276246574487682858906899808269544140218917497205965354285099641091349756"),
+        MontFp!("18746777136177241043722556179260854313319807637092383577312657349740719965076"),
+        MontFp!("14517023428366357570216698819722831600577825429761151189605029742824536459972"),
+        MontFp!("20223198094330596704408798588338060788093323967112845691364940702136543962642"),
+        MontFp!("2924401185705980722600796492514644487545258803954418619331883216838542308543"),
+        MontFp!("485440919681570468713530641755278841324413691217763990572458853294843435089"),
+        MontFp!("21560476826107225363638525612645382878298890750874072774141701406519608285783"),
+        MontFp!("7856508582404120415593106596945280577031904101959961641860467517902309769386"),
+    ],
+    [
+        MontFp!("1505151890969527772884247006998953879441745452105187039442954300997320053301"),
+        MontFp!("18861812597641777105968621029392243993700881183944538936666186678355756609806"),
+        MontFp!("11964609307983840306843122014689504510236749206766494519381451521217569407396"),
+        MontFp!("17764783391855759749651949748230026302359698415337858912932633638930034077791"),
+        MontFp!("16562247632438820849068750036602367255890087581186727955070681252413797347277"),
+        MontFp!("3341595358840888933968836940161983842834749603437573997372892853189756769506"),
+        MontFp!("3198140245778498430686233550970322127895441994253754893043542706415030678798"),
+        MontFp!("9829840339700031668849847901844029075426216057792062644639239580989060312114"),
+        MontFp!("5999422607425238131817993672620301343082348300090537110946144186609066413585"),
+    ],
+    [
+        MontFp!("19901271533560906428202710740924807375620638454776660078183104891177283526156"),
+        MontFp!("16697165654181109350158134734382046723004976300078845885330478879604895897280"),
+        MontFp!("19171906568090360833249366643372143476587242793789646446664643684138123124668"),
+        MontFp!("5557557332632668793539639636185643553639926364115539987556075445308999628265"),
+        MontFp!("4797522865199880517123583692586561796505378758857130153602827907909887751116"),
+        MontFp!("15409514194242892627651944305634286919424076146534027188938906487506413405089"),
+        MontFp!("10407013998132974348561594118793213466618426284969698091916131778477581263008"),
+        MontFp!("2534925381155806875978186916525958864791165037467997034976228683909613017312"),
+        MontFp!("16140842893634434452708565053572928560639256480905937421023970743339301598617"),
+    ],
+    [
+        MontFp!("7517617592925372620130293329989654305076737363747701594349097857054039164182"),
+        MontFp!("17572708764253481596340159581412737527195601517063980704204677005617144607526"),
+        MontFp!("16697796470163537491131716229045730242536059781538196375577575057386248458494"),
+        MontFp!("38275164685285960308550480834951641755153240877853193094138358285155638204"),
+        MontFp!("19780228589871041196871406056718374983456578990309085234484187723923738516508"),
+        MontFp!("4573417308961077301452769955811063226515352449986725327722241421281202736681"),
+        MontFp!("4768055042642730073498433238804346134649067788593835428664493008393684000706"),
+        MontFp!("17566912618951175959416490797476610679702184562687840273697859062459883449046"),
+        MontFp!("11477598695424707935165112148975667441147635429812599883095916948275334113413"),
+    ],
+    [
+        MontFp!("3408907078049921938725945268376819484694115736385272440041090673225197146180"),
+        MontFp!("2488590561390551829094067182419871806900177001183027832070626654223650976899"),
+        MontFp!("12116557895894464059885135778994901345424716569754903115015740397131803733982"),
+        MontFp!("15881232965640921626180413777392630630338847181632662075996983398726326426432"),
+        MontFp!("20914323757596181391651855665547258251038466184617935369425714249299063760685"),
+        MontFp!("4275923143992397246911855313401177253209967573031785993454148836244404305934"),
+        MontFp!("13098973753894185378061607442839048669135765294488505596582737281481575045554"),
+        MontFp!("7995472162206735324879506324600884378126850726543803581430135236761716527753"),
+        MontFp!("3690915804478314734124615543749602171459078573370790663994412906012450478823"),
+    ],
+    [
+        MontFp!("1256453655839486811750227055618146120819862944082463957526146264573763714294"),
+        MontFp!("4406492967670422538631080907830590263463047897583684262207883537903678091970"),
+        MontFp!("13380843970691717863215678292643800288491103227905602355694129412234174194363"),
+        MontFp!("19680159398793220289979983679401118779763854719759576408245027038965290325739"),
+        MontFp!("8515713472495355510508289305321355004480161123461789103991491891201940557902"),
+        MontFp!("18392703846804297332972535728243845000077361414687818948278976164182674947067"),
+        MontFp!("19823604647876421559318429394175186838817554072847524297827763377975574273192"),
+        MontFp!("17719715026846703054856559310322577442906188886145763860157972477138788247667"),
+        MontFp!("8745282777320550983079435446349157218001552450433897097227622172209480270781"),
+    ],
+    [
+        MontFp!("3259368608255603766247016957318442624095407655100612967940789373312058996520"),
+        MontFp!("3379679235619387594255002628664818227413294377266729211815713998759100259668"),
+        MontFp!("10282673789366804521601844018863748004632586596870138135887183100195194767004"),
+        MontFp!("8431227731426467642712572981755086675999345721043460063547234289139267810255"),
+        MontFp!("14117058124827023634266519281629142766485227596060997608233088670325722698559"),
+        MontFp!("17113232771025226173986361792697170950811880770802373827827162227101499645884"),
+        MontFp!("9906220434844104062978204733717072107397540599291396561476275675218575564970"),
+        MontFp!("711369587296778404961826907371863989722457674941832862265420496583620086218"),
+        MontFp!("10995654568685707735109869974152491589223292425449581061000447170660561828729"),
+    ],
+    [
+        MontFp!("17197923097868441003908860864777521604587651639410061820516916970875615238246"),
+        MontFp!("3121715947184842829391029463556305441693293825061846129844634146823663627601"),
+        MontFp!("8817835750782344079827519863863370969960597321588294656839911940551490704717"),
+        MontFp!("21074199894730915603594812797833479514843396752652846676596119472522115586998"),
+        MontFp!("8903588044620722375103549330291845285230849782400990458525441823641905996819"),
+        MontFp!("7157451412319473873395155428325762769952294079544485671397508107346256362850"),
+        MontFp!("5366933733103001902997281886950280717532636892191522349820059149392915169558"),
+        MontFp!("3729196254269053915687004590799382892429870424157270200083981101426772909827"),
+        MontFp!("3918096703119862723362353838062260616080657756068272173354821697584630247209"),
+    ],
+    [
+        MontFp!("11073027330528765229119199873305594827907404967404841004751556462671634016839"),
+        MontFp!("16424651511178205757967439516888026957937418127900739730326874335888617161971"),
+        MontFp!("17036562818332519536292487256920458988625450115083747105277938048739292827058"),
+        MontFp!("795554890382567685751618566957270321871701261784565632343709559354970377145"),
+        MontFp!("633072079840093073847779349151531317793918731920375040247534587265858418734"),
+        MontFp!("19421194221177975514787747427021411300539454454371387008642591623632727982196"),
+        MontFp!("9954719107136377193496025917640974425520732567100168938432529522254697824571"),
+        MontFp!("8674312532180246290069249621352567303340886011365637785384772665860996736758"),
+        MontFp!("14809129550856657213168714888239735820810817787153747648450536960647330811703"),
+    ],
+    [
+        MontFp!("18479959092813678391370975524549834571584338614798320263799188362327888537937"),
+        MontFp!("11754080849414921164216607793483937490683185256818320971638570891360029327056"),
+        MontFp!("10287736699385961112844233987245832756528102056561178731804188514133469579013"),
+        MontFp!("14370616700332892416887680617217669883953806003377620695037833373409292189021"),
+        MontFp!("12131262377053219810698216976753909777223459611599034218924662817794274728701"),
+        MontFp!("15129974113281645648506209149692470898425572316691306513209191313993708898437"),
+        MontFp!("7871644959999350003348485402403894487663479920989578076708137744830000430296"),
+        MontFp!("1576915733292398470896862707357585951921545131195468346129170132189223165938"),
+        MontFp!("13316238922195025030929715018519212370128739646325014577776776032463179349855"),
+    ],
+    [
+        MontFp!("15160020868051885495078648274966503057453505806774983308629511566464684311627"),
+        MontFp!("1692269682153339201433258246771340974628904846837119864247013056373782718416"),
+        MontFp!("19628837155426033423644376042848583705054394443378101622337255362403724735047"),
+        MontFp!("19222966046507618124793516210121558272031295169005274768240595331459420997142"),
+        MontFp!("12990748614547458190976906297393525840623470679364771518133250166378979874463"),
+        MontFp!("10124996030376091099517250678153357142212975502206884325977282211158514276950"),
+        MontFp!("17630673366223237394418802287655202715156124721482801416980858260564381593966"),
+        MontFp!("6743037447395702022066513290929048145404894812633440602191382691018136524423"),
+        MontFp!("3910195434942407507599129230554588207801501224467133349280934483448828467487"),
+    ],
+    [
+        MontFp!("2025953242925331197360540874793022332074847486979998082380244277507702608951"),
+        MontFp!("17290925253475198968609624243667228472127383792887388480830073536530705682760"),
+        MontFp!("15557314422719360545874148111856256188428921052029295715627017447052250706766"),
+        MontFp!("19758557148246918190283097589287660972538989627091387035573386136809005998935"),
+        MontFp!("10859351185398338650386876904094285059182038967427299340069909694684844129362"),
+        MontFp!("3496018793417449121342556434800740598384008787187762642325224753304909741349"),
+        MontFp!("13695501250971489187692201493870442254612771332042272465953359508617675704938"),
+        MontFp!("13572242195808512474816152630443442412961099907068902213470234329372028271256"),
+        MontFp!("6257061132956659095252686302119011010885219692712894010340612889095488866530"),
+    ],
+    [
+        MontFp!("4330599809632843338876238530496396340118064854909940219910748808728579051913"),
+        MontFp!("9157987606978264109338780586425009211347479724574125407732261019832259951031"),
+        MontFp!("2328698634372378957406958821467382289342903425118775270878244960387352862845"),
+        MontFp!("20636525922386221727012980541907198653039323429055563362662406273278160984146"),
+        MontFp!("15847894355448175995216566821171916679432807087340467956339517156584053817157"),
+        MontFp!("1942360378421747943668019094002571732886982847410366696537432314848905467679"),
+        MontFp!("9512432294361739988724195228775769058251373607278744642461344881575127503031"),
+        MontFp!("7373765909536890992660842391636719615263272667672747352621337161184389163446"),
+        MontFp!("16805165862480928364732162070809175154629112007405963636466097184868514458659"),
+    ],
+    [
+        MontFp!("7667777941325858499291332847392489530780564386762784335358233711706517931292"),
+        MontFp!("6446208647487337326336908745536052288215677968074882840304817109073334759485"),
+        MontFp!("11285516171986135785540153632137541881991922296507010937224736080386568662797"),
+        MontFp!("10115214387228124714106659470937696440920497755599449040012569123044717722706"),
+        MontFp!("15485618097017003479590081826451772255273462073640651108645768569284210541135"),
+        MontFp!("14933383877101576453093795963534828854771957327481830015228527838452944594646"),
+        MontFp!("12699366929120600543724208703956381057734625711467645612998923493410472579972"),
+        MontFp!("12636366946456086231704939526732303791619337704833963854669708252203542584210"),
+        MontFp!("12149350767700952579168066320091211427411187251056390220529300991824437924228"),
+    ],
+    [
+        MontFp!("7521252564104984899409328139379375498829232271563704354107116269254046402507"),
+        MontFp!("12033991121152464927378622393121300999333393690763174606686511857615848602007"),
+        MontFp!("17232776948709347607296344257668859070263618035653710252910881198999758003380"),
+        MontFp!("8692908682458431891302516268928916165669902656866484222966303081483718910104"),
+        MontFp!("1253076047322637463481069610081050841277544153675308425513468857300598987482"),
+        MontFp!("17753389824587331559955818909257943804816005297310986968447179587639048799696"),
+        MontFp!("5220269242560242526244582743085713945173060875457087963936380952653150665967"),
+        MontFp!("17126848126303954156127690428371193690154903947228604938919561454676410821149"),
+        MontFp!("16844245036721981603144243350071451732279678956963696493069130132912694448751"),
+    ],
+    [
+        MontFp!("16797761350119564409426534689125994845767740388070744929816576998448097719798"),
+        MontFp!("19353620610135120026060560134469588460709151673182029068633909633596535108020"),
+        MontFp!("19135326024992044270104645311242450367403619348108625528873986701416220617679"),
+        MontFp!("17665816362466043406415418194780245586053150534372814020191541209753248047067"),
+        MontFp!("11399583108978058354832763133747562621839059603612742599115200702193127837394"),
+        MontFp!("10094334549114303273265943473013412623520307578724043117639269488721170750917"),
+        MontFp!("21601458494506173036246860827162868889968956934810679234022762622742359366252"),
+        MontFp!("6386580477827919478878489737663301647954047211008970416851133263802072756591"),
+        MontFp!("4792043837032853062947152822210390150724912812294333339974827814683543135564"),
+    ],
+    [
+        MontFp!("20876886123310865680023706563792643033695666593071136348323857270657128199374"),
+        MontFp!("5931154799422838405687052216230902279350178420072288819326391251206607447359"),
+        MontFp!("5239679324690579237822809044372316561806419523557737441242604861240795339076"),
+        MontFp!("10385003741667422202343482240152986976068622687279646189490976516013598227432"),
+        MontFp!("8464156248644168452015929033942509092145250244998026718035923409819766539834"),
+        MontFp!("13177537753162628205208392995644675716264814191265988042404781479197639366733"),
+        MontFp!("5919477377826036950488668794024141041792143979412430063956231337921980979482"),
+        MontFp!("1351402666854456730370541080745509803482004768817122599092881844387000676155"),
+        MontFp!("6818673776641149273361875347660949176445649468306471072411086367313332518455"),
+    ],
+    [
+        MontFp!("1366646945884507587781123424154966453464902291438811059924651777083838835678"),
+        MontFp!("16219293249111347900064666257423013936256436002819357345030961998874555359000"),
+        MontFp!("779230149490072246312543789505064727370429119089791148581854356816464370377"),
+        MontFp!("18480337167389263493513952937037301086055810692872257722500635290543939189393"),
+        MontFp!("1345414110418158215433956620396568245327910182467730711109133441878095212920"),
+        MontFp!("12518315654451653143886317929532883727219058399486775127781649065277400104111"),
+        MontFp!("19716171362713656659833259243590727588692449255201500490000859973307782246016"),
+        MontFp!("1865072487559894165339723956247507020827160163812334855490266264867949416605"),
+        MontFp!("8915174456326318257703177400411158958853446829269268103252573093652570933472"),
+    ],
+    [
+        MontFp!("20191934956657253997484040571514242713447218897800997897558899754776252309230"),
+        MontFp!("3900170788760364547006546697350123842323924137566872497612605525517074710000"),
+        MontFp!("2242244954905694264442292936230335662862827521454977184433268725352453968501"),
+        MontFp!("17212753633823250440920113486091598217346743686574392123683302470302281044057"),
+        MontFp!("11939276774333100126191320505078174289237596631307779156488772314461752488631"),
+        MontFp!("361355126674011999247836373885105218009746852422112563922207274436194144681"),
+        MontFp!("3861054771271956681986534133247127581996350841974597302976225613765246291116"),
+        MontFp!("19968479093411941747037123171825881488638273087679549521610505739311299462846"),
+        MontFp!("8537196135596544183619390135426012949552627827993128615534814021127294540392"),
+    ],
+    [
+        MontFp!("2438879838432432949185118142364194193697006515067980632650379470739663214843"),
+        MontFp!("10769366200854175394348657213265947929465261545591304593688343101111720627317"),
+        MontFp!("8455019976119342575889554308499186802278388693477937667704910645050957262689"),
+        MontFp!("20644389417984700539779514908032253651696357386572813102276555909201716748299"),
+        MontFp!("8820039786383750409041489202684137325382534899692778928304664068322226640076"),
+        MontFp!("8636461459675525672530300171201543901107046823820677414340465229975162161919"),
+        MontFp!("9061524648737340075438868917468774023866583922769991567001812766008277156749"),
+        MontFp!("7602969742956570438827438826124187210014769304752116695796494779120606534919"),
+        MontFp!("17880480383024583813657184645997268710007005482705400161841684734099773182094"),
+    ],
+    [
+        MontFp!("12468433127385453618607022105559942067759302463679348320088817783890080634670"),
+        MontFp!("5227335513133160328788197758812517500875193491652227971114102085123079105787"),
+        MontFp!("6151293357148965084809035339276030775032864902311425722089088413878852880603"),
+        MontFp!("13699219811250783019541356007733829713463891996344484242492968708316395244276"),
+        MontFp!("20523944015644472920486129305620987253227711059638489683670518491277805771642"),
+        MontFp!("10421521516830672217871475174620176828341870738569247402138774913961149048583"),
+        MontFp!("15243709334491280025949017219424981672670169674700467979049999809115231651422"),
+        MontFp!("15516151337135073170256217447458198066207320794936363948307836943072374966170"),
+        MontFp!("17337341094266438501679457986886656365327787301649468585664115813920643670255"),
+    ],
+    [
+        MontFp!("13262611487153423909813660830277859169133522588408913308784951544213550636850"),
+        MontFp!("18531665394082016871726276363920851282983017715104457591860421181826617619235"),
+        MontFp!("3700454591945927209171569025131477008196191968736477330379417168348613474972"),
+        MontFp!("3604972001659087732761769946443190920343158947813896848729866695375607825911"),
+        MontFp!("20952949990925307134028293094501736726689724950451065635729323134614933963162"),
+        MontFp!("9405357171465854081502883779215538022417071330241830295392540662303830897477"),
+        MontFp!("21638057691528924765719568024989208898293733581278465977164525893773900371884"),
+        MontFp!("1423261214711655336057796638966786076518765517452404205191550645234914655224"),
+        MontFp!("4051452662373209612509106830833400151748328181316060758960838588997502328136"),
+    ],
+    [
+        MontFp!("18894191275634392250799133342573131067016712303481664374003128715704286175519"),
+        MontFp!("8319722910647187566775047002603641370685637216565762886509056643924765393708"),
+        MontFp!("18376807271218398458453428415456722166053637869198381036620575958015471551748"),
+        MontFp!("12035584964270041086110602893321059914382792217135345721427943800456312398294"),
+        MontFp!("12648928151571890511419082198798501903838843998709266232987169892491925610349"),
+        MontFp!("21412038262513052722667255278175073999553643537758589877888129674442282140610"),
+        MontFp!("7706735190856341161262212613554225730619876208755452623628315796884166016734"),
+        MontFp!("10999966015370832078836488333389544875338251739488999274500058322944383211399"),
+        MontFp!("4088296406085952300442596245852961024918851819760395990644634222875937267642"),
+    ],
+    [
+        MontFp!("19399822412575078284884340953745677500886533272999950579143260384703504507006"),
+        MontFp!("3008499431966541245607724530938385192395211534821775780577277325698653345072"),
+        MontFp!("21447244586691806434401916456546893987941039399147865009673973728056412619884"),
+        MontFp!("893624395222035047010673050230651164575948871010677581303166873938544655581"),
+        MontFp!("21402344785412208717452894839332459679574051179708007417742748857146495441368"),
+        MontFp!("9392712010553327328684355664342647815409597079361837524976044019430681532876"),
+        MontFp!("11566000613582826375650817776243972243778859250974226949316472392849073658674"),
+        MontFp!("12900046757905605731200852057204734685283283637014313056501123642345467590346"),
+        MontFp!("2147232762440136333246788660102778148879449441151868600321283583777116020664"),
+    ],
+    [
+        MontFp!("16301766972982581403924204059742972933467455194833897714073756335881543890771"),
+        MontFp!("9546560122931098895129690583175071306095759562194496054583390881525378967396"),
+        MontFp!("3814097068175987733354103462855355721851435755267819873064912557751073632829"),
+        MontFp!("10704509016547426355599213335456446765914211024738080860797634337598031536580"),
+        MontFp!("11921271012710313311785310319425095342886561942032945429395596578758895308264"),
+        MontFp!("21265249694322068914280109016742517903125526413969519857556032179013285196924"),
+        MontFp!("7207578215754030787157150149235357460121567678249968060366462431427104673093"),
+        MontFp!("20820013978092841458072065536574129286011620075823185493370309064760526240362"),
+        MontFp!("16441600678335369077753559950421185577542163640313037056248177018465084864223"),
+    ],
+    [
+        MontFp!("297097313501884278852369638329400055327872945847645211148627847628970916078"),
+        MontFp!("18298084629287541333205519012404334789930413367615524379442280529941257264699"),
+        MontFp!("15206243674059814574375077493088319889784970587286591062649045683132661681752"),
+        MontFp!("18726053049188513051286348977772545167577661574609708038977390139794201099882"),
+        MontFp!("20262858185621074639529176348089123044694437795099449154711162805012934737131"),
+        MontFp!("2249345697973053772423677422936999849381692933292653912080014325442939977122"),
+        MontFp!("20814726663898441680439335735982981967722006066824203970896314191676769388296"),
+        MontFp!("3816485989624386223507317175678560807682224519267326958526058565555245734714"),
+        MontFp!("16741230612980371365533431648017361867585544111098407772560748428499802539906"),
+    ],
+    [
+        MontFp!("2436865301432265520692873922135716828388518032014231744012990863912440945389"),
+        MontFp!("5265261577128499220460184630262997769060828863581478135168474766310582001180"),
+        MontFp!("20550548783058990082416235781987882123241946829605049684648813233836863290502"),
+        MontFp!("21523044301008793877416122201092687874337292497403523925455260117417170777735"),
+        MontFp!("9283421400783174646451499708802113832695004549893166692004850391713463380536"),
+        MontFp!("17813773547838391112844362681067751767404443478918792865885006908077545151618"),
+        MontFp!("16486730475669947890512191574075897324037778751496940417084163322433837359720"),
+        MontFp!("11367125189013824464048785896422572845103707778462525259651446893275289247873"),
+        MontFp!("4759445724467851058773503846834304672223785226936531021666916376323562671488"),
+    ],
+    [
+        MontFp!("782273457631193956426744043048759353979593033245260492990657945904665284910"),
+        MontFp!("13487130697992008212099652811750242205045881544509489831523448570173633517977"),
+        MontFp!("15621563974535086891768796441515013364217522966350445838133979748032034816142"),
+        MontFp!("19364835034502915244801518193980688426244659266819997726035650961451415757173"),
+        MontFp!("21037385853462058267099182407141652124171361973889761119816789091401609511088"),
+        MontFp!("20434791917020905003166852059282129255412677606775079570484129378535005615291"),
+        MontFp!("4835039666519156760310260600042269943079463379265872618778854224413385690994"),
+        MontFp!("17796521681519947552208651467058827825861565135255248123077469895978163706264"),
+        MontFp!("2823350440792171019111081223801188552138104039380675927963458669980277420276"),
+    ],
+    [
+        MontFp!("16030935304664378631941573945857397096373696981104104381156313618686049806120"),
+        MontFp!("17523561865544155408760007908067668065236326734119657233234283826019015377013"),
+        MontFp!("3861341406966982603014220134107636493882146780655211775629734223927755221098"),
+        MontFp!("1327887013530867777305056212037691710827939709365211251951525926327942169414"),
+        MontFp!("16874372098146373517691588057974501095408377103185981262983559391956463291137"),
+        MontFp!("1335930538845994150082853775454018356383085560294444442667355553131066129276"),
+        MontFp!("16846954448852864630121063053695845658867759327963014776419090787323732938912"),
+        MontFp!("1910615356880143423765930148112668984411979710628153215580997630269783916489"),
+        MontFp!("8793723522335768214688108364110927144836722932802666660252079036893034856492"),
+    ],
+    [
+        MontFp!("3725321587522884864935206279104882080790553804758085564413847527197687551835"),
+        MontFp!("17549397166194503933313005107479073474671951786436058351827338574279485542057"),
+        MontFp!("6575272615526665941236934551769345604089554458721499014263130089965203838692"),
+        MontFp!("19479945993771870488240738504390121923410154808673876321101554256856036124677"),
+        MontFp!("15218540520084042504179141700157006972641510542203443030571191341196460163766"),
+        MontFp!("11605382280428426652337162672330854829498688801746852913129963366330544359414"),
+        MontFp!("19452583367341408020642116770501289011436457479987875413223766731278874726613"),
+        MontFp!("2498463382382553480222037299113185800507848748313035345734629490930688205092"),
+        MontFp!("1815123960727364421144419865126922339611466868807520419660969560789979822474"),
+    ],
+    [
+        MontFp!("20531692711768862540943545541715345229360673134388506876856593310216372259130"),
+        MontFp!("21106443640856542784867046664180461359993554892163126756059125921876166419615"),
+        MontFp!("8538925154199646282458477113696635826112766123791239931164489946578874271866"),
+        MontFp!("6179996393486486548378164504724190431464526698002381214818146508779777698063"),
+        MontFp!("1334556948430115939422649531996020210538905726908545666936164977436729124944"),
+        MontFp!("14555087544451841622469763698691954343538388285983305607235034906273022598676"),
+        MontFp!("3263678860186354326206053303615515256258748076250020171477442794745232038780"),
+        MontFp!("1342606052959540554052550853649027290857482440100275878202185177537473434874"),
+        MontFp!("19067318604617984900108104413860593038444834168491290140413988853573796446193"),
+    ],
+    [
+        MontFp!("11453576191720077983310542494091726783885546118293459348522522324645101050430"),
+        MontFp!("3772400828106882724656632136643514300687950364203707059277582466654856015909"),
+        MontFp!("19928616354232846804233301414766074864065580313304404532140360351457581578733"),
+        MontFp!("17669618023197654971616078177762451816976570462585423216749814198562722234016"),
+        MontFp!("20487504497482961764356160511764652912371612840137405927810776425577238052311"),
+        MontFp!("15959943319286858239034503624455112049217253792773599324329593237810330429519"),
+        MontFp!("18384331160163107383609864825156022277275076414745740108239579270660154123750"),
+        MontFp!("11807744905122445070761653068499781933485269571078706728521902995972849333739"),
+        MontFp!("21636069700028297640587439425598371999203459272489053044479958900301869951268"),
+    ],
+    [
+        MontFp!("5974406255004817187688462241155741022204236935194897255519053490391727654963"),
+        MontFp!("18655439470676485950283686008645538637216956533059508817637925480405213882893"),
+        MontFp!("15164692255429309369428108531856612257028649418370969640920631880841690009016"),
+        MontFp!("12342219963417210875401056442100023070134657858086394031902694268469750570612"),
+        MontFp!("481209231155250366998260270814874408671884781003382050138985430923825730090"),
+        MontFp!("3242985953168013112117560001466320034030784952490866310190327264524235633420"),
+        MontFp!("13671160391160864796369771052335315926068131063004086507703804642392143876725"),
+        MontFp!("16716228406804746939632807079686149044089946710213611348848847599210659020138"),
+        MontFp!("9496049727665863372935045496498617414460003517119878231671018103126084599100"),
+    ],
+    [
+        MontFp!("16483340875218689502751737973203780724082025375353804209734656041473116836207"),
+        MontFp!("2627597076078148403546873341483726933849452415436198036537442451261384383723"),
+        MontFp!("20527956374075302103516613197928664717455732919429461243667758971357150882342"),
+        MontFp!("11711450220231538029408058975978592998998598526983681112180323327131923215776"),
+        MontFp!("14877293714143600802178367397934915488570060506993092692625720179311507474506"),
+        MontFp!("17326201000468992158693082078045140389930457394232528033746431682308160431934"),
+        MontFp!("8241890704089720408679017565592201736334812957892898769189351788325500937732"),
+        MontFp!("6134985085876540657808139826388808003135254271482158519839818774839726308917"),
+        MontFp!("6944918715501093472287921248184355748547193680657762762284351108190443908482"),
+    ],
+    [
+        MontFp!("20293371855859360749476040038457808453751087076170457949707661658124460443795"),
+        MontFp!("12686929429491234226470786986230897140429036877303905464553700071658994784104"),
+        MontFp!("17469937611674874489854850805106365496296990924579100118175990663783068480118"),
+        MontFp!("4389315288495042551686883151731749050970801790377604942482415778510472384968"),
+        MontFp!("11356013296312574683565144017425132580728729177241949155779586695189495537084"),
+        MontFp!("5103616537832821778796048073410908442363049367034544148603830689894368565040"),
+        MontFp!("17797731362169406634431131949969435652804582561417001546024888062211188454886"),
+        MontFp!("14413974530545126251158359344156378502844867672748912889426381728267720393327"),
+        MontFp!("18860675036245741580291857551498220749884348391920381715922087052471051304459"),
+    ],
+    [
+        MontFp!("2078681010293955893545295223175290151677764183673754633340142745613957031877"),
+        MontFp!("11594462210573371469687203943585180057860108341927961420756260896877407822187"),
+        MontFp!("8232172476137304604696594035794651005660416081930158074561971898151387789159"),
+        MontFp!("16234745736110953717672420346414210260779855851076189537371942811750295876135"),
+        MontFp!("12403261277735118438898936378116787991453555210970659659639856670648844247938"),
+        MontFp!("10260185954137740247486488192570496092684935183379388125044125653647328054023"),
+        MontFp!("12655661577981598013787126068450556825218951206788052328715378240540030673155"),
+        MontFp!("18875782029492829253540920061867800401544385695523240332551730645990253683286"),
+        MontFp!("13000939909369679921538945109975441940863265779072482929455684540500587590629"),
+    ],
+    [
+        MontFp!("239651505606383903278277662841450805219997298453219985892834268956273681444"),
+        MontFp!("14053674646208577108881262953518523519057705122297176784230960366018789686467"),
+        MontFp!("3606574524342197944154321263420984044427893927972300192386619594198948706444"),
+        MontFp!("4925738689374393290519002876270198297196104042467164940497567711764321354393"),
+        MontFp!("9820857610236925174040210045575219513594477725958302510866127781620764675531"),
+        MontFp!("8644935227560188528158307606853375529544842899940616765747319983176480635667"),
+        MontFp!("12589563927120228887319930197852404057542625019034806374830349240796880735981"),
+        MontFp!("13728987671030134173563628755348391107370774536000844606094840710456114349003"),
+        MontFp!("15280672692530045491619672502933299001869276703035606138561063102232345967821"),
+    ],
+    [
+        MontFp!("21236672540209166733321925277807375026701626666734236841532747395149863205571"),
+        MontFp!("18193368154219306112046312834283644566129199372283662927472078427038205531636"),
+        MontFp!("17828956732555553542546753429670551891943977601119756829631880115504235233984"),
+        MontFp!("16641047964358580103472953437535358748387376425127849904658691126285684204504"),
+        MontFp!("7196281413799658043487145161620082973834461754768351228587249162400339111893"),
+        MontFp!("21279455923934963235610861427104388147894350922169838127737714784897083581830"),
+        MontFp!("10868227810739752166142906769497786680491652628709341836398414527811509748689"),
+        MontFp!("2545479497580424357309396388184225593698470568625667945691755386799845345027"),
+        MontFp!("18560104754451358950174079457178017278416450108044438296553162755384040068059"),
+    ],
+    [
+        MontFp!("11209544817144484509471895492404241079181269159060632258040504564376475442191"),
+        MontFp!("14007605578670373547623429803718323316371456029307063658189484725071020560017"),
+        MontFp!("19316201371814679831554697580647476192318282119512681720915001227483533198021"),
+        MontFp!("16788142218280927569387096932066591137887806957079516944927766625343518189548"),
+        MontFp!("961359518362994763330685811948798278197676602059504713988410706948791494727"),
+        MontFp!("19776591693739287332042935252284088014720557305781829207369487992244783048185"),
+        MontFp!("9480779019638564372864984254416095889603560407402750333423136372713778963272"),
+        MontFp!("7812061847536565125280880398757948966749177710701972331770694629380983832516"),
+        MontFp!("14806224217889264732099766866344263686300132511433376375954468192761174167878"),
+    ],
+    [
+        MontFp!("10982734897602724370866115596864634266746118759609469486863878972425453415519"),
+        MontFp!("9054801238670111257982773992849940941038784597792282084645523468554872244495"),
+        MontFp!("16788499373458165601983802204061832376825550128562541027433580619384299691535"),
+        MontFp!("4361212778425224413929793165968418385407821814716394404713983701050982051159"),
+        MontFp!("21198869506404830651226227162808186595284220877501140400488215541390720176503"),
+        MontFp!("7255012904510681544072472510832565052731304049336267892176928038570971034121"),
+        MontFp!("9737409770400739938717035426255379270654933363992002237053138761832402079248"),
+        MontFp!("14206577906412186888550704503752653056320975796075254442765439825369882967977"),
+        MontFp!("19036632138581200062386943078412086222459679497578993523004498970778925638274"),
+    ],
+    [
+        MontFp!("2855178582526872375806959544405581665248537620420194093904041355969926293337"),
+        MontFp!("12896727255458884273207928529421874672712973447260798892551468479503233439215"),
+        MontFp!("20930350939164528694912500193219456539952966506926646436560438515643683077210"),
+        MontFp!("184093243282405111677536457857692693581379037444126410664343605529966199122"),
+        MontFp!("15658149328429348710722591333703516363901544310832580304722884306208924451465"),
+        MontFp!("17544235160628712643216064131303569753533519783718786133736357990785709619346"),
+        MontFp!("9378984995834426590515136439048146470293781405649183047514776402081048834772"),
+        MontFp!("15827462476470655610816981948418438654022314364182315935007413461648751735708"),
+        MontFp!("13474113844360907776462232979612140726930720201237003164521648175005015977732"),
+    ],
+    [
+        MontFp!("1846676454601041085237775396212630553832771346942418764660365576890630152018"),
+        MontFp!("8958790186410745003596973786908460746144469347369569174866696175944574520886"),
+        MontFp!("16716100142556090678395507171596864615262575578180211444515549196841601774046"),
+        MontFp!("17584363243087108058467208592097637069605249776196694465943790236027601639916"),
+        MontFp!("15462568643993327150997687623907692370120490318886920754261967569094539968909"),
+        MontFp!("11670427917584674115542198398366950879185738970881616803513412243898491416455"),
+        MontFp!("5883010686944177614793479335292002976406988590121850032334552332298599405710"),
+        MontFp!("20848023045403944451304856285219275218146149181988087184275301094312642906291"),
+        MontFp!("20892609628755793476767683891284835591758207667306100001065280698890821585620"),
+    ],
+    [
+        MontFp!("11041559416099382923560246079300939393371149141074957197352566129686429429340"),
+        MontFp!("17004024027027164912556351303862470964296900000646134239805113699616064012220"),
+        MontFp!("110742314120280698533248152539115345099402903868297760208823130532853128340"),
+        MontFp!("13611598917097489441998314826578736196564311189470688979687759717921520208428"),
+        MontFp!("20362978391139708024092837231934567580385484740720090300868417284017430844864"),
+        MontFp!("4130975720087443718484415210347908638971321493417335260526136858657572592254"),
+        MontFp!("15799784358302997284875412214187555553319485274948108081666806701893845835839"),
+        MontFp!("12410480753305882251320943831026503736012757975027018073585110506521877824193"),
+        MontFp!("11835843853657957571888855948788121206617247107501669280697395787347649231752"),
+    ],
+    [
+        MontFp!("3326313455005237548503557557286834479752096887215379141590090769222516357133"),
+        MontFp!("3193633369267878319453517203588676707547172638050950764150162277144428673066"),
+        MontFp!("3543696055990388683071939150214505536733386566291338758519836333135488212473"),
+        MontFp!("453840133795717001022433249997110059635014609516452256954528366651276289770"),
+        MontFp!("10086004265216215714804100477403907145516617200748655771783383139854288214070"),
+        MontFp!("18938459257787140207383332020952460039308194017940327258304986766920440675756"),
+        MontFp!("18017538799787896442217663532610710859333377084532654794368604069493775630216"),
+        MontFp!("5517691591172342790575564654696650661133600869824307632295945043592492062300"),
+        MontFp!("5846204096126701465613249085053971321249645306247508562697696901334354225619"),
+    ],
+    [
+        MontFp!("3177064511134248081568628736306700282095095665917536853000298191943047784014"),
+        MontFp!("7886005759395499452194553110700824805018792487440311729836576312028682853862"),
+        MontFp!("19249432464407391173245558257296856631584193393398113008165174416171947900609"),
+        MontFp!("16818455958785909569371690525990846776263170512884599090849081099178789681425"),
+        MontFp!("16250344336602567919050898941410625842485562539342327155695417850618940905704"),
+        MontFp!("6273998461375119044609362240019558608655450921258416376794979330773412610302"),
+        MontFp!("15933077340738498731035173703791932079747269039222967104684412531145625747085"),
+        MontFp!("17631878023023477567294765381542867314814954498487832435087010633074888584009"),
+        MontFp!("3387656327342575368928488173891176548794878068816523542226413637288662472792"),
+    ],
+    [
+        MontFp!("15770343706243316227190526252701886989383556270818375222569120097305537622560"),
+        MontFp!("21025947829537149117391184273139276031347299127217645728072786010534368285621"),
+        MontFp!("11728430055160129100077268133090903533902452454196978455625432056779499908581"),
+        MontFp!("2184576630760971645143677026393147474439766939689140114811262608230414186937"),
+        MontFp!("20744811853491523948066896610767067484129121010717068573365370365324040781186"),
+        MontFp!("5378129452609441814399329369785055593231824205814541852039878139773312247469"),
+        MontFp!("18082900764136659604287793533371380099349929291808230688664846500365863263118"),
+        MontFp!("10463958995559323021196963984934883570109613942564610388110191948063546468897"),
+        MontFp!("244120224370345949702567256216804961153505781666838608095297311545160357032"),
+    ],
+    [
+        MontFp!("17924705581798291273661662368787600134425123985006190354093511903371507000154"),
+        MontFp!("3107793385049037773698181795186417899797325916401357881664725445733609110598"),
+        MontFp!("5665818573123185227274537904890713907625420710982346291959547939830358917272"),
+        MontFp!("967322682615997637785254033877348832211978156650281338584051044602311410196"),
+        MontFp!("19419941178285529854771216440310658103611219351729270204884834098822007849679"),
+        MontFp!("6901963792883328370624032472781824547409040392368725235274158498520441238159"),
+        MontFp!("13721659825627300509722716825333808233371435398666022190921612703736274379535"),
+        MontFp!("2784281502858555298249063959836879135450746982163416748737579846439268828933"),
+        MontFp!("9904373282060708277943634486822397019446454722637742217276784802015824898651"),
+    ],
+    [
+        MontFp!("5782567592658163731724098371574354386783075175203877502094122152538152467682"),
+        MontFp!("10854330629450460532485325799036675355255970975925867222693267730198057197195"),
+        MontFp!("7162558805520478103072398765799613453839879264508883857822705210986309908966"),
+        MontFp!("14561060495007338369036260685346480181377385446422680685283066135483167829865"),
+        MontFp!("11521954935420160563214644175207412771411940789064933791820101643809540481492"),
+        MontFp!("3893071612329582305940837979511590531534863287842007408024123330272447072664"),
+        MontFp!("19982770443796802008915975147614604175753586689418309845602797606117149147490"),
+        MontFp!("19714753609495058998670661272525609201695470529132258598980221623379639411831"),
+        MontFp!("10656632215192474178114431876399520721084839753473211054259843433641616176373"),
+    ],
+    [
+        MontFp!("15519943627473966175746342389219894179761085602008029155282295063466585111230"),
+        MontFp!("429220418726674010600368106136723992478318707196454289985261340376476917460"),
+        MontFp!("16943119555428737036287647863079565463224985076466268175824843518378134856246"),
+        MontFp!("7079268853451648384434335899135383974808119657387366504271184409878695702895"),
+        MontFp!("5787261347913259367727842908192773692002199385877294080619854106978539332397"),
+        MontFp!("8254314874636465273639128395147895313719165057850599581478980264860146008069"),
+        MontFp!("15417738281457065064716789110361253613929614783743035738325702945037527193953"),
+        MontFp!("8995940809050737092434676062651493038351424361820394016896779859938155003450"),
+        MontFp!("8930952966754141446126393622188683431566029237395186071059700311531927009283"),
+    ],
+    [
+        MontFp!("9012970415439810859538557593310902447051948348093454112737452817814629449500"),
+        MontFp!("21700461010267441715993595978543322483687194036588160210184366057201658507847"),
+        MontFp!("19191426116308521669196161733982754533604260068907220372422504926794231257150"),
+        MontFp!("18022413735343984488479130392027693687461867574196874267731354592562070094392"),
+        MontFp!("13853879871506882218224060020827336496729967255850404386800036291019021382781"),
+        MontFp!("13303720125164503437055631247918150173085142868095887759030649510172293881844"),
+        MontFp!("12463581809293287384469946044562671884924464520288697069370030386140109068261"),
+        MontFp!("20468619377263375923071378952981485015200979956112400596511865225946853604157"),
+        MontFp!("16682148710681177357125570715056314888342059670705617513402649433802720432267"),
+    ],
+    [
+        MontFp!("16299073895000203963165709887505572454180623116454760411179563591228007694413"),
+        MontFp!("6439155427163506786329349605983728674821430800627321435200421453561910062302"),
+        MontFp!("16531483734580605436075637034861280240342858648848575098901014901746112480232"),
+        MontFp!("17413802217650584016261506268242623594956116228659732892682224912798301233645"),
+        MontFp!("19833018739354446018077109493089909435818386368530968355647208939546565982905"),
+        MontFp!("13005203599293796776324509750491064421128717423989464867065044987475986374420"),
+        MontFp!("15433711189444672576513248931602290892518442446252602686878477157678233603772"),
+        MontFp!("11272192842480959445178012145556234469776261923967845001064211055340129168135"),
+        MontFp!("21349777755000957327199310930646977290027138137542241555905014230683052104267"),
+    ],
+    [
+        MontFp!("2414795183415356147955181901405712632718942970568205736628916600696077941534"),
+        MontFp!("13910388410253717440990758214044472114511432613509643223811561885135488623236"),
+        MontFp!("10073917454281511762447567386654530277776617831005093724557094001489771821135"),
+        MontFp!("15674657915196276639699997458656008228696751013801231738985398708672037426000"),
+        MontFp!("12030695425048598984176709301472822771003849589255577773183310838231109921591"),
+        MontFp!("6658172369461756755506276881582345916252610724131747740625283609123100367529"),
+        MontFp!("6460801016753822141904293563006139350014125998787400018150863192907944207957"),
+        MontFp!("10798491465896968361800574703868612181389697312199241920447162078078725409638"),
+        MontFp!("6331917501914253534943383807348566698937757752033630507696817298838693259937"),
+    ],
+    [
+        MontFp!("21521172968280414216108032807577565012642487518706778276505136864150789112592"),
+        MontFp!("11443202152743097070847729825799673217706162711935940510632741405015900516668"),
+        MontFp!("10360970774813507384412119692215277392320350056791930702078433469299837875151"),
+        MontFp!("8111678922881662305935841208620197469657237670526301850210945861223648259810"),
+        MontFp!("3828566775247110089904016755996284741548002327940628727687176763639903716661"),
+        MontFp!("21019871488460899469684764817167629979753844957147537040703291790231271795829"),
+        MontFp!("11744049805554498869931942573519884330545637954557542018916739662277241821806"),
+        MontFp!("4521092770491436085084640166923844634777984445583984077999595768778116564222"),
+        MontFp!("2428018726292924561718904390333390438951211767580762396913313600061529081905"),
+    ],
+    [
+        MontFp!("2672992591753804066533616673591169777906973091506536575810912266557203322920"),
+        MontFp!("5631180351966611479340932319081124575466459942666630580683510336616679680271"),
+        MontFp!("10149209329290376952496655294191511204529081153402908137750268385347783758010"),
+        MontFp!("18292794133971639465196495021864699906132845458944945214425906730119328661326"),
+        MontFp!("21442863185355178191454777233963814974940050392649316620141474331670970354424"),
+        MontFp!("3768420898310640667772098495371174917665155708578905018940113026409140957987"),
+        MontFp!("13677778555119984843885943251631654212176086447994430552012266440677394344669"),
+        MontFp!("13884681165958999171515885225547717032289759601884108191367706162606597842698"),
+        MontFp!("123196094575938824660055152882088188411485715788351262262924974166600702398"),
+    ],
+    [
+        MontFp!("1121836698372380581784934880625694675020871234049336489788624481922395781738"),
+        MontFp!("20941331435492311592529607715649713508861806194386837398916323083940590908651"),
+        MontFp!("2470912827043971002614412337239267059969980871643559631900987795139200233821"),
+        MontFp!("10806505189594612637071931546921663393081238567888534876058498530874738324701"),
+        MontFp!("667951375802630033661777802749339877422061577764798227349674331630120025667"),
+        MontFp!("18416355600415187627018330134584431345513028652497077471935121971918269469363"),
+        MontFp!("14167152054564590179475064444026440101215733530475912312508414765738108715862"),
+        MontFp!("18633695428427030575173671831485026260967985663658201463236228419717189642766"),
+        MontFp!("152822669216765741203342297512101138657182497046533047369566701489981099230"),
+    ],
+    [
+        MontFp!("13835701173750333056481994253160471551109858589047436642253159392878873667798"),
+        MontFp!("3993942321148722649703549241999711668949060533276325947207349685002693878681"),
+        MontFp!("15582244332423092177434976075689385819450099629893355758782548118218073388706"),
+        MontFp!("15110236879710270343688993144525012407319759236015974251051640787524859884359"),
+        MontFp!("5104405092803829419537383694663582438349376353030379488011426113631155364320"),
+        MontFp!("11034886586481561934231698674217393887518948538322130743646058638919797229737"),
+        MontFp!("21614370562083755709911993869347579638113152610927033622836963904672826178593"),
+        MontFp!("11909716327216431973191112809713028257963610176155315584304717743448686635887"),
+        MontFp!("9670047520194835060472941420215502268522351803257892125345072551055025494562"),
+    ],
+    [
+        MontFp!("8752044341583145728028411582583224350471084864272507077624316823400738066962"),
+        MontFp!("20685513123216586620977713797881862528998788503897607377725195418550074311551"),
+        MontFp!("20219162196364967181713755472576994456615542213293827108438968625041058321145"),
+        MontFp!("18287830464300889532838439052863785386620820747210980263612361113628554829988"),
+        MontFp!("10146051396529576924597355409059465520468869175466632446875430377637660889879"),
+        MontFp!("13466459020798488583841582724067017412922317425102130151754649408559458307937"),
+        MontFp!("14062280191830459071860023268317938748180670907089383563443465249500572357980"),
+        MontFp!("18486553995294693573565546696966437493113894571993019524170031057367640632085"),
+        MontFp!("11156566424349445901806390826392443373766529722049710427351550423908421767094"),
+    ],
+    [
+        MontFp!("209671637225069235519570008386635562520193585953162475265417907100134848923"),
+        MontFp!("17226989944018790920809176115775819865824823495740082575382169759054625372382"),
+        MontFp!("15644589951345053163188258692419292119540702867922222648564209455819510994564"),
+        MontFp!("3689635641036835670663293726548900381724135109917216986885298700630212836435"),
+        MontFp!("3367607896403464195671402279459329078003744183784952830994679539910724667259"),
+        MontFp!("6227320552634621985217890398406127207902736210419315868051857823685244516725"),
+        MontFp!("7357930890687295365886228617478473072206575811998185548162905341534675558305"),
+        MontFp!("9337019296542497689612612043175604595811913796434346282222317112981594913389"),
+        MontFp!("14658782859891978670907070276103444826326577838777644289370207112293812556778"),
+    ],
+    [
+        MontFp!("1700861002075407761970169168361393086239805454951858464329713573177596208454"),
+        MontFp!("8422307882422345667268572118847227804767508317685246864132851358134342544918"),
+        MontFp!("3824678171886439611637777800578730196591582015637069631407414390326082519384"),
+        MontFp!("7520989644070067743500997565082513560943860081670904302057616063200273050286"),
+        MontFp!("5278276919931895959830110725703210158384647399821914390314400092195592076331"),
+        MontFp!("14590632939277529585876696200177152214896495867542780671631701634592299041714"),
+        MontFp!("14365499645924743985349770983085181263329435144891175678390938245209017764418"),
+        MontFp!("2519790270252875654107597063434691592006935573176284731324585122712988059511"),
+        MontFp!("17688843544040778657269233842324532395371012201506418912518394656290716826075"),
+    ],
+    [
+        MontFp!("16584068781164994465207120381716024087231836173689783891650623302438290695506"),
+        MontFp!("12224860044594664185598615945328866758529752520066027818906177267571423023661"),
+        MontFp!("13664317767999211366109254182438581912610775541954425083255023643648887081779"),
+        MontFp!("19324196860555787958873349597666822462940695051471419602454830948112942481945"),
+        MontFp!("15338841226759355791277440652242849878000656382388414806186764010001628984934"),
+        MontFp!("11076363155150973228897602285090741665942726007445165132980573631249449594126"),
+        MontFp!("11228309866140794620879641097623963859536328868056691748463227126359575786386"),
+        MontFp!("4762608512226640372168720665137259637840828925512114281702049841301872652787"),
+        MontFp!("18282645934358125859102195916568492018711932725386725562892735740355836227532"),
+    ],
+    [
+        MontFp!("12803228415054755333149187333584509982900042807310255834005394843350472605458"),
+        MontFp!("17675693156369747720817703064233611574822178844066411565804543111769294187197"),
+        MontFp!("9900029048144575309490519431063332695303076438539483419053219772370202428926"),
+        MontFp!("3684590949621971596368895784562632626464811455818343794800044114209066071601"),
+        MontFp!("5443335602638685057982926800093482287199751584817191972983546508574786160090"),
+        MontFp!("11352900694666160844325992247118358443639716695965864728670968730093466793722"),
+        MontFp!("9836739435541786452166525951732520477055729763398281521212184905286650567233"),
+        MontFp!("8222926590877635625730738050718327099397892409701316035188479123499338707893"),
+        MontFp!("8154558268770648194631329585722892880905143452138234292827603893129808716905"),
+    ],
+];
+
+/// The circomlib Poseidon parameters for the BN254 curve with a internal state of size t=9.
+pub static POSEIDON1_BN254_T9_PARAMS: Poseidon1Permutation<Scalar, T, D, ROUNDS_F, ROUNDS_P> =
+    Poseidon1Permutation::new(MDS, FULL_RC, PARTIAL_RC);
+
+/// Applies the Poseidon permutation to a 9-element state over `bn254`.
+///
+/// Returns a new permuted state based on the input.
+///
+/// # Arguments
+/// * `state` - A reference to the input state array (`[ark_bn254::Fr; 9]`).
+///
+/// # Returns
+/// A permuted state as `[ark_bn254::Fr; 9]`.
+#[must_use]
+pub fn permutation(state: &[ark_bn254::Fr; 9]) -> [ark_bn254::Fr; 9] {
+    POSEIDON1_BN254_T9_PARAMS.permutation(state)
+}
+
+/// Applies the Poseidon permutation in place to a 9-element state over `bn254`.
+///
+/// Mutates the input state array directly.
+///
+/// # Arguments
+/// * `state` - A mutable reference to the state array (`[ark_bn254::Fr; 9]`).
+pub fn permutation_in_place(state: &mut [ark_bn254::Fr; 9]) {
+    POSEIDON1_BN254_T9_PARAMS.permutation_in_place(state);
+}
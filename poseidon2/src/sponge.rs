@@ -0,0 +1,516 @@
+//! A sponge construction on top of the Poseidon2 permutation, following the SAFE API ([eprint.iacr.org/2023/522](https://eprint.iacr.org/2023/522)).
+//!
+//! # Construction
+//!
+//! The state of size `T` is split into a capacity part `state[0..T-rate]` and a rate part `state[T-rate..T]`.
+//! Before using the sponge, the caller declares the IO pattern, i.e., the sequence of [`SpongeOp::Absorb`] and [`SpongeOp::Squeeze`] operations including their lengths.
+//! Together with a domain separator, the IO pattern is hashed into a tag, which initializes `state[0]`. All other elements of the state are initialized with zero.
+//!
+//! * **Absorbing** adds input elements to the rate part, one position at a time. If the rate part is full, the permutation is applied before the next element is added.
+//!   After absorbing, the next squeeze always applies the permutation first.
+//! * **Squeezing** reads output elements from the rate part, one position at a time. If all elements of the rate part were read (or if the previous operation was absorbing), the permutation is applied before reading.
+//!
+//! # Padding and domain separation
+//!
+//! No padding is applied. Since the lengths of all operations are part of the tag, two different IO patterns (or domain separators) lead to different initial states, which makes padding unnecessary.
+//! Consequently, the sponge enforces that the actual calls match the declared IO pattern and returns a [`SpongeError`] otherwise.
+//!
+//! # Tag computation
+//!
+//! Deviating from the SAFE paper, the tag is not computed with SHA3 but with the Poseidon2 permutation itself, such that no additional dependency is required:
+//! 1. The IO pattern is encoded as in SAFE: consecutive operations of the same kind are merged, then each absorb of length `n` is encoded as the 32-bit word `0x8000_0000 | n` and each squeeze of length `n` as `n`.
+//!    The words are serialized in big-endian order and the domain separator bytes are appended.
+//! 2. The bytes are split into chunks of `(F::MODULUS_BIT_SIZE - 1) / 8` bytes (31 for `bn254`), each interpreted as a little-endian integer.
+//! 3. The sequence `[number of bytes, chunk_0, ..., chunk_{k-1}]` is zero-padded to a multiple of `T - 1` and absorbed into an all-zero state, adding each block to `state[1..T]` and applying the permutation afterwards.
+//! 4. The tag is `state[1]` after the last permutation.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use poseidon2::sponge::{Poseidon2Sponge, SpongeOp};
+//!
+//! let pattern = [SpongeOp::Absorb(5), SpongeOp::Squeeze(1)];
+//! let mut sponge = Poseidon2Sponge::new(poseidon2::bn254::t4::permutation_in_place, 3, &pattern, b"my protocol")?;
+//! sponge.absorb(&input)?;
+//! let output = sponge.squeeze(1)?;
+//! sponge.finish()?;
+//! ```
+
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+
+/// A single operation of the IO pattern of a [`Poseidon2Sponge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(
+    clippy::exhaustive_enums,
+    reason = "The SAFE API only defines these two operations"
+)]
+pub enum SpongeOp {
+    /// Absorb the given number of field elements.
+    Absorb(u32),
+    /// Squeeze the given number of field elements.
+    Squeeze(u32),
+}
+
+/// The errors that can occur when using a [`Poseidon2Sponge`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpongeError {
+    /// The rate must be in `1..T`, such that the capacity is at least one element.
+    #[error("invalid rate {rate} for state size {state_size}")]
+    InvalidRate {
+        /// The requested rate.
+        rate: usize,
+        /// The state size of the permutation.
+        state_size: usize,
+    },
+    /// The IO pattern contains an operation of length zero or a length which does not fit into 31 bits.
+    #[error("invalid length in IO pattern")]
+    InvalidPattern,
+    /// The call does not match the next operation of the IO pattern.
+    #[error("call does not match IO pattern, expected {expected:?}")]
+    PatternMismatch {
+        /// The remaining part of the expected operation, or `None` if the IO pattern is already completed.
+        expected: Option<SpongeOp>,
+    },
+    /// The sponge was finished before all operations of the IO pattern were performed.
+    #[error("IO pattern not completed")]
+    IncompletePattern,
+}
+
+/// A sponge over the Poseidon2 permutation with state size `T`, see the [module documentation](self) for the exact construction.
+#[derive(Debug, Clone)]
+pub struct Poseidon2Sponge<F: PrimeField, const T: usize> {
+    state: [F; T],
+    rate: usize,
+    permutation: fn(&mut [F; T]),
+    absorb_pos: usize,
+    squeeze_pos: usize,
+    /// The merged IO pattern in reverse order, such that the next operation is at the end.
+    pattern: Vec<SpongeOp>,
+}
+
+impl<F: PrimeField, const T: usize> Poseidon2Sponge<F, T> {
+    /// Creates a new sponge from the given in-place permutation, rate, IO pattern and domain separator.
+    ///
+    /// The capacity is `T - rate`.
+    ///
+    /// # Errors
+    /// Returns an error if the rate is not in `1..T` or if the IO pattern contains an operation of length zero or of length at least `2^31`.
+    pub fn new(
+        permutation: fn(&mut [F; T]),
+        rate: usize,
+        pattern: &[SpongeOp],
+        domain_separator: &[u8],
+    ) -> Result<Self, SpongeError> {
+        if rate == 0 || rate >= T {
+            return Err(SpongeError::InvalidRate {
+                rate,
+                state_size: T,
+            });
+        }
+        let pattern = Self::merge_pattern(pattern)?;
+        let mut state = [F::zero(); T];
+        state[0] = Self::compute_tag(permutation, &pattern, domain_separator);
+        Ok(Self {
+            state,
+            rate,
+            permutation,
+            absorb_pos: 0,
+            squeeze_pos: rate,
+            pattern: pattern.into_iter().rev().collect(),
+        })
+    }
+
+    /// Hashes a variable-length input to `output_len` field elements.
+    ///
+    /// This is a shorthand for a sponge with the IO pattern `[Absorb(input.len()), Squeeze(output_len)]`.
+    ///
+    /// # Errors
+    /// Returns an error if the rate is not in `1..T` or if `input` or `output_len` are empty or too large.
+    pub fn hash(
+        permutation: fn(&mut [F; T]),
+        rate: usize,
+        domain_separator: &[u8],
+        input: &[F],
+        output_len: usize,
+    ) -> Result<Vec<F>, SpongeError> {
+        let absorb_len = u32::try_from(input.len()).map_err(|_| SpongeError::InvalidPattern)?;
+        let squeeze_len = u32::try_from(output_len).map_err(|_| SpongeError::InvalidPattern)?;
+        let mut sponge = Self::new(
+            permutation,
+            rate,
+            &[SpongeOp::Absorb(absorb_len), SpongeOp::Squeeze(squeeze_len)],
+            domain_separator,
+        )?;
+        sponge.absorb(input)?;
+        let output = sponge.squeeze(output_len)?;
+        sponge.finish()?;
+        Ok(output)
+    }
+
+    /// Absorbs the given field elements into the sponge.
+    ///
+    /// An absorb operation of the IO pattern may be split across multiple calls.
+    ///
+    /// # Errors
+    /// Returns an error if the IO pattern does not expect (at least) `input.len()` more elements to be absorbed. Absorbing an empty slice is a no-op.
+    pub fn absorb(&mut self, input: &[F]) -> Result<(), SpongeError> {
+        if input.is_empty() {
+            return Ok(());
+        }
+        self.consume(SpongeOp::Absorb, input.len())?;
+        let capacity = T - self.rate;
+        for x in input {
+            if self.absorb_pos == self.rate {
+                (self.permutation)(&mut self.state);
+                self.absorb_pos = 0;
+            }
+            self.state[capacity + self.absorb_pos] += x;
+            self.absorb_pos += 1;
+        }
+        // Force a permutation before the next squeeze
+        self.squeeze_pos = self.rate;
+        Ok(())
+    }
+
+    /// Squeezes `length` field elements from the sponge.
+    ///
+    /// A squeeze operation of the IO pattern may be split across multiple calls.
+    ///
+    /// # Errors
+    /// Returns an error if the IO pattern does not expect (at least) `length` more elements to be squeezed. Squeezing zero elements is a no-op.
+    pub fn squeeze(&mut self, length: usize) -> Result<Vec<F>, SpongeError> {
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+        self.consume(SpongeOp::Squeeze, length)?;
+        let capacity = T - self.rate;
+        let mut output = Vec::with_capacity(length);
+        for _ in 0..length {
+            if self.squeeze_pos == self.rate {
+                (self.permutation)(&mut self.state);
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            output.push(self.state[capacity + self.squeeze_pos]);
+            self.squeeze_pos += 1;
+        }
+        Ok(output)
+    }
+
+    /// Finishes the sponge, checking that the complete IO pattern was performed.
+    ///
+    /// # Errors
+    /// Returns an error if there are operations of the IO pattern left.
+    pub fn finish(self) -> Result<(), SpongeError> {
+        if self.pattern.is_empty() {
+            Ok(())
+        } else {
+            Err(SpongeError::IncompletePattern)
+        }
+    }
+
+    /// Checks that the next operation of the IO pattern matches and removes `length` elements from it.
+    fn consume(&mut self, op: fn(u32) -> SpongeOp, length: usize) -> Result<(), SpongeError> {
+        let expected = self.pattern.last().copied();
+        let mismatch = SpongeError::PatternMismatch { expected };
+        let length = u32::try_from(length).map_err(|_| mismatch.clone())?;
+        let remaining = match (expected, op(length)) {
+            (Some(SpongeOp::Absorb(remaining)), SpongeOp::Absorb(_))
+            | (Some(SpongeOp::Squeeze(remaining)), SpongeOp::Squeeze(_))
+                if remaining >= length =>
+            {
+                remaining - length
+            }
+            _ => return Err(mismatch),
+        };
+        self.pattern.pop();
+        if remaining > 0 {
+            self.pattern.push(op(remaining));
+        }
+        Ok(())
+    }
+
+    /// Merges consecutive operations of the same kind and checks that all lengths are valid.
+    fn merge_pattern(pattern: &[SpongeOp]) -> Result<Vec<SpongeOp>, SpongeError> {
+        let mut merged: Vec<SpongeOp> = Vec::with_capacity(pattern.len());
+        for op in pattern {
+            let new = match (merged.last(), op) {
+                (_, SpongeOp::Absorb(0) | SpongeOp::Squeeze(0)) => {
+                    return Err(SpongeError::InvalidPattern);
+                }
+                (Some(SpongeOp::Absorb(a)), SpongeOp::Absorb(b)) => {
+                    SpongeOp::Absorb(a.checked_add(*b).ok_or(SpongeError::InvalidPattern)?)
+                }
+                (Some(SpongeOp::Squeeze(a)), SpongeOp::Squeeze(b)) => {
+                    SpongeOp::Squeeze(a.checked_add(*b).ok_or(SpongeError::InvalidPattern)?)
+                }
+                _ => {
+                    merged.push(*op);
+                    continue;
+                }
+            };
+            merged.pop();
+            merged.push(new);
+        }
+        if merged.iter().any(|op| match op {
+            SpongeOp::Absorb(n) | SpongeOp::Squeeze(n) => *n >= 1 << 31,
+        }) {
+            return Err(SpongeError::InvalidPattern);
+        }
+        Ok(merged)
+    }
+
+    /// Computes the tag from the merged IO pattern and the domain separator, see the [module documentation](self).
+    fn compute_tag(
+        permutation: fn(&mut [F; T]),
+        pattern: &[SpongeOp],
+        domain_separator: &[u8],
+    ) -> F {
+        let mut bytes = Vec::with_capacity(4 * pattern.len() + domain_separator.len());
+        for op in pattern {
+            let word = match op {
+                SpongeOp::Absorb(n) => 0x8000_0000 | n,
+                SpongeOp::Squeeze(n) => *n,
+            };
+            bytes.extend_from_slice(&word.to_be_bytes());
+        }
+        bytes.extend_from_slice(domain_separator);
+
+        let chunk_size = (F::MODULUS_BIT_SIZE as usize - 1) / 8;
+        let input = core::iter::once(F::from(bytes.len() as u64))
+            .chain(bytes.chunks(chunk_size).map(F::from_le_bytes_mod_order))
+            .collect::<Vec<_>>();
+        let mut state = [F::zero(); T];
+        for block in input.chunks(T - 1) {
+            for (s, x) in state[1..].iter_mut().zip(block) {
+                *s += x;
+            }
+            permutation(&mut state);
+        }
+        state[1]
+    }
+}
+
+#[cfg(all(feature = "bn254", feature = "t3", feature = "t4", feature = "t8"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254;
+    use ark_std::{UniformRand, rand::thread_rng};
+    use std::str::FromStr;
+
+    type Scalar = ark_bn254::Fr;
+
+    #[test]
+    fn sponge_rejects_invalid_parameters() {
+        let pattern = [SpongeOp::Absorb(1), SpongeOp::Squeeze(1)];
+        for rate in [0, 4, 5] {
+            assert_eq!(
+                Poseidon2Sponge::<Scalar, 4>::new(
+                    bn254::t4::permutation_in_place,
+                    rate,
+                    &pattern,
+                    b"test"
+                )
+                .map(|_| ()),
+                Err(SpongeError::InvalidRate {
+                    rate,
+                    state_size: 4
+                }),
+                "rate {rate} should be rejected"
+            );
+        }
+        for pattern in [
+            &[SpongeOp::Absorb(0)][..],
+            &[SpongeOp::Squeeze(0)],
+            &[SpongeOp::Absorb(1 << 30), SpongeOp::Absorb(1 << 30)],
+        ] {
+            assert_eq!(
+                Poseidon2Sponge::<Scalar, 4>::new(
+                    bn254::t4::permutation_in_place,
+                    3,
+                    pattern,
+                    b"test"
+                )
+                .map(|_| ()),
+                Err(SpongeError::InvalidPattern),
+                "pattern {pattern:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn sponge_enforces_pattern() {
+        let pattern = [SpongeOp::Absorb(3), SpongeOp::Squeeze(2)];
+        let mut sponge = Poseidon2Sponge::<Scalar, 4>::new(
+            bn254::t4::permutation_in_place,
+            3,
+            &pattern,
+            b"test",
+        )
+        .expect("valid parameters");
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SpongeError::PatternMismatch {
+                expected: Some(SpongeOp::Absorb(3))
+            }),
+            "squeeze before absorb should fail"
+        );
+        sponge
+            .absorb(&[Scalar::from(1u64), Scalar::from(2u64)])
+            .expect("absorb matches pattern");
+        assert_eq!(
+            sponge.absorb(&[Scalar::from(3u64), Scalar::from(4u64)]),
+            Err(SpongeError::PatternMismatch {
+                expected: Some(SpongeOp::Absorb(1))
+            }),
+            "absorbing too many elements should fail"
+        );
+        sponge
+            .absorb(&[Scalar::from(3u64)])
+            .expect("absorb matches pattern");
+        sponge.squeeze(1).expect("squeeze matches pattern");
+        assert_eq!(
+            sponge.clone().finish(),
+            Err(SpongeError::IncompletePattern),
+            "finish before completing the pattern should fail"
+        );
+        sponge.squeeze(1).expect("squeeze matches pattern");
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SpongeError::PatternMismatch { expected: None }),
+            "squeeze after completing the pattern should fail"
+        );
+        sponge.finish().expect("pattern is completed");
+    }
+
+    #[test]
+    fn sponge_split_calls_match_single_call() {
+        let mut rng = thread_rng();
+        let input = (0..10).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+        let pattern = [SpongeOp::Absorb(10), SpongeOp::Squeeze(5)];
+
+        let mut sponge1 = Poseidon2Sponge::<Scalar, 3>::new(
+            bn254::t3::permutation_in_place,
+            2,
+            &pattern,
+            b"test",
+        )
+        .expect("valid parameters");
+        sponge1.absorb(&input).expect("absorb matches pattern");
+        let output1 = sponge1.squeeze(5).expect("squeeze matches pattern");
+
+        // Same pattern declared with split operations, since operations are merged
+        let pattern = [
+            SpongeOp::Absorb(3),
+            SpongeOp::Absorb(7),
+            SpongeOp::Squeeze(1),
+            SpongeOp::Squeeze(4),
+        ];
+        let mut sponge2 = Poseidon2Sponge::<Scalar, 3>::new(
+            bn254::t3::permutation_in_place,
+            2,
+            &pattern,
+            b"test",
+        )
+        .expect("valid parameters");
+        sponge2.absorb(&input[..1]).expect("absorb matches pattern");
+        sponge2.absorb(&input[1..]).expect("absorb matches pattern");
+        let mut output2 = sponge2.squeeze(2).expect("squeeze matches pattern");
+        output2.extend(sponge2.squeeze(3).expect("squeeze matches pattern"));
+
+        assert_eq!(output1, output2, "split calls should give the same output");
+        sponge1.finish().expect("pattern is completed");
+        sponge2.finish().expect("pattern is completed");
+    }
+
+    #[test]
+    fn sponge_domain_separation() {
+        let input = [Scalar::from(1u64), Scalar::from(2u64)];
+        let hash = |ds: &[u8], input: &[Scalar], output_len| {
+            Poseidon2Sponge::<Scalar, 4>::hash(
+                bn254::t4::permutation_in_place,
+                3,
+                ds,
+                input,
+                output_len,
+            )
+            .expect("valid parameters")
+        };
+        let output = hash(b"test", &input, 1);
+        assert_ne!(
+            output,
+            hash(b"test2", &input, 1),
+            "different domain separators should give different outputs"
+        );
+        assert_ne!(
+            output,
+            hash(b"test", &[input[0], input[1], Scalar::from(0u64)], 1),
+            "zero padding should give a different output"
+        );
+        assert_ne!(
+            output[0],
+            hash(b"test", &input, 2)[0],
+            "different output lengths should give different outputs"
+        );
+    }
+
+    #[test]
+    fn sponge_matches_manual_construction() {
+        let mut rng = thread_rng();
+        let input = (0..9).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+        let output = Poseidon2Sponge::<Scalar, 8>::hash(
+            bn254::t8::permutation_in_place,
+            7,
+            b"test",
+            &input,
+            8,
+        )
+        .expect("valid parameters");
+
+        let pattern = [SpongeOp::Absorb(9), SpongeOp::Squeeze(8)];
+        let mut state = [Scalar::from(0u64); 8];
+        state[0] = Poseidon2Sponge::<Scalar, 8>::compute_tag(
+            bn254::t8::permutation_in_place,
+            &pattern,
+            b"test",
+        );
+        state[1..8]
+            .iter_mut()
+            .zip(&input[..7])
+            .for_each(|(s, x)| *s += x);
+        bn254::t8::permutation_in_place(&mut state);
+        state[1..3]
+            .iter_mut()
+            .zip(&input[7..])
+            .for_each(|(s, x)| *s += x);
+        bn254::t8::permutation_in_place(&mut state);
+        let mut expected = state[1..8].to_vec();
+        bn254::t8::permutation_in_place(&mut state);
+        expected.push(state[1]);
+        assert_eq!(output, expected, "sponge should match manual construction");
+    }
+
+    #[test]
+    fn sponge_kat() {
+        let input = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(3u64)];
+        let output = Poseidon2Sponge::<Scalar, 4>::hash(
+            bn254::t4::permutation_in_place,
+            3,
+            b"poseidon2 sponge",
+            &input,
+            1,
+        )
+        .expect("valid parameters");
+        assert_eq!(
+            output,
+            [Scalar::from_str(
+                "18598911166386691803728953584030508220634523396103460406976368257299529559836"
+            )
+            .expect("Is in Fr")],
+            "sponge output should match KAT"
+        );
+    }
+}
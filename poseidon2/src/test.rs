@@ -0,0 +1,119 @@
+//! Shared test helpers for the permutation instances.
+use crate::perm::Poseidon2Permutation;
+
+use ark_ff::PrimeField;
+use ark_std::rand::thread_rng;
+
+pub(crate) const TESTRUNS: usize = 10;
+
+pub(crate) fn poseidon2_kat<
+    F: PrimeField,
+    const T: usize,
+    const D: u64,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+>(
+    poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
+    input: &[F; T],
+    expected: &[F; T],
+) {
+    let result = poseidon2_perm.permutation(input);
+    assert_eq!(&result, expected, "permutation output does not match KAT");
+}
+
+pub(crate) fn poseidon2_consistent_perm<
+    F: PrimeField,
+    const T: usize,
+    const D: u64,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+>(
+    poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
+) {
+    let mut rng = &mut thread_rng();
+    let input1: Vec<F> = (0..T).map(|_| F::rand(&mut rng)).collect();
+    let mut input2 = input1.clone();
+    input2.rotate_right(T / 2);
+
+    let perm1 = poseidon2_perm.permutation(input1.as_slice().try_into().expect("vec has length T"));
+    let perm2 = poseidon2_perm.permutation(&input1.try_into().expect("vec has length T"));
+    let perm3 = poseidon2_perm.permutation(&input2.try_into().expect("vec has length T"));
+
+    assert_eq!(perm1, perm2, "same input should give same output");
+    assert_ne!(perm1, perm3, "different input should give different output");
+}
+
+#[cfg(all(
+    feature = "bn254",
+    any(
+        feature = "t2",
+        feature = "t3",
+        feature = "t4",
+        feature = "t8",
+        feature = "t12",
+        feature = "t16",
+        feature = "t20",
+        feature = "t24"
+    )
+))]
+pub(crate) fn poseidon2_batch_perm<
+    F: PrimeField,
+    const T: usize,
+    const D: u64,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+>(
+    poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
+) {
+    let mut rng = &mut thread_rng();
+    // Covers empty batches as well as full and partial chunks
+    for len in [0, 1, 4, 7, 33] {
+        let input: Vec<[F; T]> = (0..len)
+            .map(|_| std::array::from_fn(|_| F::rand(&mut rng)))
+            .collect();
+        let mut batch = input.clone();
+        poseidon2_perm.permutation_batch(&mut batch);
+        let expected: Vec<[F; T]> = input
+            .iter()
+            .map(|s| poseidon2_perm.permutation(s))
+            .collect();
+        assert_eq!(
+            batch, expected,
+            "batch permutation differs for {len} states"
+        );
+    }
+}
+
+pub(crate) fn poseidon2_inverse_perm<
+    F: PrimeField,
+    const T: usize,
+    const D: u64,
+    const ROUNDS_F: usize,
+    const ROUNDS_P: usize,
+>(
+    poseidon2_perm: &'static Poseidon2Permutation<F, T, D, ROUNDS_F, ROUNDS_P>,
+) {
+    let mut rng = &mut thread_rng();
+    let input: [F; T] = std::array::from_fn(|_| F::rand(&mut rng));
+
+    let perm = poseidon2_perm.permutation(&input);
+    assert_eq!(
+        poseidon2_perm.permutation_inverse(&perm),
+        input,
+        "inverse permutation does not undo the permutation"
+    );
+
+    let inverse = poseidon2_perm.permutation_inverse(&input);
+    assert_eq!(
+        poseidon2_perm.permutation(&inverse),
+        input,
+        "permutation does not undo the inverse permutation"
+    );
+
+    let mut state = perm;
+    poseidon2_perm.permutation_inverse_in_place(&mut state);
+    assert_eq!(
+        state, input,
+        "inverse permutation and in-place inverse permutation differ"
+    );
+}